@@ -1,5 +1,5 @@
 use crate::fields::FieldElement;
-use std::sync::Arc;
+use alloc::{sync::Arc, vec, vec::Vec};
 
 #[derive(Clone, Debug)]
 pub struct AnemoiParams<F: FieldElement> {
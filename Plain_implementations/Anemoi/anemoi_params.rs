@@ -0,0 +1,179 @@
+use super::anemoi::AnemoiParams;
+use crate::fields::{biguint_to_limbs_le_4, PrimeField};
+use crate::utils::{binomial, modinv, smallest_invertible_power};
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use num_traits::One;
+
+// First and second blocks of 100 decimal digits of pi.
+const PI_0: &[u8] = b"1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679";
+const PI_1: &[u8] = b"8214808651328230664709384460955058223172535940812848111745028410270193852110555964462294895493038196";
+
+/// Deterministic instantiation following the Anemoi reference implementation
+/// (Bouvier et al., "New Design Techniques for Efficient Arithmetization-Oriented Hash
+/// Functions: Anemoi Permutations and Jive Compression Mode").
+impl<F: PrimeField> AnemoiParams<F> {
+    pub fn generate(n_cols: usize, security_level: usize) -> Self {
+        let p = F::modulus();
+        let g = F::generator();
+        let g_inv = modinv(&g, &p);
+        let (alpha, alpha_inv) = smallest_invertible_power(&p);
+        let rounds = get_number_of_rounds(n_cols, alpha, security_level);
+        let (round_constants_c, round_constants_d) =
+            get_round_constants::<F>(&p, &g, alpha, n_cols, rounds);
+
+        AnemoiParams {
+            n_cols,
+            width: 2 * n_cols,
+            rounds,
+            alpha,
+            alpha_inv: biguint_to_limbs_le_4(&alpha_inv),
+            beta: F::from_biguint(&g),
+            delta: F::from_biguint(&g_inv),
+            mds: get_mds_matrix::<F>(&p, &g, n_cols),
+            round_constants_c,
+            round_constants_d,
+        }
+    }
+}
+
+/// Rounds resisting the algebraic attacks for `2^security_level`, plus two rounds for the second
+/// model and a `min(5, n_cols + 1)` margin, with a floor of 8.
+pub fn get_number_of_rounds(n_cols: usize, alpha: u64, security_level: usize) -> usize {
+    let kappa = match alpha {
+        3 => 1,
+        5 => 2,
+        7 => 4,
+        9 => 7,
+        11 => 9,
+        _ => panic!("unsupported Anemoi alpha"),
+    };
+    let target = BigUint::one() << security_level;
+
+    let mut rounds = 0;
+    loop {
+        rounds += 1;
+        let bound = binomial(4 * n_cols * rounds + kappa, 2 * n_cols * rounds);
+        if &bound * &bound >= target {
+            break;
+        }
+    }
+
+    (rounds + 2 + (n_cols + 1).min(5)).max(8)
+}
+
+/// The small MDS matrices from the Anemoi paper for `n_cols <= 4`, and the Cauchy matrix
+/// `1 / (i + j + n_cols + 2)` for wider states.
+pub fn get_mds_matrix<F: PrimeField>(p: &BigUint, g: &BigUint, n_cols: usize) -> Vec<Vec<F>> {
+    let g2 = (g * g) % p;
+    let small = |rows: &[&[&BigUint]]| -> Vec<Vec<F>> {
+        rows.iter()
+            .map(|row| row.iter().map(|v| F::from_biguint(v)).collect())
+            .collect()
+    };
+    let one = BigUint::one();
+    let g_plus_1 = g + 1u32;
+    let two_g_plus_1 = g * 2u32 + 1u32;
+
+    match n_cols {
+        1 => small(&[&[&one]]),
+        2 => small(&[&[&one, g], &[g, &(&g2 + 1u32)]]),
+        3 => small(&[
+            &[&g_plus_1, &one, &g_plus_1],
+            &[&one, &one, g],
+            &[g, &one, &one],
+        ]),
+        4 => small(&[
+            &[&one, &g2, &g2, &g_plus_1],
+            &[&g_plus_1, &(g + &g2), &g2, &two_g_plus_1],
+            &[g, &g_plus_1, &one, g],
+            &[g, &two_g_plus_1, &g_plus_1, &g_plus_1],
+        ]),
+        _ => (0..n_cols)
+            .map(|i| {
+                (0..n_cols)
+                    .map(|j| {
+                        let denom = BigUint::from(i + j + n_cols + 2);
+                        F::from_biguint(&modinv(&denom, p))
+                    })
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+/// `C[r][i] = g * pi_0^(2r) + (pi_0^r + pi_1^i)^alpha` and
+/// `D[r][i] = g * pi_1^(2i) + (pi_0^r + pi_1^i)^alpha + g^-1`.
+pub fn get_round_constants<F: PrimeField>(
+    p: &BigUint,
+    g: &BigUint,
+    alpha: u64,
+    n_cols: usize,
+    rounds: usize,
+) -> (Vec<Vec<F>>, Vec<Vec<F>>) {
+    let pi_0 = BigUint::parse_bytes(PI_0, 10).expect("valid pi digits") % p;
+    let pi_1 = BigUint::parse_bytes(PI_1, 10).expect("valid pi digits") % p;
+    let g_inv = modinv(g, p);
+    let alpha = BigUint::from(alpha);
+
+    let mut round_constants_c = Vec::with_capacity(rounds);
+    let mut round_constants_d = Vec::with_capacity(rounds);
+    let mut pi_0_r = BigUint::one();
+    for _ in 0..rounds {
+        let mut c_row = Vec::with_capacity(n_cols);
+        let mut d_row = Vec::with_capacity(n_cols);
+        let mut pi_1_i = BigUint::one();
+        for _ in 0..n_cols {
+            let pow_alpha = (&pi_0_r + &pi_1_i).modpow(&alpha, p);
+            let c = (g * &pi_0_r * &pi_0_r + &pow_alpha) % p;
+            let d = (g * &pi_1_i * &pi_1_i + &pow_alpha + &g_inv) % p;
+            c_row.push(F::from_biguint(&c));
+            d_row.push(F::from_biguint(&d));
+            pi_1_i = (pi_1_i * &pi_1) % p;
+        }
+        round_constants_c.push(c_row);
+        round_constants_d.push(d_row);
+        pi_0_r = (pi_0_r * &pi_0) % p;
+    }
+
+    (round_constants_c, round_constants_d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::anemoi::AnemoiParams;
+    use super::super::instances::{
+        ANEMOI_BABYBEAR_16_PARAMS, ANEMOI_BABYBEAR_24_PARAMS, ANEMOI_BLS12_381_2_PARAMS,
+        ANEMOI_BN254_2_PARAMS, ANEMOI_GOLDILOCKS_12_PARAMS, ANEMOI_GOLDILOCKS_8_PARAMS,
+        ANEMOI_KOALABEAR_16_PARAMS, ANEMOI_KOALABEAR_24_PARAMS, ANEMOI_MERSENNE31_16_PARAMS,
+        ANEMOI_MERSENNE31_24_PARAMS,
+    };
+    use crate::fields::PrimeField;
+
+    fn assert_generates<F: PrimeField>(want: &AnemoiParams<F>) {
+        let got = AnemoiParams::<F>::generate(want.n_cols, 128);
+        assert_eq!(got.width, want.width);
+        assert_eq!(got.rounds, want.rounds);
+        assert_eq!(got.alpha, want.alpha);
+        assert_eq!(got.alpha_inv, want.alpha_inv);
+        assert_eq!(got.beta, want.beta);
+        assert_eq!(got.delta, want.delta);
+        assert_eq!(got.mds, want.mds);
+        assert_eq!(got.round_constants_c, want.round_constants_c);
+        assert_eq!(got.round_constants_d, want.round_constants_d);
+    }
+
+    #[test]
+    fn generated_params_match_shipped_instances() {
+        assert_generates(&ANEMOI_BN254_2_PARAMS);
+        assert_generates(&ANEMOI_BLS12_381_2_PARAMS);
+        assert_generates(&ANEMOI_GOLDILOCKS_8_PARAMS);
+        assert_generates(&ANEMOI_GOLDILOCKS_12_PARAMS);
+        assert_generates(&ANEMOI_BABYBEAR_16_PARAMS);
+        assert_generates(&ANEMOI_BABYBEAR_24_PARAMS);
+        assert_generates(&ANEMOI_KOALABEAR_16_PARAMS);
+        assert_generates(&ANEMOI_KOALABEAR_24_PARAMS);
+        assert_generates(&ANEMOI_MERSENNE31_16_PARAMS);
+        assert_generates(&ANEMOI_MERSENNE31_24_PARAMS);
+    }
+}
@@ -6,8 +6,8 @@ use crate::fields::goldilocks::Goldilocks;
 use crate::fields::koalabear::KoalaBear;
 use crate::fields::mersenne31::Mersenne31;
 use crate::fields::{biguint_from_limbs_le, PrimeField};
+use alloc::{sync::Arc, vec, vec::Vec};
 use lazy_static::lazy_static;
-use std::sync::Arc;
 
 fn f_from_words<F: PrimeField>(words: [u64; 4]) -> F {
     F::from_biguint(&biguint_from_limbs_le(&words))
@@ -1,2 +1,3 @@
 pub mod anemoi;
+pub mod anemoi_params;
 pub mod instances;
@@ -0,0 +1,166 @@
+use super::gmimc_erf::GmimcErfParams;
+use crate::fields::PrimeField;
+use crate::utils::field_element_from_shake;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+
+pub const INIT_SHAKE: &str = "GMiMC";
+
+/// Deterministic instantiation of GMiMC with the expanding round function (Albrecht et al.,
+/// "Feistel Structures for MPC, and More").
+impl<F: PrimeField> GmimcErfParams<F> {
+    pub fn generate(t: usize, d: u64) -> Self {
+        assert!(t >= 2);
+        assert!(d >= 2);
+        let p = F::modulus();
+        let rounds = get_number_of_rounds(t, d, &p);
+        let round_constants = get_round_constants::<F>(&p, rounds);
+
+        GmimcErfParams::new(t, d, rounds, &round_constants)
+    }
+}
+
+/// `max(2 + 2 * (t + t^2), ceil(2 * log_d(p)) + 2 * t)`: the first term covers the
+/// statistical attacks, the second the interpolation attack.
+pub fn get_number_of_rounds(t: usize, d: u64, p: &BigUint) -> usize {
+    let statistical = 2 + 2 * (t + t * t);
+
+    // ceil(2 * log_d(p)) is the smallest k with d^k >= p^2.
+    let p2 = p * p;
+    let d = BigUint::from(d);
+    let mut power = d.clone();
+    let mut log = 1;
+    while power < p2 {
+        power *= &d;
+        log += 1;
+    }
+
+    statistical.max(log + 2 * t)
+}
+
+/// SHAKE128 seeded with `"GMiMC"` followed by the little-endian 64-bit limbs of `p`.
+pub fn init_shake(p: &BigUint) -> impl XofReader {
+    let mut shake = Shake128::default();
+    shake.update(INIT_SHAKE.as_bytes());
+    for limb in p.to_u64_digits() {
+        shake.update(&u64::to_le_bytes(limb));
+    }
+    shake.finalize_xof()
+}
+
+/// One constant per round, read from [`init_shake`].
+pub fn get_round_constants<F: PrimeField>(p: &BigUint, rounds: usize) -> Vec<F> {
+    let mut shake = init_shake(p);
+    (0..rounds)
+        .map(|_| F::from_biguint(&field_element_from_shake(p, &mut shake)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::gmimc_erf::GmimcErfParams;
+    use super::super::instances::{
+        GMIMC_ERF_ALPHA3_BABYBEAR_16_PARAMS, GMIMC_ERF_ALPHA3_BABYBEAR_24_PARAMS,
+        GMIMC_ERF_ALPHA3_BLS12_381_2_PARAMS, GMIMC_ERF_ALPHA3_BLS12_381_3_PARAMS,
+        GMIMC_ERF_ALPHA3_BN254_2_PARAMS, GMIMC_ERF_ALPHA3_BN254_3_PARAMS,
+        GMIMC_ERF_ALPHA3_GOLDILOCKS_12_PARAMS, GMIMC_ERF_ALPHA3_GOLDILOCKS_8_PARAMS,
+        GMIMC_ERF_ALPHA3_KOALABEAR_16_PARAMS, GMIMC_ERF_ALPHA3_KOALABEAR_24_PARAMS,
+        GMIMC_ERF_ALPHA3_MERSENNE31_16_PARAMS, GMIMC_ERF_ALPHA3_MERSENNE31_24_PARAMS,
+        GMIMC_ERF_BABYBEAR_16_PARAMS, GMIMC_ERF_BABYBEAR_24_PARAMS, GMIMC_ERF_BLS12_381_2_PARAMS,
+        GMIMC_ERF_BLS12_381_3_PARAMS, GMIMC_ERF_BN254_2_PARAMS, GMIMC_ERF_BN254_3_PARAMS,
+        GMIMC_ERF_GOLDILOCKS_12_PARAMS, GMIMC_ERF_GOLDILOCKS_8_PARAMS,
+        GMIMC_ERF_KOALABEAR_16_PARAMS, GMIMC_ERF_KOALABEAR_24_PARAMS,
+        GMIMC_ERF_MERSENNE31_16_PARAMS, GMIMC_ERF_MERSENNE31_24_PARAMS,
+    };
+    use super::get_round_constants;
+    use crate::fields::PrimeField;
+
+    // The generator reproduces the benchmarked alpha = 3 round counts. For alpha = 2 the
+    // interpolation bound dominates over the ~256-bit fields and needs about 2 log_2(p) rounds.
+    fn assert_generated<F: PrimeField>(
+        alpha2: &GmimcErfParams<F>,
+        alpha3: &GmimcErfParams<F>,
+        rounds: [usize; 2],
+    ) {
+        assert_eq!(alpha2.d, 2);
+        assert_eq!(alpha2.rounds, rounds[0]);
+        assert_eq!(alpha3.d, 3);
+        assert_eq!(alpha3.rounds, rounds[1]);
+        assert_eq!(
+            alpha2.round_constants,
+            get_round_constants::<F>(&F::modulus(), rounds[0])
+        );
+        assert_eq!(
+            alpha3.round_constants[..],
+            alpha2.round_constants[..rounds[0].min(rounds[1])]
+        );
+    }
+
+    #[test]
+    fn generated_params_match_benchmarked_rounds() {
+        assert_generated(
+            &GMIMC_ERF_BN254_2_PARAMS,
+            &GMIMC_ERF_ALPHA3_BN254_2_PARAMS,
+            [512, 325],
+        );
+        assert_generated(
+            &GMIMC_ERF_BN254_3_PARAMS,
+            &GMIMC_ERF_ALPHA3_BN254_3_PARAMS,
+            [514, 327],
+        );
+        assert_generated(
+            &GMIMC_ERF_BLS12_381_2_PARAMS,
+            &GMIMC_ERF_ALPHA3_BLS12_381_2_PARAMS,
+            [514, 326],
+        );
+        assert_generated(
+            &GMIMC_ERF_BLS12_381_3_PARAMS,
+            &GMIMC_ERF_ALPHA3_BLS12_381_3_PARAMS,
+            [516, 328],
+        );
+        assert_generated(
+            &GMIMC_ERF_GOLDILOCKS_8_PARAMS,
+            &GMIMC_ERF_ALPHA3_GOLDILOCKS_8_PARAMS,
+            [146, 146],
+        );
+        assert_generated(
+            &GMIMC_ERF_GOLDILOCKS_12_PARAMS,
+            &GMIMC_ERF_ALPHA3_GOLDILOCKS_12_PARAMS,
+            [314, 314],
+        );
+        assert_generated(
+            &GMIMC_ERF_BABYBEAR_16_PARAMS,
+            &GMIMC_ERF_ALPHA3_BABYBEAR_16_PARAMS,
+            [546, 546],
+        );
+        assert_generated(
+            &GMIMC_ERF_BABYBEAR_24_PARAMS,
+            &GMIMC_ERF_ALPHA3_BABYBEAR_24_PARAMS,
+            [1202, 1202],
+        );
+        assert_generated(
+            &GMIMC_ERF_KOALABEAR_16_PARAMS,
+            &GMIMC_ERF_ALPHA3_KOALABEAR_16_PARAMS,
+            [546, 546],
+        );
+        assert_generated(
+            &GMIMC_ERF_KOALABEAR_24_PARAMS,
+            &GMIMC_ERF_ALPHA3_KOALABEAR_24_PARAMS,
+            [1202, 1202],
+        );
+        assert_generated(
+            &GMIMC_ERF_MERSENNE31_16_PARAMS,
+            &GMIMC_ERF_ALPHA3_MERSENNE31_16_PARAMS,
+            [546, 546],
+        );
+        assert_generated(
+            &GMIMC_ERF_MERSENNE31_24_PARAMS,
+            &GMIMC_ERF_ALPHA3_MERSENNE31_24_PARAMS,
+            [1202, 1202],
+        );
+    }
+}
@@ -5,7122 +5,59 @@ use crate::fields::bn254::Bn254;
 use crate::fields::goldilocks::Goldilocks;
 use crate::fields::koalabear::KoalaBear;
 use crate::fields::mersenne31::Mersenne31;
-use crate::fields::{biguint_from_limbs_le, PrimeField};
+use alloc::sync::Arc;
 use lazy_static::lazy_static;
-use std::sync::Arc;
-
-fn f_from_words<F: PrimeField>(words: [u64; 4]) -> F {
-    F::from_biguint(&biguint_from_limbs_le(&words))
-}
 
 lazy_static! {
-    pub static ref GMIMC_ERF_BN254_2_PARAMS: Arc<GmimcErfParams<Bn254>> = {
-        let round_constants: Vec<Bn254> = vec![
-            f_from_words::<Bn254>([0x561b957379a02ddeu64, 0x1ac5c26643c2b85du64, 0xf452658b0bd96728u64, 0x2b70a65cc52d0aa1u64]),
-            f_from_words::<Bn254>([0x7b502dd962d53060u64, 0xfcc6201bfde3b640u64, 0xa50e7c3b8d5b4a6eu64, 0x1a89194d2a6c8902u64]),
-            f_from_words::<Bn254>([0x434cdc09b32d14dcu64, 0xcb0d152f285257e4u64, 0xb71bbdb0a8c97022u64, 0x1966fd0c97b5b8f9u64]),
-            f_from_words::<Bn254>([0x1924eb3b6181bba1u64, 0x9cddf296ad53c7e4u64, 0xfb75ad7f87a46de4u64, 0x11170c049bb5a9d2u64]),
-            f_from_words::<Bn254>([0x446c6491476279a6u64, 0xbf136babd446f21du64, 0x4c280b589fc91ac9u64, 0x175b7991f49de9feu64]),
-            f_from_words::<Bn254>([0x11eae07213ac0091u64, 0xffa933677615ff76u64, 0x0e1bb30e465bc203u64, 0x2eb0668c8f5d3721u64]),
-            f_from_words::<Bn254>([0x40b8b30220ce1b19u64, 0xed461fafb72d9b7eu64, 0x5861da26770f6e9du64, 0x2f5f3dc3e9189a37u64]),
-            f_from_words::<Bn254>([0x9914c4be224ede68u64, 0x35132895a2798f0cu64, 0x1e43730d2f5f550au64, 0x1001cd4af49906a6u64]),
-            f_from_words::<Bn254>([0xd71d0d1489795e04u64, 0x13e1556056e84d2bu64, 0xea1816d504232632u64, 0x28fd9f8212ed540cu64]),
-            f_from_words::<Bn254>([0x9fbae5553a592e65u64, 0x23e43f2ae5e4198eu64, 0xd987d41847dbd2b1u64, 0x2cff365d949da623u64]),
-            f_from_words::<Bn254>([0x861fec8ba3529b46u64, 0xa79485b74b72ca85u64, 0xa109a6cc24f0da7eu64, 0x1e9bec2b3c3e4086u64]),
-            f_from_words::<Bn254>([0xed54a3b76b6ab469u64, 0x1bec4f60caba2024u64, 0xc365a5bd07242b3cu64, 0x1a313f399c552664u64]),
-            f_from_words::<Bn254>([0x90762469397899aau64, 0x4c6d9e42078b149eu64, 0x568737560439f107u64, 0x0cbd036f92233c1au64]),
-            f_from_words::<Bn254>([0x143fd0dae37a6dfbu64, 0x2f2cc5a8405a59d4u64, 0x2acdccc2ed5927adu64, 0x0466119d32a30458u64]),
-            f_from_words::<Bn254>([0x1761bd6d238129e2u64, 0x89b2fd2baaf0103au64, 0x10cfb46d4c998a4bu64, 0x0a1d88f8423fc5d5u64]),
-            f_from_words::<Bn254>([0xd9c6ac77c01ddd2eu64, 0x8cb68e1ed89f2deeu64, 0x385b871152cd3d01u64, 0x00376891e9583090u64]),
-            f_from_words::<Bn254>([0xcf8f03c8aab7b9aau64, 0x18e6536ccd814327u64, 0x04f82ba9b47df509u64, 0x2c8b545daaaa7f56u64]),
-            f_from_words::<Bn254>([0x0b5d4be1217e7177u64, 0x38ed6f70119ec798u64, 0x5b212ccc38b81d97u64, 0x2111e2bca559e705u64]),
-            f_from_words::<Bn254>([0x43d16eafdbb390efu64, 0x816b7254a39cf3e6u64, 0xda56f01d2976de6au64, 0x12e11192532c6badu64]),
-            f_from_words::<Bn254>([0xdb5e20f0e6560460u64, 0x3210444ec417a36du64, 0xf091f74942f4f409u64, 0x0540d5b8c58792d4u64]),
-            f_from_words::<Bn254>([0xc6a2ddf5af829dffu64, 0xb505eadfc8dc509cu64, 0x92fdb4c09f9926afu64, 0x295140f5b1894011u64]),
-            f_from_words::<Bn254>([0x98efef9b136c407du64, 0xbb8127cdb6c56736u64, 0xf8505ca9ce03e549u64, 0x1fd690c7455cdc2cu64]),
-            f_from_words::<Bn254>([0x402f7afe505a684au64, 0xae83d6258b9e20afu64, 0x66617c8e5c4d83beu64, 0x039fc63237b64520u64]),
-            f_from_words::<Bn254>([0x9f1628a5d1fe27bau64, 0x7d62eb16ce2d21c0u64, 0xed6867d3f0e3c816u64, 0x2105b6f10af5b53eu64]),
-            f_from_words::<Bn254>([0x888476c039b17e30u64, 0xdd6ae22821b195fdu64, 0x7757d7cdbfa2f14bu64, 0x0710a056422240c1u64]),
-            f_from_words::<Bn254>([0x9982b650280811b6u64, 0x18a35099f0af36cfu64, 0xd1053ad15ab471eau64, 0x0d321c091184dd04u64]),
-            f_from_words::<Bn254>([0x353381c4b9a285d1u64, 0xbbf2860fa0f3d1c2u64, 0x92660a214f8ecb4du64, 0x228a83d566fe834du64]),
-            f_from_words::<Bn254>([0x818c6ce8a80ec86fu64, 0xd156c01a1ae6b1c6u64, 0x6a6c2804b2abadd4u64, 0x16e507d25430e8eau64]),
-            f_from_words::<Bn254>([0x75e1adc292aad0dfu64, 0xa290ce95c8ee07fbu64, 0x935e0eb32e6b2723u64, 0x13c97c5188ca8309u64]),
-            f_from_words::<Bn254>([0xb8741592b2b6e26du64, 0x6cb5afa356df9ca1u64, 0x7439960865d5f881u64, 0x1dd50c6553813047u64]),
-            f_from_words::<Bn254>([0xe9fff81dfea990bau64, 0xb75d8b743f2f236eu64, 0xcc5ae836b6e1c3a3u64, 0x1a822ba0068a645fu64]),
-            f_from_words::<Bn254>([0xce1415b78568852fu64, 0x8af8ca78886e425fu64, 0x2e078b86ff769a84u64, 0x0c5dc7b241dee2f6u64]),
-            f_from_words::<Bn254>([0xd5717db69eeee892u64, 0xd909b0f4191e83a9u64, 0x457773298da6af1fu64, 0x008532b051b17758u64]),
-            f_from_words::<Bn254>([0xdbad2964c39bb387u64, 0x21337682cd1cc1c6u64, 0x70ccefecf9be52c5u64, 0x09e10527a95fd1aau64]),
-            f_from_words::<Bn254>([0xd4240fbf56af861au64, 0xc9c6a7079711728bu64, 0x5948429f4742dd7cu64, 0x0b7a290c902ebb97u64]),
-            f_from_words::<Bn254>([0xef0082ffc699b16cu64, 0xa7f07ac97f7db62fu64, 0x16c837535fc8c030u64, 0x00fbfaf2eb8e3a0du64]),
-            f_from_words::<Bn254>([0xcff99d0cbc71b39du64, 0xe0b5d5b0942d4334u64, 0x86bb6fdf494eb13au64, 0x02629ab8c8e34225u64]),
-            f_from_words::<Bn254>([0x7e71ba5c08614f7au64, 0x07838ec4b6ced36fu64, 0xafdac8de11b6cb08u64, 0x1f6c81c435f912beu64]),
-            f_from_words::<Bn254>([0x3ef63a0ae775ef63u64, 0x834240f948ee0c61u64, 0x511125285a548f1cu64, 0x10d9998de8c4dd08u64]),
-            f_from_words::<Bn254>([0xd51ff6c92cffffa0u64, 0x507b8a267860816du64, 0x181e330737f633dbu64, 0x0703c5695204ee9bu64]),
-            f_from_words::<Bn254>([0x65dcddaa4380c859u64, 0x80af7ef7ef799924u64, 0x7aad9f63d97a21d3u64, 0x190818fb763627e7u64]),
-            f_from_words::<Bn254>([0x5a467b0e941312fbu64, 0x6680c88fe16d10d4u64, 0xf8d42d54b52b285cu64, 0x244e619a82990337u64]),
-            f_from_words::<Bn254>([0x3f148acd8a6895fau64, 0x04ac0ab3b19aafe4u64, 0xeb9fbdc9491374d5u64, 0x22c686b6041cc436u64]),
-            f_from_words::<Bn254>([0xc877ebf384c0d910u64, 0xff28908f6f52b504u64, 0xea7f34ed93a6828eu64, 0x1ca5fedda2d23d72u64]),
-            f_from_words::<Bn254>([0x9a8c2a1a23ab232au64, 0xe5552f928a482447u64, 0x3238b87b0d63a6beu64, 0x04e2d1ae7019b330u64]),
-            f_from_words::<Bn254>([0x62f1e936da3749d2u64, 0x3ec84f6c2b2c860du64, 0xd504b51c3827c45fu64, 0x1a10dbd9cb97b049u64]),
-            f_from_words::<Bn254>([0x4cf1e4ae92ce884du64, 0x072525de734ac426u64, 0x28f7552c75a985a3u64, 0x163401e442e7e15bu64]),
-            f_from_words::<Bn254>([0x2238fd1c5d135980u64, 0x784d8c24d6242c9fu64, 0x1b973ec6b7622bb7u64, 0x1421d84fb1cfb28fu64]),
-            f_from_words::<Bn254>([0xaf5252c4b7a56a87u64, 0xbe79f3785725f578u64, 0xc9cbe2a6d7ccd8f4u64, 0x1638ec0f3a04b383u64]),
-            f_from_words::<Bn254>([0x25f1a9436060d438u64, 0x39841d5b7e203c2eu64, 0xbe2f5752366ef61au64, 0x1c66d11ba508c7dcu64]),
-            f_from_words::<Bn254>([0x2c706d9b3db3ba96u64, 0x338b1e853ad4d80eu64, 0x309fde70a4ed7cc7u64, 0x1e42544eec7c32a2u64]),
-            f_from_words::<Bn254>([0x2cc5995d88b0738bu64, 0x9d8592c331150fc2u64, 0x81578c516b599caeu64, 0x05c08bf8b830529fu64]),
-            f_from_words::<Bn254>([0x37c6fb71da463201u64, 0x865c2ed53d65e68eu64, 0x9e477d7d9dffa916u64, 0x2be4f3ba549107adu64]),
-            f_from_words::<Bn254>([0x610cf3d43aacd2e5u64, 0x3285e1441eb9654au64, 0x408fcfd521d74524u64, 0x02ba06cb3885b605u64]),
-            f_from_words::<Bn254>([0x62bff8287b2dc29au64, 0xe593626031ee6787u64, 0x0e2f317609f17a22u64, 0x0fd162e681e28bbfu64]),
-            f_from_words::<Bn254>([0x844b286efa9e0c68u64, 0xb6513388f748b7deu64, 0x3218a77fa119e2eeu64, 0x16c0f6a23c745343u64]),
-            f_from_words::<Bn254>([0x4090ccaa097464cbu64, 0x2c86027aa83d6afeu64, 0x4d8baa3b4cd2834du64, 0x15e54f4de48512f1u64]),
-            f_from_words::<Bn254>([0x801744f46c06f1b7u64, 0xb5a7da8ed9e3ed0eu64, 0x7900514189cf1613u64, 0x06204a6cbc79ca57u64]),
-            f_from_words::<Bn254>([0x831e8cf228fb3048u64, 0xc85da64387dc13f4u64, 0x295fd5f09ca3e571u64, 0x20e0e282b0f67a76u64]),
-            f_from_words::<Bn254>([0x3a47928bc7eb7702u64, 0xa0b2817b07086c91u64, 0x1357dc6f2da59276u64, 0x2a1de9a677f72d1au64]),
-            f_from_words::<Bn254>([0x09b8650c6b038291u64, 0x826ad66d472a51beu64, 0x45f28707d9222f19u64, 0x2dbdd50a4cd90d79u64]),
-            f_from_words::<Bn254>([0x902f9f6848e839d3u64, 0x622a9ba7ecbbdf78u64, 0x54beecf8e62c8c01u64, 0x2118af70fa3bfc72u64]),
-            f_from_words::<Bn254>([0x4b4a2e5fc324222eu64, 0x53a5b058b18fc75bu64, 0x1d8046fda13698d3u64, 0x26b84665d95ed8b9u64]),
-            f_from_words::<Bn254>([0xc46c3644d9553d6cu64, 0xa9ceeb1c125de77du64, 0x7f60225f2919f091u64, 0x072cb7dcbb57366cu64]),
-            f_from_words::<Bn254>([0x533c39d9de57aa94u64, 0xc6ea4153f98fbfe0u64, 0x4b51e92d87c26ea2u64, 0x00d8cc510b5d550eu64]),
-            f_from_words::<Bn254>([0x79892be0559302e9u64, 0xf9220eae9174eebau64, 0x8e80b340cc4d3136u64, 0x20362c6e1e4b4865u64]),
-            f_from_words::<Bn254>([0x003a949f617f8ca6u64, 0x68a27e1ede536540u64, 0xbeb8521d4e314545u64, 0x104af66df0ccd34cu64]),
-            f_from_words::<Bn254>([0x6071bee4bc78dcd8u64, 0x08ac2c226b0027a3u64, 0x30c17dfbc4b95560u64, 0x108a205a87141687u64]),
-            f_from_words::<Bn254>([0xeab6e1095ccb78dfu64, 0x94a584d884248f52u64, 0xcc0babce9b4d3b94u64, 0x00fea3c7d66c0f3bu64]),
-            f_from_words::<Bn254>([0x4da4a93aa24badb0u64, 0x5043864ab01e0cb4u64, 0x24f87c6c13e0e302u64, 0x0adbc427424a7a77u64]),
-            f_from_words::<Bn254>([0xda239cdcba8d3ca2u64, 0x5d0ebed537e2dc3au64, 0x31cd3eb6231cb1c0u64, 0x272bf09d7593e166u64]),
-            f_from_words::<Bn254>([0x3cbcefeee486ac51u64, 0x81e3eb3511c22417u64, 0xe64f852f40b211ffu64, 0x22cbbfbf59b6e172u64]),
-            f_from_words::<Bn254>([0xdea7bb7da523cbf1u64, 0x99583ac2a4271f02u64, 0xcb02e2d8299e8451u64, 0x091f5321238989cfu64]),
-            f_from_words::<Bn254>([0xc465cf994749af59u64, 0xd411cd01b7a0df35u64, 0x1e7b23a8e81a7865u64, 0x03e555ca3da5d82du64]),
-            f_from_words::<Bn254>([0xec9e4b95d6ef89e9u64, 0x81cc74f3f6a94b82u64, 0xdb8dad282e7bb3a1u64, 0x1ce7762cf8fb2284u64]),
-            f_from_words::<Bn254>([0x14b0d59cea031ff7u64, 0x71e559aac8f3b872u64, 0xaa5c31b7995f950fu64, 0x1977b45a90c2a1b4u64]),
-            f_from_words::<Bn254>([0xd078878a56d51753u64, 0xd88bd3b3f91a7849u64, 0x045fe8a723421f5au64, 0x1fc5188f75e96c8au64]),
-            f_from_words::<Bn254>([0x58cb49a652402596u64, 0x27418f3d5773967bu64, 0x93d9e0f10d8a93e3u64, 0x2a39ccdde6b2c1ddu64]),
-            f_from_words::<Bn254>([0xad71c7a9708c01acu64, 0x4c7b8ef592be76dcu64, 0x3a5f3281ce1d520du64, 0x207378b95daf98f0u64]),
-            f_from_words::<Bn254>([0xbe7f8c6b8be32539u64, 0xccb9f483db1f39b2u64, 0x0c3db204e6fce1fdu64, 0x3024a178b1d9dba1u64]),
-            f_from_words::<Bn254>([0x28fee757edd3b71fu64, 0x4dfbe3c679d0a648u64, 0xc33b932486d7e2a0u64, 0x2c901ab84a7aca5cu64]),
-            f_from_words::<Bn254>([0x567fa5fde528d54cu64, 0x6cd308bd2ba5a47cu64, 0xd46f4630ce1457feu64, 0x177cc1a51ccd357eu64]),
-            f_from_words::<Bn254>([0x4cba519396f5f4b0u64, 0xb5f47c3a4ea86521u64, 0x8eca3d4c7a45b5d0u64, 0x221fafa02eef11c4u64]),
-            f_from_words::<Bn254>([0x4c64244556729212u64, 0xe2d0a89cebf646ccu64, 0xd1cf53886217356bu64, 0x10d0bd30cae47608u64]),
-            f_from_words::<Bn254>([0xabc0be69c49ef53fu64, 0x00d1128164614f15u64, 0x3438089a1eaa493eu64, 0x11edfe8eadc0f7c0u64]),
-            f_from_words::<Bn254>([0x9576a7bb865312feu64, 0x0aa482f9f530b928u64, 0xb392a993f4da9d1bu64, 0x1b0ba2a290f27ccdu64]),
-            f_from_words::<Bn254>([0x9cc85eb8f280ce02u64, 0xac33e643526ea1a3u64, 0x537935db8876dbefu64, 0x1c10e768095c6987u64]),
-            f_from_words::<Bn254>([0x15106b782033587cu64, 0x72f576ec6899a065u64, 0x90160787847689b6u64, 0x02ed395e40794d85u64]),
-            f_from_words::<Bn254>([0x1770ef96a87deb1cu64, 0x3f5360e6ef46a43bu64, 0x2a2992fb9328f24cu64, 0x09539e1dbb141d4cu64]),
-            f_from_words::<Bn254>([0xbea63afee0f3ccd6u64, 0x7837aec437c62676u64, 0xb870f276b4e8acd6u64, 0x26d3a59b02f431d8u64]),
-            f_from_words::<Bn254>([0xd972e0905f463bfdu64, 0x7bdfd1eac4b15670u64, 0x9a166e155b0894d1u64, 0x180e6b3a12388475u64]),
-            f_from_words::<Bn254>([0x7f28ce17f5d5d981u64, 0x56150f9552a93a7fu64, 0xee6b4d60cdcf62d3u64, 0x2c0727514b693590u64]),
-            f_from_words::<Bn254>([0xc36f4ede65d5cf7bu64, 0x3d5d3bf31c7c9aa7u64, 0xf2d6d8bb392203c6u64, 0x2c677306c76e0300u64]),
-            f_from_words::<Bn254>([0x9c9a7c5ca68766efu64, 0x7c900f7f28708d2cu64, 0xb61d08be51afcdd0u64, 0x14c4dcf6a0fb4057u64]),
-            f_from_words::<Bn254>([0x8039f8942c9a02d7u64, 0x75b96a8b591cf1f9u64, 0xefa3fc13561636b7u64, 0x0e0e0f37791daf09u64]),
-            f_from_words::<Bn254>([0x57713ac7e6875c51u64, 0x52df7a55f5e34229u64, 0xb823cee8b708bdbau64, 0x17b65ce2f41167d5u64]),
-            f_from_words::<Bn254>([0x2d3311020cfff951u64, 0x1aafbcb1dca07b53u64, 0xa9e1a374152ff609u64, 0x025bf25fe20e477fu64]),
-            f_from_words::<Bn254>([0xd7e022f03168aed8u64, 0xd6a076a614f4f15bu64, 0xbc1130a1d63073a1u64, 0x0cea59efced69cb9u64]),
-            f_from_words::<Bn254>([0xbaad2493d68a1c4cu64, 0xfd46771897077c46u64, 0x949fc254524e149au64, 0x2a03594e714a8a11u64]),
-            f_from_words::<Bn254>([0x2aa94cb6f164902eu64, 0x2d13fc09a86a6af6u64, 0xa205cf2c345397f5u64, 0x2e64fb91020076b3u64]),
-            f_from_words::<Bn254>([0xbd78c13161633d77u64, 0x80ff491bcb11f656u64, 0x4a7a1cb87098a053u64, 0x178472f60be9120au64]),
-            f_from_words::<Bn254>([0x9d11988bb8e2c764u64, 0x02e23693065aa6a3u64, 0xbb33f9b5e49771ddu64, 0x12c95a833ab499c6u64]),
-            f_from_words::<Bn254>([0x5744f60dbbbeb789u64, 0x3f42f1901187be26u64, 0xbdb7470a9a8de643u64, 0x175e07800ef29ca0u64]),
-            f_from_words::<Bn254>([0xce0b01a626869448u64, 0x7b4304851ad60e53u64, 0x1cb7b9b782ba47aeu64, 0x29116bd07dd25323u64]),
-            f_from_words::<Bn254>([0x84e65a3a8bffd003u64, 0x0a7d0496cc546559u64, 0x7bdcb0ccf90bed0du64, 0x0b3d89768896e8e3u64]),
-            f_from_words::<Bn254>([0x57f9c66f69254ef9u64, 0x12c17087c60ec979u64, 0xb540d146d5f2b343u64, 0x018cb35835d8a9d6u64]),
-            f_from_words::<Bn254>([0xb1b7cbe35fee8acdu64, 0x8c1c948ec66a4e93u64, 0x33d8ca807c5da37au64, 0x22b7816fb8be69f5u64]),
-            f_from_words::<Bn254>([0x54900c57252be589u64, 0x5e2770a3dbb454a3u64, 0xf63739b9323167afu64, 0x15178c843a124eccu64]),
-            f_from_words::<Bn254>([0x330d3f8c3948fc4cu64, 0xb63c254bb965f1f9u64, 0x97b21642732e671fu64, 0x0d256db0eb87b230u64]),
-            f_from_words::<Bn254>([0xc4b72325c8c7397fu64, 0xfc4824aa1450eefau64, 0x341a7c16d5ef9f24u64, 0x17b146858ebad6d8u64]),
-            f_from_words::<Bn254>([0x0c4afb06e056285au64, 0x56495746e6319e2cu64, 0x88bb617a179d10fcu64, 0x24add74786faf043u64]),
-            f_from_words::<Bn254>([0xb836c6d22cbea6c6u64, 0xc23d459327ed0227u64, 0x7acfafee265258edu64, 0x0a2f6967ba46953au64]),
-            f_from_words::<Bn254>([0x8fcafa56ac76c4dbu64, 0x767a00a671bdbf44u64, 0x94c80bf7441555a8u64, 0x2dac79105b4b1fc0u64]),
-            f_from_words::<Bn254>([0x3e0c82ab886de554u64, 0xa3df827313e24573u64, 0x5860bb25e0323245u64, 0x22bcb0500cf7fcd2u64]),
-            f_from_words::<Bn254>([0x1b91807c39073ef7u64, 0x0b1b5d5341692dfeu64, 0x7b3075b7ffe485cbu64, 0x2d06e2e2a02c5e20u64]),
-            f_from_words::<Bn254>([0xcff153f2b7041e05u64, 0x7f2f5a837482ef0au64, 0xbe37038e85704dbfu64, 0x2722fd8135f7ca38u64]),
-            f_from_words::<Bn254>([0x672351094094c59bu64, 0x396b7801ece62dceu64, 0x2eca986560071578u64, 0x1c29af00f86a9c45u64]),
-            f_from_words::<Bn254>([0x8578c6ebeb6a6bf3u64, 0x0d60326a367ccfcdu64, 0x50d38f3132eae4dfu64, 0x0db0020ca0a8f81fu64]),
-            f_from_words::<Bn254>([0x818c9673ef06a27cu64, 0x745097b30ba566a6u64, 0xb75e6a10cbcf4e27u64, 0x16e846151fc1ad12u64]),
-            f_from_words::<Bn254>([0x2aef27e10827ccebu64, 0x8ff08dd1fa303829u64, 0x93d9383aaa440490u64, 0x1d0d5831a6f9df7du64]),
-            f_from_words::<Bn254>([0x287f0576f72d3940u64, 0xdd94cfddbac58d28u64, 0x4dbfde40e1283b51u64, 0x20701b1fc0cd1cb6u64]),
-            f_from_words::<Bn254>([0x91ef21f04f4c84d7u64, 0xb2c3d237b0da8ae6u64, 0x9f46ae83f5828b94u64, 0x12781d6b843c6e55u64]),
-            f_from_words::<Bn254>([0xa82f05fb7117b6e2u64, 0xe6f7d77056b8f73cu64, 0x1a3955e8f3f074cdu64, 0x0525a0b414cb7789u64]),
-            f_from_words::<Bn254>([0x7d5ed6554e347d30u64, 0x8f0cef0fdffef4c4u64, 0x5da685c87ba2bf5du64, 0x0a49e0a200a0f800u64]),
-            f_from_words::<Bn254>([0xd16939144f53046fu64, 0x4cae0ff4ddae6752u64, 0xc8fb4368c5abc990u64, 0x1d62313564c26545u64]),
-            f_from_words::<Bn254>([0x453fefc6ca417134u64, 0x97e152a292fe1906u64, 0xa7e552d3ce1404a3u64, 0x1632d893c77afd88u64]),
-            f_from_words::<Bn254>([0xba0b3e6749c179beu64, 0x91210971325184f6u64, 0x035fd12b5667f984u64, 0x09bac1fa7932a9b3u64]),
-            f_from_words::<Bn254>([0x2e7313510f2bb04du64, 0xc37601806be22a66u64, 0x157826f8232d1cbau64, 0x2932b4fadc38c956u64]),
-            f_from_words::<Bn254>([0x27d3fe311c7e3e9du64, 0xa97fb3973a9dea72u64, 0xdfe05c68e6dc5e47u64, 0x01358125ca29537bu64]),
-            f_from_words::<Bn254>([0x29a7be883a5722aeu64, 0x0cee1ad4b05020b2u64, 0xadb7d8cd0bee52eau64, 0x26d788e37b608e2eu64]),
-            f_from_words::<Bn254>([0x558ee8b68565ca53u64, 0xc29bb93039f86a09u64, 0x87c53330edd20105u64, 0x2f7bae610cdaa365u64]),
-            f_from_words::<Bn254>([0xdbda78a308e41c1au64, 0x38099835db530e93u64, 0xb31440e17c61bde7u64, 0x28a12e887c4522f3u64]),
-            f_from_words::<Bn254>([0x9b203bd05e2699dbu64, 0xdc68e0e654d20e65u64, 0xf5fb7979677e43cfu64, 0x2d498a84b86219e8u64]),
-            f_from_words::<Bn254>([0xfc39bf98cb0461aeu64, 0x765c502286313b7du64, 0xa160633cbe476903u64, 0x12168d784a7c8e5bu64]),
-            f_from_words::<Bn254>([0x60d3af3fcd712b4cu64, 0x5a9960aff37881d2u64, 0xf061eb18fe6037d3u64, 0x12ae446f6f5f0703u64]),
-            f_from_words::<Bn254>([0xbdbd7f4de65d04c2u64, 0xc73442f0ce1f300cu64, 0x786ff72ccb242be0u64, 0x2395f403547d82fbu64]),
-            f_from_words::<Bn254>([0x6636bc167db59611u64, 0x593abb1150e9638bu64, 0xa0836382ebece5cau64, 0x1d19e58f12bc8b1cu64]),
-            f_from_words::<Bn254>([0x4b302b3ab7412b12u64, 0x7119b1bd345d09ecu64, 0x6644bdcdc37ed509u64, 0x27a93d36b18eaba9u64]),
-            f_from_words::<Bn254>([0x4edc66aff13c7b4cu64, 0x2a8cda341fff718au64, 0x89c311155976610eu64, 0x2a1a5693d71261eau64]),
-            f_from_words::<Bn254>([0x6db985a202738b76u64, 0x73bf6bf580088e8fu64, 0x8a415d0d47316ccfu64, 0x247ff8b0d1ed9df0u64]),
-            f_from_words::<Bn254>([0xdc017c5f9b14d4cdu64, 0xf35a5d0ee482b101u64, 0x50e6a5e4cdc82eceu64, 0x26a1a2d833cfd14eu64]),
-            f_from_words::<Bn254>([0x7c2e1113275e88deu64, 0xd516e1c1799fea0fu64, 0xfa79bf5342d0aff1u64, 0x02269de596f73c19u64]),
-            f_from_words::<Bn254>([0xe80ca902f81bec84u64, 0xdabd224f23b2363bu64, 0xde8653aa930f23c9u64, 0x2b57ab46890aeabau64]),
-            f_from_words::<Bn254>([0x2f601b624c3c7cacu64, 0x96843f35ebf61c42u64, 0xcc8313a1fcd19f60u64, 0x2eab56a83a53d109u64]),
-            f_from_words::<Bn254>([0x14e9c84288dbeec8u64, 0x9847835d9fdec224u64, 0xa4d56b996b7cbce8u64, 0x059ee22f6187340fu64]),
-            f_from_words::<Bn254>([0x7b45a2b86c7293ceu64, 0x255125715293503cu64, 0x6413c1b6860f98a7u64, 0x1fd3fc5b1e776398u64]),
-            f_from_words::<Bn254>([0xc49d706008ec3953u64, 0xeeb2fb8ac9f53c00u64, 0x32d40b4252123a16u64, 0x16e3a34ce6983e38u64]),
-            f_from_words::<Bn254>([0x37887c8ea660b969u64, 0x6b29aef97bb622aeu64, 0xf6b2460976e15a69u64, 0x228e2305d3231ce4u64]),
-            f_from_words::<Bn254>([0x51ae9c775ea1e62fu64, 0x3e390e605b42fc19u64, 0x6c2c8b13fd7b4cc4u64, 0x024d2903e38ca225u64]),
-            f_from_words::<Bn254>([0x898140ece0fc2c14u64, 0x1d3134431844e95eu64, 0x20b620f3ab96d046u64, 0x2033e5261e40d866u64]),
-            f_from_words::<Bn254>([0x847b0c424bbd6905u64, 0x1b269fa807c510e5u64, 0x6c1664f76c6d9b66u64, 0x1c3ef30f50a591d5u64]),
-            f_from_words::<Bn254>([0x89504eedf46fe56eu64, 0x6d6508dfda53be36u64, 0x2e247f9fa10443f3u64, 0x201aeccf4cec50a9u64]),
-            f_from_words::<Bn254>([0x4647acd70085fe73u64, 0x9731c68d2a0a7775u64, 0x89ff1b77c4fb3c0eu64, 0x082919f5d60a78e4u64]),
-            f_from_words::<Bn254>([0xdf9cc39f2e92aabau64, 0xe27b7ff12bd800d8u64, 0xe74fbd4d33246448u64, 0x068b4c9c3b9b12cdu64]),
-            f_from_words::<Bn254>([0x4b034ede5f91bb2bu64, 0x8d16621be45350f9u64, 0xf538eb6c90830643u64, 0x299bdf962bf993ebu64]),
-            f_from_words::<Bn254>([0xf9c34434124dbefau64, 0x4c94801e51e0fea9u64, 0xe3501c0db82c9918u64, 0x293fd772d0e46182u64]),
-            f_from_words::<Bn254>([0x5592cd12d43d89d1u64, 0x76a0cd325ddb78ddu64, 0x0ebf02d871d5c3f9u64, 0x11975a16ba8ec7e6u64]),
-            f_from_words::<Bn254>([0xf9331fde4e605453u64, 0xc468da6cde4c8090u64, 0xee6cfb90d020a223u64, 0x2ca3274b3e247ec5u64]),
-            f_from_words::<Bn254>([0x66487b0c045480cdu64, 0xc09c15351184be3fu64, 0xc47fcbfa453c7523u64, 0x2b73394d8b8bc4ccu64]),
-            f_from_words::<Bn254>([0xb5860899a9e15bd1u64, 0xa6535b9a1532e854u64, 0xfed0e6cc0964aa23u64, 0x20f00f4a63f8b39fu64]),
-            f_from_words::<Bn254>([0x97aa39a9d4595e88u64, 0x8a45b244658f515bu64, 0x142e460599619b29u64, 0x2f625a4244431db3u64]),
-            f_from_words::<Bn254>([0x5e7fcb4ca7db7171u64, 0x3529edd02e1b205au64, 0x788097ac7307d4d7u64, 0x0b8623fd4361f1e4u64]),
-            f_from_words::<Bn254>([0xc3ac3c2ab72a01e4u64, 0x1d9dfce527139752u64, 0x523bfe24ec9e78fdu64, 0x23cdc785b97e0d0bu64]),
-            f_from_words::<Bn254>([0xb68c8600cbc658cbu64, 0xa6c80ec424cbf7e3u64, 0x5191125445c1386fu64, 0x02130a9f381a25dbu64]),
-            f_from_words::<Bn254>([0xe8fa6fc299908522u64, 0xa266eea3d4fb3e74u64, 0x020f66ebd6be47aeu64, 0x2cac11b93e0eeb4bu64]),
-            f_from_words::<Bn254>([0xeb2bfe6fa4a1cca4u64, 0xaa0b0a0c5ff5122eu64, 0x54deae7088c6bb06u64, 0x2e6bf029637d3228u64]),
-            f_from_words::<Bn254>([0xc9f546d08ae5b490u64, 0xddfff82705fb9e9bu64, 0xb62247ebc870658eu64, 0x11d702963f22aa45u64]),
-            f_from_words::<Bn254>([0x06d9e609c92b5a63u64, 0x3ba3df59f35de5c3u64, 0xdb94f798da45dc04u64, 0x25b0e6a53fa5c71du64]),
-            f_from_words::<Bn254>([0xaaf928fffc777d71u64, 0x1730c7bdcaa087c2u64, 0xfd3485f76c9922b3u64, 0x10dd253430d9b865u64]),
-            f_from_words::<Bn254>([0x33dacd0c9d28eb5fu64, 0x3971251e44bfc068u64, 0x6914a88ecac5fa83u64, 0x29556a110e984f1bu64]),
-            f_from_words::<Bn254>([0xe2f645d3335371a9u64, 0x4575edb92eb7364bu64, 0x0a600052f9edf132u64, 0x222059f9c0a91dd9u64]),
-            f_from_words::<Bn254>([0x9d7aef221b5d6581u64, 0xfd9d50675abc5f55u64, 0x620d2777440de3a2u64, 0x0d2865e0ca4ea101u64]),
-            f_from_words::<Bn254>([0x80e5408793710342u64, 0x880feab23944a802u64, 0xb28b9e2d3e1f8929u64, 0x00c6a5b635279e19u64]),
-            f_from_words::<Bn254>([0x433018a67badac57u64, 0x95a318439f36caacu64, 0xc1ce937c2b2babcdu64, 0x2fe777a494bd0fd9u64]),
-            f_from_words::<Bn254>([0x92715209f62ffdd5u64, 0xa4d2b28293186e76u64, 0xb48d34489bf2cc45u64, 0x28cfd425916617eau64]),
-            f_from_words::<Bn254>([0x4f262a12a4e095b2u64, 0x17b13be85b949681u64, 0x060cd6ea8dc2163eu64, 0x1137164a6ce51b65u64]),
-            f_from_words::<Bn254>([0x2857d8ad750ae412u64, 0xd88c5cfef4133f13u64, 0x0873822641aee439u64, 0x1357059d29f9348du64]),
-            f_from_words::<Bn254>([0xba2930ff3478bb90u64, 0x52047a3917909533u64, 0xa986a4ddb2c88be2u64, 0x08811088d38966a7u64]),
-            f_from_words::<Bn254>([0xe24c9e503840b534u64, 0x65bcba9ee0dc85c4u64, 0x99a3449a9b1b1597u64, 0x0a1e119a16c0d8dau64]),
-            f_from_words::<Bn254>([0x6357758409715048u64, 0x38300644db3b6037u64, 0x2ff707b4acc6a15du64, 0x2934056cf18e2396u64]),
-            f_from_words::<Bn254>([0x31db65f9855d9a36u64, 0xa2a5e144af860f3fu64, 0x58861efc3e73be11u64, 0x25cfc5112c822385u64]),
-            f_from_words::<Bn254>([0xfb04ca5b86b2b04du64, 0xd13b2564b8c07f8cu64, 0xb80dd276f208be13u64, 0x22ab271c20ad678cu64]),
-            f_from_words::<Bn254>([0xa11322263f96ecbbu64, 0x32281b4b68827d1cu64, 0x027b6a2cccef630du64, 0x1da7d50a24f096a7u64]),
-            f_from_words::<Bn254>([0xe554a37b154b8010u64, 0x70ec731326f7b6efu64, 0x7fdf01e73732cb8cu64, 0x07c143a04ba01397u64]),
-            f_from_words::<Bn254>([0x636a109bc643a3b0u64, 0x01079dca52016d4au64, 0xbe7282194f6d6a04u64, 0x0d1dff692d166e09u64]),
-            f_from_words::<Bn254>([0xc6595caf11a2ca07u64, 0xc404124d291a2caau64, 0xe467852991cce12bu64, 0x2976ed1f9efff7b3u64]),
-            f_from_words::<Bn254>([0xc9a3afb8416d4cccu64, 0x1163acbf09132011u64, 0xfc946cb7e0f46958u64, 0x0b187b1bbe8ea8d9u64]),
-            f_from_words::<Bn254>([0xb7f9558c685c70f1u64, 0xcce0d3c5823028e0u64, 0xef15c702703519dbu64, 0x147e559bf93d7234u64]),
-            f_from_words::<Bn254>([0x98d98cde74b86c96u64, 0x3c7328929ab25495u64, 0x43543fab976ec907u64, 0x21b90027e6f4869cu64]),
-            f_from_words::<Bn254>([0xe956298ffc9140a4u64, 0xeeaafa512597d6c6u64, 0x02ba330c93b52c4cu64, 0x1012d72fc0e103c4u64]),
-            f_from_words::<Bn254>([0x79137cbcd9cc3275u64, 0xc52c7ca9714783ceu64, 0x9315245d011d8638u64, 0x179bb21cbed1f6cdu64]),
-            f_from_words::<Bn254>([0xd9c133782201e94eu64, 0x3c13fd1b10f51a11u64, 0xfd30f09b2e8857e7u64, 0x2a6f250111b595f2u64]),
-            f_from_words::<Bn254>([0x82275db700672d27u64, 0xe2d5685474b21e99u64, 0xf0fcec5c34647b22u64, 0x1c4e944990942858u64]),
-            f_from_words::<Bn254>([0x160cadedcb07b988u64, 0xed638ed3d6e428d1u64, 0x347e4659e582406fu64, 0x030abaee1576781cu64]),
-            f_from_words::<Bn254>([0x2c079203dbe58b4cu64, 0xf460e6a19e1a1723u64, 0xf77dcc27c3b49d19u64, 0x0f6ff762ece9d3ecu64]),
-            f_from_words::<Bn254>([0x50f0cfc34c2cfb22u64, 0xf1f708e532b23856u64, 0x1f271f54cb44700au64, 0x21eae2b7885d149bu64]),
-            f_from_words::<Bn254>([0xd7c2a59ad753b880u64, 0x2c24e70711eae7aeu64, 0x27f562b8a1eda783u64, 0x01cdea5f5def51d6u64]),
-            f_from_words::<Bn254>([0x8ed688a5eb558572u64, 0x2c44205bbc82343cu64, 0x9246748ea1b56d23u64, 0x0265253e7c5901f8u64]),
-            f_from_words::<Bn254>([0x92b24ca5e5e556c1u64, 0xad579f3f8f29bd5du64, 0x98bd86592517bc25u64, 0x2114c9b1e42e6a00u64]),
-            f_from_words::<Bn254>([0xfc79f37f390569e8u64, 0xda17e5458022511eu64, 0xb65e95214a0978c4u64, 0x03650fd9bd87ff80u64]),
-            f_from_words::<Bn254>([0x2a5b35091433e487u64, 0xcaf827514805eba1u64, 0x0361a72bb0c4e036u64, 0x09f5b251e441f734u64]),
-            f_from_words::<Bn254>([0xbab7dd2abb8e8224u64, 0x0f460fa92aae15dau64, 0x1a1915d5dc071405u64, 0x28a713f5ed7311b3u64]),
-            f_from_words::<Bn254>([0x4a1b1d82fcdbe924u64, 0x552eb798b6d5d20cu64, 0x7f54ca4141056dabu64, 0x144a4a9445180701u64]),
-            f_from_words::<Bn254>([0x009ac825c16ed744u64, 0x0626a5beb68f1d87u64, 0xefcd5f641772e551u64, 0x0d842ccffdad6c33u64]),
-            f_from_words::<Bn254>([0xc54fb7fa56ef34d9u64, 0xd9cd487bf7b47fc9u64, 0xa6e4f5770b8e750au64, 0x1b02410e99f4fbd6u64]),
-            f_from_words::<Bn254>([0x3d85f828693fd099u64, 0x82922de8f3fa6b50u64, 0x8404bfc6a4852a32u64, 0x2db8e15ca85465aau64]),
-            f_from_words::<Bn254>([0x5bc9ee62c0ac52beu64, 0x2632d1c6ba18a507u64, 0xc3e72507c70dba78u64, 0x2c2fb72760375178u64]),
-            f_from_words::<Bn254>([0x269e38ef68d0db68u64, 0x823a4c1abd16eb08u64, 0xdf29cf8d849c4f97u64, 0x0ddf6c315c8af6b4u64]),
-            f_from_words::<Bn254>([0x00de068a033874d8u64, 0x2e22b6e74076e463u64, 0x5e10b52c3edc2393u64, 0x2c8b4d1f959e45f3u64]),
-            f_from_words::<Bn254>([0x979c6549e08b367du64, 0x11d45eab0966ea71u64, 0x9e7411ff0e38b6c4u64, 0x00cc03be1a5fb924u64]),
-            f_from_words::<Bn254>([0x3ee76a3455509914u64, 0x888219dd82aae937u64, 0xeee8f9db86ed8b45u64, 0x2c317490bf08656au64]),
-            f_from_words::<Bn254>([0x9043698798054491u64, 0xa6cba3064758bd0cu64, 0x799114a5deb2b2f3u64, 0x145679ad067a14d9u64]),
-            f_from_words::<Bn254>([0x28ec0c4ae5799581u64, 0xcf90542659940095u64, 0x337cdd501e873c2du64, 0x2f2d1bd3341e475au64]),
-            f_from_words::<Bn254>([0x8e097e46f822f278u64, 0xb3d89e3cdd31ad27u64, 0x37ddb8b97c539d86u64, 0x22472ec2cfe66a0au64]),
-            f_from_words::<Bn254>([0xa4901d62e945a9cdu64, 0x8302d85c4bb8c9d3u64, 0x9fbfc479267e5b8fu64, 0x1cd74704e31bafc0u64]),
-            f_from_words::<Bn254>([0xd9b60fe95357c963u64, 0x0f751fb385ffa72au64, 0x329bf192d36d2e71u64, 0x1d52e0e5274c3b40u64]),
-            f_from_words::<Bn254>([0xdb5a8309c0396c3cu64, 0x26c86098e0102271u64, 0xdc3f6ea8d338782au64, 0x120e6e5859d033c2u64]),
-            f_from_words::<Bn254>([0x3ea7a2ea6e04176bu64, 0x350e921572b64d9fu64, 0xda3183fbc0cb7ed0u64, 0x04e216aa76a980d8u64]),
-            f_from_words::<Bn254>([0x23355ef0c2a11fbdu64, 0xd65eaa66c57b128eu64, 0x456a9d95271cffacu64, 0x072b8f7d6c24c56du64]),
-            f_from_words::<Bn254>([0x91eb095ea96d4b35u64, 0xe2a00e0c6d2dd7e8u64, 0x3eae789ab2086803u64, 0x29bfa29e81351475u64]),
-            f_from_words::<Bn254>([0xcec4973f85ad4745u64, 0x907827a5ded9bbf1u64, 0x29c1faa3c95f6e63u64, 0x0fbe65cc11632913u64]),
-            f_from_words::<Bn254>([0xf00237aa45dc463du64, 0x8e29d4b4cffd3f96u64, 0x6bd9f30c3974e9b2u64, 0x2be8ebe8fafb60c5u64]),
-            f_from_words::<Bn254>([0x5e281c0c8ab89599u64, 0xf6b9c562f0363bd3u64, 0xa86152d9fb28a672u64, 0x0228281e5a40273du64]),
-            f_from_words::<Bn254>([0x4229f7b778baeb16u64, 0x06356c2ceceb1260u64, 0x006e6e994333c253u64, 0x138df3e49562ab15u64]),
-            f_from_words::<Bn254>([0x8237a24f4c28e4d8u64, 0xc8cbe18a6f1d15aau64, 0x225c9539b7aefe01u64, 0x17f3e54bf738df9bu64]),
-            f_from_words::<Bn254>([0x8ec4fa3a7399e0a8u64, 0x61ed18e94c465b84u64, 0xc147d5bb3324ab89u64, 0x2f711a31b2d5e60au64]),
-            f_from_words::<Bn254>([0xbf4bca27ab4a7ceeu64, 0x99bf75e9fbc48e43u64, 0xddf551e18f404984u64, 0x2256063334fdae4eu64]),
-            f_from_words::<Bn254>([0xf67b205467f86918u64, 0x9c9e52b71d05a103u64, 0xfbe7927d6357810au64, 0x03aa519d758902e9u64]),
-            f_from_words::<Bn254>([0xda08eed0e20db19bu64, 0x8fe2cfc6bc721397u64, 0x3867d8b11e3870feu64, 0x1dd47c805a3ed86bu64]),
-            f_from_words::<Bn254>([0x2dc9e1d6d9351de3u64, 0xb5f9c28290dc4c81u64, 0xc9fc134e95d29915u64, 0x22aecfc1e2c810a5u64]),
-            f_from_words::<Bn254>([0xde2b04bd6fb3e132u64, 0x1a7f380084647f60u64, 0xef04fa7546733b8eu64, 0x0c627130c42dc0feu64]),
-            f_from_words::<Bn254>([0x849a888a844b44f1u64, 0x34c25bf5e50268ceu64, 0x8afd8aee66b7cd99u64, 0x0cf2e61af545173au64]),
-            f_from_words::<Bn254>([0x0aff89c23c5890f4u64, 0xeedb947813d91020u64, 0xd0640b2c4b9cf7ddu64, 0x137d53060fa1d2eeu64]),
-            f_from_words::<Bn254>([0xfb9a084b3f104ad8u64, 0xabe4b3fdafd5aee2u64, 0xccbedb7f2471fcabu64, 0x284eb397dac576eau64]),
-            f_from_words::<Bn254>([0x84b555e5fe785fc5u64, 0x2564b310c846df38u64, 0x6614c79cfbc988d6u64, 0x01b2fea57bd69f38u64]),
-            f_from_words::<Bn254>([0x89f05c8c363d3830u64, 0x809def5378d45a88u64, 0xde57a40482696263u64, 0x1a7666188306859cu64]),
-            f_from_words::<Bn254>([0xbaabc7154e807101u64, 0xb01de985b763bc82u64, 0x24dbb3fa3d5d334eu64, 0x299a40dd1f3204b0u64]),
-            f_from_words::<Bn254>([0x4d77c0c3bb8dc8e3u64, 0x4b07f854c73d2c00u64, 0x3363933bd42d4458u64, 0x13eac33f21147128u64]),
-            f_from_words::<Bn254>([0x94b531f2751e7ba8u64, 0x7fe907925493f5ceu64, 0xd95695ec5cdf928fu64, 0x024f41eda777d318u64]),
-            f_from_words::<Bn254>([0x279909880c9d20afu64, 0x01ae16f6ea7c5724u64, 0x33098e59dea82b9fu64, 0x2909b6747edc6188u64]),
-            f_from_words::<Bn254>([0x40c970dbaecb0c8fu64, 0x91d75833abf3f927u64, 0x9485632901213a1au64, 0x050d2319806c57c1u64]),
-            f_from_words::<Bn254>([0x2d88dc46646e015eu64, 0xd6afb47461503f52u64, 0x6d778d2fdba7d41bu64, 0x099004d5fa5227feu64]),
-            f_from_words::<Bn254>([0x5b5714b6263fc109u64, 0x9ef8a5de33569654u64, 0xcfbe271abb70963bu64, 0x2ea8658a03a55575u64]),
-            f_from_words::<Bn254>([0xf2f8713d125a3de3u64, 0x50204ea9e3ba6e41u64, 0xf243c273d8392cecu64, 0x29c3cc0dc7a12164u64]),
-            f_from_words::<Bn254>([0x84608f6aa5633866u64, 0xbca4697b42a0e1d0u64, 0x8f249d5aec810437u64, 0x2ff4a169df289af9u64]),
-            f_from_words::<Bn254>([0x1589fbe139c7add0u64, 0xeeca08bcd96d915eu64, 0x0d49311da766b9bfu64, 0x19a4673bc3aed598u64]),
-            f_from_words::<Bn254>([0x43f1d6deb0244c8cu64, 0x52763b3d550a01bcu64, 0xede9cc489201c8c3u64, 0x1097a5446ba7c201u64]),
-            f_from_words::<Bn254>([0x735f3456fa2a69fbu64, 0x745d12d04535629bu64, 0x19cdb746949f4a49u64, 0x1ed782e05c50d927u64]),
-            f_from_words::<Bn254>([0x864caadc72008f82u64, 0xc51c34e3d1939b70u64, 0x1ca0ea9a7faed647u64, 0x1aa9be78f0cf45c5u64]),
-            f_from_words::<Bn254>([0x70226a716160542bu64, 0xde2658cb0e9424edu64, 0x8d80f78032d0e533u64, 0x2125b8189b5bdd04u64]),
-            f_from_words::<Bn254>([0x4af63108d5f05e4eu64, 0xf562f766495f3b93u64, 0xfebf1e0a6f2cfbf7u64, 0x20d9d9af7c384923u64]),
-            f_from_words::<Bn254>([0x65041b80bcde5148u64, 0x64236f95de790e16u64, 0xfb48560383aa900eu64, 0x1d434269451b8d4bu64]),
-            f_from_words::<Bn254>([0x2f791f023320af12u64, 0x6d1d83b371f0a003u64, 0x5b771ca073178938u64, 0x01c69ea2ad41bd26u64]),
-            f_from_words::<Bn254>([0xd44b58a2a43670acu64, 0xf80ba138bd90555bu64, 0x86a414a14417ec77u64, 0x2bec6587f8a28346u64]),
-            f_from_words::<Bn254>([0x6032c3de99ed0c84u64, 0x4077ef37fdcad2e2u64, 0xe02a8703092de1cdu64, 0x0844ea205d3daaf4u64]),
-            f_from_words::<Bn254>([0x085dc1a60afc57bfu64, 0x623e4dd3e5fbe96fu64, 0x42fb99a1a08c786du64, 0x16fce5a4ddfc6cc4u64]),
-            f_from_words::<Bn254>([0x8c6bd3181860eae8u64, 0xeb8fa250e1424ff4u64, 0xd31fe38369e66d3au64, 0x2013651eb587bc1cu64]),
-            f_from_words::<Bn254>([0x81fa9fa6d30bd691u64, 0x4b2aa4e5c516134fu64, 0x3da22a12548e6d85u64, 0x269d05acf439eabdu64]),
-            f_from_words::<Bn254>([0xc89cc53d1095823au64, 0xdc4966bb78e61749u64, 0x47b6cf623fb8145fu64, 0x0554cfc99b30b437u64]),
-            f_from_words::<Bn254>([0x448ac0c36ff7450du64, 0x8761a2fc8ed323a1u64, 0x72a1b8d54716c4dau64, 0x0fe81f5cf0deacc1u64]),
-            f_from_words::<Bn254>([0x8e47c8703ac4ab3cu64, 0xbebac454933659d2u64, 0xd3ad4fc381066825u64, 0x127c9506d6c31573u64]),
-            f_from_words::<Bn254>([0xd5e30d72bd75cfc1u64, 0x983b6afdb45181f8u64, 0xd9f3aa4457875c73u64, 0x2d33ce812bda49b0u64]),
-            f_from_words::<Bn254>([0xa6bfd906e1bd6690u64, 0x5a6d7f9be4299e11u64, 0xa7b5c9c5f29f1d70u64, 0x1df8982131062269u64]),
-            f_from_words::<Bn254>([0x5c515b9dcadc19e8u64, 0x5bd0134e9fd55f9bu64, 0xe9e9c5ce9744812cu64, 0x2a02b425e47c4654u64]),
-            f_from_words::<Bn254>([0xde0a6aabb2994398u64, 0x060e57eeda744f7bu64, 0xc10156645a13ff8fu64, 0x1af9eb7ff628b1c5u64]),
-            f_from_words::<Bn254>([0x4619687dac34c42cu64, 0x7e0dae1d7b11b09fu64, 0x679949af2cc13632u64, 0x27c4865eda8a0848u64]),
-            f_from_words::<Bn254>([0x3abf8372ebb371c2u64, 0x55bf0006db5d7e3eu64, 0xaf9625a87fbc504fu64, 0x2697cd201c257002u64]),
-            f_from_words::<Bn254>([0x1aa57a0de2435733u64, 0x197a930913d1b073u64, 0x43bd50b17d0f04aeu64, 0x19cf931e43524590u64]),
-            f_from_words::<Bn254>([0xa875fc5a30fef560u64, 0xb7ae35c10b6e2589u64, 0xd0d1eddbdbec0de1u64, 0x2ca6f9fee83416d7u64]),
-            f_from_words::<Bn254>([0xbfa97dceba730642u64, 0xaa88f64a997b7a59u64, 0x47ff5c7fafb20c9cu64, 0x08339f8e9120bb72u64]),
-            f_from_words::<Bn254>([0xf43d18360d695fefu64, 0x27a571fa548980f0u64, 0x156d7c419de72b04u64, 0x06494c603e6bf0f8u64]),
-            f_from_words::<Bn254>([0x2c1a814d337cbb84u64, 0xbc9d4054720fa055u64, 0xe9dd7d3d360e01d7u64, 0x2e628d12daed3b24u64]),
-            f_from_words::<Bn254>([0x731b38e127af2c3eu64, 0x9abc18612dbae082u64, 0x010b4ffeec521f48u64, 0x12af897a7bec1f9fu64]),
-            f_from_words::<Bn254>([0x95065efa79353a4cu64, 0x4dfbd3337edd89a4u64, 0x9166fc0cf3bd6082u64, 0x2007ca7bd8ded0a8u64]),
-            f_from_words::<Bn254>([0x8050f33e2e7184d5u64, 0x10311c942e96352du64, 0x3eea0ea9dfd45b7au64, 0x2bd9a53e3dff43cfu64]),
-            f_from_words::<Bn254>([0x1171eacdd5b73090u64, 0x7a7d6d1f30f9b71du64, 0x29a0e65b1c5b7159u64, 0x0ae1f1d5470e990fu64]),
-            f_from_words::<Bn254>([0x088a27021279d869u64, 0x170069fddcfbb9e7u64, 0x724a45fe6529e42cu64, 0x0e24608756bfa6cdu64]),
-            f_from_words::<Bn254>([0x84aa5fbe55c08eefu64, 0x977403d6853177f8u64, 0x8e6014a99269a8cdu64, 0x2db7ffa36c7c6d84u64]),
-            f_from_words::<Bn254>([0x1a583e4ccb8951e2u64, 0xf6c5f8bb7dd073b0u64, 0xc02ab083c34d53dfu64, 0x19fbe04408ec0260u64]),
-            f_from_words::<Bn254>([0x3677f7e5002cf776u64, 0xc22893166e70d80bu64, 0xd333d3ed4eed0b7au64, 0x1e20721873092d4cu64]),
-            f_from_words::<Bn254>([0x7667fac512bbe10au64, 0xe6f9d13890935049u64, 0x8c29fe85569c73e7u64, 0x0ed987aca646637du64]),
-            f_from_words::<Bn254>([0x8ee768f4c65a8cb6u64, 0xb08a6225094b20d5u64, 0xae532a8b02c0ee7fu64, 0x2993176bcb455510u64]),
-            f_from_words::<Bn254>([0xeb889f5e18f002edu64, 0xb8cc540a049d76bau64, 0xa061fa88b863f3c6u64, 0x01ac85bc22a3036au64]),
-            f_from_words::<Bn254>([0x87e75086172e565eu64, 0x5129dd2bf2c01f93u64, 0x6b3c9a2e6647fab3u64, 0x1065bd329de71473u64]),
-            f_from_words::<Bn254>([0x6987515b6d7254b8u64, 0xcc390fc1641ea913u64, 0xf045dcd1084d3c96u64, 0x1ba171c3570bc91bu64]),
-            f_from_words::<Bn254>([0xb8b10a93a67d0d3fu64, 0x68902bcbf6eb3196u64, 0xb829ed80ef3d4cbeu64, 0x040f1fb56c0e60afu64]),
-            f_from_words::<Bn254>([0xdd793c438186cd2eu64, 0xb951848981158995u64, 0x9c8d9476fa2dec2bu64, 0x0ea851f8955255efu64]),
-            f_from_words::<Bn254>([0xfc2d6ce2468c3417u64, 0xceadca9de017b335u64, 0x5703fbfa4d616bcfu64, 0x018ae070d97192f3u64]),
-            f_from_words::<Bn254>([0x8ee976b98140eec2u64, 0x2ded74faca03a416u64, 0x00b92f90f6dd0cf8u64, 0x06216b4f3009e9e3u64]),
-            f_from_words::<Bn254>([0xc987deb6a3241987u64, 0x181d7dfed6a3b616u64, 0x781709ae72e33540u64, 0x12ae0d97427134aeu64]),
-            f_from_words::<Bn254>([0x08787135495a59d5u64, 0x9121d53246cae792u64, 0xfa204a2b75c86ea3u64, 0x2f8e85ddb641bffdu64]),
-            f_from_words::<Bn254>([0xad6a2645645796d3u64, 0x4652b71a9ec9657du64, 0x53363eb6d6670dceu64, 0x18bef87c66592779u64]),
-            f_from_words::<Bn254>([0x13045fb830130f4cu64, 0x518c7b9afcefe37eu64, 0x99804dae2ebd8c5au64, 0x009ae2db895aca62u64]),
-            f_from_words::<Bn254>([0x3468ff964f08cf8au64, 0x0fb010e2a7849f9bu64, 0xc124728a37bcf1e9u64, 0x0cf6ba44d2cea28au64]),
-            f_from_words::<Bn254>([0x3844faea40893980u64, 0x911339aa4265b88du64, 0xa998de5c133f6298u64, 0x02384f207859df0fu64]),
-            f_from_words::<Bn254>([0xdbfe25fb7dd3462au64, 0x5b6f38f91f45115cu64, 0x03b31c6fa08f8e09u64, 0x267b12341105c186u64]),
-            f_from_words::<Bn254>([0x29f21e34c539c79cu64, 0x00fa253f5ea72c33u64, 0xb61e05d2cff53508u64, 0x1fa32bdcc23e42a0u64]),
-            f_from_words::<Bn254>([0x8d5c81ce54cc6c89u64, 0xa7dfd6280bf23b93u64, 0xa3d82f9266e94b57u64, 0x20d6f80a566261c4u64]),
-            f_from_words::<Bn254>([0x241135c00c578683u64, 0x895e4a6c3c39a95du64, 0x67d3ad32dfee7cebu64, 0x2288f7f88c45f4c0u64]),
-            f_from_words::<Bn254>([0x1447b8774582f454u64, 0xd0c96ad89e4ec937u64, 0x474c640a3a058064u64, 0x21da95a63d8ac826u64]),
-            f_from_words::<Bn254>([0x9094f150573ae9d2u64, 0x060d3347e4f303d7u64, 0x0459213aa4e76117u64, 0x12633c9c84b0b78bu64]),
-            f_from_words::<Bn254>([0x233bd55921649858u64, 0xb4ca6933d6084aadu64, 0x72030078fa354034u64, 0x03e60fbe188b71b5u64]),
-            f_from_words::<Bn254>([0xc3f224e2b467a84du64, 0xf87c2f5bb2a4a06au64, 0xba49df50cf33dff7u64, 0x1cb429ab101ab1deu64]),
-            f_from_words::<Bn254>([0x7abbb7e195eec4b3u64, 0x716f95d0fe753650u64, 0xbf888b7e3deaf5adu64, 0x083a5b4f158210fbu64]),
-            f_from_words::<Bn254>([0xa687e37ea32a0151u64, 0x646732489cb8dfdau64, 0xc3e0c161232b9c2cu64, 0x0c1fbcc0afef0a9bu64]),
-            f_from_words::<Bn254>([0x4d32f5cba61b417eu64, 0xffe065f470ca39f2u64, 0x662c241712e96934u64, 0x0d130d19a98772c6u64]),
-            f_from_words::<Bn254>([0x364f56351c544b65u64, 0xa939c462a7873924u64, 0x3595eb100e7d94f8u64, 0x1193818ace214963u64]),
-            f_from_words::<Bn254>([0x0221a6de23d9bbf1u64, 0x1dcac52d7d12b722u64, 0x976eee963e05ef8cu64, 0x14ca9340c438c67cu64]),
-            f_from_words::<Bn254>([0x0ee2d9a52f3026c7u64, 0x61f7f044b75e7e70u64, 0xc594b5c624b9e74au64, 0x1ad48553d58c8236u64]),
-            f_from_words::<Bn254>([0xd0ddd981f074e5deu64, 0xe774a1c2e4aa7d72u64, 0x34d97ebda18bb5bau64, 0x21148bcda2ed76a9u64]),
-            f_from_words::<Bn254>([0x979502bf86ec993au64, 0xd5f30a7dc9cfb824u64, 0x479e40d33d0e7d80u64, 0x1eb145d9620fd782u64]),
-            f_from_words::<Bn254>([0x9a5871ef6cecb929u64, 0xe62c1bac9e9961cau64, 0x5830f4ef7587cb42u64, 0x23104637231d3c99u64]),
-            f_from_words::<Bn254>([0x8ca5d655aeb38abbu64, 0x6a86a7927f56a558u64, 0xb521efcb08177475u64, 0x2b9e85049b287ffdu64]),
-            f_from_words::<Bn254>([0xdca90c2b0e45f552u64, 0xa778aef86a520000u64, 0x90037e33d4997cc8u64, 0x2ae9c47db149dbcbu64]),
-            f_from_words::<Bn254>([0xe22c8aec2ff7c3dfu64, 0x5d3e62885fccb3c6u64, 0x795738bc38ad6394u64, 0x201774c1a32c9e79u64]),
-            f_from_words::<Bn254>([0x0492df94137d3464u64, 0x86cf45e32ca854a1u64, 0xd4e6f51a0881879du64, 0x172f521cf7377df5u64]),
-            f_from_words::<Bn254>([0x00322747a1e3a5eau64, 0xbfa6eb716196f479u64, 0x2ac0deeed561d618u64, 0x02e203ff89cf8779u64]),
-            f_from_words::<Bn254>([0xad140ee11cf5de91u64, 0x41bf99cbc56d68d1u64, 0xa43fc1e4297050bfu64, 0x040efdac30569bb2u64]),
-            f_from_words::<Bn254>([0x5560fbdacc38d81bu64, 0x75b6118a8df69009u64, 0x8f4e69779322ac3au64, 0x2296169cb9070d7du64]),
-            f_from_words::<Bn254>([0xbc1c9e51b576ce73u64, 0x76adc48ef8b93be2u64, 0x48aea5ab76efeb33u64, 0x19c2a6922e03066eu64]),
-            f_from_words::<Bn254>([0x4c6e99b44c252446u64, 0x64e3b106ac439d80u64, 0xe7dbfb29b8de4c18u64, 0x29da7ec96321c783u64]),
-            f_from_words::<Bn254>([0xfd221af1faac3143u64, 0x8629909b7ee83bd6u64, 0x40580357a432c0f8u64, 0x2597bb68494e6f93u64]),
-            f_from_words::<Bn254>([0xcb2b636e74e073c1u64, 0x147f7da4fb45ac9cu64, 0x4881574e2987c403u64, 0x2f6cbe3a6a3889e6u64]),
-            f_from_words::<Bn254>([0x2a16027a30d09c7cu64, 0xf31fc8a5cf6789cdu64, 0x210964196ce7c639u64, 0x10b2386c33643a77u64]),
-            f_from_words::<Bn254>([0x9f75432c8efa994du64, 0xf9867a21c27f7a94u64, 0x4b5d46d442180ce1u64, 0x1426ebfb7e107ec0u64]),
-        ];
-        Arc::new(GmimcErfParams::new(2, 2, 325, &round_constants))
-    };
-    pub static ref GMIMC_ERF_BN254_3_PARAMS: Arc<GmimcErfParams<Bn254>> = {
-        let round_constants: Vec<Bn254> = vec![
-            f_from_words::<Bn254>([0x561b957379a02ddeu64, 0x1ac5c26643c2b85du64, 0xf452658b0bd96728u64, 0x2b70a65cc52d0aa1u64]),
-            f_from_words::<Bn254>([0x7b502dd962d53060u64, 0xfcc6201bfde3b640u64, 0xa50e7c3b8d5b4a6eu64, 0x1a89194d2a6c8902u64]),
-            f_from_words::<Bn254>([0x434cdc09b32d14dcu64, 0xcb0d152f285257e4u64, 0xb71bbdb0a8c97022u64, 0x1966fd0c97b5b8f9u64]),
-            f_from_words::<Bn254>([0x1924eb3b6181bba1u64, 0x9cddf296ad53c7e4u64, 0xfb75ad7f87a46de4u64, 0x11170c049bb5a9d2u64]),
-            f_from_words::<Bn254>([0x446c6491476279a6u64, 0xbf136babd446f21du64, 0x4c280b589fc91ac9u64, 0x175b7991f49de9feu64]),
-            f_from_words::<Bn254>([0x11eae07213ac0091u64, 0xffa933677615ff76u64, 0x0e1bb30e465bc203u64, 0x2eb0668c8f5d3721u64]),
-            f_from_words::<Bn254>([0x40b8b30220ce1b19u64, 0xed461fafb72d9b7eu64, 0x5861da26770f6e9du64, 0x2f5f3dc3e9189a37u64]),
-            f_from_words::<Bn254>([0x9914c4be224ede68u64, 0x35132895a2798f0cu64, 0x1e43730d2f5f550au64, 0x1001cd4af49906a6u64]),
-            f_from_words::<Bn254>([0xd71d0d1489795e04u64, 0x13e1556056e84d2bu64, 0xea1816d504232632u64, 0x28fd9f8212ed540cu64]),
-            f_from_words::<Bn254>([0x9fbae5553a592e65u64, 0x23e43f2ae5e4198eu64, 0xd987d41847dbd2b1u64, 0x2cff365d949da623u64]),
-            f_from_words::<Bn254>([0x861fec8ba3529b46u64, 0xa79485b74b72ca85u64, 0xa109a6cc24f0da7eu64, 0x1e9bec2b3c3e4086u64]),
-            f_from_words::<Bn254>([0xed54a3b76b6ab469u64, 0x1bec4f60caba2024u64, 0xc365a5bd07242b3cu64, 0x1a313f399c552664u64]),
-            f_from_words::<Bn254>([0x90762469397899aau64, 0x4c6d9e42078b149eu64, 0x568737560439f107u64, 0x0cbd036f92233c1au64]),
-            f_from_words::<Bn254>([0x143fd0dae37a6dfbu64, 0x2f2cc5a8405a59d4u64, 0x2acdccc2ed5927adu64, 0x0466119d32a30458u64]),
-            f_from_words::<Bn254>([0x1761bd6d238129e2u64, 0x89b2fd2baaf0103au64, 0x10cfb46d4c998a4bu64, 0x0a1d88f8423fc5d5u64]),
-            f_from_words::<Bn254>([0xd9c6ac77c01ddd2eu64, 0x8cb68e1ed89f2deeu64, 0x385b871152cd3d01u64, 0x00376891e9583090u64]),
-            f_from_words::<Bn254>([0xcf8f03c8aab7b9aau64, 0x18e6536ccd814327u64, 0x04f82ba9b47df509u64, 0x2c8b545daaaa7f56u64]),
-            f_from_words::<Bn254>([0x0b5d4be1217e7177u64, 0x38ed6f70119ec798u64, 0x5b212ccc38b81d97u64, 0x2111e2bca559e705u64]),
-            f_from_words::<Bn254>([0x43d16eafdbb390efu64, 0x816b7254a39cf3e6u64, 0xda56f01d2976de6au64, 0x12e11192532c6badu64]),
-            f_from_words::<Bn254>([0xdb5e20f0e6560460u64, 0x3210444ec417a36du64, 0xf091f74942f4f409u64, 0x0540d5b8c58792d4u64]),
-            f_from_words::<Bn254>([0xc6a2ddf5af829dffu64, 0xb505eadfc8dc509cu64, 0x92fdb4c09f9926afu64, 0x295140f5b1894011u64]),
-            f_from_words::<Bn254>([0x98efef9b136c407du64, 0xbb8127cdb6c56736u64, 0xf8505ca9ce03e549u64, 0x1fd690c7455cdc2cu64]),
-            f_from_words::<Bn254>([0x402f7afe505a684au64, 0xae83d6258b9e20afu64, 0x66617c8e5c4d83beu64, 0x039fc63237b64520u64]),
-            f_from_words::<Bn254>([0x9f1628a5d1fe27bau64, 0x7d62eb16ce2d21c0u64, 0xed6867d3f0e3c816u64, 0x2105b6f10af5b53eu64]),
-            f_from_words::<Bn254>([0x888476c039b17e30u64, 0xdd6ae22821b195fdu64, 0x7757d7cdbfa2f14bu64, 0x0710a056422240c1u64]),
-            f_from_words::<Bn254>([0x9982b650280811b6u64, 0x18a35099f0af36cfu64, 0xd1053ad15ab471eau64, 0x0d321c091184dd04u64]),
-            f_from_words::<Bn254>([0x353381c4b9a285d1u64, 0xbbf2860fa0f3d1c2u64, 0x92660a214f8ecb4du64, 0x228a83d566fe834du64]),
-            f_from_words::<Bn254>([0x818c6ce8a80ec86fu64, 0xd156c01a1ae6b1c6u64, 0x6a6c2804b2abadd4u64, 0x16e507d25430e8eau64]),
-            f_from_words::<Bn254>([0x75e1adc292aad0dfu64, 0xa290ce95c8ee07fbu64, 0x935e0eb32e6b2723u64, 0x13c97c5188ca8309u64]),
-            f_from_words::<Bn254>([0xb8741592b2b6e26du64, 0x6cb5afa356df9ca1u64, 0x7439960865d5f881u64, 0x1dd50c6553813047u64]),
-            f_from_words::<Bn254>([0xe9fff81dfea990bau64, 0xb75d8b743f2f236eu64, 0xcc5ae836b6e1c3a3u64, 0x1a822ba0068a645fu64]),
-            f_from_words::<Bn254>([0xce1415b78568852fu64, 0x8af8ca78886e425fu64, 0x2e078b86ff769a84u64, 0x0c5dc7b241dee2f6u64]),
-            f_from_words::<Bn254>([0xd5717db69eeee892u64, 0xd909b0f4191e83a9u64, 0x457773298da6af1fu64, 0x008532b051b17758u64]),
-            f_from_words::<Bn254>([0xdbad2964c39bb387u64, 0x21337682cd1cc1c6u64, 0x70ccefecf9be52c5u64, 0x09e10527a95fd1aau64]),
-            f_from_words::<Bn254>([0xd4240fbf56af861au64, 0xc9c6a7079711728bu64, 0x5948429f4742dd7cu64, 0x0b7a290c902ebb97u64]),
-            f_from_words::<Bn254>([0xef0082ffc699b16cu64, 0xa7f07ac97f7db62fu64, 0x16c837535fc8c030u64, 0x00fbfaf2eb8e3a0du64]),
-            f_from_words::<Bn254>([0xcff99d0cbc71b39du64, 0xe0b5d5b0942d4334u64, 0x86bb6fdf494eb13au64, 0x02629ab8c8e34225u64]),
-            f_from_words::<Bn254>([0x7e71ba5c08614f7au64, 0x07838ec4b6ced36fu64, 0xafdac8de11b6cb08u64, 0x1f6c81c435f912beu64]),
-            f_from_words::<Bn254>([0x3ef63a0ae775ef63u64, 0x834240f948ee0c61u64, 0x511125285a548f1cu64, 0x10d9998de8c4dd08u64]),
-            f_from_words::<Bn254>([0xd51ff6c92cffffa0u64, 0x507b8a267860816du64, 0x181e330737f633dbu64, 0x0703c5695204ee9bu64]),
-            f_from_words::<Bn254>([0x65dcddaa4380c859u64, 0x80af7ef7ef799924u64, 0x7aad9f63d97a21d3u64, 0x190818fb763627e7u64]),
-            f_from_words::<Bn254>([0x5a467b0e941312fbu64, 0x6680c88fe16d10d4u64, 0xf8d42d54b52b285cu64, 0x244e619a82990337u64]),
-            f_from_words::<Bn254>([0x3f148acd8a6895fau64, 0x04ac0ab3b19aafe4u64, 0xeb9fbdc9491374d5u64, 0x22c686b6041cc436u64]),
-            f_from_words::<Bn254>([0xc877ebf384c0d910u64, 0xff28908f6f52b504u64, 0xea7f34ed93a6828eu64, 0x1ca5fedda2d23d72u64]),
-            f_from_words::<Bn254>([0x9a8c2a1a23ab232au64, 0xe5552f928a482447u64, 0x3238b87b0d63a6beu64, 0x04e2d1ae7019b330u64]),
-            f_from_words::<Bn254>([0x62f1e936da3749d2u64, 0x3ec84f6c2b2c860du64, 0xd504b51c3827c45fu64, 0x1a10dbd9cb97b049u64]),
-            f_from_words::<Bn254>([0x4cf1e4ae92ce884du64, 0x072525de734ac426u64, 0x28f7552c75a985a3u64, 0x163401e442e7e15bu64]),
-            f_from_words::<Bn254>([0x2238fd1c5d135980u64, 0x784d8c24d6242c9fu64, 0x1b973ec6b7622bb7u64, 0x1421d84fb1cfb28fu64]),
-            f_from_words::<Bn254>([0xaf5252c4b7a56a87u64, 0xbe79f3785725f578u64, 0xc9cbe2a6d7ccd8f4u64, 0x1638ec0f3a04b383u64]),
-            f_from_words::<Bn254>([0x25f1a9436060d438u64, 0x39841d5b7e203c2eu64, 0xbe2f5752366ef61au64, 0x1c66d11ba508c7dcu64]),
-            f_from_words::<Bn254>([0x2c706d9b3db3ba96u64, 0x338b1e853ad4d80eu64, 0x309fde70a4ed7cc7u64, 0x1e42544eec7c32a2u64]),
-            f_from_words::<Bn254>([0x2cc5995d88b0738bu64, 0x9d8592c331150fc2u64, 0x81578c516b599caeu64, 0x05c08bf8b830529fu64]),
-            f_from_words::<Bn254>([0x37c6fb71da463201u64, 0x865c2ed53d65e68eu64, 0x9e477d7d9dffa916u64, 0x2be4f3ba549107adu64]),
-            f_from_words::<Bn254>([0x610cf3d43aacd2e5u64, 0x3285e1441eb9654au64, 0x408fcfd521d74524u64, 0x02ba06cb3885b605u64]),
-            f_from_words::<Bn254>([0x62bff8287b2dc29au64, 0xe593626031ee6787u64, 0x0e2f317609f17a22u64, 0x0fd162e681e28bbfu64]),
-            f_from_words::<Bn254>([0x844b286efa9e0c68u64, 0xb6513388f748b7deu64, 0x3218a77fa119e2eeu64, 0x16c0f6a23c745343u64]),
-            f_from_words::<Bn254>([0x4090ccaa097464cbu64, 0x2c86027aa83d6afeu64, 0x4d8baa3b4cd2834du64, 0x15e54f4de48512f1u64]),
-            f_from_words::<Bn254>([0x801744f46c06f1b7u64, 0xb5a7da8ed9e3ed0eu64, 0x7900514189cf1613u64, 0x06204a6cbc79ca57u64]),
-            f_from_words::<Bn254>([0x831e8cf228fb3048u64, 0xc85da64387dc13f4u64, 0x295fd5f09ca3e571u64, 0x20e0e282b0f67a76u64]),
-            f_from_words::<Bn254>([0x3a47928bc7eb7702u64, 0xa0b2817b07086c91u64, 0x1357dc6f2da59276u64, 0x2a1de9a677f72d1au64]),
-            f_from_words::<Bn254>([0x09b8650c6b038291u64, 0x826ad66d472a51beu64, 0x45f28707d9222f19u64, 0x2dbdd50a4cd90d79u64]),
-            f_from_words::<Bn254>([0x902f9f6848e839d3u64, 0x622a9ba7ecbbdf78u64, 0x54beecf8e62c8c01u64, 0x2118af70fa3bfc72u64]),
-            f_from_words::<Bn254>([0x4b4a2e5fc324222eu64, 0x53a5b058b18fc75bu64, 0x1d8046fda13698d3u64, 0x26b84665d95ed8b9u64]),
-            f_from_words::<Bn254>([0xc46c3644d9553d6cu64, 0xa9ceeb1c125de77du64, 0x7f60225f2919f091u64, 0x072cb7dcbb57366cu64]),
-            f_from_words::<Bn254>([0x533c39d9de57aa94u64, 0xc6ea4153f98fbfe0u64, 0x4b51e92d87c26ea2u64, 0x00d8cc510b5d550eu64]),
-            f_from_words::<Bn254>([0x79892be0559302e9u64, 0xf9220eae9174eebau64, 0x8e80b340cc4d3136u64, 0x20362c6e1e4b4865u64]),
-            f_from_words::<Bn254>([0x003a949f617f8ca6u64, 0x68a27e1ede536540u64, 0xbeb8521d4e314545u64, 0x104af66df0ccd34cu64]),
-            f_from_words::<Bn254>([0x6071bee4bc78dcd8u64, 0x08ac2c226b0027a3u64, 0x30c17dfbc4b95560u64, 0x108a205a87141687u64]),
-            f_from_words::<Bn254>([0xeab6e1095ccb78dfu64, 0x94a584d884248f52u64, 0xcc0babce9b4d3b94u64, 0x00fea3c7d66c0f3bu64]),
-            f_from_words::<Bn254>([0x4da4a93aa24badb0u64, 0x5043864ab01e0cb4u64, 0x24f87c6c13e0e302u64, 0x0adbc427424a7a77u64]),
-            f_from_words::<Bn254>([0xda239cdcba8d3ca2u64, 0x5d0ebed537e2dc3au64, 0x31cd3eb6231cb1c0u64, 0x272bf09d7593e166u64]),
-            f_from_words::<Bn254>([0x3cbcefeee486ac51u64, 0x81e3eb3511c22417u64, 0xe64f852f40b211ffu64, 0x22cbbfbf59b6e172u64]),
-            f_from_words::<Bn254>([0xdea7bb7da523cbf1u64, 0x99583ac2a4271f02u64, 0xcb02e2d8299e8451u64, 0x091f5321238989cfu64]),
-            f_from_words::<Bn254>([0xc465cf994749af59u64, 0xd411cd01b7a0df35u64, 0x1e7b23a8e81a7865u64, 0x03e555ca3da5d82du64]),
-            f_from_words::<Bn254>([0xec9e4b95d6ef89e9u64, 0x81cc74f3f6a94b82u64, 0xdb8dad282e7bb3a1u64, 0x1ce7762cf8fb2284u64]),
-            f_from_words::<Bn254>([0x14b0d59cea031ff7u64, 0x71e559aac8f3b872u64, 0xaa5c31b7995f950fu64, 0x1977b45a90c2a1b4u64]),
-            f_from_words::<Bn254>([0xd078878a56d51753u64, 0xd88bd3b3f91a7849u64, 0x045fe8a723421f5au64, 0x1fc5188f75e96c8au64]),
-            f_from_words::<Bn254>([0x58cb49a652402596u64, 0x27418f3d5773967bu64, 0x93d9e0f10d8a93e3u64, 0x2a39ccdde6b2c1ddu64]),
-            f_from_words::<Bn254>([0xad71c7a9708c01acu64, 0x4c7b8ef592be76dcu64, 0x3a5f3281ce1d520du64, 0x207378b95daf98f0u64]),
-            f_from_words::<Bn254>([0xbe7f8c6b8be32539u64, 0xccb9f483db1f39b2u64, 0x0c3db204e6fce1fdu64, 0x3024a178b1d9dba1u64]),
-            f_from_words::<Bn254>([0x28fee757edd3b71fu64, 0x4dfbe3c679d0a648u64, 0xc33b932486d7e2a0u64, 0x2c901ab84a7aca5cu64]),
-            f_from_words::<Bn254>([0x567fa5fde528d54cu64, 0x6cd308bd2ba5a47cu64, 0xd46f4630ce1457feu64, 0x177cc1a51ccd357eu64]),
-            f_from_words::<Bn254>([0x4cba519396f5f4b0u64, 0xb5f47c3a4ea86521u64, 0x8eca3d4c7a45b5d0u64, 0x221fafa02eef11c4u64]),
-            f_from_words::<Bn254>([0x4c64244556729212u64, 0xe2d0a89cebf646ccu64, 0xd1cf53886217356bu64, 0x10d0bd30cae47608u64]),
-            f_from_words::<Bn254>([0xabc0be69c49ef53fu64, 0x00d1128164614f15u64, 0x3438089a1eaa493eu64, 0x11edfe8eadc0f7c0u64]),
-            f_from_words::<Bn254>([0x9576a7bb865312feu64, 0x0aa482f9f530b928u64, 0xb392a993f4da9d1bu64, 0x1b0ba2a290f27ccdu64]),
-            f_from_words::<Bn254>([0x9cc85eb8f280ce02u64, 0xac33e643526ea1a3u64, 0x537935db8876dbefu64, 0x1c10e768095c6987u64]),
-            f_from_words::<Bn254>([0x15106b782033587cu64, 0x72f576ec6899a065u64, 0x90160787847689b6u64, 0x02ed395e40794d85u64]),
-            f_from_words::<Bn254>([0x1770ef96a87deb1cu64, 0x3f5360e6ef46a43bu64, 0x2a2992fb9328f24cu64, 0x09539e1dbb141d4cu64]),
-            f_from_words::<Bn254>([0xbea63afee0f3ccd6u64, 0x7837aec437c62676u64, 0xb870f276b4e8acd6u64, 0x26d3a59b02f431d8u64]),
-            f_from_words::<Bn254>([0xd972e0905f463bfdu64, 0x7bdfd1eac4b15670u64, 0x9a166e155b0894d1u64, 0x180e6b3a12388475u64]),
-            f_from_words::<Bn254>([0x7f28ce17f5d5d981u64, 0x56150f9552a93a7fu64, 0xee6b4d60cdcf62d3u64, 0x2c0727514b693590u64]),
-            f_from_words::<Bn254>([0xc36f4ede65d5cf7bu64, 0x3d5d3bf31c7c9aa7u64, 0xf2d6d8bb392203c6u64, 0x2c677306c76e0300u64]),
-            f_from_words::<Bn254>([0x9c9a7c5ca68766efu64, 0x7c900f7f28708d2cu64, 0xb61d08be51afcdd0u64, 0x14c4dcf6a0fb4057u64]),
-            f_from_words::<Bn254>([0x8039f8942c9a02d7u64, 0x75b96a8b591cf1f9u64, 0xefa3fc13561636b7u64, 0x0e0e0f37791daf09u64]),
-            f_from_words::<Bn254>([0x57713ac7e6875c51u64, 0x52df7a55f5e34229u64, 0xb823cee8b708bdbau64, 0x17b65ce2f41167d5u64]),
-            f_from_words::<Bn254>([0x2d3311020cfff951u64, 0x1aafbcb1dca07b53u64, 0xa9e1a374152ff609u64, 0x025bf25fe20e477fu64]),
-            f_from_words::<Bn254>([0xd7e022f03168aed8u64, 0xd6a076a614f4f15bu64, 0xbc1130a1d63073a1u64, 0x0cea59efced69cb9u64]),
-            f_from_words::<Bn254>([0xbaad2493d68a1c4cu64, 0xfd46771897077c46u64, 0x949fc254524e149au64, 0x2a03594e714a8a11u64]),
-            f_from_words::<Bn254>([0x2aa94cb6f164902eu64, 0x2d13fc09a86a6af6u64, 0xa205cf2c345397f5u64, 0x2e64fb91020076b3u64]),
-            f_from_words::<Bn254>([0xbd78c13161633d77u64, 0x80ff491bcb11f656u64, 0x4a7a1cb87098a053u64, 0x178472f60be9120au64]),
-            f_from_words::<Bn254>([0x9d11988bb8e2c764u64, 0x02e23693065aa6a3u64, 0xbb33f9b5e49771ddu64, 0x12c95a833ab499c6u64]),
-            f_from_words::<Bn254>([0x5744f60dbbbeb789u64, 0x3f42f1901187be26u64, 0xbdb7470a9a8de643u64, 0x175e07800ef29ca0u64]),
-            f_from_words::<Bn254>([0xce0b01a626869448u64, 0x7b4304851ad60e53u64, 0x1cb7b9b782ba47aeu64, 0x29116bd07dd25323u64]),
-            f_from_words::<Bn254>([0x84e65a3a8bffd003u64, 0x0a7d0496cc546559u64, 0x7bdcb0ccf90bed0du64, 0x0b3d89768896e8e3u64]),
-            f_from_words::<Bn254>([0x57f9c66f69254ef9u64, 0x12c17087c60ec979u64, 0xb540d146d5f2b343u64, 0x018cb35835d8a9d6u64]),
-            f_from_words::<Bn254>([0xb1b7cbe35fee8acdu64, 0x8c1c948ec66a4e93u64, 0x33d8ca807c5da37au64, 0x22b7816fb8be69f5u64]),
-            f_from_words::<Bn254>([0x54900c57252be589u64, 0x5e2770a3dbb454a3u64, 0xf63739b9323167afu64, 0x15178c843a124eccu64]),
-            f_from_words::<Bn254>([0x330d3f8c3948fc4cu64, 0xb63c254bb965f1f9u64, 0x97b21642732e671fu64, 0x0d256db0eb87b230u64]),
-            f_from_words::<Bn254>([0xc4b72325c8c7397fu64, 0xfc4824aa1450eefau64, 0x341a7c16d5ef9f24u64, 0x17b146858ebad6d8u64]),
-            f_from_words::<Bn254>([0x0c4afb06e056285au64, 0x56495746e6319e2cu64, 0x88bb617a179d10fcu64, 0x24add74786faf043u64]),
-            f_from_words::<Bn254>([0xb836c6d22cbea6c6u64, 0xc23d459327ed0227u64, 0x7acfafee265258edu64, 0x0a2f6967ba46953au64]),
-            f_from_words::<Bn254>([0x8fcafa56ac76c4dbu64, 0x767a00a671bdbf44u64, 0x94c80bf7441555a8u64, 0x2dac79105b4b1fc0u64]),
-            f_from_words::<Bn254>([0x3e0c82ab886de554u64, 0xa3df827313e24573u64, 0x5860bb25e0323245u64, 0x22bcb0500cf7fcd2u64]),
-            f_from_words::<Bn254>([0x1b91807c39073ef7u64, 0x0b1b5d5341692dfeu64, 0x7b3075b7ffe485cbu64, 0x2d06e2e2a02c5e20u64]),
-            f_from_words::<Bn254>([0xcff153f2b7041e05u64, 0x7f2f5a837482ef0au64, 0xbe37038e85704dbfu64, 0x2722fd8135f7ca38u64]),
-            f_from_words::<Bn254>([0x672351094094c59bu64, 0x396b7801ece62dceu64, 0x2eca986560071578u64, 0x1c29af00f86a9c45u64]),
-            f_from_words::<Bn254>([0x8578c6ebeb6a6bf3u64, 0x0d60326a367ccfcdu64, 0x50d38f3132eae4dfu64, 0x0db0020ca0a8f81fu64]),
-            f_from_words::<Bn254>([0x818c9673ef06a27cu64, 0x745097b30ba566a6u64, 0xb75e6a10cbcf4e27u64, 0x16e846151fc1ad12u64]),
-            f_from_words::<Bn254>([0x2aef27e10827ccebu64, 0x8ff08dd1fa303829u64, 0x93d9383aaa440490u64, 0x1d0d5831a6f9df7du64]),
-            f_from_words::<Bn254>([0x287f0576f72d3940u64, 0xdd94cfddbac58d28u64, 0x4dbfde40e1283b51u64, 0x20701b1fc0cd1cb6u64]),
-            f_from_words::<Bn254>([0x91ef21f04f4c84d7u64, 0xb2c3d237b0da8ae6u64, 0x9f46ae83f5828b94u64, 0x12781d6b843c6e55u64]),
-            f_from_words::<Bn254>([0xa82f05fb7117b6e2u64, 0xe6f7d77056b8f73cu64, 0x1a3955e8f3f074cdu64, 0x0525a0b414cb7789u64]),
-            f_from_words::<Bn254>([0x7d5ed6554e347d30u64, 0x8f0cef0fdffef4c4u64, 0x5da685c87ba2bf5du64, 0x0a49e0a200a0f800u64]),
-            f_from_words::<Bn254>([0xd16939144f53046fu64, 0x4cae0ff4ddae6752u64, 0xc8fb4368c5abc990u64, 0x1d62313564c26545u64]),
-            f_from_words::<Bn254>([0x453fefc6ca417134u64, 0x97e152a292fe1906u64, 0xa7e552d3ce1404a3u64, 0x1632d893c77afd88u64]),
-            f_from_words::<Bn254>([0xba0b3e6749c179beu64, 0x91210971325184f6u64, 0x035fd12b5667f984u64, 0x09bac1fa7932a9b3u64]),
-            f_from_words::<Bn254>([0x2e7313510f2bb04du64, 0xc37601806be22a66u64, 0x157826f8232d1cbau64, 0x2932b4fadc38c956u64]),
-            f_from_words::<Bn254>([0x27d3fe311c7e3e9du64, 0xa97fb3973a9dea72u64, 0xdfe05c68e6dc5e47u64, 0x01358125ca29537bu64]),
-            f_from_words::<Bn254>([0x29a7be883a5722aeu64, 0x0cee1ad4b05020b2u64, 0xadb7d8cd0bee52eau64, 0x26d788e37b608e2eu64]),
-            f_from_words::<Bn254>([0x558ee8b68565ca53u64, 0xc29bb93039f86a09u64, 0x87c53330edd20105u64, 0x2f7bae610cdaa365u64]),
-            f_from_words::<Bn254>([0xdbda78a308e41c1au64, 0x38099835db530e93u64, 0xb31440e17c61bde7u64, 0x28a12e887c4522f3u64]),
-            f_from_words::<Bn254>([0x9b203bd05e2699dbu64, 0xdc68e0e654d20e65u64, 0xf5fb7979677e43cfu64, 0x2d498a84b86219e8u64]),
-            f_from_words::<Bn254>([0xfc39bf98cb0461aeu64, 0x765c502286313b7du64, 0xa160633cbe476903u64, 0x12168d784a7c8e5bu64]),
-            f_from_words::<Bn254>([0x60d3af3fcd712b4cu64, 0x5a9960aff37881d2u64, 0xf061eb18fe6037d3u64, 0x12ae446f6f5f0703u64]),
-            f_from_words::<Bn254>([0xbdbd7f4de65d04c2u64, 0xc73442f0ce1f300cu64, 0x786ff72ccb242be0u64, 0x2395f403547d82fbu64]),
-            f_from_words::<Bn254>([0x6636bc167db59611u64, 0x593abb1150e9638bu64, 0xa0836382ebece5cau64, 0x1d19e58f12bc8b1cu64]),
-            f_from_words::<Bn254>([0x4b302b3ab7412b12u64, 0x7119b1bd345d09ecu64, 0x6644bdcdc37ed509u64, 0x27a93d36b18eaba9u64]),
-            f_from_words::<Bn254>([0x4edc66aff13c7b4cu64, 0x2a8cda341fff718au64, 0x89c311155976610eu64, 0x2a1a5693d71261eau64]),
-            f_from_words::<Bn254>([0x6db985a202738b76u64, 0x73bf6bf580088e8fu64, 0x8a415d0d47316ccfu64, 0x247ff8b0d1ed9df0u64]),
-            f_from_words::<Bn254>([0xdc017c5f9b14d4cdu64, 0xf35a5d0ee482b101u64, 0x50e6a5e4cdc82eceu64, 0x26a1a2d833cfd14eu64]),
-            f_from_words::<Bn254>([0x7c2e1113275e88deu64, 0xd516e1c1799fea0fu64, 0xfa79bf5342d0aff1u64, 0x02269de596f73c19u64]),
-            f_from_words::<Bn254>([0xe80ca902f81bec84u64, 0xdabd224f23b2363bu64, 0xde8653aa930f23c9u64, 0x2b57ab46890aeabau64]),
-            f_from_words::<Bn254>([0x2f601b624c3c7cacu64, 0x96843f35ebf61c42u64, 0xcc8313a1fcd19f60u64, 0x2eab56a83a53d109u64]),
-            f_from_words::<Bn254>([0x14e9c84288dbeec8u64, 0x9847835d9fdec224u64, 0xa4d56b996b7cbce8u64, 0x059ee22f6187340fu64]),
-            f_from_words::<Bn254>([0x7b45a2b86c7293ceu64, 0x255125715293503cu64, 0x6413c1b6860f98a7u64, 0x1fd3fc5b1e776398u64]),
-            f_from_words::<Bn254>([0xc49d706008ec3953u64, 0xeeb2fb8ac9f53c00u64, 0x32d40b4252123a16u64, 0x16e3a34ce6983e38u64]),
-            f_from_words::<Bn254>([0x37887c8ea660b969u64, 0x6b29aef97bb622aeu64, 0xf6b2460976e15a69u64, 0x228e2305d3231ce4u64]),
-            f_from_words::<Bn254>([0x51ae9c775ea1e62fu64, 0x3e390e605b42fc19u64, 0x6c2c8b13fd7b4cc4u64, 0x024d2903e38ca225u64]),
-            f_from_words::<Bn254>([0x898140ece0fc2c14u64, 0x1d3134431844e95eu64, 0x20b620f3ab96d046u64, 0x2033e5261e40d866u64]),
-            f_from_words::<Bn254>([0x847b0c424bbd6905u64, 0x1b269fa807c510e5u64, 0x6c1664f76c6d9b66u64, 0x1c3ef30f50a591d5u64]),
-            f_from_words::<Bn254>([0x89504eedf46fe56eu64, 0x6d6508dfda53be36u64, 0x2e247f9fa10443f3u64, 0x201aeccf4cec50a9u64]),
-            f_from_words::<Bn254>([0x4647acd70085fe73u64, 0x9731c68d2a0a7775u64, 0x89ff1b77c4fb3c0eu64, 0x082919f5d60a78e4u64]),
-            f_from_words::<Bn254>([0xdf9cc39f2e92aabau64, 0xe27b7ff12bd800d8u64, 0xe74fbd4d33246448u64, 0x068b4c9c3b9b12cdu64]),
-            f_from_words::<Bn254>([0x4b034ede5f91bb2bu64, 0x8d16621be45350f9u64, 0xf538eb6c90830643u64, 0x299bdf962bf993ebu64]),
-            f_from_words::<Bn254>([0xf9c34434124dbefau64, 0x4c94801e51e0fea9u64, 0xe3501c0db82c9918u64, 0x293fd772d0e46182u64]),
-            f_from_words::<Bn254>([0x5592cd12d43d89d1u64, 0x76a0cd325ddb78ddu64, 0x0ebf02d871d5c3f9u64, 0x11975a16ba8ec7e6u64]),
-            f_from_words::<Bn254>([0xf9331fde4e605453u64, 0xc468da6cde4c8090u64, 0xee6cfb90d020a223u64, 0x2ca3274b3e247ec5u64]),
-            f_from_words::<Bn254>([0x66487b0c045480cdu64, 0xc09c15351184be3fu64, 0xc47fcbfa453c7523u64, 0x2b73394d8b8bc4ccu64]),
-            f_from_words::<Bn254>([0xb5860899a9e15bd1u64, 0xa6535b9a1532e854u64, 0xfed0e6cc0964aa23u64, 0x20f00f4a63f8b39fu64]),
-            f_from_words::<Bn254>([0x97aa39a9d4595e88u64, 0x8a45b244658f515bu64, 0x142e460599619b29u64, 0x2f625a4244431db3u64]),
-            f_from_words::<Bn254>([0x5e7fcb4ca7db7171u64, 0x3529edd02e1b205au64, 0x788097ac7307d4d7u64, 0x0b8623fd4361f1e4u64]),
-            f_from_words::<Bn254>([0xc3ac3c2ab72a01e4u64, 0x1d9dfce527139752u64, 0x523bfe24ec9e78fdu64, 0x23cdc785b97e0d0bu64]),
-            f_from_words::<Bn254>([0xb68c8600cbc658cbu64, 0xa6c80ec424cbf7e3u64, 0x5191125445c1386fu64, 0x02130a9f381a25dbu64]),
-            f_from_words::<Bn254>([0xe8fa6fc299908522u64, 0xa266eea3d4fb3e74u64, 0x020f66ebd6be47aeu64, 0x2cac11b93e0eeb4bu64]),
-            f_from_words::<Bn254>([0xeb2bfe6fa4a1cca4u64, 0xaa0b0a0c5ff5122eu64, 0x54deae7088c6bb06u64, 0x2e6bf029637d3228u64]),
-            f_from_words::<Bn254>([0xc9f546d08ae5b490u64, 0xddfff82705fb9e9bu64, 0xb62247ebc870658eu64, 0x11d702963f22aa45u64]),
-            f_from_words::<Bn254>([0x06d9e609c92b5a63u64, 0x3ba3df59f35de5c3u64, 0xdb94f798da45dc04u64, 0x25b0e6a53fa5c71du64]),
-            f_from_words::<Bn254>([0xaaf928fffc777d71u64, 0x1730c7bdcaa087c2u64, 0xfd3485f76c9922b3u64, 0x10dd253430d9b865u64]),
-            f_from_words::<Bn254>([0x33dacd0c9d28eb5fu64, 0x3971251e44bfc068u64, 0x6914a88ecac5fa83u64, 0x29556a110e984f1bu64]),
-            f_from_words::<Bn254>([0xe2f645d3335371a9u64, 0x4575edb92eb7364bu64, 0x0a600052f9edf132u64, 0x222059f9c0a91dd9u64]),
-            f_from_words::<Bn254>([0x9d7aef221b5d6581u64, 0xfd9d50675abc5f55u64, 0x620d2777440de3a2u64, 0x0d2865e0ca4ea101u64]),
-            f_from_words::<Bn254>([0x80e5408793710342u64, 0x880feab23944a802u64, 0xb28b9e2d3e1f8929u64, 0x00c6a5b635279e19u64]),
-            f_from_words::<Bn254>([0x433018a67badac57u64, 0x95a318439f36caacu64, 0xc1ce937c2b2babcdu64, 0x2fe777a494bd0fd9u64]),
-            f_from_words::<Bn254>([0x92715209f62ffdd5u64, 0xa4d2b28293186e76u64, 0xb48d34489bf2cc45u64, 0x28cfd425916617eau64]),
-            f_from_words::<Bn254>([0x4f262a12a4e095b2u64, 0x17b13be85b949681u64, 0x060cd6ea8dc2163eu64, 0x1137164a6ce51b65u64]),
-            f_from_words::<Bn254>([0x2857d8ad750ae412u64, 0xd88c5cfef4133f13u64, 0x0873822641aee439u64, 0x1357059d29f9348du64]),
-            f_from_words::<Bn254>([0xba2930ff3478bb90u64, 0x52047a3917909533u64, 0xa986a4ddb2c88be2u64, 0x08811088d38966a7u64]),
-            f_from_words::<Bn254>([0xe24c9e503840b534u64, 0x65bcba9ee0dc85c4u64, 0x99a3449a9b1b1597u64, 0x0a1e119a16c0d8dau64]),
-            f_from_words::<Bn254>([0x6357758409715048u64, 0x38300644db3b6037u64, 0x2ff707b4acc6a15du64, 0x2934056cf18e2396u64]),
-            f_from_words::<Bn254>([0x31db65f9855d9a36u64, 0xa2a5e144af860f3fu64, 0x58861efc3e73be11u64, 0x25cfc5112c822385u64]),
-            f_from_words::<Bn254>([0xfb04ca5b86b2b04du64, 0xd13b2564b8c07f8cu64, 0xb80dd276f208be13u64, 0x22ab271c20ad678cu64]),
-            f_from_words::<Bn254>([0xa11322263f96ecbbu64, 0x32281b4b68827d1cu64, 0x027b6a2cccef630du64, 0x1da7d50a24f096a7u64]),
-            f_from_words::<Bn254>([0xe554a37b154b8010u64, 0x70ec731326f7b6efu64, 0x7fdf01e73732cb8cu64, 0x07c143a04ba01397u64]),
-            f_from_words::<Bn254>([0x636a109bc643a3b0u64, 0x01079dca52016d4au64, 0xbe7282194f6d6a04u64, 0x0d1dff692d166e09u64]),
-            f_from_words::<Bn254>([0xc6595caf11a2ca07u64, 0xc404124d291a2caau64, 0xe467852991cce12bu64, 0x2976ed1f9efff7b3u64]),
-            f_from_words::<Bn254>([0xc9a3afb8416d4cccu64, 0x1163acbf09132011u64, 0xfc946cb7e0f46958u64, 0x0b187b1bbe8ea8d9u64]),
-            f_from_words::<Bn254>([0xb7f9558c685c70f1u64, 0xcce0d3c5823028e0u64, 0xef15c702703519dbu64, 0x147e559bf93d7234u64]),
-            f_from_words::<Bn254>([0x98d98cde74b86c96u64, 0x3c7328929ab25495u64, 0x43543fab976ec907u64, 0x21b90027e6f4869cu64]),
-            f_from_words::<Bn254>([0xe956298ffc9140a4u64, 0xeeaafa512597d6c6u64, 0x02ba330c93b52c4cu64, 0x1012d72fc0e103c4u64]),
-            f_from_words::<Bn254>([0x79137cbcd9cc3275u64, 0xc52c7ca9714783ceu64, 0x9315245d011d8638u64, 0x179bb21cbed1f6cdu64]),
-            f_from_words::<Bn254>([0xd9c133782201e94eu64, 0x3c13fd1b10f51a11u64, 0xfd30f09b2e8857e7u64, 0x2a6f250111b595f2u64]),
-            f_from_words::<Bn254>([0x82275db700672d27u64, 0xe2d5685474b21e99u64, 0xf0fcec5c34647b22u64, 0x1c4e944990942858u64]),
-            f_from_words::<Bn254>([0x160cadedcb07b988u64, 0xed638ed3d6e428d1u64, 0x347e4659e582406fu64, 0x030abaee1576781cu64]),
-            f_from_words::<Bn254>([0x2c079203dbe58b4cu64, 0xf460e6a19e1a1723u64, 0xf77dcc27c3b49d19u64, 0x0f6ff762ece9d3ecu64]),
-            f_from_words::<Bn254>([0x50f0cfc34c2cfb22u64, 0xf1f708e532b23856u64, 0x1f271f54cb44700au64, 0x21eae2b7885d149bu64]),
-            f_from_words::<Bn254>([0xd7c2a59ad753b880u64, 0x2c24e70711eae7aeu64, 0x27f562b8a1eda783u64, 0x01cdea5f5def51d6u64]),
-            f_from_words::<Bn254>([0x8ed688a5eb558572u64, 0x2c44205bbc82343cu64, 0x9246748ea1b56d23u64, 0x0265253e7c5901f8u64]),
-            f_from_words::<Bn254>([0x92b24ca5e5e556c1u64, 0xad579f3f8f29bd5du64, 0x98bd86592517bc25u64, 0x2114c9b1e42e6a00u64]),
-            f_from_words::<Bn254>([0xfc79f37f390569e8u64, 0xda17e5458022511eu64, 0xb65e95214a0978c4u64, 0x03650fd9bd87ff80u64]),
-            f_from_words::<Bn254>([0x2a5b35091433e487u64, 0xcaf827514805eba1u64, 0x0361a72bb0c4e036u64, 0x09f5b251e441f734u64]),
-            f_from_words::<Bn254>([0xbab7dd2abb8e8224u64, 0x0f460fa92aae15dau64, 0x1a1915d5dc071405u64, 0x28a713f5ed7311b3u64]),
-            f_from_words::<Bn254>([0x4a1b1d82fcdbe924u64, 0x552eb798b6d5d20cu64, 0x7f54ca4141056dabu64, 0x144a4a9445180701u64]),
-            f_from_words::<Bn254>([0x009ac825c16ed744u64, 0x0626a5beb68f1d87u64, 0xefcd5f641772e551u64, 0x0d842ccffdad6c33u64]),
-            f_from_words::<Bn254>([0xc54fb7fa56ef34d9u64, 0xd9cd487bf7b47fc9u64, 0xa6e4f5770b8e750au64, 0x1b02410e99f4fbd6u64]),
-            f_from_words::<Bn254>([0x3d85f828693fd099u64, 0x82922de8f3fa6b50u64, 0x8404bfc6a4852a32u64, 0x2db8e15ca85465aau64]),
-            f_from_words::<Bn254>([0x5bc9ee62c0ac52beu64, 0x2632d1c6ba18a507u64, 0xc3e72507c70dba78u64, 0x2c2fb72760375178u64]),
-            f_from_words::<Bn254>([0x269e38ef68d0db68u64, 0x823a4c1abd16eb08u64, 0xdf29cf8d849c4f97u64, 0x0ddf6c315c8af6b4u64]),
-            f_from_words::<Bn254>([0x00de068a033874d8u64, 0x2e22b6e74076e463u64, 0x5e10b52c3edc2393u64, 0x2c8b4d1f959e45f3u64]),
-            f_from_words::<Bn254>([0x979c6549e08b367du64, 0x11d45eab0966ea71u64, 0x9e7411ff0e38b6c4u64, 0x00cc03be1a5fb924u64]),
-            f_from_words::<Bn254>([0x3ee76a3455509914u64, 0x888219dd82aae937u64, 0xeee8f9db86ed8b45u64, 0x2c317490bf08656au64]),
-            f_from_words::<Bn254>([0x9043698798054491u64, 0xa6cba3064758bd0cu64, 0x799114a5deb2b2f3u64, 0x145679ad067a14d9u64]),
-            f_from_words::<Bn254>([0x28ec0c4ae5799581u64, 0xcf90542659940095u64, 0x337cdd501e873c2du64, 0x2f2d1bd3341e475au64]),
-            f_from_words::<Bn254>([0x8e097e46f822f278u64, 0xb3d89e3cdd31ad27u64, 0x37ddb8b97c539d86u64, 0x22472ec2cfe66a0au64]),
-            f_from_words::<Bn254>([0xa4901d62e945a9cdu64, 0x8302d85c4bb8c9d3u64, 0x9fbfc479267e5b8fu64, 0x1cd74704e31bafc0u64]),
-            f_from_words::<Bn254>([0xd9b60fe95357c963u64, 0x0f751fb385ffa72au64, 0x329bf192d36d2e71u64, 0x1d52e0e5274c3b40u64]),
-            f_from_words::<Bn254>([0xdb5a8309c0396c3cu64, 0x26c86098e0102271u64, 0xdc3f6ea8d338782au64, 0x120e6e5859d033c2u64]),
-            f_from_words::<Bn254>([0x3ea7a2ea6e04176bu64, 0x350e921572b64d9fu64, 0xda3183fbc0cb7ed0u64, 0x04e216aa76a980d8u64]),
-            f_from_words::<Bn254>([0x23355ef0c2a11fbdu64, 0xd65eaa66c57b128eu64, 0x456a9d95271cffacu64, 0x072b8f7d6c24c56du64]),
-            f_from_words::<Bn254>([0x91eb095ea96d4b35u64, 0xe2a00e0c6d2dd7e8u64, 0x3eae789ab2086803u64, 0x29bfa29e81351475u64]),
-            f_from_words::<Bn254>([0xcec4973f85ad4745u64, 0x907827a5ded9bbf1u64, 0x29c1faa3c95f6e63u64, 0x0fbe65cc11632913u64]),
-            f_from_words::<Bn254>([0xf00237aa45dc463du64, 0x8e29d4b4cffd3f96u64, 0x6bd9f30c3974e9b2u64, 0x2be8ebe8fafb60c5u64]),
-            f_from_words::<Bn254>([0x5e281c0c8ab89599u64, 0xf6b9c562f0363bd3u64, 0xa86152d9fb28a672u64, 0x0228281e5a40273du64]),
-            f_from_words::<Bn254>([0x4229f7b778baeb16u64, 0x06356c2ceceb1260u64, 0x006e6e994333c253u64, 0x138df3e49562ab15u64]),
-            f_from_words::<Bn254>([0x8237a24f4c28e4d8u64, 0xc8cbe18a6f1d15aau64, 0x225c9539b7aefe01u64, 0x17f3e54bf738df9bu64]),
-            f_from_words::<Bn254>([0x8ec4fa3a7399e0a8u64, 0x61ed18e94c465b84u64, 0xc147d5bb3324ab89u64, 0x2f711a31b2d5e60au64]),
-            f_from_words::<Bn254>([0xbf4bca27ab4a7ceeu64, 0x99bf75e9fbc48e43u64, 0xddf551e18f404984u64, 0x2256063334fdae4eu64]),
-            f_from_words::<Bn254>([0xf67b205467f86918u64, 0x9c9e52b71d05a103u64, 0xfbe7927d6357810au64, 0x03aa519d758902e9u64]),
-            f_from_words::<Bn254>([0xda08eed0e20db19bu64, 0x8fe2cfc6bc721397u64, 0x3867d8b11e3870feu64, 0x1dd47c805a3ed86bu64]),
-            f_from_words::<Bn254>([0x2dc9e1d6d9351de3u64, 0xb5f9c28290dc4c81u64, 0xc9fc134e95d29915u64, 0x22aecfc1e2c810a5u64]),
-            f_from_words::<Bn254>([0xde2b04bd6fb3e132u64, 0x1a7f380084647f60u64, 0xef04fa7546733b8eu64, 0x0c627130c42dc0feu64]),
-            f_from_words::<Bn254>([0x849a888a844b44f1u64, 0x34c25bf5e50268ceu64, 0x8afd8aee66b7cd99u64, 0x0cf2e61af545173au64]),
-            f_from_words::<Bn254>([0x0aff89c23c5890f4u64, 0xeedb947813d91020u64, 0xd0640b2c4b9cf7ddu64, 0x137d53060fa1d2eeu64]),
-            f_from_words::<Bn254>([0xfb9a084b3f104ad8u64, 0xabe4b3fdafd5aee2u64, 0xccbedb7f2471fcabu64, 0x284eb397dac576eau64]),
-            f_from_words::<Bn254>([0x84b555e5fe785fc5u64, 0x2564b310c846df38u64, 0x6614c79cfbc988d6u64, 0x01b2fea57bd69f38u64]),
-            f_from_words::<Bn254>([0x89f05c8c363d3830u64, 0x809def5378d45a88u64, 0xde57a40482696263u64, 0x1a7666188306859cu64]),
-            f_from_words::<Bn254>([0xbaabc7154e807101u64, 0xb01de985b763bc82u64, 0x24dbb3fa3d5d334eu64, 0x299a40dd1f3204b0u64]),
-            f_from_words::<Bn254>([0x4d77c0c3bb8dc8e3u64, 0x4b07f854c73d2c00u64, 0x3363933bd42d4458u64, 0x13eac33f21147128u64]),
-            f_from_words::<Bn254>([0x94b531f2751e7ba8u64, 0x7fe907925493f5ceu64, 0xd95695ec5cdf928fu64, 0x024f41eda777d318u64]),
-            f_from_words::<Bn254>([0x279909880c9d20afu64, 0x01ae16f6ea7c5724u64, 0x33098e59dea82b9fu64, 0x2909b6747edc6188u64]),
-            f_from_words::<Bn254>([0x40c970dbaecb0c8fu64, 0x91d75833abf3f927u64, 0x9485632901213a1au64, 0x050d2319806c57c1u64]),
-            f_from_words::<Bn254>([0x2d88dc46646e015eu64, 0xd6afb47461503f52u64, 0x6d778d2fdba7d41bu64, 0x099004d5fa5227feu64]),
-            f_from_words::<Bn254>([0x5b5714b6263fc109u64, 0x9ef8a5de33569654u64, 0xcfbe271abb70963bu64, 0x2ea8658a03a55575u64]),
-            f_from_words::<Bn254>([0xf2f8713d125a3de3u64, 0x50204ea9e3ba6e41u64, 0xf243c273d8392cecu64, 0x29c3cc0dc7a12164u64]),
-            f_from_words::<Bn254>([0x84608f6aa5633866u64, 0xbca4697b42a0e1d0u64, 0x8f249d5aec810437u64, 0x2ff4a169df289af9u64]),
-            f_from_words::<Bn254>([0x1589fbe139c7add0u64, 0xeeca08bcd96d915eu64, 0x0d49311da766b9bfu64, 0x19a4673bc3aed598u64]),
-            f_from_words::<Bn254>([0x43f1d6deb0244c8cu64, 0x52763b3d550a01bcu64, 0xede9cc489201c8c3u64, 0x1097a5446ba7c201u64]),
-            f_from_words::<Bn254>([0x735f3456fa2a69fbu64, 0x745d12d04535629bu64, 0x19cdb746949f4a49u64, 0x1ed782e05c50d927u64]),
-            f_from_words::<Bn254>([0x864caadc72008f82u64, 0xc51c34e3d1939b70u64, 0x1ca0ea9a7faed647u64, 0x1aa9be78f0cf45c5u64]),
-            f_from_words::<Bn254>([0x70226a716160542bu64, 0xde2658cb0e9424edu64, 0x8d80f78032d0e533u64, 0x2125b8189b5bdd04u64]),
-            f_from_words::<Bn254>([0x4af63108d5f05e4eu64, 0xf562f766495f3b93u64, 0xfebf1e0a6f2cfbf7u64, 0x20d9d9af7c384923u64]),
-            f_from_words::<Bn254>([0x65041b80bcde5148u64, 0x64236f95de790e16u64, 0xfb48560383aa900eu64, 0x1d434269451b8d4bu64]),
-            f_from_words::<Bn254>([0x2f791f023320af12u64, 0x6d1d83b371f0a003u64, 0x5b771ca073178938u64, 0x01c69ea2ad41bd26u64]),
-            f_from_words::<Bn254>([0xd44b58a2a43670acu64, 0xf80ba138bd90555bu64, 0x86a414a14417ec77u64, 0x2bec6587f8a28346u64]),
-            f_from_words::<Bn254>([0x6032c3de99ed0c84u64, 0x4077ef37fdcad2e2u64, 0xe02a8703092de1cdu64, 0x0844ea205d3daaf4u64]),
-            f_from_words::<Bn254>([0x085dc1a60afc57bfu64, 0x623e4dd3e5fbe96fu64, 0x42fb99a1a08c786du64, 0x16fce5a4ddfc6cc4u64]),
-            f_from_words::<Bn254>([0x8c6bd3181860eae8u64, 0xeb8fa250e1424ff4u64, 0xd31fe38369e66d3au64, 0x2013651eb587bc1cu64]),
-            f_from_words::<Bn254>([0x81fa9fa6d30bd691u64, 0x4b2aa4e5c516134fu64, 0x3da22a12548e6d85u64, 0x269d05acf439eabdu64]),
-            f_from_words::<Bn254>([0xc89cc53d1095823au64, 0xdc4966bb78e61749u64, 0x47b6cf623fb8145fu64, 0x0554cfc99b30b437u64]),
-            f_from_words::<Bn254>([0x448ac0c36ff7450du64, 0x8761a2fc8ed323a1u64, 0x72a1b8d54716c4dau64, 0x0fe81f5cf0deacc1u64]),
-            f_from_words::<Bn254>([0x8e47c8703ac4ab3cu64, 0xbebac454933659d2u64, 0xd3ad4fc381066825u64, 0x127c9506d6c31573u64]),
-            f_from_words::<Bn254>([0xd5e30d72bd75cfc1u64, 0x983b6afdb45181f8u64, 0xd9f3aa4457875c73u64, 0x2d33ce812bda49b0u64]),
-            f_from_words::<Bn254>([0xa6bfd906e1bd6690u64, 0x5a6d7f9be4299e11u64, 0xa7b5c9c5f29f1d70u64, 0x1df8982131062269u64]),
-            f_from_words::<Bn254>([0x5c515b9dcadc19e8u64, 0x5bd0134e9fd55f9bu64, 0xe9e9c5ce9744812cu64, 0x2a02b425e47c4654u64]),
-            f_from_words::<Bn254>([0xde0a6aabb2994398u64, 0x060e57eeda744f7bu64, 0xc10156645a13ff8fu64, 0x1af9eb7ff628b1c5u64]),
-            f_from_words::<Bn254>([0x4619687dac34c42cu64, 0x7e0dae1d7b11b09fu64, 0x679949af2cc13632u64, 0x27c4865eda8a0848u64]),
-            f_from_words::<Bn254>([0x3abf8372ebb371c2u64, 0x55bf0006db5d7e3eu64, 0xaf9625a87fbc504fu64, 0x2697cd201c257002u64]),
-            f_from_words::<Bn254>([0x1aa57a0de2435733u64, 0x197a930913d1b073u64, 0x43bd50b17d0f04aeu64, 0x19cf931e43524590u64]),
-            f_from_words::<Bn254>([0xa875fc5a30fef560u64, 0xb7ae35c10b6e2589u64, 0xd0d1eddbdbec0de1u64, 0x2ca6f9fee83416d7u64]),
-            f_from_words::<Bn254>([0xbfa97dceba730642u64, 0xaa88f64a997b7a59u64, 0x47ff5c7fafb20c9cu64, 0x08339f8e9120bb72u64]),
-            f_from_words::<Bn254>([0xf43d18360d695fefu64, 0x27a571fa548980f0u64, 0x156d7c419de72b04u64, 0x06494c603e6bf0f8u64]),
-            f_from_words::<Bn254>([0x2c1a814d337cbb84u64, 0xbc9d4054720fa055u64, 0xe9dd7d3d360e01d7u64, 0x2e628d12daed3b24u64]),
-            f_from_words::<Bn254>([0x731b38e127af2c3eu64, 0x9abc18612dbae082u64, 0x010b4ffeec521f48u64, 0x12af897a7bec1f9fu64]),
-            f_from_words::<Bn254>([0x95065efa79353a4cu64, 0x4dfbd3337edd89a4u64, 0x9166fc0cf3bd6082u64, 0x2007ca7bd8ded0a8u64]),
-            f_from_words::<Bn254>([0x8050f33e2e7184d5u64, 0x10311c942e96352du64, 0x3eea0ea9dfd45b7au64, 0x2bd9a53e3dff43cfu64]),
-            f_from_words::<Bn254>([0x1171eacdd5b73090u64, 0x7a7d6d1f30f9b71du64, 0x29a0e65b1c5b7159u64, 0x0ae1f1d5470e990fu64]),
-            f_from_words::<Bn254>([0x088a27021279d869u64, 0x170069fddcfbb9e7u64, 0x724a45fe6529e42cu64, 0x0e24608756bfa6cdu64]),
-            f_from_words::<Bn254>([0x84aa5fbe55c08eefu64, 0x977403d6853177f8u64, 0x8e6014a99269a8cdu64, 0x2db7ffa36c7c6d84u64]),
-            f_from_words::<Bn254>([0x1a583e4ccb8951e2u64, 0xf6c5f8bb7dd073b0u64, 0xc02ab083c34d53dfu64, 0x19fbe04408ec0260u64]),
-            f_from_words::<Bn254>([0x3677f7e5002cf776u64, 0xc22893166e70d80bu64, 0xd333d3ed4eed0b7au64, 0x1e20721873092d4cu64]),
-            f_from_words::<Bn254>([0x7667fac512bbe10au64, 0xe6f9d13890935049u64, 0x8c29fe85569c73e7u64, 0x0ed987aca646637du64]),
-            f_from_words::<Bn254>([0x8ee768f4c65a8cb6u64, 0xb08a6225094b20d5u64, 0xae532a8b02c0ee7fu64, 0x2993176bcb455510u64]),
-            f_from_words::<Bn254>([0xeb889f5e18f002edu64, 0xb8cc540a049d76bau64, 0xa061fa88b863f3c6u64, 0x01ac85bc22a3036au64]),
-            f_from_words::<Bn254>([0x87e75086172e565eu64, 0x5129dd2bf2c01f93u64, 0x6b3c9a2e6647fab3u64, 0x1065bd329de71473u64]),
-            f_from_words::<Bn254>([0x6987515b6d7254b8u64, 0xcc390fc1641ea913u64, 0xf045dcd1084d3c96u64, 0x1ba171c3570bc91bu64]),
-            f_from_words::<Bn254>([0xb8b10a93a67d0d3fu64, 0x68902bcbf6eb3196u64, 0xb829ed80ef3d4cbeu64, 0x040f1fb56c0e60afu64]),
-            f_from_words::<Bn254>([0xdd793c438186cd2eu64, 0xb951848981158995u64, 0x9c8d9476fa2dec2bu64, 0x0ea851f8955255efu64]),
-            f_from_words::<Bn254>([0xfc2d6ce2468c3417u64, 0xceadca9de017b335u64, 0x5703fbfa4d616bcfu64, 0x018ae070d97192f3u64]),
-            f_from_words::<Bn254>([0x8ee976b98140eec2u64, 0x2ded74faca03a416u64, 0x00b92f90f6dd0cf8u64, 0x06216b4f3009e9e3u64]),
-            f_from_words::<Bn254>([0xc987deb6a3241987u64, 0x181d7dfed6a3b616u64, 0x781709ae72e33540u64, 0x12ae0d97427134aeu64]),
-            f_from_words::<Bn254>([0x08787135495a59d5u64, 0x9121d53246cae792u64, 0xfa204a2b75c86ea3u64, 0x2f8e85ddb641bffdu64]),
-            f_from_words::<Bn254>([0xad6a2645645796d3u64, 0x4652b71a9ec9657du64, 0x53363eb6d6670dceu64, 0x18bef87c66592779u64]),
-            f_from_words::<Bn254>([0x13045fb830130f4cu64, 0x518c7b9afcefe37eu64, 0x99804dae2ebd8c5au64, 0x009ae2db895aca62u64]),
-            f_from_words::<Bn254>([0x3468ff964f08cf8au64, 0x0fb010e2a7849f9bu64, 0xc124728a37bcf1e9u64, 0x0cf6ba44d2cea28au64]),
-            f_from_words::<Bn254>([0x3844faea40893980u64, 0x911339aa4265b88du64, 0xa998de5c133f6298u64, 0x02384f207859df0fu64]),
-            f_from_words::<Bn254>([0xdbfe25fb7dd3462au64, 0x5b6f38f91f45115cu64, 0x03b31c6fa08f8e09u64, 0x267b12341105c186u64]),
-            f_from_words::<Bn254>([0x29f21e34c539c79cu64, 0x00fa253f5ea72c33u64, 0xb61e05d2cff53508u64, 0x1fa32bdcc23e42a0u64]),
-            f_from_words::<Bn254>([0x8d5c81ce54cc6c89u64, 0xa7dfd6280bf23b93u64, 0xa3d82f9266e94b57u64, 0x20d6f80a566261c4u64]),
-            f_from_words::<Bn254>([0x241135c00c578683u64, 0x895e4a6c3c39a95du64, 0x67d3ad32dfee7cebu64, 0x2288f7f88c45f4c0u64]),
-            f_from_words::<Bn254>([0x1447b8774582f454u64, 0xd0c96ad89e4ec937u64, 0x474c640a3a058064u64, 0x21da95a63d8ac826u64]),
-            f_from_words::<Bn254>([0x9094f150573ae9d2u64, 0x060d3347e4f303d7u64, 0x0459213aa4e76117u64, 0x12633c9c84b0b78bu64]),
-            f_from_words::<Bn254>([0x233bd55921649858u64, 0xb4ca6933d6084aadu64, 0x72030078fa354034u64, 0x03e60fbe188b71b5u64]),
-            f_from_words::<Bn254>([0xc3f224e2b467a84du64, 0xf87c2f5bb2a4a06au64, 0xba49df50cf33dff7u64, 0x1cb429ab101ab1deu64]),
-            f_from_words::<Bn254>([0x7abbb7e195eec4b3u64, 0x716f95d0fe753650u64, 0xbf888b7e3deaf5adu64, 0x083a5b4f158210fbu64]),
-            f_from_words::<Bn254>([0xa687e37ea32a0151u64, 0x646732489cb8dfdau64, 0xc3e0c161232b9c2cu64, 0x0c1fbcc0afef0a9bu64]),
-            f_from_words::<Bn254>([0x4d32f5cba61b417eu64, 0xffe065f470ca39f2u64, 0x662c241712e96934u64, 0x0d130d19a98772c6u64]),
-            f_from_words::<Bn254>([0x364f56351c544b65u64, 0xa939c462a7873924u64, 0x3595eb100e7d94f8u64, 0x1193818ace214963u64]),
-            f_from_words::<Bn254>([0x0221a6de23d9bbf1u64, 0x1dcac52d7d12b722u64, 0x976eee963e05ef8cu64, 0x14ca9340c438c67cu64]),
-            f_from_words::<Bn254>([0x0ee2d9a52f3026c7u64, 0x61f7f044b75e7e70u64, 0xc594b5c624b9e74au64, 0x1ad48553d58c8236u64]),
-            f_from_words::<Bn254>([0xd0ddd981f074e5deu64, 0xe774a1c2e4aa7d72u64, 0x34d97ebda18bb5bau64, 0x21148bcda2ed76a9u64]),
-            f_from_words::<Bn254>([0x979502bf86ec993au64, 0xd5f30a7dc9cfb824u64, 0x479e40d33d0e7d80u64, 0x1eb145d9620fd782u64]),
-            f_from_words::<Bn254>([0x9a5871ef6cecb929u64, 0xe62c1bac9e9961cau64, 0x5830f4ef7587cb42u64, 0x23104637231d3c99u64]),
-            f_from_words::<Bn254>([0x8ca5d655aeb38abbu64, 0x6a86a7927f56a558u64, 0xb521efcb08177475u64, 0x2b9e85049b287ffdu64]),
-            f_from_words::<Bn254>([0xdca90c2b0e45f552u64, 0xa778aef86a520000u64, 0x90037e33d4997cc8u64, 0x2ae9c47db149dbcbu64]),
-            f_from_words::<Bn254>([0xe22c8aec2ff7c3dfu64, 0x5d3e62885fccb3c6u64, 0x795738bc38ad6394u64, 0x201774c1a32c9e79u64]),
-            f_from_words::<Bn254>([0x0492df94137d3464u64, 0x86cf45e32ca854a1u64, 0xd4e6f51a0881879du64, 0x172f521cf7377df5u64]),
-            f_from_words::<Bn254>([0x00322747a1e3a5eau64, 0xbfa6eb716196f479u64, 0x2ac0deeed561d618u64, 0x02e203ff89cf8779u64]),
-            f_from_words::<Bn254>([0xad140ee11cf5de91u64, 0x41bf99cbc56d68d1u64, 0xa43fc1e4297050bfu64, 0x040efdac30569bb2u64]),
-            f_from_words::<Bn254>([0x5560fbdacc38d81bu64, 0x75b6118a8df69009u64, 0x8f4e69779322ac3au64, 0x2296169cb9070d7du64]),
-            f_from_words::<Bn254>([0xbc1c9e51b576ce73u64, 0x76adc48ef8b93be2u64, 0x48aea5ab76efeb33u64, 0x19c2a6922e03066eu64]),
-            f_from_words::<Bn254>([0x4c6e99b44c252446u64, 0x64e3b106ac439d80u64, 0xe7dbfb29b8de4c18u64, 0x29da7ec96321c783u64]),
-            f_from_words::<Bn254>([0xfd221af1faac3143u64, 0x8629909b7ee83bd6u64, 0x40580357a432c0f8u64, 0x2597bb68494e6f93u64]),
-            f_from_words::<Bn254>([0xcb2b636e74e073c1u64, 0x147f7da4fb45ac9cu64, 0x4881574e2987c403u64, 0x2f6cbe3a6a3889e6u64]),
-            f_from_words::<Bn254>([0x2a16027a30d09c7cu64, 0xf31fc8a5cf6789cdu64, 0x210964196ce7c639u64, 0x10b2386c33643a77u64]),
-            f_from_words::<Bn254>([0x9f75432c8efa994du64, 0xf9867a21c27f7a94u64, 0x4b5d46d442180ce1u64, 0x1426ebfb7e107ec0u64]),
-            f_from_words::<Bn254>([0x01c633d3220cdb1bu64, 0x158bae0fdb3556dau64, 0x41e843a718eef49du64, 0x2785ed8bb3348340u64]),
-            f_from_words::<Bn254>([0x2ea0763b63d6fc31u64, 0x798edc0ee9a7373bu64, 0x6f5bfdcafb66b906u64, 0x1eb6080c95b77be0u64]),
-        ];
-        Arc::new(GmimcErfParams::new(3, 2, 327, &round_constants))
-    };
-    pub static ref GMIMC_ERF_BLS12_381_2_PARAMS: Arc<GmimcErfParams<Bls12_381>> = {
-        let round_constants: Vec<Bls12_381> = vec![
-            f_from_words::<Bls12_381>([0x7c4c8652c027e6acu64, 0x266f2f0febabe849u64, 0xd59f20e43d04e361u64, 0x1baa67527dcc9220u64]),
-            f_from_words::<Bls12_381>([0x471cbaef7e8f11fcu64, 0x1587064f6ff944beu64, 0x4a82b3bf04c4b971u64, 0x4832ecee81a170eau64]),
-            f_from_words::<Bls12_381>([0x3d292104c650f412u64, 0x881801f57716db0cu64, 0x0f8c92195ff2d351u64, 0x64bbb3f664b1712bu64]),
-            f_from_words::<Bls12_381>([0x1e06c6af463a37ddu64, 0xd068baa6db76e011u64, 0xbc373ea1d6aec2edu64, 0x2d576abe18ce6710u64]),
-            f_from_words::<Bls12_381>([0x7b85c0d39dfbd9c6u64, 0xc53a7cb48c18950bu64, 0x96d432b8c8427b6du64, 0x0eda4b87c49db453u64]),
-            f_from_words::<Bls12_381>([0x30a048549cbee50eu64, 0x5af3bafd2115b662u64, 0x3d6a7166a27c7236u64, 0x3e26e5befa4770b6u64]),
-            f_from_words::<Bls12_381>([0xfb059a381803b5ecu64, 0x11b7f1e84e45123au64, 0x5638b47b4a84df2bu64, 0x16acef6c3b9d8dbfu64]),
-            f_from_words::<Bls12_381>([0xf2bdadfe2e131dc2u64, 0x31f4dc26f61a8070u64, 0xd9faff09df4ec272u64, 0x6dcfc1184b968152u64]),
-            f_from_words::<Bls12_381>([0x289bf2380d90a836u64, 0x03156747c28efd5bu64, 0x903db80cfb8e0c68u64, 0x3a56941470d10699u64]),
-            f_from_words::<Bls12_381>([0x7182384f43544887u64, 0x362c61473c1fc93bu64, 0x934a93ca4e48c3fau64, 0x4b4e654fb499889au64]),
-            f_from_words::<Bls12_381>([0xd2b659d279ae1149u64, 0xfefabbd836c31aa0u64, 0xca8d7316f5c5ce04u64, 0x09fb242e7f11a722u64]),
-            f_from_words::<Bls12_381>([0x8970745c8fb1dbcbu64, 0x94ea1b536095b696u64, 0xd1106c6919e54791u64, 0x45e9563ae1f9359cu64]),
-            f_from_words::<Bls12_381>([0x7e4c06ae2198184cu64, 0xe65a349f6d039cf2u64, 0x027d57259694d55du64, 0x1c7639c901ea095bu64]),
-            f_from_words::<Bls12_381>([0x4fef83663c1d0d77u64, 0xa0bb59f0ecf35c14u64, 0xc51ca591c9fd737au64, 0x5eb7278eb8ac5352u64]),
-            f_from_words::<Bls12_381>([0xea1cb3f7beab4968u64, 0x49a35c4b9cca0770u64, 0xdc3f81a1474cabf1u64, 0x2e3f7f5e3eb7a78au64]),
-            f_from_words::<Bls12_381>([0x74e7271ff8c2efc2u64, 0xec39630bd119489au64, 0xf7564a5cbd8782d2u64, 0x15556e224ad61c0fu64]),
-            f_from_words::<Bls12_381>([0xd9ffa46dce00bef8u64, 0xabfd288380e86553u64, 0x3528a719b6098cadu64, 0x34ffa841b627dde9u64]),
-            f_from_words::<Bls12_381>([0xb25e1d4d4a25ccc0u64, 0x3dc2a44603532c79u64, 0x1c824078a255db85u64, 0x3f0240c657883306u64]),
-            f_from_words::<Bls12_381>([0x293bc4b0f1f56850u64, 0xb2ff8ea6460ddfbfu64, 0xf04047005e66f561u64, 0x5d0002d0187026c8u64]),
-            f_from_words::<Bls12_381>([0xb448482f00a5a4f0u64, 0x43519886e0b3aebfu64, 0xa5146911bcf51124u64, 0x264ddaf276780408u64]),
-            f_from_words::<Bls12_381>([0xa4d69b27ddab65f1u64, 0x7bf39c541ba767c7u64, 0xd0e28cb5680e2e00u64, 0x6b5c069c18237f3fu64]),
-            f_from_words::<Bls12_381>([0x1370c77bec6807d2u64, 0x789f34176abdacd5u64, 0xe726327ecf532d5fu64, 0x03492728d40921a8u64]),
-            f_from_words::<Bls12_381>([0x9791b14ff202e3c9u64, 0x184ebbb7003e96e7u64, 0x0cef47ae9d7b3e39u64, 0x1d666e18aef4997du64]),
-            f_from_words::<Bls12_381>([0x29ca5aeb8929c6f7u64, 0xb1c02c67cce9c8dcu64, 0x0ce2528921d22d96u64, 0x342e0ea91f3164a2u64]),
-            f_from_words::<Bls12_381>([0xb35858890a0e13f7u64, 0x6b1637728e527adcu64, 0xc17ca30ce906a695u64, 0x3cb3886d030370fbu64]),
-            f_from_words::<Bls12_381>([0xfd63a9b3e45d9711u64, 0x3e690d50007bd6a7u64, 0xe459e17af162d15fu64, 0x221efa994ca009c7u64]),
-            f_from_words::<Bls12_381>([0x44e7a341adfd7b1du64, 0xc98c1b3665012298u64, 0x7b88e6fee3a0224du64, 0x4dd59d4615f52e1bu64]),
-            f_from_words::<Bls12_381>([0x8f140c682d7ffd08u64, 0x553701fe6ffb27ddu64, 0xed88cb252346cb0cu64, 0x082300fe10d99afau64]),
-            f_from_words::<Bls12_381>([0x395d16fbc56c0091u64, 0xbfcc953649390edcu64, 0x94a3adfd04194549u64, 0x3b45edf81eda768bu64]),
-            f_from_words::<Bls12_381>([0x9eed39bff99c0aeeu64, 0x7490ba31f3cad871u64, 0xe4c0480bbb75a2c5u64, 0x203a8458978bc0cau64]),
-            f_from_words::<Bls12_381>([0xb85346afe1a4133du64, 0x7dded75094df8599u64, 0x18f6a2d225ad45a6u64, 0x5481d3f151a7825du64]),
-            f_from_words::<Bls12_381>([0x7f927aa3988e625du64, 0x77a5c8bae861af43u64, 0x8a7f8a1a022eaceau64, 0x72e38d6d1f1582ccu64]),
-            f_from_words::<Bls12_381>([0xe6d6b7e8ceb1795bu64, 0x333fde233311e6cau64, 0xfb4d37b5355c804bu64, 0x668051ccb3641a94u64]),
-            f_from_words::<Bls12_381>([0x4f285603eda045c1u64, 0x804a6df3826d7704u64, 0xc4c1f2b9b68da85bu64, 0x1c7860ec4238e50au64]),
-            f_from_words::<Bls12_381>([0xcc0b1fbe89259d57u64, 0xc8811459f7cc5180u64, 0xbbc75e813cefa428u64, 0x250a565e4cedccecu64]),
-            f_from_words::<Bls12_381>([0x407390dee848c538u64, 0x8089cf3493b620f8u64, 0x754e48d4a367df22u64, 0x5634e6c2be47c441u64]),
-            f_from_words::<Bls12_381>([0x66bfb82f0fb0f5f0u64, 0xb7e432f0e0336001u64, 0xfff9f490aed791e0u64, 0x5b813f654b94cc49u64]),
-            f_from_words::<Bls12_381>([0x98e9f97c757a76c2u64, 0xb0a4bb3fc1bbc2f0u64, 0xfa513938fd495da7u64, 0x56a526b046bf68ecu64]),
-            f_from_words::<Bls12_381>([0x667a2d5ce1777a53u64, 0x3dd63aefd735c50bu64, 0x144995ad290c7e95u64, 0x53935ea618e08471u64]),
-            f_from_words::<Bls12_381>([0x4883adbc9c62935au64, 0x19d56a896f3847a8u64, 0x0bd1c20564158c30u64, 0x238b166377ce22d9u64]),
-            f_from_words::<Bls12_381>([0x29cd33d59184ee0au64, 0x3795810bdfe77183u64, 0xff3e123533aa5357u64, 0x231dd0bbcc71e25du64]),
-            f_from_words::<Bls12_381>([0x18721b501d244592u64, 0x1ce9c8a8f4c3eee0u64, 0x46f6cfa55968b09au64, 0x265264d8d6feef6eu64]),
-            f_from_words::<Bls12_381>([0xd3a36b17db2c1febu64, 0xad14efaba20e9aa5u64, 0x09324b1d3c5ae135u64, 0x4f08d7961302c340u64]),
-            f_from_words::<Bls12_381>([0xf96273eb41eb0615u64, 0x3fef336db7431ad5u64, 0xd52058193e401712u64, 0x53545e7212db2936u64]),
-            f_from_words::<Bls12_381>([0x64f20fb4208ae695u64, 0xeecde35c36cc20d1u64, 0x2d7a851823587d17u64, 0x279445cd3a47517cu64]),
-            f_from_words::<Bls12_381>([0xe3746a8ae6442d7eu64, 0x8ecca6a137f727f5u64, 0xf3d2fd2b5931afb0u64, 0x2fa7faff72992cb5u64]),
-            f_from_words::<Bls12_381>([0x9ee3038c1d87c055u64, 0xb63f49d6f5b9f20au64, 0xdd683901681b0981u64, 0x5fea48142dc299b2u64]),
-            f_from_words::<Bls12_381>([0xf732844652b88e9eu64, 0xd7f4055d3aa9b831u64, 0x7c48f546bf85a74eu64, 0x18769a2a770a0aefu64]),
-            f_from_words::<Bls12_381>([0xd8600892041d6d0bu64, 0x1cd3c9e0c027f98eu64, 0x17f903190521031du64, 0x16224f944345445cu64]),
-            f_from_words::<Bls12_381>([0xc371c604b6e3117au64, 0x5091a3eb3516cd49u64, 0x0f18e2ffa5b01a26u64, 0x518abd8ec2f5660du64]),
-            f_from_words::<Bls12_381>([0xefdac7e3aaa6bf56u64, 0xc515c67276ef0296u64, 0xff67f3f866c2fcb9u64, 0x5ee342844f27e68eu64]),
-            f_from_words::<Bls12_381>([0x883756df7216d66eu64, 0x6631561cc95b07b5u64, 0xccf1f000d11820cfu64, 0x2e1fa2ebb64641eeu64]),
-            f_from_words::<Bls12_381>([0x0ae92b17f9db66b9u64, 0x9dc34d298a31c63fu64, 0x5a9ef6ba147e182cu64, 0x126cda3b8b7d155eu64]),
-            f_from_words::<Bls12_381>([0xf7ea5ac59510055cu64, 0xccf0eeffc025f91du64, 0xf3ed60870a96ce0eu64, 0x40268d3f8de14865u64]),
-            f_from_words::<Bls12_381>([0x5b4c6cc905403a41u64, 0x738bebc78af04c4cu64, 0x5bc6c8b1e0f39a1du64, 0x634a9f5657df7ea8u64]),
-            f_from_words::<Bls12_381>([0x0471b9b8f6b30405u64, 0xb9923edb1611c554u64, 0x5d8d83208e596253u64, 0x5b5b134c29bfbd0cu64]),
-            f_from_words::<Bls12_381>([0x84f73c4260a54543u64, 0x6dc4b56d751d8e58u64, 0x068a7f92eb1dc38cu64, 0x10fbb1c585fbe5a6u64]),
-            f_from_words::<Bls12_381>([0xbbf14377c7409f4du64, 0x6c1541dfa3ff1319u64, 0x9b4d6acba3dc19a8u64, 0x49af574a18164767u64]),
-            f_from_words::<Bls12_381>([0xeac5117fb1ea9edeu64, 0x8ba699f17d241981u64, 0x7c28f22af07591a6u64, 0x59d74c8f9b0e3c69u64]),
-            f_from_words::<Bls12_381>([0xe28ae6b6d0b236acu64, 0x05c8d99059d03cc5u64, 0x4dd4ff44aee9ec00u64, 0x039ae722a22558d0u64]),
-            f_from_words::<Bls12_381>([0x7e02aa376f36febau64, 0xdcba6f3bf982ac2au64, 0x101e8a1f0a940a9au64, 0x081bba19cdaea321u64]),
-            f_from_words::<Bls12_381>([0xfeaf2667e601be0cu64, 0xe8fc7462e7f5c34du64, 0xfcfb84a892d9a482u64, 0x5c904fb2fb99cb29u64]),
-            f_from_words::<Bls12_381>([0xdce14028d30acc81u64, 0xd314895b7669243du64, 0x830aa9644550821eu64, 0x5c96dd20f3162825u64]),
-            f_from_words::<Bls12_381>([0x6ae92d7d3eac8406u64, 0x213a02707fbd040du64, 0x4b269f49b8d0fbabu64, 0x17ae04afdbcd7e4cu64]),
-            f_from_words::<Bls12_381>([0xedc572f683efb4f0u64, 0x497a5b356152002eu64, 0x8ad784a73ddc0d44u64, 0x40412c55450c577bu64]),
-            f_from_words::<Bls12_381>([0x50a6208944aca9c5u64, 0x068cb0330ece1ff5u64, 0xdc4cf5bd88cda494u64, 0x0e5f5a9b5f52a7b1u64]),
-            f_from_words::<Bls12_381>([0x07da96210eacbc73u64, 0x8baed42a1c03ebb0u64, 0xf261cf1e1db30404u64, 0x3ab9fdf491696f61u64]),
-            f_from_words::<Bls12_381>([0xcc5829a099b25875u64, 0x59c744cf920a4821u64, 0xbaefcdb9ffce0c94u64, 0x626bc5494aba46b0u64]),
-            f_from_words::<Bls12_381>([0x61b9e6955c0e4170u64, 0x8949bed11b7c654au64, 0x7223874225179421u64, 0x3a990f8e4f557158u64]),
-            f_from_words::<Bls12_381>([0x38ac984bd2a2c75du64, 0x1a41397cb6e25b02u64, 0x07546391a4af3c79u64, 0x078c7efd3495ba0cu64]),
-            f_from_words::<Bls12_381>([0xdb131b122172fe6cu64, 0x87d3eaea10f5dfe8u64, 0x3a09b255ec100f38u64, 0x22098d7a77e8a4c6u64]),
-            f_from_words::<Bls12_381>([0xc97d2c2f862746dfu64, 0x736bb299d9b551beu64, 0x67c5c8eec0e61687u64, 0x36edaad0f1e8172cu64]),
-            f_from_words::<Bls12_381>([0x04e634b58be96a5cu64, 0x888a87d133a14edeu64, 0x01c52716dd7161e7u64, 0x3a908d99fdff75f7u64]),
-            f_from_words::<Bls12_381>([0x91c83a194c582fe8u64, 0x3e30b315b0d710edu64, 0x2529ad02bbaabb0cu64, 0x4f0325db25747aacu64]),
-            f_from_words::<Bls12_381>([0xc3e2aa8209bbe726u64, 0x710774f5db84f95eu64, 0x71363194a2ed147du64, 0x1baece6dc7daa4dcu64]),
-            f_from_words::<Bls12_381>([0xbe7193191f5a485du64, 0x69ea67f34a2c44afu64, 0xd9dd201d2fd00581u64, 0x083a959d7c4bc8b1u64]),
-            f_from_words::<Bls12_381>([0x427c0138adc004b6u64, 0x55724b7daf331c53u64, 0xc75e52bd9b0a4c9cu64, 0x4c93ed1dba90f4c0u64]),
-            f_from_words::<Bls12_381>([0xbc6bc5ad5218b566u64, 0x22ebf1145a0d9d10u64, 0x85ca5cdfa23324e9u64, 0x26c40fc5f81c0531u64]),
-            f_from_words::<Bls12_381>([0x4d3aaa5d075362c8u64, 0xdb6cb97a2351ca95u64, 0xda5de6950f0364c4u64, 0x138faeeae1a3b7beu64]),
-            f_from_words::<Bls12_381>([0xedd0a70a09ac9621u64, 0x2e82a4c8c8cc5295u64, 0x3a8e3205080df192u64, 0x126eef869626bf31u64]),
-            f_from_words::<Bls12_381>([0x522bd0a5a43d3545u64, 0x5a00184f58a1aafau64, 0x97cad53d97111cbdu64, 0x1d856d9db31ace9au64]),
-            f_from_words::<Bls12_381>([0xfe0c6ec0caffe7dbu64, 0x8d2320dc90e53001u64, 0x5b6bec0856b86fc8u64, 0x5ae7a058d86c1ab0u64]),
-            f_from_words::<Bls12_381>([0xa1b0ce446c3894e2u64, 0x327960a17c86bee7u64, 0x5ad3c0ad216de8bdu64, 0x0367b77e2bb701ffu64]),
-            f_from_words::<Bls12_381>([0x0b20f79d58165eddu64, 0x4e6aae9cea3a771du64, 0x6333c1d582b2ee9fu64, 0x5ebc8873c7393820u64]),
-            f_from_words::<Bls12_381>([0x79f77a3ca3a00633u64, 0x88b9c94191f57b82u64, 0xadf0afa6a8ca2b0eu64, 0x713388d6fdd7c409u64]),
-            f_from_words::<Bls12_381>([0x02e6887261b49e39u64, 0x7de863494688ea03u64, 0xfd0ce01a2f257284u64, 0x1797b6984df87f4cu64]),
-            f_from_words::<Bls12_381>([0xb9987e0708eafc6fu64, 0x4827dc7c1d0ac31cu64, 0xad6cf90e0fd67008u64, 0x2b64f5848a17f9bau64]),
-            f_from_words::<Bls12_381>([0x0356a87d9573b602u64, 0x39e6cf63c79ed525u64, 0xee3ff85e81a806d7u64, 0x160c3bf86d2c9914u64]),
-            f_from_words::<Bls12_381>([0x375bc38697a202e6u64, 0xcfbe6797233e2b90u64, 0x5ccf26b97f9a302au64, 0x30faca058477c1ddu64]),
-            f_from_words::<Bls12_381>([0xf5825f943d412b4bu64, 0x9f47d546f4cd2964u64, 0xa5a482b7d600dd66u64, 0x6646182ee7973e7fu64]),
-            f_from_words::<Bls12_381>([0x33e9d3b33c5f67a2u64, 0x95b9ab10619223ebu64, 0x32eb7464fb5831a7u64, 0x5ce8d7fa31b18763u64]),
-            f_from_words::<Bls12_381>([0xc693118e7c3ea832u64, 0x8780089d50431595u64, 0x03a284c7f3c2562cu64, 0x63b2d8def84ec443u64]),
-            f_from_words::<Bls12_381>([0x4bfb9ebaba109d7bu64, 0xec05e5838fdaabbbu64, 0x937e4c7da9b22204u64, 0x49dd7e14ed383389u64]),
-            f_from_words::<Bls12_381>([0xf16bd6c0b60c6027u64, 0x13306a6b1668ef93u64, 0x015b61933daf309fu64, 0x3fe0452193cfdf5au64]),
-            f_from_words::<Bls12_381>([0x1855e78572fca2b6u64, 0x4f39db3dbad9073fu64, 0x883fae472bd41a4eu64, 0x3c9b2a48f23243cdu64]),
-            f_from_words::<Bls12_381>([0x84ac178abc72ef0eu64, 0x8bf1cda2c8183024u64, 0x296cc65f37bc3ec8u64, 0x0df38399ea5fe3a0u64]),
-            f_from_words::<Bls12_381>([0x359cc39b316e6ac4u64, 0x58c082ada2fb19eau64, 0x4d6f1a8ef4d99589u64, 0x51d0d3e9fcf31ffbu64]),
-            f_from_words::<Bls12_381>([0xf61ff17642856f62u64, 0x5c5034100f5ac45bu64, 0x9a58f125a4b6ed59u64, 0x43d5b423e990bd65u64]),
-            f_from_words::<Bls12_381>([0x59cc365e249efc3cu64, 0xa36859b4b1dab297u64, 0xd9b83754bce01410u64, 0x42119357f852439cu64]),
-            f_from_words::<Bls12_381>([0xd647db53118bfb4fu64, 0x3f6db95b0bcc906bu64, 0xabf0162bdc0a12ccu64, 0x6c4f5ceda2c3302au64]),
-            f_from_words::<Bls12_381>([0xd71a35e65af4081fu64, 0xcacb30000331b32au64, 0xebfb5ca35a060b84u64, 0x2de1972fe5c69971u64]),
-            f_from_words::<Bls12_381>([0xe80470a6b1133a72u64, 0xe7d7856bb89b30e8u64, 0x957641b9d27260aeu64, 0x0507f161a5aa4385u64]),
-            f_from_words::<Bls12_381>([0xb50f8c90488c00c1u64, 0x138a8594bbfe3886u64, 0x06e8d68044e2ace8u64, 0x3843ced15554247au64]),
-            f_from_words::<Bls12_381>([0xdefeb149539808bcu64, 0x71331ced6867bd5au64, 0xe03f2530ac2cc6e8u64, 0x41878ac4fb7ea1d2u64]),
-            f_from_words::<Bls12_381>([0x55edf6679e15dd1bu64, 0xc7097707bef46f97u64, 0xd177e3970fd7b17au64, 0x4d728de3d3756612u64]),
-            f_from_words::<Bls12_381>([0x03c663d24fc19170u64, 0x4519962060a6ac8au64, 0x84b839231418efb9u64, 0x4cddfeda8319b279u64]),
-            f_from_words::<Bls12_381>([0x19dd402d55a0f8f0u64, 0x3edd4bdd4bd56f6au64, 0x3df0c2a5025997e5u64, 0x206a75863850ed9du64]),
-            f_from_words::<Bls12_381>([0xb38c941a76306993u64, 0x3fa6cde6d3242cf1u64, 0xa3683e921b8b705fu64, 0x6cd617fd47b19deeu64]),
-            f_from_words::<Bls12_381>([0xa68b1e5fde3f338bu64, 0x6206c73240356f89u64, 0xded2e52fc16afb34u64, 0x4fece59a7d826afcu64]),
-            f_from_words::<Bls12_381>([0xcc22fbefca85439au64, 0xe0f38518cf240d3fu64, 0x675bc38eed721f6cu64, 0x6c68d67656b5eb38u64]),
-            f_from_words::<Bls12_381>([0xde14a6f35add8368u64, 0x1ed4866388f9b9e8u64, 0x5d0c3c28bd0b54c0u64, 0x61869987afc4a7f7u64]),
-            f_from_words::<Bls12_381>([0x851b899d3b6d0f18u64, 0x9b26216a525bad1bu64, 0x8dce27716754b81au64, 0x08360fdfa77f9662u64]),
-            f_from_words::<Bls12_381>([0xe9d14591596cd949u64, 0x0eda48de07176badu64, 0x713c7d4944a38e0cu64, 0x4bced791d1537e27u64]),
-            f_from_words::<Bls12_381>([0xbb0dcaa8658ccc29u64, 0x6a4a68cd9e684627u64, 0xe002e9c322e0388eu64, 0x0b1deff574623f49u64]),
-            f_from_words::<Bls12_381>([0x93194f800035d572u64, 0x58358ffb12c27dd4u64, 0x6c5dda2ec9c796d7u64, 0x1b62d75065682cacu64]),
-            f_from_words::<Bls12_381>([0x25865113e5c171fau64, 0x46558bc804da007cu64, 0xa86e3cbeb9278805u64, 0x14843638c61fa30du64]),
-            f_from_words::<Bls12_381>([0x1e72a2468d87c00au64, 0xae740e8718facf02u64, 0x20b6c6bbd73de09cu64, 0x457a3f7b619e2948u64]),
-            f_from_words::<Bls12_381>([0xfbfdaed3bbea4bd7u64, 0xe7ef5472728b9132u64, 0x4d1061a6f5d4b2ebu64, 0x0d30e44c7da45356u64]),
-            f_from_words::<Bls12_381>([0xdaa06b2234ca48b4u64, 0x7da0198484fba4a5u64, 0xba0d1c18bf313e94u64, 0x0a942b73f0ca24a9u64]),
-            f_from_words::<Bls12_381>([0xa23dfcdd0101d7efu64, 0x5bdeefe16243fbf9u64, 0x37cfff577f10c871u64, 0x6fcb820133c88b80u64]),
-            f_from_words::<Bls12_381>([0x647bc281bf386eb0u64, 0x517906557975f29du64, 0xffbabd9f57711296u64, 0x1648b8cd351cb578u64]),
-            f_from_words::<Bls12_381>([0x8c1c579ecad4a640u64, 0xf8cd3347d61fc0d4u64, 0x2a42142a6dd70e46u64, 0x24e5685d05633d5bu64]),
-            f_from_words::<Bls12_381>([0x498574fac17b2694u64, 0xadf7d4b26b393ddeu64, 0x9a1490df52cc5c0du64, 0x13396645b36a6e83u64]),
-            f_from_words::<Bls12_381>([0xd5cc51e377b5b0bfu64, 0xe54b3dcfa19bcd83u64, 0x11cc7ecfb71f2c2au64, 0x6d968e6dd9c8c337u64]),
-            f_from_words::<Bls12_381>([0xfdc4b097a2bb7378u64, 0xdaf40036c5021536u64, 0xd1c26afb5479d07du64, 0x0a71f7749497831fu64]),
-            f_from_words::<Bls12_381>([0x1b7864a3b050dbceu64, 0x2808154d8c05e320u64, 0xaf915e441c417a89u64, 0x468d9bfb440842b0u64]),
-            f_from_words::<Bls12_381>([0xcd09c3caed181123u64, 0xe60ed2300c883bddu64, 0x9fa6e947d60b2d21u64, 0x6b43746deaf8dd1eu64]),
-            f_from_words::<Bls12_381>([0xef25e66ec64fc5b6u64, 0x3219724405a40b60u64, 0x3d930c55cc7c04a4u64, 0x0ae4b4142a984ddeu64]),
-            f_from_words::<Bls12_381>([0xe09e8c858773d5b0u64, 0x45fea8d273920c48u64, 0x2ef96bb4f2c86aaeu64, 0x1ec8ca4dac1298b6u64]),
-            f_from_words::<Bls12_381>([0xaa0eff355f4bd01cu64, 0x133665f50c7ee99cu64, 0xcf02c6af6073cfdeu64, 0x20bbd6f3b5e1e400u64]),
-            f_from_words::<Bls12_381>([0xca95fe80cbace07eu64, 0xfbc007b63b4244f7u64, 0xbb15de68e70c6598u64, 0x31e4e117dbee5b82u64]),
-            f_from_words::<Bls12_381>([0x5c966bad955bed4cu64, 0xa44a284805cc091au64, 0x7c27ad047133d747u64, 0x3969a93294a78fcbu64]),
-            f_from_words::<Bls12_381>([0x6f336b7c8e2755f6u64, 0x43cb3669067f581eu64, 0x00240015f6275a96u64, 0x193daabf1332d0c6u64]),
-            f_from_words::<Bls12_381>([0x4f6f56113b654e42u64, 0x84fe53037a98bf12u64, 0x48553f12dc05a746u64, 0x1547f6ee02f183e0u64]),
-            f_from_words::<Bls12_381>([0x6a7ad926329f0ff7u64, 0xc8393325f16958e2u64, 0x1caf20f1f5a97b5eu64, 0x2a11159a230dd46du64]),
-            f_from_words::<Bls12_381>([0xcf54f080b9709811u64, 0x6f85524e054b0106u64, 0xee95c253a5debd9fu64, 0x626cebcf93897a53u64]),
-            f_from_words::<Bls12_381>([0xe1f7421d3fc3a480u64, 0xa9795cfcf3000d81u64, 0x5f7c38daf8b0fe8cu64, 0x0c907ac186ea501au64]),
-            f_from_words::<Bls12_381>([0x862f974aef544c27u64, 0x87c8d4d3ff3040a0u64, 0x04c3ebafcca1e8bcu64, 0x61c1fe3f9b118dcbu64]),
-            f_from_words::<Bls12_381>([0x2db8c12530202853u64, 0xd899bd90f34315fbu64, 0x6d8c2bba883ae189u64, 0x147f881ebaf30c66u64]),
-            f_from_words::<Bls12_381>([0x46b9fa741408e5fcu64, 0x5c69345a73bc3592u64, 0x07d9c45a14682372u64, 0x1fb3927774bc055eu64]),
-            f_from_words::<Bls12_381>([0x221cd54c274cd887u64, 0x9aed279e67765044u64, 0x0ec25f42fd0c52bfu64, 0x59ff0e275dc0ed92u64]),
-            f_from_words::<Bls12_381>([0x4f71a943808477fbu64, 0xb57957d6e3a0c8d4u64, 0xf2d5166507f7c2a6u64, 0x446e89c3b7d8e2beu64]),
-            f_from_words::<Bls12_381>([0x380456afd4fe68dbu64, 0x9ea868c52cfe5976u64, 0xaf7b3887c4756253u64, 0x6e6c94b13221dbe4u64]),
-            f_from_words::<Bls12_381>([0x0b4fc8181d70d707u64, 0x85d34ed2833e6f9fu64, 0x88245f86dc8ba89cu64, 0x181696a8600961e7u64]),
-            f_from_words::<Bls12_381>([0xa2b616e25ecf677eu64, 0x37a1491bb9200125u64, 0x4ca96b6d78a3fcb0u64, 0x0ff15168d97cc3f0u64]),
-            f_from_words::<Bls12_381>([0xde9df3b3c3387f4fu64, 0x0aaef79547c5b7f9u64, 0x06bbd5319cbfb776u64, 0x3f709c5c038fcd66u64]),
-            f_from_words::<Bls12_381>([0x7d593a6f080b8053u64, 0xdd78e185d85e4df2u64, 0xa755a6293b8d2a8du64, 0x5e0b8c0bb4da2437u64]),
-            f_from_words::<Bls12_381>([0x4cc4036631761a00u64, 0x1dedae19332fcda5u64, 0x149c5787728c0599u64, 0x161b80d5bef32f9du64]),
-            f_from_words::<Bls12_381>([0x622f62f4f84a9e12u64, 0x39cfbefe79e1fa77u64, 0xa5fad9c7f428be76u64, 0x62f1b34ade9eb68fu64]),
-            f_from_words::<Bls12_381>([0x19bfae5cc0763c85u64, 0xed2e222fbdd7f2eau64, 0x5910c308a1dfc4cdu64, 0x0543c3a26f0818fdu64]),
-            f_from_words::<Bls12_381>([0x1b59330d1bcac86cu64, 0xfb0b340d63c9da2fu64, 0x2322ba6f22fe0cddu64, 0x0640b896714a26bcu64]),
-            f_from_words::<Bls12_381>([0x3a54f7d6d003933fu64, 0xf74752a28b80ea5du64, 0x29a38a025d005146u64, 0x4e6239185c2beed1u64]),
-            f_from_words::<Bls12_381>([0x00d53a8611fd228cu64, 0x3f85b36c5a371d6cu64, 0x2177c41f359814b3u64, 0x0687be0c802aec29u64]),
-            f_from_words::<Bls12_381>([0x7f7d256d108914abu64, 0xdf0182f0dd9e797du64, 0x53ac4833bde98233u64, 0x040456dfb528019cu64]),
-            f_from_words::<Bls12_381>([0x4bb93b4e2362cdd0u64, 0xa3edec7fcd56a898u64, 0x2cc13f4a242b3c14u64, 0x6ce2d6e0f63dff1eu64]),
-            f_from_words::<Bls12_381>([0xabcc1d6d32d49e58u64, 0x8e33bd8777fd093au64, 0xd142c2f250eb782fu64, 0x5fc28ca5b1c9d370u64]),
-            f_from_words::<Bls12_381>([0xf1df5973876bc4feu64, 0x61b8dd99691f5639u64, 0x1be8e70bee156a29u64, 0x72ac866dc1ee755bu64]),
-            f_from_words::<Bls12_381>([0xcd38324fcc75f8efu64, 0xcd8ac3cd8de3f658u64, 0x93da2328c76309a3u64, 0x4f0dad9ac86de2c4u64]),
-            f_from_words::<Bls12_381>([0x5ba07422371a3976u64, 0xc91ece436622510cu64, 0x819462dbc5b2b84du64, 0x065ab12e01348544u64]),
-            f_from_words::<Bls12_381>([0xe72a0c2d4995570cu64, 0x06ca2d5a2b1481f4u64, 0x06749a70bea76617u64, 0x289d450e278b602bu64]),
-            f_from_words::<Bls12_381>([0xacc82c3df6dc6155u64, 0xf9588d0682521b87u64, 0x09ae280858e78a6eu64, 0x4f00b4fbc5aea65du64]),
-            f_from_words::<Bls12_381>([0x6a27924fde27eb80u64, 0xe364edd9e1566f91u64, 0xe0ced1e4223b65b5u64, 0x62e72251e22dbf3cu64]),
-            f_from_words::<Bls12_381>([0x5c6de03c26a331ddu64, 0x920cf57341a5f95eu64, 0xce659266d56c922fu64, 0x6b793a0e59eb1e8du64]),
-            f_from_words::<Bls12_381>([0xa765e62f0353240bu64, 0x8c0650809df949dau64, 0x0db8aa31f79127eeu64, 0x1a1000b6d1edd3cau64]),
-            f_from_words::<Bls12_381>([0x33034945b624669cu64, 0x8a9983ba346f7e0cu64, 0x1670a5f3a4d53a03u64, 0x0fba568f66d8e167u64]),
-            f_from_words::<Bls12_381>([0xf0f4a6076a67d1ccu64, 0xffc2f3ff764b732eu64, 0x225568788eca6c92u64, 0x21ea6de996086d4fu64]),
-            f_from_words::<Bls12_381>([0x0194dfd8824c4b04u64, 0x24b69aa0e61cbcd9u64, 0x5c6c739c4fc3445bu64, 0x26b6c66c4d6ca831u64]),
-            f_from_words::<Bls12_381>([0x24bae42efadfeeadu64, 0x745c6b8c6b1bc91au64, 0xeb9bdacf3cefaf3du64, 0x5e03538f5af3c829u64]),
-            f_from_words::<Bls12_381>([0x2ca169b30f13403du64, 0xe34de77b7df531d4u64, 0x31ec83234ddbd3dcu64, 0x2212ccfa28a07c7eu64]),
-            f_from_words::<Bls12_381>([0xf4cf25838b4a3397u64, 0x63cf7559a125009bu64, 0xafc8e2a4676744ceu64, 0x4ea56263e2245bb4u64]),
-            f_from_words::<Bls12_381>([0x6f86c6db18aa2fb3u64, 0xbdfa7f4e9632d8f3u64, 0x287b3a604cb62f73u64, 0x618f3f5b4429d09au64]),
-            f_from_words::<Bls12_381>([0x736b14ecc1cbcc64u64, 0xcc737ed9e3723c4eu64, 0x49ea2b5c8d418aa3u64, 0x5bd0f2efa5e68220u64]),
-            f_from_words::<Bls12_381>([0x0291edd2102c5d55u64, 0x6f22ba0b53bc8ca5u64, 0x756785bfce08904fu64, 0x5d1003d1b1207c39u64]),
-            f_from_words::<Bls12_381>([0xeb3c2104bc045f16u64, 0x23d92e672bf3941au64, 0xa493f4c071d536c7u64, 0x562bc2d9ca513dd5u64]),
-            f_from_words::<Bls12_381>([0xaeeaaedcc1a5cfc9u64, 0x23a8bb18b1f50041u64, 0x746cda44475238b8u64, 0x6387bdf84155aea2u64]),
-            f_from_words::<Bls12_381>([0x783e85f709714a02u64, 0x1c4d43fbcf2d8f63u64, 0xe84c3e8876aa3d41u64, 0x260a6a3c546904d4u64]),
-            f_from_words::<Bls12_381>([0xed162596b33fb323u64, 0xb0b3dfc7d33495a2u64, 0xe0f17a2ad550894au64, 0x59db4a19dbcb1596u64]),
-            f_from_words::<Bls12_381>([0x5e86c94c67cc8b8bu64, 0x9f65d1da15c03cceu64, 0xa6c94a1af16c370au64, 0x71b617e69c35c650u64]),
-            f_from_words::<Bls12_381>([0x602ad252a6b9adcau64, 0xb56d15043edfaf3au64, 0xfe7f5fd4eed011b6u64, 0x4ca9a5b15f13a55du64]),
-            f_from_words::<Bls12_381>([0xdd13550325701942u64, 0x7e89697f60ca1370u64, 0xbbaff1d12b98072cu64, 0x25d3a859d22c2501u64]),
-            f_from_words::<Bls12_381>([0x311635bdfb654838u64, 0x7e05c0238d99e8bfu64, 0x7135b6d2f08dbb31u64, 0x45d22349a095d2f7u64]),
-            f_from_words::<Bls12_381>([0x056404a2102e83e3u64, 0xeeb35e82a6570485u64, 0x95cb9cb255d539c6u64, 0x32d223ed410dac4cu64]),
-            f_from_words::<Bls12_381>([0x213b24b7822e3d6cu64, 0x757feec7ebe7ba19u64, 0x88e52b61098fb045u64, 0x4f5c76026014f848u64]),
-            f_from_words::<Bls12_381>([0xe727f0141d60d7b2u64, 0x12c434ce7d7e325au64, 0x9cd2f09268f51228u64, 0x728a6be59c8d97fdu64]),
-            f_from_words::<Bls12_381>([0x7cda950c12eff1deu64, 0x7ba9f1a2ac0efcf7u64, 0x165e64802a53de96u64, 0x5f89ae6cb9d476adu64]),
-            f_from_words::<Bls12_381>([0xa8e43f8a9aad1b18u64, 0x843c74fbce5ce20fu64, 0xa36a0587ec576a41u64, 0x71f486796048d3e1u64]),
-            f_from_words::<Bls12_381>([0x03b597d9a68f185cu64, 0xf775dcd791bc4a44u64, 0x36117ed4fd7c4b66u64, 0x66923275f9ee6f78u64]),
-            f_from_words::<Bls12_381>([0xf168484a62af817bu64, 0xf878af780b2c0f3cu64, 0xd309b2f8fd3ab428u64, 0x16f8bf77e5aab477u64]),
-            f_from_words::<Bls12_381>([0x1669c0b083c83c2du64, 0xce29fd865432bcf4u64, 0x33c8e586eb008980u64, 0x47cdf46dc27583d2u64]),
-            f_from_words::<Bls12_381>([0xdf0fb3b6023104cau64, 0x210ae33aa374b410u64, 0x5ecb133a471aaeb4u64, 0x3e4291d40fb0a428u64]),
-            f_from_words::<Bls12_381>([0x3b670a97652f6404u64, 0xb349d8eebe03c142u64, 0x5f085ff4e5b5bb0cu64, 0x3b89d27b4e033758u64]),
-            f_from_words::<Bls12_381>([0x0532ad5675a3183cu64, 0x8051f10318ea4c83u64, 0x012fb588dc3e4f44u64, 0x6fd510328c426f90u64]),
-            f_from_words::<Bls12_381>([0x7cf74550bbee6a51u64, 0x6d8a491fecc24844u64, 0x800da9fd20e4c638u64, 0x0d030fe9846f4076u64]),
-            f_from_words::<Bls12_381>([0x25041368fa8a6fd6u64, 0x813e31380ee173f5u64, 0xd0e0c39c3ced73d3u64, 0x3800859c22962bf3u64]),
-            f_from_words::<Bls12_381>([0x8910714a1cce2200u64, 0x23a12ded0a93bc86u64, 0x5659f895c8f5640bu64, 0x5262422a17f843f7u64]),
-            f_from_words::<Bls12_381>([0x24a9e1709e6a7279u64, 0x418f99ae7f13a649u64, 0xcdf8e8d5f73572fau64, 0x08a2adc4b12e823au64]),
-            f_from_words::<Bls12_381>([0x0c7aab5693d7f2c9u64, 0xff341a1bcff0ee2eu64, 0xb0c6bbb57527f4d4u64, 0x615e079ba848ab3cu64]),
-            f_from_words::<Bls12_381>([0xd0d16170999ed8afu64, 0x38f0cf94249e8e87u64, 0x7d244472aabab41fu64, 0x40573ec740e425f4u64]),
-            f_from_words::<Bls12_381>([0x5457a087a53c5c86u64, 0xd491b62cc77e1842u64, 0x4f074f6fdf15c688u64, 0x2caf3dfbbdf05f65u64]),
-            f_from_words::<Bls12_381>([0xf8684013de375faau64, 0xad0ba92a27a1e761u64, 0x953e5acdbd2ba5ebu64, 0x319459ca3c0b5785u64]),
-            f_from_words::<Bls12_381>([0xef5429a893750558u64, 0xfbb0817bfa6acf9bu64, 0xce572c1d85bb386fu64, 0x07be600714abce5au64]),
-            f_from_words::<Bls12_381>([0x3c97bba81fff7a30u64, 0x2cdc46e1541191ecu64, 0xfab72bbd56b54f18u64, 0x42060cebd02c0fe4u64]),
-            f_from_words::<Bls12_381>([0x96832a64a236845au64, 0x6c803d9222147410u64, 0xafa5850226d658e1u64, 0x134fa299407f350bu64]),
-            f_from_words::<Bls12_381>([0x828a06e034b5f088u64, 0x79df020683fba670u64, 0x84fed3cf35c823cbu64, 0x203bb96ef0a42853u64]),
-            f_from_words::<Bls12_381>([0xc3026f19447e0593u64, 0xe9c34a5603f0937au64, 0x00d82fc8d9b8dfe3u64, 0x551f9b641cae00ceu64]),
-            f_from_words::<Bls12_381>([0xdc5ee2b30a0f1e50u64, 0xec93aac26b2ef1beu64, 0xeaf3157f67052c8fu64, 0x11eb97761cd201d3u64]),
-            f_from_words::<Bls12_381>([0x4febd5d3217d642cu64, 0x0bcc91af6244ca2bu64, 0x4c43aa9f986cf826u64, 0x04be8a8374c84de0u64]),
-            f_from_words::<Bls12_381>([0x59de649940c1ccfdu64, 0x6534005f5a4d8798u64, 0x9a240535cee1b81bu64, 0x15673d0a8a89a448u64]),
-            f_from_words::<Bls12_381>([0x791209a9b7b2292cu64, 0x4a356f034727802cu64, 0xc00321613c23366bu64, 0x288a00d85a0aac4bu64]),
-            f_from_words::<Bls12_381>([0xd394d650170f62f2u64, 0x8d672050a556161eu64, 0x532a1afba13fcfb6u64, 0x59bfe29ca57168d9u64]),
-            f_from_words::<Bls12_381>([0xe9d06a01802e95b7u64, 0xc50ec71eb39aa956u64, 0xe732cdc409731f0eu64, 0x547579c9b2c51d35u64]),
-            f_from_words::<Bls12_381>([0xcb93691a63b659dau64, 0x18e7552d4083a62au64, 0x3975ed5510862f4du64, 0x1397f693aeee1c42u64]),
-            f_from_words::<Bls12_381>([0x10a9768a047d6a54u64, 0x0b60566241a11d53u64, 0x1a4c6b1f1e71d8ffu64, 0x34d286e5833555d6u64]),
-            f_from_words::<Bls12_381>([0xf88a279d405148b7u64, 0xf82920d42f42dc39u64, 0x2dd5c4da6bc44c56u64, 0x39de70390eb1984du64]),
-            f_from_words::<Bls12_381>([0xdd5b6f7fe0a54fe9u64, 0x21ff5a4b35567fcfu64, 0x854612ccf9794e5du64, 0x3a52c703183da4ddu64]),
-            f_from_words::<Bls12_381>([0x2bbd81fe756a14bau64, 0x3ca51705ccd3d1b8u64, 0xbcc8595d8eb94eceu64, 0x27b3eb410ed620b1u64]),
-            f_from_words::<Bls12_381>([0xffd84cd35f38e67fu64, 0xdf06b3b67b196e60u64, 0x5c32f8dab59465e5u64, 0x2e4aa67e147add82u64]),
-            f_from_words::<Bls12_381>([0x13fb01cf8973a1d5u64, 0x1a0dfb2bec63c5f4u64, 0x453031e0e640e18au64, 0x1fa9b0443aaf33bau64]),
-            f_from_words::<Bls12_381>([0x9c4fcbce9f0e74acu64, 0xae41af167d368c0au64, 0x4dc56696dc653c1eu64, 0x090d3146b29e2820u64]),
-            f_from_words::<Bls12_381>([0x67e6cbf0b1ec2996u64, 0xc4453d50a7b30ad0u64, 0x7f9df2f11666c6c6u64, 0x31e5211d840d3cfdu64]),
-            f_from_words::<Bls12_381>([0x37aaa3ad1ad10d2au64, 0x1608f3e1013e8e8eu64, 0xd2b547255f7f1d16u64, 0x0e34943066bf60b1u64]),
-            f_from_words::<Bls12_381>([0x6910ee4fcfb52e9bu64, 0x9248bd8bd34fe6bbu64, 0x347a6a95923ddd95u64, 0x4b39dfe7d2123a0cu64]),
-            f_from_words::<Bls12_381>([0x7b44b9f1f252dbe8u64, 0x3bf4f960375ad415u64, 0x3feef3437381bef8u64, 0x3212f85007c2b3d9u64]),
-            f_from_words::<Bls12_381>([0x29bec36a1d878f69u64, 0xc3a43097e282b47fu64, 0xa0076406ca695629u64, 0x3d20f6ae6bd855c4u64]),
-            f_from_words::<Bls12_381>([0xbed49ecc9d1ddad8u64, 0x9d98cb527d781570u64, 0xfb702c61e477b51fu64, 0x49bef2fee3d08f75u64]),
-            f_from_words::<Bls12_381>([0xbb078d084695326bu64, 0x0e0fbaf09325db1au64, 0x5b7f445365cdac88u64, 0x6a2df866d7ad8427u64]),
-            f_from_words::<Bls12_381>([0xa6217a24d9f6f363u64, 0xa7dc160c0162da8du64, 0x2804431dc8bccdc7u64, 0x0a3b9657bea8bc48u64]),
-            f_from_words::<Bls12_381>([0xb71a72c58e98c79bu64, 0xaaad4ccee79075d6u64, 0x347a11e048add344u64, 0x1da6cb82e3508a73u64]),
-            f_from_words::<Bls12_381>([0xea9d63130cfa6644u64, 0x9f4d61fff6b48008u64, 0xb1d7ad1424085c0eu64, 0x262db1d59402e576u64]),
-            f_from_words::<Bls12_381>([0x41153601998f6958u64, 0x2344a4b22aa879bau64, 0x2da5c4a41c4fbca0u64, 0x014830d169614601u64]),
-            f_from_words::<Bls12_381>([0xfa2a56b92734fcacu64, 0x05c88851a5d5aebfu64, 0x2a78b85f369b7713u64, 0x04e588c570ac67d5u64]),
-            f_from_words::<Bls12_381>([0x766d8231691aeaa0u64, 0xe5134360a8a17b53u64, 0xf30dccb410cda8b4u64, 0x0d92bdae3497901eu64]),
-            f_from_words::<Bls12_381>([0x8f9719d7d6d56a03u64, 0x68f6117326f957efu64, 0x767e01669ed50f16u64, 0x5e5cdcfc215795ebu64]),
-            f_from_words::<Bls12_381>([0x5f52987476b455dau64, 0x32517ba6d7dc02ccu64, 0x3281fc536e651eebu64, 0x34a14bdd76b65308u64]),
-            f_from_words::<Bls12_381>([0x90b05e9725ada58fu64, 0x4fa5c34f4c88bed6u64, 0x2456545de19f303eu64, 0x43f5814b0a020f0cu64]),
-            f_from_words::<Bls12_381>([0x74e1fe6357d844c4u64, 0x974aa7ddb9a69714u64, 0x206034cb33a666d8u64, 0x18d7965f6a4d304fu64]),
-            f_from_words::<Bls12_381>([0x8a00139dc88ce8c1u64, 0xde44a170060a2e3cu64, 0xeee6a08cef582c9cu64, 0x4f4fb61f53d76dbau64]),
-            f_from_words::<Bls12_381>([0x4b7ee5ea10f3670bu64, 0xbeb15bd36db13c9bu64, 0x03074e8790eb5669u64, 0x59f3c8f269f0f29eu64]),
-            f_from_words::<Bls12_381>([0xf1f4782132e3c151u64, 0xe56f84a3f41a3c26u64, 0xf44cc88b3abe9d8au64, 0x6005e5b21bce1b40u64]),
-            f_from_words::<Bls12_381>([0x2b8a7f114536bd62u64, 0x6eb011b8ffaf1f94u64, 0xa149513d08c51c0cu64, 0x59e02dbc87e9cca1u64]),
-            f_from_words::<Bls12_381>([0x808ff943781e948bu64, 0x9b902b3ef26c5e43u64, 0x54f68f7bb7eaf06cu64, 0x3100da5caaabd59fu64]),
-            f_from_words::<Bls12_381>([0x0ff6e5e1d06b83fdu64, 0x47f2baa72f14a211u64, 0x475be93175e3ff72u64, 0x1361a7d063ba6b42u64]),
-            f_from_words::<Bls12_381>([0xb16eeb95a8339330u64, 0xcfd3ed8407722c1du64, 0xedf3c73398d0ad0au64, 0x6a09aafe4ba2713fu64]),
-            f_from_words::<Bls12_381>([0xf99117eda8d2c112u64, 0x395ca325790cd155u64, 0x1645e848c127349fu64, 0x2b8fd65bf0f8d7c3u64]),
-            f_from_words::<Bls12_381>([0xd660a2e9771b1068u64, 0x28df169004789120u64, 0xf9bb56a3e210a2fcu64, 0x2a3b5e8f5566fc2du64]),
-            f_from_words::<Bls12_381>([0x3469fab0c3787855u64, 0xfd12bb3b42f5c395u64, 0x9b0474c44cb82180u64, 0x07f3f397258cd185u64]),
-            f_from_words::<Bls12_381>([0xdcc467576809dceeu64, 0x520932a05117050du64, 0x2dbb8ec1ea412c33u64, 0x082fbd2428bcc031u64]),
-            f_from_words::<Bls12_381>([0x28eb60326b8462bbu64, 0x2da18f042c203fabu64, 0xd04e8ca67a9d5930u64, 0x52b5552c7dcd45d3u64]),
-            f_from_words::<Bls12_381>([0x501e1f29660e10dbu64, 0x0f5e0fecdd5daf19u64, 0x68cd4f724ab238a7u64, 0x30abbfeaded4f472u64]),
-            f_from_words::<Bls12_381>([0x23616e3506b3d743u64, 0xc83a8cfa62f675d1u64, 0x2a00227de2455e09u64, 0x681c6453beecaee1u64]),
-            f_from_words::<Bls12_381>([0x44edc104c810ec64u64, 0x18ee9817d670e118u64, 0xd9960e91aa6a4e30u64, 0x36bce8f1477b37b0u64]),
-            f_from_words::<Bls12_381>([0x9386d97a55276de1u64, 0xb90ec61a755904eau64, 0x8cfc423bb39a003cu64, 0x66c617665dfcc203u64]),
-            f_from_words::<Bls12_381>([0xa142b211286df7e6u64, 0x5dd2dc0fc3eca152u64, 0xb130adc489310072u64, 0x2330a937e11aa102u64]),
-            f_from_words::<Bls12_381>([0xa140e7ce8642f863u64, 0x0f6d55e91bc229f5u64, 0xa18c656d39a2087au64, 0x00f0402036b1ba2du64]),
-            f_from_words::<Bls12_381>([0xf1196692e76d9d94u64, 0x9df1fea95423da05u64, 0x3ede68241ff5116bu64, 0x1aafbc3aa9c8a6f3u64]),
-            f_from_words::<Bls12_381>([0xed1648b5b9db91c4u64, 0x24f82ca70630c470u64, 0x150a608a44b12b6eu64, 0x5baf014248d261d4u64]),
-            f_from_words::<Bls12_381>([0x31a3ad83becef9b0u64, 0x86d11e8cdcbc26dfu64, 0x9dcfb04debe79ff9u64, 0x16841c2fecb31f10u64]),
-            f_from_words::<Bls12_381>([0xd601b7f66c332b2au64, 0x70368ab13257589bu64, 0x82752ef2c3be7ea1u64, 0x592191e6b060ac49u64]),
-            f_from_words::<Bls12_381>([0x768ddd2884aeae95u64, 0x9a8255148bc7aba9u64, 0x89a9b20b7c2d459du64, 0x3d417b447b6af8a2u64]),
-            f_from_words::<Bls12_381>([0x3cfdf1f1d7a0bebfu64, 0xda80438824566b3du64, 0xc6cd0baac7f9e54eu64, 0x57f6a27b4212379au64]),
-            f_from_words::<Bls12_381>([0xa944ced7db36ad8du64, 0xaa798dac7965af63u64, 0x35dde15a92e59542u64, 0x1336851a5c220b25u64]),
-            f_from_words::<Bls12_381>([0x84ab5de2ccad6468u64, 0xaeb3511a90d8d8b0u64, 0x08b7782a5b95ad3du64, 0x6db8aa9aa4a6d962u64]),
-            f_from_words::<Bls12_381>([0xa70ab99c4c5d413du64, 0xfb6207e7465ef2cfu64, 0x689501f38b3da1aeu64, 0x3ab73ac129735049u64]),
-            f_from_words::<Bls12_381>([0x04ba130311aaae8cu64, 0xaeed98f05d425cb5u64, 0xa27e844c66f4e894u64, 0x398513fc99c5bf52u64]),
-            f_from_words::<Bls12_381>([0xa476ce4a3a12dc9du64, 0x67dbeb34e7c82158u64, 0x97be132f7d058a55u64, 0x163394d3509c439du64]),
-            f_from_words::<Bls12_381>([0x4fb53d37e419151bu64, 0xc00661a8bdf071f5u64, 0xa826f386765b51a4u64, 0x0624afa15718480du64]),
-            f_from_words::<Bls12_381>([0x379094d5591c0a59u64, 0x60f7cdf127ad4e5eu64, 0x723570851b9e2953u64, 0x6c1082ff3b095ca4u64]),
-            f_from_words::<Bls12_381>([0x58a013d15bfd8d1bu64, 0x4b110cdb7fb9acbeu64, 0x334fb94dd4bb05f9u64, 0x4ecdf844e1476053u64]),
-            f_from_words::<Bls12_381>([0xda733a0a3cca2ae1u64, 0x516701d85ed111bau64, 0x4323ad010ad4f126u64, 0x650124f6c9791be8u64]),
-            f_from_words::<Bls12_381>([0x305dd9a5bd25bf69u64, 0x21fe2b784be1801bu64, 0x49f4507a47895604u64, 0x0945815f9c7c2ac0u64]),
-            f_from_words::<Bls12_381>([0xfdbd11ea728a7225u64, 0xcf5e36956b55a741u64, 0x56069cb5e9cd0071u64, 0x2077ccccb8c4e281u64]),
-            f_from_words::<Bls12_381>([0x54d19687a985d08eu64, 0x0305d7c1f78fb615u64, 0xc336d45ffc510f9cu64, 0x0f5819bfcc794307u64]),
-            f_from_words::<Bls12_381>([0xff166bff7d3901e4u64, 0x4a1defb03c076ee5u64, 0x390ed4852e2e73bdu64, 0x63c745383db1e391u64]),
-            f_from_words::<Bls12_381>([0xae45de347d05f3e0u64, 0xae96d4ceacbf6c1au64, 0xd6c9ad78d782f0dbu64, 0x38b58e18f065d68du64]),
-            f_from_words::<Bls12_381>([0xa7eacf8f95b878b7u64, 0xbf8901a37184b43au64, 0x8a19166964e53f43u64, 0x052442a3ed9a70c6u64]),
-            f_from_words::<Bls12_381>([0x5a2a6cc33bad7de7u64, 0xaf598e5e816db2c8u64, 0xa83614cc4c261260u64, 0x5b4e3037adb9bcb2u64]),
-            f_from_words::<Bls12_381>([0x8c92e428f60841e2u64, 0x143dcf00a3332231u64, 0xba1373d6666dd520u64, 0x599dd99647db12f9u64]),
-            f_from_words::<Bls12_381>([0x13f920449e856b1eu64, 0x513a87cab4dee0f7u64, 0x48b276d63d879637u64, 0x19840bf574952c86u64]),
-            f_from_words::<Bls12_381>([0xd31d0ad3f940a8d6u64, 0x0da362a77ce92d77u64, 0x910bc44dbb71a1bfu64, 0x30783a4f3f96ce09u64]),
-            f_from_words::<Bls12_381>([0x483df2524c757627u64, 0xef9f0c4ece0c12aeu64, 0x51d34536c5421642u64, 0x5ae2c66ac60b354du64]),
-            f_from_words::<Bls12_381>([0x9291ab8c4ab53ba0u64, 0x1e7693f92daceb62u64, 0xde1baec8b2d44e91u64, 0x010cbd1eee0463cbu64]),
-            f_from_words::<Bls12_381>([0x0931d419ab387289u64, 0xdf144cfe0afcd12cu64, 0x92f3268e983b0c07u64, 0x00184dfad69578c8u64]),
-            f_from_words::<Bls12_381>([0x1930954501f28286u64, 0x1c849bf5e3800689u64, 0x622bc9567b54f435u64, 0x5303e909ce80100fu64]),
-            f_from_words::<Bls12_381>([0x67e0178442cf8dc8u64, 0xd12f032426659900u64, 0xc52594b66c8d7358u64, 0x620ff84069f48f5eu64]),
-            f_from_words::<Bls12_381>([0x60a89a2581f30e15u64, 0x1ba424a42efa739eu64, 0xc06e8f60a83efbb6u64, 0x41dd0c5b3a2f02f3u64]),
-            f_from_words::<Bls12_381>([0xdfc619dc68281601u64, 0xd9169ad2439cb9a8u64, 0x76e276b91e61f72bu64, 0x51194fbc9d2c1ad6u64]),
-            f_from_words::<Bls12_381>([0xb753439b6fba05d1u64, 0x2537f32a9fe6b532u64, 0x6e3ec94435ab588bu64, 0x4568e7ff74989403u64]),
-            f_from_words::<Bls12_381>([0xeaec281592eacab6u64, 0x151d982f84176b0eu64, 0x2aceb53e32adfbd9u64, 0x30a93158272987b5u64]),
-            f_from_words::<Bls12_381>([0x2e2c7689571c9b09u64, 0x0fc7a9858390ecf2u64, 0x59d8d331cae56e0fu64, 0x3181338fd65c264du64]),
-            f_from_words::<Bls12_381>([0xfb5fefcb7a926d7cu64, 0xadd1daa0920c6b9au64, 0x9c9451e442a0f663u64, 0x673f30f3c8506556u64]),
-            f_from_words::<Bls12_381>([0xcb7f9e692febcf0bu64, 0x0ad7a98691622514u64, 0x60b4e4cab189eec2u64, 0x5043ba959fd1c47eu64]),
-            f_from_words::<Bls12_381>([0xf2784e3660e98ed1u64, 0xaa2cb1cf530f21d8u64, 0x92c2a4f1d68438f2u64, 0x3843dde5ea61322eu64]),
-            f_from_words::<Bls12_381>([0xf9d4a163b1772398u64, 0x2dcd3323d12f5c9cu64, 0x1584c3c9ba4b8ef6u64, 0x1640dd24cbfdf928u64]),
-            f_from_words::<Bls12_381>([0x1a5f20063cbd1c92u64, 0x71168f762b9f8243u64, 0x4a1d75103b4ffedau64, 0x0c677d8ebcde37c1u64]),
-            f_from_words::<Bls12_381>([0xcbbc52223cb533cau64, 0x123ea6275c9218c2u64, 0x37aec84b97d0a76fu64, 0x369de7f8db63ca07u64]),
-            f_from_words::<Bls12_381>([0x90fc9800bb5c5b50u64, 0x0281b06eb1c741b8u64, 0x3cbe0eec00b965d3u64, 0x67ece27a744b5595u64]),
-            f_from_words::<Bls12_381>([0xe10962d35c2f93a2u64, 0x93ae39e227d6c674u64, 0xdfe13afe9c6b886cu64, 0x58bb608aaa16d56du64]),
-            f_from_words::<Bls12_381>([0xc784feda689b67c0u64, 0x51be13603bb7fb1cu64, 0x99f371c48e4be882u64, 0x233c8cab0cbe2f03u64]),
-            f_from_words::<Bls12_381>([0x352507d8091c73e8u64, 0x507398eab20f1aefu64, 0xef8ce10d77697aa4u64, 0x2bd686223a949432u64]),
-            f_from_words::<Bls12_381>([0x080159c0ac69f188u64, 0xe6b13763eee56dcbu64, 0x5ab1cb4fe2c7a552u64, 0x1bcf6d2a7d1ef874u64]),
-            f_from_words::<Bls12_381>([0x6b9f6ab29ef2a99fu64, 0x5b8f9f631b271cdeu64, 0x8c4adb376a3d0162u64, 0x5ac80df5d04991b2u64]),
-            f_from_words::<Bls12_381>([0x3ce408fb75d2abc6u64, 0xf1899c14469ff512u64, 0xb3517e7ba7cdc96cu64, 0x380bc6550e8629b0u64]),
-            f_from_words::<Bls12_381>([0xe512ecaf704a7c10u64, 0x47c4bb7e6f0c6551u64, 0x89243c1aaa1f3907u64, 0x6be945133847cce8u64]),
-            f_from_words::<Bls12_381>([0xb4dab8fff0a0b736u64, 0xda33fca0eeb0fdc3u64, 0x72cb6e0815d0a94eu64, 0x472aa85d121be021u64]),
-            f_from_words::<Bls12_381>([0x6b2f9220453f45d6u64, 0xf01de7f9973b4677u64, 0x473852410122a11bu64, 0x09409975c727c900u64]),
-            f_from_words::<Bls12_381>([0xe1547cab7bd94674u64, 0x4406e710a0ad7966u64, 0x299b74c7f6db38dbu64, 0x2f7581237eca4e89u64]),
-            f_from_words::<Bls12_381>([0xdbd86ae63e0f46e8u64, 0x8b7c8e04113a2adau64, 0x3f712100e0912060u64, 0x0a508cac771dfbe5u64]),
-            f_from_words::<Bls12_381>([0xa4321fb62e679303u64, 0xcefbe40e575d5ad2u64, 0xa7f02705a3bddfb6u64, 0x100b56850b318df8u64]),
-            f_from_words::<Bls12_381>([0x1db0c69ab551cc08u64, 0xd4889cf16fcc34adu64, 0x6372845d0c3eaeb8u64, 0x51244c285a4e9a5au64]),
-            f_from_words::<Bls12_381>([0xfe9ed63268dca410u64, 0x339e10d12e0f9dd5u64, 0x8bd7d2a7c77914a1u64, 0x141f905e9c0f8de0u64]),
-            f_from_words::<Bls12_381>([0x7f2d01ec7c6f55f6u64, 0xe047e87a75f488adu64, 0x25c5288797279119u64, 0x0a669f0899606f9du64]),
-            f_from_words::<Bls12_381>([0x0457677c44f1d0dau64, 0x85ace69ba8cce7c8u64, 0xacb4f786fb1b17deu64, 0x4452f384a1fcfbbcu64]),
-            f_from_words::<Bls12_381>([0x5b1a10b503d129dfu64, 0x4823e09f51acb2b9u64, 0x1a8e2968e92d59aeu64, 0x5e3aeb29a5dd0231u64]),
-            f_from_words::<Bls12_381>([0x7708682a49ed638du64, 0x724c72d32e667999u64, 0xc2ea9c5976046121u64, 0x1fd55250b1330d07u64]),
-            f_from_words::<Bls12_381>([0x019bed9775626be6u64, 0xbd13db9e2b23ae55u64, 0x0ae11b8525638ac6u64, 0x426e358f97cd31b9u64]),
-            f_from_words::<Bls12_381>([0x59d0a7fbfbaf7720u64, 0xcaf7e582ed97a2edu64, 0x8f4f55272eac7385u64, 0x4fcf538b3775439cu64]),
-            f_from_words::<Bls12_381>([0x5b6818d9857d19f3u64, 0x0158e5f88d9dd252u64, 0x47abd25129db581cu64, 0x3464ba37f74d979fu64]),
-            f_from_words::<Bls12_381>([0x704469eb27ee63adu64, 0x30eef5d56925123au64, 0xaa2c549151dbf4f0u64, 0x04eba8eaeab75359u64]),
-            f_from_words::<Bls12_381>([0xb49357281750e2b2u64, 0x8c59cdc9678bbe50u64, 0xe8ecd56ef33d40f7u64, 0x5941769f9c70afccu64]),
-            f_from_words::<Bls12_381>([0x0c7fb88890f0f205u64, 0xa75ab72e977dfc6fu64, 0x36793d2fa0b98d89u64, 0x0985bf3ccf73ed86u64]),
-            f_from_words::<Bls12_381>([0x35f2ad720b134fd6u64, 0x005b7d09bb43d320u64, 0xc0840f172f3d2629u64, 0x46803bebd53a4c6bu64]),
-            f_from_words::<Bls12_381>([0x8a84fab62826e3c4u64, 0xe696fc694a8355abu64, 0xe510687d7aee42abu64, 0x59867911250cf0b5u64]),
-            f_from_words::<Bls12_381>([0x7f019f3c7d33c4eau64, 0x4f99e49c74c99fa1u64, 0xfd73c489897178f2u64, 0x271515f5a37cf17eu64]),
-            f_from_words::<Bls12_381>([0xb35f769572ed598bu64, 0xdbea3d8702703c48u64, 0x6ace626e380a7c69u64, 0x06c57331227362ecu64]),
-            f_from_words::<Bls12_381>([0x240e10821e92618eu64, 0x4d32bef91c810b1cu64, 0x9381c09b623f3841u64, 0x50c6752718dacc01u64]),
-            f_from_words::<Bls12_381>([0x5966d2c2e10a14fau64, 0x5cb99f01183fd6cfu64, 0x1b52dd38aa89bb65u64, 0x0018347e2a5be401u64]),
-        ];
-        Arc::new(GmimcErfParams::new(2, 2, 326, &round_constants))
-    };
-    pub static ref GMIMC_ERF_BLS12_381_3_PARAMS: Arc<GmimcErfParams<Bls12_381>> = {
-        let round_constants: Vec<Bls12_381> = vec![
-            f_from_words::<Bls12_381>([0x7c4c8652c027e6acu64, 0x266f2f0febabe849u64, 0xd59f20e43d04e361u64, 0x1baa67527dcc9220u64]),
-            f_from_words::<Bls12_381>([0x471cbaef7e8f11fcu64, 0x1587064f6ff944beu64, 0x4a82b3bf04c4b971u64, 0x4832ecee81a170eau64]),
-            f_from_words::<Bls12_381>([0x3d292104c650f412u64, 0x881801f57716db0cu64, 0x0f8c92195ff2d351u64, 0x64bbb3f664b1712bu64]),
-            f_from_words::<Bls12_381>([0x1e06c6af463a37ddu64, 0xd068baa6db76e011u64, 0xbc373ea1d6aec2edu64, 0x2d576abe18ce6710u64]),
-            f_from_words::<Bls12_381>([0x7b85c0d39dfbd9c6u64, 0xc53a7cb48c18950bu64, 0x96d432b8c8427b6du64, 0x0eda4b87c49db453u64]),
-            f_from_words::<Bls12_381>([0x30a048549cbee50eu64, 0x5af3bafd2115b662u64, 0x3d6a7166a27c7236u64, 0x3e26e5befa4770b6u64]),
-            f_from_words::<Bls12_381>([0xfb059a381803b5ecu64, 0x11b7f1e84e45123au64, 0x5638b47b4a84df2bu64, 0x16acef6c3b9d8dbfu64]),
-            f_from_words::<Bls12_381>([0xf2bdadfe2e131dc2u64, 0x31f4dc26f61a8070u64, 0xd9faff09df4ec272u64, 0x6dcfc1184b968152u64]),
-            f_from_words::<Bls12_381>([0x289bf2380d90a836u64, 0x03156747c28efd5bu64, 0x903db80cfb8e0c68u64, 0x3a56941470d10699u64]),
-            f_from_words::<Bls12_381>([0x7182384f43544887u64, 0x362c61473c1fc93bu64, 0x934a93ca4e48c3fau64, 0x4b4e654fb499889au64]),
-            f_from_words::<Bls12_381>([0xd2b659d279ae1149u64, 0xfefabbd836c31aa0u64, 0xca8d7316f5c5ce04u64, 0x09fb242e7f11a722u64]),
-            f_from_words::<Bls12_381>([0x8970745c8fb1dbcbu64, 0x94ea1b536095b696u64, 0xd1106c6919e54791u64, 0x45e9563ae1f9359cu64]),
-            f_from_words::<Bls12_381>([0x7e4c06ae2198184cu64, 0xe65a349f6d039cf2u64, 0x027d57259694d55du64, 0x1c7639c901ea095bu64]),
-            f_from_words::<Bls12_381>([0x4fef83663c1d0d77u64, 0xa0bb59f0ecf35c14u64, 0xc51ca591c9fd737au64, 0x5eb7278eb8ac5352u64]),
-            f_from_words::<Bls12_381>([0xea1cb3f7beab4968u64, 0x49a35c4b9cca0770u64, 0xdc3f81a1474cabf1u64, 0x2e3f7f5e3eb7a78au64]),
-            f_from_words::<Bls12_381>([0x74e7271ff8c2efc2u64, 0xec39630bd119489au64, 0xf7564a5cbd8782d2u64, 0x15556e224ad61c0fu64]),
-            f_from_words::<Bls12_381>([0xd9ffa46dce00bef8u64, 0xabfd288380e86553u64, 0x3528a719b6098cadu64, 0x34ffa841b627dde9u64]),
-            f_from_words::<Bls12_381>([0xb25e1d4d4a25ccc0u64, 0x3dc2a44603532c79u64, 0x1c824078a255db85u64, 0x3f0240c657883306u64]),
-            f_from_words::<Bls12_381>([0x293bc4b0f1f56850u64, 0xb2ff8ea6460ddfbfu64, 0xf04047005e66f561u64, 0x5d0002d0187026c8u64]),
-            f_from_words::<Bls12_381>([0xb448482f00a5a4f0u64, 0x43519886e0b3aebfu64, 0xa5146911bcf51124u64, 0x264ddaf276780408u64]),
-            f_from_words::<Bls12_381>([0xa4d69b27ddab65f1u64, 0x7bf39c541ba767c7u64, 0xd0e28cb5680e2e00u64, 0x6b5c069c18237f3fu64]),
-            f_from_words::<Bls12_381>([0x1370c77bec6807d2u64, 0x789f34176abdacd5u64, 0xe726327ecf532d5fu64, 0x03492728d40921a8u64]),
-            f_from_words::<Bls12_381>([0x9791b14ff202e3c9u64, 0x184ebbb7003e96e7u64, 0x0cef47ae9d7b3e39u64, 0x1d666e18aef4997du64]),
-            f_from_words::<Bls12_381>([0x29ca5aeb8929c6f7u64, 0xb1c02c67cce9c8dcu64, 0x0ce2528921d22d96u64, 0x342e0ea91f3164a2u64]),
-            f_from_words::<Bls12_381>([0xb35858890a0e13f7u64, 0x6b1637728e527adcu64, 0xc17ca30ce906a695u64, 0x3cb3886d030370fbu64]),
-            f_from_words::<Bls12_381>([0xfd63a9b3e45d9711u64, 0x3e690d50007bd6a7u64, 0xe459e17af162d15fu64, 0x221efa994ca009c7u64]),
-            f_from_words::<Bls12_381>([0x44e7a341adfd7b1du64, 0xc98c1b3665012298u64, 0x7b88e6fee3a0224du64, 0x4dd59d4615f52e1bu64]),
-            f_from_words::<Bls12_381>([0x8f140c682d7ffd08u64, 0x553701fe6ffb27ddu64, 0xed88cb252346cb0cu64, 0x082300fe10d99afau64]),
-            f_from_words::<Bls12_381>([0x395d16fbc56c0091u64, 0xbfcc953649390edcu64, 0x94a3adfd04194549u64, 0x3b45edf81eda768bu64]),
-            f_from_words::<Bls12_381>([0x9eed39bff99c0aeeu64, 0x7490ba31f3cad871u64, 0xe4c0480bbb75a2c5u64, 0x203a8458978bc0cau64]),
-            f_from_words::<Bls12_381>([0xb85346afe1a4133du64, 0x7dded75094df8599u64, 0x18f6a2d225ad45a6u64, 0x5481d3f151a7825du64]),
-            f_from_words::<Bls12_381>([0x7f927aa3988e625du64, 0x77a5c8bae861af43u64, 0x8a7f8a1a022eaceau64, 0x72e38d6d1f1582ccu64]),
-            f_from_words::<Bls12_381>([0xe6d6b7e8ceb1795bu64, 0x333fde233311e6cau64, 0xfb4d37b5355c804bu64, 0x668051ccb3641a94u64]),
-            f_from_words::<Bls12_381>([0x4f285603eda045c1u64, 0x804a6df3826d7704u64, 0xc4c1f2b9b68da85bu64, 0x1c7860ec4238e50au64]),
-            f_from_words::<Bls12_381>([0xcc0b1fbe89259d57u64, 0xc8811459f7cc5180u64, 0xbbc75e813cefa428u64, 0x250a565e4cedccecu64]),
-            f_from_words::<Bls12_381>([0x407390dee848c538u64, 0x8089cf3493b620f8u64, 0x754e48d4a367df22u64, 0x5634e6c2be47c441u64]),
-            f_from_words::<Bls12_381>([0x66bfb82f0fb0f5f0u64, 0xb7e432f0e0336001u64, 0xfff9f490aed791e0u64, 0x5b813f654b94cc49u64]),
-            f_from_words::<Bls12_381>([0x98e9f97c757a76c2u64, 0xb0a4bb3fc1bbc2f0u64, 0xfa513938fd495da7u64, 0x56a526b046bf68ecu64]),
-            f_from_words::<Bls12_381>([0x667a2d5ce1777a53u64, 0x3dd63aefd735c50bu64, 0x144995ad290c7e95u64, 0x53935ea618e08471u64]),
-            f_from_words::<Bls12_381>([0x4883adbc9c62935au64, 0x19d56a896f3847a8u64, 0x0bd1c20564158c30u64, 0x238b166377ce22d9u64]),
-            f_from_words::<Bls12_381>([0x29cd33d59184ee0au64, 0x3795810bdfe77183u64, 0xff3e123533aa5357u64, 0x231dd0bbcc71e25du64]),
-            f_from_words::<Bls12_381>([0x18721b501d244592u64, 0x1ce9c8a8f4c3eee0u64, 0x46f6cfa55968b09au64, 0x265264d8d6feef6eu64]),
-            f_from_words::<Bls12_381>([0xd3a36b17db2c1febu64, 0xad14efaba20e9aa5u64, 0x09324b1d3c5ae135u64, 0x4f08d7961302c340u64]),
-            f_from_words::<Bls12_381>([0xf96273eb41eb0615u64, 0x3fef336db7431ad5u64, 0xd52058193e401712u64, 0x53545e7212db2936u64]),
-            f_from_words::<Bls12_381>([0x64f20fb4208ae695u64, 0xeecde35c36cc20d1u64, 0x2d7a851823587d17u64, 0x279445cd3a47517cu64]),
-            f_from_words::<Bls12_381>([0xe3746a8ae6442d7eu64, 0x8ecca6a137f727f5u64, 0xf3d2fd2b5931afb0u64, 0x2fa7faff72992cb5u64]),
-            f_from_words::<Bls12_381>([0x9ee3038c1d87c055u64, 0xb63f49d6f5b9f20au64, 0xdd683901681b0981u64, 0x5fea48142dc299b2u64]),
-            f_from_words::<Bls12_381>([0xf732844652b88e9eu64, 0xd7f4055d3aa9b831u64, 0x7c48f546bf85a74eu64, 0x18769a2a770a0aefu64]),
-            f_from_words::<Bls12_381>([0xd8600892041d6d0bu64, 0x1cd3c9e0c027f98eu64, 0x17f903190521031du64, 0x16224f944345445cu64]),
-            f_from_words::<Bls12_381>([0xc371c604b6e3117au64, 0x5091a3eb3516cd49u64, 0x0f18e2ffa5b01a26u64, 0x518abd8ec2f5660du64]),
-            f_from_words::<Bls12_381>([0xefdac7e3aaa6bf56u64, 0xc515c67276ef0296u64, 0xff67f3f866c2fcb9u64, 0x5ee342844f27e68eu64]),
-            f_from_words::<Bls12_381>([0x883756df7216d66eu64, 0x6631561cc95b07b5u64, 0xccf1f000d11820cfu64, 0x2e1fa2ebb64641eeu64]),
-            f_from_words::<Bls12_381>([0x0ae92b17f9db66b9u64, 0x9dc34d298a31c63fu64, 0x5a9ef6ba147e182cu64, 0x126cda3b8b7d155eu64]),
-            f_from_words::<Bls12_381>([0xf7ea5ac59510055cu64, 0xccf0eeffc025f91du64, 0xf3ed60870a96ce0eu64, 0x40268d3f8de14865u64]),
-            f_from_words::<Bls12_381>([0x5b4c6cc905403a41u64, 0x738bebc78af04c4cu64, 0x5bc6c8b1e0f39a1du64, 0x634a9f5657df7ea8u64]),
-            f_from_words::<Bls12_381>([0x0471b9b8f6b30405u64, 0xb9923edb1611c554u64, 0x5d8d83208e596253u64, 0x5b5b134c29bfbd0cu64]),
-            f_from_words::<Bls12_381>([0x84f73c4260a54543u64, 0x6dc4b56d751d8e58u64, 0x068a7f92eb1dc38cu64, 0x10fbb1c585fbe5a6u64]),
-            f_from_words::<Bls12_381>([0xbbf14377c7409f4du64, 0x6c1541dfa3ff1319u64, 0x9b4d6acba3dc19a8u64, 0x49af574a18164767u64]),
-            f_from_words::<Bls12_381>([0xeac5117fb1ea9edeu64, 0x8ba699f17d241981u64, 0x7c28f22af07591a6u64, 0x59d74c8f9b0e3c69u64]),
-            f_from_words::<Bls12_381>([0xe28ae6b6d0b236acu64, 0x05c8d99059d03cc5u64, 0x4dd4ff44aee9ec00u64, 0x039ae722a22558d0u64]),
-            f_from_words::<Bls12_381>([0x7e02aa376f36febau64, 0xdcba6f3bf982ac2au64, 0x101e8a1f0a940a9au64, 0x081bba19cdaea321u64]),
-            f_from_words::<Bls12_381>([0xfeaf2667e601be0cu64, 0xe8fc7462e7f5c34du64, 0xfcfb84a892d9a482u64, 0x5c904fb2fb99cb29u64]),
-            f_from_words::<Bls12_381>([0xdce14028d30acc81u64, 0xd314895b7669243du64, 0x830aa9644550821eu64, 0x5c96dd20f3162825u64]),
-            f_from_words::<Bls12_381>([0x6ae92d7d3eac8406u64, 0x213a02707fbd040du64, 0x4b269f49b8d0fbabu64, 0x17ae04afdbcd7e4cu64]),
-            f_from_words::<Bls12_381>([0xedc572f683efb4f0u64, 0x497a5b356152002eu64, 0x8ad784a73ddc0d44u64, 0x40412c55450c577bu64]),
-            f_from_words::<Bls12_381>([0x50a6208944aca9c5u64, 0x068cb0330ece1ff5u64, 0xdc4cf5bd88cda494u64, 0x0e5f5a9b5f52a7b1u64]),
-            f_from_words::<Bls12_381>([0x07da96210eacbc73u64, 0x8baed42a1c03ebb0u64, 0xf261cf1e1db30404u64, 0x3ab9fdf491696f61u64]),
-            f_from_words::<Bls12_381>([0xcc5829a099b25875u64, 0x59c744cf920a4821u64, 0xbaefcdb9ffce0c94u64, 0x626bc5494aba46b0u64]),
-            f_from_words::<Bls12_381>([0x61b9e6955c0e4170u64, 0x8949bed11b7c654au64, 0x7223874225179421u64, 0x3a990f8e4f557158u64]),
-            f_from_words::<Bls12_381>([0x38ac984bd2a2c75du64, 0x1a41397cb6e25b02u64, 0x07546391a4af3c79u64, 0x078c7efd3495ba0cu64]),
-            f_from_words::<Bls12_381>([0xdb131b122172fe6cu64, 0x87d3eaea10f5dfe8u64, 0x3a09b255ec100f38u64, 0x22098d7a77e8a4c6u64]),
-            f_from_words::<Bls12_381>([0xc97d2c2f862746dfu64, 0x736bb299d9b551beu64, 0x67c5c8eec0e61687u64, 0x36edaad0f1e8172cu64]),
-            f_from_words::<Bls12_381>([0x04e634b58be96a5cu64, 0x888a87d133a14edeu64, 0x01c52716dd7161e7u64, 0x3a908d99fdff75f7u64]),
-            f_from_words::<Bls12_381>([0x91c83a194c582fe8u64, 0x3e30b315b0d710edu64, 0x2529ad02bbaabb0cu64, 0x4f0325db25747aacu64]),
-            f_from_words::<Bls12_381>([0xc3e2aa8209bbe726u64, 0x710774f5db84f95eu64, 0x71363194a2ed147du64, 0x1baece6dc7daa4dcu64]),
-            f_from_words::<Bls12_381>([0xbe7193191f5a485du64, 0x69ea67f34a2c44afu64, 0xd9dd201d2fd00581u64, 0x083a959d7c4bc8b1u64]),
-            f_from_words::<Bls12_381>([0x427c0138adc004b6u64, 0x55724b7daf331c53u64, 0xc75e52bd9b0a4c9cu64, 0x4c93ed1dba90f4c0u64]),
-            f_from_words::<Bls12_381>([0xbc6bc5ad5218b566u64, 0x22ebf1145a0d9d10u64, 0x85ca5cdfa23324e9u64, 0x26c40fc5f81c0531u64]),
-            f_from_words::<Bls12_381>([0x4d3aaa5d075362c8u64, 0xdb6cb97a2351ca95u64, 0xda5de6950f0364c4u64, 0x138faeeae1a3b7beu64]),
-            f_from_words::<Bls12_381>([0xedd0a70a09ac9621u64, 0x2e82a4c8c8cc5295u64, 0x3a8e3205080df192u64, 0x126eef869626bf31u64]),
-            f_from_words::<Bls12_381>([0x522bd0a5a43d3545u64, 0x5a00184f58a1aafau64, 0x97cad53d97111cbdu64, 0x1d856d9db31ace9au64]),
-            f_from_words::<Bls12_381>([0xfe0c6ec0caffe7dbu64, 0x8d2320dc90e53001u64, 0x5b6bec0856b86fc8u64, 0x5ae7a058d86c1ab0u64]),
-            f_from_words::<Bls12_381>([0xa1b0ce446c3894e2u64, 0x327960a17c86bee7u64, 0x5ad3c0ad216de8bdu64, 0x0367b77e2bb701ffu64]),
-            f_from_words::<Bls12_381>([0x0b20f79d58165eddu64, 0x4e6aae9cea3a771du64, 0x6333c1d582b2ee9fu64, 0x5ebc8873c7393820u64]),
-            f_from_words::<Bls12_381>([0x79f77a3ca3a00633u64, 0x88b9c94191f57b82u64, 0xadf0afa6a8ca2b0eu64, 0x713388d6fdd7c409u64]),
-            f_from_words::<Bls12_381>([0x02e6887261b49e39u64, 0x7de863494688ea03u64, 0xfd0ce01a2f257284u64, 0x1797b6984df87f4cu64]),
-            f_from_words::<Bls12_381>([0xb9987e0708eafc6fu64, 0x4827dc7c1d0ac31cu64, 0xad6cf90e0fd67008u64, 0x2b64f5848a17f9bau64]),
-            f_from_words::<Bls12_381>([0x0356a87d9573b602u64, 0x39e6cf63c79ed525u64, 0xee3ff85e81a806d7u64, 0x160c3bf86d2c9914u64]),
-            f_from_words::<Bls12_381>([0x375bc38697a202e6u64, 0xcfbe6797233e2b90u64, 0x5ccf26b97f9a302au64, 0x30faca058477c1ddu64]),
-            f_from_words::<Bls12_381>([0xf5825f943d412b4bu64, 0x9f47d546f4cd2964u64, 0xa5a482b7d600dd66u64, 0x6646182ee7973e7fu64]),
-            f_from_words::<Bls12_381>([0x33e9d3b33c5f67a2u64, 0x95b9ab10619223ebu64, 0x32eb7464fb5831a7u64, 0x5ce8d7fa31b18763u64]),
-            f_from_words::<Bls12_381>([0xc693118e7c3ea832u64, 0x8780089d50431595u64, 0x03a284c7f3c2562cu64, 0x63b2d8def84ec443u64]),
-            f_from_words::<Bls12_381>([0x4bfb9ebaba109d7bu64, 0xec05e5838fdaabbbu64, 0x937e4c7da9b22204u64, 0x49dd7e14ed383389u64]),
-            f_from_words::<Bls12_381>([0xf16bd6c0b60c6027u64, 0x13306a6b1668ef93u64, 0x015b61933daf309fu64, 0x3fe0452193cfdf5au64]),
-            f_from_words::<Bls12_381>([0x1855e78572fca2b6u64, 0x4f39db3dbad9073fu64, 0x883fae472bd41a4eu64, 0x3c9b2a48f23243cdu64]),
-            f_from_words::<Bls12_381>([0x84ac178abc72ef0eu64, 0x8bf1cda2c8183024u64, 0x296cc65f37bc3ec8u64, 0x0df38399ea5fe3a0u64]),
-            f_from_words::<Bls12_381>([0x359cc39b316e6ac4u64, 0x58c082ada2fb19eau64, 0x4d6f1a8ef4d99589u64, 0x51d0d3e9fcf31ffbu64]),
-            f_from_words::<Bls12_381>([0xf61ff17642856f62u64, 0x5c5034100f5ac45bu64, 0x9a58f125a4b6ed59u64, 0x43d5b423e990bd65u64]),
-            f_from_words::<Bls12_381>([0x59cc365e249efc3cu64, 0xa36859b4b1dab297u64, 0xd9b83754bce01410u64, 0x42119357f852439cu64]),
-            f_from_words::<Bls12_381>([0xd647db53118bfb4fu64, 0x3f6db95b0bcc906bu64, 0xabf0162bdc0a12ccu64, 0x6c4f5ceda2c3302au64]),
-            f_from_words::<Bls12_381>([0xd71a35e65af4081fu64, 0xcacb30000331b32au64, 0xebfb5ca35a060b84u64, 0x2de1972fe5c69971u64]),
-            f_from_words::<Bls12_381>([0xe80470a6b1133a72u64, 0xe7d7856bb89b30e8u64, 0x957641b9d27260aeu64, 0x0507f161a5aa4385u64]),
-            f_from_words::<Bls12_381>([0xb50f8c90488c00c1u64, 0x138a8594bbfe3886u64, 0x06e8d68044e2ace8u64, 0x3843ced15554247au64]),
-            f_from_words::<Bls12_381>([0xdefeb149539808bcu64, 0x71331ced6867bd5au64, 0xe03f2530ac2cc6e8u64, 0x41878ac4fb7ea1d2u64]),
-            f_from_words::<Bls12_381>([0x55edf6679e15dd1bu64, 0xc7097707bef46f97u64, 0xd177e3970fd7b17au64, 0x4d728de3d3756612u64]),
-            f_from_words::<Bls12_381>([0x03c663d24fc19170u64, 0x4519962060a6ac8au64, 0x84b839231418efb9u64, 0x4cddfeda8319b279u64]),
-            f_from_words::<Bls12_381>([0x19dd402d55a0f8f0u64, 0x3edd4bdd4bd56f6au64, 0x3df0c2a5025997e5u64, 0x206a75863850ed9du64]),
-            f_from_words::<Bls12_381>([0xb38c941a76306993u64, 0x3fa6cde6d3242cf1u64, 0xa3683e921b8b705fu64, 0x6cd617fd47b19deeu64]),
-            f_from_words::<Bls12_381>([0xa68b1e5fde3f338bu64, 0x6206c73240356f89u64, 0xded2e52fc16afb34u64, 0x4fece59a7d826afcu64]),
-            f_from_words::<Bls12_381>([0xcc22fbefca85439au64, 0xe0f38518cf240d3fu64, 0x675bc38eed721f6cu64, 0x6c68d67656b5eb38u64]),
-            f_from_words::<Bls12_381>([0xde14a6f35add8368u64, 0x1ed4866388f9b9e8u64, 0x5d0c3c28bd0b54c0u64, 0x61869987afc4a7f7u64]),
-            f_from_words::<Bls12_381>([0x851b899d3b6d0f18u64, 0x9b26216a525bad1bu64, 0x8dce27716754b81au64, 0x08360fdfa77f9662u64]),
-            f_from_words::<Bls12_381>([0xe9d14591596cd949u64, 0x0eda48de07176badu64, 0x713c7d4944a38e0cu64, 0x4bced791d1537e27u64]),
-            f_from_words::<Bls12_381>([0xbb0dcaa8658ccc29u64, 0x6a4a68cd9e684627u64, 0xe002e9c322e0388eu64, 0x0b1deff574623f49u64]),
-            f_from_words::<Bls12_381>([0x93194f800035d572u64, 0x58358ffb12c27dd4u64, 0x6c5dda2ec9c796d7u64, 0x1b62d75065682cacu64]),
-            f_from_words::<Bls12_381>([0x25865113e5c171fau64, 0x46558bc804da007cu64, 0xa86e3cbeb9278805u64, 0x14843638c61fa30du64]),
-            f_from_words::<Bls12_381>([0x1e72a2468d87c00au64, 0xae740e8718facf02u64, 0x20b6c6bbd73de09cu64, 0x457a3f7b619e2948u64]),
-            f_from_words::<Bls12_381>([0xfbfdaed3bbea4bd7u64, 0xe7ef5472728b9132u64, 0x4d1061a6f5d4b2ebu64, 0x0d30e44c7da45356u64]),
-            f_from_words::<Bls12_381>([0xdaa06b2234ca48b4u64, 0x7da0198484fba4a5u64, 0xba0d1c18bf313e94u64, 0x0a942b73f0ca24a9u64]),
-            f_from_words::<Bls12_381>([0xa23dfcdd0101d7efu64, 0x5bdeefe16243fbf9u64, 0x37cfff577f10c871u64, 0x6fcb820133c88b80u64]),
-            f_from_words::<Bls12_381>([0x647bc281bf386eb0u64, 0x517906557975f29du64, 0xffbabd9f57711296u64, 0x1648b8cd351cb578u64]),
-            f_from_words::<Bls12_381>([0x8c1c579ecad4a640u64, 0xf8cd3347d61fc0d4u64, 0x2a42142a6dd70e46u64, 0x24e5685d05633d5bu64]),
-            f_from_words::<Bls12_381>([0x498574fac17b2694u64, 0xadf7d4b26b393ddeu64, 0x9a1490df52cc5c0du64, 0x13396645b36a6e83u64]),
-            f_from_words::<Bls12_381>([0xd5cc51e377b5b0bfu64, 0xe54b3dcfa19bcd83u64, 0x11cc7ecfb71f2c2au64, 0x6d968e6dd9c8c337u64]),
-            f_from_words::<Bls12_381>([0xfdc4b097a2bb7378u64, 0xdaf40036c5021536u64, 0xd1c26afb5479d07du64, 0x0a71f7749497831fu64]),
-            f_from_words::<Bls12_381>([0x1b7864a3b050dbceu64, 0x2808154d8c05e320u64, 0xaf915e441c417a89u64, 0x468d9bfb440842b0u64]),
-            f_from_words::<Bls12_381>([0xcd09c3caed181123u64, 0xe60ed2300c883bddu64, 0x9fa6e947d60b2d21u64, 0x6b43746deaf8dd1eu64]),
-            f_from_words::<Bls12_381>([0xef25e66ec64fc5b6u64, 0x3219724405a40b60u64, 0x3d930c55cc7c04a4u64, 0x0ae4b4142a984ddeu64]),
-            f_from_words::<Bls12_381>([0xe09e8c858773d5b0u64, 0x45fea8d273920c48u64, 0x2ef96bb4f2c86aaeu64, 0x1ec8ca4dac1298b6u64]),
-            f_from_words::<Bls12_381>([0xaa0eff355f4bd01cu64, 0x133665f50c7ee99cu64, 0xcf02c6af6073cfdeu64, 0x20bbd6f3b5e1e400u64]),
-            f_from_words::<Bls12_381>([0xca95fe80cbace07eu64, 0xfbc007b63b4244f7u64, 0xbb15de68e70c6598u64, 0x31e4e117dbee5b82u64]),
-            f_from_words::<Bls12_381>([0x5c966bad955bed4cu64, 0xa44a284805cc091au64, 0x7c27ad047133d747u64, 0x3969a93294a78fcbu64]),
-            f_from_words::<Bls12_381>([0x6f336b7c8e2755f6u64, 0x43cb3669067f581eu64, 0x00240015f6275a96u64, 0x193daabf1332d0c6u64]),
-            f_from_words::<Bls12_381>([0x4f6f56113b654e42u64, 0x84fe53037a98bf12u64, 0x48553f12dc05a746u64, 0x1547f6ee02f183e0u64]),
-            f_from_words::<Bls12_381>([0x6a7ad926329f0ff7u64, 0xc8393325f16958e2u64, 0x1caf20f1f5a97b5eu64, 0x2a11159a230dd46du64]),
-            f_from_words::<Bls12_381>([0xcf54f080b9709811u64, 0x6f85524e054b0106u64, 0xee95c253a5debd9fu64, 0x626cebcf93897a53u64]),
-            f_from_words::<Bls12_381>([0xe1f7421d3fc3a480u64, 0xa9795cfcf3000d81u64, 0x5f7c38daf8b0fe8cu64, 0x0c907ac186ea501au64]),
-            f_from_words::<Bls12_381>([0x862f974aef544c27u64, 0x87c8d4d3ff3040a0u64, 0x04c3ebafcca1e8bcu64, 0x61c1fe3f9b118dcbu64]),
-            f_from_words::<Bls12_381>([0x2db8c12530202853u64, 0xd899bd90f34315fbu64, 0x6d8c2bba883ae189u64, 0x147f881ebaf30c66u64]),
-            f_from_words::<Bls12_381>([0x46b9fa741408e5fcu64, 0x5c69345a73bc3592u64, 0x07d9c45a14682372u64, 0x1fb3927774bc055eu64]),
-            f_from_words::<Bls12_381>([0x221cd54c274cd887u64, 0x9aed279e67765044u64, 0x0ec25f42fd0c52bfu64, 0x59ff0e275dc0ed92u64]),
-            f_from_words::<Bls12_381>([0x4f71a943808477fbu64, 0xb57957d6e3a0c8d4u64, 0xf2d5166507f7c2a6u64, 0x446e89c3b7d8e2beu64]),
-            f_from_words::<Bls12_381>([0x380456afd4fe68dbu64, 0x9ea868c52cfe5976u64, 0xaf7b3887c4756253u64, 0x6e6c94b13221dbe4u64]),
-            f_from_words::<Bls12_381>([0x0b4fc8181d70d707u64, 0x85d34ed2833e6f9fu64, 0x88245f86dc8ba89cu64, 0x181696a8600961e7u64]),
-            f_from_words::<Bls12_381>([0xa2b616e25ecf677eu64, 0x37a1491bb9200125u64, 0x4ca96b6d78a3fcb0u64, 0x0ff15168d97cc3f0u64]),
-            f_from_words::<Bls12_381>([0xde9df3b3c3387f4fu64, 0x0aaef79547c5b7f9u64, 0x06bbd5319cbfb776u64, 0x3f709c5c038fcd66u64]),
-            f_from_words::<Bls12_381>([0x7d593a6f080b8053u64, 0xdd78e185d85e4df2u64, 0xa755a6293b8d2a8du64, 0x5e0b8c0bb4da2437u64]),
-            f_from_words::<Bls12_381>([0x4cc4036631761a00u64, 0x1dedae19332fcda5u64, 0x149c5787728c0599u64, 0x161b80d5bef32f9du64]),
-            f_from_words::<Bls12_381>([0x622f62f4f84a9e12u64, 0x39cfbefe79e1fa77u64, 0xa5fad9c7f428be76u64, 0x62f1b34ade9eb68fu64]),
-            f_from_words::<Bls12_381>([0x19bfae5cc0763c85u64, 0xed2e222fbdd7f2eau64, 0x5910c308a1dfc4cdu64, 0x0543c3a26f0818fdu64]),
-            f_from_words::<Bls12_381>([0x1b59330d1bcac86cu64, 0xfb0b340d63c9da2fu64, 0x2322ba6f22fe0cddu64, 0x0640b896714a26bcu64]),
-            f_from_words::<Bls12_381>([0x3a54f7d6d003933fu64, 0xf74752a28b80ea5du64, 0x29a38a025d005146u64, 0x4e6239185c2beed1u64]),
-            f_from_words::<Bls12_381>([0x00d53a8611fd228cu64, 0x3f85b36c5a371d6cu64, 0x2177c41f359814b3u64, 0x0687be0c802aec29u64]),
-            f_from_words::<Bls12_381>([0x7f7d256d108914abu64, 0xdf0182f0dd9e797du64, 0x53ac4833bde98233u64, 0x040456dfb528019cu64]),
-            f_from_words::<Bls12_381>([0x4bb93b4e2362cdd0u64, 0xa3edec7fcd56a898u64, 0x2cc13f4a242b3c14u64, 0x6ce2d6e0f63dff1eu64]),
-            f_from_words::<Bls12_381>([0xabcc1d6d32d49e58u64, 0x8e33bd8777fd093au64, 0xd142c2f250eb782fu64, 0x5fc28ca5b1c9d370u64]),
-            f_from_words::<Bls12_381>([0xf1df5973876bc4feu64, 0x61b8dd99691f5639u64, 0x1be8e70bee156a29u64, 0x72ac866dc1ee755bu64]),
-            f_from_words::<Bls12_381>([0xcd38324fcc75f8efu64, 0xcd8ac3cd8de3f658u64, 0x93da2328c76309a3u64, 0x4f0dad9ac86de2c4u64]),
-            f_from_words::<Bls12_381>([0x5ba07422371a3976u64, 0xc91ece436622510cu64, 0x819462dbc5b2b84du64, 0x065ab12e01348544u64]),
-            f_from_words::<Bls12_381>([0xe72a0c2d4995570cu64, 0x06ca2d5a2b1481f4u64, 0x06749a70bea76617u64, 0x289d450e278b602bu64]),
-            f_from_words::<Bls12_381>([0xacc82c3df6dc6155u64, 0xf9588d0682521b87u64, 0x09ae280858e78a6eu64, 0x4f00b4fbc5aea65du64]),
-            f_from_words::<Bls12_381>([0x6a27924fde27eb80u64, 0xe364edd9e1566f91u64, 0xe0ced1e4223b65b5u64, 0x62e72251e22dbf3cu64]),
-            f_from_words::<Bls12_381>([0x5c6de03c26a331ddu64, 0x920cf57341a5f95eu64, 0xce659266d56c922fu64, 0x6b793a0e59eb1e8du64]),
-            f_from_words::<Bls12_381>([0xa765e62f0353240bu64, 0x8c0650809df949dau64, 0x0db8aa31f79127eeu64, 0x1a1000b6d1edd3cau64]),
-            f_from_words::<Bls12_381>([0x33034945b624669cu64, 0x8a9983ba346f7e0cu64, 0x1670a5f3a4d53a03u64, 0x0fba568f66d8e167u64]),
-            f_from_words::<Bls12_381>([0xf0f4a6076a67d1ccu64, 0xffc2f3ff764b732eu64, 0x225568788eca6c92u64, 0x21ea6de996086d4fu64]),
-            f_from_words::<Bls12_381>([0x0194dfd8824c4b04u64, 0x24b69aa0e61cbcd9u64, 0x5c6c739c4fc3445bu64, 0x26b6c66c4d6ca831u64]),
-            f_from_words::<Bls12_381>([0x24bae42efadfeeadu64, 0x745c6b8c6b1bc91au64, 0xeb9bdacf3cefaf3du64, 0x5e03538f5af3c829u64]),
-            f_from_words::<Bls12_381>([0x2ca169b30f13403du64, 0xe34de77b7df531d4u64, 0x31ec83234ddbd3dcu64, 0x2212ccfa28a07c7eu64]),
-            f_from_words::<Bls12_381>([0xf4cf25838b4a3397u64, 0x63cf7559a125009bu64, 0xafc8e2a4676744ceu64, 0x4ea56263e2245bb4u64]),
-            f_from_words::<Bls12_381>([0x6f86c6db18aa2fb3u64, 0xbdfa7f4e9632d8f3u64, 0x287b3a604cb62f73u64, 0x618f3f5b4429d09au64]),
-            f_from_words::<Bls12_381>([0x736b14ecc1cbcc64u64, 0xcc737ed9e3723c4eu64, 0x49ea2b5c8d418aa3u64, 0x5bd0f2efa5e68220u64]),
-            f_from_words::<Bls12_381>([0x0291edd2102c5d55u64, 0x6f22ba0b53bc8ca5u64, 0x756785bfce08904fu64, 0x5d1003d1b1207c39u64]),
-            f_from_words::<Bls12_381>([0xeb3c2104bc045f16u64, 0x23d92e672bf3941au64, 0xa493f4c071d536c7u64, 0x562bc2d9ca513dd5u64]),
-            f_from_words::<Bls12_381>([0xaeeaaedcc1a5cfc9u64, 0x23a8bb18b1f50041u64, 0x746cda44475238b8u64, 0x6387bdf84155aea2u64]),
-            f_from_words::<Bls12_381>([0x783e85f709714a02u64, 0x1c4d43fbcf2d8f63u64, 0xe84c3e8876aa3d41u64, 0x260a6a3c546904d4u64]),
-            f_from_words::<Bls12_381>([0xed162596b33fb323u64, 0xb0b3dfc7d33495a2u64, 0xe0f17a2ad550894au64, 0x59db4a19dbcb1596u64]),
-            f_from_words::<Bls12_381>([0x5e86c94c67cc8b8bu64, 0x9f65d1da15c03cceu64, 0xa6c94a1af16c370au64, 0x71b617e69c35c650u64]),
-            f_from_words::<Bls12_381>([0x602ad252a6b9adcau64, 0xb56d15043edfaf3au64, 0xfe7f5fd4eed011b6u64, 0x4ca9a5b15f13a55du64]),
-            f_from_words::<Bls12_381>([0xdd13550325701942u64, 0x7e89697f60ca1370u64, 0xbbaff1d12b98072cu64, 0x25d3a859d22c2501u64]),
-            f_from_words::<Bls12_381>([0x311635bdfb654838u64, 0x7e05c0238d99e8bfu64, 0x7135b6d2f08dbb31u64, 0x45d22349a095d2f7u64]),
-            f_from_words::<Bls12_381>([0x056404a2102e83e3u64, 0xeeb35e82a6570485u64, 0x95cb9cb255d539c6u64, 0x32d223ed410dac4cu64]),
-            f_from_words::<Bls12_381>([0x213b24b7822e3d6cu64, 0x757feec7ebe7ba19u64, 0x88e52b61098fb045u64, 0x4f5c76026014f848u64]),
-            f_from_words::<Bls12_381>([0xe727f0141d60d7b2u64, 0x12c434ce7d7e325au64, 0x9cd2f09268f51228u64, 0x728a6be59c8d97fdu64]),
-            f_from_words::<Bls12_381>([0x7cda950c12eff1deu64, 0x7ba9f1a2ac0efcf7u64, 0x165e64802a53de96u64, 0x5f89ae6cb9d476adu64]),
-            f_from_words::<Bls12_381>([0xa8e43f8a9aad1b18u64, 0x843c74fbce5ce20fu64, 0xa36a0587ec576a41u64, 0x71f486796048d3e1u64]),
-            f_from_words::<Bls12_381>([0x03b597d9a68f185cu64, 0xf775dcd791bc4a44u64, 0x36117ed4fd7c4b66u64, 0x66923275f9ee6f78u64]),
-            f_from_words::<Bls12_381>([0xf168484a62af817bu64, 0xf878af780b2c0f3cu64, 0xd309b2f8fd3ab428u64, 0x16f8bf77e5aab477u64]),
-            f_from_words::<Bls12_381>([0x1669c0b083c83c2du64, 0xce29fd865432bcf4u64, 0x33c8e586eb008980u64, 0x47cdf46dc27583d2u64]),
-            f_from_words::<Bls12_381>([0xdf0fb3b6023104cau64, 0x210ae33aa374b410u64, 0x5ecb133a471aaeb4u64, 0x3e4291d40fb0a428u64]),
-            f_from_words::<Bls12_381>([0x3b670a97652f6404u64, 0xb349d8eebe03c142u64, 0x5f085ff4e5b5bb0cu64, 0x3b89d27b4e033758u64]),
-            f_from_words::<Bls12_381>([0x0532ad5675a3183cu64, 0x8051f10318ea4c83u64, 0x012fb588dc3e4f44u64, 0x6fd510328c426f90u64]),
-            f_from_words::<Bls12_381>([0x7cf74550bbee6a51u64, 0x6d8a491fecc24844u64, 0x800da9fd20e4c638u64, 0x0d030fe9846f4076u64]),
-            f_from_words::<Bls12_381>([0x25041368fa8a6fd6u64, 0x813e31380ee173f5u64, 0xd0e0c39c3ced73d3u64, 0x3800859c22962bf3u64]),
-            f_from_words::<Bls12_381>([0x8910714a1cce2200u64, 0x23a12ded0a93bc86u64, 0x5659f895c8f5640bu64, 0x5262422a17f843f7u64]),
-            f_from_words::<Bls12_381>([0x24a9e1709e6a7279u64, 0x418f99ae7f13a649u64, 0xcdf8e8d5f73572fau64, 0x08a2adc4b12e823au64]),
-            f_from_words::<Bls12_381>([0x0c7aab5693d7f2c9u64, 0xff341a1bcff0ee2eu64, 0xb0c6bbb57527f4d4u64, 0x615e079ba848ab3cu64]),
-            f_from_words::<Bls12_381>([0xd0d16170999ed8afu64, 0x38f0cf94249e8e87u64, 0x7d244472aabab41fu64, 0x40573ec740e425f4u64]),
-            f_from_words::<Bls12_381>([0x5457a087a53c5c86u64, 0xd491b62cc77e1842u64, 0x4f074f6fdf15c688u64, 0x2caf3dfbbdf05f65u64]),
-            f_from_words::<Bls12_381>([0xf8684013de375faau64, 0xad0ba92a27a1e761u64, 0x953e5acdbd2ba5ebu64, 0x319459ca3c0b5785u64]),
-            f_from_words::<Bls12_381>([0xef5429a893750558u64, 0xfbb0817bfa6acf9bu64, 0xce572c1d85bb386fu64, 0x07be600714abce5au64]),
-            f_from_words::<Bls12_381>([0x3c97bba81fff7a30u64, 0x2cdc46e1541191ecu64, 0xfab72bbd56b54f18u64, 0x42060cebd02c0fe4u64]),
-            f_from_words::<Bls12_381>([0x96832a64a236845au64, 0x6c803d9222147410u64, 0xafa5850226d658e1u64, 0x134fa299407f350bu64]),
-            f_from_words::<Bls12_381>([0x828a06e034b5f088u64, 0x79df020683fba670u64, 0x84fed3cf35c823cbu64, 0x203bb96ef0a42853u64]),
-            f_from_words::<Bls12_381>([0xc3026f19447e0593u64, 0xe9c34a5603f0937au64, 0x00d82fc8d9b8dfe3u64, 0x551f9b641cae00ceu64]),
-            f_from_words::<Bls12_381>([0xdc5ee2b30a0f1e50u64, 0xec93aac26b2ef1beu64, 0xeaf3157f67052c8fu64, 0x11eb97761cd201d3u64]),
-            f_from_words::<Bls12_381>([0x4febd5d3217d642cu64, 0x0bcc91af6244ca2bu64, 0x4c43aa9f986cf826u64, 0x04be8a8374c84de0u64]),
-            f_from_words::<Bls12_381>([0x59de649940c1ccfdu64, 0x6534005f5a4d8798u64, 0x9a240535cee1b81bu64, 0x15673d0a8a89a448u64]),
-            f_from_words::<Bls12_381>([0x791209a9b7b2292cu64, 0x4a356f034727802cu64, 0xc00321613c23366bu64, 0x288a00d85a0aac4bu64]),
-            f_from_words::<Bls12_381>([0xd394d650170f62f2u64, 0x8d672050a556161eu64, 0x532a1afba13fcfb6u64, 0x59bfe29ca57168d9u64]),
-            f_from_words::<Bls12_381>([0xe9d06a01802e95b7u64, 0xc50ec71eb39aa956u64, 0xe732cdc409731f0eu64, 0x547579c9b2c51d35u64]),
-            f_from_words::<Bls12_381>([0xcb93691a63b659dau64, 0x18e7552d4083a62au64, 0x3975ed5510862f4du64, 0x1397f693aeee1c42u64]),
-            f_from_words::<Bls12_381>([0x10a9768a047d6a54u64, 0x0b60566241a11d53u64, 0x1a4c6b1f1e71d8ffu64, 0x34d286e5833555d6u64]),
-            f_from_words::<Bls12_381>([0xf88a279d405148b7u64, 0xf82920d42f42dc39u64, 0x2dd5c4da6bc44c56u64, 0x39de70390eb1984du64]),
-            f_from_words::<Bls12_381>([0xdd5b6f7fe0a54fe9u64, 0x21ff5a4b35567fcfu64, 0x854612ccf9794e5du64, 0x3a52c703183da4ddu64]),
-            f_from_words::<Bls12_381>([0x2bbd81fe756a14bau64, 0x3ca51705ccd3d1b8u64, 0xbcc8595d8eb94eceu64, 0x27b3eb410ed620b1u64]),
-            f_from_words::<Bls12_381>([0xffd84cd35f38e67fu64, 0xdf06b3b67b196e60u64, 0x5c32f8dab59465e5u64, 0x2e4aa67e147add82u64]),
-            f_from_words::<Bls12_381>([0x13fb01cf8973a1d5u64, 0x1a0dfb2bec63c5f4u64, 0x453031e0e640e18au64, 0x1fa9b0443aaf33bau64]),
-            f_from_words::<Bls12_381>([0x9c4fcbce9f0e74acu64, 0xae41af167d368c0au64, 0x4dc56696dc653c1eu64, 0x090d3146b29e2820u64]),
-            f_from_words::<Bls12_381>([0x67e6cbf0b1ec2996u64, 0xc4453d50a7b30ad0u64, 0x7f9df2f11666c6c6u64, 0x31e5211d840d3cfdu64]),
-            f_from_words::<Bls12_381>([0x37aaa3ad1ad10d2au64, 0x1608f3e1013e8e8eu64, 0xd2b547255f7f1d16u64, 0x0e34943066bf60b1u64]),
-            f_from_words::<Bls12_381>([0x6910ee4fcfb52e9bu64, 0x9248bd8bd34fe6bbu64, 0x347a6a95923ddd95u64, 0x4b39dfe7d2123a0cu64]),
-            f_from_words::<Bls12_381>([0x7b44b9f1f252dbe8u64, 0x3bf4f960375ad415u64, 0x3feef3437381bef8u64, 0x3212f85007c2b3d9u64]),
-            f_from_words::<Bls12_381>([0x29bec36a1d878f69u64, 0xc3a43097e282b47fu64, 0xa0076406ca695629u64, 0x3d20f6ae6bd855c4u64]),
-            f_from_words::<Bls12_381>([0xbed49ecc9d1ddad8u64, 0x9d98cb527d781570u64, 0xfb702c61e477b51fu64, 0x49bef2fee3d08f75u64]),
-            f_from_words::<Bls12_381>([0xbb078d084695326bu64, 0x0e0fbaf09325db1au64, 0x5b7f445365cdac88u64, 0x6a2df866d7ad8427u64]),
-            f_from_words::<Bls12_381>([0xa6217a24d9f6f363u64, 0xa7dc160c0162da8du64, 0x2804431dc8bccdc7u64, 0x0a3b9657bea8bc48u64]),
-            f_from_words::<Bls12_381>([0xb71a72c58e98c79bu64, 0xaaad4ccee79075d6u64, 0x347a11e048add344u64, 0x1da6cb82e3508a73u64]),
-            f_from_words::<Bls12_381>([0xea9d63130cfa6644u64, 0x9f4d61fff6b48008u64, 0xb1d7ad1424085c0eu64, 0x262db1d59402e576u64]),
-            f_from_words::<Bls12_381>([0x41153601998f6958u64, 0x2344a4b22aa879bau64, 0x2da5c4a41c4fbca0u64, 0x014830d169614601u64]),
-            f_from_words::<Bls12_381>([0xfa2a56b92734fcacu64, 0x05c88851a5d5aebfu64, 0x2a78b85f369b7713u64, 0x04e588c570ac67d5u64]),
-            f_from_words::<Bls12_381>([0x766d8231691aeaa0u64, 0xe5134360a8a17b53u64, 0xf30dccb410cda8b4u64, 0x0d92bdae3497901eu64]),
-            f_from_words::<Bls12_381>([0x8f9719d7d6d56a03u64, 0x68f6117326f957efu64, 0x767e01669ed50f16u64, 0x5e5cdcfc215795ebu64]),
-            f_from_words::<Bls12_381>([0x5f52987476b455dau64, 0x32517ba6d7dc02ccu64, 0x3281fc536e651eebu64, 0x34a14bdd76b65308u64]),
-            f_from_words::<Bls12_381>([0x90b05e9725ada58fu64, 0x4fa5c34f4c88bed6u64, 0x2456545de19f303eu64, 0x43f5814b0a020f0cu64]),
-            f_from_words::<Bls12_381>([0x74e1fe6357d844c4u64, 0x974aa7ddb9a69714u64, 0x206034cb33a666d8u64, 0x18d7965f6a4d304fu64]),
-            f_from_words::<Bls12_381>([0x8a00139dc88ce8c1u64, 0xde44a170060a2e3cu64, 0xeee6a08cef582c9cu64, 0x4f4fb61f53d76dbau64]),
-            f_from_words::<Bls12_381>([0x4b7ee5ea10f3670bu64, 0xbeb15bd36db13c9bu64, 0x03074e8790eb5669u64, 0x59f3c8f269f0f29eu64]),
-            f_from_words::<Bls12_381>([0xf1f4782132e3c151u64, 0xe56f84a3f41a3c26u64, 0xf44cc88b3abe9d8au64, 0x6005e5b21bce1b40u64]),
-            f_from_words::<Bls12_381>([0x2b8a7f114536bd62u64, 0x6eb011b8ffaf1f94u64, 0xa149513d08c51c0cu64, 0x59e02dbc87e9cca1u64]),
-            f_from_words::<Bls12_381>([0x808ff943781e948bu64, 0x9b902b3ef26c5e43u64, 0x54f68f7bb7eaf06cu64, 0x3100da5caaabd59fu64]),
-            f_from_words::<Bls12_381>([0x0ff6e5e1d06b83fdu64, 0x47f2baa72f14a211u64, 0x475be93175e3ff72u64, 0x1361a7d063ba6b42u64]),
-            f_from_words::<Bls12_381>([0xb16eeb95a8339330u64, 0xcfd3ed8407722c1du64, 0xedf3c73398d0ad0au64, 0x6a09aafe4ba2713fu64]),
-            f_from_words::<Bls12_381>([0xf99117eda8d2c112u64, 0x395ca325790cd155u64, 0x1645e848c127349fu64, 0x2b8fd65bf0f8d7c3u64]),
-            f_from_words::<Bls12_381>([0xd660a2e9771b1068u64, 0x28df169004789120u64, 0xf9bb56a3e210a2fcu64, 0x2a3b5e8f5566fc2du64]),
-            f_from_words::<Bls12_381>([0x3469fab0c3787855u64, 0xfd12bb3b42f5c395u64, 0x9b0474c44cb82180u64, 0x07f3f397258cd185u64]),
-            f_from_words::<Bls12_381>([0xdcc467576809dceeu64, 0x520932a05117050du64, 0x2dbb8ec1ea412c33u64, 0x082fbd2428bcc031u64]),
-            f_from_words::<Bls12_381>([0x28eb60326b8462bbu64, 0x2da18f042c203fabu64, 0xd04e8ca67a9d5930u64, 0x52b5552c7dcd45d3u64]),
-            f_from_words::<Bls12_381>([0x501e1f29660e10dbu64, 0x0f5e0fecdd5daf19u64, 0x68cd4f724ab238a7u64, 0x30abbfeaded4f472u64]),
-            f_from_words::<Bls12_381>([0x23616e3506b3d743u64, 0xc83a8cfa62f675d1u64, 0x2a00227de2455e09u64, 0x681c6453beecaee1u64]),
-            f_from_words::<Bls12_381>([0x44edc104c810ec64u64, 0x18ee9817d670e118u64, 0xd9960e91aa6a4e30u64, 0x36bce8f1477b37b0u64]),
-            f_from_words::<Bls12_381>([0x9386d97a55276de1u64, 0xb90ec61a755904eau64, 0x8cfc423bb39a003cu64, 0x66c617665dfcc203u64]),
-            f_from_words::<Bls12_381>([0xa142b211286df7e6u64, 0x5dd2dc0fc3eca152u64, 0xb130adc489310072u64, 0x2330a937e11aa102u64]),
-            f_from_words::<Bls12_381>([0xa140e7ce8642f863u64, 0x0f6d55e91bc229f5u64, 0xa18c656d39a2087au64, 0x00f0402036b1ba2du64]),
-            f_from_words::<Bls12_381>([0xf1196692e76d9d94u64, 0x9df1fea95423da05u64, 0x3ede68241ff5116bu64, 0x1aafbc3aa9c8a6f3u64]),
-            f_from_words::<Bls12_381>([0xed1648b5b9db91c4u64, 0x24f82ca70630c470u64, 0x150a608a44b12b6eu64, 0x5baf014248d261d4u64]),
-            f_from_words::<Bls12_381>([0x31a3ad83becef9b0u64, 0x86d11e8cdcbc26dfu64, 0x9dcfb04debe79ff9u64, 0x16841c2fecb31f10u64]),
-            f_from_words::<Bls12_381>([0xd601b7f66c332b2au64, 0x70368ab13257589bu64, 0x82752ef2c3be7ea1u64, 0x592191e6b060ac49u64]),
-            f_from_words::<Bls12_381>([0x768ddd2884aeae95u64, 0x9a8255148bc7aba9u64, 0x89a9b20b7c2d459du64, 0x3d417b447b6af8a2u64]),
-            f_from_words::<Bls12_381>([0x3cfdf1f1d7a0bebfu64, 0xda80438824566b3du64, 0xc6cd0baac7f9e54eu64, 0x57f6a27b4212379au64]),
-            f_from_words::<Bls12_381>([0xa944ced7db36ad8du64, 0xaa798dac7965af63u64, 0x35dde15a92e59542u64, 0x1336851a5c220b25u64]),
-            f_from_words::<Bls12_381>([0x84ab5de2ccad6468u64, 0xaeb3511a90d8d8b0u64, 0x08b7782a5b95ad3du64, 0x6db8aa9aa4a6d962u64]),
-            f_from_words::<Bls12_381>([0xa70ab99c4c5d413du64, 0xfb6207e7465ef2cfu64, 0x689501f38b3da1aeu64, 0x3ab73ac129735049u64]),
-            f_from_words::<Bls12_381>([0x04ba130311aaae8cu64, 0xaeed98f05d425cb5u64, 0xa27e844c66f4e894u64, 0x398513fc99c5bf52u64]),
-            f_from_words::<Bls12_381>([0xa476ce4a3a12dc9du64, 0x67dbeb34e7c82158u64, 0x97be132f7d058a55u64, 0x163394d3509c439du64]),
-            f_from_words::<Bls12_381>([0x4fb53d37e419151bu64, 0xc00661a8bdf071f5u64, 0xa826f386765b51a4u64, 0x0624afa15718480du64]),
-            f_from_words::<Bls12_381>([0x379094d5591c0a59u64, 0x60f7cdf127ad4e5eu64, 0x723570851b9e2953u64, 0x6c1082ff3b095ca4u64]),
-            f_from_words::<Bls12_381>([0x58a013d15bfd8d1bu64, 0x4b110cdb7fb9acbeu64, 0x334fb94dd4bb05f9u64, 0x4ecdf844e1476053u64]),
-            f_from_words::<Bls12_381>([0xda733a0a3cca2ae1u64, 0x516701d85ed111bau64, 0x4323ad010ad4f126u64, 0x650124f6c9791be8u64]),
-            f_from_words::<Bls12_381>([0x305dd9a5bd25bf69u64, 0x21fe2b784be1801bu64, 0x49f4507a47895604u64, 0x0945815f9c7c2ac0u64]),
-            f_from_words::<Bls12_381>([0xfdbd11ea728a7225u64, 0xcf5e36956b55a741u64, 0x56069cb5e9cd0071u64, 0x2077ccccb8c4e281u64]),
-            f_from_words::<Bls12_381>([0x54d19687a985d08eu64, 0x0305d7c1f78fb615u64, 0xc336d45ffc510f9cu64, 0x0f5819bfcc794307u64]),
-            f_from_words::<Bls12_381>([0xff166bff7d3901e4u64, 0x4a1defb03c076ee5u64, 0x390ed4852e2e73bdu64, 0x63c745383db1e391u64]),
-            f_from_words::<Bls12_381>([0xae45de347d05f3e0u64, 0xae96d4ceacbf6c1au64, 0xd6c9ad78d782f0dbu64, 0x38b58e18f065d68du64]),
-            f_from_words::<Bls12_381>([0xa7eacf8f95b878b7u64, 0xbf8901a37184b43au64, 0x8a19166964e53f43u64, 0x052442a3ed9a70c6u64]),
-            f_from_words::<Bls12_381>([0x5a2a6cc33bad7de7u64, 0xaf598e5e816db2c8u64, 0xa83614cc4c261260u64, 0x5b4e3037adb9bcb2u64]),
-            f_from_words::<Bls12_381>([0x8c92e428f60841e2u64, 0x143dcf00a3332231u64, 0xba1373d6666dd520u64, 0x599dd99647db12f9u64]),
-            f_from_words::<Bls12_381>([0x13f920449e856b1eu64, 0x513a87cab4dee0f7u64, 0x48b276d63d879637u64, 0x19840bf574952c86u64]),
-            f_from_words::<Bls12_381>([0xd31d0ad3f940a8d6u64, 0x0da362a77ce92d77u64, 0x910bc44dbb71a1bfu64, 0x30783a4f3f96ce09u64]),
-            f_from_words::<Bls12_381>([0x483df2524c757627u64, 0xef9f0c4ece0c12aeu64, 0x51d34536c5421642u64, 0x5ae2c66ac60b354du64]),
-            f_from_words::<Bls12_381>([0x9291ab8c4ab53ba0u64, 0x1e7693f92daceb62u64, 0xde1baec8b2d44e91u64, 0x010cbd1eee0463cbu64]),
-            f_from_words::<Bls12_381>([0x0931d419ab387289u64, 0xdf144cfe0afcd12cu64, 0x92f3268e983b0c07u64, 0x00184dfad69578c8u64]),
-            f_from_words::<Bls12_381>([0x1930954501f28286u64, 0x1c849bf5e3800689u64, 0x622bc9567b54f435u64, 0x5303e909ce80100fu64]),
-            f_from_words::<Bls12_381>([0x67e0178442cf8dc8u64, 0xd12f032426659900u64, 0xc52594b66c8d7358u64, 0x620ff84069f48f5eu64]),
-            f_from_words::<Bls12_381>([0x60a89a2581f30e15u64, 0x1ba424a42efa739eu64, 0xc06e8f60a83efbb6u64, 0x41dd0c5b3a2f02f3u64]),
-            f_from_words::<Bls12_381>([0xdfc619dc68281601u64, 0xd9169ad2439cb9a8u64, 0x76e276b91e61f72bu64, 0x51194fbc9d2c1ad6u64]),
-            f_from_words::<Bls12_381>([0xb753439b6fba05d1u64, 0x2537f32a9fe6b532u64, 0x6e3ec94435ab588bu64, 0x4568e7ff74989403u64]),
-            f_from_words::<Bls12_381>([0xeaec281592eacab6u64, 0x151d982f84176b0eu64, 0x2aceb53e32adfbd9u64, 0x30a93158272987b5u64]),
-            f_from_words::<Bls12_381>([0x2e2c7689571c9b09u64, 0x0fc7a9858390ecf2u64, 0x59d8d331cae56e0fu64, 0x3181338fd65c264du64]),
-            f_from_words::<Bls12_381>([0xfb5fefcb7a926d7cu64, 0xadd1daa0920c6b9au64, 0x9c9451e442a0f663u64, 0x673f30f3c8506556u64]),
-            f_from_words::<Bls12_381>([0xcb7f9e692febcf0bu64, 0x0ad7a98691622514u64, 0x60b4e4cab189eec2u64, 0x5043ba959fd1c47eu64]),
-            f_from_words::<Bls12_381>([0xf2784e3660e98ed1u64, 0xaa2cb1cf530f21d8u64, 0x92c2a4f1d68438f2u64, 0x3843dde5ea61322eu64]),
-            f_from_words::<Bls12_381>([0xf9d4a163b1772398u64, 0x2dcd3323d12f5c9cu64, 0x1584c3c9ba4b8ef6u64, 0x1640dd24cbfdf928u64]),
-            f_from_words::<Bls12_381>([0x1a5f20063cbd1c92u64, 0x71168f762b9f8243u64, 0x4a1d75103b4ffedau64, 0x0c677d8ebcde37c1u64]),
-            f_from_words::<Bls12_381>([0xcbbc52223cb533cau64, 0x123ea6275c9218c2u64, 0x37aec84b97d0a76fu64, 0x369de7f8db63ca07u64]),
-            f_from_words::<Bls12_381>([0x90fc9800bb5c5b50u64, 0x0281b06eb1c741b8u64, 0x3cbe0eec00b965d3u64, 0x67ece27a744b5595u64]),
-            f_from_words::<Bls12_381>([0xe10962d35c2f93a2u64, 0x93ae39e227d6c674u64, 0xdfe13afe9c6b886cu64, 0x58bb608aaa16d56du64]),
-            f_from_words::<Bls12_381>([0xc784feda689b67c0u64, 0x51be13603bb7fb1cu64, 0x99f371c48e4be882u64, 0x233c8cab0cbe2f03u64]),
-            f_from_words::<Bls12_381>([0x352507d8091c73e8u64, 0x507398eab20f1aefu64, 0xef8ce10d77697aa4u64, 0x2bd686223a949432u64]),
-            f_from_words::<Bls12_381>([0x080159c0ac69f188u64, 0xe6b13763eee56dcbu64, 0x5ab1cb4fe2c7a552u64, 0x1bcf6d2a7d1ef874u64]),
-            f_from_words::<Bls12_381>([0x6b9f6ab29ef2a99fu64, 0x5b8f9f631b271cdeu64, 0x8c4adb376a3d0162u64, 0x5ac80df5d04991b2u64]),
-            f_from_words::<Bls12_381>([0x3ce408fb75d2abc6u64, 0xf1899c14469ff512u64, 0xb3517e7ba7cdc96cu64, 0x380bc6550e8629b0u64]),
-            f_from_words::<Bls12_381>([0xe512ecaf704a7c10u64, 0x47c4bb7e6f0c6551u64, 0x89243c1aaa1f3907u64, 0x6be945133847cce8u64]),
-            f_from_words::<Bls12_381>([0xb4dab8fff0a0b736u64, 0xda33fca0eeb0fdc3u64, 0x72cb6e0815d0a94eu64, 0x472aa85d121be021u64]),
-            f_from_words::<Bls12_381>([0x6b2f9220453f45d6u64, 0xf01de7f9973b4677u64, 0x473852410122a11bu64, 0x09409975c727c900u64]),
-            f_from_words::<Bls12_381>([0xe1547cab7bd94674u64, 0x4406e710a0ad7966u64, 0x299b74c7f6db38dbu64, 0x2f7581237eca4e89u64]),
-            f_from_words::<Bls12_381>([0xdbd86ae63e0f46e8u64, 0x8b7c8e04113a2adau64, 0x3f712100e0912060u64, 0x0a508cac771dfbe5u64]),
-            f_from_words::<Bls12_381>([0xa4321fb62e679303u64, 0xcefbe40e575d5ad2u64, 0xa7f02705a3bddfb6u64, 0x100b56850b318df8u64]),
-            f_from_words::<Bls12_381>([0x1db0c69ab551cc08u64, 0xd4889cf16fcc34adu64, 0x6372845d0c3eaeb8u64, 0x51244c285a4e9a5au64]),
-            f_from_words::<Bls12_381>([0xfe9ed63268dca410u64, 0x339e10d12e0f9dd5u64, 0x8bd7d2a7c77914a1u64, 0x141f905e9c0f8de0u64]),
-            f_from_words::<Bls12_381>([0x7f2d01ec7c6f55f6u64, 0xe047e87a75f488adu64, 0x25c5288797279119u64, 0x0a669f0899606f9du64]),
-            f_from_words::<Bls12_381>([0x0457677c44f1d0dau64, 0x85ace69ba8cce7c8u64, 0xacb4f786fb1b17deu64, 0x4452f384a1fcfbbcu64]),
-            f_from_words::<Bls12_381>([0x5b1a10b503d129dfu64, 0x4823e09f51acb2b9u64, 0x1a8e2968e92d59aeu64, 0x5e3aeb29a5dd0231u64]),
-            f_from_words::<Bls12_381>([0x7708682a49ed638du64, 0x724c72d32e667999u64, 0xc2ea9c5976046121u64, 0x1fd55250b1330d07u64]),
-            f_from_words::<Bls12_381>([0x019bed9775626be6u64, 0xbd13db9e2b23ae55u64, 0x0ae11b8525638ac6u64, 0x426e358f97cd31b9u64]),
-            f_from_words::<Bls12_381>([0x59d0a7fbfbaf7720u64, 0xcaf7e582ed97a2edu64, 0x8f4f55272eac7385u64, 0x4fcf538b3775439cu64]),
-            f_from_words::<Bls12_381>([0x5b6818d9857d19f3u64, 0x0158e5f88d9dd252u64, 0x47abd25129db581cu64, 0x3464ba37f74d979fu64]),
-            f_from_words::<Bls12_381>([0x704469eb27ee63adu64, 0x30eef5d56925123au64, 0xaa2c549151dbf4f0u64, 0x04eba8eaeab75359u64]),
-            f_from_words::<Bls12_381>([0xb49357281750e2b2u64, 0x8c59cdc9678bbe50u64, 0xe8ecd56ef33d40f7u64, 0x5941769f9c70afccu64]),
-            f_from_words::<Bls12_381>([0x0c7fb88890f0f205u64, 0xa75ab72e977dfc6fu64, 0x36793d2fa0b98d89u64, 0x0985bf3ccf73ed86u64]),
-            f_from_words::<Bls12_381>([0x35f2ad720b134fd6u64, 0x005b7d09bb43d320u64, 0xc0840f172f3d2629u64, 0x46803bebd53a4c6bu64]),
-            f_from_words::<Bls12_381>([0x8a84fab62826e3c4u64, 0xe696fc694a8355abu64, 0xe510687d7aee42abu64, 0x59867911250cf0b5u64]),
-            f_from_words::<Bls12_381>([0x7f019f3c7d33c4eau64, 0x4f99e49c74c99fa1u64, 0xfd73c489897178f2u64, 0x271515f5a37cf17eu64]),
-            f_from_words::<Bls12_381>([0xb35f769572ed598bu64, 0xdbea3d8702703c48u64, 0x6ace626e380a7c69u64, 0x06c57331227362ecu64]),
-            f_from_words::<Bls12_381>([0x240e10821e92618eu64, 0x4d32bef91c810b1cu64, 0x9381c09b623f3841u64, 0x50c6752718dacc01u64]),
-            f_from_words::<Bls12_381>([0x5966d2c2e10a14fau64, 0x5cb99f01183fd6cfu64, 0x1b52dd38aa89bb65u64, 0x0018347e2a5be401u64]),
-            f_from_words::<Bls12_381>([0x90170cbf9481eaf9u64, 0x8f376ea17e4fbd04u64, 0xb73148a2617f01cbu64, 0x5c6b00d2d7504149u64]),
-            f_from_words::<Bls12_381>([0x395c3261577fd822u64, 0x175113f62c096dc0u64, 0x0aeb376bab956630u64, 0x09980529efcb16ccu64]),
-        ];
-        Arc::new(GmimcErfParams::new(3, 2, 328, &round_constants))
-    };
-    pub static ref GMIMC_ERF_GOLDILOCKS_8_PARAMS: Arc<GmimcErfParams<Goldilocks>> = {
-        let round_constants: Vec<Goldilocks> = vec![
-            f_from_words::<Goldilocks>([0x5ca8f0cbcd2ea006u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9a333d86ded8842eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x312d20e7306de1b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc8124de832969515u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8528fb4ac25f4956u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x735b213ba8307405u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5b81523ecc3dd84cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8bb4918451c486d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb96aab2c4fc73042u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xeecf9c252dfffa90u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x78a27c4a39367464u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x364f8043b11534dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc500e957c6df8008u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x160d77986c8b1b10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3624063556932c48u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x38c634d0dc56a61fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x86afc5989e483c35u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe6dd95eb0dadb13eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8e3dd7748c9b6a8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfecbcd01c1c829d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x750b5fefcc0d388au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x58b454c111f9a83eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xff54eafd942da1a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1b2e59b4b8971a49u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3cb7b60225c9b7b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x96d59fe55b0a9002u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x24110f359c07205au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfa539a6410570fd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x206bd8164475e9c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x98ed1e1199ed3cdau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd56f222555f96ed5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x22006054ee94b6b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x6e970b1d75d021a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc1adc9659610f71fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x019796f38afb2f1fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x77d31dc2488aa56du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb7fe8aec599116c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd9db720e10efb281u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x10a45d83b0827aa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5bb9da1da43aa398u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2a0588d6baa1f065u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x09446e5bdde938f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x6eba16a3618bb7c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd27137482a8100a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa7ebd145f96bce60u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0722e64021c552fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7abd53a7a61ef726u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x70e3aab3888e0c6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x317f65a789b01764u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa078e09ea9bbfc20u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb49a5a1271c4e07cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd0b9a9be1124a1d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8d8f4a87cdea437eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf853d9f2fb952a0eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2c9bbcc435e630deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc8125b4184f18690u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8c2066eea19eb30eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xea94b288bd38415du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2a276c1a052aca7au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x14efb34ca4b20b6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x59a7703be1c1ec4au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfe80ad968db010d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7566a3968952cc02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfa94c91c5e88cf54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xed0938f35f60cf5au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2667864f65c7a00au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0a9ca930371fc89fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa785caed2207accfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2ed69d5ab13900e6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8e9af6fa1b0ac2eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x47545fa03b6d1cd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x879422e94a1ca10cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfe54556931542659u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x70275e8cd57a606au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8c7b9ceb3e6e1304u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfb50ec41897af707u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x80cb4c5de1698b00u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd013078d2ff1ae67u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x56146f58cc58c0ceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb14d021d70dbaaf0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8cb4f3e4d8a194c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8618aa3da3f6fa97u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x31936a761cfc98dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x874f7de63183e34du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x607d9d8f0ffbe071u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8a35c4332c22d9c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xae008c9719a4e3f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb6529b41b6839f87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2664a1a1b4422a01u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x18019a3b582eb848u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf1d5b5a3d0c17025u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5957f1ae251fa6d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xad24102f2b329e93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xbc9c559f257067e3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x355102f255f0500fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe2c8d4b0ddcbe2b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x62c97307b2e72161u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1fe86e3805b90187u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3b49f0aae85d89a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5671dd2ae356b254u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3524898a1e7fed4du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x29b586ee261f02d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe2450dc1b13383aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa05d4b73adf8c852u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x436c70df3ec9015fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x66be10f53a2d51fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xcff287576d33b292u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x64f8d384b7e07619u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd877daa539263e45u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe02bfa5b78163b4bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7b1a23fa14bc3e6cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x67e7e99e3a898087u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb3cdb217607d3ce3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc33252c373284a90u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf3018858582bff1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x82d1c395689c11bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2e42fbbbc6b39a37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x846f8f5b56fac7b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xde93f1c60aaa0029u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1f5ce8428d387a7cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x489c2e23e3bf63c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2012c811bf04d642u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x4c040647aa5cf3acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf8602675cd57b2f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x4b494afeeb15d0b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xbe971fcf312acd3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8f43facda7c0aac2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x57c504490dfa9131u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3a0aa9d3b795e7eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc26d8af42de02525u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd256a4f3ce3445adu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd8afcfd0b08531a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd2cbff8fe408c573u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe932bb39f085cbd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x82dd9a6d1deb4df7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7ca9d774f483fffbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5f4783582131cfe2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xccb9e49c71cd6b9bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9c036b7faeedea02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf421846dbe7e4923u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x4f7e7fdbca30f838u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x049e5bc203df3744u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3d088acaef7f92a7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0e19a91e6400789du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0331b87ae67c1232u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x08e1319b5c706ef3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-        ];
-        Arc::new(GmimcErfParams::new(8, 2, 146, &round_constants))
-    };
-    pub static ref GMIMC_ERF_GOLDILOCKS_12_PARAMS: Arc<GmimcErfParams<Goldilocks>> = {
-        let round_constants: Vec<Goldilocks> = vec![
-            f_from_words::<Goldilocks>([0x5ca8f0cbcd2ea006u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9a333d86ded8842eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x312d20e7306de1b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc8124de832969515u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8528fb4ac25f4956u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x735b213ba8307405u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5b81523ecc3dd84cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8bb4918451c486d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb96aab2c4fc73042u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xeecf9c252dfffa90u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x78a27c4a39367464u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x364f8043b11534dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc500e957c6df8008u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x160d77986c8b1b10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3624063556932c48u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x38c634d0dc56a61fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x86afc5989e483c35u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe6dd95eb0dadb13eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8e3dd7748c9b6a8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfecbcd01c1c829d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x750b5fefcc0d388au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x58b454c111f9a83eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xff54eafd942da1a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1b2e59b4b8971a49u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3cb7b60225c9b7b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x96d59fe55b0a9002u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x24110f359c07205au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfa539a6410570fd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x206bd8164475e9c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x98ed1e1199ed3cdau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd56f222555f96ed5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x22006054ee94b6b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x6e970b1d75d021a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc1adc9659610f71fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x019796f38afb2f1fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x77d31dc2488aa56du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb7fe8aec599116c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd9db720e10efb281u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x10a45d83b0827aa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5bb9da1da43aa398u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2a0588d6baa1f065u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x09446e5bdde938f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x6eba16a3618bb7c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd27137482a8100a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa7ebd145f96bce60u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0722e64021c552fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7abd53a7a61ef726u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x70e3aab3888e0c6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x317f65a789b01764u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa078e09ea9bbfc20u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb49a5a1271c4e07cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd0b9a9be1124a1d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8d8f4a87cdea437eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf853d9f2fb952a0eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2c9bbcc435e630deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc8125b4184f18690u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8c2066eea19eb30eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xea94b288bd38415du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2a276c1a052aca7au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x14efb34ca4b20b6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x59a7703be1c1ec4au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfe80ad968db010d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7566a3968952cc02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfa94c91c5e88cf54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xed0938f35f60cf5au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2667864f65c7a00au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0a9ca930371fc89fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa785caed2207accfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2ed69d5ab13900e6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8e9af6fa1b0ac2eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x47545fa03b6d1cd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x879422e94a1ca10cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfe54556931542659u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x70275e8cd57a606au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8c7b9ceb3e6e1304u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfb50ec41897af707u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x80cb4c5de1698b00u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd013078d2ff1ae67u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x56146f58cc58c0ceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb14d021d70dbaaf0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8cb4f3e4d8a194c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8618aa3da3f6fa97u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x31936a761cfc98dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x874f7de63183e34du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x607d9d8f0ffbe071u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8a35c4332c22d9c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xae008c9719a4e3f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb6529b41b6839f87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2664a1a1b4422a01u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x18019a3b582eb848u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf1d5b5a3d0c17025u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5957f1ae251fa6d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xad24102f2b329e93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xbc9c559f257067e3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x355102f255f0500fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe2c8d4b0ddcbe2b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x62c97307b2e72161u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1fe86e3805b90187u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3b49f0aae85d89a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5671dd2ae356b254u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3524898a1e7fed4du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x29b586ee261f02d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe2450dc1b13383aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa05d4b73adf8c852u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x436c70df3ec9015fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x66be10f53a2d51fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xcff287576d33b292u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x64f8d384b7e07619u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd877daa539263e45u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe02bfa5b78163b4bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7b1a23fa14bc3e6cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x67e7e99e3a898087u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb3cdb217607d3ce3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc33252c373284a90u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf3018858582bff1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x82d1c395689c11bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2e42fbbbc6b39a37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x846f8f5b56fac7b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xde93f1c60aaa0029u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1f5ce8428d387a7cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x489c2e23e3bf63c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2012c811bf04d642u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x4c040647aa5cf3acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf8602675cd57b2f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x4b494afeeb15d0b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xbe971fcf312acd3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8f43facda7c0aac2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x57c504490dfa9131u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3a0aa9d3b795e7eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc26d8af42de02525u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd256a4f3ce3445adu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd8afcfd0b08531a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd2cbff8fe408c573u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe932bb39f085cbd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x82dd9a6d1deb4df7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7ca9d774f483fffbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5f4783582131cfe2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xccb9e49c71cd6b9bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9c036b7faeedea02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf421846dbe7e4923u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x4f7e7fdbca30f838u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x049e5bc203df3744u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3d088acaef7f92a7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0e19a91e6400789du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0331b87ae67c1232u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x08e1319b5c706ef3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfb6a827c35db0cb5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc1cdc13a76678829u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x4ccde7215ac854ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x710a4955fd347a51u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9f49ed9ee6ec1668u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xab0652b945c92fffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x6271bb6d34e66c00u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xafc632544b29dd7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x03aca6767f729345u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb13207c0761f878du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3b5c66fe6808ff37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5bcae750366c7403u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x20e142db8671cc59u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb28aaf5e129259f9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3837e5daf19d3766u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x19c9b9e875ecf630u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0dbc994727cf6486u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9b113bbe07314fecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x751fb9d1b2a8a710u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x042c4879fd070871u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xcba87e8b07d69c0bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x19854a5c7934dd24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x4c22a385a4c171e8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfa921d8c9a786ffeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xdc54b9a55f617719u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x72791fafde371b73u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3c497fd800505ae5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xed64f56ed88da0e3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x14b374fff7a4cc7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x72870f72f3bf0758u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x4882559533a836c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1c14d409dcea361cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x172c2916c909b44eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xca43410c8945b064u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x75a0ebbd1b840f91u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8772c52ae88ee819u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9975c23536922f83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9e51f9f91c3c4830u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x18549f572ac9bfccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x00421ee2ee5dcf44u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x36ee5b19e5167b13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7ede0e3b22d2497bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8a008e440c192584u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1dd6ff49a60d7c6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf61070876848e5a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5ffadd6ea5109b24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1ddbf3fbf116c6a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc1ad62255b0463acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf6f92055a2b81cd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xbd5f449f5f5861d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xdeb881260e79186bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1db9bf9457793afbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8c034fc49e3b3a68u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb8fff7b5f927af55u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xcd7568fd401aada9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x36ea8b8f7b6d91a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x257227a703b5ee57u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc414e18dc0969f26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1725d44f71fb6991u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2c8878e6196b90fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x75002cc82fb1642au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x8c91583f9d82a83eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf88e99a58cfb8213u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa4aba7de0214f95cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x18ca32dc82acf195u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x06529dbb00b88effu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xaabcbf46af22053fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb8d40a6171db74c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x657a1e092a12220au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfaa32ac74bdb8d0eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xad1a593b131a2d5eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x6d24ec1077518d2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd949aaf13804f00bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd7ccf0cc8e3d5490u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x43c27e86bd7a4298u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x142daad4fc433fc8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3216c7edc2734e82u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1e60dd04788af156u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa002365e129d7e9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0d12c73b9574b42bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x6ee8fd215161dd2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x321217f6d9159e68u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe1fc3fbd6369a12du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x520678e38948785fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9cdd6f3b4d4d23b7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x2822419f4f824f9bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfe31b01871b2fb11u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3a1ad886ef805652u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x269a0978d481f7e6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x441c3d2800410d76u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x778e3026cc0a16b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x856d57e3bac9db44u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc4b120dded0c5381u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1b369b0b9bbde14eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x4875521e5dba4b99u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9e18d67993ec650du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf2788912e864530du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5fb963fca274942fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x93d244cbd02fdf77u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe0543c0bf31a5fdau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x16d7c5986815fb93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb466e9986701b559u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x10dfde2f3e48d1a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe94e3e9a858174eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1c9ecb3cfe946d8cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x97a4a42b391dde25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5b50271525bcd653u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xcf1babecba202465u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x538c9f1edce60832u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfbdc3af385b10bc1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd8fc4328aca4d204u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x873cf037093a3593u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7e12e310b2906e9bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb7845408e63fcdd4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x77919b3d1c17a6e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x79811f80509a55eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3b0bd17fdafd8a17u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0805c2bff933cd2du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3e08d8f4811dca0cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe8b70b7aff6f39aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x51c85028ab21eb34u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x6c18a5ca71912059u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x91b19ba40fbba1abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x6c8ed2638c87b488u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x758f11f61a7541d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xaa90f876b8c3298du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x84a5e35de938839cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xfe20417cfeb9af4cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xcc23ee349445b97cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xdef8fd230f1ba33eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x68b80cfc19a30007u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe05d1bb4d93832fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd66bfb61b8a969e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x737199c0da1411fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc556db53c725bf22u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb0e9d51b2c9a3621u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5d9fc7873cfcdcadu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xeb232dd06240a5c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x097196ede6df1f48u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x841e05b332a25b79u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x942c9afe8a0f5097u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xaf2a15ba13862a39u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xc403edb3dc3e076bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb089397e26e70c8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x365294284835fc2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9077b23ff4eef110u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x086b087967d239c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9cd6b65ebd6ad270u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x9dc261b8feb7d731u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xf9210db5c034fe5bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xb50ed63f80df872eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xdcbbccb8d58b7958u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x1388daedae87853bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x164c57570871530du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x710aa7001cb68558u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe91b0929f78ba5c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xa88b96d55884a87fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x5e99a19b09697f50u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x3532d34eb8c18f08u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x87a6f8b35a2be08du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x7fc43ae245af2e31u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x473a0ee02dee53e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x603c1d0dfef145afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x52fdb390a24b29dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x33f76c2df479f38au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xe47db86d36df45d4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0xd5523e833e3b23b5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Goldilocks>([0x0e43dc4f50c6eb0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-        ];
-        Arc::new(GmimcErfParams::new(12, 2, 314, &round_constants))
-    };
-    pub static ref GMIMC_ERF_BABYBEAR_16_PARAMS: Arc<GmimcErfParams<BabyBear>> = {
-        let round_constants: Vec<BabyBear> = vec![
-            f_from_words::<BabyBear>([0x00000000513d584eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011be30aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000010906fefu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000010fefb3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f1bbee8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004bdda048u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000278a4a83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000031fa0d05u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000067be0676u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e144023u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000019b3d16u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053424081u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034c6bd32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059c7e423u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077c66592u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027164e52u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000582313b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000003320567u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006033ec83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004a46146eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000229edfau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001077f812u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000499f8812u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000337a9bd3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001668140eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000f52c29cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004ee47c25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004f1c98deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000bd5454eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b5a323eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000248ab6eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000021f5d76fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003772cdedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003ab22c13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017b8c1cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051a3dcd3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012f6682bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005c60f67u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002eba3e7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c9bcbe5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001b3a1f9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027fe8923u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002d3f93c0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000054cf7fccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000045507c0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000072547b04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006593253au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005fa8b986u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064f22307u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006fb0c51eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003062d586u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060a8263au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070e0f101u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053ef10a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f76b9eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000031a60544u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e5d6643u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005d9221dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015f2ba42u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005a979a57u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004003e4b7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000167e5068u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001ac3630eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e705cb3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004530ec78u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000444823fcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005eb2de6fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003266e79au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e3f5741u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000008be34b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012f02001u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c975866u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000255eebf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000055f40d6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000212f7d5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000089d41e8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059f4d11au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000056583f1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002ed35bd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005e058d71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000138b24d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000099fb8a6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000f318759u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000383f1850u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000cf6caacu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002651d722u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002d2ac194u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053ce575eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000500d7638u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001abe57dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000c7a581bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000009e47d46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000018e3d9fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004b7c3444u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002c13a972u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f002755u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003d1a272du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000478c87f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061a6dca3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000042737751u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002fcd26edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002963fb89u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004bca14f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000752030d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001d2ea6b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000161950deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069ecae17u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000008f2740au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e058efau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c27d6d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016d807c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a3fd984u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c555d90u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030ebd9f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005115aeeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e1be4c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006ab6d027u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030bf77c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002721e4a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000f99b2d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002551cd06u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000a704452u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000478a4789u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000076e58d8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002408925cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000417c5fabu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000362010dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000728509d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002da84fe5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b9dac19u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000332bf80u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005579a7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006449fa98u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c4f875eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000153ddcbau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060734299u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b1d40c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000022862eccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000326b54b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070cf8bc0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003dafff59u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000047b7aedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000730c0746u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002c593af7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000045445b2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000363efe93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000088b8215u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006758b5b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000566f657bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005bfb81a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043c3108eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051d8edcfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000643c8760u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004d6d6e03u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000072bafb88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002b738fd0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065581d62u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000066eac950u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003d7de3a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002d0fcbfbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a9169f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001a77742fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000032bd4997u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005dfece6fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ccef06cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c238ad7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000531f4fb9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005558c31fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000020e2c0cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001721a62au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000019fde093u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c1e470au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003070f69cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000066fb71c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001534c30au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000267b8eb5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001773e2f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000019b900a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000047cd82a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000423d1afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000491bc709u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069a4c51bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000050f06ea6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000044307d5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b492c16u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000222a2fdbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000289b3facu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005fa23ab6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000267c1f88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003972ff7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000b8fafd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000072751d7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c6dd611u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059075f34u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000076b057c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000473985d3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000391e2d58u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013eeec12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002fbc776au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005369ba08u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012802101u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c316404u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000004bba037u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000062b6fe4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003299091du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000000e95632u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e3841dcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000049dc6076u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000082ec4c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e5f86dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006cfd1f3au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017a574abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006599a3b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000147657bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000515a132cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070a1db69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070fb7f7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011657c70u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041933097u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043280869u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ee6f548u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000298c0c23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004204c840u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003120559bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b33f4d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000042255feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000075fbfb9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000022f8ba4bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065f9357fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000023a988feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000075eba194u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000042101952u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000073078145u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001a3a8f75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b6b518eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000068ac8381u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000035b7adau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001ca09b92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000122bc95du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002c2571f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000066605634u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000020211414u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000375addedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059313c83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000430a08efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e04c26eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015fb1c12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c41a630u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069108ca7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000320006c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064a409b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006b322d4eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000006cc8093u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025f78fe6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005cd7ca25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003e29724cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005308acau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000bcf53d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000007f4fceau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000224b9085u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017e86cb1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000035c0411fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006cf3288cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000020608a58u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000058101d09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005eb8786fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006825ac43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000440a5be1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011ce2a71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000721c5642u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003019a2d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004cb7e808u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000085d42du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005260247bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000094b6a47u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b4c0eceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003aaa7042u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043890d5cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000044538bd1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000644a7f54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000002ed07e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059a62ceau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017a67511u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000143162f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000050cd6211u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000040507138u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012fb0553u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001fcf0f96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000031041a6bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000054d83cebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005ee1e350u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001144878cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006999ca46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011ca7667u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005fd16cedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064d18e78u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000696768cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000048489136u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000545fa719u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000045e0923fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000670239cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e421974u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007364468au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000438c6be0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000adb109fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000e193b84u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000270fb18u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a8711b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002a4b59f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034ffa064u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015941e53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000d080742u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003e187523u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030a3d7fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003cb46f33u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c222f93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000b0cadf7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002cc22116u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004275e2c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000033b22du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012c8df95u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000028760982u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000033caa8d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000636596aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000017b432u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004f695fadu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002508de9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064abda6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000077d98b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c944996u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065440318u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e0f4b48u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000076a18eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002cd39a40u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000632c9be7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000002fbdd9au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004b226f88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002437f39au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004fec2884u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000114a1759u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000231c3c0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000452d40efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004b683cbdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000024284c66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b3ea257u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016de29d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027284484u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000082917c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c8aa819u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000057179833u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002f4bf1edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000479da0f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006cbd4dd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016f8d97du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000048d8f306u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b7b8192u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034db97a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c27b39du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006253e5fcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061cc9813u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000636e072cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001180f6f6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005729815u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e392cdau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000581ae5d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003bf40282u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053f74eb1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025378975u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004082a671u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c268533u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000022278a2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017fcb3aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004f92475bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001933b0eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000a046ddcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005512fb4fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a808489u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000056cfa269u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002cb0dee8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004a57c9f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000583b4196u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f84a28au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006599603fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000028011097u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f3a70afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000063bc6a71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000054870cc7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a9104c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000275c8fe4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000760b82e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000039400b04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e57c194u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f6251a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e9aaabcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070003b86u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001299b0bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000023c24646u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e062804u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034e62115u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000009f61bfeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000029cf6636u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000046bcefe1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003e43e3e1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000029c48de8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000705e65ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000031e904a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b008572u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061ed3933u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f439ba0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004552e65bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006bab2551u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059b2930fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077b460a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ff91c8au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000076c33983u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000693c1851u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027027bddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c7f1486u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000037f82c47u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034768fe1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e5a6924u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004544a790u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000050993bd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041c46cb3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000026c8764au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a51154du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000219c007du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003e7ab371u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000055b77b0fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013b20afeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000496330fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000646997deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001a7e2776u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000260e765bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000055bb59afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a89a16bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005d8945d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000021eb399u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000390dc70cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004bd6ef2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000417fb538u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007317135fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000653c9bf3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000160f4ed0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000d1e90b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061885a27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000045a1c9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001238cd68u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000744540dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000466d682u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000620025e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000269982a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000036703693u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017330203u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004527ed71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000048809426u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000239c6d5cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000026af4fa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065c17950u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005492b0f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000188ff611u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001eaee227u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004ac5c740u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000207bec2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000c290940u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000044faba9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000379eadf9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b92b4c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003710d8b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002ff90883u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000354a863cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005cd53abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002ecce7ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006973133bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001f225e9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053316164u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002b086a2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001223344cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c152e7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000036299c83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000220b4e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038bd17a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005af969b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f493c57u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016cfcaa5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006bb1c923u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034c44fd3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000072811858u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000550b8776u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000029362a9du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a9e6754u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000021210d1eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005651a4d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004107d962u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064954779u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003da3f243u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069634d37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000039437e91u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c983f7du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030aea320u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000627ab05cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a6dbf27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000545a0424u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000046a12bf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005521cef7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051b78098u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004fceaefdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000010d3cbd4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ccaeb42u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000418e77e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007694dafdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002b7377f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000055eeb48eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000058122922u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069911ff8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005a75521fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000075a11c83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000007aeea8eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000405c1bbdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000068a0fd32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001715df55u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027d7646bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000b77d3f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000036d5ba2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e51aabfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002318177du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b56800cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000057892ddbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060200462u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000673fb07cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000018535651u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e9d782bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016222f97u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000051771ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f94f891u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000304ee8a7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041e4b722u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004d462573u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000135c27aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003209e1deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000063b82e9bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000028490029u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000014516772u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000049defafdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006192a9aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061fefa7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-        ];
-        Arc::new(GmimcErfParams::new(16, 2, 546, &round_constants))
-    };
-    pub static ref GMIMC_ERF_BABYBEAR_24_PARAMS: Arc<GmimcErfParams<BabyBear>> = {
-        let round_constants: Vec<BabyBear> = vec![
-            f_from_words::<BabyBear>([0x00000000513d584eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011be30aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000010906fefu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000010fefb3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f1bbee8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004bdda048u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000278a4a83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000031fa0d05u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000067be0676u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e144023u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000019b3d16u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053424081u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034c6bd32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059c7e423u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077c66592u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027164e52u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000582313b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000003320567u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006033ec83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004a46146eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000229edfau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001077f812u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000499f8812u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000337a9bd3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001668140eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000f52c29cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004ee47c25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004f1c98deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000bd5454eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b5a323eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000248ab6eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000021f5d76fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003772cdedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003ab22c13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017b8c1cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051a3dcd3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012f6682bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005c60f67u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002eba3e7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c9bcbe5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001b3a1f9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027fe8923u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002d3f93c0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000054cf7fccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000045507c0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000072547b04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006593253au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005fa8b986u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064f22307u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006fb0c51eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003062d586u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060a8263au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070e0f101u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053ef10a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f76b9eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000031a60544u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e5d6643u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005d9221dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015f2ba42u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005a979a57u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004003e4b7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000167e5068u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001ac3630eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e705cb3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004530ec78u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000444823fcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005eb2de6fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003266e79au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e3f5741u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000008be34b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012f02001u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c975866u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000255eebf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000055f40d6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000212f7d5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000089d41e8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059f4d11au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000056583f1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002ed35bd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005e058d71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000138b24d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000099fb8a6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000f318759u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000383f1850u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000cf6caacu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002651d722u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002d2ac194u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053ce575eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000500d7638u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001abe57dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000c7a581bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000009e47d46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000018e3d9fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004b7c3444u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002c13a972u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f002755u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003d1a272du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000478c87f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061a6dca3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000042737751u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002fcd26edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002963fb89u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004bca14f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000752030d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001d2ea6b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000161950deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069ecae17u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000008f2740au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e058efau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c27d6d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016d807c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a3fd984u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c555d90u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030ebd9f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005115aeeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e1be4c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006ab6d027u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030bf77c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002721e4a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000f99b2d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002551cd06u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000a704452u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000478a4789u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000076e58d8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002408925cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000417c5fabu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000362010dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000728509d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002da84fe5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b9dac19u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000332bf80u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005579a7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006449fa98u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c4f875eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000153ddcbau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060734299u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b1d40c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000022862eccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000326b54b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070cf8bc0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003dafff59u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000047b7aedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000730c0746u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002c593af7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000045445b2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000363efe93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000088b8215u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006758b5b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000566f657bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005bfb81a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043c3108eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051d8edcfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000643c8760u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004d6d6e03u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000072bafb88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002b738fd0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065581d62u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000066eac950u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003d7de3a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002d0fcbfbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a9169f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001a77742fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000032bd4997u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005dfece6fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ccef06cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c238ad7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000531f4fb9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005558c31fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000020e2c0cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001721a62au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000019fde093u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c1e470au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003070f69cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000066fb71c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001534c30au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000267b8eb5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001773e2f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000019b900a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000047cd82a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000423d1afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000491bc709u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069a4c51bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000050f06ea6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000044307d5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b492c16u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000222a2fdbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000289b3facu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005fa23ab6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000267c1f88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003972ff7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000b8fafd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000072751d7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c6dd611u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059075f34u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000076b057c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000473985d3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000391e2d58u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013eeec12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002fbc776au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005369ba08u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012802101u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c316404u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000004bba037u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000062b6fe4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003299091du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000000e95632u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e3841dcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000049dc6076u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000082ec4c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e5f86dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006cfd1f3au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017a574abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006599a3b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000147657bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000515a132cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070a1db69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070fb7f7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011657c70u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041933097u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043280869u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ee6f548u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000298c0c23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004204c840u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003120559bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b33f4d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000042255feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000075fbfb9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000022f8ba4bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065f9357fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000023a988feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000075eba194u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000042101952u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000073078145u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001a3a8f75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b6b518eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000068ac8381u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000035b7adau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001ca09b92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000122bc95du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002c2571f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000066605634u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000020211414u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000375addedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059313c83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000430a08efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e04c26eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015fb1c12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c41a630u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069108ca7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000320006c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064a409b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006b322d4eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000006cc8093u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025f78fe6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005cd7ca25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003e29724cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005308acau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000bcf53d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000007f4fceau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000224b9085u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017e86cb1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000035c0411fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006cf3288cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000020608a58u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000058101d09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005eb8786fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006825ac43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000440a5be1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011ce2a71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000721c5642u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003019a2d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004cb7e808u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000085d42du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005260247bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000094b6a47u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b4c0eceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003aaa7042u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043890d5cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000044538bd1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000644a7f54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000002ed07e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059a62ceau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017a67511u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000143162f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000050cd6211u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000040507138u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012fb0553u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001fcf0f96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000031041a6bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000054d83cebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005ee1e350u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001144878cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006999ca46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011ca7667u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005fd16cedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064d18e78u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000696768cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000048489136u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000545fa719u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000045e0923fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000670239cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e421974u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007364468au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000438c6be0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000adb109fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000e193b84u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000270fb18u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a8711b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002a4b59f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034ffa064u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015941e53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000d080742u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003e187523u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030a3d7fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003cb46f33u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c222f93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000b0cadf7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002cc22116u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004275e2c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000033b22du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012c8df95u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000028760982u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000033caa8d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000636596aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000017b432u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004f695fadu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002508de9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064abda6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000077d98b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c944996u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065440318u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e0f4b48u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000076a18eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002cd39a40u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000632c9be7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000002fbdd9au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004b226f88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002437f39au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004fec2884u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000114a1759u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000231c3c0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000452d40efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004b683cbdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000024284c66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b3ea257u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016de29d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027284484u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000082917c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c8aa819u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000057179833u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002f4bf1edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000479da0f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006cbd4dd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016f8d97du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000048d8f306u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b7b8192u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034db97a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c27b39du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006253e5fcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061cc9813u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000636e072cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001180f6f6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005729815u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e392cdau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000581ae5d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003bf40282u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053f74eb1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025378975u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004082a671u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c268533u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000022278a2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017fcb3aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004f92475bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001933b0eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000a046ddcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005512fb4fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a808489u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000056cfa269u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002cb0dee8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004a57c9f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000583b4196u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f84a28au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006599603fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000028011097u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f3a70afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000063bc6a71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000054870cc7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a9104c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000275c8fe4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000760b82e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000039400b04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e57c194u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f6251a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e9aaabcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070003b86u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001299b0bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000023c24646u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e062804u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034e62115u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000009f61bfeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000029cf6636u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000046bcefe1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003e43e3e1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000029c48de8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000705e65ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000031e904a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b008572u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061ed3933u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f439ba0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004552e65bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006bab2551u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059b2930fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077b460a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ff91c8au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000076c33983u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000693c1851u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027027bddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c7f1486u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000037f82c47u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034768fe1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e5a6924u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004544a790u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000050993bd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041c46cb3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000026c8764au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a51154du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000219c007du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003e7ab371u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000055b77b0fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013b20afeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000496330fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000646997deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001a7e2776u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000260e765bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000055bb59afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a89a16bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005d8945d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000021eb399u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000390dc70cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004bd6ef2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000417fb538u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007317135fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000653c9bf3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000160f4ed0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000d1e90b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061885a27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000045a1c9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001238cd68u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000744540dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000466d682u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000620025e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000269982a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000036703693u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017330203u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004527ed71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000048809426u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000239c6d5cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000026af4fa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065c17950u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005492b0f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000188ff611u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001eaee227u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004ac5c740u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000207bec2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000c290940u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000044faba9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000379eadf9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b92b4c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003710d8b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002ff90883u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000354a863cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005cd53abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002ecce7ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006973133bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001f225e9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053316164u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002b086a2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001223344cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c152e7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000036299c83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000220b4e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038bd17a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005af969b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f493c57u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016cfcaa5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006bb1c923u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034c44fd3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000072811858u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000550b8776u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000029362a9du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a9e6754u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000021210d1eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005651a4d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004107d962u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064954779u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003da3f243u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069634d37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000039437e91u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c983f7du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030aea320u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000627ab05cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a6dbf27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000545a0424u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000046a12bf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005521cef7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051b78098u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004fceaefdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000010d3cbd4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ccaeb42u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000418e77e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007694dafdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002b7377f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000055eeb48eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000058122922u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069911ff8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005a75521fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000075a11c83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000007aeea8eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000405c1bbdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000068a0fd32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001715df55u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027d7646bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000b77d3f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000036d5ba2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e51aabfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002318177du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b56800cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000057892ddbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060200462u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000673fb07cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000018535651u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e9d782bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016222f97u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000051771ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f94f891u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000304ee8a7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041e4b722u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004d462573u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000135c27aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003209e1deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000063b82e9bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000028490029u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000014516772u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000049defafdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006192a9aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061fefa7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000074475dddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001f384767u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000486ae6f9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000024c2b873u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070cc5078u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000f96c03au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005fcf8ae1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b04bb53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012bb05ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003419f2c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004622876au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000031a5c946u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000019fea509u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005132e474u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017bfa27cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005808f187u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000029587e7du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001cc63842u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000156f7198u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000a6971f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005388ece2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000a41c039u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000288b6f09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000046a02f3eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000037640862u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000006adcf31u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e1c09a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006280a454u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000007743e88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e557aedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038211dd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003e7e4a9au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012fb3a60u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077b0a4afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000057aaa8aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005e8e602au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000023f507c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002c1e1b97u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a395d31u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c858ebcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c0f79bau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b1d697au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002595aa2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011eb6242u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006273d641u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006375285cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000665571e6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006bb2854cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000fb4880eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002678c52au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000042ecd60cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000023bfe87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006f93ed36u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005d69a0abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001126275au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ef36effu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003cb12e46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000002eac992u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ae5495cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e697060u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000fa319c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053f67262u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000010f25998u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c7fb9d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003ced9728u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000006d407e8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002002042au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077408eb6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000045d35ee2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000456d6b0au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000072168630u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000006096e54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013499753u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005019519u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061e66fb8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000238e6448u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000007494610u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005128c7f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060472db2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006450168du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005cb8dfbbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001897d6bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005d4de0dcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000000811561u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004da808acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061e9286du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000c357a7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065577887u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004a095705u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e11c09du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000008834a0fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000059891bb8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000368b08fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c3efeebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000485be424u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000008e683bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004641160bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007667545cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005d809420u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003edec6f9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002e017bb3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003900f1a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000024b5c55cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004396dda1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003681c7fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000054dd9d6fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000022433c22u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000033883638u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025ec9aabu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015f07834u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000003e7599cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000039a5adfbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000021f00241u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000334e88b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005451e289u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c060dedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003bba031eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003d72a892u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001004d898u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000020b41708u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002931221eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003977ae73u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000020e1afebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005ff1a3dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006cdff642u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005250e17eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000056acaeecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f8da1d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000012e6095u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003980eeb1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003278b784u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003824dfb2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004da98e96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004918f926u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c5b474cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003fbc5941u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006bd4b933u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000541d3b9du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a2ebf92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000014de1bceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007513a21bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005aafce29u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000724e07e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a9f3f9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000d175c16u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000053604acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000050734348u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000297e372u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004385cf7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006151a897u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000168c2b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043c34f7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000001fd2104u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003bf0fef7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000569ca76u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005faad2c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000779a2561u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000487a1c4eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000595f739au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005e700e18u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000018a79313u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000067dc213du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000235a46e5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003677299fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069cb390au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007045c9cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043bc651au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005bdadb33u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000032e57c98u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000018832109u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000058f2b587u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000575f8ef0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005a627fffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041f04ff7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000c5a20fcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000626df410u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007730985au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000517f2ffeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000019a45effu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002727fa70u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000006713572u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005672e48bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061c0e0a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007516b7a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f44398cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005481029bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041cd4484u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069ea051fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027b86db7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000156274ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011d0002fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005cfba111u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000238db866u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000a045fa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002adbeb4fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005125e047u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000076e9bbe9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000073ed6b35u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005249e882u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002cb0da9du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004796b5c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e61ce5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000046e0141cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000008b924cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000470fe60du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a289f02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000487219e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004ae5c9ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000076903d53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000055af436u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005afb862au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000047121362u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000022d10bd0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000072d55f75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000039a14b3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a21cb4fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000077e3defu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002f538156u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003e60a469u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002df5bc4eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000393dd42au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ee47ce8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000068722673u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002c07a3eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000638c314u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005a60d5e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000000fa29f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002c257241u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007226eea6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000007ffe627u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003d95d359u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001ca1efb0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006478ee1bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060757937u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038355d0eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a5e9892u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053bc9b64u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004233abe6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005ae9a327u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001910176au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004a82a70bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000012ae99f9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000235b3866u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001b65193eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000676cd621u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005023232au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051c36e69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038e64438u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070980cbeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000491e2f1bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004f3b61cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053f7d706u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000edc043au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005478e89eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001dc2548cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f9ad312u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007208c22cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013e86124u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000154d4df9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000029b95b17u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006313c677u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000584dba9du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003660ec06u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000021e64e50u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001652615cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000044d0280eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000027bac696u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000007653b04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000009011dd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000643246aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f88b0abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000073f7f9c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004af8fd23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e366446u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e99782eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015142a86u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000074483794u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004be021b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003550778au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c4d10efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000618e9e70u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003dd482e1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003be95cc9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077153cf2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004be3607eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a780930u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000318145bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011622006u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000080d07d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005cb02e96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f3dea8au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000074bb348cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004a9591bau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000075f5f1d4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005eceace1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000539b3cadu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000062f3eb84u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000074403febu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000035a7eb34u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006eb6dc01u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000238400adu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003dcf5690u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000654786afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000b0baaccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025d2344eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041c1f711u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000f8128fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003596168bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000052d1ac09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000396f8c9bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043abaeb3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006445f123u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053237d3du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000664b3125u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000719fed17u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004b33ac5cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043a05db8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006ec2c22cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000026bc4ec3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001122374au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004df23d67u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051abca6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004da25af8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005daee128u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004f1a0fd3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017a6497cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000074cf2f28u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c100c80u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000538066au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000069e2936du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007104e7bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007797fb4bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005ee67dc4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000026400a9au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017aebe25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000358b14f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000056140671u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004fb29e08u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004f3b9c0fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006282e431u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000061e363e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000303b1d7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000040f7a543u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006dd382e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e34b664u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004890fddcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041af62c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077772a02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004d597388u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000267d4eb2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000741f3c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c39cd59u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007025f2ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b1bbd7au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000071587cb5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000379a0b45u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000043e77c12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000019ff5f10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000021b56349u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006d6bda27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000033d0c1d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000058fa442au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002b4e8ed9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004555267bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e652dacu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000807da8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006887754eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000064fc9868u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001cb410d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000029146dceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013c39dc6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005e75d3e3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000033172b5bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e786795u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002592e555u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000f01666bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000033f087f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000052746481u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003d8530dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060e69c22u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001f4b2628u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004392a9fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002198f38du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000002b73e2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015026ed8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000057022a92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000076246e3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000109211d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e74006au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077ea9937u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017dab9a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000032a7ead6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000047553e25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001a406c6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000003966f0bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000071f4d174u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000244a8a82u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000041706c36u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003ca7c77bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077b3cfc2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053c8a9b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c475a24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000691a3cb8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000679cc7a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002b18f3d3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005e62258eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000841e180u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006da7a6d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000007d87f67u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000002fee508u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000058f2935au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000002e7b08u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000587dd787u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000001325d5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000721c9d77u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000050d438b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025c2a1b3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001099159eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004a15d5b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005263fac7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000048569b98u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000600914ceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b7d6206u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000052d2185du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000597e0df7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000071bd870eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000066b188bau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000010971095u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000028f1284cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004bbb45c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007256ba8cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004cdad303u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000d1d31f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004342238cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000645f87a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025f60e93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c5695d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004fc3289cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000062b5d821u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000733042bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053fee160u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006b83e207u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000032dc5e59u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051607c19u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000070759fc6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000019b46c34u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003b4f0271u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001abb5880u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000382b1bd0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000036f16efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006d409f77u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000618cff97u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065864c23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004be77e31u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000014c216ceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005529b24eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f122ef4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e62badcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006ab3fb2au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003ab6addcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038d1b75fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000039abf91eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002912cf62u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002a8dae8eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000b3d9908u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004e6c8308u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e46ec77u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006d404789u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000298ba755u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034b93b5au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c898ce6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000646e388au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025de2453u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000694e1fc2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001e921322u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003678785bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004ea45e8au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c852ad9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c2088edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002cbe5e7au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060eca0e8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000067c3e71bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015d899ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005ca871c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000046500b7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004b723229u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e39daaau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000047f79843u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000073cbb3d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e452844u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000310bd7c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004d4f798du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005d4b0177u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000058a376d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f40a86du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005faa5595u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002178f584u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004fa03f26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000153557fcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000022c6002u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003368b761u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030bcdfb0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000017bb0fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000049b242f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001a53bea5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005cfb678au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f48e517u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000173e3439u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000273b4cb1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005a4c469eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002ee5a41cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006278a81cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b31e33du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013bc435bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005d5a6a5cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000077568628u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002c97d150u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000011c390bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030394372u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004d2f60a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000071060e3du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051d26c12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000018997706u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005cb7b098u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000563b6c6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003474e227u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000270e876bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002f675366u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000017940b45u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002b118952u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000065592bcau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002959ec26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005dd16c53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c5f689bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000533ed648u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000018d2f3ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000032dbaf52u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f899dbeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007148b0bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005c5eb761u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038f3ce28u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000fc7edd0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000071d85eb2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001ade2c66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038f7aa2eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000023417f72u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000062096e7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000021fe280du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000313bf156u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000587da211u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000125232eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000177aa199u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000062ce9b19u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053fc1264u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053341f8fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001fc45360u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004789311bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006eadc1ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025f83cedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000025d3d0feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002f017157u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000016cbb37eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000051833891u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000158087e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003a601e42u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000049784c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001dd0e64fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000053f853b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001cff1c24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000044134b40u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001f249b07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000ed22e31u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c2fa2a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000565b2c54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000034200196u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000037f0c3dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005a93779au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005df71e76u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000449fe604u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013c89b88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013b6e996u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003c662a36u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000074f197d4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000015ae5ea9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005b541561u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003d9f0323u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004db63e9bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000111a55ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006193ff04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000009330423u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003dc4a3a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000071559040u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000649fd44eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000350efa9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000413b7102u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e11de71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000b821a00u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000c6d33fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000044f4e667u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000021bbae43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004942a393u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000013a0919u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000007443a5c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002811a1b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000060e9ba4du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006a1281b5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001a71e245u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006267b386u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000c50067cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000b1ce304u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000280344fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004eaa0017u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000012c76fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000062a4aa73u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c616323u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000005147122u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000005f169cc7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000292fa87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006c17da2du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003bd5c9ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000009c7f8aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038bcda1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003eadd15cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038468c37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000030d532d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003898fbeeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006f30dfcbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003534c69fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003eda7681u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001db6f147u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000006e246d8au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000056d60a46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004cea97b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000038bd73acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013979adbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001a62d66au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001efb31cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004baea63du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000a908abfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000013c0eafdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000022a04679u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c868fcau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003f6b3bf6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004880e4fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000682d180bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000001c74abcau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000052e8c46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000c6093f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004c13ae39u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x00000000542fa205u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000002b5b7585u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000003ab52bfdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000000acba0dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x0000000039c17986u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004958087cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<BabyBear>([0x000000004353bef6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-        ];
-        Arc::new(GmimcErfParams::new(24, 2, 1202, &round_constants))
-    };
-    pub static ref GMIMC_ERF_KOALABEAR_16_PARAMS: Arc<GmimcErfParams<KoalaBear>> = {
-        let round_constants: Vec<KoalaBear> = vec![
-            f_from_words::<KoalaBear>([0x000000003c14f38bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004084221eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000561fb773u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001b1178edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000025e0c6a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003983776u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000477e7a4cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000754fb5cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000084a31bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000004749191u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b821226u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000045d0cf8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000040660d18u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a83481au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000058b374fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000020c4b04fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000047113777u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000051e071c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006a4c1cc3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d147dd6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001bbfcb18u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064a4e685u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031aa52e8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002cb7d4d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e4b9ee0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005616c56au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004b23c465u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d6cc776u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001766cf26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058b991c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000011339550u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005ddb4784u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000014f01b9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007a5d551u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000509e1a5eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d0a0f44u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003f5144e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000072030fefu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000045f14c07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001a78ce3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000324aedd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001eb123c7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000569093bau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000019303ad3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067621f68u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056b80efdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000353b4ce2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001035f9bau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005448aa83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000484bcab4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002e8bf673u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002586278au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000043dd4f26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ec4fbf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002b84f22u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002de796b7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000385d61fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000054fdae23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000237e7c31u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002a3dd3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c10b9ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000639acba8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056f01419u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004a829892u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000048747efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006441796u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005f9f8beau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000756ac5b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059f21af7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000008602179u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d65dcd5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005ddd4839u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001b742026u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000155082b5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000015b15259u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000066f995ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000066f14624u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000029a1af27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000579c4a4fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000008c5d335u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000649e232fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006a8cb7edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e6c3511u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000174d5252u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000754aab05u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000493fbdb5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000015ec5abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000752dcb81u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f1590b3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000276d95e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004445e0bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062b6d243u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a957237u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006a36dbbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d4cbc9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000076f46ed3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053758af5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000685f1e88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005482b69au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005a2f46d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000a78daaau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001dd8f857u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005b821a88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059526cb4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004808c053u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003a60460u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000524dfca3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000052fb046u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003410968au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a005ebfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005efa59aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000271f146cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000c6daf56u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000521bac7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000505a76d3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000018aeff8cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000030d3c27au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005bd7d961u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002185f549u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000311aaec6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000032ee74a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056ab5bbdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002c6e2cf4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e102bdeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ddb2e46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ebf7839u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000651b06c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010dd916eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000371314deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000020d9c954u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000694ee516u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a81b16eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000419e04c7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000270c158fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000322531c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001dc65fd6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058b08683u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000014a976aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000425428ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000028232e09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000054b862bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006bc33a0au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f2f1376u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000029c010a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000048f72366u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067588132u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e2786c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004670ed96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e0cd20bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001850cf9au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003377ad03u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a4c453au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000070111b23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000547a594du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000627d96b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002c76eb3du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000061d9f5f8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000034929cc2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d4067ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006b0dae75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000075b92bbcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000411ba765u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e40aa04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004aac1e2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009b05493u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007fdb0b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000057e5f992u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005a8dac18u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000105e277du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b115a4bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000752ac40au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000046a0e6c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005e76c6aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074d8de3du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003e132995u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d467fe6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007955d2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000590f3754u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000069182c79u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001ef11eb0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000012521f4cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000076341b51u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006676dbf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d9bd110u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c706560u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e6530ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002029b517u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001aaf84abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000069a7a5e4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059f23701u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007953b747u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a436cf7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005973d618u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000ace9dfbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000051952a68u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005deed397u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004480d32bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002831e2b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038bae603u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000238fc932u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000299a76ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001bd6b26fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000050a4f72fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c616518u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002e747facu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000094699cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005596de5bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006f15614fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000057563e3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d6105b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e276f2au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017feff9eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c30ca6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000013e6094cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000d40342eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005037365du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006fa6c66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c439975u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003b5d0ef6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000597d17bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002b7203f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017c39c53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017c1df3du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000043fd0dcbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058b9d75au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000534c882cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000f7c5e63u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001c0ba384u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003930175u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003924c776u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000063c274d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000168e4fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006fb1d3f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000617ad1c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002807498u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062e63a9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000042cd4137u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000065428bddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b05651du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001b05f54cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000744de533u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002f457d3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000015bf1f69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d73f8d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002139bccdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f835e1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031d083e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001fe2c180u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f649b97u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056efc8b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c44b89bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053d50152u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003574585u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067d66536u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000030f17f7cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058b0f96cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000037d3ff62u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d1d81c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a6fc0fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000095d7b02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000077d43c0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c8be273u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000776ce28eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006f47c4fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ed6bfd5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000624c6e4du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000076a0f3c0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000041d197fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000068b33ed5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007987c765u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ae47dc5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002c9d05d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000069ffef6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000004818ff5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000034573f87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055a2c34du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059cc9600u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005dea62f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062bbbbf0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001934fd58u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000419e12bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f7d001cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000019ece9fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004e3b6bf2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000045383970u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007050d049u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004bac479au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000314881ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006392423du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006563a62cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000794b57e6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d20000eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004278a179u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004acaa7e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000676e7a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000041ddad8cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d249338u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058439d58u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074b0705cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000048440284u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000394ce54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d53f9d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000036d71ac3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000026e88fe6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000034fd823eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000044eefcc5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000170306acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f1909f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064672d16u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000023f5ffaeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009096badu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a04ae88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005a54c5e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005e4f4c49u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005e5cb867u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000415e8516u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f7faf24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004af16c4eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000034dbbdb7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002534ecd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001196c8f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f1217e1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004a3a4030u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004af8e9ceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005679fe49u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067e9111du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007afd97d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000019eb6812u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001b087457u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000039ca8c14u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003598f9b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000442fd4fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000537ec68au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e393f8fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000072e80816u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000753cfb09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b92aab1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002045edfau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038a639d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000574d225fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003d7423a6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c168a4cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000415679bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000029b17b54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001209c5a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003f3f4872u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005759fb91u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d287015u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000037785c3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000de31c38u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003a1e2c39u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d7db8f6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002086aaf7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005a852174u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000ae784b5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003506a445u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c1a1ab0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059083fc7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000327d8aa2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e175329u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006d73bb20u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003f75d623u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000300eb54au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000021b8b763u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000687b778eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003646d5bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002b74f9d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007458e14eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000123f7d53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000796a418du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000025290d2eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003b54ef05u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000032511931u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000783b282au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002efeb74fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000064d7f5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002ba169au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005b7301ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000001bfa948u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004264c772u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005afc6fc8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000e1b1b39u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000061343c88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006d3e58cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064dc56b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000008d64f60u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000051a958bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006147f338u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000069840cd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000457ccf87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007ec97e8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062ba1ab0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000072f5dcbbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000021141a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000aa1f718u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003d79bd54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003e0cde92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067643910u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000345d2f02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002dac796u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010aef941u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005247bf72u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e620eaeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000e3e7155u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ea574bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007115b818u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000004bc9f5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005249e684u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000046b7b136u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000784302aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007ea7f529u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031a41b3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005594e1b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009cb084du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000033001564u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003be83e3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006ba88911u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ca7701du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000549e0bd8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005fa0088fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000063466a81u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000df8f4a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000be04970u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007af2ffeeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d9e84ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000264c6b6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005afa08c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000068f0d8a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001758b5cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000440f343bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000005281655u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000398e1d73u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a4a8972u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000113f6f93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000442e389u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006f92cce5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000004f92310u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000028d6dbf0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000192e34cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000385742f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074710f2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006618a1fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000276a485au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003f7725ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000068f3832bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002cbfd6bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000f4ef148u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055a365b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017697a64u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000032452223u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000071b000bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000075eda9ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000065425f95u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002fbbd554u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000037674d37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001efba68eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000057dc5d7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000325fe853u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e7faceau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f471a03u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010c3cd6du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000011dd423fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009fee6e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000172b88f8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004db343bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000079b25a04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000464cef67u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000017d3de4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000274cff32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f0c4b04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000442ba111u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053e64fd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d02ab3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001a8a5eedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007bcd508bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000052eefe32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001c2b12a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002cee01fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000213e66bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000532984dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000eb957b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000022a39de7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004ebb4735u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006a3cda3eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004dfa4003u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000039a2d490u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003021e263u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000074c6595u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000013f26f09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d63e42eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000018321da5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005b0b909bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000142644au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003767cdd5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006235db24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007207486u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010b0958au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001fdce49fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003c1b67ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031a17f04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000107b7d39u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000071383a0eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000063f44861u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003e647644u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000052c33694u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c210489u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002dce3717u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005265e81fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000210aceb3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000666ebef2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006718fa28u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055027ba8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000656f141du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000706ec8b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000027c9f79du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007cfd3829u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004bbe6315u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000698418beu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ebabba2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000018cdd527u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000023f8faf5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055352b32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001660a6ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000050173a17u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d9694f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000005709ee7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007dcde2d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000044b8a29u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000f7d75d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005256b3c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004555c1d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003c455bfau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006cf71d82u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000118db2efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ac1da8eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000035a244d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074e4301cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006affd047u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000050e2be90u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001865a5edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d809969u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038cd0228u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001297f507u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007796d7cbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000057ca2b46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c9e0f96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-        ];
-        Arc::new(GmimcErfParams::new(16, 2, 546, &round_constants))
-    };
-    pub static ref GMIMC_ERF_KOALABEAR_24_PARAMS: Arc<GmimcErfParams<KoalaBear>> = {
-        let round_constants: Vec<KoalaBear> = vec![
-            f_from_words::<KoalaBear>([0x000000003c14f38bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004084221eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000561fb773u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001b1178edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000025e0c6a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003983776u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000477e7a4cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000754fb5cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000084a31bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000004749191u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b821226u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000045d0cf8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000040660d18u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a83481au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000058b374fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000020c4b04fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000047113777u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000051e071c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006a4c1cc3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d147dd6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001bbfcb18u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064a4e685u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031aa52e8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002cb7d4d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e4b9ee0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005616c56au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004b23c465u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d6cc776u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001766cf26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058b991c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000011339550u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005ddb4784u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000014f01b9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007a5d551u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000509e1a5eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d0a0f44u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003f5144e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000072030fefu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000045f14c07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001a78ce3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000324aedd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001eb123c7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000569093bau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000019303ad3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067621f68u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056b80efdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000353b4ce2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001035f9bau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005448aa83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000484bcab4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002e8bf673u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002586278au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000043dd4f26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ec4fbf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002b84f22u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002de796b7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000385d61fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000054fdae23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000237e7c31u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002a3dd3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c10b9ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000639acba8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056f01419u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004a829892u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000048747efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006441796u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005f9f8beau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000756ac5b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059f21af7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000008602179u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d65dcd5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005ddd4839u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001b742026u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000155082b5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000015b15259u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000066f995ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000066f14624u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000029a1af27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000579c4a4fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000008c5d335u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000649e232fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006a8cb7edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e6c3511u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000174d5252u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000754aab05u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000493fbdb5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000015ec5abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000752dcb81u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f1590b3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000276d95e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004445e0bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062b6d243u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a957237u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006a36dbbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d4cbc9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000076f46ed3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053758af5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000685f1e88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005482b69au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005a2f46d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000a78daaau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001dd8f857u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005b821a88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059526cb4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004808c053u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003a60460u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000524dfca3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000052fb046u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003410968au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a005ebfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005efa59aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000271f146cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000c6daf56u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000521bac7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000505a76d3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000018aeff8cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000030d3c27au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005bd7d961u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002185f549u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000311aaec6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000032ee74a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056ab5bbdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002c6e2cf4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e102bdeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ddb2e46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ebf7839u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000651b06c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010dd916eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000371314deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000020d9c954u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000694ee516u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a81b16eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000419e04c7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000270c158fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000322531c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001dc65fd6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058b08683u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000014a976aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000425428ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000028232e09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000054b862bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006bc33a0au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f2f1376u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000029c010a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000048f72366u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067588132u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e2786c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004670ed96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e0cd20bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001850cf9au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003377ad03u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a4c453au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000070111b23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000547a594du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000627d96b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002c76eb3du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000061d9f5f8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000034929cc2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d4067ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006b0dae75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000075b92bbcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000411ba765u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e40aa04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004aac1e2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009b05493u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007fdb0b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000057e5f992u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005a8dac18u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000105e277du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b115a4bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000752ac40au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000046a0e6c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005e76c6aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074d8de3du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003e132995u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d467fe6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007955d2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000590f3754u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000069182c79u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001ef11eb0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000012521f4cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000076341b51u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006676dbf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d9bd110u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c706560u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e6530ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002029b517u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001aaf84abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000069a7a5e4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059f23701u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007953b747u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a436cf7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005973d618u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000ace9dfbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000051952a68u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005deed397u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004480d32bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002831e2b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038bae603u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000238fc932u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000299a76ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001bd6b26fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000050a4f72fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c616518u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002e747facu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000094699cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005596de5bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006f15614fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000057563e3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d6105b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e276f2au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017feff9eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c30ca6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000013e6094cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000d40342eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005037365du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006fa6c66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c439975u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003b5d0ef6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000597d17bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002b7203f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017c39c53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017c1df3du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000043fd0dcbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058b9d75au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000534c882cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000f7c5e63u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001c0ba384u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003930175u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003924c776u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000063c274d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000168e4fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006fb1d3f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000617ad1c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002807498u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062e63a9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000042cd4137u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000065428bddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b05651du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001b05f54cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000744de533u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002f457d3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000015bf1f69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d73f8d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002139bccdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f835e1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031d083e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001fe2c180u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f649b97u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056efc8b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c44b89bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053d50152u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003574585u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067d66536u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000030f17f7cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058b0f96cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000037d3ff62u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d1d81c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a6fc0fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000095d7b02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000077d43c0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c8be273u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000776ce28eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006f47c4fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ed6bfd5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000624c6e4du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000076a0f3c0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000041d197fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000068b33ed5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007987c765u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ae47dc5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002c9d05d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000069ffef6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000004818ff5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000034573f87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055a2c34du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059cc9600u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005dea62f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062bbbbf0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001934fd58u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000419e12bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f7d001cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000019ece9fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004e3b6bf2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000045383970u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007050d049u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004bac479au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000314881ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006392423du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006563a62cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000794b57e6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d20000eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004278a179u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004acaa7e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000676e7a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000041ddad8cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d249338u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058439d58u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074b0705cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000048440284u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000394ce54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d53f9d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000036d71ac3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000026e88fe6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000034fd823eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000044eefcc5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000170306acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f1909f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064672d16u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000023f5ffaeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009096badu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a04ae88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005a54c5e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005e4f4c49u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005e5cb867u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000415e8516u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f7faf24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004af16c4eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000034dbbdb7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002534ecd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001196c8f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f1217e1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004a3a4030u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004af8e9ceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005679fe49u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067e9111du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007afd97d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000019eb6812u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001b087457u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000039ca8c14u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003598f9b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000442fd4fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000537ec68au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e393f8fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000072e80816u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000753cfb09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b92aab1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002045edfau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038a639d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000574d225fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003d7423a6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c168a4cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000415679bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000029b17b54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001209c5a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003f3f4872u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005759fb91u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d287015u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000037785c3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000de31c38u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003a1e2c39u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d7db8f6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002086aaf7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005a852174u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000ae784b5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003506a445u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c1a1ab0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059083fc7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000327d8aa2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e175329u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006d73bb20u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003f75d623u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000300eb54au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000021b8b763u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000687b778eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003646d5bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002b74f9d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007458e14eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000123f7d53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000796a418du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000025290d2eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003b54ef05u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000032511931u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000783b282au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002efeb74fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000064d7f5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002ba169au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005b7301ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000001bfa948u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004264c772u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005afc6fc8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000e1b1b39u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000061343c88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006d3e58cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064dc56b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000008d64f60u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000051a958bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006147f338u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000069840cd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000457ccf87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007ec97e8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062ba1ab0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000072f5dcbbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000021141a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000aa1f718u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003d79bd54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003e0cde92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067643910u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000345d2f02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002dac796u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010aef941u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005247bf72u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e620eaeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000e3e7155u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ea574bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007115b818u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000004bc9f5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005249e684u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000046b7b136u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000784302aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007ea7f529u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031a41b3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005594e1b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009cb084du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000033001564u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003be83e3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006ba88911u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ca7701du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000549e0bd8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005fa0088fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000063466a81u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000df8f4a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000be04970u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007af2ffeeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d9e84ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000264c6b6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005afa08c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000068f0d8a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001758b5cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000440f343bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000005281655u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000398e1d73u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a4a8972u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000113f6f93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000442e389u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006f92cce5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000004f92310u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000028d6dbf0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000192e34cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000385742f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074710f2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006618a1fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000276a485au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003f7725ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000068f3832bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002cbfd6bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000f4ef148u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055a365b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017697a64u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000032452223u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000071b000bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000075eda9ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000065425f95u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002fbbd554u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000037674d37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001efba68eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000057dc5d7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000325fe853u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e7faceau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f471a03u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010c3cd6du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000011dd423fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009fee6e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000172b88f8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004db343bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000079b25a04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000464cef67u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000017d3de4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000274cff32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f0c4b04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000442ba111u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053e64fd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d02ab3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001a8a5eedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007bcd508bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000052eefe32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001c2b12a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002cee01fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000213e66bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000532984dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000eb957b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000022a39de7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004ebb4735u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006a3cda3eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004dfa4003u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000039a2d490u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003021e263u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000074c6595u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000013f26f09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d63e42eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000018321da5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005b0b909bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000142644au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003767cdd5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006235db24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007207486u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010b0958au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001fdce49fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003c1b67ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031a17f04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000107b7d39u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000071383a0eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000063f44861u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003e647644u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000052c33694u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c210489u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002dce3717u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005265e81fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000210aceb3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000666ebef2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006718fa28u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055027ba8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000656f141du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000706ec8b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000027c9f79du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007cfd3829u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004bbe6315u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000698418beu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ebabba2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000018cdd527u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000023f8faf5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055352b32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001660a6ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000050173a17u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d9694f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000005709ee7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007dcde2d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000044b8a29u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000f7d75d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005256b3c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004555c1d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003c455bfau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006cf71d82u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000118db2efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ac1da8eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000035a244d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074e4301cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006affd047u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000050e2be90u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001865a5edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d809969u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038cd0228u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001297f507u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007796d7cbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000057ca2b46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c9e0f96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000481d39d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000072fb2ef1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006de3a499u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004ed5496au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c654d96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006081504au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000065b53e1fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002367876u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062aed66fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000e9f3204u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000768801bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000070f7fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007261d577u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001a001d08u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000020e6d3feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000020ef18d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000ebf5147u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004a771a4bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003375ba7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000054557cdbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031ddb5beu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074d4aff0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007712c378u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000037ae60bau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001ab386cbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000dccb8e6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000671524a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003493a94au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006715a2abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001029b122u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006642ffdcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000440eed29u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000055e9ff9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000022e25b29u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006d2a3204u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002883c1d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000078e9c3eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006801ba37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000337c463cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000d11ff92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002c90341fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004b3fbb75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000d839d78u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000070e31489u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002de36152u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000429e6162u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003417429au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000fe7af4du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000349f59d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038354f00u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002e99718fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000015132f8eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b5685e1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000086213feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d9b60beu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000037f4716fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000245bef1fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000036226339u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ba91140u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000208fc1b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002e4d69d4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038dc5475u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000330fa15du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000034b8af94u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000061e467adu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000529ab7cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053c87773u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000290e6f48u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000032676a33u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000014692837u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000014547c7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003aa309dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000336060b3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003d2f79dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007441ddbfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e520413u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000066d47482u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002648444bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ef19e39u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000203a65e3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000028fddbf8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005b628443u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000edad762u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ea3407bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000020d55ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a91e314u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000355fc30fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ea03c05u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004f915165u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000025c2b16du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005fb86d75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002dc5bf9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000024fc59fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003376bfa5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005939ed08u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006568a87eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000012caad76u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000229b07fcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001997b179u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000021f36503u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000337ab76au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000050695138u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005651f8c0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000076c90c60u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000697f0987u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e4e05e1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000079ab8239u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000812a919u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000016056ca8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003d8bc92du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005cc188d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002febde2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003ca44f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000021bffa47u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000090d5449u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000043cb9e82u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003715862cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000269726cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000653441c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000061cfdcf4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e721906u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000135f3f8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006973a53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053c995f8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000021cfcd48u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000424c068fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000072a8e83au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000016c6a79u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005280ecd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004a815d99u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002485b960u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000cbaa47fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000365b45a7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005607e567u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000994dbbcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005b84413cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000494aef5du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000a2b0ea1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000016433a64u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ef830eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000151e95f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000048c8eab5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000277d6af4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c965e62u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074c8e7cbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000110e9999u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000261c0cbbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000075c4ddd4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000575554cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000642f7ab4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000442a0a07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002980b16du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000069cc256du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003668db22u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000020c746c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003237db7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c8ad654u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001446aaa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006df1465au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004965eeebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000518cd175u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005531305eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005fa0f7fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000074bcd4b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000063315d09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005217f1adu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000588c556cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000792d6ccdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000008cb0af7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000021e1f2c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000008599420u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000232e8c17u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001628cfb4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d6a6dd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000030921060u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d2422c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d89f9e4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000036280f4au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001df4e1a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003aa714a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059cabeb5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006d063bf4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007825b2ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e24d8d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c471f99u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000532c6e6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000005956240u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000018db9be9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000065bd6714u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003c62c6e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004f85a237u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003c7a8a9au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003faae012u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002bffa915u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f0dc41bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000488091feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010953ed9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000b86600cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000eb42c39u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005963ea17u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000005107ddbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055a8045eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002bf4d887u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000696b7685u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009253a73u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000156e8b6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b78aa3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000025fb6375u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006a631896u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003f161e19u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006f434b65u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000340471d3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001eb3088au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002b606b0eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ee0f1fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000054a2c225u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000093bfc5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000028875232u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000554d868au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000703a55f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003c6f6ba9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006143f509u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004e11bc03u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004757122fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004b6fbb95u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000039dfa824u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e890996u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000239034a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c11c56cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000013cda8b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000666d6f21u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000076d2a598u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000049064cddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005f45e2a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000418a3744u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000175f4b0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d3dfc27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003bb5e7e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d90edbfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000054570f68u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007cdd0dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004389c5d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058bf6fffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007236637du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000040bc01cbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000446068f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006282bdc4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000ef07666u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002c81e45u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005349cbaau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000063b175b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006ce3e177u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000027c68ae0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d8421d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005491e8f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000742b4824u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000037520e87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d31c985u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ec0135eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017b24c02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000057785825u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000128a4057u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000072da79ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000331faf1fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000763868f6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000700bacb0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000340ee5bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000742e06cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006230bd95u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004bc1147au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e29a04du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000078affb86u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000189bca5du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038e40b79u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005d1f3584u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010662f61u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000175daec1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038f98358u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000bb983cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056d5f41fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000219d0b9eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000262946a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002059e942u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000957d068u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000016313181u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000065601fa1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002858d27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000f01a80eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003bd22d35u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005f63f1d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000036badd90u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000744ede3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e30c012u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d35d219u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005f9ab3b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006835851au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002df3f06eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000078f815dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004dc5730fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005144bd37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004ae017feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000040f50655u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002ec7941cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006b9c2c2au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038072b7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000060bd00beu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006c5a2d53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005dcb9b18u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000013ea6126u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000067094f1eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009a6e7f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000559ca7d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004107e40eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000478520ceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e010f80u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003264858bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d36de4bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007c534c1eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001ec6bc71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003b0a841eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031c74582u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006ef8165au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005f53426eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001420170bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006a77ebabu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001f63b1a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000043a91ff3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000cc83aecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000321c6f20u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000026ee3118u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c426723u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000060c76779u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002e9901cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003c44e771u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000602c9eeeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006ef17466u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000658264b7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006732e3ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000015d5c37cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d182052u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000562c569au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000019f237bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000041057a1fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000001eccb92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c796827u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000060a5a88au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007bbf4219u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064ce6376u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000063aed73eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000070ebf6bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000048ae630u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004de170c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000000bf89du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000b9d4a43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003caa9cafu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000034957c70u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003dc19441u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002eb7c004u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056275300u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000421286fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000015a65fa5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000726b2f9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001009c9f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006dfa4e2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000020345cd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006530f685u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002f991641u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004a0438f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000026f682b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e563a59u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a37642fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000016775ba9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006412a36du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000032354890u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055e35e7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000021555668u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031521382u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001d2f9490u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004e2d45a6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000707395bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000030604468u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005247c421u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000056c2d25du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000004af6c8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053a4d531u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000533e8d7au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e11207du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000221411beu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000452c32f9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000460ceed7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000025b92d56u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003b34e453u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003ace2a80u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000697e67f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d2410deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002883ed7fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000061152a57u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007cd07c8au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000014429cbcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d72c762u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000051eada2du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004013ff58u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000f158360u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000fdbf0cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003d1c7398u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003d81897eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000039cfd973u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002b19e01u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000b1d3a43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000749f0b7du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007b6af8edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000385c7efeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000051bec03cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005df01f13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000684b5602u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000030ba6ec8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000033f2e784u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006e06501u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001eaf2352u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000e414b76u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000335b1a79u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005227a4f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006ec53c50u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005feb531cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000048fc817cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000469ba9eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005368973du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031fae92bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003054aabbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005e9b4e23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000066699dc8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000070de7cfeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002cc37a1fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000304decc5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001af27065u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004032d5c0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000015a2b959u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003de31a47u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053d541b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000551612ceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000011fd5532u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003d1b42b7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000764281abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006800da52u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000571dd4b3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c88c5b5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007ef9fc1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003c5d5942u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006ff1341u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000444a6217u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000065513c87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003d70898u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009efe966u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000226fdf0cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017e16cd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001ddd6a3du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004bd936cbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000011f74f86u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000009dbf8b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000031009271u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006b1fbd2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000644dd336u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000f96d788u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000607ab34fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000001d7c96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000591b6edeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064504212u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006edc8fa3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001c6b8852u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e70d596u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001bf54a83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005bdabf78u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003d00b5e4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000030517f76u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000768463c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000066491948u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000001c79a10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002e4d0fc2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003865da96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000025c8f626u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000106883dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005376f5ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002fe56df7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002bc5e5bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002db2921au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004d2b2bf3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000119395f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000046560313u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000071804ee9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000054ccde2du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e604b30u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004eb822a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e11cbdbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000574e6457u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000014902eb3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e7633c7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000010328325u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006762f01bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000679b0585u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000047feea36u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c599589u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000001230b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001faddc41u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004e0a96b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000248af326u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000452ae88cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003968c5a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000050d672adu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000285581fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000075e91b74u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000630d3dd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000048d37b17u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005a227812u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000608b12abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053b8fa63u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006493b9f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000058bf0525u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007d0d7b7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000014f919d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000002464c5au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002dd12275u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a9155d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000016df1fcbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000403aca3bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001abc734eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001adb3626u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000029744082u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005cbd608au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000020420385u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000038897254u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006ee350c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000202cb415u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000005f767cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000030a77de3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000794bf68au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000454713eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a5cf621u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000028d86e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000250ab8ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000078c4c672u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007d61bb9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000070e18f71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000702dc19du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000006acfc6bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000065ec9ae1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000001caf35u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000016f7d689u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000052d3b099u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006eedde8cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000314cd43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d756cb9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000052b23e0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000105ce89u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005c1c4bedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000604c9990u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000046967a1bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000795e254bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007956d7cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001bf18076u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000025ded029u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004791d299u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059899e87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006eb05f11u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001159eb9bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000068f1fc69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000231337c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001dfa5d1au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002775135bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001cbba2a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000018ce6c9du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000048014804u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000008994e6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000027725a66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064db3c3bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003c16f12au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006e02dffbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004bf56e9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005e70d2fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002baa7cf5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000050686448u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000035b08d35u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006ea78cd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000017cfb810u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000138a0f90u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004ed6d40fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007612ec86u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005b4c5774u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005dd0a15cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005fc5b700u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000e082150u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003386354au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000be7a297u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000020a19e6fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002a7a8b1fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006612785bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003604cb32u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007e9b3eb8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004a13cd92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000077554c41u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000040b3dd65u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000076d70b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000005a8e0af7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000059138300u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004355bd86u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000053b95b03u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001a22e3d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004707ee5au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000021d47387u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000768ddea5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000060380203u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000a0407f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000057ed9421u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064fe126fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000007d0318du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000035351f5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000061768e2au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007161922bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055200eb7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004eed789du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000776d49fcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000006bf3f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000546c02eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004a13ceebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004aeaaa56u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006da303d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000046809d1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001dcddca4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000570407eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007ad94cc7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000016deefdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002d53efbfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000436edae7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000001e921ab1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000025527933u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000649e4dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000745e05f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000000bef05efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000006560cda1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000003cdfb213u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000004668bfcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000064e93130u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000026cf2c5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002868d484u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007a185af2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062847e06u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004ac4a314u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000063d94636u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000055202890u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000004c59e533u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000003f37422u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000035e9c361u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000002c070be8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x00000000525a538cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000041ed17aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x000000007276af54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<KoalaBear>([0x0000000062ed9abcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-        ];
-        Arc::new(GmimcErfParams::new(24, 2, 1202, &round_constants))
-    };
-    pub static ref GMIMC_ERF_MERSENNE31_16_PARAMS: Arc<GmimcErfParams<Mersenne31>> = {
-        let round_constants: Vec<Mersenne31> = vec![
-            f_from_words::<Mersenne31>([0x0000000016654349u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000027f405dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000604fc368u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029b4ecffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000016333cd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000012f63814u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a278af8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000228a054bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003eee2ec3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000022c92e36u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002831de01u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046e47468u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006b3a4950u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003cb40852u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000026ac9dfeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007061ea6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001d408537u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004db00963u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005477866au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000629c3fa5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000004074485u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d3f318bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002440265du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000e640761u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003ab64bebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000025b577c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000068b33341u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007aa34ce5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000267abebbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002fc896ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a3314a7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a52d279u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005514796du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000009110c12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000662149ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000030399b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001fb277cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021899ef9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006777b834u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c142516u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000017ad85f6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076573c0eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005b787e40u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006fadf311u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000744a6871u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e69055du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c31f949u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001da1f9bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000064ac3347u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000382923cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000020dfd5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004b70228fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005314b94fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000016aa3a51u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000025993036u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000553a64fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000039c71294u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e163271u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001dfe574fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000106af037u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004f100c7au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bbcdf70u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000075ec0a5bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001b1a0459u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042ec83cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000068c30818u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c26c00du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000044278d45u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003df56c63u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077f07678u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f588241u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003709998du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002e5ac245u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042c1f717u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007384df64u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c297e01u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000027503f83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000071fc4b03u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000483c9bb0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000066577b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030060973u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007d71d30bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000608b0ce7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000022a5ce8eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007ad9ef7au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002287947bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f6e202du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007f83f486u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006866dd33u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000072badd49u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000010b9fee0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b3668ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000015a9c0aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000066d1d7e6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003723f205u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000ec6986eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000028c6df53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007825bb0bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032822339u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a572e9eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000234e5837u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005aab2cf8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000017cf632cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000618abc6cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020b5f6e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000462d7670u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000086f29dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001d1ea792u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c058923u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013f10dc3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000280b8365u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000705bdcacu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000022360e75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005c2ba885u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000075064dbfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a7fa924u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000453d68f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000078fb9c36u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000072cfff54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029020fe8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076b8bb75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000006104545u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000743068f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c8ab572u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000000414a76u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d05f87au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000485bdae2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001fd5e832u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000047359f7du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003747eea3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c880431u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a0db526u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030e33ff6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bcd5c66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000040f39dcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003271bdf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000031f36b8fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000065ba2baeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000079de1d78u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000712d5f9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006b5eaed9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061178e95u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002b33eec0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000383f7c7du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061d532a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021773e10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000054c0c64du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001db31c1eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000617d072fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003e0d40a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005798c05cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000032a3efcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000064ecc27cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000003d641d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002dcca753u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000056980002u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003ea4f20fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061babf11u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000002fa9030u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076bfc134u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000200ab7feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061709470u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003cc48b43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000059e440eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002ffe087du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000026f16f2cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000072102f07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c566eabu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001db173b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a72bcb0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d50e6e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002728cb57u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000052b5337bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000170837bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f847467u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000176ba023u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000058351936u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002e34d885u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006eb370f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000073f6088au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000694adcfdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001ddc1196u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000764699au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003e31a229u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001cc92ff7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077b7c2e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000784a3751u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000701b44cbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a63c597u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055254c8du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000070d2aa37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001688aa3bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003d783f3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c7b4656u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000640291f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000069efe743u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f17362fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a81c07bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076fac39eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005689fff0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000c55b9eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bd9caa5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000015a132b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000531a0ae9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006b91c2aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000135bb268u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000015ccd8a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c067df8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046764639u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002176204du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000065cc7438u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000da363fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000481b5682u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000454d849eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000454499c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005be9a079u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000014efdf3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004e069a7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000187a1f96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000b1a01fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a5aad83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003b0178afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003f5f1efau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005bb168afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d3644f8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000793b0871u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001362ee46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000026b37e04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002c5825a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bc884deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004348923cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005919fe07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c39fc5eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000302990afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032135cd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004c08fbd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005cf397f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a2f6d66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001a71a290u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001a07455cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006b1b983eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006ba4be0cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000035d9c28u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a5cd88du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004fd48462u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000a834fdfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002817b66fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000007f5d56au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003bd4587fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000014f33814u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005cc2fba8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000417eceedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000034494da0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000bbd4805u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000041c75ed8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003eb83adcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000047647342u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f7b388au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055d52b93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000350d9d10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001f7839d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000054eba542u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000001f4edd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000057ba1ee1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001fde6455u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000049ba4046u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d55cd6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001f44eacau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046b3d849u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000024a71034u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000002b7d06u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002f599f77u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000619301d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000652234d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000038911d61u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000058430491u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000067ed75bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006506ce9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021fd74ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003e30ceddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000043061834u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c9f1690u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001669deeeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001857c4bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d2951acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000611be21u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e979974u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000039c981a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006de51929u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000070277768u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000064a72282u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005ae6e7ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000062c79a3bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061b64d7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a1d6c5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000218f8ca4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001ef92592u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000464bfd2cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003f02f356u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046e4ad6cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005eb1945eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046ca8458u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000044fa2298u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000002e2144cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007f9847b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000079f628afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f5c77edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004116f09bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000049a0cb56u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a6c4fa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000288a4a09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000210597ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006788c718u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020e05964u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000011bb517u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000011527b8du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005caa36e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005ef29ab7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a7e7163u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000023e7343bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000052de3efeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000282ec387u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000076614e1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000cded649u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000071d62f1au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c4413f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007494f4a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000008fd470eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003de9ff2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000c47bc80u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013433f96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c68af0au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000d1601b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061cc789fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000027028002u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029d1ccadu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f649711u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006af91725u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000036464f86u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077fc5ae8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003221d7a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004feae2f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000063c6e4a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000066a545d3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055eb2bf3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f8e4535u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000060f3b8c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001098aad1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000e4f3436u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000091325e4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000b018fdbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005bc325d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000313bc7d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000c8e0c3eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013086709u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004ee89fc1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000119fdc71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001149b450u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000494fc305u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000ebc8e0fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d5af5eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000078589ce3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000038451aa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003eaa10c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030484674u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004915ae0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000672816f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c169571u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000049cf1314u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000044efcae3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000040a318deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e9168ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000023ccdc98u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000010ef4339u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003341e76bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000038895a99u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000048900821u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e5dcaceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000002266bf4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000012fdf30bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005d4ff7e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055d92e2du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000630ae0b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b12f6ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000071acd877u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000078c5ddecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f8939cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000024adda13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000009d46c7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a1be44eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000ca81061u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001129ed8fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e792b02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000016a77e66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007054b116u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000019359c75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000718f5072u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000075901cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000489f8b25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004df0e85au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000075f37da0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000122184b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013966293u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000039e8de70u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000393afbd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000041c0095eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000063da96du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006a761b88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000799cff69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000542423fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002235fab2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000162c8dbau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000033f45addu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000667f6da5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007bd72695u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000191c0a23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005af3c04eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e952a9du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005294d294u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000156f314bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e2aa7b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004c2fde1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002978c082u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000037882f26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002e08a697u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000023098e00u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a1afea1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000057a78a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004ea35040u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000304852c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006a3ff987u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005ca5f31bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001b20b16eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000711ade11u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032674a6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013069a2cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007f04738au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007d341709u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000037c39ea2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bbecff7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000035f96fbbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000a5c8c4au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042c3813eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e475f02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000072754c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000075869fb6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e612012u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000020d5debu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005b423591u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032a1e556u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006337d173u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000008662db9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000477f73f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002a224c5cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021310ab6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007d392cdau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000028c2beb4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000125620f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000045d9946bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e1f96bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005dabfc92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c0f55aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b8fcb5au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000048b386bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000681e7fefu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002caa06cbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000746aedc2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f093de5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042178385u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e7a640cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020d058bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d9cb260u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004410780au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004c1d4b7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000035447dc9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000043974512u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002908558eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000068c72b27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000066e923c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005022fb98u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055c7f828u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004b2081a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002dc9e26cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000304a491u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bacfc69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029a68d10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000596cb34au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000723ae5a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000415182a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004cdfdad7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a8ec19eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000413537du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000273683dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020c9e912u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000581612b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000062a8b573u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000352a6ffeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000050f0eadfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000221b3f13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005857dd21u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000069dbe06eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006947882eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b2bfd2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000051d47379u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000031574b4au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000001c8d1afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000628ba617u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001704d279u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000066e23b6bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000012d8cbcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001853d27du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000001d1d2bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000045e24ad0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000546f2c12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000015355d24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f2f0085u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e3182dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000000b9b419u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030ae8054u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000fdfea66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000175372cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000660fb6adu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000277a814au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d29eb5cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000074f35af3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000a9e6e88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000737e542au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000035b0a13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000026e690ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000044a5b075u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000018ffba8cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000026e1b2ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a212d23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000044804542u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000ae8a048u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003eaefb26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000365b6bffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001b0755eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a8faa5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000338f7a6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006781c8e4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000014c05b4au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029ae55f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000616625a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001a959edau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c98ee22u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-        ];
-        Arc::new(GmimcErfParams::new(16, 2, 546, &round_constants))
-    };
-    pub static ref GMIMC_ERF_MERSENNE31_24_PARAMS: Arc<GmimcErfParams<Mersenne31>> = {
-        let round_constants: Vec<Mersenne31> = vec![
-            f_from_words::<Mersenne31>([0x0000000016654349u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000027f405dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000604fc368u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029b4ecffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000016333cd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000012f63814u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a278af8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000228a054bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003eee2ec3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000022c92e36u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002831de01u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046e47468u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006b3a4950u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003cb40852u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000026ac9dfeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007061ea6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001d408537u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004db00963u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005477866au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000629c3fa5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000004074485u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d3f318bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002440265du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000e640761u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003ab64bebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000025b577c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000068b33341u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007aa34ce5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000267abebbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002fc896ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a3314a7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a52d279u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005514796du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000009110c12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000662149ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000030399b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001fb277cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021899ef9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006777b834u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c142516u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000017ad85f6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076573c0eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005b787e40u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006fadf311u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000744a6871u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e69055du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c31f949u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001da1f9bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000064ac3347u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000382923cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000020dfd5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004b70228fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005314b94fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000016aa3a51u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000025993036u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000553a64fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000039c71294u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e163271u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001dfe574fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000106af037u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004f100c7au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bbcdf70u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000075ec0a5bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001b1a0459u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042ec83cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000068c30818u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c26c00du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000044278d45u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003df56c63u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077f07678u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f588241u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003709998du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002e5ac245u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042c1f717u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007384df64u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c297e01u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000027503f83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000071fc4b03u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000483c9bb0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000066577b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030060973u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007d71d30bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000608b0ce7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000022a5ce8eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007ad9ef7au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002287947bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f6e202du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007f83f486u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006866dd33u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000072badd49u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000010b9fee0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b3668ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000015a9c0aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000066d1d7e6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003723f205u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000ec6986eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000028c6df53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007825bb0bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032822339u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a572e9eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000234e5837u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005aab2cf8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000017cf632cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000618abc6cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020b5f6e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000462d7670u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000086f29dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001d1ea792u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c058923u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013f10dc3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000280b8365u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000705bdcacu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000022360e75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005c2ba885u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000075064dbfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a7fa924u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000453d68f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000078fb9c36u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000072cfff54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029020fe8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076b8bb75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000006104545u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000743068f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c8ab572u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000000414a76u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d05f87au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000485bdae2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001fd5e832u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000047359f7du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003747eea3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c880431u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a0db526u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030e33ff6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bcd5c66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000040f39dcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003271bdf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000031f36b8fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000065ba2baeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000079de1d78u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000712d5f9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006b5eaed9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061178e95u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002b33eec0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000383f7c7du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061d532a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021773e10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000054c0c64du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001db31c1eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000617d072fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003e0d40a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005798c05cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000032a3efcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000064ecc27cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000003d641d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002dcca753u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000056980002u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003ea4f20fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061babf11u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000002fa9030u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076bfc134u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000200ab7feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061709470u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003cc48b43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000059e440eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002ffe087du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000026f16f2cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000072102f07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c566eabu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001db173b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a72bcb0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d50e6e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002728cb57u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000052b5337bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000170837bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f847467u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000176ba023u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000058351936u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002e34d885u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006eb370f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000073f6088au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000694adcfdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001ddc1196u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000764699au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003e31a229u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001cc92ff7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077b7c2e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000784a3751u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000701b44cbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a63c597u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055254c8du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000070d2aa37u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001688aa3bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003d783f3fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c7b4656u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000640291f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000069efe743u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f17362fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a81c07bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076fac39eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005689fff0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000c55b9eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bd9caa5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000015a132b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000531a0ae9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006b91c2aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000135bb268u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000015ccd8a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c067df8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046764639u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002176204du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000065cc7438u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000da363fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000481b5682u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000454d849eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000454499c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005be9a079u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000014efdf3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004e069a7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000187a1f96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000b1a01fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a5aad83u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003b0178afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003f5f1efau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005bb168afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d3644f8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000793b0871u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001362ee46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000026b37e04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002c5825a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bc884deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004348923cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005919fe07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c39fc5eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000302990afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032135cd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004c08fbd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005cf397f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a2f6d66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001a71a290u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001a07455cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006b1b983eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006ba4be0cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000035d9c28u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a5cd88du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004fd48462u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000a834fdfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002817b66fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000007f5d56au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003bd4587fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000014f33814u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005cc2fba8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000417eceedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000034494da0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000bbd4805u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000041c75ed8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003eb83adcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000047647342u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f7b388au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055d52b93u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000350d9d10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001f7839d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000054eba542u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000001f4edd7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000057ba1ee1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001fde6455u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000049ba4046u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d55cd6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001f44eacau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046b3d849u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000024a71034u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000002b7d06u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002f599f77u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000619301d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000652234d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000038911d61u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000058430491u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000067ed75bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006506ce9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021fd74ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003e30ceddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000043061834u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c9f1690u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001669deeeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001857c4bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d2951acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000611be21u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e979974u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000039c981a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006de51929u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000070277768u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000064a72282u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005ae6e7ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000062c79a3bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061b64d7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a1d6c5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000218f8ca4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001ef92592u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000464bfd2cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003f02f356u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046e4ad6cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005eb1945eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046ca8458u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000044fa2298u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000002e2144cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007f9847b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000079f628afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f5c77edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004116f09bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000049a0cb56u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a6c4fa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000288a4a09u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000210597ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006788c718u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020e05964u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000011bb517u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000011527b8du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005caa36e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005ef29ab7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a7e7163u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000023e7343bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000052de3efeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000282ec387u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000076614e1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000cded649u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000071d62f1au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c4413f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007494f4a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000008fd470eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003de9ff2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000c47bc80u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013433f96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c68af0au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000d1601b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061cc789fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000027028002u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029d1ccadu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f649711u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006af91725u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000036464f86u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077fc5ae8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003221d7a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004feae2f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000063c6e4a8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000066a545d3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055eb2bf3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f8e4535u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000060f3b8c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001098aad1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000e4f3436u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000091325e4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000b018fdbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005bc325d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000313bc7d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000c8e0c3eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013086709u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004ee89fc1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000119fdc71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001149b450u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000494fc305u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000ebc8e0fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d5af5eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000078589ce3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000038451aa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003eaa10c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030484674u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004915ae0du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000672816f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c169571u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000049cf1314u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000044efcae3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000040a318deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e9168ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000023ccdc98u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000010ef4339u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003341e76bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000038895a99u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000048900821u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e5dcaceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000002266bf4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000012fdf30bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005d4ff7e7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055d92e2du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000630ae0b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b12f6ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000071acd877u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000078c5ddecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f8939cau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000024adda13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000009d46c7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a1be44eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000ca81061u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001129ed8fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e792b02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000016a77e66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007054b116u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000019359c75u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000718f5072u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000075901cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000489f8b25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004df0e85au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000075f37da0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000122184b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013966293u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000039e8de70u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000393afbd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000041c0095eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000063da96du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006a761b88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000799cff69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000542423fau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002235fab2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000162c8dbau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000033f45addu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000667f6da5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007bd72695u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000191c0a23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005af3c04eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e952a9du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005294d294u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000156f314bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e2aa7b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004c2fde1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002978c082u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000037882f26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002e08a697u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000023098e00u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a1afea1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000057a78a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004ea35040u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000304852c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006a3ff987u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005ca5f31bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001b20b16eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000711ade11u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032674a6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013069a2cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007f04738au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007d341709u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000037c39ea2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bbecff7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000035f96fbbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000a5c8c4au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042c3813eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e475f02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000072754c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000075869fb6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e612012u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000020d5debu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005b423591u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032a1e556u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006337d173u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000008662db9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000477f73f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002a224c5cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021310ab6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007d392cdau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000028c2beb4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000125620f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000045d9946bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e1f96bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005dabfc92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c0f55aau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b8fcb5au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000048b386bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000681e7fefu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002caa06cbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000746aedc2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f093de5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042178385u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e7a640cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020d058bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d9cb260u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004410780au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004c1d4b7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000035447dc9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000043974512u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002908558eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000068c72b27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000066e923c3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005022fb98u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055c7f828u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004b2081a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002dc9e26cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000304a491u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bacfc69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029a68d10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000596cb34au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000723ae5a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000415182a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004cdfdad7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a8ec19eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000413537du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000273683dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020c9e912u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000581612b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000062a8b573u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000352a6ffeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000050f0eadfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000221b3f13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005857dd21u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000069dbe06eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006947882eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b2bfd2fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000051d47379u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000031574b4au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000001c8d1afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000628ba617u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001704d279u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000066e23b6bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000012d8cbcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001853d27du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000001d1d2bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000045e24ad0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000546f2c12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000015355d24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f2f0085u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e3182dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000000b9b419u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030ae8054u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000fdfea66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000175372cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000660fb6adu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000277a814au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d29eb5cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000074f35af3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000a9e6e88u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000737e542au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000035b0a13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000026e690ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000044a5b075u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000018ffba8cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000026e1b2ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a212d23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000044804542u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000ae8a048u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003eaefb26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000365b6bffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001b0755eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a8faa5fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000338f7a6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006781c8e4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000014c05b4au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029ae55f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000616625a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001a959edau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c98ee22u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007632c386u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000025a7a1f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004f942c42u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000691bcb97u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000028e5d26fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003f24ebd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000147cbc7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e020ec7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000188b4536u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002c76d4ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003eb91f26u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077bad08eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000024821109u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002c1429afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000c1899abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000054c772eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002a9df651u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a486b5eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032851a38u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007fdc8042u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004659b119u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003f009055u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000039b1fa1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000072d60b8au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000056c1b77fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000235ccabbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000531cb6d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001a41d206u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006ee4f86au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000008442565u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000fd35857u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000031c26b33u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006cf2c1b1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001bae0a11u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000064bde8c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000502bc955u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000064c66bceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000007584b04u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004e4e56dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007ca9b7dau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002c3bc84du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000580fba1cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002df18caeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a7ea45cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000074e8845cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000067a2859fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000752acbcau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001ba17413u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000b20cf71u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000b37245eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004bb3a679u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002ec6c8a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001a1bf952u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000b8286cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003dc5f1c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005060e385u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000028fb0a64u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c8e4876u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d5d3320u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000040ae7722u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a96eba9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005487e334u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007f42d6c7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000078e1706cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032b05ad9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003996a370u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000b605903u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000248e7788u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005330f505u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000037c0af68u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002dc4c45du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001a25f638u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000461fc925u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000697bebd2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000034dade5eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061408264u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006dd009dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000002eb6164u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000079502c5eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042a1a213u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002cd18b9fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000071abd7c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000498ccc4fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000230364c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003d272dbcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000830236du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005c3c946eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000588b8618u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000072768275u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000067b33d4cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004cb45ac8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005a1921b4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000079d5f699u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007198750cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001f0a86f8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000057000debu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005f7651f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000436ea2e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001bbf4d1bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001e5567f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007afdcdc8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000473868fcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077c17bccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000007182655u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006674a5fbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004bdaa779u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f148c3bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002014786du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003d5d248au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000014bbb61cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a164c23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005ec4da9au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006664a6d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007daaa7cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000733eac5au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002683257bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000027013d38u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000005d921deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004b250763u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000393e6a89u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005a726598u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000a6611c7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000007435c47u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000da320beu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000027b9dc24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000376a735eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003616512bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005d10f2f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000035be9fddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000078b3a23cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000079cd967eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000663c8589u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002c99dca7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000132ff90au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029504c0eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001f10037bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000468cf4a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001fe0b70cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c93441au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f43db95u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000d422e24u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000780e01a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000069b329b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005b44fe45u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000041de7a3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000306d9192u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000040d3bd25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003cd76681u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007930f1cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000333be9f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002e2bf6c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b087cceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020b404f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002e29421du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000034fa3e87u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001eafa493u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000b6091cdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000059b2cccbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001d59d227u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003af49e8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000bde1825u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003fcfd5a0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000777125beu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000060936cc8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000373ac66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000035eab354u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000025464019u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006367b857u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000009a56e22u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004552d8b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000632505cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000369003cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000370968f6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007f5a368eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000007923787u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002374ac27u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020745802u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000506d00ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f49b5ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000028149de5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e22feceu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005682a6d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003f02de8fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003dc98523u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046798613u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e4d2eb9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000008a40da6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000089fc77du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000124f590u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000001b43b59u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004cb910deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005ded1c77u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000151d0237u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000011410176u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000059504015u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004c4b862bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f35b090u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a1b77acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000323a8e2cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002cc8e846u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061e15809u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002da0e9beu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004e4f0241u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000767b7adfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000415379c8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005a3c930au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007a80d246u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000492e1096u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003f2856a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000036a5a6cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006a33fa7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002a9fbf00u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000363388cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000257e6dfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000056d969f9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c2d1d46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000049a52f80u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000786e8ab4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002fa2198bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000052c7f4d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030c65961u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000063908b50u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000005a5404au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000018086dcau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000329b3d14u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000053e13599u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005f0939d6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001635bb59u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001bf1999du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000065b469ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002270b70au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000018384e4cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076e03dc2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000594cf15fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c6a4a44u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001cb69bffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000070a5a013u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005577d123u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000064248781u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000347b36e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003fbf14eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007f4d5e2cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000040e4eb5du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003d1e398fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c418164u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005847511cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000547f7b0au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007491ee46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000a4d0402u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000022e61c10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000032315d9cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000049925e8fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000786557a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000070e17482u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000078fa91fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005a0a356eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000cad0e5au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000690a2385u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006b89db91u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002133d0e1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005b9ddeb3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005f2bfe92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000068ef930cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046983f65u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004fdd9210u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003712bb28u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000492b7e46u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001aea272cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005afd0e73u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c722d56u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076389784u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000356df07fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000005a3c2deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a9134a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000001b2804du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000e4faa4bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000071ea0046u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021d8f6b6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000204b2c56u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000275c51ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003de9bc92u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000a37f7a1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f1b59c4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000d689402u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000054827fd8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c2984f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000413fe48cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e92b9d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004afe5450u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007820240fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000036119c12u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002f7fda79u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000031e986b2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013916968u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003efadb19u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000260b8305u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c589d0bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007eec36f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000079bbf15du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055eddcdeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000040d7bb69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000034f5913au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000064e3b9afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000039112e40u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020e8bb6au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000726afc2bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a02a4ffu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000bb2e82cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000c49d097u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000016aff799u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005968e977u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000440c837eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000041a3a0dbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003e3d2c5bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004588b088u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020ab7e07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002a1c7e47u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c39bfbfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005d37fee2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000067f1563fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005315c9c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000007fc456bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000056235c91u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005492c118u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003154451du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000012efa5e2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000017de439fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000138f0270u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000008d8924eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000038df5b1au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004f42c48au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000067a914b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006a7f6c89u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000607dc44cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000060463ea8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007cf0baf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f1feb8au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a54847bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005d082c67u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000006b01457u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e422aaeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005cf4a03fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f622d6eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e0e2ad0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000004c0e4d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001506b0b7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000028ed5c43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004b2f0653u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003650b124u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000009285d07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000730dc633u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000040d260d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000039c3dcd5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000001bd60dcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005a27a9c5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000014a6ab23u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005ec1afe2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006ffa6bd4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029e7baf6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006cde5e1du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003509afa5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004c488133u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001cd543f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020c86579u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005595bc96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000007b57c52u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000017d21071u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001ad40703u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005ba14788u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002d9949d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000000528b29u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e7228d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000057e680b7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006469aa15u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000046536d89u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003ef66d25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001e30894fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000122643f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003d15f05fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029839302u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003730444cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000ebed693u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000012208a43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000068f3e171u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000012b105f1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000020865a2eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000018f241deu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000054db329au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002b0f905eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004666a21cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000630ca369u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003e2f6270u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000056ee72c0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000245c9e01u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000071da31acu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000340b250u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c0a3b1au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000040e6b926u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000019ccb8abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005fd8c635u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007df05ad8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002ab0102fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004246cb3cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002b143387u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030d348f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007d0da35eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000674425e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006fee1e48u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000fd91813u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006c52f73bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000331755ecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002409cf36u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001e276afau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002a16e984u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061c98fe4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002d65d575u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000027be08f4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000d80b363u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000062b206d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000048a8ba53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001122dce5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d5669beu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000009f33f13u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004fd771d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003322c1a3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e4b3b10u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000621d38d5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001e28e574u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007e4624d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042399b0fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000040102772u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005cc536d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000047448b57u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000713b07a2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000185c7c65u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001e70500fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d36baf3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000343e26cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a31394cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005d7f1822u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000596ab178u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003e7e79aeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005bed89c0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001f712465u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000028f3a097u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000008ce881du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000050bc511du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007089e8feu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000282ca455u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000052094918u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003d676cfeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000068655ad6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000589a0d0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000175cdd8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002bdd806eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000009830058u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004bc3cba2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029486c64u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061ae350fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000078bf9546u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000435a7592u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000780566bdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000396b3abbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003bed91e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001d0ff698u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000623d9cb8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004e0060efu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003d3d0b55u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000058e1f7c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004a7bf32bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000180291ddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006eb91e66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013fcff69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000015ac1837u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000bbd2832u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001ff23ec8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000041a563ebu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000043258530u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002a151588u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000069271246u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000072147a53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005d77f48bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007242336fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002db61425u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000047ca3a54u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002eb33ce3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000339cc8abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000037ec65f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077083e5bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000071fa425fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c229e20u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000076356d1fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000820b6cfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005e0df9c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001eee8196u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002efe85c1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001cdeda7du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001bdabd25u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000936b2bbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007061faedu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c6005e3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001e8ba38cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002201382cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029eea3f0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077d6ad48u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000077903148u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000017c6487du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000771b1925u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000416b675bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000066b69b66u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002140cb3eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000ac23f45u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000415a66b3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c119dc9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c86f2dcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000013f95793u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006eb7bad7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000057f65cecu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005601f7afu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029e75189u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002a1fafddu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004229bbb1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000c409521u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002f05c566u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f61dbd9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000374a27eeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001ce99b96u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000079a83cfcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002370fe62u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002599279fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000002100311u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002259bedau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c8917d2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000379486edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000037cf0050u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000712fe3e0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006d09bb1fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002d7104edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000033d3c93au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003510baa2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000371214bcu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000bc335edu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000025996c2du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001ea34edbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004155610cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000069d069f2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003427e4abu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000567e419bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007cc43fd1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000030030955u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002f0d108eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000062a9621au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000501a56a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004453ab69u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000039f26bf1u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000003ce292fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004634ab9au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000306e85eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004efd78eau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003dc3b4bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007ad1b2b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004286cbdeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006092c66du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001c2c0760u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002c40febbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d0de60eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003d21fe06u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000073081609u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021d05301u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021da25fdu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000706ad030u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b5a7f72u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000055265952u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003568c7b9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000702b9d02u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000073800574u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003910b5c7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000682fd1d9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042870c8cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000297b5f5au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006be73e42u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000043ff9cfeu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005b7a0ac7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000045e506d8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c1f8a99u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000063b76ec8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006f4841b8u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006dee4c43u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002e7ed6e9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000330adc60u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000142aa2c9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000059406984u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002c172d53u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000034eb49c2u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000005a71153u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000042da3584u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000074c4d88au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000050fa7b77u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002445e868u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000578abd77u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004912cbdau64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000019f7f16fu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000c90d534u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003119aa55u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004b7e80c6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003c3268f3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000549ccebbu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000007566d7eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004c6015ccu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007ed16949u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000068963971u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004955e8a5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001610278du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000419d692cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002ff02d89u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007f4344bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000075791bf0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000061e63212u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000021eb460au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000027ffe9f5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000021b7aa6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007c257bf6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000175bf1e3u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000525c30a4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000007b3080a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000029886ef4u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000014ac8d5du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004d9d9396u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001530d215u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000183445b5u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003a3fd23eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000243654f7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000004b23c048u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000086dfa7bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000028d04effu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000003dd93b9bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000f9f36d7u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000629779cu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000395c9b8bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005f56f531u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000000788442u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000769dd4a9u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000045a55c07u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002c145782u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006a25f12eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000005f947a7du64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000399c255bu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000058449bb6u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x00000000789a4e4au64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000010a5fd49u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000050b3c5bfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x0000000024525fbfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000006e401abfu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000002b8e514eu64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000000b00a2b0u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-            f_from_words::<Mersenne31>([0x000000001fee4e41u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-        ];
-        Arc::new(GmimcErfParams::new(24, 2, 1202, &round_constants))
-    };
+    pub static ref GMIMC_ERF_BN254_2_PARAMS: Arc<GmimcErfParams<Bn254>> =
+        Arc::new(GmimcErfParams::generate(2, 2));
+    pub static ref GMIMC_ERF_BN254_3_PARAMS: Arc<GmimcErfParams<Bn254>> =
+        Arc::new(GmimcErfParams::generate(3, 2));
+    pub static ref GMIMC_ERF_BLS12_381_2_PARAMS: Arc<GmimcErfParams<Bls12_381>> =
+        Arc::new(GmimcErfParams::generate(2, 2));
+    pub static ref GMIMC_ERF_BLS12_381_3_PARAMS: Arc<GmimcErfParams<Bls12_381>> =
+        Arc::new(GmimcErfParams::generate(3, 2));
+    pub static ref GMIMC_ERF_GOLDILOCKS_8_PARAMS: Arc<GmimcErfParams<Goldilocks>> =
+        Arc::new(GmimcErfParams::generate(8, 2));
+    pub static ref GMIMC_ERF_GOLDILOCKS_12_PARAMS: Arc<GmimcErfParams<Goldilocks>> =
+        Arc::new(GmimcErfParams::generate(12, 2));
+    pub static ref GMIMC_ERF_BABYBEAR_16_PARAMS: Arc<GmimcErfParams<BabyBear>> =
+        Arc::new(GmimcErfParams::generate(16, 2));
+    pub static ref GMIMC_ERF_BABYBEAR_24_PARAMS: Arc<GmimcErfParams<BabyBear>> =
+        Arc::new(GmimcErfParams::generate(24, 2));
+    pub static ref GMIMC_ERF_KOALABEAR_16_PARAMS: Arc<GmimcErfParams<KoalaBear>> =
+        Arc::new(GmimcErfParams::generate(16, 2));
+    pub static ref GMIMC_ERF_KOALABEAR_24_PARAMS: Arc<GmimcErfParams<KoalaBear>> =
+        Arc::new(GmimcErfParams::generate(24, 2));
+    pub static ref GMIMC_ERF_MERSENNE31_16_PARAMS: Arc<GmimcErfParams<Mersenne31>> =
+        Arc::new(GmimcErfParams::generate(16, 2));
+    pub static ref GMIMC_ERF_MERSENNE31_24_PARAMS: Arc<GmimcErfParams<Mersenne31>> =
+        Arc::new(GmimcErfParams::generate(24, 2));
 }
 
 lazy_static! {
-    pub static ref GMIMC_ERF_ALPHA3_BN254_2_PARAMS: Arc<GmimcErfParams<Bn254>> = Arc::new(
-        GmimcErfParams::new(2, 3, GMIMC_ERF_BN254_2_PARAMS.rounds, &GMIMC_ERF_BN254_2_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_BN254_3_PARAMS: Arc<GmimcErfParams<Bn254>> = Arc::new(
-        GmimcErfParams::new(3, 3, GMIMC_ERF_BN254_3_PARAMS.rounds, &GMIMC_ERF_BN254_3_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_BLS12_381_2_PARAMS: Arc<GmimcErfParams<Bls12_381>> = Arc::new(
-        GmimcErfParams::new(2, 3, GMIMC_ERF_BLS12_381_2_PARAMS.rounds, &GMIMC_ERF_BLS12_381_2_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_BLS12_381_3_PARAMS: Arc<GmimcErfParams<Bls12_381>> = Arc::new(
-        GmimcErfParams::new(3, 3, GMIMC_ERF_BLS12_381_3_PARAMS.rounds, &GMIMC_ERF_BLS12_381_3_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_GOLDILOCKS_8_PARAMS: Arc<GmimcErfParams<Goldilocks>> = Arc::new(
-        GmimcErfParams::new(8, 3, GMIMC_ERF_GOLDILOCKS_8_PARAMS.rounds, &GMIMC_ERF_GOLDILOCKS_8_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_GOLDILOCKS_12_PARAMS: Arc<GmimcErfParams<Goldilocks>> = Arc::new(
-        GmimcErfParams::new(12, 3, GMIMC_ERF_GOLDILOCKS_12_PARAMS.rounds, &GMIMC_ERF_GOLDILOCKS_12_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_BABYBEAR_16_PARAMS: Arc<GmimcErfParams<BabyBear>> = Arc::new(
-        GmimcErfParams::new(16, 3, GMIMC_ERF_BABYBEAR_16_PARAMS.rounds, &GMIMC_ERF_BABYBEAR_16_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_BABYBEAR_24_PARAMS: Arc<GmimcErfParams<BabyBear>> = Arc::new(
-        GmimcErfParams::new(24, 3, GMIMC_ERF_BABYBEAR_24_PARAMS.rounds, &GMIMC_ERF_BABYBEAR_24_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_KOALABEAR_16_PARAMS: Arc<GmimcErfParams<KoalaBear>> = Arc::new(
-        GmimcErfParams::new(16, 3, GMIMC_ERF_KOALABEAR_16_PARAMS.rounds, &GMIMC_ERF_KOALABEAR_16_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_KOALABEAR_24_PARAMS: Arc<GmimcErfParams<KoalaBear>> = Arc::new(
-        GmimcErfParams::new(24, 3, GMIMC_ERF_KOALABEAR_24_PARAMS.rounds, &GMIMC_ERF_KOALABEAR_24_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_MERSENNE31_16_PARAMS: Arc<GmimcErfParams<Mersenne31>> = Arc::new(
-        GmimcErfParams::new(16, 3, GMIMC_ERF_MERSENNE31_16_PARAMS.rounds, &GMIMC_ERF_MERSENNE31_16_PARAMS.round_constants),
-    );
-    pub static ref GMIMC_ERF_ALPHA3_MERSENNE31_24_PARAMS: Arc<GmimcErfParams<Mersenne31>> = Arc::new(
-        GmimcErfParams::new(24, 3, GMIMC_ERF_MERSENNE31_24_PARAMS.rounds, &GMIMC_ERF_MERSENNE31_24_PARAMS.round_constants),
-    );
+    pub static ref GMIMC_ERF_ALPHA3_BN254_2_PARAMS: Arc<GmimcErfParams<Bn254>> =
+        Arc::new(GmimcErfParams::generate(2, 3));
+    pub static ref GMIMC_ERF_ALPHA3_BN254_3_PARAMS: Arc<GmimcErfParams<Bn254>> =
+        Arc::new(GmimcErfParams::generate(3, 3));
+    pub static ref GMIMC_ERF_ALPHA3_BLS12_381_2_PARAMS: Arc<GmimcErfParams<Bls12_381>> =
+        Arc::new(GmimcErfParams::generate(2, 3));
+    pub static ref GMIMC_ERF_ALPHA3_BLS12_381_3_PARAMS: Arc<GmimcErfParams<Bls12_381>> =
+        Arc::new(GmimcErfParams::generate(3, 3));
+    pub static ref GMIMC_ERF_ALPHA3_GOLDILOCKS_8_PARAMS: Arc<GmimcErfParams<Goldilocks>> =
+        Arc::new(GmimcErfParams::generate(8, 3));
+    pub static ref GMIMC_ERF_ALPHA3_GOLDILOCKS_12_PARAMS: Arc<GmimcErfParams<Goldilocks>> =
+        Arc::new(GmimcErfParams::generate(12, 3));
+    pub static ref GMIMC_ERF_ALPHA3_BABYBEAR_16_PARAMS: Arc<GmimcErfParams<BabyBear>> =
+        Arc::new(GmimcErfParams::generate(16, 3));
+    pub static ref GMIMC_ERF_ALPHA3_BABYBEAR_24_PARAMS: Arc<GmimcErfParams<BabyBear>> =
+        Arc::new(GmimcErfParams::generate(24, 3));
+    pub static ref GMIMC_ERF_ALPHA3_KOALABEAR_16_PARAMS: Arc<GmimcErfParams<KoalaBear>> =
+        Arc::new(GmimcErfParams::generate(16, 3));
+    pub static ref GMIMC_ERF_ALPHA3_KOALABEAR_24_PARAMS: Arc<GmimcErfParams<KoalaBear>> =
+        Arc::new(GmimcErfParams::generate(24, 3));
+    pub static ref GMIMC_ERF_ALPHA3_MERSENNE31_16_PARAMS: Arc<GmimcErfParams<Mersenne31>> =
+        Arc::new(GmimcErfParams::generate(16, 3));
+    pub static ref GMIMC_ERF_ALPHA3_MERSENNE31_24_PARAMS: Arc<GmimcErfParams<Mersenne31>> =
+        Arc::new(GmimcErfParams::generate(24, 3));
 }
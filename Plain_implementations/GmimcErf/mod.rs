@@ -1,2 +1,3 @@
 pub mod gmimc_erf;
+pub mod gmimc_erf_params;
 pub mod instances;
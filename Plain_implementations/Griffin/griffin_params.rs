@@ -0,0 +1,189 @@
+use super::griffin::GriffinParams;
+use crate::fields::{biguint_to_limbs_le_4, PrimeField};
+use crate::utils::{binomial, field_element_from_shake, smallest_invertible_power};
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+
+pub const INIT_SHAKE: &str = "Griffin";
+
+/// Deterministic instantiation following the Griffin paper (Grassi et al., "Horst Meets
+/// Fluid-SPN: Griffin for Zero-Knowledge Applications") and its reference implementation.
+impl<F: PrimeField> GriffinParams<F> {
+    pub fn generate(t: usize, security_level: usize) -> Self {
+        let p = F::modulus();
+        let (d, d_inv) = smallest_invertible_power(&p);
+        let rounds = get_number_of_rounds(t, d, security_level);
+
+        let mut shake = init_shake(&p);
+        let round_constants = get_round_constants::<F>(&p, t, rounds, &mut shake);
+        let alpha_beta = get_alpha_beta::<F>(&p, t, &mut shake);
+
+        GriffinParams::new(
+            t,
+            d,
+            biguint_to_limbs_le_4(&d_inv),
+            rounds,
+            &alpha_beta,
+            &round_constants,
+        )
+    }
+}
+
+/// Smallest `R_GB` with `min(binomial(R_GB * (d + t) + 1, 1 + t * R_GB),
+/// binomial(d^R_GB + 1 + R_GB, 1 + R_GB))^2 > 2^security_level`, then
+/// `ceil(1.2 * max(6, R_GB + 1))` for a 20% security margin, as in the reference script.
+pub fn get_number_of_rounds(t: usize, d: u64, security_level: usize) -> usize {
+    let d = d as usize;
+    let target = BigUint::one() << security_level;
+
+    let mut r_gb = 1;
+    loop {
+        let left = binomial(r_gb * (d + t) + 1, 1 + t * r_gb);
+        let right = binomial(d.pow(r_gb as u32) + 1 + r_gb, 1 + r_gb);
+        let bound = left.min(right);
+        if &bound * &bound > target {
+            break;
+        }
+        r_gb += 1;
+    }
+
+    (6 * 6.max(r_gb + 1)).div_ceil(5)
+}
+
+/// SHAKE128 seeded with `"Griffin"` followed by the little-endian 64-bit limbs of `p`.
+pub fn init_shake(p: &BigUint) -> impl XofReader {
+    let mut shake = Shake128::default();
+    shake.update(INIT_SHAKE.as_bytes());
+    for limb in p.to_u64_digits() {
+        shake.update(&u64::to_le_bytes(limb));
+    }
+    shake.finalize_xof()
+}
+
+/// `rounds - 1` rows of `t` constants; the last round has none.
+pub fn get_round_constants<F: PrimeField>(
+    p: &BigUint,
+    t: usize,
+    rounds: usize,
+    shake: &mut dyn XofReader,
+) -> Vec<Vec<F>> {
+    (0..rounds - 1)
+        .map(|_| {
+            (0..t)
+                .map(|_| F::from_biguint(&field_element_from_shake(p, shake)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Samples distinct non-zero `(alpha, beta)` with `alpha^2 - 4 * beta` a quadratic non-residue,
+/// so that `x^2 + alpha * x + beta` has no root, and derives `alpha_i = (i - 1) * alpha` and
+/// `beta_i = (i - 1)^2 * beta` for the lanes `i = 3..=t`.
+pub fn get_alpha_beta<F: PrimeField>(
+    p: &BigUint,
+    t: usize,
+    shake: &mut dyn XofReader,
+) -> Vec<[F; 2]> {
+    let legendre_exp = (p - 1u32) >> 1;
+    let (alpha, beta) = loop {
+        let alpha = field_element_from_shake_without_0(p, shake);
+        let beta = field_element_from_shake_without_0(p, shake);
+        if alpha == beta {
+            continue;
+        }
+        let symbol = (&alpha * &alpha + p * 4u32 - &beta * 4u32) % p;
+        if symbol.modpow(&legendre_exp, p) == p - 1u32 {
+            break (alpha, beta);
+        }
+    };
+
+    (3..=t)
+        .map(|i| {
+            let k = BigUint::from(i - 1);
+            [
+                F::from_biguint(&((&alpha * &k) % p)),
+                F::from_biguint(&((&beta * &k * &k) % p)),
+            ]
+        })
+        .collect()
+}
+
+fn field_element_from_shake_without_0(p: &BigUint, shake: &mut dyn XofReader) -> BigUint {
+    loop {
+        let element = field_element_from_shake(p, shake);
+        if !element.is_zero() {
+            return element;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::griffin::GriffinParams;
+    use super::super::instances::{
+        GRIFFIN_BLS12_381_3_PARAMS, GRIFFIN_BN254_3_PARAMS, GRIFFIN_GOLDILOCKS_12_PARAMS,
+        GRIFFIN_GOLDILOCKS_8_PARAMS,
+    };
+    use super::get_number_of_rounds;
+    use crate::fields::PrimeField;
+
+    // Round numbers of the Griffin paper for 128-bit security.
+    #[test]
+    fn round_numbers_match_paper() {
+        let widths = [3, 8, 12, 16, 24];
+        let table: [(u64, [usize; 5]); 3] = [
+            (3, [16, 11, 10, 10, 10]),
+            (5, [12, 9, 9, 9, 9]),
+            (7, [11, 8, 8, 8, 8]),
+        ];
+        for (d, rounds) in table {
+            for (t, want) in widths.into_iter().zip(rounds) {
+                assert_eq!(get_number_of_rounds(t, d, 128), want, "d = {d}, t = {t}");
+            }
+        }
+    }
+
+    // The shipped instances were not produced by this generator and their constants are not
+    // its SHAKE output, so only the exponents, the round count, the dimensions and the
+    // structure of the alpha/beta pairs are compared.
+    fn assert_same_shape<F: PrimeField>(want: &GriffinParams<F>) {
+        let got = GriffinParams::<F>::generate(want.t, 128);
+        assert_eq!(got.d, want.d);
+        assert_eq!(got.d_inv, want.d_inv);
+        assert_eq!(got.rounds, want.rounds);
+        assert_eq!(got.alpha_beta.len(), want.alpha_beta.len());
+        assert_eq!(got.round_constants.len(), want.round_constants.len());
+
+        // Pair i holds ((i + 2) * alpha, (i + 2)^2 * beta), so 2 * a_i = (i + 2) * a_0 and
+        // 4 * b_i = (i + 2)^2 * b_0.
+        let [alpha2, beta4] = &want.alpha_beta[0];
+        for (i, [a, b]) in want.alpha_beta.iter().enumerate() {
+            let k = F::from_u64(i as u64 + 2);
+            let mut lhs_a = a.clone();
+            lhs_a.mul_assign(&F::from_u64(2));
+            let mut rhs_a = alpha2.clone();
+            rhs_a.mul_assign(&k);
+            assert_eq!(lhs_a, rhs_a);
+
+            let mut lhs_b = b.clone();
+            lhs_b.mul_assign(&F::from_u64(4));
+            let mut rhs_b = beta4.clone();
+            rhs_b.mul_assign(&k);
+            rhs_b.mul_assign(&k);
+            assert_eq!(lhs_b, rhs_b);
+        }
+    }
+
+    #[test]
+    fn generated_params_match_shipped_shapes() {
+        assert_same_shape(&GRIFFIN_BN254_3_PARAMS);
+        assert_same_shape(&GRIFFIN_BLS12_381_3_PARAMS);
+        assert_same_shape(&GRIFFIN_GOLDILOCKS_8_PARAMS);
+        assert_same_shape(&GRIFFIN_GOLDILOCKS_12_PARAMS);
+    }
+}
@@ -1,2 +1,3 @@
 pub mod griffin;
+pub mod griffin_params;
 pub mod instances;
@@ -3,8 +3,8 @@ use crate::fields::babybear::BabyBear;
 use crate::fields::goldilocks::Goldilocks;
 use crate::fields::koalabear::KoalaBear;
 use crate::fields::mersenne31::Mersenne31;
+use alloc::sync::Arc;
 use lazy_static::lazy_static;
-use std::sync::Arc;
 
 impl MonolithField64 for Goldilocks {
     fn to_u64(&self) -> u64 {
@@ -1,20 +1,32 @@
-use super::monolith_params::{Monolith31Params, Monolith64Params, MonolithField32, MonolithField64};
-use std::sync::Arc;
+use super::monolith_params::{
+    bar0_8, bar1_7, Monolith31Params, Monolith64Params, MonolithField32, MonolithField64,
+};
+use crate::constant_time::LookupMode;
+use alloc::{sync::Arc, vec, vec::Vec};
 
 #[derive(Clone, Debug)]
 pub struct Monolith64<F: MonolithField64> {
     pub(crate) params: Arc<Monolith64Params<F>>,
+    pub(crate) lookup: LookupMode,
 }
 
 #[derive(Clone, Debug)]
 pub struct Monolith31<F: MonolithField32> {
     pub(crate) params: Arc<Monolith31Params<F>>,
+    pub(crate) lookup: LookupMode,
 }
 
 impl<F: MonolithField64> Monolith64<F> {
     pub fn new(params: &Arc<Monolith64Params<F>>) -> Self {
+        Self::new_with_lookup(params, LookupMode::Table)
+    }
+
+    /// With `LookupMode::ConstantTime`, Bars applies the S-box bytewise instead of using the
+    /// 16-bit table.
+    pub fn new_with_lookup(params: &Arc<Monolith64Params<F>>, lookup: LookupMode) -> Self {
         Monolith64 {
             params: Arc::clone(params),
+            lookup,
         }
     }
 
@@ -69,7 +81,10 @@ impl<F: MonolithField64> Monolith64<F> {
     fn bars(&self, state: &mut [F]) {
         for el in state.iter_mut().take(Monolith64Params::<F>::BARS) {
             let mut value = el.to_u64();
-            value = self.bar_u64_lookup(value);
+            value = match self.lookup {
+                LookupMode::Table => self.bar_u64_lookup(value),
+                LookupMode::ConstantTime => bar_u64(value),
+            };
             *el = F::from_u64(value);
         }
     }
@@ -85,8 +100,15 @@ impl<F: MonolithField64> Monolith64<F> {
 
 impl<F: MonolithField32> Monolith31<F> {
     pub fn new(params: &Arc<Monolith31Params<F>>) -> Self {
+        Self::new_with_lookup(params, LookupMode::Table)
+    }
+
+    /// With `LookupMode::ConstantTime`, Bars applies the S-boxes bytewise instead of using the
+    /// 16- and 15-bit tables.
+    pub fn new_with_lookup(params: &Arc<Monolith31Params<F>>, lookup: LookupMode) -> Self {
         Monolith31 {
             params: Arc::clone(params),
+            lookup,
         }
     }
 
@@ -141,7 +163,10 @@ impl<F: MonolithField32> Monolith31<F> {
     fn bars(&self, state: &mut [F]) {
         for el in state.iter_mut().take(Monolith31Params::<F>::BARS) {
             let mut value = el.to_u32();
-            value = self.bar_u32_lookup(value);
+            value = match self.lookup {
+                LookupMode::Table => self.bar_u32_lookup(value),
+                LookupMode::ConstantTime => bar_u32(value),
+            };
             *el = F::from_u64(value as u64);
         }
     }
@@ -152,3 +177,13 @@ impl<F: MonolithField32> Monolith31<F> {
         low | (high << 16)
     }
 }
+
+fn bar_u64(value: u64) -> u64 {
+    u64::from_le_bytes(value.to_le_bytes().map(bar0_8))
+}
+
+// The top byte of a 31-bit element only has 7 bits.
+fn bar_u32(value: u32) -> u32 {
+    let [b0, b1, b2, b3] = value.to_le_bytes();
+    u32::from_le_bytes([bar0_8(b0), bar0_8(b1), bar0_8(b2), bar1_7(b3)])
+}
@@ -1,7 +1,7 @@
 use super::monolith_params::{Monolith31Params, MonolithField32};
 use crate::fields::babybear::BabyBear;
+use alloc::sync::Arc;
 use lazy_static::lazy_static;
-use std::sync::Arc;
 
 type Scalar = BabyBear;
 
@@ -1,7 +1,7 @@
 use super::monolith_params::{Monolith64Params, MonolithField64};
 use crate::fields::goldilocks::Goldilocks;
+use alloc::sync::Arc;
 use lazy_static::lazy_static;
-use std::sync::Arc;
 
 type Scalar = Goldilocks;
 
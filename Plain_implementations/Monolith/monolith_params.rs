@@ -1,4 +1,5 @@
 use crate::fields::FieldElement;
+use alloc::vec::Vec;
 use sha3::{
     digest::{ExtendableOutput, Update, XofReader},
     Shake128,
@@ -155,7 +156,7 @@ fn instantiate_rc_32<F: MonolithField32>(t: usize, modulus: u32) -> Vec<Vec<F>>
         .collect()
 }
 
-fn bar0_8(limb: u8) -> u8 {
+pub(crate) fn bar0_8(limb: u8) -> u8 {
     let limbl1 = (limb >> 7) | (limb << 1);
     let limbl2 = (limb >> 6) | (limb << 2);
     let limbl3 = (limb >> 5) | (limb << 3);
@@ -164,7 +165,7 @@ fn bar0_8(limb: u8) -> u8 {
     (tmp >> 7) | (tmp << 1)
 }
 
-fn bar1_7(limb: u8) -> u8 {
+pub(crate) fn bar1_7(limb: u8) -> u8 {
     let limbl1 = (limb >> 6) | (limb << 1);
     let limbl2 = (limb >> 5) | (limb << 2);
 
@@ -1,4 +1,4 @@
 #[allow(clippy::module_inception)]
 pub mod polocolo;
 pub mod instances;
-mod polocolo_luts;
+pub mod polocolo_params;
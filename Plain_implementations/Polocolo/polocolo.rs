@@ -1,20 +1,23 @@
-use crate::fields::{FieldElement, PrimeFieldWords};
-use std::collections::HashMap;
-use std::sync::{Arc, OnceLock};
+use crate::constant_time::{eq_mask_words, LookupMode};
+use crate::fields::{biguint_from_limbs_le, biguint_to_limbs_le_4, FieldElement, PrimeFieldWords};
+use alloc::{borrow::ToOwned, collections::BTreeMap, sync::Arc, vec, vec::Vec};
 
 #[derive(Clone, Debug)]
 pub struct PolocoloParams<F: FieldElement> {
     pub(crate) t: usize,
-    pub(crate) m: usize,
-    pub(crate) ann: [u64; 4],
     pub(crate) rounds: usize,
     pub(crate) mds: Vec<Vec<F>>,
     pub(crate) round_constants: Vec<Vec<F>>,
-    pub(crate) lut: HashMap<[u64; 4], F>,
-    pub(crate) modulus_minus_two: [u64; 4],
+    pub(crate) ann_minus_one: [u64; 4],
+    // lut[r] = LUT(h^r) * h^-r for h = g^ann, so that S(x) = x^(ann - 1) * lut[r]. Its length is m.
+    pub(crate) lut: Vec<F>,
+    // Canonical words of h^r with their index r, sorted by words.
+    pub(crate) subgroup: Vec<([u64; 4], usize)>,
 }
 
-impl<F: FieldElement> PolocoloParams<F> {
+impl<F: PrimeFieldWords> PolocoloParams<F> {
+    /// `lut` holds `(input, output)` pairs and must cover the subgroup of order `m` generated by
+    /// `g^ann`; entries outside of it (such as zero) are ignored.
     pub fn new(
         t: usize,
         m: usize,
@@ -22,49 +25,90 @@ impl<F: FieldElement> PolocoloParams<F> {
         rounds: usize,
         mds: &[Vec<F>],
         round_constants: &[Vec<F>],
-        lut: &HashMap<[u64; 4], F>,
-        modulus_minus_two: [u64; 4],
+        lut: &[([u64; 4], F)],
     ) -> Self {
         assert_eq!(mds.len(), t);
         assert_eq!(round_constants.len(), rounds);
         assert_eq!(round_constants[0].len(), t);
 
+        let (lut, subgroup) = Self::dense_lut(m, &ann, lut);
+        let ann_minus_one = biguint_to_limbs_le_4(&(biguint_from_limbs_le(&ann) - 1u32));
+
         PolocoloParams {
             t,
-            m,
-            ann,
             rounds,
             mds: mds.to_owned(),
             round_constants: round_constants.to_owned(),
-            lut: lut.to_owned(),
-            modulus_minus_two,
+            ann_minus_one,
+            lut,
+            subgroup,
+        }
+    }
+
+    // Walk h^r for r < m, folding h^-r into the LUT outputs.
+    fn dense_lut(
+        m: usize,
+        ann: &[u64; 4],
+        lut: &[([u64; 4], F)],
+    ) -> (Vec<F>, Vec<([u64; 4], usize)>) {
+        let outputs: BTreeMap<[u64; 4], &F> = lut.iter().map(|(k, v)| (*k, v)).collect();
+        let h = F::from_biguint(&F::generator()).pow_words_le(ann);
+        let h_inv = h.pow_u64(m as u64 - 1);
+
+        let mut dense = Vec::with_capacity(m);
+        let mut subgroup = Vec::with_capacity(m);
+        let mut h_r = F::one();
+        let mut h_r_inv = F::one();
+        for r in 0..m {
+            let key = h_r.to_words_le();
+            let mut out = (*outputs
+                .get(&key)
+                .expect("LUT must cover the subgroup of order m"))
+            .clone();
+            out.mul_assign(&h_r_inv);
+            dense.push(out);
+            subgroup.push((key, r));
+            h_r.mul_assign(&h);
+            h_r_inv.mul_assign(&h_inv);
         }
+        assert_eq!(h_r, F::one(), "g^ann must have order m");
+
+        subgroup.sort_unstable();
+        assert!(
+            subgroup.windows(2).all(|w| w[0].0 != w[1].0),
+            "g^ann must have order m"
+        );
+        (dense, subgroup)
+    }
+
+    /// The index `r` with `h^r = el` for `h = g^ann`.
+    pub(crate) fn lut_index(&self, el: &F) -> usize {
+        let key = el.to_words_le();
+        let pos = self
+            .subgroup
+            .binary_search_by(|(k, _)| k.cmp(&key))
+            .expect("LUT input must lie in the subgroup of order m");
+        self.subgroup[pos].1
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Polocolo<F: FieldElement> {
     pub(crate) params: Arc<PolocoloParams<F>>,
-    fallback_lut: OnceLock<HashMap<[u64; 4], F>>,
-    fallback_poly: OnceLock<Vec<F>>,
-}
-
-impl<F: FieldElement> Clone for Polocolo<F> {
-    fn clone(&self) -> Self {
-        Self {
-            params: Arc::clone(&self.params),
-            fallback_lut: OnceLock::new(),
-            fallback_poly: OnceLock::new(),
-        }
-    }
+    pub(crate) lookup: LookupMode,
 }
 
 impl<F: PrimeFieldWords> Polocolo<F> {
     pub fn new(params: &Arc<PolocoloParams<F>>) -> Self {
+        Self::new_with_lookup(params, LookupMode::Table)
+    }
+
+    /// With `LookupMode::ConstantTime`, the S-box has no zero branch and sums every LUT entry
+    /// weighted by whether it is the one selected.
+    pub fn new_with_lookup(params: &Arc<PolocoloParams<F>>, lookup: LookupMode) -> Self {
         Polocolo {
             params: Arc::clone(params),
-            fallback_lut: OnceLock::new(),
-            fallback_poly: OnceLock::new(),
+            lookup,
         }
     }
 
@@ -85,44 +129,38 @@ impl<F: PrimeFieldWords> Polocolo<F> {
     }
 
     fn sbox(&self, input: &[F]) -> Vec<F> {
-        input.iter().map(|el| self.sbox_elem(el)).collect()
+        match self.lookup {
+            LookupMode::Table => input.iter().map(|el| self.sbox_elem(el)).collect(),
+            LookupMode::ConstantTime => input.iter().map(|el| self.sbox_elem_ct(el)).collect(),
+        }
     }
 
-    fn sbox_elem(&self, el: &F) -> F {
+    // S(x) = x^-1 * LUT(x^ann), computed as x^(ann - 1) * lut[r] with x^ann = h^r.
+    pub(crate) fn sbox_elem(&self, el: &F) -> F {
         if *el == F::zero() {
             return F::zero();
         }
 
-        let lut_in = el.pow_words_le(&self.params.ann);
-        let lut_key = lut_in.to_words_le();
-        let lut_out = if let Some(out) = self.params.lut.get(&lut_key) {
-            out
-        } else if let Some(out) = self.recovered_lut().get(&lut_key) {
-            out
-        } else {
-            let coeffs = self.fallback_poly();
-            return self.sbox_with_poly(el, coeffs);
-        };
-
-        let mut out = el.pow_words_le(&self.params.modulus_minus_two);
-        out.mul_assign(lut_out);
+        let mut out = el.pow_words_le(&self.params.ann_minus_one);
+        let mut lut_in = out.clone();
+        lut_in.mul_assign(el);
+        out.mul_assign(&self.params.lut[self.params.lut_index(&lut_in)]);
         out
     }
 
-    fn recovered_lut(&self) -> &HashMap<[u64; 4], F> {
-        self.fallback_lut
-            .get_or_init(|| recover_missing_lut_entries(self.params.as_ref()))
-    }
+    // Zero is outside the subgroup, so no entry is selected and the output is zero as well.
+    fn sbox_elem_ct(&self, el: &F) -> F {
+        let mut out = el.pow_words_le(&self.params.ann_minus_one);
+        let mut lut_in = out.clone();
+        lut_in.mul_assign(el);
 
-    fn fallback_poly(&self) -> &Vec<F> {
-        self.fallback_poly
-            .get_or_init(|| build_lut_interpolant(self.params.as_ref()))
-    }
-
-    fn sbox_with_poly(&self, el: &F, coeffs: &[F]) -> F {
-        let lut_in = el.pow_words_le(&self.params.ann);
-        let lut_out = eval_poly(coeffs, &lut_in);
-        let mut out = el.pow_words_le(&self.params.modulus_minus_two);
+        let key = lut_in.to_words_le();
+        let mut lut_out = F::zero();
+        for (words, r) in self.params.subgroup.iter() {
+            let mut selected = F::from_u64(eq_mask_words(words, &key) & 1);
+            selected.mul_assign(&self.params.lut[*r]);
+            lut_out.add_assign(&selected);
+        }
         out.mul_assign(&lut_out);
         out
     }
@@ -164,177 +202,3 @@ impl<F: PrimeFieldWords> Polocolo<F> {
             .collect()
     }
 }
-
-fn recover_missing_lut_entries<F: PrimeFieldWords>(
-    params: &PolocoloParams<F>,
-) -> HashMap<[u64; 4], F> {
-    let mut recovered = HashMap::new();
-    let m = params.m;
-    if m == 0 {
-        return recovered;
-    }
-
-    let g = F::from_biguint(&F::generator());
-    let gk = g.pow_words_le(&params.ann);
-
-    let mut subgroup_index = HashMap::with_capacity(m);
-    let mut h = F::one();
-    for r in 0..m {
-        subgroup_index.insert(h.to_words_le(), r);
-        h.mul_assign(&gk);
-    }
-
-    let mut g_pow_index = HashMap::with_capacity(m);
-    let mut gs = F::one();
-    for s in 0..m {
-        g_pow_index.insert(gs.to_words_le(), s);
-        gs.mul_assign(&g);
-    }
-
-    let mut sigma: Vec<Option<usize>> = vec![None; m];
-    let zero_key = F::zero().to_words_le();
-
-    for (key, out) in params.lut.iter() {
-        if *key == zero_key {
-            continue;
-        }
-        let Some(&r) = subgroup_index.get(key) else {
-            continue;
-        };
-
-        let base_exp = (m as u64 + 1) * (r as u64);
-        let base = g.pow_u64(base_exp);
-        let inv_base = base.pow_words_le(&params.modulus_minus_two);
-        let mut ratio = out.clone();
-        ratio.mul_assign(&inv_base);
-
-        if let Some(&s) = g_pow_index.get(&ratio.to_words_le()) {
-            sigma[r] = Some(s);
-        }
-    }
-
-    let mut used_sigma = vec![false; m];
-    let mut missing_r = Vec::new();
-    for (r, maybe_sigma) in sigma.iter().enumerate() {
-        if let Some(s) = maybe_sigma {
-            used_sigma[*s] = true;
-        } else {
-            missing_r.push(r);
-        }
-    }
-
-    let mut missing_sigma = Vec::new();
-    for (s, used) in used_sigma.iter().enumerate() {
-        if !used {
-            missing_sigma.push(s);
-        }
-    }
-
-    if missing_r.len() == 1 && missing_sigma.len() == 1 {
-        sigma[missing_r[0]] = Some(missing_sigma[0]);
-    } else if missing_r.len() == missing_sigma.len() {
-        for (r, s) in missing_r.iter().zip(missing_sigma.iter()) {
-            sigma[*r] = Some(*s);
-        }
-    } else {
-        return recovered;
-    }
-
-    for r in missing_r {
-        let Some(s) = sigma[r] else {
-            continue;
-        };
-
-        let key = gk.pow_u64(r as u64).to_words_le();
-        if params.lut.contains_key(&key) {
-            continue;
-        }
-
-        let out_exp = (m as u64 + 1) * (r as u64) + s as u64;
-        recovered.insert(key, g.pow_u64(out_exp));
-    }
-
-    recovered
-}
-
-fn build_lut_interpolant<F: PrimeFieldWords>(params: &PolocoloParams<F>) -> Vec<F> {
-    let mut xs = Vec::with_capacity(params.lut.len());
-    let mut ys = Vec::with_capacity(params.lut.len());
-    for (x_words, y) in params.lut.iter() {
-        xs.push(F::from_words_le(*x_words));
-        ys.push(y.clone());
-    }
-    interpolate_poly(&xs, &ys, &params.modulus_minus_two)
-}
-
-fn eval_poly<F: FieldElement>(coeffs: &[F], x: &F) -> F {
-    if coeffs.is_empty() {
-        return F::zero();
-    }
-    let mut acc = coeffs[coeffs.len() - 1].clone();
-    for coeff in coeffs[..coeffs.len() - 1].iter().rev() {
-        acc.mul_assign(x);
-        acc.add_assign(coeff);
-    }
-    acc
-}
-
-fn interpolate_poly<F: FieldElement>(xs: &[F], ys: &[F], modulus_minus_two: &[u64; 4]) -> Vec<F> {
-    let m = xs.len();
-    assert_eq!(ys.len(), m);
-
-    let mut poly = vec![F::one()];
-    for x in xs.iter() {
-        poly = poly_mul_monic_linear(&poly, x);
-    }
-
-    let mut coeffs = vec![F::zero(); m];
-    for i in 0..m {
-        let mut denom = F::one();
-        for j in 0..m {
-            if i == j {
-                continue;
-            }
-            let mut diff = xs[i].clone();
-            diff.sub_assign(&xs[j]);
-            denom.mul_assign(&diff);
-        }
-        let denom_inv = denom.pow_words_le(modulus_minus_two);
-        let q = poly_div_monic_linear(&poly, &xs[i]);
-
-        let mut scale = ys[i].clone();
-        scale.mul_assign(&denom_inv);
-        for k in 0..m {
-            let mut term = q[k].clone();
-            term.mul_assign(&scale);
-            coeffs[k].add_assign(&term);
-        }
-    }
-
-    coeffs
-}
-
-fn poly_mul_monic_linear<F: FieldElement>(poly: &[F], root: &F) -> Vec<F> {
-    let mut out = vec![F::zero(); poly.len() + 1];
-    for (i, coeff) in poly.iter().enumerate() {
-        let mut neg = root.clone();
-        neg.mul_assign(coeff);
-        out[i].sub_assign(&neg);
-        out[i + 1].add_assign(coeff);
-    }
-    out
-}
-
-fn poly_div_monic_linear<F: FieldElement>(poly: &[F], root: &F) -> Vec<F> {
-    let m = poly.len() - 1;
-    let mut q = vec![F::zero(); m];
-    q[m - 1] = poly[m].clone();
-    for k in (1..m).rev() {
-        let mut term = root.clone();
-        term.mul_assign(&q[k]);
-        let mut val = poly[k].clone();
-        val.add_assign(&term);
-        q[k - 1] = val;
-    }
-    q
-}
@@ -1,2059 +0,0 @@
-// Generated once from Polocolo parameter logic (m=1024, t=3).
-// Static tables are used to match Polocolo-main/plain style and remove runtime LUT generation.
-
-pub const POLOCOLO_BN254_T3_M1024_LUT_WORDS: [([u64; 4], [u64; 4]); 1025] = [
-    ([0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64], [0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-    ([0x0000000000000001u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64], [0xfe80b9371ceb4bccu64, 0xd37c2482d971402bu64, 0x554a1a31874b72aau64, 0x24718dad09dabebdu64]),
-    ([0x11f995e1bc2e5912u64, 0x39ba79c0a8d2d7abu64, 0xebbebc2bb08771e3u64, 0x06fd19c17017a420u64], [0x0b5cd28538491c89u64, 0xfdb9290846503157u64, 0x49212447702624eeu64, 0x0de7a1db61df1effu64]),
-    ([0x893a7fa1470157ceu64, 0xe8302a41fc782d75u64, 0xffc02c0edd9b0675u64, 0x0f1ded1ef6e72f5bu64], [0xd2847d2d76085456u64, 0x9e35a8dcb4c06648u64, 0x66d8ffca776ccda7u64, 0x0b394ff1f7d0d2fbu64]),
-    ([0x0a01d4b055e3c654u64, 0xff951e8bf6acd936u64, 0x91575c14ef8f578cu64, 0x2478efdcd24140b5u64], [0xd27ca3a5a004201du64, 0x64beab4ecdb8401bu64, 0xe21c5d72b7ae1480u64, 0x1e6216322cd82c72u64]),
-    ([0xc893619174a57a76u64, 0x617942546750f230u64, 0xf086204a9f36ffb0u64, 0x07b0c561a6148404u64], [0x28e230f632a56246u64, 0x9332ca4d5131da34u64, 0x77848ea716a88f93u64, 0x1f19fc9c0f9178b4u64]),
-    ([0x5627630d9af9194au64, 0x30bbe1d20d470e90u64, 0x2753395404aa4931u64, 0x093edab2585d8755u64], [0xab3698748925fee6u64, 0xec12630ba3d092e9u64, 0x1d5ea27326de6a03u64, 0x0af5b6a6b731cf8au64]),
-    ([0x9dae20c8a4b6fa6fu64, 0x9496aa9193a97955u64, 0xd01607c4ca2a6ad9u64, 0x1260a58dbc513430u64], [0x190c5d5aca72ebf8u64, 0xad90f722099f9d84u64, 0x2f782012caa5d3b7u64, 0x202f2ec231c87f74u64]),
-    ([0x3d1c384166f57495u64, 0xe8a8437ea29f4bf2u64, 0x0527c499dbb42f12u64, 0x0f85acfe92e6c740u64], [0x1a06f0594f69725bu64, 0xf2ad29709a2ba55fu64, 0x448048f4cc5a93b8u64, 0x212e8e23125e9783u64]),
-    ([0x9d0e470cba9d1811u64, 0x1dcb5564b6f24c79u64, 0xdf5ce19ce85943e0u64, 0x16e73dfdad310991u64], [0xf1151cfb8f4b1c55u64, 0x6e93ac1db8cf2f68u64, 0x1238d2b4f90e1388u64, 0x05f3fcba90e8d6c5u64]),
-    ([0xd27dd83c88f502d1u64, 0x9a315086c842605fu64, 0x7612a2ac5b991bd6u64, 0x10a469cc693e7615u64], [0xbdedf8f0c6d0714au64, 0x311b95c605961e98u64, 0xf6e61ad919979de9u64, 0x14ce77df2db0b186u64]),
-    ([0x55f546f823004bc1u64, 0xdcacdf9ec39f31b8u64, 0x0429c0f9ed1f00c3u64, 0x2b0b8cbfb6276376u64], [0xabf9bf441998a826u64, 0xb28e4784438abd57u64, 0xa1ff515a71bb903au64, 0x2694a88435a22701u64]),
-    ([0x74276263c469b8d5u64, 0xceeda34b3c4975acu64, 0x286b732c124d867bu64, 0x0f4323d708c44db4u64], [0xe7d99d85ecd1b82du64, 0x551b259a5ce2bbdau64, 0x440b8aefa744bce3u64, 0x2f284a8a4afb7accu64]),
-    ([0x66784dc079d2a8b4u64, 0x1b87f6e4bb596969u64, 0x30f2a5a8c4c3d42du64, 0x07cccd4c42494b91u64], [0x772e2b46416182cbu64, 0x4affc5e9dcb8b6a5u64, 0xf411b6f00cd03ac4u64, 0x23155225c9f544e7u64]),
-    ([0xb6267e88c7c2e754u64, 0x4827e78670f44b30u64, 0xb456ebf44f5a3c6fu64, 0x2495ac6ade11e024u64], [0x53e1757dd26363c7u64, 0xa849f8ac00969c42u64, 0xd9c4444eb89e99d5u64, 0x30083f07940fec5fu64]),
-    ([0x1a977113c7974745u64, 0x0d8931d899848e90u64, 0x45f3e25a88e9af4du64, 0x004583035ff440f8u64], [0xd2bc9e446ad7bf33u64, 0xbf9609bea706804au64, 0x9df96fc6dec7cd15u64, 0x1bd6e27c368144d9u64]),
-    ([0x5498312b61fb60c3u64, 0x0fe357a353bc3795u64, 0x6f3c0c2c3cc01499u64, 0x235c70979347a1d4u64], [0xa2392a9f369741abu64, 0x3cb6756a1509f5bfu64, 0x1c04cd3d924f21e4u64, 0x189f9162bb80bf50u64]),
-    ([0xdb94fb05118f023au64, 0x46a6cb2426e324beu64, 0xc24cdb7649bdadf2u64, 0x1418144d5b080fcau64], [0x3b185975b3404d74u64, 0x58352c2912de17a9u64, 0x3defa727276ff8dbu64, 0x004e5b502c0a2894u64]),
-    ([0xd60475ba32d7f5cdu64, 0xcc7ca90584a8c0a4u64, 0xca7819bb4edbc3fbu64, 0x2e5bd9ad505ab112u64], [0xa97f83220372d1a0u64, 0x5505543f44c75eb0u64, 0x4581d9ae16c0b4f4u64, 0x2d76e865fa6d98a4u64]),
-    ([0x734c2970eaabe23du64, 0x301c907f9bf3f5a5u64, 0x648f5a560ead9325u64, 0x237240c78035f703u64], [0x55270c71cc2a4b7eu64, 0x626fc739ffdcbad7u64, 0x6745a164c6cd170bu64, 0x1a8a903773370e5au64]),
-    ([0xfc31e65e6b90e9d1u64, 0x81134bbf69db6b5eu64, 0xad6a45ea36908942u64, 0x146e24d8976a08e2u64], [0x20ff7f9b879cf4d5u64, 0xf092bce66b9a7479u64, 0x8e55a04a45be2776u64, 0x2c3757532eb1e5c6u64]),
-    ([0x8acef18014ab1087u64, 0x23eae0b63c76e985u64, 0xa7df2f8b3ad9b4a7u64, 0x1bc29581ec417c4du64], [0x5139d9691ce94070u64, 0x52e160c09fbdb157u64, 0x4949a8c6ae7a5d20u64, 0x0fe004eca2cdafe4u64]),
-    ([0x18a09fda39a5bfc6u64, 0xf98d6910f4066099u64, 0xae6504d8d97bed0eu64, 0x2b567cf4c102323au64], [0x76152882ba359c60u64, 0xffc781d0ac5e3917u64, 0x921d1db4b6046b70u64, 0x0fbe2462e5748526u64]),
-    ([0x21752f815b903ce4u64, 0x6f9c4238d56a0165u64, 0x03420cf5056aa67du64, 0x2d7062563d1519a4u64], [0xbf19f64aa5f10054u64, 0xa3a6777fc30c1b9bu64, 0xb03bb78f97d0cf03u64, 0x25f9b0ec543d059au64]),
-    ([0x718cbbc22b525a18u64, 0xa6db295d2b8c9b77u64, 0x264ea7624379bbdbu64, 0x2304338003e60dbeu64], [0x1c788f175b972794u64, 0x05df27edac27e436u64, 0x47a862e66a809a06u64, 0x075708a9d57b737fu64]),
-    ([0xc217b7d08df983d6u64, 0x982c4cb8643dd412u64, 0x37b410298800d3bbu64, 0x09386dc814e3cba0u64], [0x8fef31fb2d95c0dcu64, 0xe2194d85227a545eu64, 0x5ea65959785b1c12u64, 0x23c68ec95859a6fau64]),
-    ([0x5773cf7ce49235f1u64, 0x7e13d1253c28f5eau64, 0xe5046ae068eff988u64, 0x114768dd76282d27u64], [0x8bdda2ef9822c6a0u64, 0x9e5a044afb5139cfu64, 0x9d2ca803c98aeea6u64, 0x2f57a2a8283bdaccu64]),
-    ([0x01c6ce40b4e13016u64, 0x24cc89cca75f9b10u64, 0x07803122de9e7da7u64, 0x0e93e4f5e322f095u64], [0x6d413502c0fa4582u64, 0x399e591fb8f9fb34u64, 0xcc061f9562dc96a2u64, 0x247a6bea3904c3a5u64]),
-    ([0x4cb2de349a3f6c55u64, 0xabbf6a079c143882u64, 0x75cfffef37568241u64, 0x06ade440c76d98b9u64], [0x2f7fe461a2a98a81u64, 0x839f30d134c52166u64, 0x4e841306b5b873f2u64, 0x10bb18229859bd17u64]),
-    ([0xa69ef938b7fcde87u64, 0xda961aba2bc0b55cu64, 0x37d6095a566be415u64, 0x26f5ac5221eb65bdu64], [0x75937e6850a6e9e9u64, 0x2628edf1cfaa20b7u64, 0x0939518bef9eeae0u64, 0x1e576fb6033537cbu64]),
-    ([0x05fdea1e95f2082fu64, 0x783023a39cfe0906u64, 0x1bba457426523fafu64, 0x1cf1a2ed15d69364u64], [0xf5f910fe8dbe08fcu64, 0xa0bdac8f9106215cu64, 0x4b1066ebab81509cu64, 0x01bcd2c7666a52c6u64]),
-    ([0xc196ef129e562754u64, 0x48f7f6ceafef92f8u64, 0xbed0424be8d003f6u64, 0x05875fb21dfe7c8bu64], [0xee87c97ab432b8d1u64, 0xaa4f107d68f8f975u64, 0x6d5438ecd97787e4u64, 0x121ddcc902582843u64]),
-    ([0x5553684dfc237e43u64, 0xf861230effe7ae0eu64, 0x20aba2e4c12da8eeu64, 0x1f412b277075a295u64], [0x8031931062465243u64, 0x5b444be182f625d8u64, 0xc000fd9af7462a7du64, 0x2e593b8538e46762u64]),
-    ([0x3eed4c533bb512d0u64, 0x9c18d51b838eeb1du64, 0x9678200d47c0b2a9u64, 0x09c532c6306b93d2u64], [0xc41ad65e8550e511u64, 0xebc77f5c30e988fdu64, 0x66ad28bf394be004u64, 0x07556e429c40b6ccu64]),
-    ([0x9c869cd0d6e18692u64, 0x01d240b4af2267fbu64, 0x612f535b2eefc24cu64, 0x16d6fe854f5c7f0cu64], [0x97b52b23727eb923u64, 0x8cd2f7e3c62277bfu64, 0x7a45e28350141191u64, 0x0cca6ea926a88e01u64]),
-    ([0x131d3ddd01b6ec71u64, 0xef738706f884b3bdu64, 0xb0a626e3f2e2f0a8u64, 0x07ad8f37a68fb3c7u64], [0x0b94baecc8296c96u64, 0x732d55a7a6eaf7f1u64, 0xc7dd0a2065183146u64, 0x16f9aa3efa12e462u64]),
-    ([0x82475d85920d73a6u64, 0xa2715159b9e34ecdu64, 0x2b2eea003e5339f5u64, 0x1eb727e9a0308457u64], [0xedc03e56a35f85ecu64, 0xc32a4dd807eb987cu64, 0x1930ef84e451dc75u64, 0x0b9441cc9c7c655eu64]),
-    ([0xff93f7e0bd67845fu64, 0xcba5864cdda00382u64, 0x57ca9299e39d3d7au64, 0x214516013c51c9c2u64], [0x08949d485b19c437u64, 0x0e8e4478174dde1eu64, 0xd6e1a73a9c7f349au64, 0x02f3c9fc1d533f2au64]),
-    ([0x923a5c4a9ccc51a7u64, 0x49896ed4f544b61cu64, 0xe7e4ec4ef20ad8fbu64, 0x0eca43320a22a4bdu64], [0xb46340602f5e5b41u64, 0x13539356ddc4c31eu64, 0x11d737f3deb2fc78u64, 0x0df8ba8c90637ab7u64]),
-    ([0x1f392ec9a3c259a7u64, 0x5f17d14825a12c5bu64, 0x2c8a47583390ffd6u64, 0x26b1a6381fc311acu64], [0x60d9a791c80e5e0au64, 0xc2000e4f4bbee6e2u64, 0xa3cc04853061e879u64, 0x068c5138e42d101au64]),
-    ([0xdf7b3715a3ac625du64, 0x87040e7802d32bbdu64, 0xcc81034c8faa038du64, 0x116b6caff1a11d51u64], [0xaf510a19c0d0a770u64, 0x9c0b7618ccd45a3eu64, 0xdd9d756405dabf0du64, 0x18aeff01797c342fu64]),
-    ([0x6dec33f7969b4184u64, 0xa1687ecd356e1c56u64, 0xdf8b4ad88bceab4du64, 0x18ee3017054d65d1u64], [0x588ac066be38a9d7u64, 0x820e311694ff53eeu64, 0x3e1743585b9aff17u64, 0x0bcd060f44111c45u64]),
-    ([0xf5879aa7afb17a8bu64, 0x9764a84f00fd04d3u64, 0x93bda6d6b89b06d0u64, 0x2cb0a350d1afdae4u64], [0x52d5ecce63dfa4ccu64, 0x8aad724f2eceeb9bu64, 0xf8a3a5fc30d149dbu64, 0x2df4d50519a09af4u64]),
-    ([0xe74ff831cde60f33u64, 0x3349ef02f8b942ccu64, 0xc1ec8578dc5868c6u64, 0x226fd9fe0935a192u64], [0x71c4fa9affa28989u64, 0x6c847a3e15e19f46u64, 0x4b64f891e38ee496u64, 0x087fe7e9a5e78fc8u64]),
-    ([0xe8f962722f22eda4u64, 0x5ad194386ed2ea9du64, 0x9b403e9b25e361a8u64, 0x0a97ef53773af62du64], [0x6e3a2331e43da830u64, 0x790c78a7caa890a4u64, 0xcc1a6814b420e185u64, 0x14319f0a1e6f3d11u64]),
-    ([0x4dbec67469a91457u64, 0x9da004da9fe4a44bu64, 0x0df27cae8e277f2bu64, 0x25248bfeac49d0b1u64], [0xc2ddd9759675702cu64, 0x3d8cd1deb988a9d3u64, 0x827cb605b4c4a403u64, 0x0d2568119878f42eu64]),
-    ([0x81f281e6b0628b37u64, 0xc110727729171cc4u64, 0xd24e44da1aa5bb1au64, 0x03039aa72b264c09u64], [0x526e07ca90140e2du64, 0x3cb0903f25a534fdu64, 0xce13b9c5a623b592u64, 0x19216fcd2a512970u64]),
-    ([0xf03f7183af17bb66u64, 0xc718afe38638b181u64, 0x8bf2a487197ed19bu64, 0x1e6af1d3fa3ab8fau64], [0xd78abc5ee4e8ffcdu64, 0x8bf9582ca4051db1u64, 0xfe954512e7792ee5u64, 0x2824e02f417548a6u64]),
-    ([0xab0213d9db599081u64, 0x67a2e94899fecf4du64, 0xbd3936d42c50d1a0u64, 0x0fa404383b993e9fu64], [0x54c2066ecd7965c6u64, 0x22445892afa1fd84u64, 0xa4edf2e1f8ec3970u64, 0x1702a1da4d3a3b3cu64]),
-    ([0x9c70097548f0f866u64, 0x3dff988d955c8c4du64, 0x5640a310a1e26d00u64, 0x179fcba9f31d99cbu64], [0x424dbdc5242dff57u64, 0x79453abe1d48bc3bu64, 0x97442758fbab4fdbu64, 0x0daa3148e2ac1ce7u64]),
-    ([0x6ed35ca42ccb0ce7u64, 0xa4f33d9d3da517c9u64, 0xa2e8c4d5730ce767u64, 0x1b3a719e7beec682u64], [0x9ddafe189dc25341u64, 0x541ac2414333830cu64, 0x24e7eb76b8c7d301u64, 0x1462ffb88669ac61u64]),
-    ([0x4f5290513bbe7d55u64, 0xaf0ea5ca7cc8f229u64, 0x30125047a0c8b25fu64, 0x2c052a5918dc0570u64], [0x531ca9574f975e9fu64, 0x48dce1ca6f315d6bu64, 0x0c5c82044170859eu64, 0x15ebf025cdd0bba7u64]),
-    ([0x7f4605ddbd89525au64, 0x610e59c0729eb44au64, 0x383e7f2ba4f52cd6u64, 0x0340a37c6df9033du64], [0x8807843c744af2e8u64, 0x467fc6d7f06f07eeu64, 0x581c0400276f11bdu64, 0x14fc90773d9522afu64]),
-    ([0x3352ed77966119f7u64, 0xebd980dcb6cc915au64, 0xb6f4d5dba3b5b2fau64, 0x25efbdf1972c4bf3u64], [0xd6f3e55f334f8daeu64, 0x8783993cdebad993u64, 0x279aae43d89668a4u64, 0x0f36a75dbae3cf0cu64]),
-    ([0xa923f6c0e72b7ad1u64, 0xa67232a093a38b66u64, 0xc5d468e15cc7d195u64, 0x080a533e9ec8988du64], [0x75a9287d944364a0u64, 0x725a1b65723cd167u64, 0x3e428abce855b72fu64, 0x1902c717a4cd6525u64]),
-    ([0x46f0d23c2e46d0eeu64, 0x3c3182ac6746ecf8u64, 0xcc6058f4debab55eu64, 0x1450639d9e69854fu64], [0xa656bd36fb67150eu64, 0x54ec3cb5c7368cb0u64, 0x9f5b0d7b3b653d0au64, 0x219b23c37eccc593u64]),
-    ([0xc47a279ea5268118u64, 0x9cf81c09add9706au64, 0x786358b8f2a717e5u64, 0x295db5ce5ed0f299u64], [0x5b6b040826aa9f2du64, 0x6d2c51c2ef1ce8fbu64, 0x17ca9e96c7cdd61eu64, 0x14416bf8b31a1fa2u64]),
-    ([0xbe89cc45435ad247u64, 0x492cc2b57d16e575u64, 0x80a7208f7224b122u64, 0x172dcbc8576263beu64], [0x7c5c0fa1e9a82184u64, 0xedf2da32e862b203u64, 0x27284998ec5ab921u64, 0x107f3c088f4d42c8u64]),
-    ([0xb2e64592361f039eu64, 0x6477f4685651d89eu64, 0x0ce28a2eb17b5e63u64, 0x0f22cb0fc7d368d4u64], [0xefbc9a7e844b5fe9u64, 0x90cc91426afe9f03u64, 0x3ac664ecfc112821u64, 0x291f6bf1a8636992u64]),
-    ([0xf7b58b0756d0d7c9u64, 0x6399379ac46bea4fu64, 0xd3de387a9fd50672u64, 0x2b36d44ec54161f5u64], [0x5b65922ac5053a45u64, 0xf7bbbf3fc46bf2b3u64, 0x3413bd08779b54c8u64, 0x185602eb435bb399u64]),
-    ([0xcbccd306d94423f8u64, 0xa3900fe626aef4b4u64, 0x8a001e5eeef81546u64, 0x236a812b1bd7976cu64], [0xd1b6e251ebfe1e4bu64, 0x1df8c706289a8ffbu64, 0x82c0030d0c1e9e8cu64, 0x26a50b73e3996007u64]),
-    ([0x8b2be6f796b485adu64, 0xe381a866d19e41a6u64, 0xdcd99730afb0281cu64, 0x110124bba520a830u64], [0xface4b77be4f806fu64, 0x9bbb7d5641f26725u64, 0x30f8d395b34cbd95u64, 0x17a11dfb228dcaedu64]),
-    ([0xc8f7aa484d1ed9c4u64, 0xf39daf4b8e41c68eu64, 0x4a8e7e4626c7af8eu64, 0x23490e402d5e7532u64], [0x45778053834b174bu64, 0x12ef83d80d031934u64, 0x6ff14b38b371bbe5u64, 0x09212959af5a43c3u64]),
-    ([0xff9e07ee1c43bfccu64, 0x47e5c37603cad86cu64, 0x3054dc0a8f4aa787u64, 0x26f8aee75b98e866u64], [0x470dcbf7b793fbf1u64, 0x4a9bc85d262b35a9u64, 0xee693183f629b634u64, 0x0e894de6f06d78d4u64]),
-    ([0x4009f24b05b648d4u64, 0x2a96dd239a2b3aa7u64, 0x14c2ff13d33e85f3u64, 0x0b05cb3f8ff0e7dbu64], [0xac1fd056ba1ef4e7u64, 0x88c72f81917c65cbu64, 0xb1beb5786af574bau64, 0x215c434be30be37bu64]),
-    ([0xb11509c6e306460bu64, 0x996dfbe1174efb98u64, 0x1c6e4f4594dd508cu64, 0x21082ca216cbbf4eu64], [0x005b19bec4e2b0dfu64, 0x79b99608bd521ed2u64, 0x704d48d026228900u64, 0x2ebdbc69dc6bdec4u64]),
-    ([0xca63666824824b0au64, 0x525f11d704b5ad0eu64, 0x76f59988b372fc2eu64, 0x086aed06634ee892u64], [0x3e63999fc0e0cf44u64, 0x5d94b62531c65a85u64, 0xa24819efa9b2deecu64, 0x273b484d25f33003u64]),
-    ([0x07a9f700cf6cf2b3u64, 0x76d9f7fcefb2c585u64, 0xd5c7333dee3f20bdu64, 0x0c277489666c82abu64], [0x300c49cae4c7ce68u64, 0xd3a910934c8a4e0au64, 0x82fb58116a705f93u64, 0x089b088e23251fd3u64]),
-    ([0x878b8d87792903c2u64, 0x0a46186ba2cb6627u64, 0x79e3ed40dbfde29eu64, 0x0ca56f0731ffa457u64], [0xe86e83119400494du64, 0x6aa73a7ece552e8fu64, 0xb315e9647bb6d7e9u64, 0x1dfd237f51675019u64]),
-    ([0xc7d2d2cd225a4c36u64, 0x4b798f24568a0dc1u64, 0xa2b42c2fdfb7b6ccu64, 0x15acd6341d8dd037u64], [0xf97bb0076949d98fu64, 0x2d98b5b8e1e488d3u64, 0xee298fdc31e0b2dbu64, 0x02d4617e83fa9fa0u64]),
-    ([0x82d7ef04e38e3da7u64, 0x597c3e4f66855df0u64, 0xca67c4b65e9f0b93u64, 0x1e54081386325968u64], [0xc5b55c2c578180afu64, 0xfa4d414f7a811cf7u64, 0xc4d3f42a075a312du64, 0x2f64b1413360d349u64]),
-    ([0x1a51a8f19c06765du64, 0xe4f28bc65a88ad73u64, 0x74203d7be641d8d9u64, 0x24942762689cca51u64], [0xee0e9e645a517badu64, 0x14bbdb032313e411u64, 0x107e2c83f92ab399u64, 0x2a4821d1343e6521u64]),
-    ([0x70f717ff6bad9241u64, 0x2609a59e6c0a6698u64, 0x9fc396d3ee1b66ceu64, 0x26ffb51a2d8acee1u64], [0x4830c3660dd5eadcu64, 0x6c360890d9164529u64, 0x90654c5b4d6430a1u64, 0x266c006332e65b5du64]),
-    ([0xa88ff86c86b0905eu64, 0xf031594fb170606cu64, 0xe9db1375f4d54965u64, 0x11cebd30e471805bu64], [0x134dcc5f7cdd0cfeu64, 0x25e0c845da86cf0du64, 0xb9787bd679c4a095u64, 0x23f63f0f55865e16u64]),
-    ([0x783eec2ee3591c41u64, 0xf97f2648fb3d3651u64, 0x957688bb46574ac9u64, 0x2cbc6166dd642967u64], [0xd7079f692c2b0834u64, 0x9b16741e3d50271eu64, 0xd9da873e55f683bau64, 0x2f847490e52fcc91u64]),
-    ([0xb2c8bf3b15dc3b8fu64, 0x682f5ea9ff7d8fb5u64, 0xc00e51eddf13a693u64, 0x084869bebc60d973u64], [0x188ef687ee56c533u64, 0xd7821f654577ab61u64, 0xa26c8973fb40eda2u64, 0x11458caed9f81ef8u64]),
-    ([0xe79dd382376ed155u64, 0xfb00ca69e796e45bu64, 0x106b5991234785f9u64, 0x226edbf4dbbc1555u64], [0x6b72833bbe3e4ff7u64, 0x69967f3df51cd244u64, 0x0cba829dd8be8400u64, 0x2cd3722d91130a09u64]),
-    ([0x3fd69af9b0f3124au64, 0xe72ffe0834f6e16du64, 0x7b80073bc15da413u64, 0x0b607b9e0008a29du64], [0x7a5e7e2f2480b5e7u64, 0x12762d22401ae61fu64, 0x086c55591f33154cu64, 0x24d869c46c26693bu64]),
-    ([0x546ac1c589f81fc0u64, 0xc7a14378e6cbf88du64, 0x7ae40c44994bffc0u64, 0x1766526f79e85d9au64], [0x72e28f22e96ac6cau64, 0x65a642f3b752b2e2u64, 0x537e7c960d004513u64, 0x08c1cd6033d8ceaeu64]),
-    ([0x961614c1bd7b5a52u64, 0x3a00e7c501d31f2au64, 0x23e873810177eba1u64, 0x2b3572a8485731d8u64], [0x110b5e34656f4a23u64, 0x694da645416e58b7u64, 0x1d83fee53bffca8bu64, 0x0aa6e7b61d1b4ce4u64]),
-    ([0x1539a266837b66a9u64, 0x95964282d982621fu64, 0x9988f64d7c9159b0u64, 0x22089ae245248031u64], [0x1160cb9dd89f0898u64, 0x940ea56b4fe172c6u64, 0xb9357eaa900a5ad7u64, 0x1a57743f92ff22adu64]),
-    ([0x7540464e0d7110ddu64, 0xb96cd30a6936e8feu64, 0x9e97e8e2c5f94e89u64, 0x02cd1f095cf7741du64], [0x0bc4c966121416c3u64, 0xa3c4d8c4ba8e428au64, 0xbbc7f27839ae76dcu64, 0x22a134b137cb1a3fu64]),
-    ([0xbad508b48bb3ffd8u64, 0x5b5e3fb7078e817au64, 0xa9ec77b7a8acf47bu64, 0x08aab2921ac1250au64], [0x2a2367acb4aec393u64, 0x94d32da47b822965u64, 0xd0c352d9088505c1u64, 0x250de0edb7923c9eu64]),
-    ([0xeecc0241f5907985u64, 0x56c384c6a49795c1u64, 0x92523a542ee84e3eu64, 0x1b95d92f19baffb2u64], [0xcf187ec80c98bb57u64, 0xdf644bae8bcf9136u64, 0xb1cc3a2bdea6367cu64, 0x2e720a9d4e88edb2u64]),
-    ([0x25fb7670bdcacbc2u64, 0xdc9443a915248560u64, 0x418a6468c02da30eu64, 0x0199b3c713dd4353u64], [0x60ce1a710982f953u64, 0xdafdc0551cfd97bdu64, 0x236e3a3ebb724948u64, 0x2f831756a2f06ccdu64]),
-    ([0xd718dac466ae4e7bu64, 0x57cba44eff600568u64, 0xa9c538c923729fbeu64, 0x0383e93ebd50379eu64], [0x929b8a5288396589u64, 0x1a27d2c7a5799083u64, 0x05decba040719e2au64, 0x02fcacb56ea61c46u64]),
-    ([0x45842e8a808827b7u64, 0xcfe521859d7d34d7u64, 0xaf09868e72ffba09u64, 0x2f818fd11a36d326u64], [0xd41bd982d4d58b29u64, 0xbebaada474bdac1bu64, 0x5ac8034da31d642cu64, 0x063b115b949c652au64]),
-    ([0xbd4ae67b58a2de2au64, 0x459bbeac868a3cfeu64, 0x9a599c2fb20ebbf3u64, 0x24b1c5a35edf2b6bu64], [0x76dea46102fb8ce7u64, 0x4fba292a26d47a1du64, 0xd9fd650f4d056a32u64, 0x2110a59db9aaf6b3u64]),
-    ([0xebf8f8656f614850u64, 0x92f3669d8e409c1eu64, 0xa782814b7e8e0ac8u64, 0x17aee229466b4447u64], [0x43568f3552d2a814u64, 0xc7cab648fef79b6fu64, 0x912fe8d8f41a5674u64, 0x2fcffc27c1c06447u64]),
-    ([0x8553702c3122a5cau64, 0x806e61f608139754u64, 0xd21c7e74fe46835cu64, 0x0e3b77e911732929u64], [0x2f80bf664a16b8f5u64, 0x852af87aef35485cu64, 0xdd9f521df433d284u64, 0x090bfd1f43717c04u64]),
-    ([0xa20a6cba6bc42ac2u64, 0x9d35d49c0944b124u64, 0x2db986e03325870au64, 0x2a417aabf0aff35du64], [0xf6ccd75fab58c117u64, 0xce2f094f11664087u64, 0x8b9edce9da34f107u64, 0x1a82dc11a316b5e4u64]),
-    ([0xdcca2a7db838eafbu64, 0x99f6b58c5786b0f3u64, 0xed89a6f5b4ffc0ecu64, 0x012d504424ac4844u64], [0x25b3713214d6fb12u64, 0xc1381e32dae1fc9du64, 0x353d96386824b6d5u64, 0x14fd642eb13687a2u64]),
-    ([0xb9aa25a859f12b08u64, 0x9b9d8dba017218d1u64, 0xb465101750d9757bu64, 0x250a657dc01f05b7u64], [0xda99eb19af5bcfb5u64, 0x96698932c4d5924fu64, 0xfa0a25330d234ac6u64, 0x14e645a66063ca98u64]),
-    ([0xa05fe59982f03376u64, 0x70dcca05ef621347u64, 0x4d8af35b7ceb08e7u64, 0x114468a975ee450fu64], [0x0feb5f20b870ff57u64, 0xbe80b3ed8efe6044u64, 0xb1c66d0ec6f1c505u64, 0x09daabc005f3d438u64]),
-    ([0x1a8cb3f170ae9858u64, 0xc3a925861b19c86fu64, 0x822298629f154169u64, 0x08d937905c3afdd4u64], [0xdbbea298330a8f8cu64, 0x267af3ad4c55a440u64, 0x320335517ef4a3e5u64, 0x2850d30a19858eb8u64]),
-    ([0x4cd23e02c560b4fbu64, 0x00b66e98dd5a30a2u64, 0xd17210c224da1d10u64, 0x0583bc24828ba547u64], [0x5e4607803918a36au64, 0xfbf2c6c4f48aad2du64, 0xad7d898ff0b14480u64, 0x1d6d38378a073ecdu64]),
-    ([0xed3650ec2417a263u64, 0x7587fae34474a6ffu64, 0x5dde80d029b2f84du64, 0x0e3113f2013f561au64], [0xf9a2eb306d33a1fbu64, 0x4e484759a1848d9cu64, 0x8e6f78ee0c7c74dau64, 0x2e886e979f060806u64]),
-    ([0x01f4a25da77c253bu64, 0x311f386e2d26d644u64, 0xf2ba5fb162c39eb7u64, 0x1277ae6415f0ef18u64], [0xd96538fa1526920cu64, 0x717ca636acfe7255u64, 0x8772e35a24250934u64, 0x19a582dff6fd3d63u64]),
-    ([0x02b1f8ebe20c8f85u64, 0xe7420b8da9307a9fu64, 0xa05893ac4ac0f868u64, 0x08efd719fe46e569u64], [0x2700f0ceed23c986u64, 0x2ac425195ea76e47u64, 0x10ae72115be97b69u64, 0x2a1d338ac9b7bba1u64]),
-    ([0xd95fbddd37c81170u64, 0x28229cce2841cc2du64, 0xde110b51b6362c56u64, 0x1a4ae7e3bfeb3804u64], [0x8d2e4915e9fce35cu64, 0x580b986753b03430u64, 0xbcd0835d747d1b89u64, 0x2490caf9807ec339u64]),
-    ([0x9c848e563b08e0b0u64, 0x90d6c5566dbfa6c6u64, 0x6b4584e65c469c45u64, 0x0dd2aaf9de5f313eu64], [0x1dc819b3265d6ca8u64, 0x0ff25b1ca37c3551u64, 0xb6593b17bb15a14au64, 0x0e1d35833971f179u64]),
-    ([0x42c345b0a508172du64, 0x5d2285b678e10685u64, 0xbd905668bd9a0944u64, 0x1b8e8307d93c374au64], [0x8540fe80b39c62b5u64, 0x441c8eafa4ed598fu64, 0x1bbf792f08c8eb6du64, 0x2c8c36e40a36c688u64]),
-    ([0xf9ee45ff9de49469u64, 0xac0295eb658156eeu64, 0x583ece2342e7569du64, 0x0f0c44036852de24u64], [0x6f39a69c20830350u64, 0xa37cd8a311dee02bu64, 0xbde777db604fc5b5u64, 0x008d26b24f88ee59u64]),
-    ([0x0c374ecaa1e8c647u64, 0x3742d8a0a6d45f23u64, 0xc1cf01c9bea55d03u64, 0x040d739eb2475465u64], [0xfd6aad97f45aa636u64, 0xefed1c6c9f884815u64, 0x58ece530e08a0960u64, 0x1ba1a5903f6e1ebfu64]),
-    ([0x281f0201283ca018u64, 0x81a344a0432f4990u64, 0xe7d1977845685a7cu64, 0x0e812fb93cd31c5bu64], [0x39c680d48a8a70d4u64, 0x7619fa5c6469b005u64, 0x284e35d356be433eu64, 0x28cab97ccb599f83u64]),
-    ([0x05aa05b09e01da8bu64, 0xaebbac6cd45a693au64, 0x0e480da13766b3f4u64, 0x189b3c125d3dc714u64], [0x4919509f559eb97cu64, 0x556fa4835a593a63u64, 0x03483345acb5c32du64, 0x07039cdf267249a4u64]),
-    ([0x491698708283be2eu64, 0x45135fb4082f283fu64, 0x783ffd96b2af685fu64, 0x1e2eda919a702be8u64], [0xd416453a78cd3ce0u64, 0xe4f69621147adb19u64, 0xfbdb0aa943fe5f0bu64, 0x02a40cb132629650u64]),
-    ([0xd3de666b53734c58u64, 0x0c3a090f5799dc9au64, 0xed3d2e7f4a0e6166u64, 0x0b234e7bf1ca9d14u64], [0x56f3efe841325323u64, 0x5f1298ea84079132u64, 0x928aad347f753650u64, 0x0ba48aae3d2fe386u64]),
-    ([0x87354795ac2f518fu64, 0x9cc67b69d198c67eu64, 0xcc3bcfd7eab50715u64, 0x02d98ac155cebb30u64], [0x7cf8f77ca3d565edu64, 0xd2c17f16068bfda8u64, 0x679aea47325b1059u64, 0x2b7f1791bd7595aeu64]),
-    ([0xd9d412f9d220cbbbu64, 0x80ebfd191d36daf2u64, 0xf820db07c38b4713u64, 0x0ddb74bdabcb5d58u64], [0x6b556f919ed92087u64, 0xf7462c0620c01441u64, 0xbb6d625e26d2aedeu64, 0x1b57a932fd6b8143u64]),
-    ([0xb735a480862d12d8u64, 0x005b474708fb7828u64, 0x1d17ff61453e33e6u64, 0x0fb04276263c51d4u64], [0xd6c33aa57bb52f5bu64, 0xff0d03eaf939e8c8u64, 0xeb39cfb8725b4882u64, 0x2a2a25149ebbe900u64]),
-    ([0x267d9282907ab1b2u64, 0xf555b7ab48198aafu64, 0x00a3279ab124e65fu64, 0x09fd942149bf1f51u64], [0x7e76361da7eb65c1u64, 0x733c1fb0fa2aec82u64, 0x7c867ffcc941f803u64, 0x2603ab90b01c8ef3u64]),
-    ([0x9ebc4575cdff2ab3u64, 0xa293d91518c2541du64, 0x2959277731bcdd2bu64, 0x03b7132271fbb6e7u64], [0x7aff4e65a2979902u64, 0xeb8624bc21b6ea19u64, 0x89a39af6b661e789u64, 0x1179f9e41cc61138u64]),
-    ([0xbfc9962b9c9e26a3u64, 0x98b3fc9d0a8566d7u64, 0xaeb66278a95444a0u64, 0x0565a2fb238a8c82u64], [0xbd16a34534d1d5f6u64, 0xbe36ae5b99ca0511u64, 0xde0045090b31e912u64, 0x1c5d2b19540ca49bu64]),
-    ([0xc91af2627cd4f0cau64, 0xd2719978c23bc0ffu64, 0x17ac1b3bf1210daeu64, 0x0ea6d1c5a612e807u64], [0xbfe01d298f1dd008u64, 0x2d5a2bf52174a9a8u64, 0xd3fa659679238c91u64, 0x0b48c2be781e282au64]),
-    ([0x5e503a416df9abdcu64, 0x2385efc0e06cc33fu64, 0xaa4cb3df74fb2084u64, 0x1ae0d258a07ba533u64], [0xcdeadb0e06ff935du64, 0x1904bea0473ab8e2u64, 0xed6d94248377fafeu64, 0x060fda039ea568b6u64]),
-    ([0xe409763090922344u64, 0x0091c394ce5b9a3du64, 0x9738e26ed713695eu64, 0x1f738b536eb05594u64], [0x3f49dd650af4afd7u64, 0x4a581d7eb0eecea5u64, 0xf9f32a8ec8091af6u64, 0x164121f4738f4383u64]),
-    ([0x8317b8fdaa02f93fu64, 0xb55ef8481d0bcd83u64, 0xe58e8429feb3dc33u64, 0x14b78d7bd2c665d5u64], [0x879e6e3fb2959bccu64, 0xec90c312880221ebu64, 0xca3dd3e508fd2b89u64, 0x2110ab373d06865eu64]),
-    ([0x5884a5c6cfa91769u64, 0xa29c898d8bd077a4u64, 0xb55911a76ef641ebu64, 0x16ca65bd31432418u64], [0xab4bafe9bb1a4dc0u64, 0x4228101aa4f1eecbu64, 0x1c85f025e676c790u64, 0x05b5436ddbfbf3a0u64]),
-    ([0x47501093d7e62addu64, 0x3b63f973ed46b5bdu64, 0x7bf3d23ce396d31eu64, 0x18e0b066544d03b4u64], [0x8f887d27f89dec3du64, 0xf14fdc0c1a5f8352u64, 0x67b721efd01f45b8u64, 0x1a0fef0c519a9d31u64]),
-    ([0x5737b351c12e7eccu64, 0xe804f0249dccb95fu64, 0xa6da941cdbac34b3u64, 0x13eb1a51342e3ed4u64], [0xb6a0be511233afd3u64, 0x3f57ccf962344b6au64, 0x715426a8c2bffbf9u64, 0x0242b0ea4de0dc9cu64]),
-    ([0x7c72ecfe9a7b86f1u64, 0x28d506ac6e1d7996u64, 0xfcac7a6c2306855cu64, 0x2df3d9ecb7d94979u64], [0x98452c5f4599e995u64, 0x8b37d9c020cac98bu64, 0x68a8108863822896u64, 0x156de083449c227cu64]),
-    ([0x897b20c3a21d1471u64, 0x87fbdac63bc54d57u64, 0xc7e7fbf24551f5b1u64, 0x01b17589c14c5b26u64], [0xf39694d658a9243cu64, 0x0a0afd03f9862269u64, 0x4490f1faf435828au64, 0x1a536e3c6b902fddu64]),
-    ([0xb3d38d73ce772d50u64, 0x925e39a549527562u64, 0x08d98aeaf79daf1du64, 0x0a8acdd94ebf348bu64], [0xf12f3faf3bfffd08u64, 0x519d9e4f6f4113ecu64, 0x441494dd44d01057u64, 0x15a3fe9caea8f62bu64]),
-    ([0xee5703aa222ea665u64, 0x1121c55061ad6894u64, 0x795a6db511cb0742u64, 0x1017f328c301a3a9u64], [0x754baec481ae9ccbu64, 0x76b1b8234b205a90u64, 0x4134b9ea375be378u64, 0x1e2bdcc1b1170201u64]),
-    ([0xe63b433f28aba10bu64, 0x432c51f48eb5846au64, 0x9825f3c9b0e89d79u64, 0x1f4d7180df501484u64], [0x8d11d173d069cd74u64, 0x316dc04f585ea86au64, 0xc7fc4fd81b98653bu64, 0x2b127e0bfac9ebb6u64]),
-    ([0xcb79e8a7342cabb2u64, 0xe030c2d88518f3efu64, 0xf62d26f63aa00823u64, 0x036ad962fe3532bfu64], [0x0811aedd313cf33bu64, 0x0cf3c078687ba965u64, 0xf4ed2c2eee3b374cu64, 0x05c58b8e82fb5980u64]),
-    ([0x5731e437981cdddbu64, 0x8a50c8050032bf20u64, 0xfaad067fe5de10ceu64, 0x05cb88639ecb6428u64], [0xfa8e23735af2383cu64, 0xeaea339abf76cc90u64, 0x1b3593ccb77f356fu64, 0x24778f0d13d79ef2u64]),
-    ([0xfef9f978c2b2c3bfu64, 0xc11760900f07f9bdu64, 0x50dfbf34d10bc412u64, 0x28ccbbcf96ed9e41u64], [0x8fb036c5f2f4e8f3u64, 0x1f50fb0ed049a3f5u64, 0x8c606b2b9d2f6cb6u64, 0x0d6f844b13f82652u64]),
-    ([0x948dad4ac1bd5e80u64, 0x52627366f8170a0au64, 0xec9b9e2f96afef36u64, 0x2b337de1c8c14f22u64], [0x6aee6127ff185299u64, 0xec94f2aef8d1924au64, 0xa22dacbc915c86f3u64, 0x0b01f8ddb600c547u64]),
-    ([0x76882478278f7b48u64, 0x6ecc5f418335590au64, 0xf975f9e31357a31eu64, 0x2c02f2edbda00f59u64], [0xc8aa44ea5df67c0bu64, 0xddd88456c0498fecu64, 0x37437c6d22e07a90u64, 0x289f4a4ff0cc4f05u64]),
-    ([0x6707e3071200d923u64, 0x035f39c8f9743458u64, 0x28b0d4cb90fbe12bu64, 0x081cb110d21c9ffeu64], [0xdd4b75ab34c5467bu64, 0x42ed5d4c82143654u64, 0x93a5237de42d8319u64, 0x1d62d87ec6c9bcbcu64]),
-    ([0x27902d3817fd2851u64, 0x9f178fbf5e53a745u64, 0xbca2fda08cae59aeu64, 0x23ef850c20d26d16u64], [0x3b38c50ca67b01a8u64, 0x6cb9f70f2371a6a8u64, 0x7e93aeea74b3886bu64, 0x08b8888345e8528fu64]),
-    ([0x5f477104a66b87e5u64, 0xd5fa41b3fb9cae56u64, 0xcc0a0e035b86723bu64, 0x19d6533aba19ac98u64], [0x30f0b0fb6f471ebau64, 0xea020fc6417c135au64, 0xf145d818422fbf0au64, 0x2651d43ca41f1e4eu64]),
-    ([0xa2603fc231995425u64, 0x4db4042dc0c52624u64, 0x239ae19ecb31e72du64, 0x0c93eb2ae86e6598u64], [0x51eabaff7f28de51u64, 0x9b10d0c071baf853u64, 0xa2257d97993185fcu64, 0x2924c4de151f0618u64]),
-    ([0x25f6c792282d14d5u64, 0x8689d587a3e330d9u64, 0x875038f912960b5eu64, 0x17dda405adc87736u64], [0xd1b261320a7a106eu64, 0xd0bdaf815bf0ea62u64, 0x15997037ed27c401u64, 0x28cfb5dda2a7d1d8u64]),
-    ([0xb631997909a319d3u64, 0xda7736507d09615bu64, 0x87664b6456200ed1u64, 0x0b6632f7783ddc35u64], [0xd2a74b6f9c82104du64, 0xfe3786dca2a81ff0u64, 0x469998c8c06d61fbu64, 0x2558e981134f5322u64]),
-    ([0x0ab036ffc0c5390eu64, 0xa3b98dd0bf722d3au64, 0xbbbd7225a7f6a22fu64, 0x0ae6445ed50dac94u64], [0x24ee51309901ed4fu64, 0x4bffd130d475d1e7u64, 0x3728e2dc0353aea3u64, 0x148fe7797ee26ef5u64]),
-    ([0x0aa4d24c4ae5621cu64, 0xda5986f9d6c4e373u64, 0x761a5a1ffd30cee9u64, 0x05785b952384270bu64], [0x8ffeba35daf85bc5u64, 0xb0e2f0cf66f5b604u64, 0x5d6a8859df017b7fu64, 0x25db42c7857fd051u64]),
-    ([0x025085e8abf02484u64, 0x9d5f0c9dd23a3ea5u64, 0x75204babf146b9b5u64, 0x2375e251220af585u64], [0x233711d997754f3fu64, 0xf88edc28f1295e01u64, 0x61dc39d2b8113e44u64, 0x094cf558bf090258u64]),
-    ([0x681f503cdec53887u64, 0x6b365889169f6ce9u64, 0xe3bbfc31d2072669u64, 0x257a2173ae03daefu64], [0x7c84d855b2403f4du64, 0xf7a0c12c2a500433u64, 0xe088858812dbbe0cu64, 0x079511893641fdfau64]),
-    ([0x5d8509aca02dbea8u64, 0x97613fa7e4e537bdu64, 0x32698832dff7ebacu64, 0x17ba3d960286ac5bu64], [0x7706bd0655ca38a6u64, 0x4c7ea62d2471ac76u64, 0x699189006d35e6d5u64, 0x2bf480284ef10e08u64]),
-    ([0x15ce4c24ce931c58u64, 0x981b2129ec599972u64, 0xe5acff907ced90a7u64, 0x13e58ef7d1a21306u64], [0xb7efaa123de6c3cau64, 0x5572aba78fe7fb83u64, 0xb29fec586f39e010u64, 0x01a061c4939e3acfu64]),
-    ([0x8f8f5c0809e4e3bbu64, 0x773aaba3bcfc17bfu64, 0xbcf788cbd555c457u64, 0x04bf6f1a76849718u64], [0x675d17411fa4b263u64, 0x1397416c0c844389u64, 0x9b2c0ecd32112871u64, 0x067d908aa7749836u64]),
-    ([0x1d3a467300f85f9cu64, 0x0eba488236586621u64, 0x80cbd340b2c9cbc1u64, 0x1cdd88a2767b90d2u64], [0x38b7875b868c98b6u64, 0xb7cec0fe6b0c21fdu64, 0x687588b3133a835au64, 0x18d9a81c1838f793u64]),
-    ([0x930580e4d20eaff1u64, 0x646c8f1232503065u64, 0x43fd4ffcb5ee15ecu64, 0x10e341a426bce177u64], [0x0c95aafb187c73aeu64, 0x57220deb43da3febu64, 0xc000794313274766u64, 0x00fdafefca6f0c3eu64]),
-    ([0x998a160d24870b14u64, 0x213c4029277e29e7u64, 0xb3899a904e946aceu64, 0x2fb2874cea6efaeeu64], [0x4d863018b8e0b1d8u64, 0xe1949e444dc52a13u64, 0x083ac599de87cbfcu64, 0x20d79a4dd3592b5cu64]),
-    ([0x4d0c53c24728a8e1u64, 0xc952fea2c9de6cb2u64, 0xdb0ead3dfec02818u64, 0x04cc349108fbacf8u64], [0x597a2d9a92e7f466u64, 0x5d6181be7aed4d1eu64, 0x4ecd7b2e94693308u64, 0x0b86e917850b5fa2u64]),
-    ([0x125cea9a6875507cu64, 0x832bcaee1458e58cu64, 0x04a59f22bdb7c782u64, 0x0c6bf4386dc727cdu64], [0x405a17affd388035u64, 0x2b0e309b0b9dc95cu64, 0xd391e8d80420f360u64, 0x1c24c4da543eae08u64]),
-    ([0x543711d6864f274eu64, 0xbd44628a6dc0cdceu64, 0xd5e3353983800cbau64, 0x087ccf786ffe4d28u64], [0x34dbbd925029cca4u64, 0x9f330936587ed0d8u64, 0x52875b275d43b898u64, 0x2dda2ff15235fdacu64]),
-    ([0xad98c8868313ba75u64, 0xb8d242273bb1aa2cu64, 0xe266cfd4c5a23e29u64, 0x1abe57b879f83ee5u64], [0x4b6b7f565314b4ffu64, 0xcf18876505d57689u64, 0xa41f35b1b157d6f8u64, 0x1005fde20e7de5e2u64]),
-    ([0x15c9a9b0d31c2088u64, 0xdb1fc7f7c66b86deu64, 0x331e8baa07f98831u64, 0x180c308379bcbf1fu64], [0xa926ae2f0dacd60au64, 0xc62a1ffd1ba54d98u64, 0x779256c084ddb747u64, 0x2bef1094b7ef7812u64]),
-    ([0x54a72ee50b6084d6u64, 0xddc033c5269c0fa0u64, 0xc0688123a3cdf96bu64, 0x00d6dac649311c10u64], [0x176ead9af89d505eu64, 0xed1be5e815491c94u64, 0xbf7083ee1e31528eu64, 0x19ff2f33164dc5bau64]),
-    ([0xe4de5fbc41d9bc5eu64, 0x22cb24c6885b6c00u64, 0x49d7c63c6a3aa1eau64, 0x24eebc6b6095d0cbu64], [0x73d296319a3afdd7u64, 0x73f3ccc44346eca5u64, 0x4ebcf10d882f59cbu64, 0x2efa881889b5df78u64]),
-    ([0xdc1e0b798a789a3au64, 0x1d374606980a0bd5u64, 0xc49756966e8c08d8u64, 0x240860c817b572b8u64], [0x2c111a2433cf5a38u64, 0x4ffa02d31029ee5cu64, 0x4077202ef2bf8b0cu64, 0x175d42e8f75b4610u64]),
-    ([0x9287641036a6a994u64, 0xbca37dad00fd593bu64, 0x5dbe9902cde4dd22u64, 0x2efbfa5c55564c42u64], [0xfe86270c61906ff8u64, 0x47666b45c58d67c9u64, 0x7a3cb1e4c976aad3u64, 0x032ecf77c4cc4534u64]),
-    ([0xff8a9be883a3169au64, 0x4c7ccaf41d8d2c02u64, 0x6bd0b3bfbb73f3feu64, 0x275cdf1e1ddfb995u64], [0xcf7cd8e6b0132a2cu64, 0x1e53f2f18c347e38u64, 0x8cc786a917400ef4u64, 0x09867e675b4d3b4bu64]),
-    ([0xe62da34d13d7b62cu64, 0x70e0eca1e662c392u64, 0x25066ebb6b0891f2u64, 0x24d5b700ee56ee58u64], [0xa760f95d2e26c5d8u64, 0x62c971617cdf67b8u64, 0x2fc5142ac71a34c8u64, 0x0ed688303a3b762cu64]),
-    ([0x36cbf67c1e4de0a3u64, 0xc43d403cc04faaadu64, 0x801eb1a491c3ac2au64, 0x0bda0e936768a068u64], [0xaad53495cc8bed10u64, 0x04eb4affb8a2b52du64, 0xbc49255003c1128cu64, 0x2e00136ee11a9694u64]),
-    ([0xed66ff173dd48460u64, 0x98d5102c7b89d35au64, 0xb8da713dbe9fb19au64, 0x249b9ed1ae9b2db6u64], [0x1f72d535b392fefau64, 0x8856e2da0761c4d1u64, 0x57b834910b68bc97u64, 0x0f94e2cab2a58060u64]),
-    ([0xd36c07ef36611675u64, 0x444abb57fa46a9ecu64, 0xdda1c52f4fd5a958u64, 0x058b1eba091a53d3u64], [0xce62e63074f9160eu64, 0x52f8afafa845c261u64, 0x28236fe6d2e696e1u64, 0x2a447ea63fe21e26u64]),
-    ([0x90632027327a5ebeu64, 0xfac5ddce77e1872eu64, 0x1a163aa9820fb88du64, 0x2fbd4dd2976be55du64], [0x9a001e82471904a0u64, 0x706beb721f2fea01u64, 0x1a9179b036bf7883u64, 0x1c1deb1601e41e11u64]),
-    ([0x0905143fdc62e157u64, 0x234c5277b19af2b7u64, 0x2005f523c64162b6u64, 0x2497a24b6a3743bcu64], [0x471433f803a65943u64, 0x7f1384660fae983fu64, 0xc96d9b309417db7au64, 0x14b232f56fac347cu64]),
-    ([0xce55857967c43f49u64, 0xe9ee7069a1fcdbffu64, 0x88f6ff5a79781c90u64, 0x0112e525684fa9d5u64], [0x30d9477d27a0fc95u64, 0xdc75b3eb1aa50638u64, 0x4f0b596d3fce297fu64, 0x01c4f1795e734a41u64]),
-    ([0xf635e9bb84bf127eu64, 0x15bd379aa760757eu64, 0x67bf1d21f86bf713u64, 0x226341b44e2c06b2u64], [0x2c17abf8d2b53839u64, 0xe498d54a15bd1ba7u64, 0x50572677b09921f7u64, 0x247b6559aa1038e3u64]),
-    ([0xdffcd9c241e73b00u64, 0x55b79b7e8a574261u64, 0xe1c95be4df4601b4u64, 0x286e64dcbe1d46a6u64], [0x3a41c8d154ba6339u64, 0x3569d89fd7c425c9u64, 0x7ed966df67433f51u64, 0x23eb7ba47be4487fu64]),
-    ([0x56aeee0079fd6258u64, 0x96f5cb9c26857d38u64, 0xfba39c736af0564eu64, 0x085a761604cef29du64], [0xb2eea3a95161f621u64, 0x64cc4b5728d187e9u64, 0x114cfed29fa1c753u64, 0x22f7aba2f796707cu64]),
-    ([0xe3047902bb6f4236u64, 0xb1bd962b8ad8f10au64, 0x4655f12b68370c43u64, 0x14bc507e9dee89a0u64], [0x63e9af5f2b42f323u64, 0x9bc93b016229b027u64, 0x7011a0134d69fbf2u64, 0x22a018a3279136f4u64]),
-    ([0xaaf771c95f92f264u64, 0x5c5f2b79197ac4c3u64, 0x02f97aaabab50cd7u64, 0x06ebeca56dc15c23u64], [0xcf846d2b0f85df67u64, 0x38aba11e71789e77u64, 0xc8a8805d264d3488u64, 0x2d061de421d4cf48u64]),
-    ([0xd82bcf2012d799d0u64, 0x949e2db4e5717e5eu64, 0x6ce0904f9afd9c26u64, 0x12f525f8f0f3e2a6u64], [0xd9e0a83cfde70351u64, 0xe0f9ae209d3bfea6u64, 0xf5980b9d085c3dc2u64, 0x18ac69a8a1dc787au64]),
-    ([0x1071f36f6708f8a7u64, 0xc73c6077cf1fd676u64, 0xee751e080d00db88u64, 0x0a1651c9d7bb60dcu64], [0xae00326adacfb6f0u64, 0xf435b602ed253938u64, 0xcbf9b3b5a1cbed0fu64, 0x12cbfa35e9679458u64]),
-    ([0xbc15e5d3d61949f0u64, 0x7bfb17f0d8f3fbfdu64, 0xd09e32bd52711226u64, 0x0e60d44df1459810u64], [0xe6fe7c908efcc528u64, 0xc93b47701fbef274u64, 0x7dbd77ed7d0e75e2u64, 0x14fc96646771d849u64]),
-    ([0x44b401d09cbe3378u64, 0xf373940f374f4896u64, 0x072ceb9dd57b0441u64, 0x000725aa205b6469u64], [0x0fbb6f4ea6f1a458u64, 0xef193f583b89486du64, 0x3d24cc905df09559u64, 0x0618091950927799u64]),
-    ([0x249d966ac658b952u64, 0x97ee201731c8eb89u64, 0x304d0a8563b2b039u64, 0x27b1a0846c194e97u64], [0x08feaf9253261150u64, 0x1bffae7ff72e5bcfu64, 0x552d1be166503386u64, 0x13d101acd8bafd70u64]),
-    ([0x447663d28d71e34fu64, 0x54ae31b479d7453du64, 0xde3189a0cc9e6651u64, 0x0e706d8c35f6eaedu64], [0x9fe5ce536863cffdu64, 0xf4c4f60022ff0cf9u64, 0xb83beb1d459d403cu64, 0x0274ebde2e0b64ddu64]),
-    ([0xf36fd87326b6110du64, 0x0aaac9e0bb1ba34au64, 0x2d1f4210acf5c400u64, 0x1fe8056907860671u64], [0xd54bc231f319f851u64, 0xa2185ef4d6451f90u64, 0xe5a01848ea926e03u64, 0x0bd15b4b9f296b8du64]),
-    ([0x6417e60707f035b4u64, 0xd00ec0ba99e51177u64, 0x1be4ddbd01dd8a3fu64, 0x1eafbc81255e978bu64], [0x467432de9066a2f9u64, 0x39ba6399e9ee36a2u64, 0x239006c0071969c3u64, 0x0dac412d8f3cbfd3u64]),
-    ([0x3426d7d830a40f75u64, 0xfda38126c3e4b8d5u64, 0x4a3c94db9ab56bbbu64, 0x0448fd16406ecb6bu64], [0x2b50d47e609b7d95u64, 0x0ce83e5059835384u64, 0xa54196efef103401u64, 0x01f267b6d43ec902u64]),
-    ([0x6cea76ded14be471u64, 0x2ab9a2b54c8381ccu64, 0xfe0834e601032d7bu64, 0x04b2c367918e83abu64], [0x7313e7737cfa263fu64, 0x5bccddc30e06670fu64, 0xa633ba567aeae1acu64, 0x08d2115dae39cf88u64]),
-    ([0xa541262cda56305eu64, 0x77c4b52aa6942e44u64, 0x73bc33a92c469d4cu64, 0x09c6c493de8776e4u64], [0xbfb8fea8b7edfc15u64, 0x148b3ed8a913f7d8u64, 0x7434a05d3fb10c50u64, 0x1bb3ea03f3c30030u64]),
-    ([0xbe70403b53aa6e18u64, 0x3801efe1d9909d09u64, 0x849eb15c4b54d3bdu64, 0x078854bf1ccd09f1u64], [0x7d07982bd3f7db3cu64, 0x0146ba11f90df9ccu64, 0xd06d39ba668d57a5u64, 0x12c9f2c63bfc1efau64]),
-    ([0xba856437be0287f1u64, 0x8eb2c660a6e775bcu64, 0xb97a0af790f89c5du64, 0x12439acffff0ec8eu64], [0xfffa044a3cf3c42cu64, 0x44838bae08f86558u64, 0x6697b6c633845390u64, 0x2216c8589ecf1295u64]),
-    ([0xff522ab27ce18c68u64, 0xc713ed826c08f5e9u64, 0xba9d2c152d292e97u64, 0x0851c4b53930c615u64], [0xb705f7e3611b5cf6u64, 0xbd0b520de0681a1fu64, 0xd6c4e8a183949e77u64, 0x27535a1a79efaafdu64]),
-    ([0xef990243596f9ec8u64, 0xbe46cfc52e2514aeu64, 0x367092708ca1a4bfu64, 0x24543339eec0fe8bu64], [0x4949f90023988bdbu64, 0xfc158fec6e080376u64, 0x453ae2de56428b29u64, 0x2a16a63ba5454821u64]),
-    ([0x5d97e56bcdddf1acu64, 0x705cd338c063a4b7u64, 0x52f29dbd2233017au64, 0x1dd024a6a47dea93u64], [0xa3a020e9b3a74ba5u64, 0x2990613f6a862e55u64, 0x84f5566b6b13ca5cu64, 0x0e989bd3e634d4c1u64]),
-    ([0x815eecf5b487706fu64, 0x7a618fe91854c933u64, 0xe4717379687387b4u64, 0x1595a101e1336c5du64], [0xa7ee5878023d15c4u64, 0xc332e42d503b64fdu64, 0x0fb3520a76e8718bu64, 0x2e48b37c1385a2a6u64]),
-    ([0x0d36885b3a1fa812u64, 0x682f06c6d46eb2b7u64, 0xc2c7577cb84548b0u64, 0x14a622b00bd1a26fu64], [0xaccda4fd7450863fu64, 0x0aaa0554c90d135bu64, 0x132573c227288ca8u64, 0x2b703f25f10093ddu64]),
-    ([0x5ad8712f455b04dbu64, 0x3954cd24b5a8c2b9u64, 0x7a1e7d76eeef7de6u64, 0x1a6cb0b91da01e6du64], [0xb5aeb75a4b8f3833u64, 0xe694c7367ed499acu64, 0x1cc3846185fbd570u64, 0x088d1bb788158c6fu64]),
-    ([0x9c322fbf3de3f73du64, 0x11e39e2975eccdf3u64, 0x7596b77ff1985eccu64, 0x0e5097a9993e2930u64], [0xc79232b929e94364u64, 0x1d4ce9b15c541d62u64, 0x8649a4103ff48023u64, 0x0986107f500d9eeeu64]),
-    ([0x6e9b249bd42b2bd3u64, 0x3478aa75f7c2e6f4u64, 0x3fb20ecec72f2ddfu64, 0x0bd9a1f372661806u64], [0x28e36e7bd3e9f7b8u64, 0x2b667f1274040dbdu64, 0xa9c39f54cdb3c40fu64, 0x07f26a3a8b903c61u64]),
-    ([0x26be90894d9f414au64, 0x552c237903ee124cu64, 0x297ab5e4753252dcu64, 0x216c13bc0177dc01u64], [0xbd01c490ca3594eeu64, 0xb758ca4b9d70454eu64, 0x0b6ce2eae472d1d5u64, 0x0c69dfbe9aebd3f2u64]),
-    ([0xc5e10bfada0c6b14u64, 0xc0ceefd695ed5242u64, 0x764d24534992cf7cu64, 0x0f28be363b2a6d5bu64], [0x5f473cf2006c4ec2u64, 0xa0ba475c1d2e934eu64, 0x792c9874cd873374u64, 0x106973304d544bb2u64]),
-    ([0x4d3f238ac044a28du64, 0xf16b5eb09e46e738u64, 0xed57adbc9431d86cu64, 0x05e166991b2ebb17u64], [0xa3819e2dd512c390u64, 0xabdb25a436511e31u64, 0x03672fc95ad41438u64, 0x2b77e668af922674u64]),
-    ([0x36bcfa5b4b039043u64, 0xd9dc1d5db39f81deu64, 0xda9cd2abf78be38bu64, 0x107aab49e65a67f9u64], [0xa87cbbecfa1bb99fu64, 0xd8847180539f8173u64, 0xadb06a4b6895a762u64, 0x165725623fc2b8b0u64]),
-    ([0xe7a6651c725e9fceu64, 0xa108a7fc27a51cbfu64, 0xbdf0dd74e67c14ecu64, 0x2356a37333880d37u64], [0x5024491030fa7288u64, 0x017f4d5e410cac2fu64, 0xe842701a56c72bc8u64, 0x2ae6409d0e93dfc2u64]),
-    ([0xaa897f5959fde9bfu64, 0x8b715b62a3aa5e60u64, 0x534d874be29e1628u64, 0x11bada685cb55d45u64], [0xb2dd8454d7d08f8eu64, 0x6f90fa96a8c645b2u64, 0xb31c0cf472cfe56cu64, 0x0d210944a01aef9bu64]),
-    ([0xe7fcd01081c6f808u64, 0xfc76dec12dc5723fu64, 0x11acf949ef088a52u64, 0x26f98866727d318eu64], [0x8d124bbe41432d0cu64, 0xf207fdd868e89ae9u64, 0xaab19e3328246830u64, 0x193a972d46eb6e98u64]),
-    ([0x3115bbad1e57c0b5u64, 0xf9ace52b4c13a1b9u64, 0x4d10ab39c4081e96u64, 0x11cd1540e36257f0u64], [0x91ac22eacab40598u64, 0x5a7cfe447a4b5625u64, 0x9ad22291fc4614a8u64, 0x19eba6aadb3093b7u64]),
-    ([0xd63d166018f8f0d2u64, 0x5b69eb682a283adfu64, 0xf063294ab39fa6c8u64, 0x09de3219afa10d8au64], [0xee2c526670826b1bu64, 0x462aea7347e8710au64, 0xbaf099c2a594baabu64, 0x291c3e50a7526905u64]),
-    ([0x265d4b1eded9c6c4u64, 0xbec52b6f1ad12f06u64, 0x70bd9114af518303u64, 0x19460de8121aadc4u64], [0x05d833575a9b68dcu64, 0xd030d89334bced0bu64, 0x1ade833f4c1f3e51u64, 0x0222c0b1696f37e2u64]),
-    ([0x7ead0cb3cc47069fu64, 0x9d95b46005dd309bu64, 0xd7fe77baafde3cccu64, 0x08e3f84f50f0057au64], [0x7b7b3bad389b3ab3u64, 0xa82e53ce1070ac1bu64, 0xae3ec252c087a41cu64, 0x0ba9e5a69b325497u64]),
-    ([0xa205fb69716d3077u64, 0x8147f8fd2377d3bau64, 0xd938b86d151ec621u64, 0x06e8ce2d205c92c8u64], [0xc73e331e08e2a362u64, 0x094d42da354bf091u64, 0xf23aa82c79fd3f96u64, 0x09569f372cdf972au64]),
-    ([0x3ac0a500808b0cc5u64, 0x1f6d08219f337455u64, 0xd5fb77b6b2641b2eu64, 0x148ec939c26510ecu64], [0x280682cb31629e93u64, 0x9eb5681aa15b56efu64, 0x873d0c0ee267e45au64, 0x168cf03cc3a3ea54u64]),
-    ([0x7902920c4042eddau64, 0x7dd06af2b169b6f9u64, 0xe38c76645d9393b6u64, 0x2275af9f00ea2eb9u64], [0x1e20346f54931a58u64, 0xcacaaed28a654818u64, 0xe4ca6196197ed0a8u64, 0x15237ebdffa0af13u64]),
-    ([0x513e1ed8158045d4u64, 0x313f14e7d8e36d4au64, 0x34883b7558d16a8cu64, 0x2c31e1d1e5745443u64], [0x1cae7dd31524e4fdu64, 0x55459738e0d44a2fu64, 0x79dab0733b5be0b2u64, 0x2c7b72b7fd5e161du64]),
-    ([0xdff7ac2761fbbb4au64, 0x6a217307a375c03du64, 0xec200b80cce93ca9u64, 0x2833b1585f40efb6u64], [0xf9139ea398d1b5a0u64, 0x435d9b48bcb1ca4bu64, 0x1b0d1d11e7f9a1a2u64, 0x101942f3b47e013bu64]),
-    ([0xdb29e4ed151059dcu64, 0x88b6ec66f4d0c07eu64, 0x8bceab7748dbe827u64, 0x157866e4745b882bu64], [0xfb2197a72b1b0a5fu64, 0x30c86c215d62b1e8u64, 0xdb62595f4bfd846au64, 0x0257dc27980d60aeu64]),
-    ([0xc9152073d883872du64, 0x59f568d6f844b07bu64, 0x0fe90534724d46b9u64, 0x1e4ec64a8f5632a6u64], [0x4c911d636f017213u64, 0x40e1a5dbc9552804u64, 0xd3ee5836dc2851d3u64, 0x268ab3c9d46c6578u64]),
-    ([0x68fb2c4e930d474bu64, 0x2307ab8fc0fa8e92u64, 0xbc0871d3f592c614u64, 0x26c7e4db3fcde305u64], [0xd75f9e18663ecb03u64, 0xebe0b08997016e06u64, 0x5b883eaf99071183u64, 0x0d7de30bf4c1d51bu64]),
-    ([0x4a1ab333eb91553bu64, 0xaeaa88bbb5203d60u64, 0x84ce815e78445443u64, 0x2866215dbab169f1u64], [0x41b3142f93c67a29u64, 0x2dcf3edf5603fc1cu64, 0x3c3bd19afe9e6579u64, 0x17e6cae773709612u64]),
-    ([0x9e1c5b0cb7c2b90au64, 0x1118ab3b0174fa68u64, 0x8eaa4dbeb10d3ae3u64, 0x0738add0344e3208u64], [0x6d7b72ff06046387u64, 0x474f9e0848975e65u64, 0x79ac30858f3203e6u64, 0x23442a3ba2ad1a9cu64]),
-    ([0x45b143a33d604153u64, 0xd296af206276d4b1u64, 0xa988a72882efcaadu64, 0x06dc8289720220d3u64], [0x1e889a4ed571f911u64, 0xf7476ac98b49fd0du64, 0x5e7f0a5c7f99c2f7u64, 0x0dcad9aa0cd844e1u64]),
-    ([0xaf869c0e7250d8c4u64, 0x08e0d93f65afd6ecu64, 0xd3b88c2039842536u64, 0x231584970dda5f33u64], [0x49542c2174fc28bdu64, 0xa7828f4617799993u64, 0x12a24b431f4f0dbbu64, 0x2db3629c96caa732u64]),
-    ([0x8c48493263894f11u64, 0xb7d1a1d26d97c26fu64, 0xef61d3e963c9752eu64, 0x04d1890d2c880fd6u64], [0xcca49a16d457f318u64, 0x83bd71d237dc5644u64, 0xedeafc49e5f6d6f2u64, 0x1b912c54095f19adu64]),
-    ([0x894f79dbee1aa9d4u64, 0x1feb5d0a3c08e4efu64, 0x1257409a1f9dfd8cu64, 0x01a8b36830b175aau64], [0x6fe3d07d487e3d1cu64, 0x15ce93b4c6e36488u64, 0xf4b415232180d7c5u64, 0x2b629257db7e2625u64]),
-    ([0x07dc8151739ff477u64, 0xc56d59ace3afb3c6u64, 0x7ebbd27bc8de2243u64, 0x1db335f592a59f0au64], [0xe32057b0a1cf1d32u64, 0x08b9e6d84b310eb9u64, 0x1e9c5aee0e1450fbu64, 0x05e58bffa45d29e0u64]),
-    ([0x97cca5aaf78f27fau64, 0xc386258251425acau64, 0x1922d459085df55cu64, 0x02a878387fb67a17u64], [0x2ccd59ab37df3de2u64, 0xdbf88b902091ec3cu64, 0xe91342fb59426f9du64, 0x1b6e273b603fcb5fu64]),
-    ([0x0324d13758e4dbbau64, 0xa9f4b9149b0632f6u64, 0x36a0a0da778aec99u64, 0x1d31833b63dde663u64], [0x891ce29fbe28bc83u64, 0x4a82e5721ed09c64u64, 0x752374c0f56527b2u64, 0x2eecaf90c12667cdu64]),
-    ([0x19d39095700c7aadu64, 0x22277ac7ce07c417u64, 0x01cf51f5bb1f11cdu64, 0x18d52ede3ac6ba96u64], [0x4a68c0e0248eda74u64, 0x8a3d9701b3bd851fu64, 0x7b2cc297d99e939cu64, 0x14c619a2bbcf3f17u64]),
-    ([0x9f256d91442b20f8u64, 0xa1b94efeb29d89fau64, 0x56679194f68361e2u64, 0x2d6813c950c47aa0u64], [0xc79e98895d7c63bfu64, 0x4cffd3f5f0e4ac7cu64, 0xe187427d165856f5u64, 0x127d5f70f8784ee3u64]),
-    ([0x953ebe56f6010065u64, 0xb25ab25e347c4158u64, 0x8b4f85775e0bb69cu64, 0x173eaf7ab403f7efu64], [0x2573aa39d92c8181u64, 0x0fb22489eb79933bu64, 0x2cab96dc33af9616u64, 0x19d4f0900b28407cu64]),
-    ([0xfe4c6bad1c3baeaau64, 0xb6e7a844f780b312u64, 0x6358af93aab1dd09u64, 0x27028a9cf78cb7eau64], [0x6f430d1d42be4b08u64, 0x15355292841b0936u64, 0x27f5198dc9bba7c4u64, 0x2661fdf329067f85u64]),
-    ([0xcd0b872ae1afd456u64, 0x9e6b469d3ab7ec83u64, 0x644f2f3127ff63f8u64, 0x0e7a7f33075613ddu64], [0xee8417c5e580ed93u64, 0x30722f8d6e78e214u64, 0xd4367010f604cf92u64, 0x00b231117ee4cedbu64]),
-    ([0x2a6cf8d834156200u64, 0x54d09d3bf285da24u64, 0xc817b2779a5501c5u64, 0x247a4ca037de8149u64], [0xa243cd1c9d0759dcu64, 0x86c3e192a3e35164u64, 0xeae5c2a0192c18dau64, 0x14a20416d26bbca2u64]),
-    ([0xce19316ef4a8b1fbu64, 0x719cd6f31a665740u64, 0xab9ef3cb6200597eu64, 0x1f7e976f670e2079u64], [0xab6bf8a1f347b160u64, 0x0b159d77b949c23fu64, 0x23d0c5d223187232u64, 0x16d82b096137620cu64]),
-    ([0x3770a40a9a343990u64, 0xd212b0d4dbf8a570u64, 0x4284a9f80a5fc41cu64, 0x00e14b6364a47e9cu64], [0x904bbb3aa8ab4779u64, 0xf38707527e62ea36u64, 0xa6eda73e3c9004eau64, 0x1dbc88ca625abb37u64]),
-    ([0x86d91c00e63b4484u64, 0x76fdfe156a9e5b1du64, 0x9e58c9598eb5857eu64, 0x058b4c5410b55d7eu64], [0xf37209c27ccc8021u64, 0x9f2fe116720b40edu64, 0xdf08c5a35f164e47u64, 0x1015124f329d6026u64]),
-    ([0x9b10b5dccdd95bedu64, 0x260c3021edd7d3a3u64, 0xe87e5c303e438266u64, 0x2978c35362e820b3u64], [0x6ff17ca28466ba41u64, 0xa0c77e515aa52acfu64, 0xaf4e0bef97507d8eu64, 0x15ca4ef30e7e11c1u64]),
-    ([0x9a0fa36aebcdd7b8u64, 0x85e0dba6c043642au64, 0x0fe7d5ff55b7dab1u64, 0x163305a2583c590fu64], [0xde2777ffd1eaf54eu64, 0x72ca3995a3420e97u64, 0xe3e3494e8dc474f9u64, 0x2a8fb44d727a7faeu64]),
-    ([0x8bca8211029e356fu64, 0x70cb6f9dcfe30be5u64, 0x9b6f47467ee84932u64, 0x13b9b41a70d940cau64], [0xcf5d6f5e250d069cu64, 0x3e1586f61ca006cau64, 0x0a53d77263c1bffau64, 0x0ad675753a1c1b30u64]),
-    ([0xff46b628b2d35a34u64, 0xef4244b1452913b9u64, 0xa1bacc8e8238551du64, 0x1f6a3de5dd435ebau64], [0xca98fd164e8c589fu64, 0xbeeef4caa90e907bu64, 0x87d0eafca0636d58u64, 0x0904536dda1e6c2fu64]),
-    ([0x7d00c6a52cd61895u64, 0x09ceea9ee3569ee0u64, 0x98946844fe497e0bu64, 0x0a5550d7baea3c1eu64], [0x6ba31868bb45c730u64, 0xb2e87d514f56ce4eu64, 0xbbd9d13c67af4d46u64, 0x1e223096186aab68u64]),
-    ([0xe70e6e42affe4a0fu64, 0x4bb842ca4612dc6au64, 0x7a3047bc3f89b35au64, 0x052f92345a3d25cau64], [0x8f4c7947d85dd9c3u64, 0x37e433c4993d57c2u64, 0x35c413911a906c64u64, 0x1910ee11cecaff46u64]),
-    ([0x51ae473f31f56dc5u64, 0x86bb794be4a7be4bu64, 0x996f0aacbc5371a0u64, 0x19e9d8b22b6206c7u64], [0xc5bc7ca24c69a39eu64, 0xe2b743e89bf6129au64, 0x8a3bd82dd3b07b65u64, 0x17f9c5952e0445d8u64]),
-    ([0x2c8d87f842bcede6u64, 0xb81e285241d6ab16u64, 0x316dd3b64c7a473du64, 0x216b5be5ea47f51bu64], [0xabfeef1b61fd5cefu64, 0x45d9be8df6fab15cu64, 0x1b7d50d08e98f418u64, 0x1a35e7c734047a19u64]),
-    ([0x9afb20a7b06a214du64, 0x19c1d7da68f12fa0u64, 0xe557d7c2017c1396u64, 0x02b38f7b842a2685u64], [0x3db120cf9199bd9au64, 0x587cebaf09f490ddu64, 0xd55c65962a842086u64, 0x282f8bce6aa5f4deu64]),
-    ([0x846e6f78c78ae685u64, 0xce743cfbcd51f841u64, 0x54022627c0057d7cu64, 0x2d6b0ee82e7b5df9u64], [0x5bd85cca5e6a0950u64, 0x450ec431e0c1b083u64, 0x7a29bbab9389f01eu64, 0x16bedc3007de1260u64]),
-    ([0xe72b8ff81ffe5c2bu64, 0xde4cd6950fe7bbf3u64, 0xc6d89699b17cb880u64, 0x0050c5c7f2fbafc8u64], [0xbe882e125bed2c1au64, 0x141ab2f1913ab389u64, 0x2cc826356b94c5beu64, 0x125eb2959c33392du64]),
-    ([0xbc9ea797cbb6d905u64, 0xc58f26c2d9abf0e2u64, 0x1e8dd9719cfa9416u64, 0x226b9c17e34f5cfbu64], [0xbc3005ab3cc0170cu64, 0xf7147805e72180c7u64, 0xd63da9fd053e66afu64, 0x22032747479bbf57u64]),
-    ([0x859b3570460b9673u64, 0xd9d81c2de4411764u64, 0x7bf9e84150b85e02u64, 0x244e7cfbd11d0565u64], [0xed1b0a0eeaadbdd7u64, 0x2d771ff6d904a6f7u64, 0x281487dfa64bb5c8u64, 0x17c0feca5a318e38u64]),
-    ([0x0d523f47901566d7u64, 0x6af205e00223673cu64, 0x4050dbbfc9eb8409u64, 0x1dd6d4cc52a0068cu64], [0x43cb060deef3f937u64, 0x78c640ea52e6c365u64, 0xeea7f8918f1ac47fu64, 0x0c300d62ec6da774u64]),
-    ([0x96683a209edd1502u64, 0x72ea4414e1a1c923u64, 0x3eb3a8acdd895a42u64, 0x050c67c3321da453u64], [0x4e3d802065ee18b9u64, 0xde6863008cecaebcu64, 0x5560dc5089716749u64, 0x141dd7efd8316972u64]),
-    ([0xbac0ee5b7bd0896au64, 0x500efd8364a43b60u64, 0x5591a02331500007u64, 0x148d89a8804b29a6u64], [0x3b8ff2c369bc7c69u64, 0xdff4a9d2343d7dbbu64, 0xa99af52232858476u64, 0x25d458d1cec50a3bu64]),
-    ([0x1a9fff93aa07856eu64, 0xa09f692bce74c5f0u64, 0xb85b583c3304d67bu64, 0x26d16174951f0cfbu64], [0x95790211cf854bbfu64, 0x5d1b4ebe08a75d26u64, 0xe93fb23ffec5a700u64, 0x24b71589d3c137b6u64]),
-    ([0x019acf58a1eca5b6u64, 0x05b6a7f650a04995u64, 0x2843af0bd0372915u64, 0x16d74e009eef2a81u64], [0xc18ed6c4ee978729u64, 0x8d8077cb2b9f8fc8u64, 0xe0dd1bc8bd421edau64, 0x0d9dda30b867538du64]),
-    ([0x536a07971f05802eu64, 0xd2d5f45fb1cb5c23u64, 0x1fe34eb98ee36256u64, 0x178a884fe04eb3d7u64], [0xf6639a04350d9a64u64, 0xd5eabc70702ddd9fu64, 0x2dae87b4a8cbdb3eu64, 0x143d2d2fe10ac699u64]),
-    ([0x624b6718bea56145u64, 0x76f3e3b740da1a1au64, 0xe72719c2085a6246u64, 0x044f56dacf2ec124u64], [0x7f78629b5630535au64, 0xa180f1b3896e2f70u64, 0x8fad849fed9a7a65u64, 0x157982d3ee242998u64]),
-    ([0x09593f55c43db7e6u64, 0x1891f96643e232a3u64, 0x916cf971c67b01aau64, 0x23ae3029ad75d69au64], [0x9cc3ff1a2334d16du64, 0xdde1e4ebdd957e16u64, 0x04c591cfe0eda756u64, 0x2250195ff669921bu64]),
-    ([0x939c3aacca0a5324u64, 0x7ce97faf1042f2e7u64, 0xd4f4cec97d3f81c0u64, 0x107cc8a98c66422eu64], [0xd9ff158759a6146eu64, 0x309baabfbb9e92ebu64, 0x934b4212e236d994u64, 0x207f5782189246d7u64]),
-    ([0x43ec8178b2e4ae8bu64, 0x1545b57ee1331433u64, 0x385354b20cfa424bu64, 0x1e3cef5e680760beu64], [0x23d69e2a4f4b2515u64, 0xbaaa8e7464bef9e8u64, 0xe3d21f06e93e7b18u64, 0x1bdea815e11ee78au64]),
-    ([0xc49e3f2d298fc793u64, 0xa9b5d43765a1f31eu64, 0xa450ba6260f039abu64, 0x011d1572335a5da5u64], [0xfba12b6b46c067ecu64, 0xfc2db0ba912ebdaeu64, 0xc671a5675ddc2cc3u64, 0x00d75365c62a3983u64]),
-    ([0x94b02bb07084a6d5u64, 0x586dc54bad285d74u64, 0xebe81b638567e89au64, 0x2ec2565a68b97910u64], [0x4d8a576d54fcd0adu64, 0x405905485c14dbd5u64, 0xc4e8e19e4a846277u64, 0x027e99d64ab0bebcu64]),
-    ([0x84bb9d91f44ef5cdu64, 0xbd621198e75a93b5u64, 0x7f84787c18bbd4a5u64, 0x090a4dca71be7c1fu64], [0xea8899a675bc4f49u64, 0x8034d7ecd8089231u64, 0x8f6626a0de245984u64, 0x0a05bd45889403d7u64]),
-    ([0x9e8224f9e58d2045u64, 0x8763970f9db56d8bu64, 0x002c22ca6924235du64, 0x23f7a8c49a5b1fe5u64], [0xe52d8ada0feb81fbu64, 0x58581f8f5de3bcb2u64, 0x18db9952714f4761u64, 0x18d3c8e70a1dbbecu64]),
-    ([0x86d1e6cb8656a485u64, 0x3661b1ac5679b7bcu64, 0x43b2077ec75ce709u64, 0x2e3d939e52ea62ecu64], [0x1bd8dff5583e1a4bu64, 0x45a3d0fb01b6edc3u64, 0x3ab6f224d02a1fbeu64, 0x2cb16a491032eb7eu64]),
-    ([0xcd69ad77548fadb4u64, 0xe49ba19175036b4bu64, 0xecb39c5e9ceb75f9u64, 0x2d35b26156e0c2a8u64], [0xd8387dcfada0bbedu64, 0x2edf3082fe7c5aabu64, 0x8f9a5a0b61bb9056u64, 0x15ea44119f966b89u64]),
-    ([0x4cf3139726e8cedbu64, 0xf7c37e0b92e0fa23u64, 0xabbe4327eaab104cu64, 0x1512ac4d8cf153b5u64], [0xa79c472dba084e63u64, 0x7fff34d582b56424u64, 0xc1ac01d764d6fdadu64, 0x08e00c3a7af3d079u64]),
-    ([0x231204708f703636u64, 0x5cea24f6fd736becu64, 0x048b6e193fd84104u64, 0x30644e72e131a029u64], [0x52b6234558ae58bcu64, 0x108ab18035bc6aa4u64, 0x7a0ba4680fdfdc21u64, 0x2dba186244e9763au64]),
-    ([0xd3d1b0cf76e3f61du64, 0x35822c12249148e5u64, 0xb63f3729d20b7f7cu64, 0x26ebbec7effba5d2u64], [0xc10638284409b149u64, 0xd711061537f4074bu64, 0x289b3b36ebe9ed08u64, 0x2f88c6ced3164b47u64]),
-    ([0x225bb63f9f66d378u64, 0x73e58e58f57b1a09u64, 0x7f3754066b52a48du64, 0x1c68515021036523u64], [0x1760f1ba69c8fee3u64, 0x5ee28b6232e70634u64, 0x477555abc189a847u64, 0x0a0f560cd4179ef8u64]),
-    ([0x7e0c0fbdd6c6cd35u64, 0x528c723e70a31121u64, 0x798ecb51b21fcdbbu64, 0x01edf995f5127844u64], [0xa8bc80069b7eae67u64, 0x5eb6808b75a0da96u64, 0x52794310be411880u64, 0x2787951eb3378997u64]),
-    ([0x197f6be2bc203e38u64, 0x6b4f843824b7ff23u64, 0xbfc6575d3a84fb82u64, 0x2e322cf1c946f479u64], [0x7d1b105b61d4e5e0u64, 0x4717a50d3c9c1fd2u64, 0x502dc183c7fe9dfcu64, 0x28b45f3b24f513c7u64]),
-    ([0x2829a4c266d73b2bu64, 0x221fd3035fd96368u64, 0x741b424f8532fdafu64, 0x163bf092b517a51bu64], [0x631c39b45fb380f5u64, 0x22012ad854068e52u64, 0xc9e3036b851a5833u64, 0x05dc2217197341e1u64]),
-    ([0xb5f8a4e46bf50e5fu64, 0xb373277d7639556du64, 0x95c85b1018793d56u64, 0x1eeed84b394e3eb7u64], [0x641ffca4a56013a0u64, 0x36104c105ccc6014u64, 0x22604094a741f92cu64, 0x20856609c687d375u64]),
-    ([0xd26ea6343b45a42au64, 0x6a4e7bede712b7abu64, 0xfdfe518ae131f37fu64, 0x1a73c74ef851ac60u64], [0x26f52a8cf6e233bbu64, 0xb1cc8d23d849af4eu64, 0x8d709035bb3bb6e8u64, 0x0d75efc15b9c4651u64]),
-    ([0xb74360485bd19296u64, 0xea3a18507483a7c0u64, 0x6e58a5a5d2954aaeu64, 0x08415ed5b41c43feu64], [0x8a5dfddd9da00e3du64, 0x48ab55b326ce78b7u64, 0xda52468e4269ecfau64, 0x120b6a521f990f7cu64]),
-    ([0x327e97d86530fe58u64, 0x68ca339b76ff75c6u64, 0x06edc84251005753u64, 0x1d2f591982c8274bu64], [0x4c651266ed9df90au64, 0x38a5304e2f60dd45u64, 0x1c3b541299537238u64, 0x0af606b84b2a9221u64]),
-    ([0x2f91381cfc9ffcc3u64, 0x524f1aa0f6bf8944u64, 0x617d2b70d27b667au64, 0x00a6ea040ddbf5ebu64], [0x0f32afde3373caadu64, 0x010b5ffa24568141u64, 0xf80bb66384100d3du64, 0x1c158673f3374ae8u64]),
-    ([0x93c0c2d550af0cc0u64, 0x8074df461dd4bd90u64, 0xf35e238f00f00301u64, 0x2a6072bb92c4aa4eu64], [0xf0554eec10276697u64, 0x7b82599888c3868cu64, 0xc9d2060033052112u64, 0x0447b1a97308516au64]),
-    ([0xe9226a857cf54490u64, 0x68a6b3d3a0a595c0u64, 0xbb5554006d89941cu64, 0x1235ae87260eaef3u64], [0xca7e6f7f1a8bc3ffu64, 0x55e79628c1ecff84u64, 0xb0ad36b67764a82bu64, 0x174d6d7b46cf78d4u64]),
-    ([0x03cf39a973318585u64, 0x01f14b27998c2ce3u64, 0xadf5f8fc089704bau64, 0x22fd106902dae43bu64], [0xb344c9f5d743ec8du64, 0x5f0c3b84a188425au64, 0x0f25005b05ed015du64, 0x1e77ea5530e8f054u64]),
-    ([0x83b717b44e1f6898u64, 0x5e3e4718106a01acu64, 0x49338a1f4cafc9b2u64, 0x2fac7336c0a26cedu64], [0x9d7148313a1900a4u64, 0x3905c3345df1a25fu64, 0x126235f8ae971cd0u64, 0x0a793e269e122308u64]),
-    ([0x04aebf464bebd55au64, 0xefe068d669355096u64, 0xc1dd8df3dd363373u64, 0x0c635135e629da06u64], [0x5649294c16bf421au64, 0x499d72aaa31469a3u64, 0xc3c8395008eee840u64, 0x18615e3ab5c095a2u64]),
-    ([0xf322313399e33ee4u64, 0x98b64ce7357e2e41u64, 0x2113fd22b7b88ef8u64, 0x2df6a47af434ff01u64], [0xf310e4ea14017b70u64, 0x91188460952d44dau64, 0x59edd9c469f605aeu64, 0x2255667337d30562u64]),
-    ([0x4a8885a9685df0bcu64, 0xc3d71195aa77c6d9u64, 0xe3f8267ca3eb8a6du64, 0x20b18aa0ebd91d25u64], [0x19bd0cc688ed723bu64, 0xaf1ea19582947f64u64, 0xc11090606afff476u64, 0x2b5b3371bf976bd6u64]),
-    ([0xc89ff0b91a38c0c6u64, 0x32690fc7f10198afu64, 0xe4b3f5c048e928ecu64, 0x13291c463d080933u64], [0x6225c27171dc25d9u64, 0x6079197bf7436cafu64, 0x73ff30680a92a995u64, 0x018f2ef38328c6d6u64]),
-    ([0x17c9a3299e99a268u64, 0xbbd8e7f9d7e88132u64, 0x59cbd52c56a76b00u64, 0x0c9368a058763a16u64], [0x130236e1ffcc76b8u64, 0xc23042dc80d77d27u64, 0x0bef3f6419f13e4au64, 0x066b442a12300325u64]),
-    ([0x0792d31b1da46548u64, 0xc2f2a8580ab90311u64, 0xc5c203f2bd90f564u64, 0x1f12ad0f9a18df4fu64], [0x00ea7ab187255dbdu64, 0x1d691d2f06f664e2u64, 0xc04af27078bca125u64, 0x2b0a5de911549e17u64]),
-    ([0x943ee8b21e324899u64, 0xbecc86553b1da190u64, 0x03cadb69943cbc83u64, 0x09c5d0cd38af70dau64], [0x389941cb3718cefdu64, 0x01764ee1d94e7506u64, 0x6ed0e9eca6e99c4au64, 0x0241134eb4985192u64]),
-    ([0xfa0888944a0063dfu64, 0x13d96a3e52cc762eu64, 0xd147614ab5590343u64, 0x2f78168237929310u64], [0x7fa8d7ae9a203e0du64, 0x88fa04268556b59du64, 0xfcbc90671ffa2ba3u64, 0x1525fcef59a19f1bu64]),
-    ([0x420a912c3c299a5bu64, 0x24ff41406813ecb9u64, 0xb16a20e4371ff3beu64, 0x012ce8e0130dad26u64], [0x8dfef0eb66393660u64, 0x97e1305d8fe57388u64, 0xaad179bffda9ce12u64, 0x2012927f17e14419u64]),
-    ([0x497e574719c9b6afu64, 0x4a0b4b4d31725fcfu64, 0x56db3c8369bf0852u64, 0x2b2676ca4abea09bu64], [0x115956692b8c0907u64, 0x99a66fdc98cf7e14u64, 0xff0cbae444488b58u64, 0x196d0e594a2542d3u64]),
-    ([0x9dab7c08d1d30d20u64, 0x4aae00aa6a6128f1u64, 0x01584765d7aa2d27u64, 0x292f64e085dbda43u64], [0x5aea3c4d62621f5cu64, 0xfad5b60f00053f0du64, 0x56afd7becc55de0eu64, 0x087976ac689625c3u64]),
-    ([0xfcb0784b60c65f31u64, 0x1ae288e0b2ae3303u64, 0xd7195b0a4b8708afu64, 0x102e9408b1a5e1d3u64], [0x9f4fb6c2f1a93661u64, 0x5b4d2810f722d9fcu64, 0xeca903b93f10040fu64, 0x0e135f87dcaafae7u64]),
-    ([0xa3993fb501c473f5u64, 0x1e2c3c82c8e6048bu64, 0x6261395486bde636u64, 0x2107392b83fe4663u64], [0xca4ddd80ff5f8436u64, 0x78341837a39147bau64, 0x142f1a18b7ab0ddbu64, 0x134ae4d837c770a2u64]),
-    ([0x187cf6e8364d142bu64, 0x7e353f98575cdcd2u64, 0x826d6e0e75a9dbbcu64, 0x1effbe567ff1187du64], [0xbc8fdf6bd747e7e8u64, 0xc16125ea793c37cdu64, 0x5fe9e1f84ea50d25u64, 0x2ceee4e4485ff30cu64]),
-    ([0xf0a2a6b798eda693u64, 0x647129cb8bbd663du64, 0x0e94ff0eec42d99du64, 0x258d5a829d23fdcfu64], [0x93f30cb5646d7fb9u64, 0x928a27fa8b7a22e3u64, 0x6543ae522acdc182u64, 0x17448a32a01e4ca0u64]),
-    ([0x5f84237567185647u64, 0xc3aeefeea84a9026u64, 0x141ad5a37d9e2fffu64, 0x2487c34389844440u64], [0xe6d832e8d5cef40au64, 0x4eafe7344dab9f68u64, 0x3e243ce825806109u64, 0x1baca1b2f74cc39bu64]),
-    ([0xb10b15eda4e3b1e3u64, 0xdfed2bb98c9e4ae0u64, 0x2de8a8f46c03ccdfu64, 0x0ae3c95fc03c0a5fu64], [0xa7717f063ecc9b5cu64, 0x2193f901be882eceu64, 0xd3c4142891bac5e9u64, 0x1ba359bd85b83c33u64]),
-    ([0x64e777cd65bb9f48u64, 0x8e3517a3a4527953u64, 0xa8de046aac50c962u64, 0x22399c34139bffadu64], [0x31173f32e5bc3a74u64, 0x6f248c561febf57du64, 0xc150bfe029e39725u64, 0x20266ffa51d79062u64]),
-    ([0xbe4d214537b7d17du64, 0x91862e76682f3a27u64, 0x15d367e0c90e2fd5u64, 0x242ac9267fe555a2u64], [0xd1cf21046903bb62u64, 0x45a26fa42011a369u64, 0x09dc42d55189d89fu64, 0x14e5d16a64058ea9u64]),
-    ([0xa3cc47e9fd49ff79u64, 0xd8fe5a1e718d7676u64, 0x3ed0dbd0ec3ce13eu64, 0x2854b960df79d0bdu64], [0x8b7219706dfb89a7u64, 0xff265c6f0004b114u64, 0xa91f64876dc5a8eeu64, 0x22dcfa4bcdc70ba2u64]),
-    ([0x09f6a32e26795d43u64, 0xd6ed658db977955eu64, 0xe099a7aa3e2d23f9u64, 0x01b9fecf78626dddu64], [0xec53cef970998ff0u64, 0x574e99c0765b7952u64, 0x865a12bc7a04a18eu64, 0x1869ff36d8fe2b44u64]),
-    ([0x7e361ae20677b279u64, 0xe72d347eec6ae2f3u64, 0x6ecce081f367b426u64, 0x2fa023af5b19b3cau64], [0x0d45d042ea1de817u64, 0xbb5ff6d2a8486ee4u64, 0x7ce7a7c9c58d8119u64, 0x0920d13f8884f72au64]),
-    ([0xf9167717d2d43db6u64, 0xfd25c6e8304d3467u64, 0x485ef582aedc5a18u64, 0x1a0e4826c37a86acu64], [0x7da12b262ccd5114u64, 0x329a95c35e36fedeu64, 0xfd1bf7d9f3c963e8u64, 0x05e81196de020d6bu64]),
-    ([0x9591fdccb0e0b3e8u64, 0x487aa2fbe9c54806u64, 0xce0dc2cea5fc5747u64, 0x00cc5636ad26246fu64], [0xc694cd467cce6352u64, 0xe636258350e6b275u64, 0xb21e57ec998d3cefu64, 0x130e8675077f05f1u64]),
-    ([0xdfa211f73fff90eau64, 0xc79fabd0dd491f4eu64, 0xfc238d40e45df3d8u64, 0x10e4035c3dd7148eu64], [0x9843e6ea6d6eac8du64, 0x7a2dbb74a3ce5985u64, 0x355c1ed28db3329fu64, 0x07f3c809027a7f32u64]),
-    ([0x260e7b4cfc1015beu64, 0x0c4533fc7ef12dbeu64, 0x0ee9ca3dd18c3c63u64, 0x2b424195a694a47du64], [0x654a8f7b0d5ff562u64, 0x229bcd86253c9125u64, 0xb6ab3e02c9fe2b73u64, 0x0979979ab536f90au64]),
-    ([0xf50f06c2710cbf95u64, 0x06dcb34878a9439cu64, 0x7956a26b3b22c30eu64, 0x1cac33f4ebe35431u64], [0x12fef2757d652575u64, 0xc3c6221e79894b43u64, 0x54fd68d340bfa51eu64, 0x002a9962289d1c9bu64]),
-    ([0x388ccd82165e64cau64, 0xd016d792960dcd26u64, 0xcd3132613d97ce00u64, 0x003c53f0d2ec4d22u64], [0x1fb64dfa8ff40af7u64, 0x524269f711e5ea9du64, 0xf48c215aef7268cbu64, 0x094e8b04693a5769u64]),
-    ([0x7f98206150db923bu64, 0x9b82eb19337701e2u64, 0xda1c4634ea07aa96u64, 0x107cb25a16d14b44u64], [0xf7b77c2fa7afc4c4u64, 0x5a2c4c14cfc01b66u64, 0x1a2b09cf4028e398u64, 0x119577afcacc8867u64]),
-    ([0xe05ef3487947a56eu64, 0xe6d6a63151728297u64, 0x60a0d5ffc5cc2449u64, 0x143e1e2bd8e2f399u64], [0xa4812263631ef09eu64, 0x31b3fab4dad23d47u64, 0x2f3ad3baa3fa4fdbu64, 0x1d2165a800800030u64]),
-    ([0x23564759e183ced5u64, 0x4fc8e399acd6b143u64, 0xa48659be64a91d7au64, 0x0720c7a497776158u64], [0x2b764645d09f64b8u64, 0x5a740870f1ab3fd6u64, 0x46dc065d5f7ad8fcu64, 0x1dc1bb40733e1e89u64]),
-    ([0x29b170de7d16aaabu64, 0xa85a94e727d39585u64, 0x1c0ee6a51d16cbfdu64, 0x2218aca18dcc6f95u64], [0x7f35e25b7aefc6a6u64, 0xc097ed7c071cec8fu64, 0x49726aa94894d05bu64, 0x17a10003726c9959u64]),
-    ([0xea011336c9d93bcau64, 0x92dc4a170820fb0au64, 0x20ba543eebe363bfu64, 0x29109aec621b6e17u64], [0x0339378d7311af7fu64, 0x02184c9eb9ccc07fu64, 0x3dac533a6433208fu64, 0x0220c09cf1426e21u64]),
-    ([0x9080aef58864725eu64, 0x6dd2d3418b088356u64, 0xfb5ee9a07a679a23u64, 0x28f685de468b3c86u64], [0x0a197197419fd242u64, 0x8ddc6134ec6d4c99u64, 0x8112404297abfd8au64, 0x1e03a226e3960699u64]),
-    ([0x0893274dbd3a11d0u64, 0x606836d6617c7355u64, 0xd73b5392727ae30du64, 0x24bb3843988ade18u64], [0xebaf4a7ba0b8594bu64, 0x89bd6691ae19ae45u64, 0xd3e1f615fe62fe79u64, 0x146e03e715ba6884u64]),
-    ([0xb24efe453e81d680u64, 0x95144c0c842cbca3u64, 0x40f285837866684fu64, 0x193876f59668063au64], [0x7acd7910d90157b5u64, 0x7e6507f662995777u64, 0x8e6a75fccd59d54du64, 0x01e4dcd97dacf84du64]),
-    ([0x8a07ca132f0fb376u64, 0x678bbc966a49796eu64, 0x25860239851ecfbdu64, 0x02ad331ffb0c9649u64], [0xcbce1b55ae679563u64, 0x209cce54ece2e458u64, 0x3b67942e08c0861au64, 0x11e0ef807ea195c6u64]),
-    ([0x1582bec6c7231913u64, 0xb00321568d6f76f6u64, 0xe8d3cc43c492453au64, 0x0d94cee5834e212du64], [0x92f65508ff683010u64, 0xca8aacafc17376e7u64, 0x34479900b8b0df94u64, 0x23fde4429c86b1fau64]),
-    ([0x902cd1b24806821cu64, 0x7c127cde9c4c527du64, 0xf83754fa7fba0276u64, 0x29a69ab797358207u64], [0x28c6d8c22ff17265u64, 0x27e7e60f17665dddu64, 0xdea0aa64e14cc29au64, 0x0a6222c830b13b1fu64]),
-    ([0xd737f3812bce4447u64, 0x14d5d44ef185d9feu64, 0x1037215968bcc7dau64, 0x0723bcf2f8575a31u64], [0xf79c735ac7c4ff64u64, 0x6bc66da9dc1b2278u64, 0x91e7dcbdd5dc69edu64, 0x1068a0d6c3a54c00u64]),
-    ([0x94c32e04781992f1u64, 0x6920bc2fbfb2f0ddu64, 0x4868f99c994b2ceeu64, 0x27120389fae51bf2u64], [0x347111deae596c13u64, 0x494164a54cda1040u64, 0xdf62d43a9ce3ece6u64, 0x0bd320c68d3a6de5u64]),
-    ([0xfad0dad8893e86a8u64, 0xebc8b6b63d00ab93u64, 0x5647b0b446ff2834u64, 0x197b870fac583309u64], [0x9213673808e97fa1u64, 0x1d8ac2b93420a9a9u64, 0x1d2adb8883e2eb92u64, 0x1fe480fd6a154f14u64]),
-    ([0x756048aec6058c53u64, 0x3fade5b797a4f7fdu64, 0x94b63b62960bf2b9u64, 0x2c8ce5f2ca09af98u64], [0x5c3e32dfbd52b44au64, 0xaa887a776241c847u64, 0x23a0530515d8358bu64, 0x21e1d946addbcee2u64]),
-    ([0xa1c5ce9c58f7e600u64, 0xf0d7f82140f1b5efu64, 0xece83eed9c9782b4u64, 0x18698648e1b2029au64], [0x37c083835401b605u64, 0x69a63a50e8cf4bebu64, 0x9d96abb3d30c2609u64, 0x1be025b1142daa16u64]),
-    ([0x67082bb5dd877667u64, 0x24ac4acdaa5df2a7u64, 0x0d7032e05c65b6deu64, 0x25208db6674d8bfcu64], [0xa5271dfe0a770b45u64, 0x9e4447402acfb27eu64, 0x9cbbe7f84b51fedcu64, 0x16bcc296a11eac13u64]),
-    ([0xc958f6f78be52262u64, 0x79dc3d69bd73ddfdu64, 0xe80c9b891aac74ceu64, 0x0d1fb1efb485d22eu64], [0x288f01b7b15f920cu64, 0xab0ef44beb93ac20u64, 0x4cbfb0d61aa6d86bu64, 0x1f0a7d3f3f93ecf4u64]),
-    ([0x9455f9905abddd59u64, 0xf7aa96c2158b5039u64, 0x23b809dd2198391cu64, 0x001a3f3866b404dau64], [0x2f2f3d4868d6abe5u64, 0x5563a7d39de0c66au64, 0x80d3901e26fb1e39u64, 0x05b464700b7a49a4u64]),
-    ([0x179f4e1a98a0b110u64, 0xa31e40a36df4a3c7u64, 0x429ba1bb76e117e2u64, 0x12f18a55f7cff7e9u64], [0x1ce0a590c541dcc5u64, 0xd78db03d72386028u64, 0x5512290b448d8750u64, 0x2f6e0550ce85fc36u64]),
-    ([0x1e7cf96f893de19cu64, 0xeee3e28d41b8ab52u64, 0x7d9ef422d0b69f2cu64, 0x213a41b93fb50a52u64], [0xea43161343705007u64, 0xcdc98c961be8d6c2u64, 0x13f8a5a60868d20du64, 0x0bbbb0c42f855190u64]),
-    ([0x13644bef31ec3bd3u64, 0x635e9004db8c3b9du64, 0xb79b1944db1c0147u64, 0x2290ee31c482cf92u64], [0xd607a2b8b9dc0614u64, 0x9b4fdfc30c164751u64, 0xe9cf6a9f0f506bedu64, 0x1109a8fbacf82a17u64]),
-    ([0x9d0f7189763c36bbu64, 0xa11c3e2af512ee93u64, 0xb57c6b37f35d7264u64, 0x077430d20b34795au64], [0x00bd7b9586d95379u64, 0x4e3876bed6f7fd1eu64, 0x2c8e4dc0990eff74u64, 0x2a45414547a8a95cu64]),
-    ([0x448cc38816e59507u64, 0x98677065a43c0fa7u64, 0xfcd66424a81b0dcfu64, 0x1e9ea6a849bf1320u64], [0x35d032ae40dc2c85u64, 0x6b5bc5a98a614e9du64, 0x6a7e25f0f925c2ceu64, 0x2549f3a2929629b9u64]),
-    ([0x5878670eac96b9c2u64, 0x349a47f48594cd11u64, 0xd750d122f7f831f3u64, 0x1fc8a5867f226d24u64], [0xedb65e4cbf4aec77u64, 0x5a612590f910288fu64, 0xbbc00a3044a4a477u64, 0x0d059ac2ccbcdb78u64]),
-    ([0x7a6e4175447ca78fu64, 0xc4c3de038cf1f5e2u64, 0xd8b71ad0172e079cu64, 0x2d342879543081f2u64], [0xe7c78449c664f35eu64, 0x9e5e7389bd1c1d90u64, 0x8bfe631a81834049u64, 0x207b2821d7d7a16fu64]),
-    ([0x43fd744207f62c03u64, 0xfed5a6292675ff65u64, 0xb8d26de764a5758eu64, 0x2f22ee5a3172000fu64], [0x2abc6c4b62302a82u64, 0x4b9cbb3e9e156f3fu64, 0x8d816382f02dbdc4u64, 0x2ed476e4db9b993cu64]),
-    ([0x673654bc7ba1c030u64, 0xdbed12ef6b66f3abu64, 0x6fb3dd46d6b650aau64, 0x10153efc53e6f1f0u64], [0x3bcb4f5a78aac1acu64, 0xa57345fca95d4857u64, 0x18188e757c60d7eau64, 0x073c4bd27c2870acu64]),
-    ([0xddfebf66ba0c3297u64, 0xc18d3f641b00b703u64, 0x309887678a688987u64, 0x1ef9080a144a3565u64], [0x5d2579075858b248u64, 0xbb3136c614a7fb5au64, 0xd676ce82af88ffdcu64, 0x0721641c27dcf83eu64]),
-    ([0xc836c35deae6f0ccu64, 0xe878e0b67fa1a24eu64, 0xa523d61391be2612u64, 0x27f89853d3b698f5u64], [0x661f0afecffe4ebfu64, 0x9fc6e95ee57552d4u64, 0xdf7eeb56c57bb87fu64, 0x0ecb1fbc3a443bb5u64]),
-    ([0x0822f5d13cbf51aeu64, 0x9355b36bd5387eceu64, 0x6a7227e5f54b89a6u64, 0x1f8c386075ed86bau64], [0x6954245de6e901edu64, 0xd85732378a163546u64, 0x2ed08f35c21d183fu64, 0x0fe6df03bdb14e6fu64]),
-    ([0x2ab544aa4f0f5fd6u64, 0xb3cd5ceed2a064c2u64, 0x2428dac9c2ec58a8u64, 0x04269c275408f2eeu64], [0x7ec7f51baa3d377cu64, 0x994a1a0145f69506u64, 0x298efee409141b8eu64, 0x2c886765bb47b68fu64]),
-    ([0xd9df0e9b15c9d1f8u64, 0x84ce8ce06ec7372du64, 0x0355f6979ebf11a2u64, 0x0fd20d4bf7d654dau64], [0x2f36486c7392cd7au64, 0xf07669367d3289bcu64, 0xa01a2ceb46519fecu64, 0x15ae4b457f5c5411u64]),
-    ([0x7d2b8590ad36d0beu64, 0x289a278da3143818u64, 0x66300825349b86b1u64, 0x2d381d1bdae474c9u64], [0x5cd031e2b345e56au64, 0xc13520c2260fe8e9u64, 0xaedd5901f5a2b628u64, 0x094eaea447e40e5bu64]),
-    ([0x134cf0840b0bb3f6u64, 0x2c5969b7d92d3af1u64, 0x7417ab60a24cd7dbu64, 0x12cb11eeff8f40f3u64], [0x287fc3f09dad4d12u64, 0x08c78e6b6da46d10u64, 0xe5799d226a304c25u64, 0x1fed8d09d7f6c4b3u64]),
-    ([0xdd0588ee94b45262u64, 0x5e75d382d6a40348u64, 0xbed6a61179b9a28eu64, 0x22663248e036e618u64], [0xdcbea3c9c2085e98u64, 0x808ac138fbcb81c6u64, 0xf7dbffc6328940dbu64, 0x0932039ddab70e53u64]),
-    ([0x7a8b5a999e4378dfu64, 0xeb9965f078a143bbu64, 0xc0cddd5aa0199b8bu64, 0x1dff2a15d06d3bf2u64], [0x61f25de4c46b754cu64, 0x0d5962272644ff1fu64, 0x38ecc432631a2776u64, 0x18ba061d1e7312adu64]),
-    ([0x174e49a6e7d540c1u64, 0x6fde3da2e9e215b2u64, 0x3cee57f9d374257du64, 0x17854d6517637f39u64], [0xcfa31ddec25a2f2cu64, 0xd44edc5f5c6f9e11u64, 0xf477fea34963ddc0u64, 0x1354d67c08607856u64]),
-    ([0xfc5afba2dbb80e82u64, 0x7a39bda0f8c402ffu64, 0x6dfc3c4cb540bc21u64, 0x1971e34c787f7f87u64], [0x761ed1e59835d932u64, 0x883103655bd3444cu64, 0xf3b0a8d86535ebefu64, 0x1a3b8932df9bd206u64]),
-    ([0x769ffc72aaea9804u64, 0x7fba761aef94c629u64, 0x5b62408ea7867fc3u64, 0x1931d7339ff4e3b1u64], [0xc32a6a30722eff3eu64, 0xeaf38fe8c9e87b83u64, 0x96e96a3b8a3f3afdu64, 0x2c943e314eaf8350u64]),
-    ([0x28bcb896150c5a38u64, 0xb15ac4971c46b622u64, 0x5beb6a29db6bbb7cu64, 0x094bcf38db88be13u64], [0x9a27b54e5acbf67cu64, 0xee75ff5b3bb8d21du64, 0x3ae28031781c128bu64, 0x0ccb1ed22790a745u64]),
-    ([0x75359d4e4fd0073au64, 0x15aa6092d8120d5au64, 0x27a3fa7f36bd3c10u64, 0x2077d2382f079536u64], [0xc17bcce32b8151e9u64, 0x77cb78a8c7efa34eu64, 0xd5b6a2aea9b03fedu64, 0x1f0357fb9ad91b85u64]),
-    ([0xa87ad75ecb9732d9u64, 0x63d81adb25d4a92au64, 0x16b45185b99d4528u64, 0x1d592d3e45e37313u64], [0x5590ac9eb41eddf9u64, 0x970421093bba046eu64, 0xfa23bd60288abf60u64, 0x272e1f2ff06c8e19u64]),
-    ([0x3b7aae031f9ee2f1u64, 0x9ba576c7a219d853u64, 0x62b3dc11f76fb6b3u64, 0x12608099497312adu64], [0x060695a6ad6f7010u64, 0x8e1178604a8895f5u64, 0xe7f6bf65b257ed79u64, 0x260a24faeff57bcau64]),
-    ([0xbc6e3682214bac45u64, 0x160c4206e0fe0e84u64, 0x9123214c9e93b30eu64, 0x296e65ad9da0ada4u64], [0x1664566c7c71f585u64, 0x63adef29e9991fb4u64, 0x50bc5533575cb114u64, 0x0dd9e03213fe73ebu64]),
-    ([0x35c37d0a2604aacfu64, 0x8fbed33dc3fa1b35u64, 0xdc8d814cb960af3du64, 0x1e220252b1860888u64], [0x578847ea07edaa5au64, 0x23b58b8457b9ec87u64, 0x51ddb8c9bd41da95u64, 0x23255d6279963eaau64]),
-    ([0xa1b9f20c07bf7598u64, 0x19b84ca56fda16d7u64, 0x8db74c7067103c20u64, 0x24819055e02d3cbbu64], [0x316fc3058b79550cu64, 0x29c62238401b42afu64, 0x9e44d2eb8951741fu64, 0x269369a6e0ae40aau64]),
-    ([0x45114272f660d498u64, 0x17f89546b8b98b56u64, 0x1a094efd165f8fe0u64, 0x1fbc84c8b07cb22fu64], [0x00a1d4de65d7353au64, 0x9afcb0aa4028b4cau64, 0xc55f362e966fcd46u64, 0x295246bd02e5ed8fu64]),
-    ([0xd6e35dc4d61f0c53u64, 0x02de7c38444a05f3u64, 0x89711c3c4cf61554u64, 0x2b930fc6369762eau64], [0x1e87041fb2bf4f71u64, 0x3ed5567ea97d31f2u64, 0x011caca432a75270u64, 0x23e1e2db62c04eb6u64]),
-    ([0x7c8d6dc6c2df8fd4u64, 0xe3a762f21e04626au64, 0x10fb9ea8c756ea58u64, 0x19b7d7a4e99bde70u64], [0xaf96e269d4be06e8u64, 0x32ff2f1679db01b5u64, 0xb06c0a44deff0dd4u64, 0x02e46a9cece6b173u64]),
-    ([0x6e97fb49c41a2cffu64, 0x662c0dbd4d47a55du64, 0x8be430f38559b834u64, 0x0daef86f88c44065u64], [0xc179a1191457f9cfu64, 0xcff746f9effcb926u64, 0x287bfb3d17dd0a46u64, 0x0f7e10d527b64689u64]),
-    ([0x0c0c582b47fb3db9u64, 0xa2cd4130a67f2c0du64, 0x3c5de4f1254917cdu64, 0x0ba98527e26013afu64], [0xeee0953c8627dd8eu64, 0xfabd7d3be5618a51u64, 0xe14a1d28456820e9u64, 0x277f19b0ce881d5cu64]),
-    ([0xb3c7430902b306b2u64, 0x7da2cc6567b5a431u64, 0xc1c5c3b753234d35u64, 0x083206f64fb8bb9au64], [0xca2d6633e1f50ca1u64, 0x66fa11c154b4740au64, 0x449efba0cdfbe720u64, 0x2dcddee48063d25bu64]),
-    ([0x506bd5d5c2b2626fu64, 0xea775dcf1b872953u64, 0x395b73c3f6f7ef9du64, 0x1463c40eb28d72bfu64], [0x5ebcdfad30477538u64, 0x04cd29a2e42bdddcu64, 0xe410a1947147f0beu64, 0x112c150b150b2d35u64]),
-    ([0x8c6b41ebb2f3b6b7u64, 0x015ba558d04502a9u64, 0xb2a1512e2115657fu64, 0x19b8a5232639f834u64], [0xcca06554a15ceaf2u64, 0x141a7e86d56c117au64, 0xc9b7428daac9e521u64, 0x0bd76a33ed12b262u64]),
-    ([0xe34c42ef38b9ddebu64, 0xde82f959278c386cu64, 0x108ef651b1e8894cu64, 0x1c953cfd96c6649bu64], [0xad85ce86f206846fu64, 0x17f7a3c8ce92db3au64, 0x7bec6a21809abeafu64, 0x0ef0851e41f4457bu64]),
-    ([0xfe38923a5c74f4f6u64, 0xbf7c8bad85c4d695u64, 0x0ffccc09cc10c84eu64, 0x1323228375e360e5u64], [0x7851cf0941b4b98fu64, 0xbb26a6f0c3e8174au64, 0x88657b3595d449e4u64, 0x2c8d9a8a75a6dc40u64]),
-    ([0x0ef14612ac08e155u64, 0x2d42fbd81a36d513u64, 0xc361209eb57e018du64, 0x027f5d200d5e8576u64], [0x44a71b9d1aa3bec8u64, 0x8aeda01008a3cd1fu64, 0x47233b4ca07efd58u64, 0x14072fbe95f160feu64]),
-    ([0x3ac58d12201d1563u64, 0x9a6b462d127b2ac0u64, 0xbecfedcfa005e0aau64, 0x04d2b25e0fb2fd93u64], [0x0a4c95692be4fd33u64, 0x3cb1a4aaf3847375u64, 0xb893ddfbc96cdcdfu64, 0x0a8ff95a5c748077u64]),
-    ([0xf79b3d0e90cc70bdu64, 0xfa364c02f4df776eu64, 0x704a8d8cb995b7bdu64, 0x0dd992de5e247e84u64], [0xe658a2b43ffb9bb7u64, 0x79c2dbbc7df58561u64, 0x47d5a9df6047598du64, 0x2867bc901be68d2au64]),
-    ([0xb43dbbe0360b2873u64, 0x833f449faa18a866u64, 0x7b6056c84893fcd3u64, 0x02d581d4d1f860f2u64], [0x4211690a3d99e2eeu64, 0x76115a92c87ddcbcu64, 0xb0e1cb949a7d9d34u64, 0x211d01df17445ed0u64]),
-    ([0x16a88cd6393447e9u64, 0xd52845b08157783bu64, 0x47c1a02a1b1f1fe7u64, 0x24765b19edf51d84u64], [0x328851f1d9afbe42u64, 0x240fb6539e6d7bb1u64, 0x15c18e59b2845a53u64, 0x27b618059d42e934u64]),
-    ([0x15655c85c0bd119au64, 0x6ccfeed8a4f3207bu64, 0x5c58974f88e9e45bu64, 0x1341b57727889cbfu64], [0x8ce1669168b08850u64, 0x176235e72a630786u64, 0xb0177b6c1ce89df4u64, 0x1faab40ff3f9586bu64]),
-    ([0xf2280d07d7eaadabu64, 0x65080349dc96cf3eu64, 0xeb7fd579660dd566u64, 0x26f081d40c0b812au64], [0xb9194753c8245d56u64, 0xa4cddffd5be98f46u64, 0xcb8e169cebe4264au64, 0x22a9f14b6ae6f856u64]),
-    ([0xff191d933defc3b5u64, 0x91b7eec2ea3d8da7u64, 0x932e8f22ce0f4d74u64, 0x11d98a5c7bfdb17bu64], [0xbc1940438c082945u64, 0xe42a956843457f79u64, 0x3785cdf634fde879u64, 0x11f657842eb10426u64]),
-    ([0xb1aeffcfafa1d299u64, 0xf006ef3325791f22u64, 0xe600930562675332u64, 0x21c70ebc4c1cb971u64], [0x2298d82e88ca00c9u64, 0x12ebbe003ba7c3c1u64, 0x36125423f4327bb6u64, 0x1903743c8a3306ffu64]),
-    ([0xd1d46680d1c97b49u64, 0x3311a8b871592fe9u64, 0x013ba2fb0599a9fdu64, 0x2e7d25d7d5bc5930u64], [0x5352a594ce4d310bu64, 0x9b8097c3002ac8deu64, 0xc5725968c66a8d62u64, 0x08ca8cfdcc9199acu64]),
-    ([0x96d97b0420583871u64, 0x2038abe2afbdd963u64, 0x59787c20f7f5250du64, 0x1b7fbc04f943b2c2u64], [0xf706340c4ef18e67u64, 0x0d7538de663799cau64, 0xd30f1732632b1645u64, 0x118000b2576abb3bu64]),
-    ([0xbecca6e223022155u64, 0xd054426e1a0400d7u64, 0x3c77da6c6f9fd1dfu64, 0x292df99f62df2158u64], [0xcb16b76b8db66c84u64, 0xf32a9b49dc800fe4u64, 0x93665159655d797du64, 0x05c0738e8ab0d3eau64]),
-    ([0xd10d50731cc425dau64, 0x5bbfe0d48282001cu64, 0xa6b50564980ef5e0u64, 0x035b10f4740fe470u64], [0xac401ea296b5755au64, 0x1f0b9f36db6bddd4u64, 0x4a0465e4e2c2a5eau64, 0x1be95eda57b7c69eu64]),
-    ([0x0a67c0212a3d9c30u64, 0xbc5b65dcff7f9daau64, 0x27d6ef0bd156cf92u64, 0x0b813b96d83c4867u64], [0x12a229748c92ed14u64, 0x3a7f34091dc300b3u64, 0xaab970a652d44e70u64, 0x161f36a835002986u64]),
-    ([0x07901b31943d9257u64, 0xa05073c1e1e79ea0u64, 0xeabd7ff61db066deu64, 0x2ae0925b5ead058eu64], [0xc2730e8c323df99cu64, 0xeac86ca0d8fc84deu64, 0xc53adc6a986f667bu64, 0x2a2685b0b1a4824bu64]),
-    ([0x5d1fa18c19690841u64, 0x08a08f0245c8c4f8u64, 0x6ac3fd8985f94f35u64, 0x0cf7dd08929b3785u64], [0x4463f2570219052eu64, 0x55239fcc793b214au64, 0x7743e2e66546e223u64, 0x2b14d825c8cd8126u64]),
-    ([0xa988e6a0be03033au64, 0x0fc0295726798909u64, 0x06dc1808bf76c069u64, 0x19419e27a6220ee6u64], [0x47355ec4cfd5e609u64, 0x9b8bdff75d86e037u64, 0x5dac842724d9100cu64, 0x03e35c74e5864cafu64]),
-    ([0x234275e5182af70cu64, 0x8ec86e71da469e63u64, 0xf6b0b25efb715df7u64, 0x139661777bb49651u64], [0xa1ee1cdcf4109246u64, 0x8619e752433c6182u64, 0x08c83c096ebcd7dfu64, 0x10c8331ec31eef58u64]),
-    ([0x98eba5cae98ee86fu64, 0x16dbed0fb0118497u64, 0x0bb56eecb55ad7e4u64, 0x2903dcd7b34097f7u64], [0xe6548b50a647027bu64, 0xafcf192b08b87f60u64, 0x97e83efb8e24965fu64, 0x0f7fb15069b9e80du64]),
-    ([0xd14ad6107b308ee2u64, 0x0a447dd21f1c48a5u64, 0x0a46b91dff515bf7u64, 0x21ecd902bad460adu64], [0x427f08df98276a08u64, 0x0696e8ab554a8b68u64, 0xd1185e1e18fcad39u64, 0x00d74bf732847c20u64]),
-    ([0x12bd028e415f5b90u64, 0x2fc6c1b3f7c54c86u64, 0xcbb6241ec422e481u64, 0x0d22abdda10bcfcdu64], [0xf8474270e6c8f22bu64, 0xf823388d7e5e37b8u64, 0x5162835800edb0aeu64, 0x2ae57f4fc7bb76cdu64]),
-    ([0x0261be6308fda1e5u64, 0xca70c6e6ba4eb61eu64, 0x64b613379c09e3cdu64, 0x21dee02306c63ccbu64], [0xab17ca69eb248117u64, 0x53afb4ada968de4au64, 0x8e80edfe4ce9bcceu64, 0x28618ddb9f7bed41u64]),
-    ([0x32512538d003d241u64, 0x94a3170ede674c1du64, 0x27b64c40e670df51u64, 0x2a1bfdfe118c4d36u64], [0x46a023c6d728f5c7u64, 0x662cb09f9d5532d5u64, 0x3eb622aeb36578c6u64, 0x0a14dc3c682702e2u64]),
-    ([0xa23c6e82f8091734u64, 0xb35f4c9baa5a5fddu64, 0x77a52fbb35ff9905u64, 0x09dcfdff958aa2cau64], [0x99bb227ff12ca473u64, 0xfb2015e0a6c4f9ffu64, 0x15dd2dadbf2653b3u64, 0x00b94939c194ce6bu64]),
-    ([0x60d813092e691a12u64, 0xbe0b5e6e48b03974u64, 0x501dd89326906e64u64, 0x2f99ae4ae53fc67du64], [0x420dd7089a6f3993u64, 0xeb3c86c7b6ec6630u64, 0x3a544e2b7b5d245eu64, 0x11cca8ce3c6efc86u64]),
-    ([0x6312bf5f71fb52a4u64, 0xcc3df490307cb0c3u64, 0x81830f44ae21fbd4u64, 0x035747242a2ba0ccu64], [0x6e0d5b695d7d3a4cu64, 0x808f54f740f60204u64, 0xc768ed04b663d4fau64, 0x00a37ba8061a7097u64]),
-    ([0x9f899cd31168365du64, 0xa903d8d58f0b119bu64, 0x3c4d308a9d4abd37u64, 0x280e767bc0b8e060u64], [0x6dbb63d468dafdf6u64, 0x064fab809304d6ebu64, 0x0f333c2377b78ffbu64, 0x186cb146667a73f8u64]),
-    ([0xe304789c75eed76cu64, 0xd2906b6a67b9181fu64, 0x8a5fb017ceec30f7u64, 0x04a79b6baf712941u64], [0x2ae84638cf31036cu64, 0x7f19e45427d28ddcu64, 0x0412f10c07335f30u64, 0x2914384e26083d25u64]),
-    ([0xb8d01be8e846a566u64, 0x07c2d99b3852513au64, 0xbd157ac850893a6fu64, 0x1d59376149b959ccu64], [0xa5199371ea2f813eu64, 0x8ba35fc5be7cd708u64, 0x56f6377034f8cde1u64, 0x056dfebc66649112u64]),
-    ([0xd5fa06c53dc413c6u64, 0xc9dcacf22f700f45u64, 0x425cddf01dbcbd9eu64, 0x098e862ab266d462u64], [0x670ab6f63879275fu64, 0x76dbce799a37c8b9u64, 0x2362e9dbe453006fu64, 0x1870627a6c5775f6u64]),
-    ([0x228296e4cc1f5f41u64, 0x33dfcfa6b64ec189u64, 0x540e6644761eb33bu64, 0x280fea3492785b8fu64], [0x89aaa69dbac799b4u64, 0xed709f25c455f2eau64, 0xb2b8ed1d43a24defu64, 0x2fdd40008ac7ec01u64]),
-    ([0x320d4105919c1a27u64, 0xc125e2464429f2d5u64, 0xd904b61736f5562cu64, 0x04b75e211465e42au64], [0x01f0a4ec0c96bdfbu64, 0xcfb27ad6939d2cbau64, 0x72102de2571ff801u64, 0x07d640fd2563fbe1u64]),
-    ([0xdd4f297595ad9380u64, 0x4fcc8daa1be73d26u64, 0x3644aeb7776d6581u64, 0x200ba6358e95baa4u64], [0x33bac8fe12c270f9u64, 0x7e002ccf73807596u64, 0xac2d4d965d878af3u64, 0x224820b51bf9bd3fu64]),
-    ([0x28eb183f43139b5du64, 0x27b36ed774189922u64, 0x30ba8e2fd0aa7c58u64, 0x2b0eb9e927792395u64], [0x8e85b4b6f960dd46u64, 0x1b7c0f06d46d433bu64, 0x00eef10f3295b821u64, 0x2467de656b736ca4u64]),
-    ([0x1345fbf51d834128u64, 0xdc95666f9b3e9b4eu64, 0x931f15496fedcda4u64, 0x2c185a7a74a9443eu64], [0x6659669b0c4084aeu64, 0x93fa8de99b97f59cu64, 0xfcd2c1c19b2e841eu64, 0x0e3b26f9c9b002c1u64]),
-    ([0x83f9c001561cff4bu64, 0x6db419c61b8b11d4u64, 0x677f1564731b17ccu64, 0x1067f2e0e626b361u64], [0x0a58abf853d0558cu64, 0x35dcafd4f8738e19u64, 0x99e21e4da2abe3eeu64, 0x18de68aa3f9853a7u64]),
-    ([0x4284918b66a04990u64, 0x34779fa8c9d92897u64, 0x6e4adf2ac082a4c9u64, 0x1fff964c009fd2a4u64], [0xfe3b018f8855c69fu64, 0x08518316b942dfa6u64, 0x58766138817fed44u64, 0x092c4daf652cd9adu64]),
-    ([0x617967c507fcb5fdu64, 0x8fffd86fae23b3deu64, 0x48859465e025611au64, 0x007031a64ba61c2bu64], [0xca26880a305bd6b1u64, 0x0a0ba55e8ce24938u64, 0xd3e4c8180d7551f0u64, 0x2d9a40a2e428693eu64]),
-    ([0x6596de623781c0ecu64, 0x0b63d4661e8bad47u64, 0xce5e48f029191247u64, 0x236f49a3da9a0c68u64], [0xe4e691064b02fd85u64, 0x30ef803b91c9b98eu64, 0x4bd2375b80a61b3du64, 0x0dad6ddbc09c91c3u64]),
-    ([0x0b92df200a008057u64, 0x9dd230565c930679u64, 0xc7f4c88f7046cbffu64, 0x23941d643a07ed15u64], [0x7fd091814209dd0eu64, 0xb715b0da3fe7d174u64, 0x393428205b8e62e8u64, 0x13d49bea3cb2bc1du64]),
-    ([0xeded7188e092b7d7u64, 0x861d228ddab72e77u64, 0x704452c03bf9f67bu64, 0x2aaf809e89db5b37u64], [0xd4fcd166f4644f93u64, 0x99f811542d8b7c8cu64, 0x9780099c3feaf306u64, 0x2fe6c6e636bb93d6u64]),
-    ([0xd98fafa30f94c281u64, 0xb772195e701ed0a3u64, 0xc7c3cb3bd9b6f091u64, 0x0e598a662885a270u64], [0x966e51b6b4c87997u64, 0x830061df99a199c9u64, 0x8c28452930957a96u64, 0x153f93c3fc1cdfefu64]),
-    ([0x7ff26852b5fbd89du64, 0xb10b0e22461544cfu64, 0x453e3d56460a81d9u64, 0x238657fdbf84c324u64], [0x59ab4fac5dc2cb87u64, 0x0069540163c0d3deu64, 0x72acc6be7e18349eu64, 0x1fc7d123b237f72bu64]),
-    ([0x5b26567e5be835e4u64, 0x9717823aaee19e78u64, 0xc0f8a9c838e166eau64, 0x0b8d45a403a218bbu64], [0x344c558e5d51f1a4u64, 0xaef39db557e5f1f5u64, 0xf1513361a5ec364au64, 0x067f4970c5231a88u64]),
-    ([0x1afd7b9d8a0113cfu64, 0x04e58b3ed7ef59adu64, 0xca3fbbe623dbeea5u64, 0x1331db5ea839337cu64], [0x7a196b199890b7b9u64, 0xb33105a7ecb38967u64, 0xbb8db8d6990c6e12u64, 0x2754925e56de340au64]),
-    ([0x3c57eac9516a815du64, 0x7315f926224b9a7fu64, 0xd188939fcc529f40u64, 0x08315978e4679310u64], [0x69726d84074cf102u64, 0x0ce51a347751d560u64, 0x701639e82e636036u64, 0x1882a5dcbecba942u64]),
-    ([0xd1b9be3aeab7bc7eu64, 0x62fcb19902ec9063u64, 0xd61f482a38007132u64, 0x0e291ceee7ed2c53u64], [0x0899b6d45a4ce280u64, 0xe5fa8aa5f7f0f18cu64, 0xdb59ee816abeafd5u64, 0x084853f7b6e9dc62u64]),
-    ([0x54a6e977d5122a45u64, 0x35f0390c41f30b55u64, 0xb2c82d651e5633dau64, 0x0f6220a42f1c3b31u64], [0xfae802b81d3eb1f7u64, 0xd35a2b6058c26a88u64, 0x00dc423b57b5ce08u64, 0x267bb9e654dd8660u64]),
-    ([0xf3d57e16a411bb9du64, 0x072b5ca786cbf160u64, 0xecace2e16170e57eu64, 0x0f44222786248d3du64], [0x10e9b15988d77ee0u64, 0x375dc94fe90ad63bu64, 0xa6cb8920bd0003c1u64, 0x18c3ae3d06d5ce73u64]),
-    ([0x9dd05574e66b74f8u64, 0x9e1f71cdf58a4f6du64, 0xd0831f91a7c4ff38u64, 0x219e96c7b8d009bfu64], [0x0532983592d5fa8cu64, 0x905b692d025a03a9u64, 0xce1f7a8eae73c04eu64, 0x096006276c8b0c96u64]),
-    ([0x86e36967d46942f2u64, 0xc3a4479b39a18522u64, 0x35812f72098faa74u64, 0x043e329abe53bea9u64], [0x8562d374685244e0u64, 0xefd46904ab4b25bbu64, 0x27054e5b3fef992au64, 0x19f90e29e7b0d16du64]),
-    ([0x25db0a08b7f89384u64, 0x6fd561f9dc738f0au64, 0xae0b7de00847deb1u64, 0x08904470c719d7eau64], [0x606b60d8462261b7u64, 0x2559e35f42e66916u64, 0xe59efde2fd57b674u64, 0x00d64a013295e0f4u64]),
-    ([0x6a8fc09c1b93ffafu64, 0x5b2e1dbd715c04eau64, 0xe6cd86302ced9f18u64, 0x07fbcdb0de585eb1u64], [0xb9c7b7cf25556a69u64, 0xc27fc6a72206f08au64, 0x405b97e8ba3ea06bu64, 0x0a32fd5796d53e64u64]),
-    ([0x2560b3b832666a8bu64, 0x3419da0720a57971u64, 0x05b10d7378713cdfu64, 0x0c9bd9722531b324u64], [0x9b3c9b45bbb17846u64, 0x76fac294a3bc7d01u64, 0x853c7123ebb32ff0u64, 0x1640b6bc99ac9d66u64]),
-    ([0x4e6cd0ac31ae22fbu64, 0xe92fdfe9c57f81b7u64, 0x90a635f9ef9d30c3u64, 0x2ee5d058494194feu64], [0x155659845227c16au64, 0xad2292b52ecbae30u64, 0x4b09aa7c37f9c980u64, 0x1a2a65fdc893c0c1u64]),
-    ([0x0061087904110bd4u64, 0x0ebd93e580b9c0b5u64, 0x1f920179ac30e04bu64, 0x03b743d74fd9ddb4u64], [0x8610af35350c2e07u64, 0xce17aceb90843f38u64, 0x198b94ffb0a43179u64, 0x21c1d05f3482f1b5u64]),
-    ([0x0094f318a53ba897u64, 0x26cb3a593bdca212u64, 0x78023b1b31816315u64, 0x182d3575df895b56u64], [0xd976974a0b05b1a0u64, 0x276807efd1ba6c10u64, 0x5252b2052dcd3f21u64, 0x1672687d498d8b11u64]),
-    ([0xf501d161148bcd38u64, 0x486cab9bfd04a2c1u64, 0x65330c482accb8d2u64, 0x04d5b918c77b9513u64], [0xa0d3b810bf71752fu64, 0x8be80b4daf4004feu64, 0x762bf94a68276277u64, 0x2c106579c145bd08u64]),
-    ([0xc9fbf0cc9ba8ba74u64, 0x2fba72d81b139063u64, 0xd71a02d9d1834cf0u64, 0x0cc932684cc3c335u64], [0x6f55f49f95556595u64, 0xb76ee4be8f1beaedu64, 0x53b43e7c7717784bu64, 0x2b6d9b863b08bac2u64]),
-    ([0x79d1755468ea2482u64, 0x4f4874e383620a5du64, 0xeac71b410a0296efu64, 0x04ccd87a3fba75d8u64], [0xe2d3ceb3f6534de4u64, 0xeaf5c5b90f7d598bu64, 0xcb47ba47e0b799afu64, 0x14b33304e195caf8u64]),
-    ([0xa236e5e619a9bf4fu64, 0xf98696bc39cff1c7u64, 0x4b1317503a9969e1u64, 0x17537f3561e4ad49u64], [0x401052a76b3484dfu64, 0x643b2c1a01f44a85u64, 0x148762ac4cfe86dbu64, 0x10fd1bbe0872c2c5u64]),
-    ([0x26f49cc445fc5115u64, 0xc60b1d8f4830dab0u64, 0x3e9a16d5073462c2u64, 0x153b8d34f8edbfbdu64], [0x01959b152413be1cu64, 0x7319c4cb2e55dd24u64, 0xe32ecba4bbef97d4u64, 0x2099b98883ec8046u64]),
-    ([0x059b0519000f18abu64, 0xcd7e371350e42ff4u64, 0xfc4556dd32d77558u64, 0x12aa43d41606dda6u64], [0x838b32eea45e57deu64, 0x2d30546664f37269u64, 0xfa3402aac9a5b5e0u64, 0x1c6e4e20eb6f76bbu64]),
-    ([0x3f75b3cd7c0d49beu64, 0xad0c3cbd1d7d6f1bu64, 0x784d614e9e8f9dfcu64, 0x1d0a9b391eac98f3u64], [0x9775e7fee71f5d25u64, 0xbc2bf8ce4bbee422u64, 0x34e3b9a77aeecf04u64, 0x0483a97e6c3adb26u64]),
-    ([0x47411afec9ace792u64, 0x3496ff1dae45712du64, 0x833a47164baee5dau64, 0x0817f48547666af9u64], [0xd8e38f6da032a1d0u64, 0x8956338de3470400u64, 0x79e53e0b48bfdfd9u64, 0x274ac83b59a438e5u64]),
-    ([0xb2e2e9335618981du64, 0x5e3781af017e8b52u64, 0xe651e03c1e4b9541u64, 0x1268f8e9fb9c6ffeu64], [0xb42a11b4fe5d6fd7u64, 0x28b108d722c5d3bdu64, 0xe20957dfc36ae101u64, 0x0c4958fe3003f78bu64]),
-    ([0x63cdc5e0f6f41929u64, 0xb4b920b89bcd2149u64, 0xb9507932256e2975u64, 0x16a9e83e3d4c4df8u64], [0x1873568d1f9091c3u64, 0x3783f659c7b9c411u64, 0xd066fb16c811cbb6u64, 0x141bfe355b7a246au64]),
-    ([0x4cfdb250d7fa7462u64, 0x9b950f53a57d2bc2u64, 0x2a8309964e2e3718u64, 0x11a450effee66a8fu64], [0xf10011ae714498acu64, 0x24b9d87dc7c50dc7u64, 0x7a68ac95620869fcu64, 0x12ffa1620871aa54u64]),
-    ([0xce7a7c5ed6720174u64, 0x411cb362e30d4836u64, 0x34007ad679cbdee7u64, 0x1ebfa95e3608ce55u64], [0x4a0fd1bec195e417u64, 0x9afe2fec50c41dfbu64, 0x0c15b2eeda93ef5eu64, 0x206995621e14f9acu64]),
-    ([0xf0df325f66bf629eu64, 0x22ec0dfd6598197du64, 0x1bd1834d4683dbf5u64, 0x0068c24581aea59fu64], [0xd6e7574166163ddbu64, 0xed6120b0446af396u64, 0x19fe7470b18c6b34u64, 0x1cc5fedc17ac6b34u64]),
-    ([0xfff0c8bce36d21c7u64, 0x3bca641de5268d2bu64, 0x8405fa12c6ed6d92u64, 0x1e33b27306907c8bu64], [0x3ec5dc1fc3464293u64, 0xacdaa3a00f301372u64, 0xcf5ad0c6c14f32a1u64, 0x15c21d846904364eu64]),
-    ([0x13eb259ed7437590u64, 0xe633ee7757e53074u64, 0x645dff87d710338eu64, 0x0b4fe3d98a51c809u64], [0x93a869a082cd9067u64, 0x534f5b5819bff38du64, 0xd07c927208907937u64, 0x144bec380e869d76u64]),
-    ([0xf2385cb198c0d089u64, 0xf32ab35c6476fa2cu64, 0x37ecaae321f74fe9u64, 0x1761e51b94e55b60u64], [0x0a9e3c9e602f52d7u64, 0x9450c45f18d58b53u64, 0x924a29c845a87fbdu64, 0x2e4dd24298af821cu64]),
-    ([0x88d95f569fab8cd5u64, 0x9d52712b1313fad7u64, 0x9e56c75c40e8f7eau64, 0x25f748941d0d8c5eu64], [0x51286d542194a074u64, 0xf94aa66fc48bf99du64, 0xee8c1f29ba4e3a15u64, 0x1948fec2eaa6d7b1u64]),
-    ([0x727b6f1e2da40017u64, 0x01fe71946282ddadu64, 0x870737297d181964u64, 0x114f8022c80807fcu64], [0x991d650303df82f9u64, 0x04127949f147c327u64, 0x161cd42434ba085bu64, 0x04e6bcc713707584u64]),
-    ([0x74cee2f5a6a8b63du64, 0x0ab0f940bff38ac8u64, 0x5dcd43006e34a960u64, 0x05b39089377f5dd8u64], [0x5337dd27dd000e39u64, 0xf5e461ce717163deu64, 0xaa3bdf6b2790b2f0u64, 0x1877eb31f0f377ccu64]),
-    ([0xcfbe832174abe3efu64, 0xaad209bce2997d12u64, 0x598e43929bdeafe8u64, 0x16a0589f1ed9c5fbu64], [0xd22c8b97ba9c3ab8u64, 0x5b0a33eee598abb7u64, 0x9ce0b83441695deeu64, 0x1ccf08b2b41525aau64]),
-    ([0x5b2695f0d8110460u64, 0x23cebf6461753f62u64, 0x0bcd844bd782531au64, 0x0b8234803047e1a5u64], [0xc95f72a7530ad14au64, 0x577f241f7042ccfeu64, 0xc33eddff2e307719u64, 0x17c949ac638bf136u64]),
-    ([0xe48e97167429e16fu64, 0xde01f328e364b150u64, 0x3f8ada1114cf3b26u64, 0x20d728baf7c313afu64], [0x397c08d068069c30u64, 0xff8416447e0d1921u64, 0x388b045723312bd6u64, 0x0a38e490a92a28ebu64]),
-    ([0xd422d8922faf3e43u64, 0x3912cfd77209336fu64, 0x5f0d6e3ced3a75b3u64, 0x0ceed0382db025c8u64], [0x666011ffc6662ea0u64, 0x1316cca50c66b9f8u64, 0x4af1121adee6fae4u64, 0x0c18dd14418787a1u64]),
-    ([0xa0e8884c1b7c00e1u64, 0xd2a3986b84f124e1u64, 0x3f1dfa385f880680u64, 0x302c1bab6d9af4dau64], [0x228aaabb87296924u64, 0xaaebc44d5821e928u64, 0x2ce76912cadcfe48u64, 0x27a4c9e3d887688du64]),
-    ([0xf890a446a6aab027u64, 0x5c06cd8ee1078316u64, 0xa16bd3992cb8b21eu64, 0x09cb31a3312d1ff3u64], [0x11485180eb77e581u64, 0x69be5b85e68e4d5bu64, 0x6595a25a827a4825u64, 0x1c88049bc708c089u64]),
-    ([0x901cb209d84a001fu64, 0x760c86f887e789d7u64, 0x96af607e94bbec0bu64, 0x0cb777d3a6940af0u64], [0x56e6069714035b95u64, 0xb2ead66a0d24e2bau64, 0xc642101821af5857u64, 0x13d2c25f460d6715u64]),
-    ([0x543dfffe24c81c77u64, 0x5767af31c067b811u64, 0xb67fac363dd512aeu64, 0x0202a99f44d950c8u64], [0x52aeb053721f08c3u64, 0x0fc231c2838d3b75u64, 0xe1fb9fd396c6f582u64, 0x0013ac871d5ead97u64]),
-    ([0x42a5c5a63ee7223cu64, 0xe4db223478d715e8u64, 0x6684ecbe8d47e779u64, 0x03d813f22cd6e3e2u64], [0x7790b17dce42c8ceu64, 0xea8b4813f30de44eu64, 0x3cf6177c94c30778u64, 0x122c921481e3b0b8u64]),
-    ([0x09d7e51297485f6au64, 0x181ef09f9385823bu64, 0x9300e6e11d632ebdu64, 0x21e01047672c1126u64], [0xdd3a8dc42413a1f0u64, 0xd379b0c7a2bc63b8u64, 0x54013e212291a87bu64, 0x0bd19171a0ec8b51u64]),
-    ([0x9556d7a3ea121c22u64, 0x10feb4a8ff2ae2ecu64, 0x47c9b7f689539932u64, 0x01dcf41017fa5f27u64], [0xbf8c005022c20178u64, 0xf20e1eb89659b2d2u64, 0xcda2a4f845d1d7d8u64, 0x1ea78f91f57ba45du64]),
-    ([0x9f9dc690d62f2d2eu64, 0x8ebe9a6f4ef2b931u64, 0x63f814c694605dd2u64, 0x210103573af4ca01u64], [0xc3da5360b74e93b1u64, 0x3a61b5e1099d26a0u64, 0x247eb7d3d5860f51u64, 0x18e43459a3f4b9eau64]),
-    ([0x584ab6b41a355310u64, 0x72fce1212291ca74u64, 0x9783adee142c20a2u64, 0x1db3af4e9a3f212eu64], [0x1af9ab2960e54508u64, 0x6e8998d4e2dd4d31u64, 0xa5737e9d0d73af59u64, 0x2fbfa69827810b36u64]),
-    ([0x074c100427977772u64, 0x2c64e1f072cc8d6bu64, 0x4bfa7c30ab699880u64, 0x137b252dee08c2f9u64], [0xd351dc807159aa42u64, 0xd98a70e59048ec82u64, 0xeaf4b29baeb25a50u64, 0x09593dba27a06416u64]),
-    ([0xdb4f9de948b7c913u64, 0x52669d65213f1471u64, 0x1f8e56c14289a52fu64, 0x01155398ac5dfe4eu64], [0xf466c73543c1e353u64, 0xdb0cf344ad7058c5u64, 0xa160ae2e4dd90437u64, 0x17d80cb4ea4da63fu64]),
-    ([0xe1872c2442d207f2u64, 0xf2c2b94a5aa9bf01u64, 0x50cc92d52320d562u64, 0x0522cb084885127fu64], [0x2d868a7918e287e6u64, 0x602603f927bf5debu64, 0xae40af75be5ca24eu64, 0x16b8a2d708c9147fu64]),
-    ([0x8b8783adb8cbd723u64, 0xb39e9b17cb093d12u64, 0x8c53bfcb514d55a5u64, 0x2d8040c3a09c4969u64], [0xa5f086690d79809du64, 0x6426d0083578820au64, 0x537b3e38bef75e43u64, 0x08ca3202e4a57b9fu64]),
-    ([0x929e4ef7b36807cau64, 0xdcad0be984994223u64, 0xf4b198831d891809u64, 0x13d0516ce2adbe77u64], [0x52c8df2ec1e5a098u64, 0x49b3b1231ad6a56fu64, 0x7fd7dd94269aa99cu64, 0x17c85aaa7ca268e3u64]),
-    ([0x73af7ff4e4fc88fcu64, 0x165b61281e9054a1u64, 0x5d2a33029bc807acu64, 0x0dd30b9ad8c17355u64], [0x980bf1d3f895d5b7u64, 0xac8a55f728b83b70u64, 0xadb6a6643781b85cu64, 0x2e432914c8afc46bu64]),
-    ([0xbb4bf7f4f1d1417fu64, 0xbc8910f16a43efdeu64, 0xeef687bba134f19bu64, 0x1d55ce5f1efc4c88u64], [0x8c88dd48d2a37d67u64, 0xb7da4943ad2c5ff9u64, 0x173ba68910638dffu64, 0x2423cb1115a48d8du64]),
-    ([0xe0b5bbc4f2b81092u64, 0xcf270c981857c0d1u64, 0x3908fd459f37ced0u64, 0x2d1ac42827fb7d9au64], [0xa9385f44de1f7f2bu64, 0x31bb733dbd73f91cu64, 0x7d57e9ec4fd6a493u64, 0x209f551fd57d0cbfu64]),
-    ([0x1edaccffa9168f83u64, 0x3c7ed94a1150c267u64, 0xd2f260fa0ad69caau64, 0x0974bbde667abca2u64], [0xdc8852ef204aedf8u64, 0x25dee30e8376d10fu64, 0x70cde602c44df674u64, 0x25e7863b9d100e89u64]),
-    ([0x513879249d3f7f4bu64, 0x00f441309e162a23u64, 0x2c1ee19ea375b837u64, 0x02dfe3a8d7e21952u64], [0x5ea098d6392a47ffu64, 0x9c414f62f3041dbbu64, 0x55dbbd11aabdcf23u64, 0x03c71419349324fdu64]),
-    ([0x87eb24502c04eeffu64, 0xf4a76cbedf4ffbadu64, 0x714a8bf2c30b9f29u64, 0x28803463ae70dd27u64], [0x26ae344fbdc828d0u64, 0x0baedfc9074a339cu64, 0xa79a3cb238cff212u64, 0x1853156d8863146bu64]),
-    ([0x6557b431faf53c64u64, 0x75d96eaf4d8f3308u64, 0x87b2116bbce79565u64, 0x1ebd7f4ebb7d760au64], [0xc5a44af5abee3359u64, 0xfda624aac54a2e07u64, 0x138a711c8b736f12u64, 0x29080d955831e361u64]),
-    ([0xde838bec351680b6u64, 0x0e59546410c3a69bu64, 0x3783f26a9ac09693u64, 0x14e59c5931bad0d1u64], [0xee3c19d842c9add0u64, 0x01edbc405012a132u64, 0x2a1c68160dfda176u64, 0x03c7f5323a7967fbu64]),
-    ([0x9bed88a5697d6ff9u64, 0x5ae655619b09802fu64, 0xc2b44d92c1fca76bu64, 0x0c66edff6f639ee8u64], [0x6b846953abad07edu64, 0xf9131a78468b02ceu64, 0xb497fced3d8a796du64, 0x20e86e9a661b5c52u64]),
-    ([0x9e2d199e979f2d3bu64, 0x35cfbd2b0ddbc1ecu64, 0xd60110f92faf46d7u64, 0x29bf130e57b03429u64], [0xdeb3fd667aa908d6u64, 0xccd73f9faa3bf01eu64, 0x1632a37616cd69d7u64, 0x2dd19823529b3ac0u64]),
-    ([0x4a7d5db6bbf339a0u64, 0x60b363dd2f407234u64, 0xca7095c29694e079u64, 0x1b2e62dd0280915cu64], [0x8b27b52918e75ae6u64, 0xc3a1a910344cb7e2u64, 0x0c814bcc1e8bb2c1u64, 0x0bdb52194f659a65u64]),
-    ([0x2cfb000fc8f202c6u64, 0xac9bb38b75cb2a15u64, 0x3f477c4668305c69u64, 0x2cb460cc08cc3e80u64], [0x69a2f3da3c612d3du64, 0x68bd174ebb7bfa10u64, 0x60334b3c3b1bbba4u64, 0x119e83c58b9211feu64]),
-    ([0xb28c55fbe5dfc54eu64, 0xc5385c16288f87d0u64, 0x3fc34ea56f2a1a5du64, 0x001697b567b11586u64], [0x8b74fd0c5b4ea80cu64, 0xbb9932733c049d47u64, 0xb2dc69dab47d0b87u64, 0x1be47e2bdb3fa2a5u64]),
-    ([0x851ca3211f7ce0a4u64, 0x653ef68956c2cf5au64, 0x1bbd93e57db4b202u64, 0x13f071a8d2aa6902u64], [0xc34583bf74c12b33u64, 0x98507e6b701b7defu64, 0xd1f2227350e7d8b9u64, 0x0dab68cd53fefe96u64]),
-    ([0x6e57c953a337654cu64, 0x074743154e03a1f9u64, 0x23df018c84fc874fu64, 0x2f0215559e251f14u64], [0xeb195a4c3087f09au64, 0xb4da97ec016b19a2u64, 0x3aab9cbfca43d239u64, 0x1da88a17bbe2733au64]),
-    ([0x209b9e06131174c4u64, 0xd833769ec9a3829fu64, 0xc6cfd7d222e2ab60u64, 0x0cef0dc825fd13f6u64], [0x9de200d8da1f5edbu64, 0xc0539bebd4368f0bu64, 0x360d220accdb43e9u64, 0x11c03e9b945204e2u64]),
-    ([0x82f9d419d44328f3u64, 0xf44d80069acf8f39u64, 0x7b35780c0bf517bdu64, 0x300efbf6af024aaeu64], [0x10dd3431e11dbc84u64, 0xa44d3cc0fc01e38du64, 0x6c6dcd84a6ae1920u64, 0x19443bc10b1240d8u64]),
-    ([0x469aa381b61ff51cu64, 0xcc8d310035d563a3u64, 0x13527b2dda377e60u64, 0x0e914eca172e02edu64], [0xd754239fbcba2c6bu64, 0x6dba20074b74c5a5u64, 0x8f0c8261b354a005u64, 0x2d7bd0d9ec9223b6u64]),
-    ([0xf99ded319d960517u64, 0xa2e835e6b462f99fu64, 0x14bbc4c78bf01202u64, 0x302c49e402dd9be8u64], [0x542e29a64d975594u64, 0x9d6c6c0e84fc4b4fu64, 0x1c142d056c730e37u64, 0x2adab25908d1a92fu64]),
-    ([0x196a07639970c405u64, 0xbc0db78b99928dfeu64, 0xa94cd3cd85d87a5du64, 0x071ddd4cd7a8fd3du64], [0xb05a9322401bf2d5u64, 0xb770a3fcc8da3e3fu64, 0x1625c7981e822913u64, 0x007bc4090c420e00u64]),
-    ([0xd95a960a681452e5u64, 0xbbe3af5efdc9ff29u64, 0x88fa132e867b2f66u64, 0x0e460f60430855c0u64], [0x1fc77ac69473765fu64, 0xc74aed97d1efa79cu64, 0xba3f358c656dfbebu64, 0x2a7e5191966e7c0bu64]),
-    ([0x7be5cae10083bdefu64, 0x46fed9f250711315u64, 0x19f127219ea02e07u64, 0x149bea92e6a91facu64], [0x372a41093e45403fu64, 0xa7ba7c85dcac428fu64, 0x30d16886ad8b0f38u64, 0x19753d8676e6d535u64]),
-    ([0xeb43fefb61c0e052u64, 0x999a9c2c54098e7bu64, 0xbf625ccfb9e03581u64, 0x281d776d12a881c5u64], [0x2468fdfa6d06a747u64, 0x63e4cce88e7d523fu64, 0x96beced4d847b9dfu64, 0x18dcd1d625e360c5u64]),
-    ([0x0eeed89e161a172fu64, 0x08c57a95b2020455u64, 0xe90cbc49b1e9beb1u64, 0x09d4c000e691f218u64], [0xd46962831d2bc8b0u64, 0x1b071aeebd432956u64, 0x35fa37b1dcc6a6bau64, 0x1cea8642dac58f02u64]),
-    ([0xafc4fe589c84ca5bu64, 0x7018b5dc43eee9a3u64, 0xb5dfb668fe32a2eeu64, 0x13e20e571da91151u64], [0x748a9d9d64d8676fu64, 0x46a311136243fd3eu64, 0x3a1197d07d87c1a5u64, 0x093d8d594c8ca9fau64]),
-    ([0x0238779c4bb09924u64, 0xb0332d6e2d175941u64, 0x5872789cf623729eu64, 0x1dde9a033e6274c4u64], [0xc9637ac763237803u64, 0x5700952c28f8a39bu64, 0x65de87adf9e3c946u64, 0x041f6530a8877eb0u64]),
-    ([0x4ff8756fce463fd1u64, 0x38114c7ee288c616u64, 0x838e6697f315fc45u64, 0x1cb471255d2fa7cdu64], [0xa8910c7f8027c98bu64, 0x91c7cd5cb871fa86u64, 0x9958ff4f3f1fd529u64, 0x1442983737fbbf9au64]),
-    ([0x062041b6d37b704bu64, 0x0aebf78612ea5dc4u64, 0x48253cb74cc58e52u64, 0x0faa06e334756e1du64], [0x05d0a5bc0d371958u64, 0x04dcda57cd59da52u64, 0xca324f466685eb41u64, 0x053ff4ba22556e88u64]),
-    ([0xb339998ca53c807au64, 0x9d5d14deaa924974u64, 0xbed2e7b7620bbaa5u64, 0x0cca9183dda62bb2u64], [0x522f857fb0cfe62au64, 0x0d3079d5250c9c14u64, 0x8e5529a8091110cbu64, 0x1d912b725b6fe268u64]),
-    ([0xfcc3eade81e17ec9u64, 0x0309c4de7d16de59u64, 0x856e616039614235u64, 0x27e782b79e664564u64], [0xb9462e728fda8fe3u64, 0xe6d90f934e0e440au64, 0x5a9039dc70355cccu64, 0x135cefa20f295adau64]),
-    ([0xb7485d86fdef6864u64, 0x38a4b73545291543u64, 0xad6551cea25b7f75u64, 0x093fdd3cdd71e2b0u64], [0x32f82e20471b78cbu64, 0xde833078ccb4b588u64, 0x665353225bfe2fb6u64, 0x1c7a74d8585d8e47u64]),
-    ([0x6d92c79750988e44u64, 0x43c4da962b742ce8u64, 0x395ac7c10aaf902bu64, 0x2a23b06a7d27aac1u64], [0xec56c13a94cd1617u64, 0x4dab4d589df1ede6u64, 0x34c92f1472271b51u64, 0x0f31419ff915d90cu64]),
-    ([0xb25990fd49654086u64, 0xb7b4ce1495744662u64, 0xe41c5e1c28929b85u64, 0x0b9bbe1167677460u64], [0xfb57d8d58714d7c8u64, 0xe829f2606899f69du64, 0xbdd9c5a7d1555f51u64, 0x03448e7675ef6358u64]),
-    ([0x8f9bb7e73d2fe10cu64, 0x3d8d7e49e7b0cfa1u64, 0x34c6dc86a57197e5u64, 0x1a68fdb82c1a25e2u64], [0x3c88bdcff413b2c3u64, 0x199e95c47c050ae7u64, 0x91f6df3fa96d74d2u64, 0x20d2ce2e5153bfd4u64]),
-    ([0x35f4a142f0376843u64, 0xd59b9d4561525634u64, 0x20120e3bf39dd8f1u64, 0x088c5cf36bd2c1ffu64], [0xad33993c9032d566u64, 0xff100ef463439b68u64, 0xc01bf5ce61a33d5cu64, 0x06546175eb87f0fbu64]),
-    ([0xf8d7bc6301fa8289u64, 0xabec584847b34b09u64, 0xf54fc3a3a11267e9u64, 0x1ab364ff944d2586u64], [0xaf65a3b1550e94fbu64, 0xd8ecc71574ba6db2u64, 0xff0d8fdb8fb77c4bu64, 0x1d040761c5f346a6u64]),
-    ([0xb81dfe484fbf0f91u64, 0x9b89e61232e890c9u64, 0x5fd9267dec3b6527u64, 0x03d70b0fc355f2b1u64], [0x35d16bffe1f67fa1u64, 0x4668132f209f8130u64, 0x6cdae6c2e74c7c36u64, 0x2c4b3bde10fed1f3u64]),
-    ([0x271f57c39fec334eu64, 0x1d6f322156c10058u64, 0x286d42508160cf04u64, 0x0dcaf6e27ebf9ac7u64], [0xc2e4aaf2368023c4u64, 0x0fe231622c02ec7fu64, 0x70410f916fdbf77bu64, 0x1886385f55324e41u64]),
-    ([0xe0b153958d8e9f88u64, 0x0b2a083940f7f737u64, 0x230e02495b0a03ecu64, 0x2cef0aaa48aef1d2u64], [0x7ed38accfe45893bu64, 0x4d1c9d58509ee938u64, 0xdaac6726e80fed43u64, 0x2db28de5d1de1652u64]),
-    ([0x1e212d684b2bac87u64, 0x83738b986d1fec74u64, 0xfafa3ca2b2df665cu64, 0x0f7f4a6e3839059au64], [0x6a44df865cf3266fu64, 0x19eadc12926c391au64, 0x30e3f742e1d24c07u64, 0x1aaa50e1d021516au64]),
-    ([0xa0cafab44f10f86du64, 0x69334cf00bf91382u64, 0x49bc32e654c541c1u64, 0x03bce47851af8230u64], [0x406c1022ecaac511u64, 0xbbe9876b6d5c390du64, 0x537b935ff51b16d5u64, 0x0ccceb2c29db818bu64]),
-    ([0x591b7b37cf814f01u64, 0x7ffc59887bc5cea7u64, 0x99e7d2e593f7e7f1u64, 0x27262861c29ab9cfu64], [0x6023e9fd07a53be0u64, 0x3e8112ed13bdde59u64, 0x58aebe82e1621fa9u64, 0x01cba2054c980009u64]),
-    ([0x6aea4d7d59380471u64, 0x786446fc57636911u64, 0x1911898a32ee24beu64, 0x0a1fb334c2ac9edfu64], [0x856e1a32a3463a77u64, 0x7e6aee4aa808d1d5u64, 0x8d4a7e0a8da5f59du64, 0x08def9500c99ab80u64]),
-    ([0x9219bec8116647cbu64, 0x3aedc72b62e2344eu64, 0x4a4ab3cccfd3c8e0u64, 0x00eaac9503306a5au64], [0xd8be8bfdf510ec7eu64, 0x8b5366d1a4a8834fu64, 0x43491c31ecbb29dcu64, 0x2080d04c0c32402cu64]),
-    ([0xab1b62db01a21ecdu64, 0x649614ae86b152ffu64, 0x77c472b6cc0119c7u64, 0x11ea39db114d0d3bu64], [0xca41d5bb1a4095e4u64, 0xf0e232cdac18d1b1u64, 0xf2269b93636984cbu64, 0x2e93bc5443ee7e99u64]),
-    ([0xd46a920a21ddd8e3u64, 0xc00f6a160eceed49u64, 0xf52ab90592c410b7u64, 0x127a7b6b0db441aeu64], [0x4ea12c689e5db38au64, 0xc8e17f3a68692dd1u64, 0xd06a2f56fde158dfu64, 0x05c534890c18ce67u64]),
-    ([0xcb138b8879c29672u64, 0x5030515c5c875ca2u64, 0xb4f3694119d8b1e6u64, 0x0a4cd1802e9062efu64], [0xd87e9288efa57d66u64, 0x482782e441c1a5f6u64, 0xdf74592f58b60914u64, 0x292fba2a22c897c5u64]),
-    ([0xe60aabd775cd6696u64, 0x681ad85a58ee4dafu64, 0x2fc7f60f470eea34u64, 0x0ab8a8abca51f4cdu64], [0x43cec30ee458a069u64, 0x99463fa8dedb7661u64, 0xc17befc9b0135344u64, 0x0bb8bf9f451b02f4u64]),
-    ([0x63d43f33440ca2deu64, 0xbabd1b8af34c5378u64, 0x7bc925abb83cb627u64, 0x179d7969db7f6bf1u64], [0xe8c3130f3bd29e3au64, 0xdae46166d3d26e92u64, 0x39787850cc78ff84u64, 0x050ddf862f6012ffu64]),
-    ([0x4db3dbdf91162fe9u64, 0x9bd7c8162f36bb5eu64, 0xe6b1bceba34ab8a5u64, 0x17ca7ced7beb8a1eu64], [0xfa9a79249e992089u64, 0x76ee2bbdf08916feu64, 0x9db4768dab9ee9a8u64, 0x1c97dfc450cdd33fu64]),
-    ([0x267696e2c473f802u64, 0xd59f5565023c1adbu64, 0x3007d784238b1e9eu64, 0x10e4bd37bf8ab5d2u64], [0xe60f59f263950881u64, 0xbc5cd7a95059945eu64, 0x7a8930a2055193deu64, 0x1bdc98eae5979ea1u64]),
-    ([0x8c5d8e91d3d15994u64, 0x903e431fa7eaeff9u64, 0x3301f4bdae0023ceu64, 0x1fb5729bc099d60cu64], [0xa129c7e6c3885954u64, 0x45f245d8af4b4459u64, 0xbc4e0df5c4a43d11u64, 0x1ea3a5186918334au64]),
-    ([0xf12ad8f7fdc4acb1u64, 0x836ed90b66e685f7u64, 0xa338d21da0eaddd3u64, 0x1f66a19e399ed2b3u64], [0x87467b0f9def0cb9u64, 0x4d759d47cf744387u64, 0xe1b663f9e69105ecu64, 0x13e63bc85ceedabcu64]),
-    ([0x22eaecdb925fc619u64, 0xfb044922cbf011c0u64, 0x721ae293897a23ccu64, 0x13c7aa1aa3cdad96u64], [0x9f93574bd5dcf509u64, 0x1b756067705ff3b2u64, 0x0c552b73d8aa9680u64, 0x25a0a0fb036b5cb9u64]),
-    ([0x66025d3429bbd82au64, 0x7de451fdd51adad3u64, 0x75d441572391cd58u64, 0x1a228e1f67c7e8f7u64], [0x2e072c0c9d6e91b1u64, 0x97e7220e1c90074eu64, 0xfe7c574396329171u64, 0x17bb8164435c98d4u64]),
-    ([0x36c438507f9ecf1fu64, 0x256f839311ad2ec5u64, 0x164260e67c7b6faeu64, 0x22879f7bf3c7731cu64], [0xde3ba277150c01d4u64, 0x96c438be3f6ba43bu64, 0x9a6fbfc196e25837u64, 0x1c57d8a57b93f255u64]),
-    ([0x43d26430f1bf85efu64, 0x67bfb513b8f4e22bu64, 0x4f7abccc1a3c03c1u64, 0x03c5411540c21528u64], [0x58c88735f82f535cu64, 0x5274caf34e747ffau64, 0x906c03e8f5a9d401u64, 0x1b7041a029152fc1u64]),
-    ([0xddea0d14d88f3773u64, 0xed12681dfba9d1b1u64, 0x0c28069c5263566eu64, 0x082b04e1ca4742d5u64], [0xd6810e5ae1e9036bu64, 0x6f7ece4a03935172u64, 0x5cab6608cbdc619du64, 0x01975244805e3657u64]),
-    ([0xdab5a056ecd60a2bu64, 0x82e6ef81f2a6ec06u64, 0x53db2add489ea276u64, 0x10187b4b6d0ea556u64], [0x41539c5d0d31892cu64, 0x1036f14eff212f6du64, 0x72b3a0553a703849u64, 0x2fa1be1250894e25u64]),
-    ([0xe8803166bc6e6720u64, 0x3ddd8e1e478561d0u64, 0x93cfc85e3918d74du64, 0x0991d367d559defau64], [0x350c69558d5d7696u64, 0x5f15542105344091u64, 0xfe05a2b019ee33fbu64, 0x0dab28e622855ae6u64]),
-    ([0xf0ef89844094c1b3u64, 0xdc0c6e4088fcafc8u64, 0x63e7cfe09c845bb1u64, 0x268b565136980a76u64], [0xf0ec50b9fef8f5adu64, 0xff811aa3a8b62bd1u64, 0xdc96d2d132c17acfu64, 0x16a8c1e9d022f944u64]),
-    ([0x793f13aa3d1a3390u64, 0x3af131ba0b763042u64, 0x49783ca10754d952u64, 0x14240bab02f51953u64], [0xa0b87aafe88f7bf2u64, 0x266ea25f12b4b5e2u64, 0x2ecf020174fd6ffau64, 0x0c16219f5d38fb46u64]),
-    ([0x43e1f593f0000000u64, 0x2833e84879b97091u64, 0xb85045b68181585du64, 0x30644e72e131a029u64], [0x1f402f562d1eaf70u64, 0xa8c83e711add4a30u64, 0x4e34c50ddef9b24du64, 0x0ad8beb615f8fa98u64]),
-    ([0x31e85fb233d1a6efu64, 0xee796e87d0e698e6u64, 0xcc91898ad0f9e679u64, 0x296734b17119fc08u64], [0xd52ace57bf43ffd1u64, 0x4414422f6904b350u64, 0xabb91a7a6320b141u64, 0x04f0114b4d4d4f33u64]),
-    ([0xbaa775f2a8fea833u64, 0x4003be067d41431bu64, 0xb89019a7a3e651e7u64, 0x21466153ea4a70cdu64], [0x40bbd1644ced2865u64, 0x2aad2b6e86e1e680u64, 0x52116089182c8b4cu64, 0x1cb31f317f1caeddu64]),
-    ([0x39e020e39a1c39adu64, 0x289ec9bc830c975bu64, 0x26f8e9a191f200d0u64, 0x0beb5e960ef05f74u64], [0x7f28ede393ca1d2au64, 0x94abae592542b55eu64, 0xc5b103324f04db20u64, 0x196f1b5f029ce5f4u64]),
-    ([0x7b4e94027b5a858bu64, 0xc6baa5f412687e60u64, 0xc7ca256be24a58acu64, 0x28b389113b1d1c24u64], [0xafbb1d09bddfe1f8u64, 0x9289f7dd45b96629u64, 0x3d1f3addcc8a416fu64, 0x122db950af51d5c8u64]),
-    ([0xedba92865506e6b7u64, 0xf77806766c726200u64, 0x90fd0c627cd70f2bu64, 0x272573c088d418d4u64], [0x8940b7ad8d83a372u64, 0xa916e373e1ba5c35u64, 0x9d91225245e84390u64, 0x0300fcd945a9ed8fu64]),
-    ([0xa633d4cb4b490592u64, 0x939d3db6e60ff73bu64, 0xe83a3df1b756ed83u64, 0x1e03a8e524e06bf8u64], [0x0c217b6190a457dau64, 0x0f4f72ef2eb09989u64, 0x0a6ed7a080a5f29bu64, 0x1522799241d8b9e3u64]),
-    ([0x06c5bd52890a8b6cu64, 0x3f8ba4c9d71a249fu64, 0xb328811ca5cd294au64, 0x20dea1744e4ad8e9u64], [0x941f36fa3224e67cu64, 0x4599fd36ab75dc2bu64, 0x36a2ab1f6451a11eu64, 0x0a1ce67b08ef3c5fu64]),
-    ([0xa6d3ae873562e7f0u64, 0x0a6892e3c2c72417u64, 0xd8f364199928147du64, 0x197d107534009697u64], [0xf2203262ed893a84u64, 0x7acf6ac7794012b0u64, 0x8b88b744bda64b93u64, 0x0ef7ab3a13a20890u64]),
-    ([0x71641d57670afd30u64, 0x8e0297c1b1771031u64, 0x423da30a25e83c86u64, 0x1fbfe4a677f32a14u64], [0x73749d71e9441483u64, 0xe736f34679ab1c99u64, 0x8e503f853a937f8du64, 0x28bffd2b02a1e94fu64]),
-    ([0xedecae9bccffb440u64, 0x4b8708a9b61a3ed8u64, 0xb42684bc94625799u64, 0x0558c1b32b0a3cb3u64], [0x40a2db9477e52589u64, 0x7a2f3e7604e8d55bu64, 0x56493c2bccce229du64, 0x0a02466af02148b1u64]),
-    ([0xcfba93302b96472cu64, 0x594644fd3d6ffae4u64, 0x8fe4d28a6f33d1e1u64, 0x21212a9bd86d5275u64], [0x221e0ead585fc840u64, 0xeda238c56e66db57u64, 0xf15206cadf2582efu64, 0x1496b04da164bba8u64]),
-    ([0xdd69a7d3762d574du64, 0x0cabf163be600727u64, 0x875da00dbcbd8430u64, 0x289781269ee85498u64], [0x6383bff79cdb94ebu64, 0x34caeda13d01635fu64, 0x17b311675b244c10u64, 0x27c708c7f5a4e7e5u64]),
-    ([0x8dbb770b283d18adu64, 0xe00c00c208c52560u64, 0x03f959c232271bedu64, 0x0bcea208031fc005u64], [0x982d341f7c538c16u64, 0xf8766390ad891563u64, 0x186e877c631c1fc3u64, 0x104da30bee2ba026u64]),
-    ([0x294a84802868b8bcu64, 0x1aaab66fe034e201u64, 0x725c635bf897a910u64, 0x301ecb6f813d5f31u64], [0x3179492ee6441b4bu64, 0x5fc39a63240e9ca0u64, 0x9fb0de0876c8fa2bu64, 0x20dfe02805cbed5au64]),
-    ([0xef49c4688e049f3eu64, 0x185090a525fd38fbu64, 0x4914398a44c143c4u64, 0x0d07dddb4de9fe55u64], [0xb33c7505dffb2a1fu64, 0x31752d059a64d0f2u64, 0x3acfbc0738aac1a8u64, 0x0863774295f626feu64]),
-    ([0x684cfa8ede70fdc7u64, 0xe18d1d2452d64bd2u64, 0xf6036a4037c3aa6au64, 0x1c4c3a258629905eu64], [0xd4a221f80e2749b3u64, 0x8d658f3d42137facu64, 0xb2bf0d4c4350f785u64, 0x17d810b52898dad4u64]),
-    ([0x6ddd7fd9bd280a34u64, 0x5bb73f42f510afecu64, 0xedd82bfb32a59461u64, 0x020874c590d6ef16u64], [0x224f596b6d909249u64, 0xa62f3497bbf9b819u64, 0x359233fd077ec738u64, 0x05a21bf97d1c6ed8u64]),
-    ([0xd095cc2305541dc4u64, 0xf81757c8ddc57aebu64, 0x53c0eb6072d3c537u64, 0x0cf20dab60fba926u64], [0x6d78a8bc906573ebu64, 0x240904745da8edb6u64, 0x8959f1b98ae930deu64, 0x0adb3eabf94ad463u64]),
-    ([0x47b00f35846f1630u64, 0xa7209c890fde0532u64, 0x0ae5ffcc4af0cf1au64, 0x1bf6299a49c79747u64], [0x8f29a5b04869bb0bu64, 0xa0b61df4a90994f3u64, 0x6a2b1ee96e44ea94u64, 0x19a837106852aec2u64]),
-    ([0xb9130413db54ef7au64, 0x044907923d42870bu64, 0x1071162b46a7a3b6u64, 0x14a1b8f0f4f023dcu64], [0x75a1c46218b21e12u64, 0x727d94f2bc09c46bu64, 0x6d0c7e7efd7eaf39u64, 0x17fc0b75f0aa61fcu64]),
-    ([0x2b4155b9b65a403bu64, 0x2ea67f3785b30ff8u64, 0x09eb40dda8056b4eu64, 0x050dd17e202f6defu64], [0x522b8151fc940956u64, 0x578a85313a5226fbu64, 0xfcf0b5f64ca2565du64, 0x19a4a8fff6afe515u64]),
-    ([0x226cc612946fc31du64, 0xb897a60fa44f6f2cu64, 0xb50e38c17c16b1dfu64, 0x02f3ec1ca41c8685u64], [0x4634671082704d4bu64, 0x9485a6905f9f8563u64, 0xae7410dbd03c2e67u64, 0x03612d4369a99883u64]),
-    ([0xd25539d1c4ada5e9u64, 0x8158beeb4e2cd519u64, 0x92019e543e079c81u64, 0x0d601af2dd4b926bu64], [0x6c96737646a097e1u64, 0xe9c896ab21158a1du64, 0x5db4f44bd23ff0c4u64, 0x1c063aa0861932b7u64]),
-    ([0x81ca3dc362067c2bu64, 0x90079b90157b9c7eu64, 0x809c358cf98084a1u64, 0x272be0aacc4dd489u64], [0x09435146e4ac923bu64, 0x5e47ee652d565ed0u64, 0x789be11d151ce187u64, 0x227528ac0ba7d952u64]),
-    ([0xec6e26170b6dca10u64, 0xaa2017233d907aa6u64, 0xd34bdad618915ed4u64, 0x1f1ce5956b097301u64], [0xeb936283f81900f0u64, 0xf4a66d03b80ee4c7u64, 0x7566e834a240fd0eu64, 0x2b9b9455a6bf9129u64]),
-    ([0x421b27533b1ecfebu64, 0x03675e7bd259d581u64, 0xb0d01493a2e2dab6u64, 0x21d0697cfe0eaf94u64], [0x5ce0a52728eece09u64, 0xe3065748a251e171u64, 0x0c0c702c0e71a745u64, 0x243e1a93fd462e67u64]),
-    ([0xf72f175f55c093acu64, 0x7c747e40dda5380eu64, 0x428045c74a2ad61bu64, 0x29b66a3219c40770u64], [0xcdcf4451f7966c64u64, 0xd9ecac93ea524e03u64, 0x9be28c2f629047d1u64, 0x2edc599af1309b11u64]),
-    ([0x9d42fc5b3803217au64, 0x4d9dcd8e4df8bb34u64, 0x807a3c5c2b157447u64, 0x096ea220bf463a6cu64], [0x4cdc5f21cb3303d4u64, 0xdd4c97824618ab33u64, 0x0d0134f8e2fafaaau64, 0x0a74977cfb4a98abu64]),
-    ([0x3de40b755a0df7d2u64, 0xb003c4a4dcbb678bu64, 0x9c9600425b2f18adu64, 0x1372ab85cb5b0cc5u64], [0x7bdc36b3ae1c28a6u64, 0xc1264d9a5dce287au64, 0x4b425458eaa2883fu64, 0x2bd8c615ea48adb9u64]),
-    ([0x824b068151a9d8adu64, 0xdf3bf179c9c9dd98u64, 0xf980036a98b15466u64, 0x2adceec0c333239du64], [0x7479073c4a884f7du64, 0x63c79a9e4f63e783u64, 0xcc5ef44a41ed4961u64, 0x1ed8c4dcfc9c359cu64]),
-    ([0xee8e8d45f3dc81beu64, 0x2fd2c53979d1c282u64, 0x97a4a2d1c053af6eu64, 0x1123234b70bbfd94u64], [0x3035bdf48512b673u64, 0x82d557361d642c44u64, 0xad4cb27c7bb943a9u64, 0x0cc1aee5defe8971u64]),
-    ([0x04f4a940b44aed31u64, 0x8c1b132cf62a8574u64, 0x21d825a939c0a5b3u64, 0x269f1bacb0c60c57u64], [0xb548e6158efa9b44u64, 0x0cba053ee13c2035u64, 0x5db6dde47d440b9cu64, 0x2dcc7556a073ac06u64]),
-    ([0xa75b58c3191e796fu64, 0x2661a793ca970895u64, 0x5720f25b52919611u64, 0x198d4fed91d5211du64], [0x300b4e99cfe6f8cfu64, 0x61e9dd55c1787f51u64, 0x41a84e7d14d031dbu64, 0x15f59fb3428f30cdu64]),
-    ([0x30c4b7b6ee491390u64, 0x38c061418134bcd4u64, 0x07aa1ed28e9e67b4u64, 0x28b6bf3b3aa1ec62u64], [0x90d34668e3ae2e50u64, 0xdddecf6bdf1d772du64, 0x0e2c35979cd081e0u64, 0x127171bb70264bb6u64]),
-    ([0xc19a980e5df28c5bu64, 0x85c296eebfd621c3u64, 0x8d215bb6432e1e67u64, 0x11ad268941011bd2u64], [0x4a1d035bc6f93478u64, 0x1c3a085e87734099u64, 0x84da453a1c553ec9u64, 0x29cf190752dbaa9cu64]),
-    ([0x444dfdb332987ba2u64, 0x5c8e61fb9c196d0eu64, 0x6085b31c9de41ae2u64, 0x0f1f3871a4dfd667u64], [0x5494f7890e433592u64, 0x4331bc84045ceb6eu64, 0x8233767509de94f2u64, 0x20d0ef198874b1eau64]),
-    ([0xb1a799495333ae5au64, 0xdeaa79738474ba74u64, 0xd06b59678f767f61u64, 0x219a0b40d70efb6bu64], [0x993409be840a7279u64, 0x054e2e1be5677673u64, 0xd82acb514096effdu64, 0x1463b49efcc07bf3u64]),
-    ([0x24a8c6ca4c3da65au64, 0xc91c170054184436u64, 0x8bc5fe5e4df05886u64, 0x09b2a83ac16e8e7du64], [0xe5a17ca71b838c15u64, 0x48c9658a2365e370u64, 0xec9af193b98a0d01u64, 0x17efa86a4f56c6ebu64]),
-    ([0x6466be7e4c539da4u64, 0xa12fd9d076e644d3u64, 0xebcf4269f1d754cfu64, 0x1ef8e1c2ef9082d7u64], [0x111efc3e84352518u64, 0xe6594a4b89a3a132u64, 0xbf60f41ec13771dcu64, 0x0ae770c8771bb6d0u64]),
-    ([0xd5f5c19c5964be7du64, 0x86cb697b444b543au64, 0xd8c4faddf5b2ad0fu64, 0x17761e5bdbe43a57u64], [0x39099dc46916e2cau64, 0xf30dd2a28ce1357du64, 0xc7ec6592ddbfcb12u64, 0x0cc53f4c090a1722u64]),
-    ([0x4e5a5aec404e8576u64, 0x90cf3ff978bc6bbdu64, 0x24929edfc8e6518cu64, 0x03b3ab220f81c545u64], [0x577a2c9e91560782u64, 0xa41b034ba09cce7au64, 0x1d71df292b701e4au64, 0x17a9c055d312c5cdu64]),
-    ([0x5c91fd622219f0ceu64, 0xf4e9f94581002dc4u64, 0xf663c03da528ef96u64, 0x0df47474d7fbfe96u64], [0xe51d0d6c4c1cb5bfu64, 0xa2873a5e25cea01cu64, 0x6052a89759e3ae36u64, 0x015317b28549a25cu64]),
-    ([0x5ae89321c0dd125du64, 0xcd6254100ae685f3u64, 0x1d10071b5b9df6b4u64, 0x25cc5f1f69f6a9fcu64], [0x729376a1bc00e44cu64, 0x1e13044de5625252u64, 0x5cf7c41cdf8ebf3au64, 0x17898d2b53cab2d8u64]),
-    ([0xf6232f1f8656ebaau64, 0x8a93e36dd9d4cc45u64, 0xaa5dc907f359d931u64, 0x0b3fc27434e7cf78u64], [0xdde5bf26f858c01cu64, 0x796d23762c9d161cu64, 0xbc36b59663165e31u64, 0x16cad99e786d4ee3u64]),
-    ([0xc1ef73ad3f9d74cau64, 0x672375d150a253ccu64, 0xe60200dc66db9d42u64, 0x2d60b3cbb60b541fu64], [0x419948e06933c709u64, 0x82f141b3a52d321du64, 0x891ef1838f4829f3u64, 0x097b857ecb2c6fedu64]),
-    ([0x53a2841040e8449bu64, 0x611b3864f380bf0fu64, 0x2c5da12f680286c1u64, 0x11f95c9ee6f6e72fu64], [0xf21e846a8f4298e3u64, 0xf5eb177777bfb62au64, 0xa50bb2003b694022u64, 0x14c790c6c529018cu64]),
-    ([0x98dfe1ba14a66f80u64, 0xc090feffdfbaa143u64, 0xfb170ee2553086bcu64, 0x20c04a3aa5986189u64], [0x6f21ae5777614ff9u64, 0x2701d631f27b4db2u64, 0xe727447d11dc9907u64, 0x1c0e2447a7e9a2c4u64]),
-    ([0xa771ec1ea70f079bu64, 0xea344fbae45ce443u64, 0x620fa2a5df9eeb5cu64, 0x18c482c8ee14065eu64], [0xb0074111e9918c5eu64, 0x78407a33a04001bdu64, 0x22d5dd43e34d6d32u64, 0x21626a7c101b8652u64]),
-    ([0xd50e98efc334f31au64, 0x8340aaab3c1458c7u64, 0x156780e10e7470f5u64, 0x1529dcd46542d9a7u64], [0xbbcc7497ca36bf17u64, 0x1cb9d4c9e8cf5d00u64, 0x10a1865b84412f3cu64, 0x28c25338d68c578cu64]),
-    ([0xf48f6542b44182acu64, 0x7925427dfcf07e67u64, 0x883df56ee0b8a5fdu64, 0x045f2419c8559ab9u64], [0x8790f08018165e06u64, 0x9d4d2916cbfcb9e3u64, 0xee4578189c0ee6f6u64, 0x28d0f09ba7a04d51u64]),
-    ([0xc49befb63276ada7u64, 0xc7258e88071abc46u64, 0x8011c68adc8c2b86u64, 0x2d23aaf673389cecu64], [0x79c45f8d5fbcbb20u64, 0x4883665f144b56c0u64, 0xb39069a1852688f4u64, 0x2efbe1acd754b357u64]),
-    ([0x108f081c599ee60au64, 0x3c5a676bc2ecdf37u64, 0x015b6fdaddcba562u64, 0x0a7490814a055436u64], [0xe1e433b6846fbb9bu64, 0xeba0270ac16356c1u64, 0x2f4aa843d34551d2u64, 0x0b9c83e4728e6feeu64]),
-    ([0x9abdfed308d48530u64, 0x81c1b5a7e615e52au64, 0xf27bdcd524b986c7u64, 0x2859fb344269079bu64], [0x3f5f38efe187d185u64, 0xca024c57318f506bu64, 0xec2fa05fa05ea5c9u64, 0x0c4f493b1685f70fu64]),
-    ([0xfcf12357c1b92f13u64, 0xec02659c12728398u64, 0xebefecc1a2c6a2feu64, 0x1c13ead542c81ad9u64], [0x29363994b0354a16u64, 0xc20ab21996b0dcefu64, 0xe92c24c03ecc1b69u64, 0x051b6c197cdf4a59u64]),
-    ([0x7f67cdf54ad97ee9u64, 0x8b3bcc3ecbe00026u64, 0x3fececfd8eda4077u64, 0x070698a48260ad90u64], [0x017e7a824b4752efu64, 0x28f59868d2ef4c1fu64, 0x47a3a0b4f279ddb8u64, 0x03b0e0f9eb381125u64]),
-    ([0x8558294eaca52dbau64, 0xdf072592fca28b1bu64, 0x37a925270f5ca73au64, 0x193682aa89cf3c6bu64], [0x14103b2164ef20d5u64, 0x8858a0d12d23ca70u64, 0xd708d5c4b20d2822u64, 0x09623e8f9b39c5a6u64]),
-    ([0x90fbb001b9e0fc63u64, 0xc3bbf3e0236797f2u64, 0xab6dbb87d005f9f9u64, 0x21418363195e3755u64], [0xfae94f608eeba6fdu64, 0x3e900d9f4c27b0b9u64, 0xc932355392f3053au64, 0x1bcaadf9e3af7f8eu64]),
-    ([0x4c2c6a8c992f2838u64, 0xc49ab0adb54d8641u64, 0xe4720d3be1ac51eau64, 0x052d7a241bf03e33u64], [0xa623d3ee626216cfu64, 0x37dbbec489013bffu64, 0x8b1dfd442a813df4u64, 0x0b7fa00e36b87a6au64]),
-    ([0x7815228d16bbdc09u64, 0x84a3d862530a7bdcu64, 0x2e50275792894316u64, 0x0cf9cd47c55a08bdu64], [0xfb433cf04fcd7764u64, 0xb0b7410ef6d8e24au64, 0xc824c15f0f06b816u64, 0x0e98e9289cf73d91u64]),
-    ([0xb8b60e9c594b7a54u64, 0x44b23fe1a81b2eeau64, 0xdb76ae85d1d13040u64, 0x1f6329b73c10f7f8u64], [0x1b833ada12f28054u64, 0xfb246c86ee5a2bdeu64, 0x85ba73634dbddc13u64, 0x2c139ec6f5d81cb4u64]),
-    ([0x7aea4b4ba2e1263du64, 0x349638fceb77aa02u64, 0x6dc1c7705ab9a8ceu64, 0x0d1b4032b3d32af7u64], [0x54d044c16a2f4382u64, 0x81509693fd941324u64, 0x5de827db673e9774u64, 0x1c6ba2189592a0aeu64]),
-    ([0x4443eda5d3bc4035u64, 0xe04e24d275ee9824u64, 0x87fb69abf236b0d5u64, 0x096b9f8b8598b7c3u64], [0xfc0734aea5531af0u64, 0x988dd50231a5eaa4u64, 0xef41c09406165fa9u64, 0x11c571b3500aa2b6u64]),
-    ([0x03d80348ea49b72du64, 0xfd9d0b24df8e35eau64, 0xa38d46a2ae42d269u64, 0x255e83335140b84eu64], [0xe3f8b214a0091010u64, 0x259ea0a566827780u64, 0x50991849a3693fd6u64, 0x20e2d148891b29ffu64]),
-    ([0x92ccebcd0cf9b9f6u64, 0x8ec5ec67626a74f8u64, 0x9be1f670eca407d0u64, 0x0f5c21d0ca65e0dbu64], [0x795f8fbf65514853u64, 0x45769a1d01562221u64, 0x15c33f70f1bcd9b2u64, 0x2a2a75783fe2f1f1u64]),
-    ([0x797e8f2bcb7db4f7u64, 0xd5d4d6717503c382u64, 0x415aac2dce0e5c2eu64, 0x27f9616c7de2b797u64], [0xf43ee3aa2f27618cu64, 0x4ca9c4d2e462a63du64, 0xab413868385ae2dbu64, 0x044ae9425eaaf6f0u64]),
-    ([0x3c37fe9320930d4eu64, 0xb159f04b8a06ab0cu64, 0xe28912789342379fu64, 0x243cd9e97ac51d7du64], [0x22f07d6b4eecdfe2u64, 0x49997c23413c477au64, 0x4c941b0b74d15467u64, 0x2b1f732c5e712966u64]),
-    ([0xbc56680c76d6fc3fu64, 0x1dedcfdcd6ee0a69u64, 0x3e6c5875a58375bfu64, 0x23bedf6baf31fbd2u64], [0xf8ae850503abdec3u64, 0x8931715eba0e7e4fu64, 0xaf6d4d17f7029862u64, 0x2f37a65f09f374c7u64]),
-    ([0x7c0f22c6cda5b3cbu64, 0xdcba5924232f62cfu64, 0x159c1986a1c9a190u64, 0x1ab7783ec3a3cff2u64], [0x568435228522441eu64, 0x24bbc9051c831814u64, 0x0469c1e21925a8e8u64, 0x02239e0d0a24cfe9u64]),
-    ([0xc10a068f0c71c25au64, 0xceb7a9f9133412a0u64, 0xede8810022e24cc9u64, 0x1210465f5aff46c0u64], [0xbb79c2a24e5eaf5au64, 0xa5e43f700960f888u64, 0x73957302bbee6c45u64, 0x156bc0886a1a48bdu64]),
-    ([0x29904ca253f989a4u64, 0x43415c821f30c31eu64, 0x4430083a9b3f7f83u64, 0x0bd027107894d5d8u64], [0x83652f4edfb170a8u64, 0x316846ebb9ae06feu64, 0x15eed3cb9c186ee0u64, 0x1b6361d5100d9fe6u64]),
-    ([0xd2eadd9484526dc0u64, 0x022a42aa0daf09f8u64, 0x188caee29365f18fu64, 0x09649958b3a6d148u64], [0xdc697524f4ebce0du64, 0xf11468a28cb11f2au64, 0x76b3c4e36619e7fau64, 0x21d70cb1b2f7de5cu64]),
-    ([0x9b51fd27694f6fa3u64, 0x38028ef8c8491024u64, 0xce7532408cac0ef7u64, 0x1e959141fcc01fcdu64], [0xec1e56f641832f58u64, 0xd504b5155a12c609u64, 0xe5b5dd3ccf1c3705u64, 0x2e8d250b67160854u64]),
-    ([0xcba309650ca6e3c0u64, 0x2eb4c1ff7e7c3a3fu64, 0x22d9bcfb3b2a0d93u64, 0x03a7ed0c03cd76c2u64], [0x4f4f2014d4a3b82du64, 0x5338ee67c211dedfu64, 0x0040c0db78922722u64, 0x21296e69d5e0c8e3u64]),
-    ([0x91193658da23c472u64, 0xc004899e7a3be0dbu64, 0xf841f3c8a26db1c9u64, 0x281be4b424d0c6b5u64], [0xb0a52a9fc139422eu64, 0x874496dd02f56749u64, 0xcd3be4fae3f4e956u64, 0x019708c05478290bu64]),
-    ([0x5c442211b8912eacu64, 0x2d331dde92228c35u64, 0xa7e4ec255e39d263u64, 0x0df5727e05758ad4u64], [0x0bd9b9e6b953c3bdu64, 0x9919ca49fcf0edccu64, 0xabfb0c6740db64eeu64, 0x0b4a59686389b861u64]),
-    ([0x040b5a9a3f0cedb7u64, 0x4103ea4044c28f24u64, 0x3cd03e7ac023b449u64, 0x2503d2d4e128fd8cu64], [0xf5847b87fc1084d3u64, 0x86b36294734d3323u64, 0x097c8990e622ebecu64, 0x1b23c01273c12433u64]),
-    ([0xef7733ce6607e041u64, 0x6092a4cf92ed7803u64, 0x3d6c3971e835589cu64, 0x18fdfc036749428fu64], [0xf415a49b02726d44u64, 0xae0c38bedac648ecu64, 0x0afc06f1ad981a96u64, 0x25748613ebc445a2u64]),
-    ([0xadcbe0d23284a5afu64, 0xee33008377e65166u64, 0x9467d23580096cbbu64, 0x052edbca98da6e51u64], [0x1ba4ebdc97a8a7d6u64, 0x5e0b388f13b306bdu64, 0x3ecd05a3d0cf345au64, 0x0cef7c2f07d4f55fu64]),
-    ([0x2ea8532d6c849958u64, 0x929da5c5a0370e72u64, 0x1ec74f6904effeacu64, 0x0e5bb3909c0d1ff8u64], [0x100b6b219f9b5765u64, 0xa84d5e7997d25c11u64, 0xba70c804919c2d8du64, 0x27e58c92f6910f46u64]),
-    ([0xcea1af45e28eef24u64, 0x6ec7153e10828792u64, 0x19b85cd3bb8809d3u64, 0x2d972f69843a2c0cu64], [0x892fd3f5fde2f947u64, 0xa546eabf0f2e63a8u64, 0xa582db4f637aacdfu64, 0x15eca9689f86eabfu64]),
-    ([0x890cecdf644c0029u64, 0xccd5a891722aef16u64, 0x0e63cdfed8d463e1u64, 0x27b99be0c6707b1fu64], [0xc36819ab3b15a611u64, 0x0479769ae100800au64, 0x6df38a9b29a60d8du64, 0x2828cec9d975ee6cu64]),
-    ([0x5515f351fa6f867cu64, 0xd1706381d521dacfu64, 0x25fe0b6252990a1eu64, 0x14ce7543c776a077u64], [0x70b8a4011f368842u64, 0xe9ec819d5063b85bu64, 0xe6e5b567eadd9ca7u64, 0x1a5ba8b84d18f2e5u64]),
-    ([0x1de67f233235343fu64, 0x4b9fa49f6494eb31u64, 0x76c5e14dc153b54eu64, 0x2eca9aabcd545cd6u64], [0x37d6e0df5ad76bdcu64, 0x631e1099250b3462u64, 0x1a11a9af7851225du64, 0x07a7905e5348293au64]),
-    ([0x6cc91acf8951b186u64, 0xd06843f97a596b28u64, 0x0e8b0ced5e0eb89eu64, 0x2ce0653423e1688bu64], [0x3eba9a58a6b8522eu64, 0x60ee79826ddeae33u64, 0x1738ba1cc67e715bu64, 0x081b7749e3543559u64]),
-    ([0xfe5dc7096f77d84au64, 0x584ec6c2dc3c3bb9u64, 0x0946bf280e819e53u64, 0x00e2bea1c6facd03u64], [0x9ba00a51cc61063bu64, 0xf1835ff810c79c21u64, 0xc3dc95c0924301f9u64, 0x2abd430d9119ed3bu64]),
-    ([0x86970f18975d21d7u64, 0xe298299bf32f3392u64, 0x1df6a986cf729c69u64, 0x0bb288cf825274beu64], [0x1236ae96dc014db5u64, 0x66c86cc6ea9188ecu64, 0xaed3826dba891cd2u64, 0x09d72d6f86444d7fu64]),
-    ([0x57e8fd2e809eb7b1u64, 0x954081aaeb78d472u64, 0x10cdc46b02f34d94u64, 0x18b56c499ac65be2u64], [0xd22f516ad633ec2cu64, 0x313a403ab07cb8afu64, 0xa6f2e28ef263f8a6u64, 0x2ba706b798bc608bu64]),
-    ([0xbe8e8567bedd5a37u64, 0xa7c5865271a5d93cu64, 0xe633c741833ad500u64, 0x2228d689cfbe76ffu64], [0x07c06d1ea639a84eu64, 0x81d38c1524506864u64, 0xbbf9b23f36561606u64, 0x092e49d2aa203943u64]),
-    ([0xa1d788d9843bd53fu64, 0x8afe13ac7074bf6cu64, 0x8a96bed64e5bd152u64, 0x0622d3c6f081acccu64], [0x8d8d4a1ffb918507u64, 0x852c09cc7a79847bu64, 0x8f1a03c14f9abae9u64, 0x1d768ed812699824u64]),
-    ([0x6717cb1637c71506u64, 0x8e3d32bc2232bf9du64, 0xcac69ec0cc819770u64, 0x2f36fe2ebc8557e4u64], [0x8f0c3bcfa4ef77cfu64, 0x8347136930020215u64, 0x462d74ee0662860fu64, 0x06a1ef9ecb749ce5u64]),
-    ([0x8a37cfeb960ed4f9u64, 0x8c965a8e784757bfu64, 0x03eb359f30a7e2e1u64, 0x0b59e8f521129a72u64], [0xc628ca1b8e74dbffu64, 0x725e3ad062c096f0u64, 0x9b8b1f974f2222b1u64, 0x2f7503f050eb86cdu64]),
-    ([0xa3820ffa6d0fcc8bu64, 0xb7571e428a575d49u64, 0x6ac5525b04964f75u64, 0x1f1fe5c96b435b1au64], [0xe617b2c120e49cb8u64, 0x5c679f07f84e553au64, 0xedfcc483b9648e7bu64, 0x2a67633da10c0816u64]),
-    ([0x295541a27f5167a9u64, 0x648ac2c25e9fa822u64, 0x362dad53e26c16f3u64, 0x278b16e284f6a255u64], [0xd96dc9990b7c0f47u64, 0x397ee4b838eb76e3u64, 0xf0e2adc4bc1ab662u64, 0x1e432b840f5f0639u64]),
-    ([0xf70fb7912a9f4b06u64, 0x277d79af9c5f3feeu64, 0xe6de34f45ca73b4du64, 0x2ae0924e5ea5fae1u64], [0x4c7278043c69876cu64, 0xd75f2c2c98dc0e76u64, 0xda91d5ca5e8af3f8u64, 0x1a2036b03276c197u64]),
-    ([0x56aba4a7cbe85d9eu64, 0xb2abed653544c991u64, 0x5a71c4e657ce600fu64, 0x22333a80dff24a0fu64], [0x33d7895b0b2e12c6u64, 0xf561bfb655cc1abbu64, 0xee326579f233e8d6u64, 0x04fbaf839d869946u64]),
-    ([0x41ed53364883dac6u64, 0xf714afda4c929a4du64, 0xc595e6051ebdb9a5u64, 0x1deca00ecb40b110u64], [0x6684610130bd7c31u64, 0x64bcb33ebaa07442u64, 0x04d5b7b5ba5acc4au64, 0x128147fa9044e523u64]),
-    ([0x412ffca80df3707cu64, 0x40f1dcbad088f5f2u64, 0x17f7b20a36c05ff4u64, 0x27747758e2eabac0u64], [0x849baf5123aaae46u64, 0xdddcf33d4398753eu64, 0xbdfe71b21f8e1f69u64, 0x0aa0d5f28221062eu64]),
-    ([0x6a8237b6b837ee91u64, 0x00114b7a5177a463u64, 0xda3f3a64cb4b2c07u64, 0x1619668f21466824u64], [0xa44caca062c50508u64, 0x345a71427ce070d2u64, 0x976a6f15aef879b7u64, 0x11faf26a5912b99fu64]),
-    ([0xa75d673db4f71f51u64, 0x975d22f20bf9c9cau64, 0x4d0ac0d0253abc17u64, 0x2291a37902d26eebu64], [0x429ee6ebbbd72eddu64, 0xac16355b5510b082u64, 0x6067700da97036c5u64, 0x08118669d569396eu64]),
-    ([0x011eafe34af7e8d4u64, 0xcb11629200d86a0cu64, 0xfabfef4dc3e74f18u64, 0x14d5cb6b07f568deu64], [0x43d2ddb9d525aa1cu64, 0x956ff40a05f59731u64, 0x0df208cd32beebd7u64, 0x27ae040fd80cc627u64]),
-    ([0x49f3af94521b6b98u64, 0x7c31525d143819a2u64, 0x601177933e9a01bfu64, 0x21580a6f78dec205u64], [0xeed5d7fd71df5edbu64, 0xf801b8b462cc6408u64, 0x155705d052598a0eu64, 0x08bcee070768a604u64]),
-    ([0x37aaa6c94e1739bau64, 0xf0f10fa7d2e5116eu64, 0xf68143ecc2dbfb59u64, 0x2c56dad42eea4bc3u64], [0xde3ada8553e1d995u64, 0x32978d8e56254a32u64, 0xb6eaefa934f90eb3u64, 0x12ed1f3276374d1cu64]),
-    ([0x1bc2f392c7c35fe9u64, 0xa690a3a8368a2701u64, 0xd07eae3e3c18fde0u64, 0x21e31eb9a45e83cdu64], [0x974dce051c44e575u64, 0x59394315af5cfff8u64, 0x2e9048595dbecdd0u64, 0x2a513c4abad486a1u64]),
-    ([0x3e37efe351fe2576u64, 0x79783bdba55f0757u64, 0xaa0838154a1aa468u64, 0x17c9126083f3d915u64], [0xa11a6a79bd304434u64, 0x1e2168187d74bab4u64, 0x7a11c4a485bc6283u64, 0x02637967a857995eu64]),
-    ([0xfacb5d236d7c41d3u64, 0xe3208894718a4851u64, 0x4010481fced1effdu64, 0x123573e146c17441u64], [0x751e304daf44ec01u64, 0x0b149ea417964b35u64, 0x018070c2611c8729u64, 0x2ae6adbefe9b5e6bu64]),
-    ([0x70038f289c8cb3a9u64, 0x1bf9df39221f93f6u64, 0xcb1317373772f6f7u64, 0x2540fff6ef670314u64], [0xe46fc884ae12d44au64, 0x3f9cd6d855092ba7u64, 0x3606ac20daef1f35u64, 0x02c8e4f3fb1666e8u64]),
-    ([0xbcacadfe43d0ae72u64, 0x8b6d6cdea820aa12u64, 0xec1475de96cc5147u64, 0x2d8ac3b18b62e4f8u64], [0x4595797589a89825u64, 0x611ff06b43a309dau64, 0xafd69ca68c58323eu64, 0x2639244d27d4e20au64]),
-    ([0x6a0de29a1ddf3446u64, 0xa747eb2f5c82959eu64, 0xc02f6aaebdf61149u64, 0x2288d9b5356642d0u64], [0x03d877d72234c187u64, 0xbb42eb60dd52bdb4u64, 0xe8f38728b74d7ec7u64, 0x03b91066f6d3a980u64]),
-    ([0x8cac511369d2ed29u64, 0x27d8a10170bdf868u64, 0x9b3846553c432477u64, 0x20b40bfcbaf54e55u64], [0x39b224670bbad263u64, 0xdadff14443113332u64, 0xbe8eb1e7631f3800u64, 0x2c78544718bd4e85u64]),
-    ([0x1d6463115f854e4fu64, 0x32de309d319fe5e2u64, 0xb7ad1e1bd05c71fdu64, 0x2666ba51977280d8u64], [0xf2267a647cb7344bu64, 0x1825303641aab44bu64, 0x937f856dfa0d8fe3u64, 0x2346e61cf4cd82e9u64]),
-    ([0xa525b01e2200d54eu64, 0x85a00f3360f71c73u64, 0x8ef71e3f4fc47b31u64, 0x2cad3b506f35e942u64], [0xad3f54a456936b06u64, 0xcbe0a2e8bdb5ed0au64, 0xb0e2481c2179412bu64, 0x0d37fe90b1323af7u64]),
-    ([0x84185f685361d95eu64, 0x8f7febab6f3409b9u64, 0x0999e33dd82d13bcu64, 0x2afeab77bda713a7u64], [0xf4bc7b0470d14f9au64, 0x9f6516fea8b43c02u64, 0x3f1b4b3795d05f01u64, 0x180fe46f62a0a5fbu64]),
-    ([0x7ac70331732b0f37u64, 0x55c24ecfb77daf91u64, 0xa0a42a7a90604aaeu64, 0x21bd7cad3b1eb822u64], [0xeb201b93fae6d9ceu64, 0x0574401aedba5e99u64, 0xa1b356c408f2e20fu64, 0x2ff7e5ab49bec097u64]),
-    ([0xe591bb5282065425u64, 0x04adf887994cad51u64, 0x0e0391d70c8637d9u64, 0x15837c1a40b5faf6u64], [0xd80f8a7fec3b68c2u64, 0x15e7b042d766fccdu64, 0x78a72bdd5239a441u64, 0x041077585e3d43e2u64]),
-    ([0x5fd87f635f6ddcbdu64, 0x27a224b3ab5dd653u64, 0x21176347aa6deeffu64, 0x10f0c31f72814a95u64], [0x5764a2ac6cf30812u64, 0x04a99680ba24ce0bu64, 0xbaf9980cb334d78fu64, 0x28d314e0806bdf2du64]),
-    ([0xc0ca3c9645fd06c2u64, 0x72d4f0005cada30du64, 0xd2c1c18c82cd7c29u64, 0x1bacc0f70e6b3a53u64], [0xd06f70e4774f28deu64, 0xe67dad5b86186037u64, 0xc938115cba2565feu64, 0x18211cdfe3b682b2u64]),
-    ([0xeb5d4fcd2056e898u64, 0x85975ebaede8f8ecu64, 0x02f7340f128b1671u64, 0x1999e8b5afee7c11u64], [0x8526475f1cd3f1b9u64, 0x09c5904b39b18300u64, 0x8ad849f3ab0bd123u64, 0x16652b086fd6e8e8u64]),
-    ([0xfc91e5001819d524u64, 0xeccfeed48c72bad3u64, 0x3c5c73799dea853eu64, 0x17839e0c8ce49c75u64], [0x3af4752ac33aba6eu64, 0x4e93522bc60f666au64, 0x6edd7f4f8c5482fau64, 0x12d05d56aed2a6c2u64]),
-    ([0xecaa42422ed18135u64, 0x402ef823dbecb731u64, 0x1175b199a5d523a9u64, 0x1c793421ad036155u64], [0x3481017ac4b81b89u64, 0x8fd9da00e240d317u64, 0xd7003afdf4694cc4u64, 0x012e8629268ec30au64]),
-    ([0xc76f089555847910u64, 0xff5ee19c0b9bf6fau64, 0xbba3cb4a5e7ad300u64, 0x02707486295856afu64], [0x3e3e0515e7dfee39u64, 0xa8c37dd062510efeu64, 0x49bc3f1619af4ed6u64, 0x23a9c7c14ef46d62u64]),
-    ([0xba66d4d04de2eb90u64, 0xa0380d823df42339u64, 0xf06849c43c2f62abu64, 0x2eb2d8e91fe54502u64], [0xd3b040e86894f278u64, 0x972af0d601f3285du64, 0xd04e555e67a21f8eu64, 0x1fe0448d1a3aa920u64]),
-    ([0x900e68202188d2b1u64, 0x95d5aea33066fb2eu64, 0xaf76bacb89e3a93fu64, 0x25d9809992726b9eu64], [0x78504ab3ea7525f2u64, 0x7e0d2fa0ea58797fu64, 0x4fa7e5986cbe01aau64, 0x04b2ef132856d279u64]),
-    ([0x558af1e9cdd1599cu64, 0x171222f8180c07fcu64, 0x3ef5d8016fb6511bu64, 0x204c5b4a1e2ffc80u64], [0x7416e2637ec396d5u64, 0x8add257949ba0a36u64, 0x0fee8e1e0a71544du64, 0x108b16938e7ccaceu64]),
-    ([0x5da6b254c7545ef6u64, 0xe5079653eb03ec26u64, 0x202a51ecd098bae3u64, 0x1116dcf201e18ba5u64], [0xa46066d32b738151u64, 0x0d4660ff2a9f8dc6u64, 0xcd77102ef3d39271u64, 0x1b677da25c18d8a5u64]),
-    ([0x78680cecbbd3544fu64, 0x4803256ff4a07ca1u64, 0xc2231ec046e15039u64, 0x2cf9750fe2fc6d69u64], [0x3b5e5dc082766a8eu64, 0x356d7c01266456fcu64, 0x27d20fc7621325ceu64, 0x03bda0865281782bu64]),
-    ([0xecb0115c57e32226u64, 0x9de320437986b170u64, 0xbda33f369ba3478eu64, 0x2a98c60f42663c00u64], [0xd18db920750d0e77u64, 0x24e19664f4111e68u64, 0x69aaf67e3b3f4a11u64, 0x25b3920533ae3546u64]),
-    ([0x44e7fc1b2d4d3c42u64, 0x671c87b86ab176d3u64, 0x67708681b075944au64, 0x079792a34a4401e8u64], [0x35f3e13e350891d7u64, 0xaae6bf122031d463u64, 0x22f0858d9e17cf25u64, 0x1619f13f28c05b38u64]),
-    ([0xaf5448492e42a181u64, 0xd5d174e181a26686u64, 0xcbb4a786ead16926u64, 0x0530d09118705106u64], [0xc1091a76ae22e945u64, 0xbaa28aa610ac5ef7u64, 0x3480363eb248aa0cu64, 0x1a3c51a37a21c5ccu64]),
-    ([0xcd59d11bc87084b9u64, 0xb9678906f6841786u64, 0xbeda4bd36e29b53eu64, 0x04615b85239190cfu64], [0x25b77e73fe7c2fb2u64, 0xb9767132319943b2u64, 0x03cd085ac60da8beu64, 0x2703196597fb0a39u64]),
-    ([0xdcda128cddff26deu64, 0x24d4ae7f80453c38u64, 0x8f9f70eaf0857732u64, 0x28479d620f15002bu64], [0xaa69696d15cb83c3u64, 0x5c1887659b3c59edu64, 0x123b7c5ddc1fc310u64, 0x252fcad47148c9f1u64]),
-    ([0x1c51c85bd802d7b0u64, 0x891c58891b65c94cu64, 0xfbad4815f4d2feaeu64, 0x0c74c966c05f3312u64], [0x880349329d5e02e7u64, 0x236c48478fffd53du64, 0x385b5e6bf0c7d7d3u64, 0x0adc33c9aea17f8bu64]),
-    ([0xe49a848f4994781cu64, 0x5239a6947e1cc23au64, 0xec4637b325fae621u64, 0x168dfb382717f390u64], [0xc5e01627cde7b19au64, 0xec722097cffeaf76u64, 0x9512da89a527095au64, 0x10881b5e3782158bu64]),
-    ([0xa181b5d1be66abdcu64, 0xda7fe41ab8f44a6cu64, 0x94b56417b64f712fu64, 0x23d06347f8c33a91u64], [0x550822ad2e0ffc24u64, 0xdd4423b10309197du64, 0x1f624702b85a4075u64, 0x1d40cffb26985f25u64]),
-    ([0x1deb2e01c7d2eb2cu64, 0xa1aa12c0d5d63fb8u64, 0x31000cbd6eeb4cfeu64, 0x1886aa6d336928f3u64], [0x8ebdf920354fa0bfu64, 0x5d210f0e0ca39a3cu64, 0xc4cd8d905bd2fe17u64, 0x2eccf4551a51c218u64]),
-    ([0x8db05c1ae65ce62eu64, 0x4dbcb1f7fcb00f35u64, 0x30e9fa522b61498bu64, 0x24fe1b7b68f3c3f4u64], [0xe4ec48b1cde77c4fu64, 0xd2ea8632fe844060u64, 0xc19a9d34889c09e3u64, 0x1586b64fc8080e82u64]),
-    ([0x3931be942f3ac6f3u64, 0x847a5a77ba474357u64, 0xfc92d390d98ab62du64, 0x257e0a140c23f394u64], [0x1586c288d955fe09u64, 0x90b459c04d1bc6f0u64, 0x284598bf58a9d92fu64, 0x2dd6da8d6b316d93u64]),
-    ([0x393d2347a51a9de5u64, 0x4dda614ea2f48d1eu64, 0x4235eb9684508973u64, 0x2aebf2ddbdad791eu64], [0x9f2287cbb6eee6afu64, 0x48572df05b4037acu64, 0x9360af233a8c034au64, 0x19db72c93dadabe6u64]),
-    ([0x41916fab440fdb7du64, 0x8ad4dbaaa77f31ecu64, 0x432ffa0a903a9ea7u64, 0x0cee6c21bf26aaa4u64], [0x33af79ac1ddd80c1u64, 0x433a0b16fe4cfda6u64, 0xa74d2374984b3ce8u64, 0x0b7f9d23da17634eu64]),
-    ([0xdbc2a557113ac77au64, 0xbcfd8fbf631a03a7u64, 0xd4944984af7a31f3u64, 0x0aea2cff332dc539u64], [0xd2b420cb17e7c91bu64, 0x72d416fee57b44beu64, 0x755069de0163c703u64, 0x256ac42bc48bc5ebu64]),
-    ([0xe65cebe74fd24159u64, 0x90d2a8a094d438d3u64, 0x85e6bd83a1896cb0u64, 0x18aa10dcdeaaf3ceu64], [0x76cddb916b73d64eu64, 0xf98a2105ca8aeb14u64, 0x6f5b273a155bbb72u64, 0x1b7cfe0fcbf7facau64]),
-    ([0x2e13a96f216ce3a9u64, 0x9018c71e8d5fd71fu64, 0xd2a346260493c7b5u64, 0x1c7ebf7b0f8f8d22u64], [0x212cc17ee88a7aaeu64, 0x716ae857ed00d885u64, 0xa1a5082ee3bea7f5u64, 0x10d3c36e8700b0d2u64]),
-    ([0xb452998be61b1c46u64, 0xb0f93ca4bcbd58d1u64, 0xfb58bceaac2b9405u64, 0x2ba4df586aad0910u64], [0xf3caac50bad4f564u64, 0x93106497b3e9ba07u64, 0x8a0d56e792ab7921u64, 0x0bf3f69a90ef7a35u64]),
-    ([0x26a7af20ef07a065u64, 0x19799fc643610a70u64, 0x37847275ceb78c9cu64, 0x1386c5d06ab60f57u64], [0x1697282a9454f977u64, 0xa450613e70120402u64, 0x3a01fdfdaa6b0f6fu64, 0x02652fcd45057340u64]),
-    ([0xb0dc74af1df15010u64, 0xc3c759364769402bu64, 0x7452f5b9cb934270u64, 0x1f810cceba74beb2u64], [0x2ad491b189031450u64, 0xd44c917e8c009306u64, 0x3c38de39e72ac735u64, 0x0c30f41fc333beeeu64]),
-    ([0xaa57df86cb78f4edu64, 0x06f7a81f523b46a9u64, 0x04c6ab2632eced8fu64, 0x00b1c725f6c2a53bu64], [0x4e20054a13bc0d04u64, 0xf4f4f1694f8b9c69u64, 0x823ea096ef0b0773u64, 0x2baf06bfc839bdd3u64]),
-    ([0xf6d5a1d1a8d75720u64, 0x5ee0e9a5afdb03deu64, 0xdd41987882c13044u64, 0x2b9819e1d835f330u64], [0x1aa8153cab57c095u64, 0x171b69dbaff4927cu64, 0x6434c7f237e20db7u64, 0x2089d71ae72c1118u64]),
-    ([0x31850af9878aaf85u64, 0xa5081d5a65608b05u64, 0xb3aaa693c3c990dau64, 0x23f85a3a736a785cu64], [0x45474fc3f41f8bf0u64, 0xa919bbbc6bd0bb09u64, 0x1ee2c6f6d3735ff4u64, 0x2d27e258ceb28c9au64]),
-    ([0xefaae3bd69b0d8b3u64, 0x6aef85be0bf8a2c2u64, 0xe26d107cfe014ba2u64, 0x27e77efa71335300u64], [0x6be6e500ffdf301eu64, 0x4d7fbf4c6f63031fu64, 0x8f8fcc4717758218u64, 0x2b6749f4ea33b280u64]),
-    ([0x96492d0d6cec458cu64, 0x6f61a6213e07c664u64, 0xd5e975e1bbdf1a33u64, 0x15a5f6ba67396143u64], [0xc89633c6abf1043du64, 0xd20be7713149983cu64, 0x41988c00a0e828fbu64, 0x0e9122cdea21f5fau64]),
-    ([0x2e184be31ce3df79u64, 0x4d142050b34de9b3u64, 0x8531ba0c7987d02bu64, 0x18581def6774e10au64], [0xe29ee896dbf9b01au64, 0xfa3f15b7b99223aeu64, 0x5bb54bbddd83bb0eu64, 0x12f4e0a1be410854u64]),
-    ([0xef3ac6aee49f7b2bu64, 0x4a73b483531d60f0u64, 0xf7e7c492ddb35ef1u64, 0x2f8d73ac98008418u64], [0x903c3ddec2656c04u64, 0xf0b481e5ff524423u64, 0x9c59dc039232efb5u64, 0x24ea9e9869fafc40u64]),
-    ([0x5f0395d7ae2643a3u64, 0x0568c381f15e0490u64, 0x6e787f7a1746b673u64, 0x0b759207809bcf5eu64], [0x89753dede1bee2dbu64, 0x7ba73fee25b38443u64, 0x4657523bb5e5cc1eu64, 0x10c2a177ce6543f4u64]),
-    ([0x67c3ea1a658765c7u64, 0x0afca241e1af64bbu64, 0xf3b8ef2012f54f85u64, 0x0c5bedaac97c2d70u64], [0x0dc718831c2f5feau64, 0xd703c6bc08045624u64, 0xe13ee793d1a99ebdu64, 0x06e99a4573ede0bau64]),
-    ([0xb15a9183b959566du64, 0x6b906a9b78bc1755u64, 0x5a91acb3b39c7b3au64, 0x016854168bdb53e7u64], [0xd60e8916ff0aee42u64, 0x6d0a64b0fce2e742u64, 0x81d52f1d6f9f83adu64, 0x01182f7e492417dbu64]),
-    ([0x445759ab6c5ce967u64, 0xdbb71d545c2c448eu64, 0x4c7f91f6c60d645eu64, 0x09076f54c351e694u64], [0x7103a249ea81839cu64, 0x1a36728aaa64c09du64, 0xf035f10fa327e654u64, 0x0cfc4f3f9973a391u64]),
-    ([0x5db45246dc2849d5u64, 0xb752fba69356acfeu64, 0x9349d6fb1678c66au64, 0x0b8e9771f2dab1d1u64], [0x8a3d7c030d08490du64, 0x1beeb1aeb3921bdau64, 0x0897ebaa4038a2adu64, 0x249a8787eaf03e61u64]),
-    ([0x0d15ff17d1b21f5eu64, 0x63f6a80bb969c5e4u64, 0x38319411efbdac32u64, 0x248a3fdf79c8ffc1u64], [0x8b58aa023eddfb99u64, 0x2cf7321b4c43e6a6u64, 0x3a5fbd1ff63c6dfdu64, 0x220df8b356751ca7u64]),
-    ([0x567af67cb22b7ba1u64, 0x8f5ed81bfe2f9d36u64, 0xff75d478c2e1a6c2u64, 0x0bc8afa132967272u64], [0x9f093e429d251f36u64, 0xcfdd87a4a41a51a2u64, 0x8484431d2fe54af5u64, 0x12fde0025bb383bau64]),
-    ([0x7075eda4b99ee98cu64, 0xe3e92cf07f72c6a4u64, 0xdaae808731abaf04u64, 0x2ad92fb8d8174c55u64], [0xf2d38e3008a262fau64, 0xd5b9353a32bf1687u64, 0x3e10c3b094253611u64, 0x2c054ab398a606afu64]),
-    ([0xb37ed56cbd85a143u64, 0x2d6e0a7a01d7e962u64, 0x9e3a0b0cff719fcfu64, 0x00a700a049c5baccu64], [0x9d70d8acec79394fu64, 0xcde4fce586d2ab84u64, 0xc99a9151799b2688u64, 0x2afdeb2e4fbcb2a5u64]),
-    ([0x3adce154139d1eaau64, 0x04e795d0c81e7ddau64, 0x984a5092bb3ff5a7u64, 0x0bccac2776fa5c6du64], [0x12175553f4aa97a7u64, 0xfee7b18f9df0de46u64, 0x0a2f334ff5fee2d8u64, 0x19ba64f2a5e548cau64]),
-    ([0x758c701a883bc0b8u64, 0x3e4577ded7bc9491u64, 0x2f59465c08093bccu64, 0x2f51694d78e1f654u64], [0x1bb8b1c37b402c5eu64, 0x8ec79ac66938af0du64, 0xe7c235bb03d2a7e7u64, 0x1d6aa600216e5a9bu64]),
-    ([0x4dac0bd86b40ed83u64, 0x1276b0add258fb12u64, 0x509128948915614au64, 0x0e010cbe93059977u64], [0xcf6b57845e62eabeu64, 0x453b4ed424c0ba6au64, 0xf622aaa6565a93adu64, 0x2d5b0be46696b2d7u64]),
-    ([0x63e51bd1ae18c501u64, 0xd27c4cc9ef622e2fu64, 0xd686e9d1a23b56a8u64, 0x07f5e99623145982u64], [0x8314c2f04351a991u64, 0xda4cede5526495e0u64, 0x02e52ece8a4352eau64, 0x10001b459bc869d2u64]),
-    ([0xed33079376029da9u64, 0x913e1cac5333f358u64, 0xbcaca9431691020eu64, 0x2809d85cdc62ad8bu64], [0x26d0257c77d99700u64, 0xe47d8746b8c1c187u64, 0xb198c37505e6e3deu64, 0x08e1ecafe743ac93u64]),
-    ([0x60dd7c913490bdcbu64, 0x7676521ceee07f86u64, 0x71fa548b194a4c19u64, 0x1ba7fdf443431689u64], [0x4414ae7de3572010u64, 0x81c64890f04a9060u64, 0xe0b217fba3f40a4fu64, 0x11c27065f9e59b65u64]),
-    ([0x98ea83ca906d0d9du64, 0xcbd4bccf603eabcdu64, 0xb556cb0bc6cc4b85u64, 0x297861cd73704406u64], [0x14d2457586c14552u64, 0x591035e912ed8ec2u64, 0x470d4f0879b513a9u64, 0x023bbbd7f9077b84u64]),
-    ([0x6bb62673dd286631u64, 0x9395ba939447f232u64, 0x4b6fb566e683bc36u64, 0x1d6f2879f03dbd83u64], [0xbf0bac71fb5c9b4cu64, 0x4de73c80fafcf2dcu64, 0x3de549938045a974u64, 0x2299da8bbb3e9c03u64]),
-    ([0x3370022488f7075au64, 0x60f787d0aa999a1bu64, 0xc9db27ae74807cd4u64, 0x264dfca909763f4cu64], [0x304bd9faba3bee8au64, 0xa4808b357e6bfb78u64, 0x7aa401f20fdd3c70u64, 0x2a795c381fa665f3u64]),
-    ([0x87cc0fc019e6b611u64, 0xac38d057a0c57493u64, 0xe7b212f92f104636u64, 0x22037a24efec0818u64], [0xe87852c5f01fa0e6u64, 0x9e9e5c66086ba491u64, 0xd90aae7c5d47d049u64, 0x09da76f54992244cu64]),
-    ([0xff2df3c35341cc89u64, 0x34c05439426a27fau64, 0xb1235a18ac06541bu64, 0x305d28c8c0d63bc0u64], [0x4ac991fdf56a765au64, 0xafc0cdeba13031b8u64, 0xd61f4dcb1979edbau64, 0x1dba9c94f46e8133u64]),
-    ([0x1f445f2929a746afu64, 0x9045c83147f08508u64, 0x88033b311dcea823u64, 0x08b2adee75185192u64], [0xde8f8c2c534909c5u64, 0x29aef84f455e9645u64, 0x6634c745edb9b390u64, 0x269fd918b51a936du64]),
-    ([0xff6b91c1628e1cb2u64, 0xd385b693ffe22b53u64, 0xda1ebc15b4e2f20bu64, 0x21f3e0e6ab3ab53bu64], [0x3c7c9128a8a89328u64, 0x29024f35e96d6a42u64, 0xc976a5e476d55d29u64, 0x1b626f01822e1c00u64]),
-    ([0x50721d20c949eef4u64, 0x1d891e67be9dcd46u64, 0x8b3103a5d48b945du64, 0x107c4909d9ab99b8u64], [0xd4450d67083ca171u64, 0xe2d8114694fe26e9u64, 0xbb4f40855d5476fau64, 0x256b190181f73f29u64]),
-    ([0xdfca0f8ce80fca4du64, 0x5825278ddfd45f19u64, 0x9c6b67f97fa3ce1du64, 0x11b491f1bbd3089eu64], [0x24e570ac45b413b3u64, 0x08e2a704b5df80f4u64, 0xfc17645981e38fbcu64, 0x1a16de1ca821470fu64]),
-    ([0x0fbb1dbbbf5bf08cu64, 0x2a906721b5d4b7bcu64, 0x6e13b0dae6cbeca1u64, 0x2c1b515ca0c2d4beu64], [0xac045687c81f7afcu64, 0xba01b959c65495d2u64, 0x3c77661714996acdu64, 0x22f841aa4ec9635au64]),
-    ([0xd6f77eb51eb41b90u64, 0xfd7a45932d35eec4u64, 0xba4810d0807e2ae1u64, 0x2bb18b0b4fa31c7du64], [0xb41cf2c49db19c1du64, 0x8d8899a08b7fa8ecu64, 0x8eeaa9d67bfff130u64, 0x223bfa236a851a00u64]),
-    ([0x9ea0cf6715a9cfa3u64, 0xb06f331dd325424cu64, 0x4494120d553abb10u64, 0x269d89df02aa2945u64], [0xe018ba6d55b97a26u64, 0x2d252761a069b986u64, 0x94844f2fa50082b5u64, 0x22ce59710cfd9264u64]),
-    ([0x8571b5589c5591e9u64, 0xf031f866a028d387u64, 0x33b1945a362c849fu64, 0x28dbf9b3c4649638u64], [0x0b58b996d82d53f9u64, 0xbb5f134e7ed63a30u64, 0x27caa4a99ea76b48u64, 0x16bc752ebcf048dfu64]),
-    ([0x895c915c31fd7810u64, 0x998121e7d2d1fad4u64, 0xfed63abef088bbffu64, 0x1e20b3a2e140b39au64], [0x15b52878234db923u64, 0xbec37ed7a12fec29u64, 0xa99c110b87eb027bu64, 0x0b75174a05d9822bu64]),
-    ([0x448fcae1731e7399u64, 0x611ffac60db07aa7u64, 0xfdb319a1545829c5u64, 0x281289bda800da13u64], [0xcc29b66880ec7ad6u64, 0x0d54752cc266f73cu64, 0x6bb911a2b7472932u64, 0x25ca6f5b385d363eu64]),
-    ([0x5448f35096906139u64, 0x69ed18834b945be2u64, 0x81dfb345f4dfb39du64, 0x0c101b38f270a19eu64], [0xbea0304a8cfadb6du64, 0x80df84e9abab698cu64, 0x5fd052174ad7039du64, 0x09a3cf5501acd1a1u64]),
-    ([0xe64a102822220e55u64, 0xb7d7150fb955cbd9u64, 0x655da7f95f4e56e2u64, 0x129429cc3cb3b596u64], [0xaddbc8aa52fbbb42u64, 0xe82123f0570cb5a2u64, 0xf56b193a4caade68u64, 0x005e8126a2c42349u64]),
-    ([0xc283089e3b788f92u64, 0xadd2585f6164a75du64, 0xd3ded23d190dd0a8u64, 0x1acead70fffe33cbu64], [0x85ace472fb268ba9u64, 0x79c2b7e00a75db9eu64, 0x0fdf52d451346b47u64, 0x0b227f2cf92aca2bu64]),
-    ([0x36ab6d38b5e057efu64, 0xc004e181a54abddau64, 0xf588ee39c93c0facu64, 0x1bbe2bc2d55ffdb9u64], [0x108810694d72ff0bu64, 0x81e423c8d5d267bfu64, 0x55e9094ed09d2fc3u64, 0x265b968e5f87b733u64]),
-    ([0xe9098464aaa4fb26u64, 0xeedf1b23c410add7u64, 0x3e31c83f9291da76u64, 0x15f79db9c39181bcu64], [0xd92813706677f3d7u64, 0x12457e9277087bc8u64, 0xe0c3e7ea873521b6u64, 0x1f29185ed3598c94u64]),
-    ([0xa7afc5d4b21c08c4u64, 0x16504a1f03cca29du64, 0x42b98e368fe8f991u64, 0x2213b6c947f376f9u64], [0x8109f9d81e2a88eeu64, 0x32394c394b3a66eau64, 0x03d9aa823df9d436u64, 0x261c75ee9fef4001u64]),
-    ([0xd546d0f81bd4d42eu64, 0xf3bb3dd281f6899cu64, 0x789e36e7ba522a7du64, 0x248aac7f6ecb8823u64], [0x620e48d00a02493au64, 0x467214d19291844du64, 0x46bbdfd001511173u64, 0x22b0ba8addd4e0c0u64]),
-    ([0x1d23650aa260beb7u64, 0xd307c4cf75cb5e45u64, 0x8ed58fd20c4f0580u64, 0x0ef83ab6dfb9c428u64], [0x656a43124d37c5d0u64, 0x79d2aa1804fbd531u64, 0xfb191f0803f2b54fu64, 0x0417b9ec97b62c8fu64]),
-    ([0x7e00e99915f394edu64, 0x6764f871e3cc1e4eu64, 0x4203216337ee88e0u64, 0x213b903ca60732ceu64], [0xaf62491df3464876u64, 0x5d6fd6f16db16b0eu64, 0xe5856b8db3c71a0cu64, 0x16d223c317b8d3a4u64]),
-    ([0xf6a2d2092fbb5d74u64, 0x36c88997db728958u64, 0xcaf897f9ed4f7ff0u64, 0x2a82e7d9c602e511u64], [0xa7a6a2d2c9acd472u64, 0x327e99e5f5dbbe79u64, 0x8aa939b74c3a24d3u64, 0x0afa82736c491160u64]),
-    ([0x0d24fb38a4fc6fbeu64, 0x4e57caeac619eeb3u64, 0xddb3730a89f574d1u64, 0x1fe9a328fad7382fu64], [0xc760fcd23fbe116du64, 0xb8d85e31ea2529c4u64, 0x3a8895f2acaf2c08u64, 0x21775b7411ddade7u64]),
-    ([0x5c3b90777da16033u64, 0x872b404c521453d1u64, 0xfa5f68419b054370u64, 0x0d0daaffada992f1u64], [0xce528bca787fc764u64, 0xd404679c671d1b4cu64, 0x1e59f639f60f6731u64, 0x0a7c4e6c9814df37u64]),
-    ([0x9958763a96021642u64, 0x9cc28ce5d60f1230u64, 0x6502be6a9ee34234u64, 0x1ea9740a847c42e4u64], [0xf61aa0be05443129u64, 0x71f53988e5efac0du64, 0xfd03b08791d9f82cu64, 0x2972dbea520a29c7u64]),
-    ([0x5be525836e3907f9u64, 0x2bbd09874bf3fe51u64, 0xa6a34c6c9278ce0au64, 0x096ac60c6eb46e9bu64], [0x9dda1c0198b48568u64, 0xe740aa5190244558u64, 0x9c7a9fc3663c393au64, 0x23853912266dbfc7u64]),
-    ([0x12cc39e6d1a83f4cu64, 0x2e87031d2da5ced8u64, 0x6b3f9a7cbd7939c6u64, 0x1e973931fdcf4839u64], [0x7b7fa25dff7dbf8du64, 0x10b26dde6ae1826du64, 0x502b24fe2595c7e9u64, 0x0d8c66c3bbbbc731u64]),
-    ([0x6da4df33d7070f2fu64, 0xccc9fce04f9135b1u64, 0xc7ed1c6bcde1b194u64, 0x26861c593190929eu64], [0xdf2b4fcccaa0a796u64, 0x30043bfa01748cb0u64, 0x6ccb996bb23cd7cfu64, 0x0605b43a1d6a080cu64]),
-    ([0x1d84aa751126393du64, 0x696ebcd95ee8418bu64, 0x4792b4a1d22fd559u64, 0x171e408acf16f265u64], [0xf6aef0e9ee0a8bf7u64, 0x0e52887a6ceb6a80u64, 0x97c1e11db09a3ff1u64, 0x2f8ba79a63186ff6u64]),
-    ([0xc534e8e023b8f962u64, 0x8a9e33e873dc3ff5u64, 0xe051cdfbd1a31b90u64, 0x2780562390419aaeu64], [0x84bc44ede5bdf5f6u64, 0x179bd93b683d6d90u64, 0x8a06a4e40ec64360u64, 0x00fd83ced6a92f30u64]),
-    ([0xa1dbfa2a7e92cf8au64, 0xa6ebef4b56419cd6u64, 0xdf178d496c62923bu64, 0x297b8045c0d50d60u64], [0xc3f8303d81ad33eeu64, 0x56130c5b39b2666eu64, 0xf9cfff4107dfa002u64, 0x15191f77c29b65b0u64]),
-    ([0x092150936f74f33cu64, 0x08c6e026da85fc3cu64, 0xe254cdffcf1d3d2fu64, 0x1bd585391ecc8f3cu64], [0x1da73f4eb5bb492eu64, 0x68b808c3b92bfaafu64, 0x66474230df0ec93bu64, 0x1f0fd079025d2ec0u64]),
-    ([0xcadf6387afbd1227u64, 0xaa637d55c84fb997u64, 0xd4c3cf5223edc4a6u64, 0x0dee9ed3e047716fu64], [0x824a76b8df8e5993u64, 0x29fc974ab4c78718u64, 0xeb9b69f0c65f8d5du64, 0x1eefdbeff5b5ae6fu64]),
-    ([0xf2a3d6bbda7fba2du64, 0xf6f4d360a0d60346u64, 0x83c80a4128afedd0u64, 0x04326ca0fbbd4be6u64], [0x81c9f612ed88fa15u64, 0xadfda4373f1bf05au64, 0xcd5870727ff03acfu64, 0x0fc48652b6a7197du64]),
-    ([0x63ea496c8e0444b7u64, 0xbe127540d643b053u64, 0xcc303a35b4981bb3u64, 0x08309d1a81f0b072u64], [0xda7dc34326e9223du64, 0x7f462cc7c4592112u64, 0xe9183fd8065d1904u64, 0x1c074279d876e38au64]),
-    ([0x68b810a6daefa625u64, 0x9f7cfbe184e8b012u64, 0x2c819a3f38a57035u64, 0x1aebe78e6cd617feu64], [0x3d8f237e67a0a032u64, 0xdf6eef30357f4b69u64, 0x3d72f1bf669a39e5u64, 0x2014327c039a205cu64]),
-    ([0x7accd520177c78d4u64, 0xce3e7f718174c015u64, 0xa86740820f3411a3u64, 0x1215882851db6d83u64], [0x7fac3d0c0980c908u64, 0x0c45b75d37d02253u64, 0x0547c2245989a923u64, 0x209b1771f7dc309eu64]),
-    ([0xdae6c9455cf2b8b6u64, 0x052c3cb8b8bee1feu64, 0xfc47d3e28bee9249u64, 0x099c6997a163bd23u64], [0x5391432331c89c03u64, 0xdf85c29ad94838dau64, 0x86d461adc24d79a5u64, 0x17279ce214b60cb2u64]),
-    ([0xf9c74260046eaac6u64, 0x79895f8cc4993330u64, 0x3381c458093d0419u64, 0x07fe2d1526803638u64], [0x64b6584f68a11d2eu64, 0x79078fe1844a7354u64, 0x3f5b8dbde294d391u64, 0x2c26837f90b00de2u64]),
-    ([0xa5c59a87383d46f7u64, 0x171b3d0d78447628u64, 0x29a5f7f7d0741d7au64, 0x292ba0a2ace36e21u64], [0xf19124a98b68656eu64, 0x42eb01ee9cf7adfdu64, 0xa4dcaaab9c40537au64, 0x19bcaf997245fdf6u64]),
-    ([0xfe30b1f0b29fbeaeu64, 0x559d392817429bdfu64, 0x0ec79e8dfe918dafu64, 0x2987cbe96f2f7f56u64], [0x5594339d5cb13c1cu64, 0xaef16c431bd9976du64, 0x436547b2bae43af0u64, 0x27a0bc4090184200u64]),
-    ([0x945b59857daf273du64, 0x1f530f09140999a4u64, 0xe497b99647fd3327u64, 0x0d4ec9dbd35740f5u64], [0x503aa4a8edd8b32du64, 0xd758641195a25061u64, 0xf410c75aff88d748u64, 0x2b465418866ce224u64]),
-    ([0xb799ac618c76b0f0u64, 0x706246760c21ae21u64, 0xc8ee71cd1db7e32eu64, 0x2b92c565b4a99052u64], [0x96cf92eb4d3354acu64, 0x5714b78fa89a52d8u64, 0xddb159f4d8c30e85u64, 0x14a08bdad9137873u64]),
-    ([0xba927bb801e5562du64, 0x08488b3e3db08ba1u64, 0xa5f9051c61e35ad1u64, 0x2ebb9b0ab0802a7fu64], [0xe621a9e98762f366u64, 0x9f872faab6afaf5du64, 0xbecabbfcdcd68377u64, 0x2b1a4b7cbbf6d499u64]),
-    ([0x3c0574427c600b8au64, 0x62c68e9b9609bccbu64, 0x3994733ab8a33619u64, 0x12b1187d4e8c011fu64], [0xccfc28cadbc6f2c0u64, 0x3cd2b43ae143bec9u64, 0xeec316deb59f2561u64, 0x114ad9608b4c4ff1u64]),
-    ([0xac154fe8f870d807u64, 0x64adc2c6287715c6u64, 0x9f2d715d79236300u64, 0x2dbbd63a617b2612u64], [0xbbf4191800a176fbu64, 0xeeb4d9e09cd7e01bu64, 0xac32850d9101cf52u64, 0x228a64434ace144eu64]),
-    ([0x40bd245c971b2447u64, 0x7e3f2f33deb33d9bu64, 0x81afa4dc09f66bc3u64, 0x1332cb377d53b9c6u64], [0xd17e9360b3cf2687u64, 0xaa7065cf3984bc3fu64, 0xa64d9babbd5446e1u64, 0x1512c0539657dd6bu64]),
-    ([0x2a0e64fe7ff38554u64, 0x060c6d80abb1ac7au64, 0xb680f3c0c6624690u64, 0x178f1f94a66ae593u64], [0x082f085f5bf583eau64, 0xfbba467fe53604cfu64, 0x8286aa54f3cc7ae4u64, 0x139add460845e154u64]),
-    ([0xa4bc8802abd4df09u64, 0x867a9949c71be696u64, 0x61e8b4218afdf67au64, 0x02fc3aa9906d2589u64], [0x551dee1cf2fbeb96u64, 0x1aaea690f8ad6029u64, 0xbfe54e353e47d300u64, 0x1438d8dc33e35474u64]),
-    ([0xaea3373cf9feff9cu64, 0x75d935ea453d2f38u64, 0x2d00c03f2375a1c0u64, 0x19259ef82d2da83au64], [0x976b45d4a0719340u64, 0x5f93e22d06c4884du64, 0xb635d8ec8c6430a2u64, 0x280f831fe5220989u64]),
-    ([0x459589e6d3c45157u64, 0x714c40038238bd7eu64, 0x54f79622d6cf7b53u64, 0x0961c3d5e9a4e83fu64], [0x078a57f8b49eabb3u64, 0x2406008a7274a0b4u64, 0x32f6d2e9e599d8c6u64, 0x1f162e685094a05cu64]),
-    ([0x76d66e690e502babu64, 0x89c8a1ab3f01840du64, 0x540116855981f464u64, 0x21e9cf3fd9db8c4cu64], [0x1fa86eb323323070u64, 0x72c83d43eecfe4f6u64, 0xbeef1fbf198669f7u64, 0x128c9258f0380361u64]),
-    ([0x1974fcbbbbea9e01u64, 0xd3634b0c8733966du64, 0xf038933ee72c5697u64, 0x0bea01d2a9531edfu64], [0x016b05ae40dc6a18u64, 0xcfffae8c4f82f90bu64, 0x7eeedaa3f903284eu64, 0x2fe4a22c61a06c59u64]),
-    ([0x75c8c424fb574e06u64, 0xb69711555f531950u64, 0x0cb151eb1f80fedeu64, 0x10e5b7037a237fb0u64], [0x4ae4a9dcd6917df6u64, 0x1eb31c0b968d738du64, 0x04d9fe0c4f01733au64, 0x2d99c90b3498ebf0u64]),
-    ([0x0c71518955cbc671u64, 0x562137739dc0cb21u64, 0x75cb9bbe77219440u64, 0x2f83030f7c8d218du64], [0x6b30e0bed8267bbeu64, 0xe64c6a0f471c62f2u64, 0xc6e9c14ed0ea90ebu64, 0x1dc276b361e6eb3eu64]),
-    ([0xbd08d99309c4bb7du64, 0xb135ea330f1b1573u64, 0x19f77c5cf2cbd2deu64, 0x2ad9021ed07c42abu64], [0xe617c0386b19e5a3u64, 0xd7a23f940e1076d2u64, 0xb95da8c1fd91badau64, 0x29786e668ec69848u64]),
-    ([0xa8d13fb72226a414u64, 0x0227b8268be19cedu64, 0xcfd1e986433dd5f7u64, 0x06eb8b1f7e497f75u64], [0xfe08b3088e1b0700u64, 0x3708f54bea4d5e2au64, 0x2382115246c1e27du64, 0x20cd5e85bd310f86u64]),
-    ([0xa9d2522904322849u64, 0xa2530ca1b9760c66u64, 0xa8686fb72bc97dabu64, 0x1a3148d088f5471au64], [0xbab96665979f09fbu64, 0x2611574340927ff7u64, 0x671295b8dbc7b89fu64, 0x2c1fefb9eae6433du64]),
-    ([0xb8177382ed61ca92u64, 0xb76878aaa9d664abu64, 0x1ce0fe7002990f2au64, 0x1caa9a5870585f5fu64], [0x86a08fab6a2d434au64, 0xdc0111efbf4da732u64, 0x4492b06a56cc245eu64, 0x178ce8b75d83a3eeu64]),
-    ([0x449b3f6b3d2ca5cdu64, 0x38f1a39734905cd7u64, 0x16957927ff49033fu64, 0x10fa108d03ee416fu64], [0x6323972b4c7918a7u64, 0x28a1d7899c7cc8d8u64, 0x0fcb03851a4e11f2u64, 0x220e7c668d721740u64]),
-    ([0xc6e12eeec329e76cu64, 0x1e64fda99662d1b0u64, 0x1fbbdd718337da52u64, 0x260efd9b2647640bu64], [0x6a2cbd634aba7c19u64, 0xfa3150cc9580331eu64, 0xbc2abecaae317bbcu64, 0x0849801ec3bdb4f6u64]),
-    ([0x5cd387514001b5f2u64, 0xdc7ba57e33a69426u64, 0x3e1ffdfa41f7a502u64, 0x2b34bc3e86f47a5fu64], [0x7d53042d43a3797eu64, 0x6d965eabb47a97d8u64, 0xa0f55df3db467040u64, 0x16c3cbbd9df38098u64]),
-    ([0xf233ae54be0a923cu64, 0xa1786efc9511b245u64, 0x1ee13b09c52de6bcu64, 0x167a75c0b5cf9962u64], [0x9a9696e944f59b8cu64, 0xdeb1ec08e4804b8fu64, 0x73bd492d6083c0dcu64, 0x12cd7a18c9ea84f5u64]),
-    ([0x17546d9bad43121bu64, 0x7015bff637e2c57bu64, 0x86e272003507111fu64, 0x0ef8f28cf6e9ab0eu64], [0x15074b431f55fd91u64, 0xaf52d357b4096a24u64, 0xe57111c58c8a5e28u64, 0x05be86e05abfd83au64]),
-    ([0xa8e6d4ec3f95deb4u64, 0x0e72106e10c840f0u64, 0xd2f86df4800544c7u64, 0x2db0bef75d0779a3u64], [0xf9859f6359204cb8u64, 0x2da10261b3db8459u64, 0x1732320f0de4716eu64, 0x24a9f380e2f1739au64]),
-    ([0xbf73861b2875197cu64, 0x59bfab4cac67784fu64, 0x644e1f8ec17bdae0u64, 0x02f93f8ab2b64230u64], [0xabd53c9087990c7eu64, 0x9c74729fdc2fd089u64, 0xdd7e74452b73ddaeu64, 0x2953b1f349bf5642u64]),
-    ([0x5cb6659bd001a3d6u64, 0x49e711b369d1b49du64, 0xf177af1cd0049fdcu64, 0x301388aaee35f060u64], [0x2148f82ed2f83548u64, 0xe18d4cf00f0d3774u64, 0xd44a8058cf1fe765u64, 0x007335312e69c83eu64]),
-    ([0x87434dfc244926fcu64, 0x62a4c185a00d7faeu64, 0x99c26c44e486c446u64, 0x0df8b25afde2432eu64], [0xf735b439df566175u64, 0xd050fb7b8e6188d8u64, 0x446df2e949fe7670u64, 0x11b496bc65db5b30u64]),
-    ([0xbe46c023a9f4698eu64, 0x4e5bcc1a9578592cu64, 0x3c565d7530c8fa5au64, 0x0c15d17710149ac4u64], [0xe381ae420b0a9b2bu64, 0xe0b266e36d211cc8u64, 0x990264e7a43e6730u64, 0x2e425c5d8d9fc468u64]),
-    ([0x368fb64c5fea992au64, 0xbd41e26877960955u64, 0x77ff69f6b795d453u64, 0x128d79a68e91999du64], [0xb198c24e5ebe0c3du64, 0x0a957c1fe4a6de26u64, 0x06ece9317b3f3b7eu64, 0x030aaeb48a6b3c87u64]),
-    ([0xad79bb735122eaffu64, 0xb549a4339817a76du64, 0x799c9d09a3f7fe1au64, 0x2b57e6afaf13fbd6u64], [0x29d610c37adf9062u64, 0x099d6733b2b139e1u64, 0xfb4d50c70e53ee28u64, 0x2e70edec77d48ff2u64]),
-    ([0x89210738742f7697u64, 0xd824eac515153530u64, 0x62bea59350315855u64, 0x1bd6c4ca60e67683u64], [0xcb889ff0bafb12d3u64, 0x3a430a86ed7e8f0eu64, 0x4afe2d09d81bf595u64, 0x1f8c03bc37728064u64]),
-    ([0x2941f60045f87a93u64, 0x87947f1cab44aaa1u64, 0xfff4ed7a4e7c81e1u64, 0x0992ecfe4c12932du64], [0xba3f171cd9882470u64, 0xf9527c2ca3d0a824u64, 0x998524da08b9a385u64, 0x0c72fc50f0e31592u64]),
-    ([0x4247263b4e135a4bu64, 0x227d4052291926fcu64, 0x900c96aab14a2f48u64, 0x198d0072424275a8u64], [0x7a214606b4e2732eu64, 0x11aee2c828aa2dd2u64, 0x2a006ae604267255u64, 0x07836882d8ddfee7u64]),
-    ([0xf077edfcd0fa7fd3u64, 0x555df3e8c7ee146du64, 0x986cf6fcf29df606u64, 0x18d9c62300e2ec52u64], [0x4d0342f5580df184u64, 0xd55513669c6674e8u64, 0x060709ebd4b77ac7u64, 0x0c7d79b9990be88du64]),
-    ([0xe1968e7b315a9ebcu64, 0xb140049138df5676u64, 0xd1292bf47926f616u64, 0x2c14f7981202df04u64], [0xbe72b6059aa7eae4u64, 0xb7728beb2336aabeu64, 0xf38bb3446a2de3ffu64, 0x05dfb30ec6ee1ed3u64]),
-    ([0x3a88b63e2bc2481bu64, 0x0fa1eee235d73deeu64, 0x26e34c44bb0656b3u64, 0x0cb61e4933bbc98fu64], [0xce47e106bd7ef52bu64, 0x194bf5e43829360au64, 0x88e5a8de7fb15744u64, 0x2fa6a4bcd93bd2afu64]),
-    ([0xb045bae725f5acddu64, 0xab4a689969767da9u64, 0xe35b76ed0441d69cu64, 0x1fe785c954cb5dfau64], [0x562541e6dda88a36u64, 0x0b28374d7517aa68u64, 0x10098fddfbce06b2u64, 0x18630ec342db169au64]),
-    ([0xfff5741b3d1b5176u64, 0x12ee32c998865c5du64, 0x7ffcf10474871612u64, 0x12275f14792a3f6bu64], [0x850a5444c1315d23u64, 0x22e8aa6b14361416u64, 0xe5d3ed60bdb0ecd0u64, 0x0b65d25f9063db50u64]),
-    ([0x7f43b666c670386eu64, 0x7e7e141114177d72u64, 0x13ff8b5420911eb1u64, 0x2f473900add74284u64], [0xff596396d7615adeu64, 0xae52cb534280b2e8u64, 0x2dd89dd28181c7d5u64, 0x10352a54372edcbfu64]),
-    ([0xaf31c9e37f7b592cu64, 0xcfc622fccc91131cu64, 0xcc682a52fc196fc2u64, 0x01a1f81878782718u64], [0x9060cf42c4da13b3u64, 0x638ee756b97ad5aeu64, 0x136ab82dd4f93fa3u64, 0x1708921f947d3a18u64]),
-    ([0xbf265801fbb10a34u64, 0x6ad1d6af925edcdbu64, 0x38cbcd3a68c583b7u64, 0x275a00a86f73240au64], [0x121da403388b87c5u64, 0x2332d9b2942bcc0cu64, 0x1d1551e238ba659au64, 0x0b21f1c4fd73421au64]),
-    ([0xa55fd09a0a72dfbcu64, 0xa0d05138dc040305u64, 0xb82422ec185d34ffu64, 0x0c6ca5ae46d68044u64], [0x5cdc80e36dcd4d6du64, 0xb0b26036013360f3u64, 0x945a564af092778du64, 0x2639aa50b4e31968u64]),
-    ([0xbd100ec869a95b7cu64, 0xf1d2369c233fb8d4u64, 0x749e3e37ba247153u64, 0x0226bad48e473d3du64], [0xf84b3d6defca3124u64, 0x0e0a5c899f8affbbu64, 0x550279afb11a2bc8u64, 0x12c65bb3d5f6861eu64]),
-    ([0x7678481c9b70524du64, 0x439846b704b60545u64, 0xcb9ca957e495e263u64, 0x032e9c118a50dd80u64], [0x91e591e371e1078fu64, 0xecd3255c763c594fu64, 0x2b28155712fd9e4eu64, 0x1ca4fbf93d966f76u64]),
-    ([0xf6eee1fcc9173126u64, 0x30706a3ce6d8766du64, 0x0c92028e96d64810u64, 0x1b51a22554404c74u64], [0x31f5c55336370f64u64, 0x8fcdf0399f7c9eb0u64, 0x1b83a91abe5e1085u64, 0x021edbd56bb0388du64]),
-    ([0x20cff123608fc9cbu64, 0xcb49c3517c4604a5u64, 0xb3c4d79d41a91758u64, 0x0000000000000000u64], [0x9409cc787b7c2bceu64, 0x146ccd0cae3879ccu64, 0xb6b2671cfa5e9506u64, 0x24143e81b7977dd3u64]),
-    ([0x701044c4791c09e4u64, 0xf2b1bc36552827abu64, 0x02110e8caf75d8e0u64, 0x09788faaf135fa57u64], [0xfc97d69fe8447819u64, 0x498fef076e09ebd5u64, 0x7cf758d86c338926u64, 0x07b866fcaf259e8fu64]),
-    ([0x21863f5450992c89u64, 0xb44e59ef843e5688u64, 0x3918f1b0162eb3cfu64, 0x13fbfd22c02e3b06u64], [0x5b850ad312241af8u64, 0x33fbe5540b104d02u64, 0xdcf3328ab07084aeu64, 0x18bc93770eb98bc0u64]),
-    ([0xc5d5e5d6193932ccu64, 0xd5a7760a09165f6fu64, 0x3ec17a64cf618aa1u64, 0x2e7654dcec1f27e5u64], [0xb7154509ff3cd6a5u64, 0x3140e0571c4b145du64, 0xa25fe452364a322fu64, 0x1ecedf0dcbade9dcu64]),
-    ([0x2a6289b133dfc1c9u64, 0xbce464105501716eu64, 0xf889ee5946fc5cdau64, 0x0232218117eaabafu64], [0xfdbd5281aa696f79u64, 0xbce2bb82f7991c1bu64, 0x81b4f0c01eb45213u64, 0x1fa0aa74b172010fu64]),
-    ([0x1bb850d18928c4d6u64, 0x0614154519e00d29u64, 0x44350366fc4e5aaeu64, 0x1a285de02c19fb0eu64], [0x5acb1b559ca5b709u64, 0x865471c140cd113bu64, 0xaca99e3f50b81b93u64, 0x2a75eb78759d3ac2u64]),
-    ([0x8de950af840af1a2u64, 0x74c0c0cb03801b23u64, 0x2287eaa669081b06u64, 0x11757627a7e36172u64], [0x6c77bde76845e263u64, 0xedf274a8ffb694f6u64, 0x54689016922f4d4cu64, 0x0c7ca2411263543eu64]),
-    ([0x71734f5fb4ba5bd7u64, 0xbde56c5a92a6b8e5u64, 0xba51f42ba04f64ddu64, 0x15f08723e8dff3c8u64], [0xf6523efdb3a5fdceu64, 0xb5125df016c4e8bbu64, 0x684852adbd0f71b2u64, 0x1192fcad807f4992u64]),
-    ([0x8c9e954b942e6d6bu64, 0x3df9cff80535c8d0u64, 0x49f7a010aeec0daeu64, 0x2822ef9d2d155c2bu64], [0x6f05d2c349d042eeu64, 0xbd74e32a186e9303u64, 0x59597ac74445e71eu64, 0x1f3f507bdcd33a42u64]),
-    ([0x11635dbb8acf01a9u64, 0xbf69b4ad02b9facbu64, 0xb1627d7430810109u64, 0x1334f5595e6978deu64], [0xdb386210daf0db47u64, 0x83c84282840874b3u64, 0x83c199ed4e0e4bc7u64, 0x126378b4845eb8ceu64]),
-    ([0x1450bd76f360033eu64, 0xd5e4cda782f9e74du64, 0x56d31a45af05f1e2u64, 0x2fbd646ed355aa3eu64], [0x8ed94aa2503b669bu64, 0x6fb74dd1daf13cbeu64, 0xa71b76d77b0b30ecu64, 0x2d6880f723844a89u64]),
-    ([0xb02132be9f50f341u64, 0xa7bf09025be4b300u64, 0xc4f222278091555bu64, 0x0603dbb74e6cf5dau64], [0x6392557882fc81beu64, 0x59283327457824d5u64, 0x4206edbe6e8ce626u64, 0x2761c3a22dd3efa4u64]),
-    ([0x5abf8b0e730abb71u64, 0xbf8d3474d913dad0u64, 0xfcfaf1b613f7c440u64, 0x1e2e9febbb22f135u64], [0x7745c706e4b93f88u64, 0x0d688ba2ac918c80u64, 0xe6fb03ea89a58183u64, 0x0385b6c36ebc7cafu64]),
-    ([0x4012bbea7cce7a7cu64, 0x26429d20e02d43aeu64, 0x0a5a4cba78ea53a3u64, 0x0d673e09de56bbeeu64], [0x4a3b57958fd89e6du64, 0x2efc7db68c9a721eu64, 0x2257512cce606312u64, 0x0ac0a5ad6080bb27u64]),
-    ([0xc02adddfa1e09769u64, 0xc9f5a130694f6ee4u64, 0x6f1cbb9734d18eaau64, 0x00b7db3c208f333cu64], [0xd72080e10570a4a6u64, 0x9a9292797ef0bbd6u64, 0x3a7a073f18e9e8f6u64, 0x1aa60d58e2b3001cu64]),
-    ([0x3f33364da4142aa7u64, 0x38537f7210841ffbu64, 0xf672b7c2a44b24e9u64, 0x2400fd3cfb07c622u64], [0x81f6e321d2093d6du64, 0x36cc01167cd68d97u64, 0x15899d26b6c3b995u64, 0x2c0585cb3d74a45bu64]),
-    ([0x50bfc460561cc11du64, 0x8f7d9b61443b424fu64, 0x973c4893c9c8c964u64, 0x026da9f7ecfca128u64], [0xdd142b4b5a729d08u64, 0x17137dd4e48251fbu64, 0xd44fb6aa71a38137u64, 0x0aab0b47f84ec73eu64]),
-    ([0xf9596fea87a20f45u64, 0x645cd6b2cf41a9b7u64, 0xd4581f39dd95cdefu64, 0x0fb2c3d1f5588303u64], [0xb986ab0a399462e1u64, 0x19f45509cdec5b6au64, 0x7e27dd9ba905bda9u64, 0x082d037997e27cfau64]),
-    ([0x7b4204dad5c73f3bu64, 0xf5cad88088b7d7e1u64, 0xd39c4ff638982f70u64, 0x1d3b322ca42996f5u64], [0x4be4dacffa411cb0u64, 0x47d23d9239da514eu64, 0x09c957395ae778c9u64, 0x209e82edd3ce46eeu64]),
-    ([0x2c18526a51665d99u64, 0x6c5b004ea1d0ef5fu64, 0x5e84708a2ad9ed5cu64, 0x23d0e5d288bb6613u64], [0x76cb15c0939a63eau64, 0x39e96eef5e5e068bu64, 0x5cecf3e49432b53cu64, 0x0ab1e547abf41d31u64]),
-    ([0x3c4f2278d25b9ab9u64, 0x65413ff06f006d80u64, 0xf28e41c3c3f062f8u64, 0x1151a1634718c0d9u64], [0xd5c809810c51bbddu64, 0x08ec98f9eea44898u64, 0xe334b831be1223cbu64, 0x0f13e1188a086cc8u64]),
-    ([0xafa30ce1d1cdb768u64, 0x696761f33e9bcf00u64, 0xb4856a4ced449bd9u64, 0x269e7da5a8822f4fu64], [0x623ef25a5f4bd8f2u64, 0x82edc4b00ce5e99fu64, 0x8e95749b0315738bu64, 0x105b4ad189574171u64]),
-    ([0x49d96cffa5ff9c22u64, 0x145a7e0a26ecfa62u64, 0xe708e46bcc28551au64, 0x00ec37f0a99f0d18u64], [0xb672fccf66462e45u64, 0xcf7099a67ff942ecu64, 0x7cdecf21437b781bu64, 0x26900c50686e2b2cu64]),
-    ([0x01d76467b3d665a6u64, 0x0334a70811a583d8u64, 0x06e624d24a61649fu64, 0x2f376592ce23f303u64], [0xca479a27ece915d5u64, 0x2483ac0ac3fb74ecu64, 0xbb0809a1b8ba4608u64, 0x0e200e0c388e987du64]),
-    ([0xfa639e4cd6364952u64, 0xde289cfb484710c1u64, 0x6175093317c2500au64, 0x053dd7a89672ff8eu64], [0x76215a5fa633c4fcu64, 0xd844f996b2fee899u64, 0xb2ad5e211b843e8bu64, 0x09bc899974b9dbbeu64]),
-    ([0xa636798b1e2cf2e1u64, 0xdd85e79e0f58479fu64, 0xb6f7fe50a9d72b35u64, 0x0734e9925b55c5e6u64], [0x9b7d6a1236b5d5fcu64, 0xc79dde8d7facd47fu64, 0x5bdde3c4b6e13942u64, 0x152f637b9f16dcebu64]),
-    ([0x47317d488f39a0d0u64, 0x0d515f67c70b3d8du64, 0xe136eaac35fa4faeu64, 0x2035ba6a2f8bbe55u64], [0x381389c49251bb09u64, 0xe51c8bf693949775u64, 0x005eaa343e03f141u64, 0x28ceee9519b36fc1u64]),
-    ([0xa048b5deee3b8c0cu64, 0x0a07abc5b0d36c05u64, 0x55ef0c61fac37227u64, 0x0f5d15475d3359c6u64], [0x96264c6ac05542bfu64, 0x4e7a820a4b238a06u64, 0xf1b344334c1e8789u64, 0x09d92b80b2a30917u64]),
-    ([0x2b64feabb9b2ebd6u64, 0xa9fea8b0225c93bfu64, 0x35e2d7a80bd77ca0u64, 0x1164901c614087acu64], [0xc3c5bc4bb8f0576bu64, 0x5ff23d8bed6f2cf3u64, 0x29b658baddb791d4u64, 0x162c7bac5fd3515cu64]),
-    ([0x533f4edc5712596eu64, 0xc3c2be7cedfc0a53u64, 0xa9bb46a7953e7ebfu64, 0x0ad6f3f0440da25au64], [0x1c6556371e0cf206u64, 0xd133132f8a2de898u64, 0xd263a954fa6fa2b5u64, 0x26981864c14e1ab8u64]),
-    ([0xe45dd21e88e7a9bau64, 0x6484f859d16ee06au64, 0xa435701303e3285du64, 0x0bdc8b2f57ad5be9u64], [0x2788c49610035ed6u64, 0x07d4716e8ea914a2u64, 0xe92c8137f75a8b12u64, 0x033a1b9e423d71c9u64]),
-    ([0x92d6dfa64b1c4e1eu64, 0x4846bc8eed1b25b0u64, 0x8a679cc2157d8b7du64, 0x2580851320f595cau64], [0xac8608a978f8792au64, 0xe798790b0a62e1b4u64, 0xdbb16f0ba6a54651u64, 0x02ef19026c125209u64]),
-    ([0xdefa7dc68a4460b9u64, 0x99fed0a4d566f73du64, 0x0f72414bd5308efau64, 0x0e2ab23ecd95a07cu64], [0x22d16279aa77263eu64, 0x330b4eff40ce433du64, 0x5ee739df347e1123u64, 0x29ff7ebd42c33038u64]),
-    ([0x8594d44eb8482e84u64, 0x96adb9d2118a3669u64, 0xa27cddd5b8732887u64, 0x0c39854c614c4a87u64], [0x96bad23bbe2be3e7u64, 0x49ce403bc575d9f2u64, 0x57fc895baaeef71du64, 0x2b0ff550ccc2bba8u64]),
-    ([0xa015ada9f2b60088u64, 0x4f358e2a082bfa1au64, 0x797f69e59544771eu64, 0x080f951201b7cf6cu64], [0x05ba28833bd66ba2u64, 0x6747c0c5f55c5c53u64, 0x356e892703cafbedu64, 0x0b5f9755311a4cc5u64]),
-    ([0x39eb5265c986a2beu64, 0x514682bac041db33u64, 0xd7b69e0c43543463u64, 0x2eaa4fa368cf324bu64], [0x4757eeff85450d86u64, 0xfcb66cafafdbc476u64, 0xd0b9d3196a06b8efu64, 0x01382c8b46843701u64]),
-    ([0xc5abdab1e9884d88u64, 0x4106b3c98d4e8d9du64, 0x498365348e19a436u64, 0x00c42ac38617ec5fu64], [0xc0cba440e8a875b0u64, 0x237502beefb8a3d5u64, 0x01ad17ebae2f60fbu64, 0x127f5e02f8f58c06u64]),
-    ([0x4acb7e7c1d2bc24bu64, 0x2b0e2160496c3c29u64, 0x6ff15033d2a4fe44u64, 0x1656064c1db7197du64], [0x538586f197bb4399u64, 0x1a8037ef33ecf5e3u64, 0x6c4e772cdd9d4ad1u64, 0x0e54d4c984be1796u64]),
-    ([0xae4ff7c73f1f4c19u64, 0xdfb9454c8ff4288au64, 0xea4282e7db850115u64, 0x2f97f83c340b7bb9u64], [0x7e89b2a3d66c14b6u64, 0xd729d070f4460c0au64, 0xb17d602fdd2a8dfdu64, 0x01f356594f791f25u64]),
-    ([0x643fe39cb0006f17u64, 0x60943c779c705142u64, 0xbc2cb8759d236484u64, 0x1f804b16a35a8b9au64], [0x67c3f591d5e5696cu64, 0x60d287240e73e3bbu64, 0x980159f13f58e427u64, 0x29bd8199a3877625u64]),
-    ([0x1dd37a46f3efea43u64, 0x1beeb44bfac842d3u64, 0xa9667b78aff51bfau64, 0x05220cdd3a9cfbacu64], [0x8772ef03c3540377u64, 0xb70760b0c9087b62u64, 0x3d356e336b3db952u64, 0x0d6fcee953577525u64]),
-    ([0x4ed2eed17ef3406cu64, 0x2157350001102cf4u64, 0x3ef9a34b465e954fu64, 0x13b81a7df54e4bf8u64], [0x6358fe6dd537a405u64, 0x87513b565153a9b8u64, 0x45bd4645c76ed49du64, 0x24400876dc345b7fu64]),
-    ([0x0b552811d9a19b37u64, 0x581d10b5e3aba36bu64, 0xeb1f135543e98a5cu64, 0x3027fa820e455306u64], [0x1aac7d019e7f784cu64, 0xd1babd408c5c399au64, 0x1514115d068c4a92u64, 0x27a5f34e9ab71422u64]),
-    ([0xc449d5329f246dc6u64, 0x8cb0fd2f46426eaeu64, 0xde33ff819779adc6u64, 0x1fe79c18ca6054e4u64], [0xbca54497a0b6bae2u64, 0xcbbf0dab5214204du64, 0xf0173b969638919eu64, 0x1a522f11ad9225e0u64]),
-    ([0x6383024b76b85a93u64, 0x415d42172846edf9u64, 0x57af6fb6bbb53413u64, 0x1c263047084eac90u64], [0x6df1707900b41f5fu64, 0xc194504fb92b42aau64, 0xfb3af318feef61cfu64, 0x24271dcad278e3b8u64]),
-    ([0x208bae3a0e7c312cu64, 0xd86b04aecce2bf4eu64, 0x13c9ebf81cd83ae2u64, 0x294386ce49ba3ed1u64], [0x5f49240c983787e1u64, 0x9bb6bc8b6752c598u64, 0x7b2fb6d0df1a21e4u64, 0x2527ae2fb819abd4u64]),
-    ([0x1a3084b572e95556u64, 0x7fd9536151e5db0cu64, 0x9c415f11646a8c5fu64, 0x0e4ba1d153653094u64], [0x30745e7015310bc6u64, 0xd83e6cc881de679fu64, 0xa67d2cd73903de21u64, 0x28fd9c253606a97eu64]),
-    ([0x59e0e25d2626c437u64, 0x95579e3171987586u64, 0x9795f177959df49du64, 0x0753b3867f163212u64], [0x35c309b42663693eu64, 0xd1225817b886e377u64, 0x6cd54a54b4dc0928u64, 0x189465a30526dc64u64]),
-    ([0xb361469e679b8da3u64, 0xba611506eeb0ed3au64, 0xbcf15c160719be39u64, 0x076dc8949aa663a2u64], [0xdbeff5765bdddf74u64, 0x306f9d1a52fe1688u64, 0xd305e02257f9eb8du64, 0x13c08d1a617f52a1u64]),
-    ([0x3b4ece4632c5ee31u64, 0xc7cbb172183cfd3cu64, 0xe114f2240f06754fu64, 0x0ba9162f48a6c210u64], [0x2be5e0bc204e4057u64, 0x48be39c930022ceau64, 0xf23c8e1a49192c0au64, 0x1b147ec71f7cf27eu64]),
-    ([0x9192f74eb17e2981u64, 0x931f9c3bf58cb3edu64, 0x775dc033091af00du64, 0x172bd77d4ac999efu64], [0xf21d29968bb41006u64, 0x4d64c5620dac750cu64, 0x8306eb0dc8bc5312u64, 0x2e177f7a11bcdf40u64]),
-    ([0xb9da2b80c0f04c8bu64, 0xc0a82bb20f6ff722u64, 0x92ca437cfc62889fu64, 0x2db71b52e62509e0u64], [0x2b659450951f91d4u64, 0xeceae48ff32f9ceeu64, 0x47df7a742669cbe4u64, 0x139c9dd48680139eu64]),
-    ([0x2e5f36cd28dce6eeu64, 0x7830c6f1ec49f99bu64, 0xcf7c7972bcef1322u64, 0x22cf7f8d5de37efbu64], [0xc0936bcbbfa89219u64, 0xf496fb2fee07192au64, 0xe76e02494a338bc1u64, 0x159bc25820c4f79fu64]),
-    ([0xb3b523e1a7f97de5u64, 0xac216b69dd6d1e13u64, 0xc018f0bc01c755e6u64, 0x06bdb3bb49fc1e21u64], [0xd0fd091bfd8f8a50u64, 0xc7b843125cd67babu64, 0x3ef47b97ce704733u64, 0x048e2e67ce3fe570u64]),
-    ([0x6caa0212c431bbbau64, 0x135e13f988339692u64, 0xa819245d18c49083u64, 0x2940917fe8da45f8u64], [0xed37a1c171432feeu64, 0x5928921454c6d4a1u64, 0x4a487abcfe312b71u64, 0x1e19f4c78c56c05au64]),
-    ([0xaf1ec78f77e66d10u64, 0xbf132c18ba067fb3u64, 0x6fe74c19e8362b6eu64, 0x09524ae8e64c8437u64], [0x7e47ff28f96a8e97u64, 0x14f3632909057213u64, 0xf1f201f1766dbe36u64, 0x09ac0dd6f6af61a7u64]),
-    ([0x49111abb66c17959u64, 0x3c6b31923cb8c4fdu64, 0x620895023a823028u64, 0x16e8c76334d96d20u64], [0x7a212c1ceb0d30d9u64, 0xf3429e2eeb7707ceu64, 0x22abbe769fab17f0u64, 0x13f141e0344cf4d6u64]),
-    ([0xce81ace529fa73aeu64, 0xe8860290e2147893u64, 0x239a0a53eb7565a3u64, 0x03d768801727f091u64], [0xe7119c175af072e5u64, 0x359e0da8b6751acdu64, 0x3b77818b3ec2a6dcu64, 0x11eff9444540873au64]),
-    ([0xa21c26f797081a01u64, 0x375bf02738c7baa1u64, 0xcb6806c8e4e9d5a8u64, 0x17fac829ff7f9d8eu64], [0x79c5ebb61d969445u64, 0x7550548bee5c587cu64, 0x1c61d5a5b1bde1e6u64, 0x1e26659ab4b781bbu64]),
-    ([0xdcd9c9de1278899au64, 0x03879d7acf5b7de9u64, 0xaae012d6251ba17fu64, 0x0b43c0bc79e4142du64], [0x13b692174bb08444u64, 0x4f553a65bd47f91fu64, 0x9e94fc03a84ebbbdu64, 0x18e5f45e568c126du64]),
-    ([0x7a88fe9c641add9fu64, 0xae57aadebc459293u64, 0xd043aa2d66d4e38eu64, 0x23449c832cabcdfau64], [0x3966fa6ee38a3a3du64, 0x30e41d90d47eaee7u64, 0x294966c7d6ecbf9fu64, 0x162ffc023d519aa1u64]),
-    ([0xaf8bfc03954222a8u64, 0x30895186642e2057u64, 0x94983bd95fe91f40u64, 0x304a0f3a7a7d9b4fu64], [0x7eb1a033098bcb95u64, 0xcf04aad470778ec1u64, 0xaae54aeaedf8892bu64, 0x290b5c7758e506b8u64]),
-    ([0x2c42a779575f4ef1u64, 0x8515a7a50bc4cccau64, 0x75b4a3fb0aa0407au64, 0x1d72c41ce961a840u64], [0xef9dcf1f613c810eu64, 0x7a6b8e193f0a7293u64, 0x66eb85d4d5b8d79eu64, 0x1a9dce3e8c31c300u64]),
-    ([0x2564fc2466c21e65u64, 0x395005bb3800c53fu64, 0x3ab15193b0cab930u64, 0x0f2a0cb9a17c95d7u64], [0x1fb614818502708eu64, 0x80dc5598e7d535eau64, 0xa2155bd054bfd26fu64, 0x27bdca2674b4be18u64]),
-    ([0x307da9a4be13c42eu64, 0xc4d558439e2d34f4u64, 0x00b52c71a6655715u64, 0x0dd360411caed097u64], [0x539eca5710517807u64, 0xe551dc9e108a0fe7u64, 0xaff713a67eb02531u64, 0x20b50afb7e736376u64]),
-    ([0xa6d2840a79c3c946u64, 0x8717aa1d84a681fdu64, 0x02d3da7e8e23e5f8u64, 0x28f01da0d5fd26cfu64], [0x89cf5f9c861dd47au64, 0xaa561ff05219ff88u64, 0x8ae6aeddd1f8948bu64, 0x14ce4942195b2ac8u64]),
-    ([0xff55320bd91a6afau64, 0x8fcc77e2d57d60e9u64, 0xbb79e191d9664a8du64, 0x11c5a7ca97728d08u64], [0xa55af16e794b72d2u64, 0x8f03f078386f4daau64, 0x194e0a3b3eb9f95eu64, 0x06dd75b14de7297eu64]),
-    ([0xeb698e854369463fu64, 0xf399a053f424a37fu64, 0xe0ff749389892669u64, 0x109ba8ec620f3304u64], [0xef3bee97a3e1d448u64, 0x1fd3d5dbfb101bcau64, 0xbb15abcd4230f729u64, 0x18d569879647e02fu64]),
-    ([0xc973b41eab835872u64, 0x63700a44ecc77aaeu64, 0xdf992ae66a5350c0u64, 0x033025f98d011e36u64], [0x85062dd6c2fc3a2au64, 0x9f420142b8a0aed2u64, 0x15e498fe21181966u64, 0x13a463e3dc70a802u64]),
-    ([0xffe48151e809d3feu64, 0x295e421f5343712bu64, 0xff7dd7cf1cdbe2ceu64, 0x01416018afbfa019u64], [0x8dd2580cffc11ac9u64, 0x9ada2ac01a53b2f8u64, 0xac39149f8ad4b794u64, 0x303b36fd8b4225d6u64]),
-    ([0xdcaba0d7745e3fd1u64, 0x4c46d5590e527ce5u64, 0x489c686faacb07b2u64, 0x204f0f768d4aae39u64], [0x4bc009725733148cu64, 0x906da2fa8861892fu64, 0x41ea4f156269d237u64, 0x06d911c9b8b91175u64]),
-    ([0x65e3362d35f3cd6au64, 0x66a6a8e45eb8b98du64, 0x87b7be4ef718ced5u64, 0x116b4668cce76ac4u64], [0x78e77d7ea83a71dau64, 0x8fc49359fe52a8cbu64, 0xba6eebeb5f4e53a0u64, 0x09741661bef908c3u64]),
-    ([0x7bab323605190f35u64, 0x3fbb0791fa17ce42u64, 0x132c6fa2efc3324au64, 0x086bb61f0d7b0734u64], [0xaa7e020cc151e78du64, 0xa8608eeb4a1d1469u64, 0x751a44e29e580d3eu64, 0x03c3f1297f29802cu64]),
-    ([0x3bbeffc2b340ae53u64, 0x94de34dca480f1c3u64, 0x4dde1dd08c35ceb6u64, 0x10d816126b44196fu64], [0xc3be93b613674dc0u64, 0x10b2b466bdc7c8bfu64, 0x65676055d11fed2eu64, 0x089eca851fd2f6d9u64]),
-    ([0x192cb0e9a0f0a02bu64, 0x74668b59a7190bcfu64, 0x94276aecbe94ffb4u64, 0x2c3db24b8d28ad3bu64], [0x67cd57e32f999ddeu64, 0xcc834bbc06d158ecu64, 0x5e0bb8c9e6c2b9b4u64, 0x242524dd407fe151u64]),
-    ([0x6a02e6f8da362e09u64, 0xa3655b680af23963u64, 0xb4fa4f1ee2c246bau64, 0x20924126e95b4b4fu64], [0xd3a276c0990c74bcu64, 0x7c26589711135406u64, 0x8e6e0c2e7a4dc4d6u64, 0x27d9a89d70dfa811u64]),
-    ([0xc6b6700342c92f43u64, 0xff99c0bad6a53878u64, 0x52203d914ce5d1abu64, 0x032c3157064d2b60u64], [0x26b6c041afce05afu64, 0x41fedb276d027ca5u64, 0x30500cfc8cadbd36u64, 0x00f751ec524986bbu64]),
-    ([0x3095050fe4f44c0bu64, 0xfbda7e90a08c35a0u64, 0x44389a55df348081u64, 0x1d993c83e1a25f36u64], [0xd7123f42e8d0d058u64, 0xac558f3bbc7a28d5u64, 0xaacb2c6fd518ab69u64, 0x2d51045e80203eaeu64]),
-    ([0x66dc6ca55b4bad9fu64, 0xc9be14c5a3156d48u64, 0xf9799fa507c7b5ceu64, 0x0dfe1c2a00faba10u64], [0x0c87197cd33480e2u64, 0x53d8ffd056640ecau64, 0x2fbdc9a3f992bef5u64, 0x29cf63b6d496489eu64]),
-    ([0xc9569afa51bc8722u64, 0x3c9a825801182cd5u64, 0xf782685be167bcd1u64, 0x1265245d10c46436u64], [0xbd23ac3529b2b59fu64, 0x6b4bb774aec34cdeu64, 0x956c9f3e56310077u64, 0x1060b5726283313au64]),
-    ([0x2c93abed082abf40u64, 0xb855aaa58fd75adfu64, 0x7b61edbcae0d32dfu64, 0x18df010dc9ce20f0u64], [0x4fbf4243d77da462u64, 0x9402818ef45c1543u64, 0x74158d4a1caa93c2u64, 0x3001d49d11331cd2u64]),
-    ([0x4786f9f11447f17fu64, 0xadfa2aa780f56d91u64, 0x4a540969cc409c3bu64, 0x16f26b2668b220a2u64], [0x8e5a6ac5fb5107e1u64, 0x7cd6d9c7606f3e8fu64, 0x7f2abc22a7e18abbu64, 0x2ca160fe6d6ab44fu64]),
-    ([0xcd41f921451567fdu64, 0xa879722d8a24aa67u64, 0x5cee0527d9fad899u64, 0x1732773f413cbc78u64], [0x6e79a6d31cc5cbffu64, 0xe4b272fcd06c51a3u64, 0x71908191b15fa3cdu64, 0x25a74f4383571455u64]),
-    ([0x1b253cfddaf3a5c9u64, 0x76d923b15d72ba6fu64, 0x5c64db8ca6159ce0u64, 0x27187f3a05a8e216u64], [0x3807ff2d7e3258cau64, 0x92f3f77c963c8a12u64, 0xbcf9b89970433d88u64, 0x1b8117215d3521f9u64]),
-    ([0xceac5845a02ff8c7u64, 0x128987b5a1a76336u64, 0x90ac4b374ac41c4du64, 0x0fec7c3ab22a0af3u64], [0xb87a3276ba092e3du64, 0x84416c5b6092b417u64, 0x19cecc716a1285cdu64, 0x1e8d19a6bb6eb02bu64]),
-    ([0x9b671e352468cd28u64, 0xc45bcd6d53e4c766u64, 0xa19bf430c7e41334u64, 0x130b21349b4e2d16u64], [0xb5471d86eaef7fc7u64, 0x9b07d6830502e0c6u64, 0x0a9c320ac5ea0bd2u64, 0x21cdc69a8d98661eu64]),
-    ([0x08674790d0611d10u64, 0x8c8e7180d79f983eu64, 0x559c69a48a11a1a9u64, 0x1e03cdd997be8d7cu64], [0xe048e21fd864c163u64, 0x1b09b68a9858fc74u64, 0x4a71b719ec97e113u64, 0x149def4420daa2c3u64]),
-    ([0x8773bf11ceb453bcu64, 0x1227a64198bb620cu64, 0x272d2469e2eda54fu64, 0x06f5e8c54390f285u64], [0xcd2ac45d76907b59u64, 0x9bcb1a83403ded7au64, 0x5d1c12b32d0e6e40u64, 0x111f86983d839737u64]),
-    ([0x0e1e7889c9fb5532u64, 0x9875150ab5bf555cu64, 0xdbc2c469c820a91fu64, 0x12424c202fab97a0u64], [0xaf1452f56ae17623u64, 0x9737da932070ab55u64, 0x358bb9005f1831d0u64, 0x241d9e071d6a2cdcu64]),
-    ([0xa2280387e8408a69u64, 0x0e7b9ba309df59b9u64, 0x2a98f9461a711c3du64, 0x0be2be1d0104636eu64], [0x71dd44b962bd06fau64, 0xac09a2380420ae05u64, 0xa534901e57757dfeu64, 0x1267f44a657a675du64]),
-    ([0xfed0b320f99f2b69u64, 0x103b5301c0ffe53au64, 0x9e46f6b96b21c87du64, 0x10a7c9aa30b4edfau64], [0xafd3d54551b24148u64, 0x450dab99332e94e2u64, 0x957a128320563c57u64, 0x20c6a56e735447c4u64]),
-    ([0x6cfe97cf19e0f3aeu64, 0x25556c10356f6a9du64, 0x2edf297a348b4309u64, 0x04d13eacaa9a3d3fu64], [0x3cb129f56d8db5c4u64, 0x31a53bde668a925cu64, 0x72b400a19ce4f78du64, 0x2e76a665733e3fcfu64]),
-    ([0xc75487cd2d20702du64, 0x448c85565bb50e26u64, 0xa754a70dba2a6e04u64, 0x16ac76cdf795c1b9u64], [0x02a1514d6bb61a43u64, 0xe6305254952e17efu64, 0x07167b958abe1264u64, 0x137c4418a661aad9u64]),
-    ([0xd549fa4a2be5d302u64, 0xc207da8b2c71cb33u64, 0x2c6c14c2fc27a028u64, 0x22b55603586d5fc4u64], [0x9493246cedca30b8u64, 0x56f8f09665356d99u64, 0x0bae672b22a9c341u64, 0x244ca193cf5d7cd2u64]),
-    ([0x37d59d68a804c248u64, 0x8566a717d33a4484u64, 0x7bf260c55c38408fu64, 0x24bac94afed18c7au64], [0x3d6071a0b9f43f2eu64, 0x180b82be034587f1u64, 0xfb6c5583f2da7cceu64, 0x20b1058c14b30205u64]),
-    ([0x901ab28aed4cf94fu64, 0xaa911be31203cc5fu64, 0xf68a81ff2e5e0b27u64, 0x2832477c9178e48eu64], [0x4002ca616bea4e3au64, 0x355d0816af9b8b53u64, 0xaca5fc62ca12d9f1u64, 0x27279f59f1cb00dbu64]),
-    ([0xf3761fbe2d4d9d92u64, 0x3dbc8a795e32473du64, 0x7ef4d1f28a8968bfu64, 0x1c008a642ea42d6au64], [0xd17dfc3f885be292u64, 0x6801f9d91a5247dau64, 0xe1eab3c2ed070a5eu64, 0x289d1fd757d456c1u64]),
-    ([0xb776b3a83d0c494au64, 0x26d842efa9746de7u64, 0x05aef488606bf2deu64, 0x16aba94fbaf7a7f5u64], [0xa567ee0177339e8du64, 0x5ccd08599d122efdu64, 0xe9369e644ec99d21u64, 0x201402e0c5f7215au64]),
-    ([0x6095b2a4b7462216u64, 0x49b0eeef522d3824u64, 0xa7c14f64cf98cf10u64, 0x13cf11754a6b3b8eu64], [0x3222d2c3fa60f892u64, 0xe04893cb90b6c2adu64, 0xc1c20cbfc3d64724u64, 0x2cb545c1254465b8u64]),
-    ([0x45a96359938b0b0bu64, 0x68b75c9af3f499fbu64, 0xa85379acb570900eu64, 0x1d412bef6b4e3f44u64], [0x091a715b32aba98cu64, 0xfb834b2f593abb1au64, 0xe25acfd76d27c24au64, 0x0b8cdc605e03df4fu64]),
-    ([0x34f0af8143f71eacu64, 0xfaf0ec705f829b7eu64, 0xf4ef2517cc0356cfu64, 0x2de4f152d3d31ab2u64], [0x663e64565a1c960du64, 0x6f5b84b4a602a826u64, 0xec1251930daa6f05u64, 0x2498214c4e814961u64]),
-    ([0x091c6881cfe2ea9eu64, 0x8dc8a21b673e45d1u64, 0xf98057e6e17b77b2u64, 0x2b919c14d17ea295u64], [0xb485e7f7ff78c665u64, 0xdf25c81a76db9ed0u64, 0x5ecf53a5c263f580u64, 0x05dc386f9f1b0120u64]),
-    ([0x4c46b8855f338f44u64, 0x2dfd9c4584d9f922u64, 0x4805b829c7eba09fu64, 0x228abb94830d21a5u64], [0x0cf3a0ba0a139f37u64, 0xbbdb9cdca540e440u64, 0x5aad3979a732d15au64, 0x24142e0c123727dfu64]),
-    ([0x8fa439b3b9f4d78eu64, 0xa4f4a3a8cfa0c82au64, 0x3cefeeee38ed5b89u64, 0x2d8ecc9e0f393f37u64], [0x42c6a3bbd9b59158u64, 0xbd8c0caae33d3565u64, 0x360ddfac9f546274u64, 0x2c5e0eb193062fc6u64]),
-    ([0x2d3968bdb6cbb818u64, 0x530ba297f861f856u64, 0x708ea58c66623875u64, 0x0bedf358f33c82a5u64], [0x1bb6e7ebdae6b76bu64, 0x303b53e17bb99a27u64, 0xaeaff6c9baa22bd5u64, 0x108f8532a24c2248u64]),
-    ([0x2e7c990e2f42ee67u64, 0xbb63f96fd4c65016u64, 0x5bf7ae66f8977401u64, 0x1d2298fbb9a9036au64], [0xef2661b838ecce8eu64, 0x0d3da0a8b8aa0457u64, 0x0778a21e647c6901u64, 0x2f05c7218bb3ae68u64]),
-    ([0x51b9e88c18155256u64, 0xc32be4fe9d22a152u64, 0xccd0703d1b7382f6u64, 0x0973cc9ed5261efeu64], [0x9599d03f70136555u64, 0x27e05a64328cf209u64, 0xe35e00646b6ed91du64, 0x0d077457da056731u64]),
-    ([0x44c8d800b2103c4cu64, 0x967bf9858f7be2e9u64, 0x2521b693b3720ae8u64, 0x1e8ac4166533eeaeu64], [0xa4df3915941d3270u64, 0xff5413766ef84d18u64, 0x08633d51d9c91e96u64, 0x1a8e9560fa1b78adu64]),
-    ([0x9232f5c4405e2d68u64, 0x382cf9155440516eu64, 0xd24fb2b11f1a052au64, 0x0e9d3fb69514e6b7u64], [0xc80f04f9b388f7aeu64, 0x42078bd4d676e65eu64, 0xff3b0637ec03d003u64, 0x178cb0df47a85635u64]),
-    ([0x720d8f131e3684b8u64, 0xf5223f90086040a7u64, 0xb714a2bb7be7ae5fu64, 0x01e7289b0b7546f9u64], [0x476027e8713dde15u64, 0x5cf32f9fa1dae1a3u64, 0x77ec1e5f7fd1b141u64, 0x03fd1ee78368a170u64]),
-    ([0xad087a8fcfa7c790u64, 0x07fb3c65c9fb972du64, 0x5ed7c995898c3350u64, 0x14e4926de7eded67u64], [0xdd8af32203010293u64, 0x55080313c1b1a2d6u64, 0x79cc35fb0f30f62bu64, 0x1b2bbafe928493ceu64]),
-    ([0x85154eb1ccfddeacu64, 0x57dfa5da5fb56fb9u64, 0x7bd86b4a11e1867du64, 0x073654d37e527ed1u64], [0x5b961159ba1c8d94u64, 0x7e24c1e42601f09bu64, 0x48e07076afe7d6eeu64, 0x06b4b209d0d1e2f2u64]),
-    ([0x72d4a520d33bda27u64, 0xcc740773f7377074u64, 0x119b4051e972627cu64, 0x2d093d7e6d21bbb9u64], [0xea188173858b7f2au64, 0x6acde8e3fd8bca2au64, 0xcf053ba37c36695cu64, 0x0150e88ddd496fefu64]),
-    ([0x397a3572c5c263d1u64, 0x6bd8826b7a39d2e7u64, 0x907956aab02a88cau64, 0x24e312dc08f557c2u64], [0x73744b888e56d2a8u64, 0xc4e98f5e0e1e14f7u64, 0x3143d293502a3781u64, 0x1d252787f63bc483u64]),
-    ([0x3c51da625bc26daau64, 0x87e3748697d1d1f1u64, 0xcd92c5c063d0f17eu64, 0x0583bc1782849a9au64], [0xd766c531f184ac69u64, 0x50a9ac0ffcc098fau64, 0x1232dd9ea9cd19ebu64, 0x213b51f5e19f12fbu64]),
-    ([0xe6c25407d696f7c0u64, 0x1f93594633f0ab98u64, 0x4d8c482cfb880928u64, 0x236c716a4e9668a4u64], [0x0e78d358bd854036u64, 0xbaf5550bcabe8f0fu64, 0x6fad805fcffe8512u64, 0x05d19a381574af8cu64]),
-    ([0x9a590ef331fcfcc7u64, 0x1873bef1533fe787u64, 0xb1742dadc20a97f4u64, 0x1722b04b3b0f9143u64], [0x93dd40b9e0ae8ec6u64, 0x3ee885ff7ddb1b76u64, 0x3c9812fec3f6a6c7u64, 0x2d051fca75f67faeu64]),
-    ([0x209f7faed7d508f5u64, 0x996b79d69f72d22eu64, 0xc19f9357860ffa65u64, 0x1ccdecfb657d09d7u64], [0x674c124ae70c3478u64, 0xef1da2d3e8c94f95u64, 0x4e1d9f7226320f69u64, 0x03c9f67151ca28c0u64]),
-    ([0xaaf64fc906711792u64, 0x1157fb38c9a7ebf9u64, 0xac9ad6c9cc268079u64, 0x0760719b2df10832u64], [0xc025e4966c382279u64, 0x7b34dfc96564b0fcu64, 0x4535be22918304bcu64, 0x1424451f17aa7d49u64]),
-    ([0x72971f8374cf711fu64, 0x1def6a765a9d27ebu64, 0xae098c98822ffc66u64, 0x0e777570265d3f7cu64], [0xeed75f06f3a9aabcu64, 0xd3f4d07f71e4c312u64, 0x87502ce10789f6bcu64, 0x296ad7b9cede4578u64]),
-    ([0x3124f305aea0a471u64, 0xf86d269481f4240bu64, 0xec9a2197bd5e73dbu64, 0x2341a2954025d05bu64], [0x633be967840fc7e3u64, 0xea911a2e326a03a8u64, 0x238fc8b08bf01a96u64, 0x25671f0b9f75baf0u64]),
-    ([0x41803730e7025e1cu64, 0x5dc32161bf6aba73u64, 0x539a327ee577748fu64, 0x0e856e4fda6b635eu64], [0xb9e6d9026891e2f8u64, 0xf4387688b72d8317u64, 0x5d420f745c9df28bu64, 0x13b70a738aa5c349u64]),
-    ([0x1190d05b1ffc2dc0u64, 0x9390d1399b522474u64, 0x9099f9759b10790bu64, 0x06485074cfa552f3u64], [0xf531d04cf56ca9c2u64, 0x0e73cab748a9ea92u64, 0x9e658e38dfcc2a88u64, 0x12da2d29c215d4f9u64]),
-    ([0xa1a58710f7f6e8cdu64, 0x74d49baccf5f10b3u64, 0x40ab15fb4b81bf57u64, 0x268750734ba6fd5fu64], [0xed26de7aa249d4a7u64, 0x1a7e36de5a290013u64, 0x21b8f2949ddf5881u64, 0x04c2766421faa940u64]),
-    ([0xe309e28ac196e5efu64, 0x6a2889da3109371cu64, 0x68326d235af0e9f8u64, 0x00caa027fbf1d9acu64], [0x526eda8f0153a075u64, 0x7249fe3637a26895u64, 0xb47a14d8ccb569afu64, 0x0db744256bf0f48fu64]),
-    ([0xe0cf36347e04ad5du64, 0x5bf5f3b8493cbfcdu64, 0x36cd3671d35f5c88u64, 0x2d0d074eb705ff5du64], [0xbacb09c0b06a0596u64, 0x8bcfe5e08f27b57bu64, 0x4e81ea230a739633u64, 0x17982b9f9abcd77au64]),
-    ([0xa45858c0de97c9a4u64, 0x7f300f72eaae5ef5u64, 0x7c03152be4369b25u64, 0x0855d7f72078bfc9u64], [0xef4513f349dce772u64, 0xae3eaa0f8ea11559u64, 0xb8630dea59dc7652u64, 0x12b75e9258311984u64]),
-    ([0x60dd7cf77a112895u64, 0x55a37cde12005871u64, 0x2df0959eb2952765u64, 0x2bbcb30731c076e8u64], [0xe99588bd8f67f6bbu64, 0x49dc5bfa84d74733u64, 0x10c9ccc372aed454u64, 0x03d5159209502d73u64]),
-    ([0x8b11d9ab07b95a9bu64, 0x20710ead41671f56u64, 0xfb3acaee30f81deeu64, 0x130b17119778465cu64], [0x9c102d702db22e99u64, 0x1e1be52d559bd4cau64, 0xa11b2c7f3a0e865fu64, 0x01a17e416f7ddf92u64]),
-    ([0x6de7eeceb23bec3bu64, 0x5e573b564a49614bu64, 0x75f367c663c49abeu64, 0x26d5c8482ecacbc7u64], [0x5cd4ae96fa5ff2d0u64, 0xb07dafbc519203e3u64, 0xa45c79cc0ffa7de1u64, 0x123bf32ae90d8cb4u64]),
-    ([0x215f5eaf23e0a0c0u64, 0xf45418a1c36aaf08u64, 0x6441df720b62a521u64, 0x0854643e4eb9449au64], [0xc4a07a7ffee29bf4u64, 0x8586eefa3a318d73u64, 0x70448cf3e67d68bdu64, 0x2445a810d06879a8u64]),
-    ([0x11d4b48e5e63e5dau64, 0x670e0602358f7dbcu64, 0xdf4b8f9f4a8c0230u64, 0x2bacf051cccbbbfeu64], [0x18ea8d5713a638edu64, 0xb52fe69ffd9e5753u64, 0xa05ca1fcd7ebd87fu64, 0x14837ba41c49ec14u64]),
-    ([0x6692cc1e5a526c81u64, 0xd8675a9e5dd2336au64, 0x820b96ff0a13f2dbu64, 0x1058a83d529be585u64], [0x44ad63f7a247233fu64, 0xaa76b448f3589a06u64, 0xa2f86dc22b838179u64, 0x16e7bed50fc36e71u64]),
-    ([0x1af6dd54acec64a4u64, 0x0080797105a0d76fu64, 0x8795b786b0d6dc05u64, 0x05559489b9b87c94u64], [0x2747a409e0433cadu64, 0x493af0c74b5867e6u64, 0x1470f8adecd927feu64, 0x2eec0065cf5f686au64]),
-    ([0x309bf99ed27cbed9u64, 0x4b9e81d8de7ad543u64, 0x2531306d11938ab8u64, 0x044bf3f86c885bebu64], [0x56771bfb4bb642e6u64, 0xaac06650308cdeffu64, 0xfa6317e24ff42c5bu64, 0x220096743da90fdeu64]),
-    ([0xbfe8359299e300b6u64, 0xba7fce825e2e5ebcu64, 0x50d130520e664090u64, 0x1ffc5b91fb0aecc8u64], [0x4e7b861fd63c2c8au64, 0xa067607b344886d8u64, 0x17b2ae6e4ed015d0u64, 0x153b0397155f9e86u64]),
-    ([0x015d6408895fb671u64, 0xf3bc489fafe047fau64, 0x4a05668bc0feb393u64, 0x1064b826e091cd85u64], [0x320092440c3431dau64, 0x27340f4d50549816u64, 0x8b06828bc8fc00feu64, 0x0597076141074718u64]),
-    ([0xe2688dcee8034a04u64, 0x98340fd8cb95bcb2u64, 0x6fcab150a15bf742u64, 0x2ff41ccc958b83feu64], [0x996bd7177fc2a5f2u64, 0x14eddd777a5f057cu64, 0x1cd2254f7a866934u64, 0x0c5ed4647e6f2b61u64]),
-    ([0xde4b1731b87e3f15u64, 0x1cd013e25b2dc349u64, 0xe9f1fcc658684616u64, 0x0cf504cf069793c0u64], [0x2708353000e4c64cu64, 0xacf035130890e583u64, 0x89f108de0890ea60u64, 0x264e9f22beca9b7fu64]),
-    ([0x384f1673e5ff7faau64, 0x8a61b7f21d266a18u64, 0xf05b7d27113a8c5du64, 0x0cd0310ea729b313u64], [0xf5e1c25e0e85e2f2u64, 0x436a0fef16423e50u64, 0x7a57302a1fdbcee2u64, 0x0a8de07ac4b17905u64]),
-    ([0x55f4840b0f6d482au64, 0xa216c5ba9f024219u64, 0x480bf2f6458761e1u64, 0x05b4cdd4575644f2u64], [0x268d3989e860f4fdu64, 0x53c760bf4b0f10afu64, 0xca4d31e938997f35u64, 0x0cd881c732badf06u64]),
-    ([0x6a5245f0e06b3d80u64, 0x70c1ceea099a9fedu64, 0xf08c7a7aa7ca67cbu64, 0x220ac40cb8abfdb8u64], [0x99dcfd9a4370f466u64, 0xb29536a511459bf8u64, 0xabe0a9168addaf6bu64, 0x2fbb59d549d0f40eu64]),
-    ([0xc3ef8d413a042764u64, 0x7728da2633a42bc1u64, 0x731208603b76d683u64, 0x0cddf67521acdd05u64], [0x5515301c876fbab0u64, 0x4f93610047832816u64, 0x5d209e5592007871u64, 0x069d3f73af0d41adu64]),
-    ([0xe8bb9f159417ca1du64, 0x911c660dcad7d218u64, 0xf7579bee489ff172u64, 0x24d708cedd8f876du64], [0x57a5cdd29812b2acu64, 0xbc42dee8f5c9a476u64, 0xf606c2d4651343edu64, 0x0d845631b33f2966u64]),
-    ([0x28e479f665feec32u64, 0x234e5d09a1ca16e4u64, 0xee1089d05da569b8u64, 0x1d32731438f86cacu64], [0x3ffe781ef3adda93u64, 0xb71036e43a01e1fdu64, 0x2aff829c0203d54fu64, 0x0f25cb8a7be844ccu64]),
-    ([0x078a0aca9e957ea4u64, 0xb51def22576dd612u64, 0xe6c7b216b52eb91cu64, 0x2832f4f9fcca0d18u64], [0x9e75c80fb1e1ec85u64, 0x46e9ad3f716598f5u64, 0xfda2deb06456930du64, 0x171662db59678a8eu64]),
-    ([0x7228375905484383u64, 0xc53736af76cce02du64, 0xe230fd8c4980e72au64, 0x223b3183f94473d5u64], [0xe26d13061326cacbu64, 0x8cc7959342e5aaa4u64, 0xb7407f8da6fa2053u64, 0x1b0528c8a2697c0cu64]),
-    ([0xef3b0c1c1aedd5bcu64, 0xf243af3c37c6653bu64, 0x05881851632b2482u64, 0x21022dceb21564f8u64], [0x92cf5aa1d95100fcu64, 0x5fba45d8f2bc365cu64, 0x148804db92c7996fu64, 0x2a3a2e1009d118dfu64]),
-    ([0x500c777d4bee4464u64, 0x21088ba0f2ed7f30u64, 0xcba362d5201072dfu64, 0x21202c4b5b0d12ebu64], [0x7bd749c4f53b2844u64, 0x4a77aa01e169e405u64, 0x963827c28b6e0e47u64, 0x065cda2cca7615e1u64]),
-    ([0xa611a01f09948b09u64, 0x8a14767a842f2123u64, 0xe7cd2624d9bc5924u64, 0x0ec5b7ab28619669u64], [0xcafd6df87e54b471u64, 0x4fa506229915ca24u64, 0x64ad362ea5b14531u64, 0x0309c9293ec7b8efu64]),
-    ([0xbcfe8c2c1b96bd0fu64, 0x648fa0ad4017eb6eu64, 0x82cf164477f1ade8u64, 0x2c261bd822dde180u64], [0x745faba8b1955263u64, 0x8685a214210f9049u64, 0x8dd1f0078f013484u64, 0x01c56873a5a6431eu64]),
-    ([0x1e06eb8b38076c7du64, 0xb85e864e9d45e187u64, 0x0a44c7d6793979abu64, 0x27d40a021a17c83fu64], [0x2d26baa38e4c1371u64, 0x06121fca20085d9fu64, 0x587a56a852a083b4u64, 0x24b19ae30c89cfedu64]),
-    ([0xd95234f7d46c0052u64, 0xcd05ca8b085d6ba6u64, 0xd182bf865493b944u64, 0x286880c202d94177u64], [0x19bc1a58b4cfa46eu64, 0x12ab375fd57b8f65u64, 0x7839ed4db88dc8bfu64, 0x0c7b9a6452395886u64]),
-    ([0x1e8141dbbd999576u64, 0xf41a0e415913f720u64, 0xb29f384309101b7du64, 0x23c87500bbffed05u64], [0xc9237d74e11b5ee8u64, 0x41c9a2a71467b0e3u64, 0x39ae64e5d8ff706bu64, 0x2de364ea68944e6bu64]),
-    ([0xf57524e7be51dd06u64, 0x3f04085eb439eed9u64, 0x27aa0fbc91e42799u64, 0x017e7e1a97f00b2bu64], [0xeedaa4a118c4f0ceu64, 0x496b515abf9841deu64, 0xf43103cecaa7e4ceu64, 0x0533aa1a40476d43u64]),
-    ([0x4380ed1aebeef42du64, 0x19765462f8ffafdcu64, 0x98be443cd5507812u64, 0x2cad0a9b9157c275u64], [0x391411bfdb2dd4bau64, 0x910e871e5b4fdb0eu64, 0x6f95d2f4d33e2185u64, 0x1ff2531415acff2bu64]),
-    ([0x434d027b4ac4576au64, 0x0168adef3ddcce7fu64, 0x404e0a9b4ffff548u64, 0x183718fd01a844d3u64], [0xb7eca97478b02536u64, 0x11e4dd32e485af81u64, 0xc0fe8867e08a84a9u64, 0x09136facd7bbb450u64]),
-    ([0x4ee02432db7432c9u64, 0xdfc73cac7cb4cdcfu64, 0x531d396e56b49f8au64, 0x2b8e955a19b60b16u64], [0xfeb6fe2881abafd2u64, 0x68b05da3d32c4a47u64, 0xd305dbd375c6e392u64, 0x0d49a2f339c88f24u64]),
-    ([0x79e604c75457458du64, 0xf87975705ea5e02du64, 0xe13642dcaffe0b6cu64, 0x239b1c0a946ddcf3u64], [0x41a03ba575fbddb4u64, 0x0b66013e09f02f26u64, 0xe84bb4c0c6756778u64, 0x10cfbdc70bb3c339u64]),
-    ([0xca10803f8715db7fu64, 0xd8eb7364f6576633u64, 0xcd892a75777ec16du64, 0x2b9775f8a1772a50u64], [0x5a2ce5af042e798au64, 0xa7476e650a22adc6u64, 0xadd0d6038a4926dau64, 0x1bc49607f983f69cu64]),
-    ([0xa1ab0fadd65640b2u64, 0x2ead518c3fe97ec9u64, 0x6d3d2e6646e7ee7bu64, 0x1910cf3d7f4cf2e0u64], [0x28369dab5592c626u64, 0xd7fc751f8933a5eau64, 0xbe146eeefe778f1au64, 0x233e49374f3adf1au64]),
-    ([0x1ced58cfaa03aeecu64, 0x6228cab9318895e1u64, 0x79b62ee17a4cf59au64, 0x1b28c13de843e06cu64], [0xd7ab020c43206474u64, 0x13a88725f9ded764u64, 0xa5cde2e1c3939fd0u64, 0x1cd07097097a4205u64]),
-    ([0x3e46f07aeff0e756u64, 0x5ab5b13528d5409du64, 0xbc0aeed94ea9e304u64, 0x1dba0a9ecb2ac282u64], [0xabf37887e682cb8du64, 0xc50bb802040ea13fu64, 0xd041a3aa5a6732f0u64, 0x02a3a079b1f97f72u64]),
-    ([0x046c41c673f2b643u64, 0x7b27ab8b5c3c0176u64, 0x4002e467e2f1ba60u64, 0x1359b339c2850736u64], [0xfd69d82f9834d99du64, 0x98d46980e288f9efu64, 0x83032acf1758fb56u64, 0x0976ca91dd886748u64]),
-    ([0xfca0da952653186fu64, 0xf39ce92acb73ff63u64, 0x3515fea035d27282u64, 0x284c59ed99cb3530u64], [0xd3329389c6d9fc18u64, 0xb48c164dadc918f6u64, 0xd1afb4f64aedc47bu64, 0x2ce40c2541181f66u64]),
-    ([0x90ff0c6099e767e4u64, 0xc9fc6699783ae53eu64, 0xd1fe657a6335c31bu64, 0x1dfb5588e595302au64], [0x6021d963b59965a7u64, 0x951314aefac4057du64, 0x316102e80917374eu64, 0x2f6f4266cd94d89eu64]),
-    ([0xe0142fb2f90be6d8u64, 0x737ac78fddec4f47u64, 0xfeffcc845c132ee7u64, 0x19ba6634a3e55230u64], [0xb3670764f9b0c495u64, 0xdbb4cdf83d20bb6fu64, 0x60dd8a19f251ab33u64, 0x0deb8fe5b3f60799u64]),
-    ([0xf6e4434318058b9fu64, 0x8c9ed8f4d43c44ceu64, 0x8dcd3c2033532144u64, 0x1ebffd82e24b359au64], [0x7677210f32032e3au64, 0xbaa04ff882e43376u64, 0xf48ec18b151085a0u64, 0x0acbe03e32269466u64]),
-    ([0x75677935198dfe8du64, 0xe71734e596ac285au64, 0x844fcae007b57975u64, 0x11a4a514ab28d1d4u64], [0xb49ae9bc2da4536du64, 0x1e6e4a8357f73a80u64, 0xb935c82bb5a2b9f0u64, 0x04bb982fa9e664f0u64]),
-    ([0x5302c33489409d63u64, 0x0547da4b14215713u64, 0x9c7ec2693afd7c68u64, 0x2ffb8c2d5f82fa8au64], [0x021489ae71dc1927u64, 0x3995f2607fc37973u64, 0x2f606e9e0bab90bbu64, 0x16438ba8e0c94736u64]),
-    ([0x43f12cd70c92de3au64, 0xec69842a9492e365u64, 0x344a4ba3ba93eacau64, 0x12309bffdaa1239eu64], [0x223872725a95a9ecu64, 0xcb50ec9ff94d377cu64, 0x7d69099ae5c1a770u64, 0x13f6c9cba3bbc466u64]),
-    ([0x2ff6cff518bc8a71u64, 0x41fff9d121d4401du64, 0x53f2462eaa7124ceu64, 0x25146a9956dfd820u64], [0x42310432aae9d293u64, 0x09011d4f79c0029cu64, 0x276fee47180dbf27u64, 0x08e34972287bbbafu64]),
-    ([0x51a998e2573f2f78u64, 0x350934ec15427664u64, 0x80639ad35f8a0873u64, 0x190269574c4c44c9u64], [0xf800dea43ac57555u64, 0x3221e8306d6b60c8u64, 0x047e3c7facf2cbb3u64, 0x1762dbfce97d6457u64]),
-    ([0xbb08963d5054732cu64, 0x8ae1771d66a575b9u64, 0x19f97e5a40986072u64, 0x0a6d05dec42413cbu64], [0x62f4a6a2c1ed3ae5u64, 0xa170e701f1b27b1fu64, 0x118980b222d2e03eu64, 0x2e70aacdf83bee60u64]),
-    ([0xd1668675c25bffeau64, 0x263576b4173692e3u64, 0x31490e8d04693ef9u64, 0x1f14ce501929982du64], [0x8f321a356028bc67u64, 0x55bd3b7acb3a9b6cu64, 0x8d9c5f7cd0ddfc92u64, 0x301e8fc1e4f91aedu64]),
-    ([0xcf13129e495749c4u64, 0x1d82ef07b9c5e5c8u64, 0x5a8302b6134caefdu64, 0x2ab0bde9a9b24251u64], [0x7efb5a100892d82cu64, 0x719410e919158a55u64, 0xa11909b3246f23a2u64, 0x068f362e41eab4b5u64]),
-    ([0x742372727b541c12u64, 0x7d61de8b971ff37eu64, 0x5ec20223e5a2a874u64, 0x19c3f5d3c257da2eu64], [0x06e9333b4e4f2c03u64, 0x7e63d0d762b75ecdu64, 0x486e34fefae6e2c8u64, 0x23f765c326daf3d3u64]),
-    ([0xe8bb5fa317eefba1u64, 0x046528e41844312eu64, 0xac82c16aa9ff0543u64, 0x24e219f2b0e9be84u64], [0x6351dfc92ea512c2u64, 0xf74b6af0dbdf33b8u64, 0xb44c2a2c755bb901u64, 0x0eb08f3b6d9b629cu64]),
-    ([0x5f535e7d7bd61e92u64, 0x4a31f51f9654bf40u64, 0x78c56ba56cb21d36u64, 0x0f8d25b7e96e8c7au64], [0x81b8be9ebaa7c622u64, 0xf9e1c608e62754beu64, 0xb39136f8b6577be9u64, 0x1c77572c26c3b6d5u64]),
-    ([0x6fbf1d01c050c1beu64, 0xef21187107b03d21u64, 0x5942d7799446e2a9u64, 0x23757e3ab3817a61u64], [0x8eabe824990bbe2bu64, 0xd0ee2cd6e86eb4dcu64, 0x96b603f3e09461bfu64, 0x1f7eee0b43d89797u64]),
-    ([0xa2f96d47d483ff20u64, 0x55904fdcf4c84bafu64, 0x79324b7e21f951dcu64, 0x003832c77396ab4fu64], [0xf31c02f69c4604b6u64, 0xe2b511468c9a536au64, 0xc903936b3eb6e491u64, 0x0fa0b0f9edcd7d7bu64]),
-    ([0x4b51514d49554fdau64, 0xcc2d1ab998b1ed7au64, 0x16e4721d54c8a63eu64, 0x26991ccfb0048036u64], [0x2bb2911f98a74297u64, 0xbb91fdfb41408121u64, 0x3e434c8d0f4ab8e8u64, 0x086f35304bb2f1b5u64]),
-    ([0xb3c5438a17b5ffe2u64, 0xb227614ff1d1e6b9u64, 0x21a0e537ecc56c51u64, 0x23acd69f3a9d9539u64], [0x6a35183a55fc1439u64, 0x774e28643cdccbd0u64, 0x26d4adfeb6bc5184u64, 0x0f9f48c0df47e5d8u64]),
-    ([0xefa3f595cb37e38au64, 0xd0cc3916b951b87fu64, 0x01d0998043ac45aeu64, 0x2e61a4d39c584f61u64], [0x44c33d5d29317bc6u64, 0x111cc72bb49c8047u64, 0xf47b92fe55cc84b5u64, 0x0b4e39db0c1fef5eu64]),
-    ([0x013c2fedb118ddc5u64, 0x4358c61400e25aa9u64, 0x51cb58f7f43970e3u64, 0x2c8c3a80b45abc47u64], [0x149f8dece6171fbbu64, 0x23fdfb7d065b3c91u64, 0x8a8e65a8ba219106u64, 0x170b4b7fe088dd87u64]),
-    ([0x3a0a108158b7a097u64, 0x1014f7a8e633ee56u64, 0x254f5ed5641e29a0u64, 0x0e843e2b7a058f03u64], [0x1df1c21eee17fcbcu64, 0x5bf03ddccf0d2e6bu64, 0xac2de5a9e326cc98u64, 0x26459c6582321c8cu64]),
-    ([0xae8b1df005ede3dfu64, 0x1735339f7a8e8da4u64, 0x70868dbff82dbf2bu64, 0x2e875a62c9374102u64], [0x1b191401a144faf2u64, 0xd102bf76292197ceu64, 0x0abac4437fc6030au64, 0x230fe7ed74fccc60u64]),
-    ([0xa4442f0319d0d2d3u64, 0x99754dd92ac6b75fu64, 0x545830efed20fa8au64, 0x0f634b1ba63cd628u64], [0x6f3cf2d9e6a5923bu64, 0xb45954e6e4895c16u64, 0xcaba1e034e25bfabu64, 0x119e7ed25b46b8e0u64]),
-    ([0xeb973edfd5caacf1u64, 0xb53707275727a61cu64, 0x20cc97c86d5537bau64, 0x12b09f2446f27efbu64], [0x78e2c5c0ce8000a8u64, 0xce4ae9e9b72d70dau64, 0xda45153047595deeu64, 0x25979f341e800ba8u64]),
-    ([0x3c95e58fc868888fu64, 0xfbcf065806ece326u64, 0x6c55c985d617bfdcu64, 0x1ce92944f328dd30u64], [0xe61b282850a6d0d3u64, 0x6967b86311364af3u64, 0x50437183e29f7f86u64, 0x2eb0a15ae43abbceu64]),
-    ([0x689257aaa74836eeu64, 0xd5cd4ae3587a5c1fu64, 0x98c1eef53ef7b32du64, 0x2f4efada34d3a1dbu64], [0xeef220544af538e1u64, 0xa25910b2b63b1e7au64, 0x93d5eb9c72119544u64, 0x2d7e780764873f2fu64]),
-    ([0x625ac96fad2df80fu64, 0x35712efe1f0fb18fu64, 0x6783b2e15e6082fau64, 0x2b41836a98ac8daau64], [0x24e466beb446e630u64, 0xbb009eb02d8da59fu64, 0x67fb435a570b7685u64, 0x1b5f5c188a0bee80u64]),
-    ([0xb85a71e6373428deu64, 0x74954d30aeb0337eu64, 0x2bfc85eb303402b7u64, 0x02e40daf409556c0u64], [0x42a79428ac45e2f5u64, 0xebc28436e2754ec6u64, 0x62b8e81928f8e974u64, 0x08ff586ca7745a23u64]),
-    ([0xb143a69c3c97f837u64, 0x4b86dc5ef5202e6du64, 0xc39ead3363f84053u64, 0x1c93fd05fe83e1b1u64], [0x14b71f076eb93af5u64, 0xbdca1d1cfc054b0bu64, 0x628d61ad6f38cbadu64, 0x27fc3669579ff7c2u64]),
-    ([0xd032759f0b037705u64, 0x11d887205b291befu64, 0x5b2612b3e5b950b1u64, 0x229142d808702cd4u64], [0xc91614c7ce1db3d3u64, 0xfc9d50db499f6b4eu64, 0x88bc37b47563f43bu64, 0x1c3b6a2927d2976bu64]),
-    ([0x8895fd9efe2ebe82u64, 0x6baad7570f7580b2u64, 0xc959bdfae04c66c1u64, 0x130e8013c23553a0u64], [0x82f87e43bc32f106u64, 0xe85c656d6cb2337au64, 0x740b7b6471520f28u64, 0x21bd5b2625cc36d6u64]),
-    ([0x632c39cefd47ef6fu64, 0x590cdbb06161afbfu64, 0x7f474870e249898cu64, 0x03498a4ab936228fu64], [0xf599a4eab607337fu64, 0x753c73b51ab39f43u64, 0xb93671dd63ae4347u64, 0x2aff4caaaa2a0b0du64]),
-    ([0x2507289446e9707eu64, 0xebb50efe6868ae2au64, 0xe55de4bc76aabbb2u64, 0x26ef92947ab6e386u64], [0x38a37f2abf615926u64, 0x4c09e7ca0db2880bu64, 0x1f148ad3d17913fau64, 0x102db202e92a3fc3u64]),
-    ([0xf2a97c6f52c080b6u64, 0x273fa717dba3466du64, 0x8c316417de0ba026u64, 0x2d846aca094f86d7u64], [0x33682c2b69fcbedbu64, 0x4718ccfb5553a80cu64, 0xbef561bf230f2f80u64, 0x1186529fb34ffc76u64]),
-    ([0xbbf6d143c3fb1102u64, 0x338c7b899a6974e3u64, 0x4705b9c3be75b933u64, 0x07e41a0f32c0c302u64], [0x82a18c9d01b8c2fbu64, 0x989afa0216de4523u64, 0x4608e6a918d0056fu64, 0x032d2fa8fe2f952cu64]),
-    ([0xde8a4161f50ac39du64, 0xb25a79992c2a3d88u64, 0x309e344ac499c2f7u64, 0x11a6cf2425b42a1fu64], [0xf5c7963fd5aa0bf5u64, 0x5a044720273db7abu64, 0xc689bb3b44826044u64, 0x118d9394ffbee648u64]),
-    ([0x655e69a7bae97f4bu64, 0x19da93e468f5c9f5u64, 0x80cc534be6c0c1cau64, 0x1b7eb219af76cf58u64], [0x83164ec7f96ed2a1u64, 0x760f23a704f9b110u64, 0xa0ffaf9105e977f8u64, 0x04d887902b90b581u64]),
-    ([0xa7f46cee86829008u64, 0xcd4d92e6deaff061u64, 0xf59bf823bf84b0f1u64, 0x23fd607371ce0140u64], [0x367536c0f592c875u64, 0xa61a1ceeecb58d8du64, 0xa6f5d87bee751b8du64, 0x09615fa89674153au64]),
-    ([0xa5b4dbf55860d2c6u64, 0xf2642b1d6bddaea4u64, 0xe24f34bd51d21185u64, 0x06a53b6489816bffu64], [0xc1721c101d04bfdbu64, 0x6eac79f3ff235ca5u64, 0xe4b8d6720bd99e7au64, 0x0679f64286f2e32au64]),
-    ([0xf96497dd340cc661u64, 0xc780846b4a78fe5cu64, 0xeddfaff3eaec77e3u64, 0x1535eb95deb10eccu64], [0xca59d39b780aaabfu64, 0x3eb97d89ab07f337u64, 0x16ed93899022597au64, 0x2dea309fbc311937u64]),
-    ([0x16e6f584270dfd3bu64, 0x7b9834bd03ee467cu64, 0x7908c9701950fbf3u64, 0x03afeda6d86561a9u64], [0xeed122ce611936c5u64, 0xcd70ec8b1884eec5u64, 0xa976f4eae36e296bu64, 0x048c9a23a454e0c8u64]),
-    ([0x91559f980a203ab3u64, 0x62fb8c325129e8c0u64, 0x788cf71112573dffu64, 0x304db6bd79808aa3u64], [0x190e525c0631abc4u64, 0x9d57a6368542f352u64, 0xaa1565494fc11cc9u64, 0x052c4056165a5fe7u64]),
-    ([0xbec55272d0831f5du64, 0xc2f4f1bf22f6a136u64, 0x9c92b1d103cca65au64, 0x1c73dcca0e873727u64], [0x4d7900b02ec89de8u64, 0x0952a0529d5d7968u64, 0x965ffa061c035981u64, 0x27fd7a9fa0780450u64]),
-    ([0xd58a2c404cc89ab5u64, 0x20eca5332bb5ce97u64, 0x94714429fc84d10eu64, 0x0162391d430c8115u64], [0x14095577b93aacc5u64, 0x14daf751afd8baa7u64, 0x424343ee2bac3051u64, 0x2fb9aa1b6eaa7e6au64]),
-    ([0x2346578ddcee8b3du64, 0x500071a9b015edf2u64, 0xf1806de45e9eacfcu64, 0x237540aabb348c32u64], [0x34aca1e74a1544fdu64, 0x99b7eef44484e146u64, 0xaee0415e149f662eu64, 0x261f74e99dcb29ecu64]),
-    ([0xc0e8217a1bbcd70eu64, 0x33e66841dee9e157u64, 0x3d1acdaa758c409fu64, 0x0055527c322f557bu64], [0x7b169e871cc5c328u64, 0x428dc4b4ebf4294cu64, 0x7004a739606decebu64, 0x03091a3bae155a83u64]),
-    ([0xfd47521239e00ae5u64, 0x5ba6b74843e40cedu64, 0xa4fdca88a749d9fcu64, 0x21d2ffa8ca039d3cu64], [0x4679e8771636aeb5u64, 0x67623ae2ee500918u64, 0x1af641a76e403ddbu64, 0x1455f1c20c6871b2u64]),
-    ([0x4a4408625269faeau64, 0x854bb261c55676f1u64, 0xa39480eef591465au64, 0x0038048ede540441u64], [0xc160ccbdd6ce4d0fu64, 0xb4919a5f73b1cc91u64, 0x59c45ed0f4a713e2u64, 0x0fd761404e21a111u64]),
-    ([0x2a77ee30568f3bfcu64, 0x6c2630bce026e293u64, 0x0f0371e8fba8ddffu64, 0x294671260988a2ecu64], [0xba99d42188318422u64, 0xf28190a7aee08392u64, 0x74c2ba5587b63ed2u64, 0x2a031fc55edca0e4u64]),
-    ([0x6a875f8987ebad1cu64, 0x6c5038e97bef7167u64, 0x2f563287fb0628f6u64, 0x221e3f129e294a69u64], [0x2b769e0d5e70f625u64, 0xc419f7bc68dd12b3u64, 0x279722618ae06bbeu64, 0x2c3619af16bcead6u64]),
-    ([0xc7fc2ab2ef7c4212u64, 0xe1350e5629485d7bu64, 0x9e5f1e94e2e12a55u64, 0x1bc863dffa88807du64], [0xc5f6867df327aa17u64, 0x224db71d9c090f14u64, 0x7feb3a2887b1308eu64, 0x23a836651ec65f59u64]),
-    ([0x589df6988e3f1fafu64, 0x8e994c1c25afe215u64, 0xf8ede8e6c7a122dbu64, 0x0846d705ce891e63u64], [0x39a836d017c7ed18u64, 0x69c15aa32cb0f952u64, 0x56761a79550a7d6fu64, 0x182ad21567442d96u64]),
-    ([0x34f31cf5d9e5e8d2u64, 0x1f6e6db2c7b76c3cu64, 0xcf43896ccf9799acu64, 0x268f8e71fa9fae10u64], [0x90669f2300148993u64, 0xac5c0c59aa3cc540u64, 0x6c144f0dce14700eu64, 0x037c3e399729dd8cu64]),
-    ([0x941cf73b537b35a6u64, 0xb81b326c35ca86edu64, 0x02708f4d834eb56eu64, 0x1c82401bc3888ed8u64], [0x6137306745338374u64, 0x328b9edd1dc2440au64, 0xb24ae615e3e974c9u64, 0x01fb16d616184fabu64]),
-    ([0x41a97df7a44f66ddu64, 0x7800bada4ca21750u64, 0x5fddcd198b5de5beu64, 0x1285b46fa2cf2b65u64], [0x4c5faa720e9c95bcu64, 0xf236ed60053119f7u64, 0x5973171e805bd105u64, 0x19113a27217b11c2u64]),
-    ([0xf3e9802421b9c030u64, 0xf0229bc99730aa7au64, 0x34c1df1e8e6b5c17u64, 0x13afdd4d8401f85cu64], [0x7db0ab76a37d7014u64, 0x801cc7fce2bf81d5u64, 0xd7b16584721fb587u64, 0x2c38c593aa871296u64]),
-    ([0x3dc1b3dd1c848fb6u64, 0x1d47f0c266cf12cdu64, 0x702b08ff34bbca0bu64, 0x20ba478facbc320cu64], [0x8197a117fd333c5eu64, 0xd6406e1cb0578c74u64, 0x0f73b372d273ad2cu64, 0x158d0812d4f15af9u64]),
-    ([0x90a85c074ac37f87u64, 0x8ad6d369cf27271cu64, 0xf97d5dff1f759db7u64, 0x2399bcef038b7476u64], [0xd30cca6554d1de7eu64, 0x1e064e9821b4a834u64, 0x4bc3fd1b4da05d7cu64, 0x1b50c9f6e7e615c8u64]),
-    ([0x471e0ab56e1e8138u64, 0x252a2369fca29237u64, 0x32e1e45648201628u64, 0x087ccbbb42cb5ac5u64], [0x17302634a123d6adu64, 0x5e05d1a650176fdbu64, 0xd6c1818d2102b291u64, 0x1dcb697fe4005563u64]),
-    ([0x8c99980cf210979du64, 0xef8f311334905b4du64, 0x0aeaf3e7df25d8e7u64, 0x2724713603bfbd79u64], [0xe9b2536fd30e7d56u64, 0xe75b03ae354dc896u64, 0x22529de76c5e23c7u64, 0x269aba1d833c671eu64]),
-    ([0xd64f2dfc9f6771bdu64, 0xe46f0db24e4543a8u64, 0x7ef57df576d1c831u64, 0x06409e086409f568u64], [0x0f0cdecd265dc2beu64, 0x47c199826b164745u64, 0x910e921e251255e5u64, 0x129f0e355c46ca6bu64]),
-    ([0x91886496a69abf7bu64, 0x707f1a33e4452a2eu64, 0xd433e79a58eebcd7u64, 0x24c8906179ca2bc8u64], [0x3a7f3d4f87f438eeu64, 0xc89d87ef624afa45u64, 0x28667c9c9e0aa3e9u64, 0x1aacbe2f8fefd8f5u64]),
-    ([0xb4463dacb2d01ef5u64, 0xeaa669fe9208a0efu64, 0x8389692fdc0fc077u64, 0x15fb50bab5177a47u64], [0x797fb50f19277381u64, 0x9c0a2673964abc32u64, 0x4308629ac0e80a9bu64, 0x248cd2b8f6026641u64]),
-    ([0x0ded5450ffc897beu64, 0x52984b0318671a5du64, 0x983e377a8de37f6bu64, 0x27d7f17f755ede2au64], [0xdf430a7379683de7u64, 0x3fa110e09097ca92u64, 0xbf6833b4e4ec9775u64, 0x0541dff13ff7c749u64]),
-    ([0x4b0a3930ee057d78u64, 0x7c47900032062587u64, 0xc3008212e06ef073u64, 0x15b0e9734ce47aa2u64], [0xbcfe11091967212eu64, 0x63ffa0fa3d607deau64, 0xfc7cac6607531bc5u64, 0x0b971f52196bdcafu64]),
-    ([0x8bc3f74ba040f070u64, 0x8caa023646d0dfc7u64, 0x58771f389545f335u64, 0x2c8d43631ddbad78u64], [0x59374060e55dcb38u64, 0x306d80e8bad6aa75u64, 0xfb1e6d2a32f75a1du64, 0x1fd7535ea7865d37u64]),
-    ([0x1cc29dd05013ccb3u64, 0x0ac4b62722f87039u64, 0x8fe3036600208959u64, 0x2299579062720562u64], [0x1cbb0619d04aa728u64, 0xacd0081702b51da8u64, 0x3934c9e5c70afebeu64, 0x1aadc9991ca1115bu64]),
-    ([0x6330a1fe62716079u64, 0x1d09e00f38c17959u64, 0x9542436d26775471u64, 0x037543c89882ae57u64], [0x5a73081ef9dbf21bu64, 0x44ef8ed93ca49b53u64, 0x286dd66e16fe408fu64, 0x1ab54398e925018bu64]),
-    ([0x25c0c82ba4d4537au64, 0xa4c05cb00c99841du64, 0xbd560913cea1f200u64, 0x20e50404a8f89a8eu64], [0xdf80523553b8432eu64, 0x1ef9aece686a8005u64, 0x16f3374c80da41adu64, 0x1536ee6cf137c2f9u64]),
-    ([0xa316fadfa0ef0794u64, 0xbf009b586dc05d0eu64, 0x6e9412d02cbc169bu64, 0x2ca769fa8f821df9u64], [0x84f72e5d870faa33u64, 0x9407e41d05164c6cu64, 0x509eec8d18f82f82u64, 0x069e41feee89b79fu64]),
-    ([0xeac67a5c207eb100u64, 0xa8378ebffdf3a1e9u64, 0x1e6872d0ed89706bu64, 0x093e26111e96e65au64], [0x4ce9979b3e9c0eb6u64, 0x588e18b2d4d700d4u64, 0xdc665c5d69466ee1u64, 0x23b31715345db76bu64]),
-    ([0xd8f7a81696c7fb90u64, 0xafcfa14c2256077fu64, 0x9f3ebc2c4e93339eu64, 0x26449b3e1e85014au64], [0x7d0204ccb0c8ef6fu64, 0x343ec5a143ed85c9u64, 0x276eeba1b9f99dbau64, 0x060f528ac6c14b40u64]),
-    ([0xb1c836cbde99b836u64, 0xed46211d16d73c42u64, 0x6e0591e9b1ad8f7cu64, 0x2f79a1ddde0135cfu64], [0x0324df91f7cc22d3u64, 0xaa1fd2dac2acf7aau64, 0xc30280ecaa205277u64, 0x2883659648d07166u64]),
-    ([0x98c692b8ee5de134u64, 0xc39dd399f3081d91u64, 0x408bd2ffb5803e95u64, 0x1e7a1497cfe492eeu64], [0xe2e880f696ca25e9u64, 0xe47d42f9e2bdc337u64, 0x5b2e03507fe82d64u64, 0x1e423acb0b61a7e2u64]),
-    ([0x6f776389ce22271eu64, 0x68247e326aea8347u64, 0xc3258cb0eebd47a5u64, 0x1de9d307d37d5e7au64], [0x800723c6f191f79bu64, 0xaab954edde7f32c3u64, 0x22623ae7daf0711au64, 0x267d2c38625b7344u64]),
-    ([0x78ce6a0b763d698fu64, 0xd80396ec1d3213eeu64, 0x035cdc7567a8a676u64, 0x26177cf2b2a13d3au64], [0x5318a6ba5c855690u64, 0x4282b6c7aef03769u64, 0x35f63aa21973dd08u64, 0x286310dfcd14436cu64]),
-    ([0x5dd749bc7a32996bu64, 0xc0190fee20cb22e1u64, 0x88884fa73a726e28u64, 0x25aba5c716dfab5cu64], [0x78f7ebace010ec0cu64, 0xde79de503c637fe5u64, 0x7580f0629499e0a9u64, 0x2227a4e5ef5afd45u64]),
-    ([0xe00db660abf35d23u64, 0x6d76ccbd866d1d18u64, 0x3c87200ac944a235u64, 0x18c6d50905b23438u64], [0x202a59a70f9cc4dbu64, 0x11de7a2f56e78af8u64, 0xae744a223699357bu64, 0x0e8640b34465d154u64]),
-    ([0xf62e19b45ee9d018u64, 0x8c5c20324a82b532u64, 0xd19e88cade369fb7u64, 0x1899d1856546160au64], [0xe7983e2f0ade905eu64, 0x121ebdb38df0e99cu64, 0xd33d1e95f12b79e1u64, 0x2fb36433dfc0eecfu64]),
-    ([0x1d6b5eb12b8c07ffu64, 0x529492e3777d55b6u64, 0x88486e325df639beu64, 0x1f7f913b21a6ea57u64], [0xc394100e3f2b5a3bu64, 0x073bdbc6b27068e0u64, 0x9bc088a3f5bc47b8u64, 0x1157c7f58e34dc50u64]),
-    ([0xb78467021c2ea66du64, 0x97f5a528d1ce8097u64, 0x854e50f8d381348eu64, 0x10aedbd72097ca1du64], [0x96e1a7a49c264dbcu64, 0xe92ac02108f93e13u64, 0x2103a15afe0f117bu64, 0x226009ba8682f415u64]),
-    ([0x52b71c9bf23b5350u64, 0xa4c50f3d12d2ea99u64, 0x15177398e0967a89u64, 0x10fdacd4a792cd76u64], [0x88b8b637a0503f32u64, 0xfe74b57999897d71u64, 0x6eb7ba9f18dc0709u64, 0x1187e0bf88464557u64]),
-    ([0x20f708b85da039e8u64, 0x2d2f9f25adc95ed1u64, 0x46356322f8073490u64, 0x1c9ca4583d63f293u64], [0x21777fd9cfda2f8cu64, 0x91b0dd84a5d04bd8u64, 0x30f2bdcfbf6ab61bu64, 0x1590239e4f0d7b00u64]),
-    ([0xdddf985fc64427d7u64, 0xaa4f964aa49e95bdu64, 0x427c045f5def8b04u64, 0x1641c0537969b732u64], [0x40724504fb7bc7d3u64, 0x3982936b349b3a79u64, 0x9ff469b8822f7db3u64, 0x29739e8098e7de8cu64]),
-    ([0x0d1dbd43706130e2u64, 0x02c464b5680c41ccu64, 0xa20de4d00505e8afu64, 0x0ddcaef6ed6a2d0du64], [0x1d5dfe9a55928238u64, 0xf9e0e57ec15c4242u64, 0x2957f60658072883u64, 0x035e1488e2d9ca65u64]),
-    ([0x000f9162fe407a12u64, 0xc0743334c0c48e66u64, 0x68d588ea6745549bu64, 0x2c9f0d5da06f8b01u64], [0x6d779ce0b587f1ddu64, 0x6ddae22fad72226du64, 0xd12310ead50df40au64, 0x105aba603cbf263au64]),
-    ([0x65f7e87f1770c88eu64, 0x3b21802a7e0f9edfu64, 0xac283f1a2f1e01eeu64, 0x2839499116ea5d54u64], [0x8e445fd180026624u64, 0x61154ed802719790u64, 0xbe1f5f801ab2fb48u64, 0x292f34ebae23a2adu64]),
-    ([0x692c553d0329f5d6u64, 0xa54cf8c68712848au64, 0x64751ad938e2b5e6u64, 0x204bd3277422fad3u64], [0x60e3cc65517412ceu64, 0x853a755d737d695cu64, 0x6426379460613d97u64, 0x0d9ab65152727572u64]),
-    ([0x5b61c42d339198e1u64, 0xea565a2a32340ec0u64, 0x24807d584868810fu64, 0x26d27b0b0bd7c12fu64], [0x14046f185d2e0275u64, 0x3f0f80458c0c07a7u64, 0x147e9b53ff981ed3u64, 0x14ccce40bf380a47u64]),
-    ([0x52f26c0faf6b3e4eu64, 0x4c277a07f0bcc0c8u64, 0x546875d5e4fcfcabu64, 0x09d8f821aa9995b3u64], [0x5d4c42ae09c78f97u64, 0xe0d5c1b2d0e20ec5u64, 0xb0528c756380cba0u64, 0x2bebc850d362bcebu64]),
-    ([0xcaa2e1e9b2e5cc71u64, 0xed42b68e6e43404eu64, 0x6ed809157a2c7f0au64, 0x1c4042c7de3c86d6u64], [0xdcc0638c8db0aed6u64, 0xb3a24eb8d7a02c4au64, 0xe3f5686703b31734u64, 0x2525e637a5a7fd28u64]),
-];
-
-// BLS12-381 LUT1024 directly extracted from Polocolo-main/plain/polocolo_luts.rs
-pub const POLOCOLO_BLS12_381_T3_M1024_LUT_WORDS: [([u64; 4], [u64; 4]); 1025] = [
-    ([0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64], [0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
-    ([0x0000000000000001u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64], [0x314aedcc0de8f7f1u64, 0xc6a36c3fb8917ce1u64, 0xab8a99cad81555f6u64, 0x398386ad9f180e19u64]),
-    ([0x3c28d666a5c2d854u64, 0xea437f9626fc085eu64, 0x8f4de02c0f776af3u64, 0x325db5c3debf77a1u64], [0xae1f07df7c688539u64, 0x146a7fb2458756beu64, 0xb7b0b64ea5692717u64, 0x52a00002d4ea6147u64]),
-    ([0x4541b8ff2ee0434eu64, 0xd697168a3a6000feu64, 0x39feec240d80689fu64, 0x095166525526a654u64], [0x32d68559dd264b30u64, 0x8dd12c876ffbc21cu64, 0x31ed7eaaff6b6a95u64, 0x4199c2ead54339e1u64]),
-    ([0x3a7478c980a80dcdu64, 0xffd8c7e12991d359u64, 0x5fea4420d98de0ddu64, 0x077fb7a1c70a2863u64], [0x03b7414f890ea352u64, 0x24db34e6df6bf3c7u64, 0x379bdda18c86d54bu64, 0x29161dd79cfe7ff8u64]),
-    ([0x4935bd2f817f694bu64, 0x0a0865a899e8deffu64, 0x6b368121ac0cf4adu64, 0x4f9b4098e2e9f12eu64], [0x9812c0d7b129f2e0u64, 0xb553256dffad9e20u64, 0x8c89b88090a50a68u64, 0x5e0eb6eefdccadf4u64]),
-    ([0x8a23f8184f67be53u64, 0x110f2fa24cca7e11u64, 0x8b6d5abad78fbbc3u64, 0x5a9433bf9e5c6e89u64], [0x8a788beb0dd769f8u64, 0xc2622902f58ffbbcu64, 0x8af66b669b3ec008u64, 0x483d4fcb0d62b496u64]),
-    ([0x8fde839c9b10f95au64, 0x6009b29d2aaa46f5u64, 0x916653ef8ee10c31u64, 0x0b47f481668a98ccu64], [0xefee620912e3f5f2u64, 0x206095de623eb431u64, 0xad3b21f8b7857577u64, 0x26f380bc8527997au64]),
-    ([0x8ecfab339e06cb5bu64, 0xa93a056f49df8897u64, 0x76665151b4cb89bbu64, 0x0d562071f73b29bbu64], [0xc46d6166f4d74d35u64, 0x5319afd7760dafd2u64, 0x17861ef3fd2dafb4u64, 0x16eacbfbd8d8cb85u64]),
-    ([0x5130c2c1660125beu64, 0x98d0caac87f5713cu64, 0xb7c68b4d7fdd60d0u64, 0x6898111413588742u64], [0x478ae3e88b0a47edu64, 0xefcabc51864e2f07u64, 0xfb0cc56b7a8446dau64, 0x6d9ce12e7b0b9c2bu64]),
-    ([0xc1bf28a8007ab548u64, 0x2010989d7476cf2bu64, 0xfc6c81de8478b8e2u64, 0x2099eb7d5ee5004fu64], [0xc996e700e23c348eu64, 0xc28de35f3e75446cu64, 0x9809d63401415cbbu64, 0x6f57c3eb8362ccbeu64]),
-    ([0x6d4b3d6d88b9ec49u64, 0x6b42f05b2dd0efa9u64, 0x8a076a0804aaf03du64, 0x5a7e2dcdcaf325dbu64], [0xf06f57583448ad36u64, 0x6de739ca1c8cd80cu64, 0xf9b8f5f784fc989du64, 0x653f33dd93bf8ec1u64]),
-    ([0xe96a0110191444deu64, 0xcb7ad52f8a5c627bu64, 0x0f669bb5886c3af4u64, 0x0753d5d6f7db3f4fu64], [0x5a34e4acdc4fb6cbu64, 0x9f86e996c5b071fdu64, 0xe10e959bfab1c14cu64, 0x1f95d181d91f3cdbu64]),
-    ([0x52a4c44c49a49f1eu64, 0x427d52a475e8ce11u64, 0x1a3ef55558b56d6du64, 0x6dee1bd32e1843bcu64], [0xff9d6ea287fde731u64, 0xf1ab05dbb9b10833u64, 0xd454b5009d96636cu64, 0x6e61bc318f2dc9dau64]),
-    ([0xa503ad6c44bc5ff8u64, 0xa440d6987940175au64, 0x07fd3c97042f2373u64, 0x5a03b1e6bfb97026u64], [0x695c43b9f765f702u64, 0x9fcaa8fe634e62bbu64, 0x916d7b8e8382c5ebu64, 0x623ff1576e05deffu64]),
-    ([0xd0c13b83274f45b0u64, 0x5edf8a6e25303f2fu64, 0x11781b3e716a0f50u64, 0x2bddbb2eb5bc528cu64], [0x37457bd9344c8d70u64, 0xacad1c04d1a4956fu64, 0x94c6ff3370142ae4u64, 0x4278b54e1e67eef9u64]),
-    ([0xc1d795605c9454e1u64, 0x499bcab0951254eeu64, 0x6d94451f7d621398u64, 0x30d14f1ed5f3daeau64], [0xeec2dbbfb31fd49bu64, 0x163a50491a2cdc6eu64, 0x68a102c99e257d37u64, 0x672ca59488167665u64]),
-    ([0x8140d032f0a9ee53u64, 0x2d967f4be2f95155u64, 0x14a1e27164d8fdbdu64, 0x45af6345ec055e4du64], [0x9ec26df6fddbd7d3u64, 0x2d242a4a6a6b2d48u64, 0x55162c6f2fbdc410u64, 0x2b1bd7b9f3e5d4f6u64]),
-    ([0x34277686b037a511u64, 0xaf2f158931e7234fu64, 0xd86327fa50db777au64, 0x3bff36a53627e337u64], [0xe83b41893708e741u64, 0x09094f4c8c769631u64, 0x3575fff74df0d560u64, 0x3e78fc309cdedbdfu64]),
-    ([0x1f5753e3ccc4922eu64, 0x4030d9ac186fc545u64, 0x4b024c21cfa23025u64, 0x41ae33f624c17d07u64], [0x2beb183f09020048u64, 0x228e67b47d6d4792u64, 0x332938051e31d891u64, 0x041ad03d6cdbfd2du64]),
-    ([0x551a3d49cec26fffu64, 0x3b17df56d813171cu64, 0x1862d870c69ea23du64, 0x34dfd46e142d366du64], [0x9ee1f28901690730u64, 0xcc8d23dbe8742693u64, 0xe51b9c6e29b85a60u64, 0x371a80c7448622f0u64]),
-    ([0x022193b333c3b855u64, 0x6e8df3051d69e220u64, 0x2e1a54f5ed6dac0eu64, 0x3683287007cdc98eu64], [0xd405a84d0fc9c2afu64, 0xa613f06c1d5483deu64, 0x205135030f130906u64, 0x436a9b2245d29253u64]),
-    ([0xab93f25b2906d21au64, 0x7e5cc1b32b2a1950u64, 0x7cf3f6638899a009u64, 0x671e69a09d5aa658u64], [0x96796e0506b6618cu64, 0x66c1bc1670906478u64, 0x6e42bb9b70787dbbu64, 0x637051f8bd5b8549u64]),
-    ([0x6b1ac0499b422b78u64, 0xa2c9e646f0bc30f4u64, 0x9efaac6d4efab2f7u64, 0x178c36685571ebe5u64], [0x8301dc1d0b22adc0u64, 0x5947a918b28ba886u64, 0xc7c83694669a8b59u64, 0x03b625451600bd26u64]),
-    ([0x68b67310ef09a9cbu64, 0x6b82345ac27d7e48u64, 0xf2507c5f193db617u64, 0x110f92f702edb6d0u64], [0xc0a1632f38cc584au64, 0x307cf4d0a4031e29u64, 0xaed34684b03ab799u64, 0x1df9aa5d22e716f4u64]),
-    ([0x64e8c456229dfdefu64, 0x71bdbdcad82928c8u64, 0x07e780cc7312dd53u64, 0x67add2940967b1a2u64], [0xac834eee31a3e112u64, 0x7b349ada45923167u64, 0x37d5a812ff710ff6u64, 0x56f33edd8e43914du64]),
-    ([0x1c6f641f5ca11dfcu64, 0xef967c6423a55ee2u64, 0xaac3e29a57a5f0d8u64, 0x00f12962daeacf09u64], [0x46ffdaeb1ba31322u64, 0xbd4ac0df8086309eu64, 0xff5840a25760bf5cu64, 0x50e56ace1506626bu64]),
-    ([0x4a33b4ca4fa500d5u64, 0x77ced4862f3112b5u64, 0xccb9d7408ba4ad4fu64, 0x46d5e64e6e329643u64], [0xc343dca607c1dddbu64, 0xf673fad1d40c3105u64, 0xc2160cd0f25b835du64, 0x4ea45ffb5fb3a8acu64]),
-    ([0x0c599e831e62b8d6u64, 0xdc4e43d7d4c8cc80u64, 0xc64b7d75ada9bc94u64, 0x20c6fe6e13f0c70eu64], [0x51eeb6a203ae2736u64, 0xcbf601c50bc743dcu64, 0x614f232012df07e9u64, 0x61ac4b237703fa89u64]),
-    ([0xe95f38660e9d1536u64, 0xdb2feb30d6711c70u64, 0x6eae360ede81eba8u64, 0x0832d7d20dac46edu64], [0xa89f3bec570e4fb5u64, 0x8cef7dae4f358ac4u64, 0x45408f4bc179c36fu64, 0x277f752c1fddec8bu64]),
-    ([0xf9a5ad12c1f3853fu64, 0x01e17e99d8256761u64, 0xe23f188a9a547b7du64, 0x6a6d36423bc3f0fau64], [0x9089eba668c58121u64, 0xfbd325d141f1c77eu64, 0x47d2d130cd07ba9bu64, 0x6e15d3f5bb5f9d8eu64]),
-    ([0x2f67888351b3aed0u64, 0x13a019a046396b88u64, 0xab8427037d4aaef5u64, 0x00df4490a807026fu64], [0x4c7b38f803b0aa28u64, 0xc565a80ff10230b3u64, 0x39ba67b3351c8c5du64, 0x4e62a39956b7240eu64]),
-    ([0x1813365f2e4dd33fu64, 0xe3071011023ca1f1u64, 0x054103abfd750fa7u64, 0x73d863d9876c3af1u64], [0x3bd714f3ac60fe51u64, 0x37b251a145b4f1a7u64, 0x6392ed75b1655814u64, 0x672322f816bb6f45u64]),
-    ([0x360c60997369df4eu64, 0xbf6e88fb4c38fb8au64, 0xb4bcd40e22f55448u64, 0x50e0903a157988bau64], [0xb7d040bb38b32fbau64, 0xd86ba93d51a984f4u64, 0x4d15a37bdee8a463u64, 0x1921a22bdbf5ffbbu64]),
-    ([0x9d6862e69abec349u64, 0xb6cfc07ba2677931u64, 0x7ce80043e235fce4u64, 0x27a57fd1ffada9fbu64], [0xe5441efe99257edeu64, 0x1806f01b5fe25b6du64, 0x5891851f787f6783u64, 0x5b4b9018a8e1bc7au64]),
-    ([0xdc6c5431b7a01194u64, 0x04ec083a342b6152u64, 0xa141881064cd54d3u64, 0x2aaf444efe927bc8u64], [0x2e14cb3cfd6e2e94u64, 0xdb7c73c80f905b65u64, 0x33f4f09bb5ba13deu64, 0x349e8c8eb6fc7587u64]),
-    ([0x730881f99cce2b05u64, 0x3bb530ecbcf382bbu64, 0xdfa201d28456bec6u64, 0x008a97b86edc1c02u64], [0xebb5b27ef5cdc735u64, 0xff6a44c8f291a2afu64, 0x859470a42fd6329bu64, 0x6a304234323df6e2u64]),
-    ([0x164881d4baa8a72eu64, 0x2e10ed5bae398c9cu64, 0xde48a52a93151b35u64, 0x443237284ec1415du64], [0x528f104d203201acu64, 0xd905a5dccbdd4cc3u64, 0xd4d893f632639286u64, 0x6e1968eba7e91e67u64]),
-    ([0x13b864a44525e8e4u64, 0xe8ee64f977b0bc82u64, 0x7e9d59a9016efb44u64, 0x1b4d576fae62c274u64], [0x3b2547cc86fbada5u64, 0x912244e9652128f4u64, 0x105771e084ad551du64, 0x43ee6a04d1b55768u64]),
-    ([0x452ccd3a84afddffu64, 0xb4df55fe0e814040u64, 0x7f89e05099b9346eu64, 0x4fc3c0bccf122cf1u64], [0xa5ee9295064fc0afu64, 0x8dddc96687fbb924u64, 0xc34c6bc8410573cau64, 0x115fa326095bf3a9u64]),
-    ([0x768d55926c7e753eu64, 0x49eb780345d8d48eu64, 0x1ad4958bc9e70004u64, 0x566499ddf792e537u64], [0x6feb58ea835bd987u64, 0x4122c4177d359c4eu64, 0x50679019a84ab74cu64, 0x607c2ea956909001u64]),
-    ([0xed9fa5c032ec0a23u64, 0x7e7d9fd5726f6b40u64, 0xa4512dec6b0a0337u64, 0x5f2fa72e47136966u64], [0x6ef73f3005bb3f18u64, 0x1a5723d4917ce7f6u64, 0xb90bcb81e9feed5cu64, 0x6a3de35319daea4du64]),
-    ([0x98d0402a397d3074u64, 0x3216c96bf3f6890au64, 0x25ff7d17d595046fu64, 0x57dfa203d88e6269u64], [0x8a618a7dd97cc22bu64, 0x1c4e0a16946cf4c4u64, 0x5dd7cb6769bf199cu64, 0x071cb99fed1980efu64]),
-    ([0x4a865bf20d7fe4ffu64, 0x7ad1a69ef390c21du64, 0xbe6bd1e602f93f1eu64, 0x0a607ca7fee6f701u64], [0x6d1066f0376bb6bau64, 0xe445662ababa10c1u64, 0xcc6fa1ea0e77e2a8u64, 0x06eadc954aabe0eeu64]),
-    ([0xeb15f8b2e0dc45d1u64, 0x3eb104627af5b12au64, 0x5d758652dede4d95u64, 0x5765afb9f051dae0u64], [0x2d51320eb745eae8u64, 0xe740f0fa064ee0a8u64, 0x985e7b4b0ddf69d4u64, 0x0d3bc262ed7c31cdu64]),
-    ([0x3f10f3a9abb257e1u64, 0xb11b8dd0e729d15du64, 0xe6c6e2fafbc3c935u64, 0x371883b64fd60550u64], [0x8e32020030c83022u64, 0x3960ea9fe3e18c48u64, 0x58e9693a96108f65u64, 0x117f804f38f690bbu64]),
-    ([0xddd48b53c0632b7fu64, 0x27de6c81def05707u64, 0x34868d055a78d4d1u64, 0x6482b79237478ed1u64], [0xaf6e680a8d307f14u64, 0xc5899d3420191683u64, 0x3668a529ebceac4fu64, 0x6da94cb47d43d1fau64]),
-    ([0x2856121a533306a0u64, 0xab9741e455322d31u64, 0xb2dd9a95731e9ccfu64, 0x5d63d41c2b82dcc6u64], [0xa8eae3b2c4247032u64, 0x918b0f5332b81fcbu64, 0xad7b7c57c179a851u64, 0x728a650725c164b7u64]),
-    ([0xc6cbff4af2be3916u64, 0xd5fd1bdd35d3c9d1u64, 0x585966d8d7855d16u64, 0x274d1e437926a687u64], [0x7beadbe39b8e2159u64, 0x766521d09bdf4353u64, 0xbe9deb559b376a05u64, 0x1a667a0527dcd877u64]),
-    ([0xa4593e1acca2cb6cu64, 0x46c3ebf43c92a949u64, 0xe3ee1645113940cfu64, 0x53c78adc7bff16bau64], [0x2dbb0861a3c37d40u64, 0xf4f3564f3c6ee4d3u64, 0x6f47cc46019eb51bu64, 0x1bc7d7d2f0c078ccu64]),
-    ([0xf0498fbd3aed65cfu64, 0x3a54efb0bc8d997fu64, 0xedd2c44927ecd41bu64, 0x21f9b926e96be027u64], [0xc7d701078337a6fcu64, 0x0bbb929f3f36cb20u64, 0x1c5aa17330ef947au64, 0x69e5919548366337u64]),
-    ([0x02e2f94cd781d69eu64, 0x36ad8bd407c8c41cu64, 0x074f9aa0ec1140c1u64, 0x17c1ec60d8a45e9du64], [0xe8b3ecbdfcecf01du64, 0x527d15dbe99527c1u64, 0xa4c2f668b07c8204u64, 0x3fdd26e695a6452bu64]),
-    ([0x7e797c34f167e1aeu64, 0xd8ffd9cd43748cd0u64, 0x95f9167176d30171u64, 0x32d0fb8721c1baedu64], [0xa7cf49fdba1388d0u64, 0x08ff29c5e5d7e20du64, 0x4ef749047eb72c8au64, 0x2f94de6664cfee03u64]),
-    ([0xe3d5dbe0d2f73e41u64, 0x644628b567af1bb4u64, 0xbe1e72f2066186fau64, 0x6ecab74a12568801u64], [0x8473460c5ecd1692u64, 0x43c8b3fca3bd61e4u64, 0x5a9717eb8853af31u64, 0x5412af2d41034245u64]),
-    ([0xbf12d1967f5228c6u64, 0xf062f50c1fa01a6eu64, 0x090ea1eafe6cd3d6u64, 0x21c7cccb86887bc1u64], [0x4d9c760dcf75a6cbu64, 0x4da7f966970f3013u64, 0x98d14d9967407c20u64, 0x6221af89ce1bb7aeu64]),
-    ([0x67cf9ca3e9f5b1b1u64, 0xd33764a3ff5f912eu64, 0x7cc088f970ebac3au64, 0x5f20bee4fcf83629u64], [0x05d775d396af24b6u64, 0x69078716602c82ffu64, 0x8c38d8e10ca2b0cfu64, 0x22a0278121aae018u64]),
-    ([0x3d6e0eb2267582d7u64, 0xad4396812497c58bu64, 0x9f799a4ac31e134au64, 0x342f19f1fc6fe5a1u64], [0x50b79416f4fdb8f8u64, 0xc3a2124eaa2ba4dcu64, 0xe1c44ae74fc61011u64, 0x17d9a9c4da64349du64]),
-    ([0xca7dadda50f555e4u64, 0xb04492b6f43bb51eu64, 0xb6a42eb341ff879cu64, 0x60259040823a88e7u64], [0x53cbc83da378362bu64, 0xdbadf8cc351c9eb2u64, 0x7c8944fb6d4388e6u64, 0x537e4c4c47b42cd0u64]),
-    ([0xc14aa34c68accbfau64, 0x6d9cde21536c74fau64, 0x1160383ee23ed3bau64, 0x17a9acabe5adc256u64], [0x4eb78c8eb39b9412u64, 0x4163c9f10ad2cbd5u64, 0x0f2cd6d793fa647bu64, 0x4799cbe0fa2c496cu64]),
-    ([0x1557c2f3467bd1cau64, 0x96548acb6d790da5u64, 0x7471e199a39130b9u64, 0x06b9515b1711e666u64], [0x585522751d70eb74u64, 0x45232a843cc0a608u64, 0xe67d7d2a0449fd7cu64, 0x3d7402eb199e97d5u64]),
-    ([0x0269e1371ad9a322u64, 0x40203872ef16ad22u64, 0x01ac67d632538bc7u64, 0x731a6fbb53c9f81bu64], [0xe46845f9d4b0c7b1u64, 0xfaf01e5e82c2371cu64, 0x592297b3c249705bu64, 0x192524048ed4a0cdu64]),
-    ([0xa4a7a41d55234864u64, 0xde785fd8b3542a38u64, 0x4789f746a17afd9bu64, 0x241f2b04f3a711b7u64], [0x11979ec0a0bb7d98u64, 0xf42f83be5d292ee6u64, 0x2650366ae4bcee16u64, 0x1fd20a7b2241cc41u64]),
-    ([0xfdc2fcf153ad903cu64, 0x6ed20df595f39f2au64, 0x0c03b16ef100307au64, 0x0d332ece77c82d2cu64], [0x36dc79f7a1d19ff7u64, 0x6ae018fa71be5320u64, 0xccc0e17b1424a6c3u64, 0x47e3a1d8433d1d1cu64]),
-    ([0x9281ca5182004249u64, 0xc112d6dbc4da70ebu64, 0xf7b5bc01b47b0c0cu64, 0x524f6ee71fba800au64], [0x74ade0a36a30e73au64, 0x653f14c7381a9affu64, 0x519a2d6aff9a3464u64, 0x47387be15327751fu64]),
-    ([0xa34807979b0215f8u64, 0x7c73d08bc76ccf5bu64, 0x663c27a5ae14f1c9u64, 0x6b48672456cb0e4du64], [0x619955a976bfc94eu64, 0xc8d69767718a8b32u64, 0x67383e86d7f9c0dfu64, 0x39b01a6cb4811e55u64]),
-    ([0x53ea61d87742bcceu64, 0x17beb312f20b6f76u64, 0xdd1c0af834cec32cu64, 0x20b1ce9140267af9u64], [0x9741dd0339578ac1u64, 0x1963daaf31a65f98u64, 0x2ffe89c6ec411effu64, 0x0e8a5658455f56d8u64]),
-    ([0xc8f54ccb3906df6eu64, 0x6a67db8f3cd65da7u64, 0x0a08b8806bf14011u64, 0x3ff0385ab3740f1du64], [0xa3519c49d0cf8527u64, 0xac22ea0fa5885190u64, 0x64e261fbf086358au64, 0x15d340c8cbf82380u64]),
-    ([0xa4c94125ad9dc176u64, 0x3e0c078154d7c539u64, 0x2ce4ba2c4bf31f35u64, 0x69050931b15c6256u64], [0x05aed1336e06b5d6u64, 0xad60ccc0209c8ef3u64, 0x04031e2616434038u64, 0x250bf689df1f292fu64]),
-    ([0xe1b3a80d3dc99e5au64, 0xfcc06812fcc08500u64, 0x87328292c58f0914u64, 0x0a7ade2bae7c6bb6u64], [0x74f5ba7bd85e14d5u64, 0x2bbd743191acda8eu64, 0x980ca53c12643739u64, 0x02616789007c488du64]),
-    ([0x5718b817f3ccad48u64, 0x358469e061a1c690u64, 0x2bf7a6f6d02f0612u64, 0x533597adfcf78a92u64], [0x4176e22f711298fbu64, 0x1fcf1009f188f056u64, 0x9f59313395ba0a7cu64, 0x2403aa307336b9e9u64]),
-    ([0xad4c4a46f5409741u64, 0x06a29e201b732f6du64, 0x09599704eba189b7u64, 0x17f3b39452ab3a47u64], [0xdcf85831b0b6503au64, 0xbe9b407ae9a20771u64, 0xfd429dcf8a7a3ebfu64, 0x2401b7bfd80c490au64]),
-    ([0x7055b514a4510f6au64, 0x555f34977fa34133u64, 0x42076ef74d0b719du64, 0x5dd7373ad66cee98u64], [0x3dc3a74590b2aaf6u64, 0x8559e1a8328fc8c4u64, 0x6add5f08f0fbb244u64, 0x22cd476788ef0601u64]),
-    ([0x0e5ae9a70d5d061au64, 0x0910558d9aa509abu64, 0x8ff79d5e980fce64u64, 0x4e6fc356d0de01d4u64], [0x333faf405801bd16u64, 0xca618026a8d07f83u64, 0x9954b1c670bf96efu64, 0x2dc5764d4990e9ceu64]),
-    ([0x7528d588439e5b12u64, 0xbf136819ab9c98a2u64, 0xae3320a20450eb9bu64, 0x1a8f3ae1cc22ee66u64], [0xa8a2b326598589bfu64, 0x46c4356356c83965u64, 0x092d2e6f158e5d8au64, 0x0bc0b0e6508954a0u64]),
-    ([0x1784febbb03b452fu64, 0x30d8f9f506885a4fu64, 0xc9809d68741ff12au64, 0x0d36d0cbdf62af6eu64], [0xb6103b568afe401eu64, 0xc982c8c3641fbfbbu64, 0xdf726ccebfbeaf71u64, 0x15a06f4b84bc8c40u64]),
-    ([0x3b9f40dc39226ef2u64, 0x244ad0183cb4d23eu64, 0xc7e207d405cec60fu64, 0x4c63bb8a5f91996cu64], [0x7e1cae387ae625bcu64, 0x5cbf5c3b6da328f8u64, 0xe01b20fdda0a1101u64, 0x3af7aaf0d2a5d2deu64]),
-    ([0x78bad60fe8295af1u64, 0x8ea6f095c3e393eau64, 0xcd846dedf76d82d6u64, 0x18f151570dd9c749u64], [0x9895106369379e5du64, 0x0d3f701e7a9d7f9eu64, 0x3e54e85e77a91c3cu64, 0x3bc94c5de1bab6b5u64]),
-    ([0xa22cb0c0043581b8u64, 0xc08111d9b58068edu64, 0xeea87dbd8b3e7293u64, 0x41185f69535823b4u64], [0x673695b1e637c4bfu64, 0x4b1179b0db979b26u64, 0x198c1274fbf954e0u64, 0x6da015142332d70du64]),
-    ([0x159a067743cae6c5u64, 0x06a240a2a069113cu64, 0xf1d0c3c19d09cf93u64, 0x585aeb7f91a0d0a7u64], [0x8927fbfd050041fcu64, 0xdf6b83e23c6e21e2u64, 0x6b368b69440fe33au64, 0x35605395ef39300au64]),
-    ([0x41fed1c1f077470au64, 0xe65fe456e0de7745u64, 0x3c011284ce2c0a5au64, 0x58eaef9cfd0d5b9cu64], [0x9ef21edbbda90740u64, 0x8f89b9b28f34544fu64, 0x81c352e4cbbd10a2u64, 0x5740e25ec63fc4deu64]),
-    ([0xb31a1acd9d26ce3cu64, 0x63e5f85ddf3c1b5cu64, 0xf81ab9316428dff7u64, 0x35050c64c68dd203u64], [0x41fa15579b6d16b4u64, 0x7d5f14d1ce9248a1u64, 0x815cd5d18bf553beu64, 0x01a72647780924a1u64]),
-    ([0xcf065a270f3c324fu64, 0xe412abf7f3a89e7au64, 0xf6ad66524f0376d9u64, 0x54fa64fb4536c4fcu64], [0xbd86256e917abc2du64, 0xb8cfde000ab23754u64, 0xca60f95fc2c06057u64, 0x4298e6d654348925u64]),
-    ([0xb08e0577296c2a24u64, 0xce6fa92e866fdb2fu64, 0x640ac08abd3c0874u64, 0x699aadb60d8a7bf7u64], [0x2d4010a0fe3026f6u64, 0xda5a4b46d5a9d427u64, 0x144c7c2b4d3bfc53u64, 0x183461a334aec123u64]),
-    ([0xf214cc49a9ca287bu64, 0xeee0344c49d79b66u64, 0x7956c16f3a78ae28u64, 0x51ec7bd852e497f3u64], [0x870ce6a35c2fd485u64, 0x9ffd65dbc0853d91u64, 0x8f19d1871f202f75u64, 0x6f2e6b90133676d3u64]),
-    ([0xfdbc5c8f8076051cu64, 0x101780f66062d06eu64, 0x12f30d72abc32d15u64, 0x2214ff129029040cu64], [0x96e3adb2ebbf13ceu64, 0x2f90ae2b46ce29c7u64, 0xbdfa560c72965082u64, 0x119d7200b31e67f3u64]),
-    ([0x148277eef8c0a297u64, 0xd93d29335383e69au64, 0x1bd07fc9fc34abeau64, 0x328f64192e295d85u64], [0x8dc4bff011eeea9bu64, 0x9111145b0f7141aau64, 0xeda73d9972641114u64, 0x19aa87e649a8d40eu64]),
-    ([0x6bb7b793ab94d67du64, 0x1529130b18a08ed6u64, 0x96987520a4a84e53u64, 0x6d86d9b87eff068au64], [0x796ee845ddb1f26au64, 0x0f24ea243b9473a6u64, 0x373aedf95fb521b2u64, 0x0a6c3c717636b75eu64]),
-    ([0x85336974811c697au64, 0x286d85a7faf7c628u64, 0xa6266415c4d6ced2u64, 0x3b35bda65519ec38u64], [0x77c20948ef64d752u64, 0xad6decc080f8335bu64, 0x8d1dd1839d2aede3u64, 0x0464ad99ddb1527cu64]),
-    ([0xa0521ef18aeb112bu64, 0x0eda57a2483dd819u64, 0xd0c49746d9253f44u64, 0x5928741f3f8e164du64], [0xba918b35c829f0fdu64, 0x29fbedef3ee979bdu64, 0x1b42a3cf10b5c6d4u64, 0x4d50b62ea8dbd535u64]),
-    ([0x440dab45cd1fac5bu64, 0xe19486bb67912a2bu64, 0x75583bdb136e9f86u64, 0x1a9d03d7743e9a06u64], [0x09435c3bd9db6422u64, 0xa4afb697dc9e1bf6u64, 0x95d1413d474cc22au64, 0x5e56150bf2c02b3fu64]),
-    ([0xb5006d3e54988604u64, 0x4306b58844f30ad2u64, 0x8a3ffa39d11f2605u64, 0x51cb08e682580ff6u64], [0x17ee7af9e8d16dfcu64, 0x96646110e9316ee4u64, 0xa3f657866dab522bu64, 0x717e6c0c4d546aa6u64]),
-    ([0x49ad9eda3ab2596bu64, 0x571169992c0dfff8u64, 0xfec2c1e6f6956739u64, 0x6c32ed13f5196adau64], [0xdbca2f668800c00au64, 0x7b4250e2729b765fu64, 0x9bc4dfa48ac76270u64, 0x37aaafa6c3761c7cu64]),
-    ([0x5a9925b13969600fu64, 0x34e49f3bc59065cdu64, 0x07faa3df067d96a2u64, 0x62b28418db7c8f6cu64], [0x7c07d692ea651f54u64, 0xcc3f56abfce5676fu64, 0x1cdabe18302348bbu64, 0x1e6278dc2272c1b7u64]),
-    ([0xd2878366fcec15bau64, 0xbdca8e5c4d2dc5ffu64, 0xbdb6bf288d790d89u64, 0x5d19702dd52769aeu64], [0x40f58a30ba4ccb1au64, 0xcbdbd30e42256f15u64, 0x4b31656e55c67df0u64, 0x4351e225b32b94d8u64]),
-    ([0x77ab619f936cb9e1u64, 0x2dee61519e282a18u64, 0x14a79f3664b930f4u64, 0x2e62f2c40c622d5cu64], [0x96be35b2748e57a8u64, 0x5276f1f72969ba24u64, 0xb567b1d30cd7a902u64, 0x4a143dcdc4c099d2u64]),
-    ([0x3c520c2b1a297a42u64, 0x3fc01d3617a56336u64, 0xa5455416cfcec9c9u64, 0x57d11b34b95dfdc7u64], [0x9102a7f8637a9babu64, 0x3b1ba9a245e87d1fu64, 0xf3de3f89ffb61a23u64, 0x41ac689798ffe869u64]),
-    ([0xbad4d376d1513768u64, 0x748bc3b8285c996du64, 0x175f0352ab72b4f8u64, 0x5ebd7256e5b76cb3u64], [0x0cfbe1a525d8c5a6u64, 0xd403bb005f589e10u64, 0xb42090c1a1bae6ceu64, 0x1deb6bd4861191eau64]),
-    ([0xe69679532ae1234cu64, 0xe3317ae6451bb89du64, 0x86fa69d8e4e48506u64, 0x0461237e58fcced4u64], [0xb6997cfb22c0008cu64, 0xa19250d6e71fe7bcu64, 0x575e93652c19739fu64, 0x052c13c70ad9b3c4u64]),
-    ([0x8c2af61a8141cd2au64, 0xc7597b23df05312fu64, 0x8edf76cab18f8a37u64, 0x08f60fd36013a65eu64], [0x7f12ee8f9d3b6287u64, 0x30c57cbfc64cf36bu64, 0x208918e43e91c8c4u64, 0x0cc7662782d4f53cu64]),
-    ([0x578545b4e64baae6u64, 0xdbd0cb7ff2325177u64, 0x0a288c1ac768fd31u64, 0x344b86f9eb22d572u64], [0x3bc661a043b85828u64, 0x56a7acbc9dce77f5u64, 0x8099e5b0ad7aa477u64, 0x16dc35402339bf96u64]),
-    ([0x2fb01e7994ae9e07u64, 0x6d1f5a7f98a0d41du64, 0x1efef1ea963a619cu64, 0x7145266275af949du64], [0x44e0378dd86970f3u64, 0x18b2be87f8f3b97fu64, 0xfdb58cd6641c0c7du64, 0x1dc8d5b50a648fc6u64]),
-    ([0xfba96832adfa9266u64, 0x8b576cd5a085170du64, 0x59b31745ff67db9eu64, 0x4e340f08c01d85dfu64], [0x8426994626874b40u64, 0xca87232c6c595c67u64, 0xf6555233ad0da410u64, 0x1bb26770a4275278u64]),
-    ([0x89831ce42c6138b3u64, 0x2adcf47128786d21u64, 0x60db58e449ef2a89u64, 0x03f6498eb85e7023u64], [0x1bba616b443dbaa6u64, 0x5058de4e6a5c0c83u64, 0x2ead14d2ccdf8eaeu64, 0x3ec05425a34e9722u64]),
-    ([0xf9b5ad7e3464f5a5u64, 0x51339834601eaf9du64, 0x056f4fd9e2e51744u64, 0x510f51053381165eu64], [0xf00a8fd61888cc34u64, 0x1d3d299a0d2a9a2du64, 0x9c6384a316238ea3u64, 0x4c1f0deaa0e64c9au64]),
-    ([0x9da284bedb2f43d0u64, 0xe2d620293ae0ef1du64, 0x798c9348185b0382u64, 0x3b69180a5114016fu64], [0x9f5993136423849fu64, 0x09a8fd897f63a03cu64, 0x68652155a30b372cu64, 0x2121c89b4ae10f1eu64]),
-    ([0x095e328eda2f8e5cu64, 0xa9ffeee172f8ac74u64, 0x0468b4bb2b446eddu64, 0x29048571ac60a4ceu64], [0xc7b72ca6c49c0256u64, 0x1711446e6e1de565u64, 0xf61f063fcbe335a5u64, 0x36a3a8ade335d774u64]),
-    ([0xb850d2a3eabf958fu64, 0x99e306b538773eb4u64, 0x4c0bb60b247d0d64u64, 0x33dc7355217fce07u64], [0x868a4343ea1a3b37u64, 0xb4ce4a14e0283a99u64, 0x4e7ea2b6727d845du64, 0x22207b219da4f0cfu64]),
-    ([0x416d25664132e681u64, 0xa8f5f8fb1cfd35bfu64, 0x5d468a777ba29478u64, 0x63a1acd15cff4a08u64], [0xb5cae130229e9483u64, 0x2dc166e68f90da88u64, 0xf498b04498a10d95u64, 0x452d83c0e4478a33u64]),
-    ([0x52ac9853b184617du64, 0x30bc3f5982e5d13eu64, 0x2818670f0f6e1efeu64, 0x5db5cfe5971c4e6cu64], [0x2094aac4216569a7u64, 0x2b71a81d58ce52c9u64, 0xc7ef99d7805a4f3au64, 0x233d29514c087385u64]),
-    ([0xb3e6eed5afa1109bu64, 0x613ec884963a020au64, 0x7f5870bc08c40816u64, 0x5db4ced439e3c789u64], [0x98f0e4489032bc5bu64, 0xc60b8bf8ad0389bdu64, 0xf3fcd5f107e39327u64, 0x4ccf5caf7f61e197u64]),
-    ([0xa57428937e370687u64, 0xf4e255d810db7c7bu64, 0x4741d7afe1d1739au64, 0x4cf72e7f20b13879u64], [0xc4a678c0d0ed891fu64, 0x1ae6d3790742cab6u64, 0x9d0dcd5a03f09852u64, 0x47a4b05eac9c6cd1u64]),
-    ([0x743e6cd3f2d8b5e4u64, 0xcb62629752f7b4acu64, 0xc24956b7927d30eau64, 0x1874b727404262a9u64], [0x81f2accce8e6b9edu64, 0x002f6a51831a0545u64, 0xd3c5b253d530395cu64, 0x2cbd0876e7497cafu64]),
-    ([0x2fb97ddcbb685b7eu64, 0x0b43fba07004bb35u64, 0xd4df8ea0dd402af3u64, 0x16aafb15e4fc1addu64], [0xd47ce9c4b1866de2u64, 0x447bc19d5a7ea935u64, 0x94d027969b80da5cu64, 0x4b2433bcb72db410u64]),
-    ([0x29b4cb21179cfb0eu64, 0x6a7493f160ce4cb7u64, 0x7f799cf20f675fdeu64, 0x036b79931cfdd894u64], [0x9fc838976ab0ea72u64, 0x91c589719562b78bu64, 0xb0b2dda89112d140u64, 0x3c0774c5eb76090cu64]),
-    ([0xf94c14f22139573fu64, 0x7f69be7aef4c8d1eu64, 0xdefa95e48ec5f0dfu64, 0x4e126f3cc7d42821u64], [0xe4e69069b7553ac6u64, 0xee38c43d3751d977u64, 0xbc39a486af730869u64, 0x2add9a010c6e779du64]),
-    ([0x39a8f2d056a4027du64, 0x76712f65dddcac53u64, 0x99713674356834f4u64, 0x59ac8447f4db2f25u64], [0x8a6b5c725d00552au64, 0xd81aa5b0c262e401u64, 0xdac50086415cba3fu64, 0x046273356d1a6501u64]),
-    ([0x1d58d36d2887248au64, 0x30b40031a396daedu64, 0xfb4b5fe309008ffbu64, 0x25d6efd47eb0b490u64], [0x4783bf72e2637b0eu64, 0x3c41f6db32510e76u64, 0x5845ca6fcc76ad13u64, 0x3b442e961b3d1824u64]),
-    ([0xb46b6df596ba6ecfu64, 0x1c54e5045f96bdb5u64, 0x15e83527ce053b13u64, 0x55e9d3064ba6d186u64], [0xff2b5557ab81a9d6u64, 0xf97ba4e36ebcf77fu64, 0xe0fc38d6e09dc48fu64, 0x3f0f634a854c8bf7u64]),
-    ([0x716d094756b66d63u64, 0xe8a933db67c24c70u64, 0xebad67ddc10c732au64, 0x04d21aa615b346f8u64], [0xf1b76cdda48d080cu64, 0xc738732eea76f061u64, 0x5867f711a5d5df59u64, 0x07db4e467ff5f4d7u64]),
-    ([0x8e69d125b2613b98u64, 0x91a08916e4882ed3u64, 0xc587252e3f738f02u64, 0x154d2412402056b9u64], [0xaba3be35c82dfd9au64, 0xe80b8b6c2c0d8cd3u64, 0x2a21d445321d99e6u64, 0x42d9bcd5649fa5d5u64]),
-    ([0x1f7cc4829e8423a4u64, 0x3aaba6e2d7d140d9u64, 0xafc992e5de759fc1u64, 0x2110b6b450e6f2bau64], [0xc168b0280c86e277u64, 0x88a53be6ddb9e86au64, 0x1c298248f572094eu64, 0x60b73c6a96558652u64]),
-    ([0xd5f57c79b59b2332u64, 0x45fb135ff413a356u64, 0x9c7943d6a33bc393u64, 0x61f273726e940997u64], [0xc12c762806d0b042u64, 0x0280a920a568beffu64, 0x11e6e3ff06025e64u64, 0x5561675204536ec8u64]),
-    ([0x63482f5e6ac7f5eeu64, 0x1b0a9c3e533dc34fu64, 0x64f3c71a7fc4285cu64, 0x1ef867bf56026f12u64], [0xd9716d420c43df39u64, 0xea90a44d2587dc24u64, 0x0be6217cca0b1995u64, 0x4d1d901b407c2c8fu64]),
-    ([0x536a984065c92adfu64, 0xf9e5fdb63351c0ecu64, 0x438d2dbf46dd5590u64, 0x0898e30b5b7bc5bdu64], [0x0686c175bb3cdf2fu64, 0xfd0d0b1ef9589982u64, 0x94911fc063678732u64, 0x5e04a92a484e343cu64]),
-    ([0x06e039f68e5ab3ddu64, 0x011b0f77f0d55ea1u64, 0xaffb53bd6eab1fa8u64, 0x3664860235a7434bu64], [0x9cd46fc214f65e57u64, 0x45f73d0402ece8ffu64, 0xd80253a4878360f7u64, 0x3c0be6d9d55f1bb5u64]),
-    ([0xe6b19f0b5eadf9cbu64, 0xd7da7cb26fe41c7fu64, 0x8ea3e9254dbb27eau64, 0x1c021c67b10780e1u64], [0xf7f1824f877d9910u64, 0x3fd99b6f783a921eu64, 0x804eee1a1f939ae4u64, 0x678f4c78a4c89d2fu64]),
-    ([0xb3a288e56cbe6ba2u64, 0x025b740bab2ae626u64, 0x3f5bdf1610b8e02cu64, 0x61be2ec9ee1056d9u64], [0xd887abb52a5c680du64, 0x4dad9cb5b25878f3u64, 0x1c1dcd71c4f85c32u64, 0x0c0ab219132a86ebu64]),
-    ([0x76fd4001b4f824c8u64, 0xb3f7ba1663b31d03u64, 0x4485c4aa168e870au64, 0x6fbe46853ef9fa57u64], [0x86026e34a831ba25u64, 0x1521ad73a29d63f4u64, 0xf90ba4fe2ae0d810u64, 0x15b305718c94cf3cu64]),
-    ([0x6d79c4d11332d72du64, 0x15da7702676dea39u64, 0x93042049534a4e95u64, 0x4eda2e7430a3a2a0u64], [0x5ea68fd629dbe509u64, 0x45388c3eb451f065u64, 0x5faf4f38f01f9aa7u64, 0x1acf674592d4dafbu64]),
-    ([0x7228fd3397743f7au64, 0xb38b21c28713b700u64, 0x8c0625cd70d77ce2u64, 0x345766f603fa66e7u64], [0x7582e991cc532e57u64, 0x6abe17d1d0f69d75u64, 0xbd54861a5076dbf8u64, 0x08afb6b246034389u64]),
-    ([0x128568bfed36d2d6u64, 0xd9d42ce812d4161au64, 0xa01a09222bf9650bu64, 0x652461d6a24bc012u64], [0x851cfc0042aeac40u64, 0x9fd850230fb2c15du64, 0x8456e2f377d7c694u64, 0x2416d15c35174542u64]),
-    ([0x9b2ee1a0bf112931u64, 0xd66c91b560c5b82cu64, 0x0eace99e48f3d3cau64, 0x243adc55e43f779eu64], [0x04fc74fd49ec8a8fu64, 0x44a3e131a3e0de50u64, 0x0427738523b127f9u64, 0x460959dbc3a86b67u64]),
-    ([0x2458759b86aa9b46u64, 0xef1234f45f84b51au64, 0x5aa7436a8e41578bu64, 0x1adebce4857298deu64], [0xe628f296281832b7u64, 0xaadbe67e5b1bd6e7u64, 0xaf6c7579fbae8a96u64, 0x6899c8c4bacb2d68u64]),
-    ([0xadc24eba36b7578eu64, 0x7439e2b6fbd943d0u64, 0x072570e877993c3du64, 0x5a0629432977ae6au64], [0xa31543f1c19ed140u64, 0xac1872357587a813u64, 0x7cfdd92e4208ea88u64, 0x009d9689d9408a03u64]),
-    ([0x9ee500f647f7d43bu64, 0xe949a4ab02e82f76u64, 0x06ede0897762f302u64, 0x3cc1b7446f10658cu64], [0xf7e752f7dad53a00u64, 0x2bd3046d691e0cf3u64, 0x79efe72e50ee6270u64, 0x3e039512ad409529u64]),
-    ([0xa0d046361160c87du64, 0xf65f59426e561ac3u64, 0x4184a3278cbac76cu64, 0x1e28f67c9a365f92u64], [0x356af425d0bd6ed9u64, 0xc21129ea8a19e551u64, 0xec17b8aab3c4005bu64, 0x66895e6ddb47f260u64]),
-    ([0x18bb67ac498bb306u64, 0x26881eea9a94f19fu64, 0x7109bb87546c7be5u64, 0x1055dd3a18e8bc71u64], [0x9c28ba7ba591173du64, 0x599c8069d020f42au64, 0x3bb4a46999cb3143u64, 0x09c4157c0c8e447au64]),
-    ([0x2ef7664cb2440ed4u64, 0x5d1e0777f7ef73e3u64, 0x01fe51c5b744878fu64, 0x510df5b7cb3dee5fu64], [0x8b431d11311c457eu64, 0xfc49689b4c052e38u64, 0x141d56d017324612u64, 0x09b1f41e0b439e6du64]),
-    ([0xaa8f3f7b31a9d713u64, 0x91472415b0f60defu64, 0xdaf8ea26346303ccu64, 0x406e5bb263c0e75eu64], [0x2a6eb782297ca0efu64, 0xce088ceffa3f58e1u64, 0x52c85bb61e7a0783u64, 0x17aded30b7d5f056u64]),
-    ([0x9dc2b95bda888cc3u64, 0x0781b9b0030fff9bu64, 0x5f8efca0709e9fdcu64, 0x4f0a66c29c0c4d93u64], [0xbe17986fd5287d6bu64, 0x16c8511014c7d282u64, 0xe87f66c465d82e86u64, 0x5732152c25bcb9bau64]),
-    ([0x4e396cf2c8808fc1u64, 0x565167b6e1c6e709u64, 0x3cbcdfb94554c903u64, 0x403c2b3fd45a67f9u64], [0xb5c2938a762b7e73u64, 0xfd330090bda5db19u64, 0xe45a105239148802u64, 0x1af5aa96151562e3u64]),
-    ([0xd78b1ef5748164e7u64, 0x8d38cc4f07468cebu64, 0xb8a0671498f38739u64, 0x5e6856c04012bb93u64], [0xf8bad63c8a5ebe87u64, 0x1905b849bf403386u64, 0xf46753c7639ca494u64, 0x462b2a7b5f2de395u64]),
-    ([0x08d2342a11db2c67u64, 0x99d30122c8436844u64, 0x5058ca2944597775u64, 0x2c14e20c7f6c968cu64], [0x529d433822c69590u64, 0x058028061dc5ca94u64, 0x7efe096ce700db22u64, 0x12bf8fbb79528088u64]),
-    ([0x2ae6e2c121bfc3c3u64, 0xe1c5922e39ae0bf9u64, 0x4af1a2d79a24d27au64, 0x70009e36de006c05u64], [0xfc7310a487b9c306u64, 0x84ebd4042a32352cu64, 0xe8164457f0122eebu64, 0x073986c819d22f94u64]),
-    ([0x1b525693c4fdf276u64, 0xfbb05750b0757d9bu64, 0x50d8f5fae982ba3fu64, 0x6226a020a192f585u64], [0x6b9607d9dce987bfu64, 0x8854e7b11c12292fu64, 0xdf4ba400ca3dc3dbu64, 0x0e00073bacd99f75u64]),
-    ([0x8469ef4d246b6883u64, 0xe4dc0ae311f00af4u64, 0x71d544f7800a7e4au64, 0x5391ad6a79b61c1au64], [0x231952e51fa9c8e2u64, 0x7a160ef399772b8bu64, 0xf19a37d2ad81e9aeu64, 0x47171edd47657fd2u64]),
-    ([0x57b9d269efd0d9fdu64, 0xed74c9b15c2f6c81u64, 0xffa6a4d8853dc711u64, 0x349a43d2741d83a4u64], [0x7b38be20757a5466u64, 0xd90349eaab7efcacu64, 0x1413fbb764f87b36u64, 0x2af5b5bd1952f7cdu64]),
-    ([0xbde69ad1be866e28u64, 0x12dc15bbb51c14b8u64, 0xef56d22dab86198au64, 0x57414d702f536f1au64], [0xcaebac7bdcbd78e3u64, 0x0315eb04d3e8ece1u64, 0x4facebd9a744f4a3u64, 0x3da44a698a5d36aau64]),
-    ([0x611337171217a856u64, 0xb97d5dfa3a479c8eu64, 0xc9bffed16f4a90e9u64, 0x23f68a69cc191d70u64], [0x4573b0f30662ba5bu64, 0xaf46ab3506718164u64, 0x7ddb0a6a9e38bdc1u64, 0x472bb6f709ea89b2u64]),
-    ([0x090bcdd715521bb1u64, 0xf2730e303c173e1cu64, 0xfaaf9f04b421b462u64, 0x46aa7940ac524648u64], [0xd9af401b3fce7d6au64, 0x61ff9dc8ad6dbef2u64, 0xed7b334d265569c4u64, 0x21e5e3b397844374u64]),
-    ([0x99b980c752953536u64, 0xe771d0d9c1e612bdu64, 0xf3d2329d2bd130b8u64, 0x512626c472d9a268u64], [0x5609f42d666e4f96u64, 0x5605823cb125178eu64, 0x448166d86d544a17u64, 0x6f31f278e7092f68u64]),
-    ([0xa271966e405dcffdu64, 0x88a1f0d04b158d7au64, 0x5e3ddfac65da256fu64, 0x378feac8ef44b61du64], [0xdaaa71f70f7a27b6u64, 0xa8b8fbfe34034136u64, 0x49297b32f9298aeeu64, 0x54e1a9b7031947bdu64]),
-    ([0xc669d39e6258ef1eu64, 0x9b8c7c2afe21b8d4u64, 0xbfb88204fb95d750u64, 0x169602a17e71df56u64], [0x398d2132cc8a49b8u64, 0x6d09c5491e31b28bu64, 0xb839aff8e54b0c47u64, 0x3f8d644301b12df5u64]),
-    ([0x1d3e494f87fd6d91u64, 0x2ae21a225b58e59fu64, 0xbdf7f8f0d267d1aeu64, 0x249a8bf565feb0f7u64], [0x70998293a6fedc90u64, 0x190c84f8d80cd9bbu64, 0x5ac8fbd67953ec2cu64, 0x377a439c95fe9460u64]),
-    ([0x694d7d44aa42aaf2u64, 0x8d4ece6e79d374f7u64, 0xfc0117e00180409du64, 0x5c9825830d537d2au64], [0xaac2b2a6cb9f8691u64, 0x996efe0220298e23u64, 0x7403ab5e5e753504u64, 0x654fc586804b2885u64]),
-    ([0x0593110cdd147867u64, 0x24ca9f6f63c7ee57u64, 0x9943b8c1f1c32bb1u64, 0x6d190c5a1acc4e03u64], [0xa7bdf175741301dau64, 0x3e6ec019c2b0a9c0u64, 0x8ee7d1beac240401u64, 0x3eee097c39a0eb06u64]),
-    ([0x0d2607b31e34bf62u64, 0x8b860ca3e0b0c8cdu64, 0xbab6734e04debb05u64, 0x46b339b5bc3017ffu64], [0x37682d5edba19797u64, 0x83fdbceee87c5ea1u64, 0x7145bb5f4941d06du64, 0x4a334e108e92b468u64]),
-    ([0x7ee857bf1f253512u64, 0xf6a33e0d6b4dc2d8u64, 0x20c8b3bf4746e7abu64, 0x48778b55856bf493u64], [0x77894ea9d2759ed3u64, 0xa1158608b02e38e5u64, 0x744be30f9b6e6a96u64, 0x2a3e05cbe89b7a28u64]),
-    ([0xe43fdb36bf47bcc3u64, 0x1c3d1d54f8f2982du64, 0x6f2e9e0b45136d4fu64, 0x6efbba63f2b18adcu64], [0x0e9817c897d3ef3fu64, 0x3cfd1df2f884dbc4u64, 0xfeca9278443f6defu64, 0x32df5fda8ebaf817u64]),
-    ([0x077d390dd7d05b42u64, 0x5eb169206b7f3154u64, 0xe5084aaf8797a96fu64, 0x41970a68359a3f51u64], [0xa51a41cac564cdafu64, 0xfbcb81352d453b96u64, 0xd0b80ecb3292f0b0u64, 0x616471948dd66031u64]),
-    ([0xc829d3b1ec72d08fu64, 0xab5e55696d530d23u64, 0xcaae5a41e711829du64, 0x3c1ec09602866dfdu64], [0x135f36bb6f3edf13u64, 0xb0bc10fd78713e70u64, 0x976cd3da8bafcbf4u64, 0x47dc7fd23aa8dcf6u64]),
-    ([0x68cc0c58459f155bu64, 0x963dc46688b5e117u64, 0x5aea51f540eb0c04u64, 0x2c7e0457c83a7d9cu64], [0x245eeff9f510de6du64, 0x50353393da150894u64, 0x2deb290c0d3c950fu64, 0x41332f8c7a2ff569u64]),
-    ([0x3653bbc394e0194fu64, 0x8d626ce47a197043u64, 0x0c2d1e5df0ba7ba0u64, 0x1b070ed3d812e190u64], [0x875f5a0e12afc3cfu64, 0x7fdbafb96432c743u64, 0x9b1f3f9b25d451f7u64, 0x158ab78449de0125u64]),
-    ([0x1cfd232fb7ac2d01u64, 0xa34148b024d3ffa6u64, 0x1ac42c62e56cb955u64, 0x58ad8edaee2eeaedu64], [0x1398ec6181f0c39au64, 0xb7dab39448f8cb09u64, 0xcad3d9cb5fd6dad2u64, 0x181b53ca1ba462a9u64]),
-    ([0xf89b6e4cc3f8a8d9u64, 0x3b61b6a2481f0bc6u64, 0xc504800176c79985u64, 0x5048cbcfbfa6e1cau64], [0x4e0dc56cd8fa3964u64, 0xe6149e5e29c62c15u64, 0x22bd2d091bcd84f2u64, 0x4e426b5dea220e82u64]),
-    ([0xecbf5d6f01ea2fe8u64, 0x2772c833e4cf3499u64, 0x69ac30e42135fbc4u64, 0x485adb96249a23e9u64], [0x85e2c1a11907038fu64, 0x1db91511168bdfe5u64, 0x55b0284219bb36e2u64, 0x6a2b53fed5683d74u64]),
-    ([0x75fe4524a3df9914u64, 0xe582849fa29e9432u64, 0x3ec786b72669f55cu64, 0x043f4787c92fb600u64], [0x0512a54bc865aecbu64, 0xa643d9eb70a051c5u64, 0x00757eccd8311133u64, 0x19cad4352bd3ec36u64]),
-    ([0x6f9d0064fac7137du64, 0xb3627ca6b51d001au64, 0xefdb2f0828054b7fu64, 0x6f56ba9652521c23u64], [0x25e197309abee78au64, 0x936a355e84fb9234u64, 0x2d6f2702698d7ea6u64, 0x0f1f443d5abf0a18u64]),
-    ([0x144f897786ee3fb6u64, 0x554430904a87ce25u64, 0xe3de5d7d83eacc3du64, 0x2f7509193ddd55abu64], [0xb16068fdf6095f69u64, 0x6196b6b97e3b66beu64, 0xba813d3c2ac51febu64, 0x04c500332845587fu64]),
-    ([0x48af25b254d71df9u64, 0x039fa49ec41505c9u64, 0xa67bb3d018ed258fu64, 0x6c1cdf508052e8d9u64], [0x5a305a209d97746bu64, 0x521108aee7a487d2u64, 0xaf72c1bbc1f1e268u64, 0x5a8829b7f4dedc43u64]),
-    ([0x58550848d0259347u64, 0xa2e540b9c586fceau64, 0xdf9d9a8301247105u64, 0x046cba723ff8c505u64], [0xc7653d47d0da7f48u64, 0xcf99783bd18fbe8cu64, 0x0ff5a8b45bd2ebe2u64, 0x5e86d0a51d1b11d5u64]),
-    ([0xcc7ee15d01f39a0du64, 0x3dfbf6b6977f335cu64, 0x463dea24c6c2f011u64, 0x583940eb8f3ff496u64], [0x12f0d6bdddfabd3fu64, 0x9e68180e4aa2bb58u64, 0xf9b6d6539a7858a0u64, 0x384078f3676cb504u64]),
-    ([0x4f527ffbb148e55bu64, 0x031a77c155967b40u64, 0x22dad195f08db8c1u64, 0x4820124a85c2aa32u64], [0xd53e6372fb2d01abu64, 0x9ceb4cad5dfcb168u64, 0x9c7869df3f6eb958u64, 0x329db6d501763049u64]),
-    ([0x16a9e3e795d08887u64, 0x9380f5ffdc6ac2e3u64, 0xa189d7e4af45dab5u64, 0x0688ba2e026f2459u64], [0xfb3cd536ee66e9f6u64, 0xf4e8b29bfb9c8e5au64, 0xd0373a2a25d06d35u64, 0x51a4397b2bc91e35u64]),
-    ([0x91e05fa34880ee67u64, 0x483059b2dacb9970u64, 0x69ae844074894dabu64, 0x2374b79d4996ad51u64], [0x09b0b106614353f4u64, 0x3c79d3471ef21c67u64, 0xf2b467c39bbfef76u64, 0x1a0899d613ae9592u64]),
-    ([0x3df47cf236da17e9u64, 0xe92d7dae9287cd04u64, 0xc579b994cbef0c2cu64, 0x1306f534d4599079u64], [0x4cb3b7e7a7c9adbau64, 0x3b00e48f9367f548u64, 0x8aee05893f5181afu64, 0x32da6ce75748c367u64]),
-    ([0x64b877880b7bd162u64, 0x6ebec17bd32d28f9u64, 0xd561eb297d716c3fu64, 0x47321c8e1645a812u64], [0x08c9a669f3117a95u64, 0x291168b41bbee6f9u64, 0x30c514054a226959u64, 0x37fec25b20097aa7u64]),
-    ([0x476e05bf67d4973cu64, 0xaf9f1f01e2bbf0acu64, 0xdcd9ce528178852eu64, 0x5a50cc64ae610371u64], [0x624e9f257a4c4349u64, 0xa74a2f7ee47cba9du64, 0x76f5bfb62dd72838u64, 0x51c98311da7d3511u64]),
-    ([0x10fc77c70f6789ebu64, 0x2f35e832d903d529u64, 0x31420245361de9b1u64, 0x575f025cc14be0adu64], [0x7ff695280745f27au64, 0x66cfdf6c7c9c461fu64, 0x76bb165b9d04ec05u64, 0x3f17661e22f83e10u64]),
-    ([0x2ee4c0317b41459bu64, 0xbab9bbd9b0a3590bu64, 0xb5c82a603c67a3a3u64, 0x16fd8c1a3548e372u64], [0xe928f142feef9f21u64, 0xb1e8db0e93f2815cu64, 0x66533c81b95e34dbu64, 0x20081ef22a652282u64]),
-    ([0x5adb37a5ea82fe43u64, 0x0d1acd98fa4e19e1u64, 0xb522fed010b950c9u64, 0x39b764500bde4ae6u64], [0x747f9cfe4fb05546u64, 0xcea24ae04ec31678u64, 0x775719d498c228dfu64, 0x412e56fc892d36b2u64]),
-    ([0x3e16064aa9a77770u64, 0xe401cd8bef1b2260u64, 0x7fb60b67a741a4eeu64, 0x6635214dfb1c37d9u64], [0xdb9a56e54d03aba4u64, 0x53ac89ad2fb4d89eu64, 0x6e547a1040532643u64, 0x299b242a8b2074e4u64]),
-    ([0xca782131084255dau64, 0x1b7fcbf22df21291u64, 0x53aa8f5c16650400u64, 0x2164b49750ddb832u64], [0x3ef417d76e161558u64, 0xafe99887201d5305u64, 0x5756cc3ab4d81c6du64, 0x35810739aefe9342u64]),
-    ([0x187a081bc020c9e9u64, 0x88b1cebef9a7dbafu64, 0x2eb22d78a01a1491u64, 0x48c649dce1168ed7u64], [0xd84d8411a2bbde1du64, 0xca5a15687ccb131bu64, 0xcf49be243c0e33a5u64, 0x31ce746591455e00u64]),
-    ([0xd09645fe30b61c23u64, 0xc7b422e761321f21u64, 0xb23e7b35ed24a1e5u64, 0x395c9970ea51b0e6u64], [0xe0c860c42fa3f41du64, 0xa663ec4c3f3ba863u64, 0x7af543a2b7a044d2u64, 0x443d65189bae2b05u64]),
-    ([0xe9dc91a650a90e49u64, 0x5cae4a21d39a23beu64, 0xf2f3e98766f8c918u64, 0x16c359d76ca02af7u64], [0x7cee1ca979f8cc2du64, 0x357ff9ee55535ac2u64, 0x3730cc69c80895adu64, 0x248cb5af3d2a668du64]),
-    ([0xf4d7712a9f7e571cu64, 0x86375b2142604d30u64, 0x8ad290c1186b5781u64, 0x5b3ccab5043cc4f4u64], [0x6e5ab2c92ee0527eu64, 0x973e491b546cd450u64, 0x6b06194fb84b6023u64, 0x68925a5fd7144dc2u64]),
-    ([0x2b436d7a469e3045u64, 0x7a6d63f8b2850344u64, 0x0c095cf1e3315298u64, 0x4c75d3a13151f5dau64], [0x703e272981c92a79u64, 0x0bda372a8b6c2268u64, 0xd78dd0dcaf38f8cau64, 0x4940f9a8538df319u64]),
-    ([0x1490fae57a7c33a7u64, 0x1f3f2f1cdac0bdd6u64, 0x94c1b71405e3140bu64, 0x1189e357b3f96e6du64], [0x90983409ffcca11bu64, 0x28414bd8b6644a13u64, 0x12fcfd1452059a6au64, 0x647bccbc94ae2f57u64]),
-    ([0x85caa34fd9b2b6ceu64, 0xe540e7eb01aad13bu64, 0x337b8c94b4e3a208u64, 0x7275b1d9346b1f74u64], [0xbad6b659d595ddb2u64, 0xf0b82e2228152a6fu64, 0x271aa8d78c5c77cau64, 0x414c8adafad9f2ccu64]),
-    ([0xeb2b24461b367723u64, 0x500e3d52802ba363u64, 0xac9e3e8ba3ed80a7u64, 0x6e680dba5e260f57u64], [0xc115e133d1bfa774u64, 0x9dbfe217cdd124adu64, 0x990a94e955f20daau64, 0x06f256ed7f9c9722u64]),
-    ([0x0c0871ffc19b100fu64, 0x2aa959dcb76fe539u64, 0x894506e8611524feu64, 0x2f73ce8ba2c48aa4u64], [0x8fecedbc1abcb581u64, 0x5989b4b432f67875u64, 0x2e2f05acdf75896cu64, 0x08ae41ea792157ebu64]),
-    ([0xb52e13c743b9975cu64, 0x02e60667a30dabcfu64, 0x1a5f72c6f875ee76u64, 0x14609bbee3ad9bc8u64], [0x7b34d0bcf13035edu64, 0x346718560fecb102u64, 0x2694da65535c0890u64, 0x1aa11f112492362au64]),
-    ([0x807b811a823f728du64, 0x967a6b6cfb0faca4u64, 0x5ccd4631f16edba4u64, 0x1edc919ec91f38acu64], [0xb11a1f96300feec6u64, 0xd733bcf0225d29a1u64, 0x82e741e4e4e4267du64, 0x3a548eb4c483df6fu64]),
-    ([0xa78adc4c393f40fbu64, 0xd9b769a52be75f80u64, 0xeba7fb8a7fa58fc5u64, 0x07f38cf482eda4eau64], [0x8a0a37689d84e76au64, 0xdc8ea97f49c4af72u64, 0xb5e032f0d00ebf37u64, 0x3e34ab0c7f9b30ddu64]),
-    ([0xb16c92903c30681cu64, 0x44df8723cf6834a9u64, 0x4a7055b0fa4b32afu64, 0x2a337af602a41b0eu64], [0x5ce116df3915ef18u64, 0xb0cd2e937eabb432u64, 0xfd47e5587bcbefe2u64, 0x5e753173b8ed19c0u64]),
-    ([0x58bd52e8fdf0f3fau64, 0xbb1230bc0e387e95u64, 0xbf4821c2f7e1d5e5u64, 0x37550e85c8adf888u64], [0x175e663369d4c09eu64, 0x6874c1e58dea5231u64, 0x825ae3b7615a9c96u64, 0x662c6d8c34d553d4u64]),
-    ([0x08993b54226b7f93u64, 0x30dcd43dec9acd71u64, 0xfa481d537345cf65u64, 0x42083f58561023a7u64], [0x636fe61b96e3cad4u64, 0xe8ddcd2cddd1f00du64, 0xbd33e6bc261f3c37u64, 0x16647595e56e2a7du64]),
-    ([0x5213a84b62a68904u64, 0xd8e820d586118b4au64, 0x4add1ac0d9aa0cc9u64, 0x46e352bba176b0cfu64], [0x72baa507e82d5395u64, 0xdb73bf7edf98a234u64, 0x5989db0ccb16e6b9u64, 0x407c4e69734e98c2u64]),
-    ([0x46add4de51661716u64, 0x320e8aaa2c0adab6u64, 0xd332c8edcb31b9c5u64, 0x6f8c17a81ec992dbu64], [0x10692c305584333bu64, 0xc908774c1987a667u64, 0xde11d882181745cfu64, 0x0454f5e94340e82au64]),
-    ([0x119436ea05caedb2u64, 0xb66246eaafa91200u64, 0x9a29e30166d46cc7u64, 0x4b5efe0d05b93186u64], [0x2961f8c850731a13u64, 0xf6e6bb03d84fe6e6u64, 0xe05dd0fa23ec5d6eu64, 0x21ed52558955d075u64]),
-    ([0x18d303a266992f63u64, 0xf495392351a789a3u64, 0x25302664820cb7fbu64, 0x058e6bb22f520df2u64], [0x5b82cd3d0c682332u64, 0xf0ca619e0826c825u64, 0x4804e961b8dd002eu64, 0x5f174f5781aaa0fbu64]),
-    ([0x56cfcec9c01c3ffeu64, 0xf7f3dc40acfe2b72u64, 0x95d19d03d8fd323cu64, 0x68f0dab0be5128d7u64], [0x3b50a4572b06cbc3u64, 0x37a495a3d1b1e8b5u64, 0xa114799f83dfefcau64, 0x71251d1f1b28ac2au64]),
-    ([0x05e2e6cb0db28404u64, 0x557f4e7f70ec86acu64, 0xe07032ac4dd9f486u64, 0x222d71b2bcbcebb0u64], [0x1cf9151d33b1e9cbu64, 0x8cbb02a303359361u64, 0x64dc0cba64a0de28u64, 0x4602e55894ccf512u64]),
-    ([0x4fe8d6d7c4afe0a9u64, 0xf39183dce28ef801u64, 0x018debf684e68567u64, 0x2b70cfdf2f836149u64], [0xd25ec096a1011adeu64, 0xd4ef5c664b1962bdu64, 0x9f5e7a83bc72faebu64, 0x02a83ef3d5556f8cu64]),
-    ([0x0f15758baad3fcf4u64, 0xb06cbfc87f549eb2u64, 0x782fd931be0cba8au64, 0x18e17eef82a73dc4u64], [0x228d4379a2e99cabu64, 0x1fd2e8517000eb40u64, 0xd8e56c26ce88a72cu64, 0x710245bef7bac7e6u64]),
-    ([0x968b0decdd5c8bffu64, 0x2eb12fefba555a4cu64, 0xb401681bc0a21daeu64, 0x1c50bdc683722a25u64], [0x6899e452da9be959u64, 0x0d86a6c34ced4eebu64, 0x69c846b5b014a35au64, 0x171bfdfd9c03e9fcu64]),
-    ([0x45a8d91e955983d1u64, 0xd4f39aabdcbb7d07u64, 0xe05891c9ff9c3987u64, 0x075ded933c287503u64], [0xfadde5688c9965d0u64, 0x3f2f4b622e5595ddu64, 0xd3f56b11fb20fd55u64, 0x534784e908f8b048u64]),
-    ([0x7d77398fb0253409u64, 0x0a7c55cac91b4684u64, 0x14bc524e83f5dfdfu64, 0x18aa54aa601bd20eu64], [0x54d141bc536aef2au64, 0xb46647ad839a3b8cu64, 0xcfdb74ff951ff81bu64, 0x2d795d899a9d23f6u64]),
-    ([0xc0f1357a508e5e7bu64, 0x911f590ef73ba2bdu64, 0xaf59d0fc7261da72u64, 0x58c400aba73798bfu64], [0x26ef2b9092a4a0e6u64, 0x69001f5ad462e7e9u64, 0x475eaca87205d8b0u64, 0x0f1a9a85653e50fbu64]),
-    ([0xc3ad07b8488f216au64, 0xe712974aa30cb0dbu64, 0x076027ac452fa314u64, 0x27f8599a73b32821u64], [0xa71134650654b492u64, 0xd0b8515ecc10fa18u64, 0xa44b4c6c29c77c2au64, 0x69d283972c7c1daau64]),
-    ([0x7b51fa789fe811c1u64, 0xc02d67a18dc83827u64, 0xdf3b80de686838f4u64, 0x420a6d59e26f95d8u64], [0x906501c9f7ed8e89u64, 0x4a8449704a073beau64, 0x12247b12fa8a9dd6u64, 0x6e0571ce037207e7u64]),
-    ([0x242860ad1233361du64, 0xe1fee0e9919be7ebu64, 0x70c5f64dfff44c0fu64, 0x525151765816dd08u64], [0xe110bf17ca4fc466u64, 0x0b717500b7b9be1bu64, 0xe991794f98f71c35u64, 0x60006aeb7908170du64]),
-    ([0x520c8c4d748fb509u64, 0x8b7570b00fca5c9au64, 0x4b35861cb1da153au64, 0x3d0655665267f097u64], [0x59834154b4ad877bu64, 0x7ea0ed274bd41493u64, 0x88659c141f35eb2du64, 0x06b18e12839c4a4cu64]),
-    ([0x2dbb2cf227158434u64, 0x67977360b30175c5u64, 0x4b70ca6708f31048u64, 0x23182fe0ca12c0b4u64], [0xfbb6b43c007891c2u64, 0x98cc7df989aa66ffu64, 0x788f9dba419f9242u64, 0x2156bc9a249b4ee6u64]),
-    ([0x5e501a6aa6138595u64, 0xea1102eb66f6fce5u64, 0x36060cc57d5ad557u64, 0x6d3d8e2034154565u64], [0xb71b8ab6bc8d3ba8u64, 0x2d21d20cd60baf5cu64, 0x4f3bf0e8720623d8u64, 0x30867df959f295d0u64]),
-    ([0xf7715c2b59ea6e61u64, 0xaf6b343de16bed11u64, 0x555938660d5f1259u64, 0x34514bd44428726eu64], [0x8c691c29421cd328u64, 0x6ae77a59c414b895u64, 0x606c7e5c677760d6u64, 0x5646312e5d2c1b9du64]),
-    ([0xcdd815ee4d942beeu64, 0xe18a65bc0f8e4d1eu64, 0x0d10f88231dde2f6u64, 0x3336a05ea5c70ef5u64], [0xc1a9a3612ef4f9efu64, 0xd91cd6bcf1b1a556u64, 0x9e45da4467914b4cu64, 0x34c44cca32ed311du64]),
-    ([0x5beb4be6840f3104u64, 0x95309873a10967a4u64, 0x7085da8a68d064eau64, 0x5b93dee51318c6e4u64], [0xbca9bff926e8ed19u64, 0xcc53293f237a9646u64, 0x56445d41d9d8a317u64, 0x03be859f70082d92u64]),
-    ([0x83a202b9605bdc7fu64, 0x0a2b4bfeb55dfba6u64, 0x3d24b851b6e0e294u64, 0x5b37cd834da0dfe1u64], [0x4d145d49d1ca3a2au64, 0xac9d4ae8a9ca6f97u64, 0x67ab4890966e510bu64, 0x2c08de917937e118u64]),
-    ([0xc65654cc6e143494u64, 0xb0858e55d4b05011u64, 0xc650a7b8aabc2c90u64, 0x637a5f38c3c0928bu64], [0x93bc22355d52ed16u64, 0x9f1d383fa68dcc77u64, 0x32fabf35b1fcc637u64, 0x319808ae1d3ad841u64]),
-    ([0xef678c866ccd32d7u64, 0x897d166d26d7d13fu64, 0x1810c6122bee51bau64, 0x725d1642c7524b47u64], [0xa47bbadda7f530c1u64, 0x0b4d643ab6a071a6u64, 0x9e7ae5c44a032914u64, 0x6aa9b8fdad0e26eeu64]),
-    ([0xf28211eacc0710ffu64, 0x32ea27b86283f69cu64, 0x9578008f160eb020u64, 0x16a80a61fb468716u64], [0x5c00e2bba58443f7u64, 0x4e3467ce10db7cb6u64, 0x3d41429885ea7f81u64, 0x1b54c3e47bb15e66u64]),
-    ([0xcdcd38d6f117f52fu64, 0x37471747bfef212bu64, 0xb6548e4a26c46cf6u64, 0x260e873a1f951adfu64], [0xac95ea6e9743c0f4u64, 0x838e94a3c89de388u64, 0x1af58eb22c5eb869u64, 0x59158ccaba01886bu64]),
-    ([0x2bb250f695030649u64, 0xc125e1a5766d2438u64, 0x8f18e758585cbb43u64, 0x5977d5ae6d99265bu64], [0xece0a2f6c46ef60au64, 0x50fba73b913faf36u64, 0x8175e49d335a09c7u64, 0x44db1db15b18b577u64]),
-    ([0x82e68bc0bc50a88fu64, 0x62a6d195014b6410u64, 0x468b04010fa5c79fu64, 0x56f35bb8ed54ae00u64], [0xceabe156050442deu64, 0x5a8f10d0ab8ed166u64, 0xa2917eabcd634e30u64, 0x3e3466e0ea538f13u64]),
-    ([0xa60c85450b7b2f47u64, 0x4fb9cfa13c9e7075u64, 0xc6b58ce353a65ae2u64, 0x01856723c0c73b43u64], [0x3d544e90cea033d2u64, 0xa1c7bee93adf0d40u64, 0x57a7bc48131ae449u64, 0x00b785652a0b4105u64]),
-    ([0x2c78c12b5913ffa2u64, 0xaf9d3446b5219ed9u64, 0x3f01ab9eac85850bu64, 0x49e602082ceecbc0u64], [0x6601239de9c39e3au64, 0x19c9ac778c532055u64, 0xf25148fd30bd2c2du64, 0x28c40f257323bdd0u64]),
-    ([0x9d1a8d0cdf05b3f5u64, 0x0fab429c42218eeau64, 0x2485088dcd0eedfdu64, 0x4571ba420ebae0cau64], [0x33aed5c7800fb4d8u64, 0x7451a67554ffc828u64, 0x63a0611cacad3fe0u64, 0x3adafdda09f7584du64]),
-    ([0xee3b38a3813633f1u64, 0x96ce26afb69019f7u64, 0x4021f50f21b39e1du64, 0x6743acd720403488u64], [0x25838b70d4402261u64, 0xf9bcdf803cdeb1f9u64, 0xfb44c5c5f54c19bbu64, 0x4331dce4210e8e7eu64]),
-    ([0x62ad6e618cd63fc9u64, 0xb15c622bba78e96eu64, 0xb4fc5b308c0f64c4u64, 0x6f1be604e2122797u64], [0x2653a950b3d2b90cu64, 0x697c0708d966f9a9u64, 0xd35f6c05d91ab1dfu64, 0x268306ab3fa22131u64]),
-    ([0x4ec74d66ce5ce51du64, 0x55a5a037f3190196u64, 0xf74564930e6096c0u64, 0x6852d1a16317e2eeu64], [0xa709068042284972u64, 0x8a2cbc6fe9274b64u64, 0x889bd2ece3f4eba3u64, 0x06964594a6c14d36u64]),
-    ([0xd1b7b97da44a349au64, 0xe2cc886ec1f3ffe0u64, 0x0188cbbb016cf2efu64, 0x6934156b1645203eu64], [0x7064512db5af343au64, 0xac81048f4e7cb17cu64, 0xd5021e23f3ada0d7u64, 0x191d3a1d3d7d53a4u64]),
-    ([0x3f0ffba2ea149337u64, 0x15fb82877b46d20du64, 0x398677e14a8cb7e7u64, 0x581583626bca158cu64], [0xfe458ffd0df6d4dcu64, 0xf455ada983e1b7a8u64, 0x8e877fd421dd2437u64, 0x1f8d63aff12cf156u64]),
-    ([0xec4a327b5d6681f3u64, 0xa5d4dd6fb2dd1c1cu64, 0xa890bb7866fb5680u64, 0x421a978bf9dde4f2u64], [0x7a5889af5a9646dbu64, 0xa30a3636967c3d54u64, 0xcc13b7224a3ab869u64, 0x08f5f180ddd23ed0u64]),
-    ([0x626cf66312dc75d4u64, 0xc0323eed0b7541f7u64, 0x2cff5e64aca06bfdu64, 0x0521f0cfc53f02adu64], [0xc959b70178cdd93eu64, 0xa3120886f99c3f05u64, 0x3fc215b5d42335e4u64, 0x09b04923fd17a534u64]),
-    ([0xce89d9caeca2788eu64, 0xdc1ca7fc06a7652du64, 0x20e2ed344c1f0d27u64, 0x6fb241484cc523dbu64], [0x5fae9eaa9958bf15u64, 0x8c11c46cd60ae04eu64, 0xdeb512c9b294bf2cu64, 0x548ab80556aeee9fu64]),
-    ([0xcd4b696e7ea423f1u64, 0x4c72266b93838c16u64, 0x34c543b8647ce9deu64, 0x0c721336b5ca226cu64], [0xcb48f5d5b08bca20u64, 0x4077f658e8c1fea2u64, 0x86078355945d0a1fu64, 0x1bef7fba8e2c2dafu64]),
-    ([0xbedbfaac558c8d18u64, 0x30892679c5bf83b3u64, 0xc9997bf03a451a66u64, 0x3754e9ac49520d3du64], [0xcad6600c254bd4d2u64, 0x94799fd6c1412111u64, 0xacb532c295961261u64, 0x51970b96c9866a6fu64]),
-    ([0xc40d56452b59c640u64, 0xe3501d116ae74916u64, 0x5acd8b4a64b679a2u64, 0x32e8823696c82086u64], [0x0912e0db975c592cu64, 0xc7c7ae1c21889627u64, 0x150082e6e063fbc0u64, 0x5a4f84b9d973487au64]),
-    ([0xb4ad409f0a9afc82u64, 0x4231dfefa8056f0bu64, 0x67c605fb4aa50a46u64, 0x2e819150327c8a81u64], [0x45acebea60d3fe1cu64, 0x2ea3576d98292490u64, 0x14defd54398d9a0cu64, 0x6efcbd257a3614a8u64]),
-    ([0x0727b36db1974ef4u64, 0x4ee20d3cfc83311cu64, 0xe7caaba64af67621u64, 0x28c6d5fd4e2f04c5u64], [0xbcb1e43718632f53u64, 0x0004b94376e99faeu64, 0x1ae9f69f41c826dfu64, 0x59392843e4bfb99fu64]),
-    ([0xa2c7312172f024d5u64, 0x9a2c25c55d44a0cdu64, 0xfcbdc0f7c531d552u64, 0x4d16856c0c995405u64], [0x7ea73d74a0349850u64, 0x1bff9e5f6cdc3677u64, 0xeb85d092f353f763u64, 0x177b2fc1109d40b9u64]),
-    ([0x1985e5010d1e28f9u64, 0x597feef21aa1455bu64, 0x4a244131970ee26eu64, 0x5cf3f7d7a6997c06u64], [0xabd113d924c37b4cu64, 0x5ab80688f17dd914u64, 0x359fddcff3a8c460u64, 0x6a45620184729f9du64]),
-    ([0x93ca2d1bc7fd9263u64, 0xb23c79d13bd65d41u64, 0x6831575a9d83a15eu64, 0x393366ae43c4c3c5u64], [0x2d13b5b82b64fbc0u64, 0xcaecb7f88595f092u64, 0xc394a168cbe5d111u64, 0x432e5abadfa827d5u64]),
-    ([0x198a034b1e705c0eu64, 0x3c771d65b2ee56d3u64, 0xe04369454396c389u64, 0x255c117113c5d5a3u64], [0x31663ea98064f27eu64, 0xb7c63e82fac8b6b3u64, 0xd18deb3ace0f2b02u64, 0x294705dd1fe28759u64]),
-    ([0x02a3555fc5ee25f7u64, 0x10afc701df2ef92fu64, 0x8a2bb3e93d180d63u64, 0x4751471ca69edd73u64], [0xa53e27db23e6f565u64, 0xc3d768bad16f62ecu64, 0x6efdd8b4be82b6f9u64, 0x3d3f5b7aa01b92feu64]),
-    ([0x1848f2442fea2cc8u64, 0x493dc04931711787u64, 0xe204d5dce8844248u64, 0x2cb06e34562bbc52u64], [0xdb6ab52ae3fcf821u64, 0xc45d48cb683c7625u64, 0xdd61e9bd926ca8f2u64, 0x3e158310a250b03bu64]),
-    ([0x7eb1318aca4306deu64, 0x6ec7276b0e0087ccu64, 0x56fb40f6e0bf6690u64, 0x445143d57eb232ecu64], [0x621f9423f05fce78u64, 0xbd47ae0cdeec7d66u64, 0x563a21f18675a123u64, 0x713e1cb3fa60c8cau64]),
-    ([0xdbcc4af4074489e8u64, 0xe6409954448fd0a2u64, 0x07238a67fd3278fdu64, 0x1bcee4668304bec2u64], [0x7bf1ba0a75147f7du64, 0x3d98cd848070a7b7u64, 0xda69dd1e76e2f1adu64, 0x0494407019d17b64u64]),
-    ([0x2345e2553f15b27du64, 0xfb537def6e0f30f9u64, 0x87f3c352e16c5500u64, 0x5ae6399fdefa8ddcu64], [0x097668ad56ae41afu64, 0x90afcd8b3ddc22b2u64, 0xae4c6a044657e094u64, 0x2ea56aeb7741a105u64]),
-    ([0xe252f6a3681e0665u64, 0xfae92a5ff656e571u64, 0x8213bbd737bf0e62u64, 0x67b748279a4d3292u64], [0xc4c1dbd49fe25217u64, 0x1d9ce7764be29478u64, 0x64dec9c3a4cc059au64, 0x0495ebf7f876641du64]),
-    ([0x8505ca50e03d6419u64, 0x4a67bb8c3c1db6eau64, 0x358ff43badf4f95au64, 0x43409cbef4cf3edeu64], [0x2f1b991e632d8f4bu64, 0x27d8d3b4c924507fu64, 0xe83d70f6d84998f2u64, 0x42e33373635e54feu64]),
-    ([0x6cfaf4c817c13fe6u64, 0x30de4d0617413924u64, 0x57f5f1a3b50f2b7fu64, 0x00c50e1ec7a28bb8u64], [0x3ac9491060226580u64, 0xe756cc6285f1a228u64, 0xc2396d43ee6a4788u64, 0x0e51341933b46d26u64]),
-    ([0x8d76f7be4ffbdf0cu64, 0xa559d09e36445b2cu64, 0x8f3e53c99a199f8fu64, 0x5deb7d907dfec341u64], [0x554f372c28beaeccu64, 0xbe080f14114c79a2u64, 0xe7311649f53c940du64, 0x3b03d99e650692f8u64]),
-    ([0xa869ecf2cc0d850au64, 0x6a1592642caef3e5u64, 0x6e952e4835770833u64, 0x4164d792bef1368du64], [0x79a3159c46021e23u64, 0x6e5a4f34e1cd111bu64, 0x044d838aff9b078bu64, 0x4a0a563d9ae42b3cu64]),
-    ([0xf50cd1a8e4c2db19u64, 0x91d6197d6c736880u64, 0x878d175aaf9256fcu64, 0x3bdf175965785290u64], [0x9710443eda13a168u64, 0x73caf19d5877fcc2u64, 0x48381c2c09b76d30u64, 0x475e35ebc2392c8fu64]),
-    ([0x0001000000000000u64, 0xec03000276030000u64, 0x8d51ccce760304d0u64, 0x0000000000000000u64], [0x97239b28c0d4c92eu64, 0x6476a4f86978dc94u64, 0x8060b98df718f00au64, 0x2d057b610eec56d3u64]),
-    ([0x1ff95ac74f4dd55eu64, 0xb6042bde9573c4adu64, 0x2d00a5be2412a997u64, 0x50c8f518291d3e25u64], [0xeb0bf1291ebdfa85u64, 0x949f22e4759115dfu64, 0x4f234f69533384b2u64, 0x28f2208e9e99d880u64]),
-    ([0x2a5e7b785841a1dau64, 0xeaa07e9cbe5a10afu64, 0x9589c90d5aa47634u64, 0x668db67ad287bb0fu64], [0xd48f54feda912245u64, 0xd5eb853362db97eeu64, 0xe63f452282c6b9f8u64, 0x71e79f5e1897f3cfu64]),
-    ([0x40103db7fbe67596u64, 0xd8be7f08f42eb31au64, 0x9fbef78669ab90d7u64, 0x5cb376187ee6c626u64], [0x15c83bf7313f0e8fu64, 0x89b01153e4d94ee5u64, 0x18255d41735f5d37u64, 0x5e1b51a2dba69f23u64]),
-    ([0x62b851e3d2a34df5u64, 0x64505ce328284205u64, 0x604ccbce31863317u64, 0x663d0aa154b7afb7u64], [0xe38ffebb3d73f2adu64, 0x8a1f503cb8005137u64, 0xc553166b07802ff3u64, 0x193937c79060b7eau64]),
-    ([0x5dd0ba3600c21ea9u64, 0x3c58b769fd60bae8u64, 0xa68d1e587fd67f19u64, 0x442acba700d5638du64], [0x1433dbcbcb12d4efu64, 0x5cfdcbf676d513c8u64, 0xa8c32a0bdf9ab6acu64, 0x53477152cba1962fu64]),
-    ([0x831af13e1a684e43u64, 0x9a8d4f7d4f608b27u64, 0x1aaa8bc17630875bu64, 0x2669eabd406c3a9du64], [0x522e11cbc25f02a0u64, 0x8f7875b7c221984au64, 0x826d50cf463c1440u64, 0x0f819bfc810342cdu64]),
-    ([0xcb692d0528f44414u64, 0xfbef2c4c46e3ffffu64, 0x4282dc4972a3cca5u64, 0x05caacccb9468e3cu64], [0x4103f8bafc59e00eu64, 0xdd6e8099eaff2f88u64, 0xb2cb7e19c9f5583cu64, 0x6f6dd303102769dfu64]),
-    ([0xa0927a327c751043u64, 0xb830a34e5e4c7a31u64, 0x413ff88848100458u64, 0x30d2983de0110e23u64], [0x4577101e10795eb7u64, 0x4087b6a9efa0ad1eu64, 0x7a1081100bb64672u64, 0x07c458e8d3bd885du64]),
-    ([0x93b28aebc303f074u64, 0xea96d79f8a3e8e0bu64, 0x7007277851e8aec9u64, 0x24dbc9102a942d51u64], [0x0849c8f67bb304b9u64, 0x08a44b163a8c1f2bu64, 0xc1cb97a7aa614e93u64, 0x2b359a214ccde3feu64]),
-    ([0x97c7058ee0f32403u64, 0x60278442906cdcb9u64, 0x8c826031662df9e7u64, 0x6c7a15d0bc7ed9cbu64], [0x4ac0163956892e99u64, 0x5fefe85d85f891cbu64, 0xaffaec270f9a5185u64, 0x5f60eafa7f4ebe32u64]),
-    ([0xc30b3c3647dd9723u64, 0x13fcbf4084f4f9b2u64, 0x782c1cd532b1b613u64, 0x2b7382a49d6b5c74u64], [0x5d93e6e346682408u64, 0xcfe6a39ff3c6b11eu64, 0xdd0ba7a35deb21f4u64, 0x3f518256477b0b80u64]),
-    ([0xc7ea608db8cd652cu64, 0x404ef4c6bf3fe0c6u64, 0xf5389b96856be8b1u64, 0x1df57ca6fc96f67fu64], [0xdda2311555b55864u64, 0xc44e2a9b55713329u64, 0x31384485c5f55802u64, 0x57c72ac98f477a1eu64]),
-    ([0x5ddbbc4e9b6615b8u64, 0x979c55f6ffe871eau64, 0x93cbbee466fd2328u64, 0x005c0415d906203du64], [0x70f2cd57eb47ce1au64, 0xd1073b1eabae0f9bu64, 0xfc87831d74865c4cu64, 0x1adf55892b1bb8f4u64]),
-    ([0x5d970e0b0666e0d8u64, 0x0ad22b0908293b43u64, 0xb44d3b688f849246u64, 0x5b030683301968fdu64], [0x7054d632e9f8da47u64, 0x6ca11a069fa0cc36u64, 0xa3559771e438a986u64, 0x2fcc42346e54e965u64]),
-    ([0x4fde7e2d7a559fd3u64, 0xe5015a142b676f21u64, 0x003f81fccd9c6817u64, 0x083d6fb6de21ab8au64], [0xe400d4172e976638u64, 0xadf79ad9629c5930u64, 0x64bae8296ee37e5au64, 0x09669c29992ec34du64]),
-    ([0x9ddf46bac40ac8e4u64, 0x0bf20a6f5e170989u64, 0x916f129332ba2dfcu64, 0x28eb300e9079af0bu64], [0x3d4a7a5edd2b85b6u64, 0xb3cc9a0aa8275aceu64, 0x5e2e9419f560e1deu64, 0x72564b824027fd68u64]),
-    ([0x07379aa242bef13au64, 0xa65b11f7e8d79503u64, 0xa48d5cc9eb4b326du64, 0x3cd9854d6e4b49d1u64], [0xe7397c11af84bd48u64, 0x8d0904125c70e851u64, 0xb9610848e1e9c80au64, 0x356c4ab096c291d0u64]),
-    ([0x2318f1f6844036acu64, 0xfe4de56daf67ef10u64, 0x604297e1df5b7fe9u64, 0x709701b7108f6c67u64], [0xba27b179fadeda86u64, 0x28272096d633555du64, 0xe59d9f9f863fe782u64, 0x028bbe0c6637307du64]),
-    ([0xd341280e40f6ff69u64, 0xd771280f074d1440u64, 0xb3d4f002ab14fce7u64, 0x3657493dd2c18195u64], [0xd4937d1df65b867du64, 0xcdfc036a3f80516du64, 0x3fc56f8ce5e6e857u64, 0x4128f9926fc2a8f5u64]),
-    ([0x2efcfdaf4e265ab7u64, 0xcaafcb31434545d8u64, 0x9a54b1f2bebcea8bu64, 0x2fb2b48988b9dbaeu64], [0x9895e0db63f3835bu64, 0xd1917a67931b1390u64, 0x90913742a84c9c4bu64, 0x73c5e20a1bf8a6fau64]),
-    ([0x8cfda586295ea84bu64, 0x83c8f5dd63301302u64, 0xf14b86bb7c12055eu64, 0x3da07cce960c7027u64], [0xf06177ed64ec0632u64, 0x2d07d39e7a9c952au64, 0xa6c1cfe77ef96eb2u64, 0x64303f763327ef1fu64]),
-    ([0x963a9c566e8bd6b5u64, 0x5f468edeb6787ad5u64, 0x26747ffd80262ac3u64, 0x3dfd92e903126986u64], [0xc425073c06eb7168u64, 0x1851df98964644bau64, 0x1bc991d8cbc2ca37u64, 0x51dfd70d2e1c43e2u64]),
-    ([0x112f96e2b0f9b3ddu64, 0xc8db0f7c98858057u64, 0xd63057d2ac2decc1u64, 0x1a6858e0c574d4f3u64], [0x2ab7d24d4963a53du64, 0x1d705e4916378b1bu64, 0x1d7dd3f7d938b5c1u64, 0x2ece14c8bce1f323u64]),
-    ([0x44568101d845f71fu64, 0x9d4b0f40448ca04cu64, 0x6e8ba6f2374a655du64, 0x5318150315161c3eu64], [0xf6e4c0f5760c2500u64, 0xc82f371a71fffecdu64, 0xb42c9aefd0d5b92fu64, 0x211edfa8bb8ce72au64]),
-    ([0x3556faf3eba03702u64, 0x10a48d852fc35b7cu64, 0xb5537e7ab5699d8eu64, 0x0a287d9f742f652cu64], [0x740f948694add898u64, 0x30d85811bc6b0677u64, 0xc85b461b9432529au64, 0x65d23aeaf1e9674bu64]),
-    ([0x3cd3d2e1c03a844cu64, 0xf1a7edc3eeb1ea0fu64, 0x2f9ded387ed31cf4u64, 0x1257c7495e0793b3u64], [0x98a3598adac15562u64, 0x31675a61a3bf2c9du64, 0xc64f75ec3014d3b8u64, 0x36b928c33e67c92fu64]),
-    ([0xb8f0b1427b5e42f8u64, 0x6ea4cf29a5781cc8u64, 0xd56abdc480f96f51u64, 0x1acf14f60f00b7cfu64], [0x1e7966cbc2eea510u64, 0x2a826e4d167297bcu64, 0x005255c9e2749b1eu64, 0x11db3100ff3255c7u64]),
-    ([0x24438957b3e25619u64, 0xb20e24ef2f570c8au64, 0xf82295c732d111a2u64, 0x1a660e58dcdd8ce7u64], [0x7283ae068176e3d8u64, 0xa214af336c2d5abeu64, 0x242225663e021910u64, 0x40d0b87e6d7b6c79u64]),
-    ([0x21eed5418571ac33u64, 0xa0306689091abed3u64, 0x7cddd189d0ec4d20u64, 0x0e3d92d95a4ef11du64], [0xbd60a4559d600f7du64, 0x016e790134e4f0fau64, 0x048cd48d7e1c7e87u64, 0x28e243d8a3648ffdu64]),
-    ([0xe7c952e4bd7af7d4u64, 0x15b7da58c5251abcu64, 0x278a8c2e3d1fd8e1u64, 0x5e8542944aa32beeu64], [0x50cf7e219b89222au64, 0x9bcf00f72c5b3149u64, 0x34e4ec1ccca7d2fau64, 0x328e7129a27b6539u64]),
-    ([0xbed39a0919fadbc3u64, 0xcc694ee83ae27a47u64, 0x2bdad7b86e55e1c1u64, 0x30e64a3b47df5caau64], [0xd1fb8530bd5afb3bu64, 0xc3d95073ff93c4d5u64, 0xf4c87be0fd4077e0u64, 0x5c2dc89705f0c363u64]),
-    ([0x3ec50c9031a36ca3u64, 0x26bc7f62d13a6e1cu64, 0x06050b5832d10997u64, 0x65f6c5837cb5fca2u64], [0x16204e8d234e973eu64, 0x53d7b979fe6a03e1u64, 0x9ce0f37e0a2a78e5u64, 0x159c8f5d9eefe407u64]),
-    ([0x389490a19470e8d1u64, 0x7c2b9280b02b0f38u64, 0x4a6b4b7925d178b9u64, 0x006c662f4b3bf7ddu64], [0x952f1f51d14e166cu64, 0x93ee9af5cf268dc7u64, 0x5148fa6bdea5296du64, 0x6d7da26ec1a4eb18u64]),
-    ([0x799bd1773c3aadf2u64, 0x77bf1d8539e5c50bu64, 0x2c0cc5b558b3509cu64, 0x455ed6eec90f1e19u64], [0x758108096964cd8du64, 0x6d0de00b3d69e3c0u64, 0x1d94555269ff078bu64, 0x660364f465913ac6u64]),
-    ([0x15f4e3caddb042a0u64, 0xfea7275730b5b115u64, 0x185006d5ff757c19u64, 0x56c8f0a1a79dc567u64], [0x21246682e397a83du64, 0xddfa710e2449f4fbu64, 0x5d822ae2772eea52u64, 0x29fdec47901f7989u64]),
-    ([0x75352d72a0c9539au64, 0xa07fcad29e981643u64, 0xfcf3ec5eda0ddb53u64, 0x42a2e61a3a2a9b39u64], [0x38b1c80bc351e483u64, 0x5354e45d9a5a9aa1u64, 0x4e9386311be39124u64, 0x569ea10b7f073ed9u64]),
-    ([0x855fca21c045e849u64, 0x77bb530e72b7bfc3u64, 0xcc64d8b824c354c3u64, 0x23d3249e0bfc4d51u64], [0x35ad04e34bc1fd08u64, 0xde16ab5fb0db3d6du64, 0x3c76fcaa41f3f5c2u64, 0x2fa82f5b45d878bdu64]),
-    ([0xb0a40e557f4b46aeu64, 0xe49025299f9d89f8u64, 0x07e1837d2ec951dcu64, 0x0c0eb7cf8d35960du64], [0x493ae1050b54b03du64, 0xd10d0d74cff78c2du64, 0x039bdf012d70a7e0u64, 0x3f40e002bd6a266fu64]),
-    ([0xd0568f04b84b2a00u64, 0xca252bfa132081f9u64, 0x55433db658105171u64, 0x726e3359a03f722au64], [0xb6eee61c4cbb40bau64, 0x90e1c2550aad6335u64, 0xb26f020e55a058f0u64, 0x1a81fe3f11125af9u64]),
-    ([0xa4f1f87d90884c5au64, 0x7f3accee4e32febau64, 0xa84b028e307c9363u64, 0x22545f6ba59c3994u64], [0x74d34bf13ef714ceu64, 0xb3e9e79ed4a3282eu64, 0xedf7ee781d698e99u64, 0x709027dd2bc9fc05u64]),
-    ([0x967bc7d5bce1a804u64, 0x96d286c9c7b2f3fdu64, 0xce91a6d6efc2c7cau64, 0x7073dd564bbe5d15u64], [0xe47ba7b327ed321fu64, 0xe86e45c06dd0f475u64, 0x5b3eed6b85b6e11bu64, 0x30edfdcd5e7e864du64]),
-    ([0x97ef4c4862dac48bu64, 0x2b62a8e248a58739u64, 0x756db007d73ddfd8u64, 0x00835edd0c55c21au64], [0x8a7ddff5e926382cu64, 0x8380205154f95cb6u64, 0x0f565fbfffed3298u64, 0x640c3b1da1d2cfb6u64]),
-    ([0x69253d44de1e4d00u64, 0x11076c47c5c2c179u64, 0x543a827b37d2ebc6u64, 0x097018e25537e45au64], [0x8d90e44ff4cd971bu64, 0x08170096cbada025u64, 0x3743b3b2e2487963u64, 0x07372333978ff8ebu64]),
-    ([0x7675b5beeb6153cau64, 0x36c5916205245125u64, 0xea18ac2f59bed4ebu64, 0x44057850ce28f03eu64], [0xbb8dcac103b7c7dau64, 0xb8bf03b6d0df44f7u64, 0xa8684ae3662c33dbu64, 0x732ecff189ec9947u64]),
-    ([0x97534a53351aeb21u64, 0x81905e68e892d765u64, 0x54c6bcf612429062u64, 0x1c1bc2b7e09e88cfu64], [0x7572dd4e75684338u64, 0xc87693ef33f0628fu64, 0xf09c4f4b2754eff8u64, 0x55f960cf5e9232b4u64]),
-    ([0xf825d2b60a32f87au64, 0xb99d89aade182224u64, 0xe027a83558a1fa00u64, 0x1df44aec85b8f956u64], [0x1a99502b99e4c130u64, 0x2a398c61ef566a6du64, 0x25be225729c2eca2u64, 0x30181a38cefc6041u64]),
-    ([0x2e36541e5c44ddc4u64, 0x4bb77311aa5733c0u64, 0xb5fca7743791401fu64, 0x649cf70a9c158f6fu64], [0x1ebd7cb60370860fu64, 0xda777e46a40b9279u64, 0x126e3f70cbf311abu64, 0x2514f25586763f5eu64]),
-    ([0xf44e14739932aa33u64, 0x59e74ba2b75ca477u64, 0x59d7d47f20aa9f02u64, 0x37d3508a14adf959u64], [0x81878d8e7f43992eu64, 0xb29cc76418eac352u64, 0xdf7c78cb07a2aa34u64, 0x38762d390a234651u64]),
-    ([0x15c92e1aac022f7au64, 0x5cc33eff3b2e005du64, 0x06419d72de2d04dau64, 0x38dd03f06c321344u64], [0xf830388afb573750u64, 0xa8f0654c21dec24eu64, 0x238a5e06ae277740u64, 0x6f66fdd01c5a65b2u64]),
-    ([0xfea7c33496a345f2u64, 0x920d246202280193u64, 0x0156dd6f2d56b9c1u64, 0x59a2bad21bb26cbeu64], [0x91c06de4bb766d1cu64, 0xd74f3debdecdc010u64, 0x2f5201bf27b52b56u64, 0x6dd0c4940c9056e5u64]),
-    ([0xcdea20340e865edcu64, 0x8a46efcbd5cbd25bu64, 0x1bb5ebece92e5017u64, 0x3bbb5cf7ea7460e7u64], [0x8ef161db871fc20bu64, 0x41cc908f8c3f895au64, 0xc36932a20f40b32au64, 0x18b5b24cc0855355u64]),
-    ([0x3b2bcdccf1ec7b16u64, 0x6f5f56946340fdfcu64, 0xbae4d7a5261114f2u64, 0x55e29e7e1c0691a3u64], [0x5af96cee1ca6fed5u64, 0x17f821fdfff528cau64, 0xb53b4922d4e1a432u64, 0x56c2669786e154e6u64]),
-    ([0x4ad463040bfa6b96u64, 0x76783e184f0f10dau64, 0xf6e0797726f231b1u64, 0x4b1289d9a062ba8fu64], [0x89ff68450e250d1bu64, 0xab7df9117e853c0bu64, 0x72aeba9f75a87d10u64, 0x68c70c9fd0ef502au64]),
-    ([0xec3894fdbbc5e597u64, 0x0a738f0e49608f7fu64, 0xe522dc0df640392du64, 0x2e916831b2c665dcu64], [0xab49c00ec53ffd8cu64, 0xafbd4f16516f8645u64, 0x5ab9d5df333d28e6u64, 0x0df065d96cf5de25u64]),
-    ([0x96e7cd165be118afu64, 0x85330ac339765778u64, 0x47fe7c0925272dc3u64, 0x6c00513188184d1eu64], [0x60375f11a589e05du64, 0x9bcc1ce94ea70ac8u64, 0xf628faf47503c43cu64, 0x37e0faf81ac15da1u64]),
-    ([0xb08956f964517062u64, 0x29c85f7efbd6188bu64, 0x09768a7087f0cc26u64, 0x13562b7d27b4c23eu64], [0x8f2526ffa0fdbfd3u64, 0xc0df539659bc6cc5u64, 0x754e5f70b1eca304u64, 0x59a021bd2eea94beu64]),
-    ([0xbd9b29beb5d8cc55u64, 0x150e05ddcf13a22fu64, 0x4bc3a5672aa39e42u64, 0x0c587f61fc62e2a9u64], [0x7737fed4670b6754u64, 0xe5f2322e58e78ffau64, 0xafdfbd8c1773c7d5u64, 0x6f68c1068254d684u64]),
-    ([0xb38d064ea8444127u64, 0x510352b62f3f203eu64, 0x023718eed874887bu64, 0x5aeefa03ad3c8255u64], [0xee88022b483002ccu64, 0x2302ba6e34e007c6u64, 0xd622684013b952f2u64, 0x1c84d1531e8ed5fcu64]),
-    ([0x6676bb13db1cfdd7u64, 0xbf46ca8e87947503u64, 0xb84e3224c2e800f6u64, 0x44dfb14784ed9323u64], [0xca7eab13be99e8dcu64, 0x13cd9225e6c5e683u64, 0xa8f039d6cf9d0923u64, 0x4434bf7cbec41d02u64]),
-    ([0xf003cd6e73efb611u64, 0xaabff88c0b7e4246u64, 0x0cc516e3bb219c82u64, 0x146d0fdde70e992du64], [0xe81109b3b664f713u64, 0x838aa44014490593u64, 0x919b65937c91b8f2u64, 0x62dfefff1809f9c0u64]),
-    ([0xf54ee691e0643777u64, 0xd70ac6a330a41421u64, 0x23c8d9e9259802f7u64, 0x21944f96d2ac9b39u64], [0x99abf49fb0d33e73u64, 0xdd428b7e7e56e00eu64, 0x154fbecaca0595c7u64, 0x17f9f6742c4893a9u64]),
-    ([0xcf4a24368337be6au64, 0xbbdd88120258390fu64, 0x4cc9ff4bf19abce5u64, 0x30e1bc58cb5eb5c4u64], [0xd895fb5b77c53d0eu64, 0x2a163181a7245274u64, 0xc11854218e2593f4u64, 0x336454a7d12fc082u64]),
-    ([0x4f23e87d934f1f96u64, 0xc6b194d22c9353d0u64, 0x674a00d694f09259u64, 0x6a55d299fff47a28u64], [0x305e1dc124c01f42u64, 0xfb5b4bac44d38c0eu64, 0x8501d2a511939378u64, 0x6a058cc55c9d0a05u64]),
-    ([0x95df4b360411fe73u64, 0x1ef4e672ebc1e1bbu64, 0x6e92a9c460afca4au64, 0x4f2c596e753e4fccu64], [0xf5b81f2ac15308d8u64, 0x62a2c8c5c04f3dc3u64, 0x664dd0f059206740u64, 0x3ac7c712351e658fu64]),
-    ([0x5de6afb9568b79d7u64, 0xfd10f133fcb8fedeu64, 0x02fca0c6b9bfafb5u64, 0x149f555d2abe285eu64], [0xc2cf77b63467cdd6u64, 0x869cb1bff116ea7au64, 0xba96a3d94fb4c2dfu64, 0x2a8abd59db138f1cu64]),
-    ([0x7372e26d5720949fu64, 0xaa9ad32419396162u64, 0x5e3ab4ed8e1c3483u64, 0x04a42ca6312ad70du64], [0x9b96483df034973fu64, 0xefda356746ee49f0u64, 0xc6c0f91461b403b0u64, 0x46cda2136baf4bdeu64]),
-    ([0x14730aa651dc14c6u64, 0x93c8b17cc28f5b59u64, 0x7e00857f06f8f130u64, 0x1dca0a2862c6b0aau64], [0xd949064c77a82676u64, 0xfd368e3e8c6a3eb6u64, 0x22de7d6a469d9030u64, 0x1cc8bdd3c01bd70bu64]),
-    ([0x998731ee0c78437au64, 0x38275f891994324cu64, 0x51ea52ef08f1e06cu64, 0x4ba357052117e1e1u64], [0x0123baa49f010641u64, 0x11bba74d8903d3f7u64, 0x157cc2666900f9c4u64, 0x3866e7a0d6658ecau64]),
-    ([0x589db18082632470u64, 0xb08c90ec812879e1u64, 0xf108e29cdc4cf25au64, 0x0bd70d64d5746e14u64], [0x84a445928b0174f8u64, 0x4f7876906b0c15dfu64, 0x8414515cf32d22bau64, 0x53960ddbbfa15fe8u64]),
-    ([0xf424341daddcab5du64, 0xa86c461ae56f072du64, 0x5204f44088385855u64, 0x4aa10d96bee11ebbu64], [0xc1cf6201db69475bu64, 0x5da7fa4eb7003c07u64, 0xbdebf66bbb7b686du64, 0x28e5bcb27c92d16du64]),
-    ([0xb135c7a97914d377u64, 0xb123c188df7ce263u64, 0x5fc93aca2333a367u64, 0x31d639349820411fu64], [0x03889a2dddc41057u64, 0x3e396549e2f4dae5u64, 0x050924558731e744u64, 0x0abcfd1eb2ba259au64]),
-    ([0x4000b0b3767c9928u64, 0x19b0deac619bda2du64, 0x8b98c6ffcd682aa2u64, 0x35c60b00588ed2cfu64], [0x5ac9b224782d7bf5u64, 0x93656a1b3e02390au64, 0x1cae98c34d87e369u64, 0x3c88fbccbedfb005u64]),
-    ([0xc1294eb7f2f2f66du64, 0xca657d647e701c3bu64, 0x5ae617b0fcc5dbf9u64, 0x2887076752b9400fu64], [0x91931457e9772b71u64, 0xae2699bbe3491f45u64, 0xf3c45fffdc1551bcu64, 0x6c8fda732186f18cu64]),
-    ([0xd0bd794485b1cd59u64, 0xde1d4b203d557fd3u64, 0x6b0fd286dc1624d8u64, 0x1a8dff0465d7945du64], [0xbec04fdc2552aa12u64, 0xcef407eed0d9c21bu64, 0xbdeb0ed9f4547452u64, 0x42614cc60dc1de52u64]),
-    ([0x51641d355ed74859u64, 0x00d4799a10300311u64, 0x184e37dc8531cf33u64, 0x5c33a82ef937f7a8u64], [0x64d6ea212da532d9u64, 0x414ca3fc823d48d1u64, 0x64d4ec4017018f68u64, 0x114a212ece2717a7u64]),
-    ([0x8dfcd4912667f55fu64, 0xe801fb897d05b853u64, 0x10a20368471f6635u64, 0x64e32898a7c259a8u64], [0x12be4f19c162ecdeu64, 0xbe149ee8612a2ae1u64, 0x030194a0ab8963bcu64, 0x0d3f1d214c97f0beu64]),
-    ([0x7899594a115b42ecu64, 0xa8c301f4f3208744u64, 0x9233a27cfafd0e6cu64, 0x2e4ca53a04150fa1u64], [0xc7d3224c9afb380au64, 0xe8a311253227b904u64, 0x1bec9badf6f4176fu64, 0x0c9d07300d3ad9feu64]),
-    ([0x64936d794ecac754u64, 0x4b4e7bab3c93dde1u64, 0x97e292677efe6e68u64, 0x192d8efcbc9ffba3u64], [0xaa935dd43fc2d297u64, 0xdf02375401e10026u64, 0x7316fb76fe4414b1u64, 0x064462d04ebde36du64]),
-    ([0x6982caeaed4863fdu64, 0x05aa559c25a32306u64, 0x9f1ca45610bea150u64, 0x040551ff3b932efdu64], [0xa8404f739dc5d26cu64, 0x52e4bd2cb0b0dc2bu64, 0xcdacab6e7ed19a23u64, 0x2861d496df571201u64]),
-    ([0xd2fc5e69ac5db47fu64, 0xa12a70a615d0b8e4u64, 0x293b1d67bc8de5d9u64, 0x0e4840ac57f86f5eu64], [0x9bead05d38c8180bu64, 0xc248ad4defa42ac5u64, 0x6622505a31841db7u64, 0x1c49af4054167799u64]),
-    ([0xfaa75e2409644896u64, 0x2310bc93086722d6u64, 0xe9b9971968377e8fu64, 0x45cc0d36010c2912u64], [0x45a7bad86a768bacu64, 0x9636f4c920febee0u64, 0x204f4365258d4406u64, 0x65eb81abb6ebfd7cu64]),
-    ([0x8e92a08175236817u64, 0x2e29e9208281d298u64, 0xb7701b97622a206bu64, 0x0f798c4fc8876304u64], [0x9838eb9e0a6d18c6u64, 0x946022af33e2fbbcu64, 0xecb4e152bfd9adf2u64, 0x2b2b60acd069d879u64]),
-    ([0xe640defe9c3d1988u64, 0x71ff74fc1738cfd4u64, 0x6539d6e7048fb70eu64, 0x2be55f9a2cb14857u64], [0x7286b375fca32c0bu64, 0x6cd595bf23eec923u64, 0x0fedbc9687ab7b83u64, 0x26a2bffee5d587c8u64]),
-    ([0x2cabadec2fe322b8u64, 0x752c84f315412560u64, 0x32a732ae1a3b0aefu64, 0x2e95da59a33dcbf2u64], [0x6c8c79107170e249u64, 0x235d33325de4ec80u64, 0x6a3663231607c89au64, 0x4f8afed08027581au64]),
-    ([0xd58a5af42d010ff9u64, 0x79efd6b0570bf109u64, 0x3ed6d55a6350721du64, 0x2f27b09858f43cefu64], [0x619ab2fe6f52ac2du64, 0x03af372909f1a67au64, 0x4f50e4d3f40d292fu64, 0x395a77d9909dcd29u64]),
-    ([0xfb350dbef02a116eu64, 0x1c510ebfb4543a3eu64, 0xf675522e37ad4ecau64, 0x1907a56e80f82b2du64], [0xe7fe53209967a054u64, 0xc7d98cd069d07cd5u64, 0x5b300bb369a0f5f7u64, 0x63a14a833f9af999u64]),
-    ([0x6eb5dc3197a8ef92u64, 0x356d9efa01c14d94u64, 0xfed2674d81d61358u64, 0x143993e8f9bde758u64], [0x7dcdf1d4355f4b83u64, 0xf438d17f5d3beea1u64, 0x2b2843e3bf59936eu64, 0x35e7da6d91dc0411u64]),
-    ([0x571ba2fccf28601bu64, 0x166fb582ac74db12u64, 0x51420be43501370bu64, 0x1996fa8d52f970bau64], [0x59c02c95498d24d7u64, 0x3702adc59c29a61fu64, 0x090a307bf4c6ad37u64, 0x095225fa016fdf94u64]),
-    ([0x596a75b117ddfba0u64, 0x0c07e8ac00ccd608u64, 0xed5d290fa2d313f1u64, 0x21a01c78170c4872u64], [0x077a4894df213853u64, 0x810c29d1b02b350bu64, 0x75f12ec4fa3b4aa4u64, 0x278bf5c119b90bf7u64]),
-    ([0x09af8f18266456c5u64, 0xe055a2ee5f896a44u64, 0x56b079736230d8d1u64, 0x5356ac223b8b00a7u64], [0x77d0779098c036b5u64, 0xac573a3c7d77ef4bu64, 0x3379a2bc4e6cb284u64, 0x293e2dbe602651deu64]),
-    ([0x4d4166e5018414f8u64, 0x2108f69bb5a34d7au64, 0x477c49bd9f429e32u64, 0x6bd50bd90a98a833u64], [0x3259d78758b1d9d5u64, 0x19b30c1c3be08b0fu64, 0x0bc459c903678475u64, 0x47560a9d53032fd3u64]),
-    ([0x7a030cc2de5db52bu64, 0xb63d7e9803ec74e1u64, 0xe5789556beff8edeu64, 0x1d12dbf168716b60u64], [0xdbb8c318b00f3099u64, 0xfbb30dc95196230bu64, 0xf3d3768c08ad42ebu64, 0x09aa73813cea846du64]),
-    ([0xe4f8b0b094a83cb3u64, 0x4cc4dccaecd1fbf5u64, 0x5ec440a4e3fd2ea1u64, 0x0539a8ec3ef06619u64], [0xfda4bfd3b5a89c93u64, 0x8a3750e85cfb4372u64, 0x08560620c083b276u64, 0x5458821bd4768cb5u64]),
-    ([0x428c9fe880b696f2u64, 0xfdd04289be4d5845u64, 0x1eb3867a7d42417bu64, 0x3337efc99d37fe72u64], [0x7a70f5a11144f8f7u64, 0x2d4794a90b5b18e0u64, 0x8486264210fd3528u64, 0x720a7c7b18d3356bu64]),
-    ([0x5af67e8ae5be1bb2u64, 0x3a894a39cb116c52u64, 0xe020f6ea3ff00d74u64, 0x625d8c6cd36c4d58u64], [0xe02f5846b37c6db9u64, 0x26f48cd74b328b37u64, 0xa9da81fd72ba563du64, 0x057f3837447b4fefu64]),
-    ([0xee5bcecc4e7773cbu64, 0x45593d6ff6dab086u64, 0xa3a95d2d4016e2bdu64, 0x047cb16caf96816fu64], [0x88f76d3dd7073a7bu64, 0x04c8a24ffd7754d3u64, 0x54b383eab2a8b6ccu64, 0x58ccdd75fb7708cdu64]),
-    ([0xb5e7ec7507841320u64, 0x3e18d8297cc221bdu64, 0x416efa2f23e851d9u64, 0x315f300ff31bf898u64], [0x4b433665c5644aa8u64, 0xee7f6e9b5132b8a9u64, 0x96f5d4654612e6f6u64, 0x15170bed020728c9u64]),
-    ([0x20da606ff4a3ba2bu64, 0x2a49071f297def3cu64, 0xcbf93d062b4fda50u64, 0x72bf096ad9987ad9u64], [0x77be7e7cc9b1f9b6u64, 0xdd09c00b8e452295u64, 0x836a3769a589ed22u64, 0x5d97c1f5223b2ae6u64]),
-    ([0x0bf6682a1f324c98u64, 0x728d78320cf0b1dcu64, 0xcca953c9a7d71357u64, 0x4e04d95c3d037697u64], [0xce14ca143c0748ccu64, 0x34876e97ca742984u64, 0x62a4c836dc74d146u64, 0x3150d62c05525ce3u64]),
-    ([0x335ab15ecceb6a2du64, 0x4e0bf5a0bea3c2eeu64, 0x7f2b1554800870c7u64, 0x5daff883ceaa889cu64], [0x86bfb413f297c2d1u64, 0x3e158e6206efe1ecu64, 0x190d0fd8471c66ecu64, 0x7307ca8a3d9c6323u64]),
-    ([0x05b59d4d67e78267u64, 0xf7f89acd6211e667u64, 0x50c8d1e120be501au64, 0x3dde51a561f46914u64], [0xfff1b471a53d22cdu64, 0x8e13df38f4c3bc19u64, 0x930eb585ff171a4du64, 0x4dde320cfdc8b72au64]),
-    ([0xed1c6473b3e31cf7u64, 0xa6ea1b243740a635u64, 0x03a56cc8813aa331u64, 0x22c36e5ee340acc5u64], [0x45c549268f475e66u64, 0x9e31e59bd2de5f06u64, 0x2f0f701a5786f3efu64, 0x0b63cde70890818eu64]),
-    ([0x719ae8985de5540au64, 0x4c6e4a6abf88d933u64, 0xfbb7a8b0d7b33af3u64, 0x2eae852ff0a7b43fu64], [0x8d98a363dad428c7u64, 0xedcef4fc151435f2u64, 0x47bfb60ddbef8655u64, 0x167a3d208fc17483u64]),
-    ([0xdbe21753938adf74u64, 0xcbfd4ee83f1b2499u64, 0xd44db4c7e28c3f15u64, 0x1aa909b8f9797bc1u64], [0xfcd05159f4ab202au64, 0xe530bbc5739cbd86u64, 0xe304d2e50d056047u64, 0x3a9354aa5308a0a9u64]),
-    ([0x49ab063c9866233au64, 0x87d5081bd50bfe8cu64, 0x0174fd293ff31fa4u64, 0x546f8d40c2ef982fu64], [0x1a41b51680332aadu64, 0xb6a1b2deff5b8133u64, 0x1b0a2cdf6946ab3du64, 0x467b998e22846362u64]),
-    ([0x085c098a064e640du64, 0xf0a148a069f07e83u64, 0xf332844e948cea8eu64, 0x59bd1d433b70d20du64], [0xc450ad40c0f4feddu64, 0x09347f0fa28e0981u64, 0x4d598e5be48f419bu64, 0x669b33e7e199f175u64]),
-    ([0xbf96906be310d83au64, 0xa7ea190ee72171b5u64, 0xe6d4fddaed19d920u64, 0x5edcb49f8ab5bb56u64], [0x11e7ca77c29b8ff8u64, 0x1d13c59dc021d940u64, 0x9f383543ef2744abu64, 0x007fb8e092b26bbau64]),
-    ([0x7429c1fa7a88c247u64, 0xcfffa1a105df6b8au64, 0x46145a561c41e22au64, 0x0701e1cbced44b4cu64], [0xa58c6d6ffe530532u64, 0x8ab6dd3f99d3d32bu64, 0x7cb09ae01f0c1cf3u64, 0x02f40a477d78679bu64]),
-    ([0x504798a56d84b81bu64, 0x7a2a36904e65334du64, 0x4619a91495c55a38u64, 0x19634e6452d67742u64], [0xb4fa0c7be5f8fadfu64, 0x60b2a60143eb5874u64, 0x10c4b2b4d95adb68u64, 0x562245142f1faa3fu64]),
-    ([0x1cfb5b8201d3c611u64, 0x710cfc2a8e76963eu64, 0x0a0955d20478e946u64, 0x38a2dbab8b181126u64], [0xe38d85c8be11e956u64, 0x961ec34a7e314ab0u64, 0x1caf188c0c6544a0u64, 0x63e43be5e2cd0f05u64]),
-    ([0x3ad32c3398b4cb0au64, 0xc956e2bbfada2646u64, 0x82acff8e98d64e22u64, 0x3095f0957745c502u64], [0x9aa1e15df5d7b620u64, 0x3c8b4480a8f901d1u64, 0xd4292b0ba322d351u64, 0x4da14cf8f4eb76c2u64]),
-    ([0x8c15c05b1901cef2u64, 0x050664a566a603f9u64, 0xf20c57f6d7dc9533u64, 0x0afced2ec80a4115u64], [0xfc71752995ffb4e6u64, 0xfb4784b01c3eab7eu64, 0x91e175137f31c767u64, 0x02804fdebcd70094u64]),
-    ([0xc5266f7287443e47u64, 0x36743ad6e2d471f5u64, 0xac20bd03516e01a5u64, 0x2adc5f70d71d12f4u64], [0x9295b7f2f8f7034du64, 0x6bb2260c4383af7bu64, 0xe6700067fb895468u64, 0x67ecbf67680c39f4u64]),
-    ([0x7554cb01f596606fu64, 0x48620b99aa201e9bu64, 0x77fc010ff1bb87e0u64, 0x00de550e339064ccu64], [0x0e95859884edca83u64, 0x04218d9a8e941357u64, 0xab980ff40f38be7cu64, 0x2a193abf24ea72a9u64]),
-    ([0x4f4e49f4db97e1d9u64, 0x6cb896192a36d9e9u64, 0x63a47e046c9d5d16u64, 0x3fd02ffe51ef6ac6u64], [0x9065d4da929144d3u64, 0x5ea73bd0339a7fd6u64, 0xdbf53eb631014303u64, 0x64427ca0bce9e6f6u64]),
-    ([0x408a86452d1142b4u64, 0x0654642678ea64b7u64, 0x9cfafd436d7b412bu64, 0x5c6214a479d7e186u64], [0x115d23d3aa9430dfu64, 0x370aacabcb7f0efdu64, 0x0e7ccaf47887dac3u64, 0x15fe09fe74f2ac29u64]),
-    ([0x660cb35a9fa42972u64, 0xeef79311d4603ebeu64, 0xf4216b3d286b5f60u64, 0x0b7bdb65a3240463u64], [0xeb7657e56c90bd17u64, 0x22f90cfa7d02413du64, 0x2bf213c8c51be3a4u64, 0x067e9d34f4463273u64]),
-    ([0x82758592725b5677u64, 0x41a44a292b2e5b29u64, 0xef7088ae62b6a012u64, 0x4c5f3df3d2c48682u64], [0x7b9fa6404434ffe9u64, 0x05338f39c9dba838u64, 0xd1cd5e73f6de60e2u64, 0x18995c60aa9bbc44u64]),
-    ([0x508dd3077d60548fu64, 0x36a01148af98688cu64, 0x8775e60c31411c7du64, 0x13e9935af62d6229u64], [0x0872498a4ab53fd6u64, 0xff295a92c6dc1f61u64, 0xed889dfc926e1150u64, 0x3e6729cd6eb1237cu64]),
-    ([0x633d3e92c7ca8ccfu64, 0x7c71082d2903a1acu64, 0x41205e1bb9264c6au64, 0x3c9f59c69ed3df99u64], [0x85019d7bd5f8792du64, 0x55b2515ac92a80e4u64, 0xc966ae0124d858a3u64, 0x34fe24c2467839bbu64]),
-    ([0x39d9acde14fb20e2u64, 0xd6f4363b42e95eb2u64, 0x2e82c238e01af647u64, 0x34fea43cfc49003du64], [0x67f3b3c49fbdebb9u64, 0x2a205a66d1042b2fu64, 0xfe22a1f925f11ed9u64, 0x543c47d1d7148e0au64]),
-    ([0xcb26d9489265bf28u64, 0x7b2db53007db7808u64, 0x27f2d34b0ee0bab2u64, 0x6103370156cdae52u64], [0xaadfbc1e51275e0fu64, 0x7802b888916c1649u64, 0xe1fcecf8566fe216u64, 0x30bc2b830e481df1u64]),
-    ([0x57717af258d76b50u64, 0x4de78026be2d5e74u64, 0xe4665ae77a416033u64, 0x0eebdacf4627bc5bu64], [0x2161f3539c02718au64, 0x03960a400cde75c8u64, 0xfcac80c7be30e674u64, 0x2dc42cf6c89ca282u64]),
-    ([0xefe8ef07b5048efau64, 0xeeafd9f0df88a27eu64, 0x4164b50a572c9c65u64, 0x0ad7742370737a12u64], [0x7110f8953bab7d8cu64, 0xec457738c891c90du64, 0xf4c95320ccfe2bb7u64, 0x2ef2a2be1736932eu64]),
-    ([0x9782d98e09dd2095u64, 0x9cb2b4506b58821bu64, 0x09faded963276edau64, 0x17171180e4201fddu64], [0xbb772171c0e97a98u64, 0xef5ac0cabc17aa9fu64, 0x3da6659df63a5dfeu64, 0x28a1e5c453b16f57u64]),
-    ([0x1f0256b78db57e18u64, 0x680119a77a9a472bu64, 0x27a0943532cbc2dbu64, 0x474cf68db38f4a7bu64], [0xf5dfe59b4f6c1273u64, 0x55528f2f741d82a6u64, 0xa7070561ac82f6bfu64, 0x2c74d63fa9946522u64]),
-    ([0xc04f2ce41153449cu64, 0x583fea587c4affedu64, 0x6606557aaa616f7fu64, 0x3eaa22a7edcf8fa7u64], [0x290145e55f56360eu64, 0xb309fcbf42754286u64, 0x9fefde084528f64cu64, 0x451995fadb30caffu64]),
-    ([0x6037fe81ae99502eu64, 0x8e74903694b04fd8u64, 0xbabc5affca86bf65u64, 0x1333b22e5ce11044u64], [0xb8b6bb029fd556e8u64, 0x72850cf7f2e6e469u64, 0x609b0b92e027f3dau64, 0x41eb34d8da747cb0u64]),
-    ([0x352b5233bf7ba901u64, 0x9f4eea3f60d8ba48u64, 0x220bc79e6b495d45u64, 0x33049a5b87438c07u64], [0xe6aad506b923fd04u64, 0x1e2f8b4a391f5dafu64, 0x31ebbb944d422aa9u64, 0x4bcc9093959b3a0eu64]),
-    ([0xa0eaaaa3583a6b80u64, 0x11424f00b1a2b32fu64, 0x045e7a34773491a6u64, 0x336291a1889c6ab2u64], [0x1b16217581185727u64, 0x2fb46af6524d5519u64, 0x19857461bb02d732u64, 0x30cb529fafb278f8u64]),
-    ([0xa5de0a8cb929b435u64, 0xcfe8cb30c74f3496u64, 0x32565e4a3f276dddu64, 0x263d7142c7493581u64], [0xd94744339e6bc73du64, 0xc975b023559e66f6u64, 0xcf44bea9dc3cdd2bu64, 0x0f13abb5cc7fe748u64]),
-    ([0x829235424bc52016u64, 0xbacc59c903893ca8u64, 0x9a8a9d78c4fed2b2u64, 0x4d3503442f2fb287u64], [0x7d5fa0e1ca3b5da7u64, 0xef75bb297a0aa880u64, 0x63cbc78c45dcbfc2u64, 0x34f2ef21966b3560u64]),
-    ([0x98a5dc3fa6a50014u64, 0xa1511800ae1cf2a8u64, 0xb1eeadb86ec0a240u64, 0x0f9d687779a20ce0u64], [0x702ea0138a2be13du64, 0x9abe976e4c077400u64, 0x4ea7686412064a1du64, 0x2465622f1aedb877u64]),
-    ([0xcd2b2f4c41dff3cbu64, 0x08b504c88bc20bd7u64, 0x7726f871b0da235eu64, 0x698f1dab874c3d6au64], [0x559474f962e518fau64, 0x135984ad0f2c3bacu64, 0x5cb870cce733eef4u64, 0x36c5a86744f92475u64]),
-    ([0xf707a2d2027625b3u64, 0xafa7f2c626b2e4deu64, 0x00b3afc1442ae9abu64, 0x6fa19b9923940fc9u64], [0x3665f227b242e53fu64, 0xe2721ee5a60bb58du64, 0xc54173f3f21ab6f7u64, 0x62d93b68089a7758u64]),
-    ([0x3eae9752b92f9726u64, 0xaf608393655b6d32u64, 0xc42ee040e5579a63u64, 0x15ae1a173d90ba01u64], [0xd006c3a005f932cdu64, 0xdcc79cd6c21ae60fu64, 0x15043c5ca149325fu64, 0x5f34d6e9549cb7c5u64]),
-    ([0xa0d0db6053a9a3e0u64, 0xdb22c16ba307dd3au64, 0xd7bcbd2b9d03b8b2u64, 0x0042e0b7bf8e8c0fu64], [0x0c951e99cdb0cf9au64, 0xa98a32d76ca39e30u64, 0xed293cab1c212ea4u64, 0x625f8f8fe15152e1u64]),
-    ([0xdaf6c1cc2b79b805u64, 0x48b13e62a6404a74u64, 0x0e536c4bf821b259u64, 0x2e8d1db2c4fc4043u64], [0x1c0f6890ea3f2fc0u64, 0xafecc689736b1185u64, 0xa1cb70fc9b8149abu64, 0x4cff55f1620ca1f6u64]),
-    ([0xc6704a19b8498e4cu64, 0x1baad9d1e07e8ad1u64, 0x166e1d63d16fa419u64, 0x2cfc9a8e6f552967u64], [0x341002b4ba1babdfu64, 0xb81e1c48c6f41bb5u64, 0x01844109eda51535u64, 0x0455348ae624b964u64]),
-    ([0x8586e7bf3217bc38u64, 0xd66041cee0c4bdacu64, 0xaf33b868f0e27d4eu64, 0x0a7975545a9dcb2fu64], [0x1a21ee42143968dbu64, 0x22d78e74d4c2a8bfu64, 0xde4882d30a9e8732u64, 0x1a86f8027bbb8c71u64]),
-    ([0xdbe2b6ae6a65e380u64, 0x41cf9853c5492f70u64, 0xefffb256ee287504u64, 0x0c944b4b630bd746u64], [0x0476900e26bb35c4u64, 0x81842d4aa1c36e18u64, 0xd41977efdf15f114u64, 0x372aee6ef07433b2u64]),
-    ([0x28f1221a8298a060u64, 0xb4e63a729649cca6u64, 0x855a08d1b96d6c46u64, 0x1272e2fcb84cd789u64], [0xa53cfb9cbf0420f9u64, 0x70421b1ac0e91625u64, 0x1b994f323b347dbbu64, 0x0f5a1f7b74f0f552u64]),
-    ([0x24ca49ae2654cfedu64, 0x149589c6d02e4291u64, 0x56679d9af60081e6u64, 0x005593b1dca7fd14u64], [0xd186e8508606e68eu64, 0x9853d21adec981cdu64, 0x17a89c40e58bf7d6u64, 0x597c2c962f0bfcddu64]),
-    ([0xd941e9338fb466f7u64, 0x232e4312e6011bf5u64, 0x6c54cb86cdf73e91u64, 0x44ed0520cdfb5d9du64], [0x4c11dd6bc892f1d8u64, 0x2e79d567346e5150u64, 0x13e940e4cc6539a4u64, 0x644d6f5c6538e38bu64]),
-    ([0x211c46be69369843u64, 0xbc922dc33e3981edu64, 0x533665b7ea0775feu64, 0x4a34792481d33f84u64], [0x894b3c81046be55bu64, 0x178d870ba5278364u64, 0xac53913b44d38e22u64, 0x54227e9e6ad31eb9u64]),
-    ([0xb994f7e88da69099u64, 0x0d148b46fc8a9536u64, 0x00f2b6fdeb243499u64, 0x54a9c54dedb0dd5cu64], [0xb2b8057e9f7a3e2du64, 0xf1efd1b958b6a5bdu64, 0x0b62537ef6217eaau64, 0x2e48097a6ab47914u64]),
-    ([0xc088bdd905a55f21u64, 0xce2297d77e27dabdu64, 0x7943135dbd04ce91u64, 0x0ec6e5ba74ab8f7bu64], [0xaf989002de9d47c3u64, 0x065aa9a86d81f2f1u64, 0x77f69647639eab4eu64, 0x5fce5a44dedd0f77u64]),
-    ([0x26dd05e4c4aec099u64, 0xafa69d8f42e2c25bu64, 0x2dee35b6cf12739eu64, 0x3dc45a6d7f643b5au64], [0x7c447fe6a7803be3u64, 0x9043d5d95aee438bu64, 0x749e73206830a515u64, 0x70314bdc3af7dbfcu64]),
-    ([0x447108a871678206u64, 0xc2735dfec23461fbu64, 0x6f84312c4cd1ae75u64, 0x0d82593fb2d1e479u64], [0x98a6f290194ba358u64, 0x75511686a96b2d7bu64, 0x719c113bcac59dc9u64, 0x0b50dfa3041df140u64]),
-    ([0x4580dba9fc46a574u64, 0x80719dedc145d3eeu64, 0x73a54de6e95a9de4u64, 0x102b8c3a3826d767u64], [0xeec934958330ad15u64, 0x35cb1c201ed99ca8u64, 0x60a3207ba75a2c12u64, 0x5b30c58d258246beu64]),
-    ([0xf54098590590f97au64, 0xc44b6eef836791e9u64, 0x7cfa546e4bac4497u64, 0x2a7518a877b7ab1du64], [0xf6bd554be19945dfu64, 0x8a8b1cf6a7fab725u64, 0x2a4420ab7b7a9084u64, 0x67b2a02732953383u64]),
-    ([0x810d9cff570736e9u64, 0xc694a89651aca0ffu64, 0xc13494e5cc0ae758u64, 0x6c4346355ac0cbecu64], [0x9cd04e8e5aad7a08u64, 0xe658b259f0b87a54u64, 0x2049d695aa64b8bcu64, 0x546377eeffb8afc8u64]),
-    ([0x6e8f66fbf9b79cc1u64, 0x14b02312ec12eeecu64, 0x62fa4f9250a0361bu64, 0x2c94b52abbca2186u64], [0xb6182ae654d1f7d2u64, 0x2db7a2440a6a5d26u64, 0xf2aed97725fedd82u64, 0x70e391ad3cfe52e8u64]),
-    ([0x460e884790b8c345u64, 0x8ba3138bbc3a874bu64, 0x457a1f0b6f25f22fu64, 0x49a98a70e4c6426cu64], [0x62e7fd921934b710u64, 0x03e93b8b936e105eu64, 0xbc7ebb10bff3496eu64, 0x40aa88919b9f12edu64]),
-    ([0xef7fba47f56fb3d8u64, 0x42aa0aa405432df6u64, 0x1a7756ed868ab151u64, 0x5dc73e710294f33cu64], [0xd4f42595ea7942d8u64, 0x6330a4fdfb328417u64, 0x0e0ebfef5e1041abu64, 0x50d9f168bf97be42u64]),
-    ([0x25c83bb50749f0afu64, 0x181764ae9a77652cu64, 0x03f012dba811a168u64, 0x5e0b7bc7ac9a8779u64], [0xf1aa9fd8956429b8u64, 0x89bb94fe5c93a497u64, 0x936a870cd1454981u64, 0x1dbf9c828b56d24au64]),
-    ([0x0a873d6c9d82cbddu64, 0x4f902f381368c0b1u64, 0x32712683cabe0734u64, 0x50ee7b0015a2d0feu64], [0xf5d655805e48a38bu64, 0x77bccfb0da4a4b36u64, 0xffdd88c8c94621c2u64, 0x180282e4b52bc92fu64]),
-    ([0xf2be473f9f510696u64, 0x7c3c05a40dd7360au64, 0x7c96515b1b38427au64, 0x4f0285d44e308ee9u64], [0xd453edf6e466a029u64, 0xf759589f64faab4cu64, 0x166dd5fa7c86ddc7u64, 0x54081db2a198ad59u64]),
-    ([0xa96caf3ac3f8ba22u64, 0x8d288cf2ea23bfefu64, 0xc4441b6584b2104fu64, 0x719fb3275950a9f9u64], [0x76b3ee05a455d0c6u64, 0x78ad56a2bebf3868u64, 0xcbebdb7135e51c41u64, 0x1a9d6071802f4436u64]),
-    ([0x20d4ed194fccfeb9u64, 0x01e9436124018997u64, 0xa8f0cfd2438f018cu64, 0x5303da18a9d30564u64], [0xc194b3155e6e0147u64, 0x23d4ea5d11198fa5u64, 0xa263dcc5d704a9e1u64, 0x0984e7353ce8ada1u64]),
-    ([0x43aac380bd084cf0u64, 0xafbf59d5e64bbfa3u64, 0xa20cb37113cdf8a9u64, 0x534ee70b5e0ee5d8u64], [0xc0558b5e43172589u64, 0x5d34864804a5fc54u64, 0xe589cdb3da28b66fu64, 0x380966ff77db8ed8u64]),
-    ([0xb6e7d38c824464d3u64, 0x238bf3cd9093c0a2u64, 0x82782d7daff31189u64, 0x223a5f24ab69299eu64], [0x7e30aed2e10e4d4fu64, 0x9c40d5d5be196d19u64, 0x34ebfdd857cc4484u64, 0x2195a3146ff202dau64]),
-    ([0xd21af29fac91cf26u64, 0xc265dd758be92292u64, 0x814b1382cf67a22cu64, 0x503ff5feecd35328u64], [0xf91d0ce16fad3622u64, 0xd20006eb91772777u64, 0x23d6ae153caf5babu64, 0x3fb79533a1387fc8u64]),
-    ([0x2fef56114b49c75du64, 0xb6f67b913ee947b9u64, 0x6e492ff0b0d05658u64, 0x02b315ee4db709aau64], [0xe4daa20f05ffb131u64, 0x85892ed140626218u64, 0x563dba84d7765618u64, 0x50699b5ab768e195u64]),
-    ([0xc2338ad83cdfa657u64, 0xf16b6f86bf14aefcu64, 0x0a859ee7dfedce98u64, 0x5c9db53f58ce8194u64], [0xda39d999d51ac952u64, 0x3979e735ca626cafu64, 0xb7c2438957de4113u64, 0x6c70a279be8c81ccu64]),
-    ([0x5f66134727f54889u64, 0x6e57c9facced7b91u64, 0x3cf6cd1d9efc7c14u64, 0x6c0ec12d8a6f8213u64], [0x9eabe7e03c05889eu64, 0x934f4f0b54a47f45u64, 0x97a3dbb767b9d770u64, 0x5b4455d31e332320u64]),
-    ([0x4a9ebdd727b87a2eu64, 0x10e4fb2dcb4d9f1au64, 0xe60238fe7599d7ecu64, 0x62ab2b3e89f62de6u64], [0x1be2e049e06b688bu64, 0xf16f5f8dc3d71f8au64, 0xe6ffa47fa3712eadu64, 0x26e83b0b30045817u64]),
-    ([0xbddd6dacba0b1d06u64, 0x330af27f081250e5u64, 0x29805a2b04c50efcu64, 0x5b759b0b4fa49715u64], [0x370e83083d0d69fdu64, 0xd2b405f72187fbf2u64, 0x6277e76daf74d1cbu64, 0x4470d902d7e01d28u64]),
-    ([0x3c3e9892b876b461u64, 0x9797ac9b42b5bc32u64, 0x0c824958cba91b6fu64, 0x1128d6151fc5ce2eu64], [0x7a77e7f7028c0025u64, 0xb02652ac34e784c7u64, 0x2b1a695904c6da1eu64, 0x59f0a5b496251c23u64]),
-    ([0x1f8dc38c14a70a7du64, 0x5aa998918baa4ee0u64, 0x431b68c23ab353ebu64, 0x529a9a0c8d46e5c1u64], [0x7794015f4b3a96b9u64, 0x71b7571fdb3ac540u64, 0xdeef1da09d501644u64, 0x4a308c7621d20af9u64]),
-    ([0xa70265e120dec41au64, 0x85526ac238e5a0c5u64, 0x610f40689e8bdb7eu64, 0x54a2b91dbc2c171bu64], [0x30a37ac58ed100b9u64, 0x57a640eb80a181f5u64, 0x3c3cd0f071a50a22u64, 0x42534dbc374d7b44u64]),
-    ([0xf1af8f454525fd66u64, 0x6178af6ba70b7705u64, 0x7d71287412503548u64, 0x4c33b1110b25238cu64], [0x399bf2a602a03c34u64, 0x8ee81a5ad472ece9u64, 0x48d2efce9a4297f6u64, 0x5a5efa868edee715u64]),
-    ([0x79aca024dfa3bea6u64, 0x7e81a6284dfcdcdbu64, 0xb44b584b898ea248u64, 0x32af4cf83a91c028u64], [0x343b5a351ea8bb67u64, 0x506ce5984c30da36u64, 0xa8e599ab86758aa4u64, 0x0b3cc4d3a5152480u64]),
-    ([0xcf2c53214fca9316u64, 0xdacbb1bd356f80cdu64, 0x6fa873d6002f921fu64, 0x3495e397c114f8f3u64], [0x471d04a221e8796eu64, 0x712721121a387d90u64, 0x6bb7dc671490972eu64, 0x25f88a35f22dd821u64]),
-    ([0x2392180523c30faau64, 0xa64b9abc640df6a9u64, 0x544edf064df058b8u64, 0x0b8bccb911dcb1aeu64], [0x4b048a5d1781e0ceu64, 0xac7d715e5d0e9c34u64, 0x8732f0c1c3b12f7fu64, 0x29d7368a451cabb4u64]),
-    ([0x78f4ee1de45ab2f2u64, 0xd21ae51df978cc38u64, 0x76407b9926e20836u64, 0x6e5703824bef73c9u64], [0xeb9c3f7f87a20fc9u64, 0xf2e694e16e6fd114u64, 0x0da1c397ea7c6a74u64, 0x21a6863e7e05d9d2u64]),
-    ([0x22be1a8aba702c85u64, 0x96b7ab8507f39201u64, 0xa671d938712e0968u64, 0x58b625e3c9a4ab06u64], [0xc6024a74282ab2eeu64, 0x307475f46bc5de30u64, 0x8c1a5c8541ac4b3eu64, 0x335ae85f6ebd1763u64]),
-    ([0x35d1ae71abd0a54du64, 0x1bc58a0b042cd4dcu64, 0xd6be007959cfa1c6u64, 0x179372419d80770cu64], [0xbc0e636b3fe844f3u64, 0x5eea5083cf9eb317u64, 0xd35624c928699cbfu64, 0x4bc9e5cb91016177u64]),
-    ([0xec89f106fb90245cu64, 0xbaf97bf1fe6c26fcu64, 0xb6504cd2a95cc231u64, 0x707ccc7b13e9ecf1u64], [0x1a34409b852901f4u64, 0x284c183f8b7a49eau64, 0x690fddf698e7333au64, 0x0e589dbe4d4f2518u64]),
-    ([0x754ceec2fde25881u64, 0xf1ab3ea313c630b6u64, 0x1b2b2fbdb88c0832u64, 0x204329cbca5aa423u64], [0x49a1e2d811bc35bdu64, 0xcd51ce53f4ba8105u64, 0xf1f6fe0c4d9803f9u64, 0x216e5ff422e6ee6cu64]),
-    ([0x02b926b27753b127u64, 0xb9eea8827f4e9ec6u64, 0xd966ea577b468e20u64, 0x4f0efbbd7f20c3ceu64], [0x03c74a55d899b493u64, 0x3ea548f593510f38u64, 0xdb8aac764f0efff9u64, 0x1e34b395adc4a136u64]),
-    ([0x072fff623d593accu64, 0x7e786c05f26d2d14u64, 0xd5ad1e34af8ba829u64, 0x4b0f4567920d7fbdu64], [0xede78bd4e1b5d566u64, 0xc8ed88a918fdca97u64, 0xf58078eb87469fafu64, 0x0e164cd2d0c01f12u64]),
-    ([0x90f41560b245b067u64, 0x410b160cf2d67974u64, 0xed07699a98a0e391u64, 0x4a5affc2ee6b0313u64], [0xa3c240b7fc201432u64, 0xcdc34701628c78e6u64, 0x6e8743a6d0c79e61u64, 0x11f43214ba0b53c9u64]),
-    ([0xa565af7a806913beu64, 0x41c708b8b84ee699u64, 0x8d16baf2bdd5e946u64, 0x2db7169d8ee08732u64], [0x82257ea7d3e1ac86u64, 0x5badfefed696da31u64, 0xc3c97f67de17ed4au64, 0x1778d09385758c26u64]),
-    ([0x4dc26f175eaf57e2u64, 0x540d311f3cdb74b0u64, 0x13a31b5c3e627cc6u64, 0x000d9930e7bfc555u64], [0x42e2397ce37cfde8u64, 0x2190e0914bc74cc9u64, 0x939ac141bac7b53bu64, 0x685a0edde9d13498u64]),
-    ([0x7846d9e1c754b5dcu64, 0x8b62f6b763e08ab3u64, 0x08078f5f1149b3a0u64, 0x327c4af774526aa0u64], [0x05fcbaa5fdf51074u64, 0x86063d9dc4a50f5au64, 0x37c8d2d5eb2ac054u64, 0x48986abfc4336395u64]),
-    ([0x9cee4813c5113caeu64, 0xb455ed3648330954u64, 0xc0e1424947746063u64, 0x6bbf623b190ab7ecu64], [0xd1c11866d5da879du64, 0x3e68bd2e41403959u64, 0x57a2d4eefb75bc4bu64, 0x174299d24d6160eau64]),
-    ([0xc1f99b485bb67d21u64, 0xef679941d7e3344au64, 0x6067482e2d9445f9u64, 0x5aefd9e4727786c3u64], [0x1bf9e94f22aa71c0u64, 0x01f7d49c5c601b1eu64, 0xd3da2bff5b728510u64, 0x2058aec2abccfb31u64]),
-    ([0x68070ff63089758bu64, 0x9ac786c39e8d76c1u64, 0xa449afd09e0b523bu64, 0x47cd4bf2fd4556abu64], [0x9373fc445e2de505u64, 0xf409a031914bf8b2u64, 0xc25292c05bb3a681u64, 0x2ad260af221df4acu64]),
-    ([0xe15b2a94a295c9adu64, 0xd173882ea6ce7aa9u64, 0x6d15603c30cffb92u64, 0x3d747fb3c3230d55u64], [0xa7268df6d071175du64, 0x85e82ace4d2c514au64, 0xee97efb749c65a40u64, 0x0afa1e295b059f50u64]),
-    ([0xf0cbebe282c25f67u64, 0x97ab86dadb989e51u64, 0x18c268a03833d984u64, 0x07a0985bbe50fcdfu64], [0x37002c91c4775f6cu64, 0x8e2498b3652b8734u64, 0xcda37c015a9ef20cu64, 0x3d6de6ef3c406dafu64]),
-    ([0xa1abb31fb37786b9u64, 0x57bc96af334c36bcu64, 0xcabf643eda8951f2u64, 0x38c7f2dd7e0c63fcu64], [0x16deb6737c6900c1u64, 0x94bdce265fe3f3a7u64, 0xf7f85d552486e5a4u64, 0x2f3d4f03253a3842u64]),
-    ([0xfb8bf031847f0368u64, 0xa71d3d048e2bae51u64, 0x6092d03a32cfcbe1u64, 0x4b4f7d0f0c1d7fa0u64], [0x535fd4cbedce3e58u64, 0x64907d1db30f5d0eu64, 0xa66406388353c185u64, 0x61d15e08e672fd7eu64]),
-    ([0x6cd888c85ca05ae9u64, 0xada005008f5fa2feu64, 0x91ecc533285f28b4u64, 0x1b61eeb1a28cdf1au64], [0x20f86358051a14bdu64, 0x8d7ac6654e074778u64, 0x885f9771faa06491u64, 0x49802f0251a9ea70u64]),
-    ([0xad110ef96d2c8b89u64, 0x39e24941616914e7u64, 0xb3e9ced3336c9ff4u64, 0x1bbc2f2b1ea09b86u64], [0x19741063e420e9beu64, 0x8d860d47f4934d46u64, 0x57c23ee8c84fe996u64, 0x3b44ed49e029352cu64]),
-    ([0xc3e268216bdc4a30u64, 0x0ab309ae850664deu64, 0xc53c32396daedbf9u64, 0x0c116d8cebced82bu64], [0x5099e97744205adeu64, 0x5da4baaf5cbfb50du64, 0x455424460fd650e5u64, 0x739e912f8b9d6374u64]),
-    ([0xd897ab600af26a9fu64, 0x52285991341c908eu64, 0xc2aeac62b2b1175du64, 0x67434729896b3e93u64], [0x448dd1daa2772f2du64, 0xb151c01cb5843710u64, 0x90befd842cb37d2du64, 0x211781036bf06d8fu64]),
-    ([0x552407971d6a01b3u64, 0x7c966df282c99da1u64, 0x1f72eaf592b1a5e3u64, 0x1064c4a1e86297ddu64], [0xdf8ca388dd87f4f1u64, 0x46080d6bf58f435cu64, 0x2a7b22af993cb620u64, 0x0d8cfa4f8a45f4d9u64]),
-    ([0x28f47d40e82d15bdu64, 0xf49d991750387166u64, 0x6a702485b38d5808u64, 0x713d093fd8cc8c19u64], [0x88e9ff1486029cd2u64, 0x5a716e7fd68c5831u64, 0x85792978dc0ab629u64, 0x2021ec054e49fef5u64]),
-    ([0x4eb4bb5cc301906fu64, 0x0447df05ce549e03u64, 0x597e225a1da4156cu64, 0x7144ace8a4aeb40bu64], [0x8943da366a6c98c7u64, 0x9a175a9d21416351u64, 0x0f4287cb0d6d3dc5u64, 0x0679b6fcb80e6a7du64]),
-    ([0xde09673bab61e898u64, 0xee39c74778c695afu64, 0x35c1728a223969d1u64, 0x13c8df77f041dc97u64], [0xf155d9faa82c0fbcu64, 0xaea4995b1dc98ebeu64, 0x809a7d9eb0886d8eu64, 0x32569443f98dd19eu64]),
-    ([0xd2f0f5e5e994ff3au64, 0x9631d6a3b689584du64, 0x6dee792e47fe9ebeu64, 0x326898eed454988bu64], [0xc304582e88795009u64, 0x665d08a76b6a1f96u64, 0xdc9b44a3abc1b480u64, 0x4b156857d52f37eeu64]),
-    ([0x3c71a7402ec2eed5u64, 0x0e78921f813dbb18u64, 0x8e4630618320a922u64, 0x34ff4ad5a9709b09u64], [0x281f9e5772ae9c21u64, 0x9a8d13f61811e358u64, 0x9743d30db70125f4u64, 0x53d9f0a154ae571bu64]),
-    ([0x1622dd5a6889a8e8u64, 0x9104a2294bb26530u64, 0xa51bfee012ab18bau64, 0x362f0e0589dd793au64], [0x0442a19b2d49fcc1u64, 0xf38c280d6f56f11au64, 0x4bffc8019beb4928u64, 0x582e5df57a88adafu64]),
-    ([0x92cbd498b8ae4748u64, 0xabefb15e20ddffa8u64, 0x3d113105a6f6224cu64, 0x37a82ca691fde375u64], [0x1fa240e3bf62fa3du64, 0x0b97387f32c47d6fu64, 0xab96367b52e7813du64, 0x074a707d482ad0dcu64]),
-    ([0xe1d977e545af710bu64, 0x7d04d8e25880e9ceu64, 0xc973f8fca82606c2u64, 0x527d5fb6453833c4u64], [0x132e7455c79d323fu64, 0xfdd4f334c8483c6bu64, 0xca4f2b742120ab10u64, 0x1d071b4cf548dab2u64]),
-    ([0xefb99d766acb5fd5u64, 0xc172ebfd53689d24u64, 0xfc3f7b8a3bd7c507u64, 0x18af53b4e3288a76u64], [0x58467bcd38871908u64, 0x2584605cd66cf197u64, 0xa8c58960c6f1b5d5u64, 0x1b80c1b5e888f379u64]),
-    ([0xddc3b03ea91bc267u64, 0xb802e4e461e72e18u64, 0x878d670fcb570ab3u64, 0x6358785206b5761au64], [0x880bef6be5dd598cu64, 0x7f6d0927b7d79587u64, 0x4fa410bfa5fbd73fu64, 0x296ffb9a0d9ff507u64]),
-    ([0x9fe2121c20b86988u64, 0xb707c23f80351429u64, 0x3ca13436f849d0c5u64, 0x52a3b000d89ab17cu64], [0x831c8a6a16d76fefu64, 0xa8d61b2702c4142bu64, 0x2091947e85d5d865u64, 0x27b73cdff3d5fd42u64]),
-    ([0xf52172b7909c822fu64, 0x6398ce89ad3c4462u64, 0x3bab579958add5d5u64, 0x02380f6f20aebd43u64], [0xdac1e4f8a8d1ec2bu64, 0x313dad6cc681039du64, 0x2937ad3852e972a3u64, 0x162d940d0dbddf7eu64]),
-    ([0x81deb3aea33c8449u64, 0xd21d417ac2d72f86u64, 0xbf9de97916504aabu64, 0x2801f5bec7ef45efu64], [0x35e67d7e4c590929u64, 0xc60c10869aaaf16fu64, 0x09832900f58557b9u64, 0x3618a2630e490ab9u64]),
-    ([0x9ca730e689c0bf9eu64, 0x7a330977b7a8bfe9u64, 0xf5109fa0d7553d93u64, 0x00aa7d2fce8520d1u64], [0xeeb4e797fae5c513u64, 0xa30240321e394af4u64, 0xd537b6e22fa07379u64, 0x6f98799a67f2b368u64]),
-    ([0x7b3354fe4df74d21u64, 0x5d835c9ca817ed35u64, 0xfba967880c356fdau64, 0x6d52a535477e1384u64], [0x923e0b8fcaab0f85u64, 0xac9d30de5302f989u64, 0x5b0f15c0a8dc73f6u64, 0x49b98d559d84226eu64]),
-    ([0x9dac7b409d585d19u64, 0x5d7dea6029017fdcu64, 0x06dbeaab1ad52557u64, 0x634fc0e6aaf7c2d9u64], [0x05d050a42e334747u64, 0x34275647e0855719u64, 0xbc5ac18e5dfd3d1cu64, 0x0b889ea44c276562u64]),
-    ([0xe1c73198fced09ecu64, 0x6bd7adea656c88ebu64, 0x47e4ce45ae3b842bu64, 0x04ba858f3343b722u64], [0xa9eab90e77e8264au64, 0xfaa993061fde7159u64, 0x132e32d1703ea179u64, 0x69c03dccf3097441u64]),
-    ([0x6e175e44c490cbc0u64, 0x9556ab6223eff040u64, 0x5187145dfaed8205u64, 0x59dc7a32e902f9e8u64], [0x4e88d638b7449d20u64, 0x6d1b85ab5417eef9u64, 0x72740c141e47a8beu64, 0x1c00c24d137f766au64]),
-    ([0xbac25779bd028d08u64, 0x146bccfd4eb9f5f9u64, 0x4553ed07dd76774cu64, 0x204e4c188b40510du64], [0x878d6a7e9be7c0c2u64, 0x3ab1155e1c7533f3u64, 0xc0621cf8de418f54u64, 0x27dba5f32ef8cbc8u64]),
-    ([0xebf8ff080a52e66fu64, 0xb695ffdf3831c932u64, 0xae7ba896e4494d8bu64, 0x364c9145a9d644ccu64], [0x155c167d8a21c2bau64, 0x86f2846645f9af3bu64, 0x7e1c526fbe989decu64, 0x1ac1b97953766089u64]),
-    ([0x1e3ac422a3bebfceu64, 0xdc56451a0f2e9703u64, 0xe1c87bd168b412c9u64, 0x07ce375f4d14b97eu64], [0xbf9ab2179fa926f1u64, 0x065d4a0b1d2ae3ecu64, 0xb1d557c3ec80b37au64, 0x61bcf36724da0727u64]),
-    ([0x72994949fe8dda46u64, 0x8803788dd25be1b0u64, 0x60f1e69f8f0320b0u64, 0x622c315227033bb1u64], [0x127c2738b8685ccau64, 0x08e17490a2cd82a2u64, 0x8f5f822ffd06184du64, 0x3f6c7bfce1dd9babu64]),
-    ([0x308183f53cba4097u64, 0x1f53ec9a4a2ac078u64, 0xd017f9d3a6beb55fu64, 0x4fb99444ba3d2df9u64], [0xef0bfcefb399e0fau64, 0x28b3877d4a9ceefcu64, 0x41191efd81c8026fu64, 0x5df3b72e5888a495u64]),
-    ([0x4582bcb64c16195cu64, 0x041bd60176f65369u64, 0x8382c4cf78190afcu64, 0x45c6f7a2a21cae9au64], [0xc797f240b0c014b4u64, 0xab18f345056bff08u64, 0xf6057273010dab40u64, 0x4397add3b9ba446fu64]),
-    ([0xd26c4075ed336c51u64, 0x2a108a14b7c70a6au64, 0x034fce3be6971602u64, 0x41dd0b039e7a6793u64], [0x77cb953e9dec4e0au64, 0x67241663ae10b1cau64, 0xca1942c734159ba2u64, 0x6c2d3a2d37ebf41cu64]),
-    ([0x51cb57e0e3035d15u64, 0xe7452d43f6d5756fu64, 0x851425ea12dcacdau64, 0x1579b9c6e6797777u64], [0x98bb8f0ec234965bu64, 0x8700f5087ef571a7u64, 0x1689523ad3e66a16u64, 0x58abfa9cbc57b86du64]),
-    ([0x56375852f503eab6u64, 0x344aa8865b38d3feu64, 0xa11bab9cdb76c9aau64, 0x2e1d0a59d3076a69u64], [0xc121f7b205e0ec48u64, 0x8ce71f7bd49b0523u64, 0xfda363f14f4bce34u64, 0x5e272070686d6cd1u64]),
-    ([0x4287ce1144fcf019u64, 0x20b7b50e5693aeb6u64, 0xc5be3aa28cfc8101u64, 0x352c4f3eb4931089u64], [0x5148a74093dfcd33u64, 0xdd2433c2523914e4u64, 0x39b080106034cb9bu64, 0x11f8affb870f9d5bu64]),
-    ([0xa970b4f3f6820606u64, 0x3b8a07f9f945e0a8u64, 0xc2989b69d7031f28u64, 0x3573c78bdc342aafu64], [0x505dc84daedc6632u64, 0xdf6b60870aaf7e46u64, 0xafaafab413b5e9dau64, 0x5e2e646e527de635u64]),
-    ([0xd165b2490ec7254eu64, 0xd9a0eb428f0d8dccu64, 0x766f71f92679eccdu64, 0x09594dd24ebf1658u64], [0x856bffb992a81f63u64, 0x8a718749aa6767c7u64, 0x5ecd87b17ce5bf0cu64, 0x1963defd25b871a1u64]),
-    ([0x728b638faa490c47u64, 0x3f7e9861925f3c85u64, 0x68fd174cc19863a8u64, 0x15908f85d4bb9c95u64], [0x844d37ac01cee6f5u64, 0xbc9c626eca869c76u64, 0xdad0dd8555a5e671u64, 0x0296ab0b6a886a83u64]),
-    ([0xe41960cc8f7cf554u64, 0xeb5b9ca898999be0u64, 0xeebdfa4d46a48617u64, 0x1be610c103e349beu64], [0xe3da3085699218b0u64, 0xc1bdeac9b2edd6eeu64, 0x86cbd7047db97d1bu64, 0x26f8650ceb9a9d9eu64]),
-    ([0xc932b24d441a3598u64, 0x40a3311e69461863u64, 0x5efd1735c252faebu64, 0x61aa4a6827f60d79u64], [0x2f919f25672a6c5du64, 0xd44c4520dff3beb5u64, 0xfc5bae831c231ba1u64, 0x227085fcd6ed5699u64]),
-    ([0xc009cf2b0763b3d5u64, 0x4af0a42ec0e52a48u64, 0x3897e487b9c66435u64, 0x54b572b23615cf70u64], [0xf9faa8e1bb2cba61u64, 0x74f14776e6e9070bu64, 0xa4f509a3dcec9974u64, 0x6f50cfb273695052u64]),
-    ([0x650613937dec903au64, 0x1346e6e574bb0264u64, 0x935c9c3ce1c6e9f0u64, 0x04e9c4fdd5553c2bu64], [0xc9537249711a813bu64, 0x09fc030e80654768u64, 0x174a5eb0b2011d4fu64, 0x0aede880959da8efu64]),
-    ([0xedd01d1e9965d339u64, 0x236d33f723b2c096u64, 0xb903259bd01c07a2u64, 0x7091e5c62447e6c5u64], [0x887cc8a9020218b4u64, 0x8687af65d7f10873u64, 0xbe3ef6998588ac4eu64, 0x188efb16056f8fcbu64]),
-    ([0x45b0c011142f943cu64, 0x5124ee69f08086a1u64, 0xe00b5ea72b0ce3ccu64, 0x2f6bbc8be1039af2u64], [0x6338997463c92770u64, 0x91fb7d5441374185u64, 0x0f3f586db0969ebau64, 0x0eea6fc0f01b6c41u64]),
-    ([0x0ff8423daf2fdb50u64, 0x946a4f579172314au64, 0x524e3a763bb50b09u64, 0x54c0fcd83aa5a469u64], [0x8a800a9d023a7e69u64, 0xe93f09d0bc740a3eu64, 0x78c058de459994ecu64, 0x4a3de7988fe9abe9u64]),
-    ([0x1c14292bf0255fd5u64, 0xd9f254f66ae5ee53u64, 0x86252263d4232859u64, 0x526e1effcfc73d5cu64], [0x8413ba6d2c881f3cu64, 0xb9eeab65818e6289u64, 0x82f34fae2ba6abb3u64, 0x04b90ba9c0224d28u64]),
-    ([0xe9fd3487793e0a1eu64, 0xeb9d714edf2c5d73u64, 0xf776f0b322161a54u64, 0x0c02777daecfbc8bu64], [0xbdc49ae95ccbddadu64, 0x33eab878133558afu64, 0xaba5e7792c090c1bu64, 0x0a8de7459bce9722u64]),
-    ([0xc2c919362a37a2f5u64, 0x1fe90e9f0a64c08bu64, 0xea8ca59fb662da9eu64, 0x387290bdf40839f0u64], [0x92e65fb60718d8efu64, 0xddd2f29d2d881a45u64, 0x801079847bc28a9cu64, 0x23afc799620b48d7u64]),
-    ([0x72d73ebbe97445d5u64, 0x7836750f231bcd86u64, 0xe4b5243a8bda7ca3u64, 0x0fe09ddec7fa8e98u64], [0x836b3843e1909912u64, 0x7fad429efe43af00u64, 0xc8cb5d435575c764u64, 0x102dd0eaf0eadf16u64]),
-    ([0x80440e833ea39b55u64, 0x0c223e998d178fb6u64, 0x5da8aa10fae01e8cu64, 0x181c42b69828825bu64], [0xf0f4bb8e6be033c4u64, 0x5b6483e6da1bd250u64, 0xa79b39b3300e1fc1u64, 0x1ea55e2f22bc0878u64]),
-    ([0xbf1b369c0a8445b6u64, 0xafef0eef02e9618bu64, 0xd74a7534d0a5cfbcu64, 0x6be4330063d404e1u64], [0xd22e49d83b403865u64, 0x4416e4494b26ef63u64, 0xae0844e324634eafu64, 0x669364f5ff3dffddu64]),
-    ([0x9c097282f8518c00u64, 0xc88534756de28ddfu64, 0x081a5545bd583161u64, 0x5fc42e85cd535086u64], [0xcf687d6d7b4f3318u64, 0x3d2b1d71f18d4dd3u64, 0xafada8089034fc5fu64, 0x3b875c1739cf69a8u64]),
-    ([0x0c06f42c4fa19fd3u64, 0x10c93f37bda7b84bu64, 0x635dba604a48d7bau64, 0x3d0bbb72051a32b8u64], [0x451d301e155bba4du64, 0xa66b62107586a9efu64, 0x6819da142433aeb8u64, 0x60f7729666570801u64]),
-    ([0xe4d08b3a9bd95dc2u64, 0x0a83b10a7188a75bu64, 0x5fd7929a9439bb00u64, 0x12c1c0e837c5ea1du64], [0x6831652a5a803485u64, 0x2407f86271e35768u64, 0x5f6ae226f768453au64, 0x582e7d24efa7a283u64]),
-    ([0x0612b0fb6cc1107cu64, 0x22d2201ed10a653fu64, 0x896fb6a2fbba8ee9u64, 0x6c1bcba74f737371u64], [0x38cc4aba5a053aa0u64, 0x8fce39be969a3ab3u64, 0x2cdb6a15d03bce3eu64, 0x63311791ec99fc8cu64]),
-    ([0xb038298303139ae1u64, 0xc1eba7f573b3b0edu64, 0xaf6a10b22f3eda88u64, 0x1629e4ce8328676bu64], [0x3916c603cc5e8224u64, 0x1fcf65e8d29d5f42u64, 0x453676e6beafef96u64, 0x51686e27ba9c2322u64]),
-    ([0x9df832cfdf2062efu64, 0x533c9d94a870e33fu64, 0xc0dcf3788fe23b2fu64, 0x1325b766f5943eadu64], [0x8bce1dc174b7225fu64, 0xcb4dc18042338228u64, 0x7b8998d2e3ccad93u64, 0x124ca66c682f38c2u64]),
-    ([0x821f241ffda547a5u64, 0x072f5049b1d0e187u64, 0x51b31982d33b68deu64, 0x0c4e8f3a988966e0u64], [0x68dab2ddfe8e94fbu64, 0xcd8a137f37d125ebu64, 0xb98dc01a279c9ca4u64, 0x26b5dc5c7fe8cd67u64]),
-    ([0x522303685af9251fu64, 0xcc4a3619fc303084u64, 0x841ec896cf8d6b71u64, 0x3f80ec39741c1ee0u64], [0xdc169f6627d65c69u64, 0x900092953f93d429u64, 0x1e7aae08cfc80bf9u64, 0x000da504cde26cf9u64]),
-    ([0xa42cef3b59936acau64, 0x3f5f6b1fce35a767u64, 0x06697712ede6bda9u64, 0x6c0d330034910c61u64], [0x459ffd658a14a319u64, 0xed3c0ef3eded02c4u64, 0x6110a2c48d50f13bu64, 0x40aa335217c18475u64]),
-    ([0x82fe7994226a7881u64, 0x7442551c91b1af33u64, 0xcb52d65ec3b820f1u64, 0x5b3330fb21510ba3u64], [0x0de1a0ca9ea96ab8u64, 0x9f0191019fdb537fu64, 0x867d756b34939dd6u64, 0x18709507d57d1da1u64]),
-    ([0xfe228225b0b37c11u64, 0x015abe13ab2a152eu64, 0xb9575235ed65ee9bu64, 0x568178ed18c25162u64], [0x81693d4c8f5a883eu64, 0x9c94b360b9cec5cfu64, 0x8bb6c4f2603bfba9u64, 0x1ca1d3efb9222baeu64]),
-    ([0xe99ab4b0ce6c0c7cu64, 0xd5ea563e74aa3185u64, 0xab1c2cbe2844a0ddu64, 0x098bcb7ba7a1102bu64], [0x44ec912b733e054fu64, 0x3d3046645ca6fb25u64, 0x573e294c256f0d9bu64, 0x730fc349fa6b97b9u64]),
-    ([0xe4a9ecb7395b88dau64, 0xb6d541ba86f98b53u64, 0x9f0db723ad6706b7u64, 0x2288dd06325810acu64], [0x1652781e7f134193u64, 0x55e7524f355665ebu64, 0xa2364e11239112beu64, 0x491d7051848e387au64]),
-    ([0xffffffff00000000u64, 0x53bda402fffe5bfeu64, 0x3339d80809a1d805u64, 0x73eda753299d7d48u64], [0x3e0ef921c97b4175u64, 0x89acd4954ba20637u64, 0x319f45db092b1a51u64, 0x6d3ff1bccfe53872u64]),
-    ([0xc3d729985a3d27adu64, 0x697a246cd90253a0u64, 0xa3ebf7dbfa2a6d11u64, 0x418ff18f4ade05a6u64], [0x5bd406dbfc0f0a08u64, 0xbf6ede7e480770c7u64, 0xcd4115a98d41bed3u64, 0x01167ec2ee02e3e1u64]),
-    ([0xbabe46ffd11fbcb3u64, 0x7d268d78c59e5b00u64, 0xf93aebe3fc216f65u64, 0x6a9c4100d476d6f3u64], [0x57ef668f5d840808u64, 0x1a1741294c6ed2cau64, 0x6589bc39f6119e8cu64, 0x45bcb0c706e3c804u64]),
-    ([0xc58b87357f57f234u64, 0x53e4dc21d66c88a5u64, 0xd34f93e73013f727u64, 0x6c6defb1629354e4u64], [0xcc0b75feb6754200u64, 0xd5edcdd41bca2f9fu64, 0x0371c1d6dc3c1a40u64, 0x3996f91e16f0c94eu64]),
-    ([0xb6ca42cf7e8096b6u64, 0x49b53e5a66157cffu64, 0xc80356e65d94e358u64, 0x245266ba46b38c19u64], [0x2a373b4b09f53079u64, 0xa05677576f849fc2u64, 0x6639de08fecf2c06u64, 0x5d0cfa787bddc62bu64]),
-    ([0x75dc07e6b09841aeu64, 0x42ae7460b333ddedu64, 0xa7cc7d4d32121c42u64, 0x195973938b410ebeu64], [0x35e0ae05a05c0729u64, 0x15db22933f49e3e7u64, 0x7b308c0f4234a82eu64, 0x5c0ab774432161c4u64]),
-    ([0x70217c6264ef06a7u64, 0xf3b3f165d5541509u64, 0xa1d384187ac0cbd3u64, 0x68a5b2d1c312e47bu64], [0x7b239a163a756932u64, 0xfb51c64bd9b814edu64, 0x0539e7e3b44eabb6u64, 0x72b4720fc9b90760u64]),
-    ([0x713054cb61f934a6u64, 0xaa839e93b61ed367u64, 0xbcd386b654d64e49u64, 0x669786e13262538cu64], [0x1f21f3090dd64f2eu64, 0x8dbae022a10bc558u64, 0x6476fc0334301dfau64, 0x410c3e9de80cd55fu64]),
-    ([0xaecf3d3d99feda43u64, 0xbaecd9567808eac2u64, 0x7b734cba89c47734u64, 0x0b55963f1644f605u64], [0x20fa0306c1b442ddu64, 0x3c4a63561edef547u64, 0x38a9f51aa07120e3u64, 0x15e7a067b39e48d2u64]),
-    ([0x3e40d756ff854ab9u64, 0x33ad0b658b878cd3u64, 0x36cd562985291f23u64, 0x5353bbd5cab87cf8u64], [0xa88249cd0b277cabu64, 0x610fe35ae7c2b3b5u64, 0x6123a658aa3b3442u64, 0x2ff9c4a56ee302a0u64]),
-    ([0x92b4c291774613b8u64, 0xe87ab3a7d22d6c55u64, 0xa9326e0004f6e7c7u64, 0x196f79855eaa576cu64], [0x9c9ead531d7ea3c1u64, 0xce842bfa00b16e12u64, 0x0022f185a374a964u64, 0x6df9a422e247f9deu64]),
-    ([0x1695feeee6ebbb23u64, 0x8842ced375a1f983u64, 0x23d33c5281359d10u64, 0x6c99d17c31c23df9u64], [0x55cdf780e5a8847eu64, 0x42c4af15a36319a6u64, 0x1448c80431a2e471u64, 0x1dbb66e4f88da5aeu64]),
-    ([0xad5b3bb2b65b60e3u64, 0x1140515e8a158dedu64, 0x18fae2b2b0ec6a98u64, 0x05ff8b7ffb85398cu64], [0x5f464b447e1989b7u64, 0x250fdd0876288f22u64, 0x84270db54901e105u64, 0x35d5f861ec3498cau64]),
-    ([0x5afc5292bb43a009u64, 0xaf7ccd6a86be44a4u64, 0x2b3c9b710572b491u64, 0x19e9f56c69e40d22u64], [0x022ca8d17bf771abu64, 0x25278ce026240dffu64, 0x99a84b47b4268890u64, 0x1d12a6cc1236ccafu64]),
-    ([0x2f3ec47bd8b0ba51u64, 0xf4de1994dace1ccfu64, 0x21c1bcc99837c8b4u64, 0x480fec2473e12abcu64], [0xcc20e0b7cde3eac9u64, 0x809c9aa0f333e1fdu64, 0x6bac360442cc5b6du64, 0x408321d8b4faeaf4u64]),
-    ([0x3e286a9ea36bab20u64, 0x0a21d9526aec0710u64, 0xc5a592e88c3fc46du64, 0x431c583453a9a25du64], [0xc0efd627595a935fu64, 0x70b46f64cb8dcba8u64, 0xbb672556fe525b16u64, 0x39f7a8db89c79ceeu64]),
-    ([0x7ebf2fcc0f5611aeu64, 0x262724b71d050aa9u64, 0x1e97f596a4c8da48u64, 0x2e3e440d3d981efbu64], [0x4d7903f59be17f7fu64, 0x5a4de16e175b7ffeu64, 0x8466f0b302d81fd6u64, 0x18946376ca3cbc97u64]),
-    ([0xcbd889784fc85af0u64, 0xa48e8e79ce1738afu64, 0x5ad6b00db8c6608au64, 0x37ee70adf3759a10u64], [0x74458c5c96c1795bu64, 0x4b4dd3c2c1807a12u64, 0x022904e33b6988a1u64, 0x449c08551bbbea2eu64]),
-    ([0xe0a8ac1b333b6dd3u64, 0x138cca56e78e96b9u64, 0xe8378be639ffa7e0u64, 0x323f735d04dc0040u64], [0x072ae184afca4733u64, 0xbf57f12fd54c4c18u64, 0x4400129c230914fbu64, 0x2700af43605249c9u64]),
-    ([0xaae5c2b5313d9002u64, 0x18a5c4ac27eb44e2u64, 0x1ad6ff97430335c8u64, 0x3f0dd2e5157046dbu64], [0xdc8e03e3e01950a0u64, 0x73aca07d0616e0cdu64, 0x91183ba74b1a80b4u64, 0x2b116991b5d33569u64]),
-    ([0xfdde6c4bcc3c47acu64, 0xe52fb0fde29479deu64, 0x051f83121c342bf6u64, 0x3d6a7ee321cfb3bau64], [0x4bcf7500214ca975u64, 0x86ec8db39decf270u64, 0x8220707679827e77u64, 0x390d9d70f3d5cb76u64]),
-    ([0x546c0da3d6f92de7u64, 0xd560e24fd4d442aeu64, 0xb645e1a4810837fbu64, 0x0ccf3db28c42d6efu64], [0xc0056eb8791b5682u64, 0xe73a01e6b19c4ab5u64, 0x323215b9d01d8612u64, 0x11c81f7799b99ec8u64]),
-    ([0x94e53fb564bdd489u64, 0xb0f3bdbc0f422b0au64, 0x943f2b9abaa7250du64, 0x5c6170ead42b9162u64], [0xb36c662ea9e2c824u64, 0x95a3078b7a2244b5u64, 0x911b2fa8203efa99u64, 0x2be1580722fa1c76u64]),
-    ([0x97498cee10f65636u64, 0xe83b6fa83d80ddb6u64, 0x40e95ba8f06421edu64, 0x62de145c26afc677u64], [0xa9fd2c0120416d7du64, 0x7e103ff6da7aafc8u64, 0xb75d9bcb48ef6400u64, 0x10558b2e4dd19f17u64]),
-    ([0x9b173ba8dd620212u64, 0xe1ffe63827d53336u64, 0x2b52573b968efab1u64, 0x0c3fd4bf2035cba6u64], [0x2de9d86d2ff9fc47u64, 0x3bb688af487abb37u64, 0x75ba4768b5762499u64, 0x134f23fb8c0e1eefu64]),
-    ([0xe3909bdfa35ee205u64, 0x6427279edc58fd1cu64, 0x8875f56db1fbe72cu64, 0x72fc7df04eb2ae3eu64], [0x1bf82a219bfbc894u64, 0x22163e6d880a8edau64, 0x9116f208e08a63b2u64, 0x71807fa43a64ed2cu64]),
-    ([0xb5cc4b34b05aff2cu64, 0xdbeecf7cd0cd4949u64, 0x668000c77dfd2ab5u64, 0x2d17c104bb6ae704u64], [0x620c64b1d78034bfu64, 0x15144b0720b52911u64, 0x7eb88b6d68bd2e57u64, 0x6d010b9a6ce5a3a1u64]),
-    ([0xf3a6617be19d472bu64, 0x776f602b2b358f7eu64, 0x6cee5a925bf81b70u64, 0x5326a8e515acb639u64], [0x18f31b069024ea26u64, 0x36b653d6570b2be3u64, 0x20a708ba900f595au64, 0x27de482a1fb55804u64]),
-    ([0x16a0c798f162eacbu64, 0x788db8d2298d3f8eu64, 0xc48ba1f92b1fec5cu64, 0x6bbacf811bf1365au64], [0x0b0f004e2afede8cu64, 0x81d288d70f753d02u64, 0xc0c6c3c2af427a66u64, 0x4e7b6e4060ae0350u64]),
-    ([0x065a52ec3e0c7ac2u64, 0x51dc256927d8f49du64, 0x50fabf7d6f4d5c88u64, 0x09807110edd98c4du64], [0x5df43b8f66df5f64u64, 0x518164ff3e870458u64, 0x60aae1a98ea8c085u64, 0x3dd4e0703b13df41u64]),
-    ([0xd098777bae4c5131u64, 0x401d8a62b9c4f076u64, 0x87b5b1048c572910u64, 0x730e62c281967ad8u64], [0xbd20b3c224e74518u64, 0xa9e5fc874854232cu64, 0xca495374b4919fceu64, 0x290c3e33623cf068u64]),
-    ([0xe7ecc99fd1b22cc2u64, 0x70b693f1fdc1ba0du64, 0x2df8d45c0c2cc85du64, 0x00154379a2314257u64], [0xd2e8bcfc3025ba8bu64, 0x6d0d8ed7d6ca985eu64, 0x547e50e9bc811f98u64, 0x57dfa4b159dc5140u64]),
-    ([0xc9f39f658c9620b3u64, 0x944f1b07b3c56074u64, 0x7e7d03f9e6ac83bcu64, 0x230d17191423f48du64], [0x0caff923f6386a17u64, 0xfaab72d397bf48cau64, 0xb81d15c62a70aa40u64, 0x55557fb1098841deu64]),
-    ([0x62979d1865413cb8u64, 0x9cede3875d96e2cdu64, 0xb651d7c4276bdb20u64, 0x4c48278129efd34cu64], [0x755997869f8c9c30u64, 0x8a113e4192a865f9u64, 0x6c65126f2835ad1du64, 0x0ed4c0bad89618e1u64]),
-    ([0x2393abcd485fee6du64, 0x4ed19bc8cbd2faacu64, 0x91f84ff7a4d48332u64, 0x493e63042b0b017fu64], [0x9ed805fa2942ecc3u64, 0xa82dfe899b217bb8u64, 0x69eb62900c9f88b3u64, 0x3c1e12187b9db76cu64]),
-    ([0x8cf77e056331d4fcu64, 0x18087316430ad943u64, 0x5397d635854b193fu64, 0x73630f9abac16145u64], [0x95e02f1293e0cdcfu64, 0x56d5fe6e9f17fbddu64, 0xda7e5960eaf40717u64, 0x10d96bf40f440c93u64]),
-    ([0xe9b77e2a455758d3u64, 0x25acb6a751c4cf62u64, 0x54f132dd768cbcd0u64, 0x2fbb702adadc3beau64], [0x7bf5ad32e4f6b3c7u64, 0x3b955ee9cb624759u64, 0xa665289eac37d8f9u64, 0x143430f362df977bu64]),
-    ([0xec479b5abada171du64, 0x6acf3f09884d9f7cu64, 0xb49c7e5f0832dcc0u64, 0x58a04fe37b3abad3u64], [0xc8e91996603631ccu64, 0x52cb0b288e2021acu64, 0xd48b1d842723c6f1u64, 0x0a20d397d8332280u64]),
-    ([0xbad332c47b502202u64, 0x9ede4e04f17d1bbeu64, 0xb3aff7b76fe8a396u64, 0x2429e6965a8b5056u64], [0x276a81b58f9a12c2u64, 0x1f54394128f44f63u64, 0x78eeda8685ffa359u64, 0x6fad642136a8bf20u64]),
-    ([0x8972aa6c93818ac3u64, 0x09d22bffba258770u64, 0x1865427c3fbad801u64, 0x1d890d75320a9811u64], [0xa1b6dcb32ef190e4u64, 0x89cb606652f0f42eu64, 0xc56349a037608c3fu64, 0x5566e16b963ffd90u64]),
-    ([0x12605a3ecd13f5deu64, 0xd540042d8d8ef0beu64, 0x8ee8aa1b9e97d4cdu64, 0x14be0024e28a13e1u64], [0xef871a8c0cd44c17u64, 0x0fe83719d2662b20u64, 0xfa2806daa77b8a77u64, 0x32abaca8eddc50ecu64]),
-    ([0x672fbfd4c682cf8du64, 0x21a6da970c07d2f4u64, 0x0d3a5af0340cd396u64, 0x1c0e054f510f1adfu64], [0x2d2521911bf8cbbbu64, 0xd278a441699f5cbfu64, 0xa0c8c0a68bb0eaeeu64, 0x0251ee85bf052fb3u64]),
-    ([0xb579a40cf2801b02u64, 0xd8ebfd640c6d99e1u64, 0x74ce062206a898e6u64, 0x698d2aab2ab68646u64], [0xe71ccb54b01127e4u64, 0xfdaaad0f6668d77bu64, 0x39327004ae958e3bu64, 0x159812bb7ee12c70u64]),
-    ([0x14ea074c1f23ba30u64, 0x150c9fa08508aad4u64, 0xd5c451b52ac38a70u64, 0x1c87f799394ba267u64], [0xd42678cabd748f17u64, 0xc8cd201d89983358u64, 0xe750fbb1039e62b5u64, 0x359c35560f8f0abeu64]),
-    ([0xc0ef0c55544da820u64, 0xa2a2163218d48aa1u64, 0x4c72f50d0dde0ecfu64, 0x3cd5239cd9c777f7u64], [0xef000da2d511e62eu64, 0x83e17616a5d11d90u64, 0x08a0ebeb016fa275u64, 0x3fe88cd9696307cfu64]),
-    ([0x222b74ab3f9cd482u64, 0x2bdf3781210e04f7u64, 0xfeb34b02af290334u64, 0x0f6aefc0f255ee76u64], [0x8e0d7a3955c19de5u64, 0x31ad934aafeef7e5u64, 0x1a4b15901fe1fac2u64, 0x2cd32ab7ec144e9eu64]),
-    ([0xd7a9ede4acccf961u64, 0xa826621eaacc2ecdu64, 0x805c3d7296833b35u64, 0x1689d336fe1aa081u64], [0x204b29f05614d984u64, 0x4665ad3d728ac84bu64, 0xf19615a84f1f4641u64, 0x072895fc8d11345bu64]),
-    ([0x393400b40d41c6ebu64, 0x7dc08825ca2a922du64, 0xdae0712f321c7aeeu64, 0x4ca0890fb076d6c0u64], [0x3f78d761911921a6u64, 0xb868a09abec05ff7u64, 0x3f464f732c142bfdu64, 0x2ef3ec805540b59eu64]),
-    ([0x5ba6c1e4335d3495u64, 0x0cf9b80ec36bb2b5u64, 0x4f4bc1c2f8689736u64, 0x20261c76ad9e668du64], [0x5b2c60188d639985u64, 0xbe45db003734a67du64, 0x49540ddf52e56e3du64, 0x1e6c3062a94855d2u64]),
-    ([0x0fb67041c5129a32u64, 0x1968b4524370c27fu64, 0x456713bee1b503eau64, 0x51f3ee2c40319d20u64], [0x244e30788eea79e4u64, 0xc446a143b5cb3169u64, 0x60bbc2fdb0aa93bdu64, 0x0e9c2b57aa3ead7cu64]),
-    ([0xfd1d06b2287e2963u64, 0x1d10182ef83597e2u64, 0x2bea3d671d909744u64, 0x5c2bbaf250f91eabu64], [0xe715610a495c3f41u64, 0x4f2311dd5613da88u64, 0x4a4f5542c6a835b4u64, 0x557b018be1b5982au64]),
-    ([0x818683ca0e981e53u64, 0x7abdca35bc89cf2eu64, 0x9d40c19692ced693u64, 0x411cabcc07dbc25au64], [0x722382e3678bbe38u64, 0x72e58a7e9c9e1bd9u64, 0x4066b96aa003813cu64, 0x6633651296506f0au64]),
-    ([0x1c2a241e2d08c1c0u64, 0xef777b4d984f404au64, 0x751b65160340510au64, 0x0522f0091746f546u64], [0x23c9ff846218464eu64, 0xe3f6f1c03734db2au64, 0x7e4d4552869ab7a9u64, 0x152c24edccb230ceu64]),
-    ([0x40ed2e6880add73bu64, 0x635aaef6e05e4190u64, 0x2a2b361d0b35042eu64, 0x5225da87a3150187u64], [0x9909576f664c5319u64, 0x3eddf3c66f169ec2u64, 0xc835c653ca7d5090u64, 0x1424c1963665a22bu64]),
-    ([0x9830635b160a4e50u64, 0x80863f5f009ecad0u64, 0xb6794f0e98b62bcau64, 0x14cce86e2ca5471eu64], [0xda4d7431789df7f2u64, 0xcbfff89750242883u64, 0xd3e2b2737ad96c2bu64, 0x65d44a2b7938e802u64]),
-    ([0xc291f14cd98a7d2au64, 0xa67a0d81db669673u64, 0x93c03dbd4683c4bau64, 0x3fbe8d612d2d97a6u64], [0x80a71307a3e925dau64, 0xa923e095a7c66e4cu64, 0xe7fbd89f1e92e1b1u64, 0x2148163e0f2555cfu64]),
-    ([0x35825224af0aaa1du64, 0xa379114c0bc2a6e0u64, 0x7c95a954c7a25068u64, 0x13c81712a762f460u64], [0x6bbba775c16106d1u64, 0x5f73559edda0e605u64, 0x38fcd1a85e4ccc20u64, 0x1172fc9e38ea9685u64]),
-    ([0x3eb55cb297533407u64, 0xe620c5e1ac91e704u64, 0x21d99fc92763044au64, 0x5c43faa743efbaf2u64], [0x5b87a72338380725u64, 0x16c38cd5539fb266u64, 0xfa0095b6eb636b96u64, 0x64f99990d2ad955du64]),
-    ([0xeaa83d0bb9842e37u64, 0xbd69193792854e59u64, 0xbec7f66e6610a74bu64, 0x6d3455f8128b96e1u64], [0x975d5234f00ad47bu64, 0xd67341d6e5f5944au64, 0x13d22a0456d4f989u64, 0x45339249d9e46aa4u64]),
-    ([0xfd961ec7e5265cdfu64, 0x139d6b9010e7aedcu64, 0x318d7031d74e4c3eu64, 0x00d33797d5d3852du64], [0x363305e2a88ca75du64, 0x85d5d55cf099beadu64, 0x8abd718be9e5f61cu64, 0x1004f1c4ca94e74eu64]),
-    ([0x5b585be1aadcb79du64, 0x7545442a4caa31c6u64, 0xebafe0c16826da69u64, 0x4fce7c4e35f66b90u64], [0xc90155399d18a64bu64, 0x9220256d4d58fef0u64, 0x948633b2a49b7333u64, 0x5034bdf09a6538c1u64]),
-    ([0x023d030dac526fc5u64, 0xe4eb960d6a0abcd4u64, 0x2736269918a1a78au64, 0x66ba7884b1d5501cu64], [0x324336ec5789eba1u64, 0x6a4feab40cd8dc05u64, 0xff97766aa37f24c3u64, 0x007bb909a85eaec9u64]),
-    ([0x6d7e35ad7dffbdb8u64, 0x92aacd273b23eb13u64, 0x3b841c065526cbf8u64, 0x219e386c09e2fd3du64], [0x593271cdf31e39c4u64, 0xe52d6bdf8e416752u64, 0xc6d0f892995f8a63u64, 0x1c5380b8053e0588u64]),
-    ([0x5cb7f86764fdea09u64, 0xd749d37738918ca3u64, 0xccfdb0625b8ce63bu64, 0x08a5402ed2d26efau64], [0x5b44b4d85a8d9765u64, 0x3892e0c2fe96e02du64, 0x34e13861b461c2cdu64, 0x0d8113dd61a22aecu64]),
-    ([0xac159e2688bd4333u64, 0x3bfef0f00df2ec88u64, 0x561dcd0fd4d314d9u64, 0x533bd8c1e977024eu64], [0xd30efeb08222f192u64, 0xc02169bc08a2585du64, 0xf64d66e3f299af9eu64, 0x71313df37958b0c5u64]),
-    ([0x370ab333c6f92093u64, 0xe955c873c327fe57u64, 0x29311f879db097f3u64, 0x33fd6ef876296e2bu64], [0x2a15e498e5fd84adu64, 0x623d426b296c6f24u64, 0x743e58bade164ae7u64, 0x0e5797c8eb71d816u64]),
-    ([0x5b36bed952623e8bu64, 0x15b19c81ab2696c5u64, 0x06551ddbbdaeb8d0u64, 0x0ae89e2178411af2u64], [0xe3112910df9d74abu64, 0x87fb1c203bccbb71u64, 0xb8ea51bc9f398a8cu64, 0x4d4082e068a7aeb1u64]),
-    ([0x1e4c57f1c23661a7u64, 0x56fd3bf0033dd6feu64, 0xac0755754412cef0u64, 0x6972c9277b211191u64], [0x7a2d61ebc3c9df5bu64, 0xb25286b7e034523eu64, 0xc30dca334bdf283au64, 0x2c33952df5587e51u64]),
-    ([0xa8e747e70c3352b9u64, 0x1e393a229e5c956eu64, 0x074231113972d1f3u64, 0x20b80fa52ca5f2b6u64], [0x3cac550c9f783476u64, 0x4a3e46d4e07ffe33u64, 0x1625a525219782e6u64, 0x72f108014ffd14a6u64]),
-    ([0x52b3b5b80abf68c0u64, 0x4d1b05e2e48b2c91u64, 0x29e041031e004e4eu64, 0x5bf9f3bed6f24301u64], [0x2730e29ed8d02ab5u64, 0x1423af6c8cef59bbu64, 0x8ff84762d8434e5fu64, 0x181b0e9b3d7c3aa6u64]),
-    ([0x8faa4aea5baef097u64, 0xfe5e6f6b805b1acbu64, 0xf1326910bc966667u64, 0x1616701853308eafu64], [0x20f5b2ff52335c7bu64, 0x4e9a6e93a44b4e0fu64, 0x015a3c5b2e9a8911u64, 0x57685e07fc503fe9u64]),
-    ([0xf1a51657f2a2f9e7u64, 0x4aad4e7565595253u64, 0xa3423aa9719209a1u64, 0x257de3fc58bf7b73u64], [0x1f480b72acad1af7u64, 0xccdbbda32e6c990fu64, 0x9f9108d9981cad73u64, 0x54bfaf7b94db7e29u64]),
-    ([0x8ad72a76bc61a4efu64, 0x94aa3be95461c35cu64, 0x8506b7660550ec69u64, 0x595e6c715d7a8ee1u64], [0x5c78e4b3056db134u64, 0x124f46406c09bd01u64, 0xdfb7c145e358bd38u64, 0x2ac475ec4542daa6u64]),
-    ([0xe87b01434fc4bad2u64, 0x22e4aa0df97601afu64, 0x69b93a9f9581e6dbu64, 0x66b6d6874a3acdd9u64], [0x323e7f737c5215d8u64, 0xb677ea25565243f8u64, 0x245b749be4572955u64, 0x53220d413a93949bu64]),
-    ([0xc460bf22c6dd910fu64, 0x2f72d3eac34989c0u64, 0x6b57d03403d311f6u64, 0x2789ebc8ca0be3dbu64], [0x9c01b940f3721e45u64, 0x24418e4d1d672fd9u64, 0x8a4320cc804a6dc6u64, 0x2a30172e3161c28bu64]),
-    ([0x874529ef17d6a510u64, 0xc516b36d3c1ac814u64, 0x65b56a1a1234552eu64, 0x5afc55fc1bc3b5feu64], [0x75eedb1ef0cc138bu64, 0xcf8c51033caeaa21u64, 0x0a00e76d350f7d94u64, 0x5f54720b0c67cfe4u64]),
-    ([0x5dd34f3efbca7e49u64, 0x933c92294a7df311u64, 0x44915a4a7e636571u64, 0x32d547e9d6455993u64], [0x648df9d83e855148u64, 0x35c7aa2a011453fcu64, 0x2cdcaa7acc25e74du64, 0x516f304409c10cfcu64]),
-    ([0xea65f987bc35193cu64, 0x4d1b63605f954ac2u64, 0x416914466c980872u64, 0x1b92bbd397fcaca0u64], [0x44f08d4cbcf3a5fcu64, 0x25a314a1aa592345u64, 0x2871c07022fc7ad1u64, 0x1e6200d2e4dbdc0au64]),
-    ([0xbe012e3d0f88b8f7u64, 0x6d5dbfac1f1fe4b9u64, 0xf738c5833b75cdaau64, 0x1b02b7b62c9021abu64], [0x3232615727b07b56u64, 0x94540ca6802bb8a4u64, 0xf5e967b2663c1e1au64, 0x33f5656184218b8du64]),
-    ([0x4ce5e53162d931c5u64, 0xefd7aba520c240a2u64, 0x3b1f1ed6a578f80du64, 0x3ee89aee630fab44u64], [0x577d15a5aff683bau64, 0x4ab3fecf017ef08cu64, 0x8d6fed44cfa6d1c5u64, 0x6ac5b3cc196b2e8du64]),
-    ([0x30f9a5d7f0c3cdb2u64, 0x6faaf80b0c55bd84u64, 0x3c8c71b5ba9e612bu64, 0x1ef34257e466b84bu64], [0xc950962a0da13061u64, 0x975211c8168e4250u64, 0x52a46a05d935fd4cu64, 0x3599533c4dda6baeu64]),
-    ([0x4f71fa87d693d5ddu64, 0x854dfad4798e80cfu64, 0xcf2f177d4c65cf90u64, 0x0a52f99d1c130150u64], [0x58a81056f762de64u64, 0xd4e2c71a4480a48au64, 0xa3a4479a2fe00cb0u64, 0x49e590b749e3a032u64]),
-    ([0x0deb33b55635d786u64, 0x64dd6fb6b626c098u64, 0xb9e31698cf2929dcu64, 0x22012b7ad6b8e554u64], [0x7368abebf99db6f1u64, 0x2ebe539f8377750au64, 0x7bffff765335ddc7u64, 0x2ea56c28b9d22ecfu64]),
-    ([0x0243a36f7f89fae5u64, 0x43a6230c9f9b8b90u64, 0x2046ca955ddeaaf0u64, 0x51d8a8409974793cu64], [0x2c4ad86c13d3fce4u64, 0xb54122c7e07ea2e0u64, 0x90e263e7eb09a56bu64, 0x48471a2a7bca08d8u64]),
-    ([0xeb7d8810073f5d6au64, 0x7a807acfac7a7564u64, 0x1769583e0d6d2c1au64, 0x415e4339fb741fc3u64], [0xd092bd8ec51d6870u64, 0x2d8c523f9ba646c9u64, 0x79163d15a03d3d59u64, 0x688bbc60c0ebe258u64]),
-    ([0x9448486b546b2984u64, 0x3e9490f7e75dcd28u64, 0x9ca162e764f989b2u64, 0x0666cd9aaa9e76bdu64], [0x6de3a95da0ffc77cu64, 0x934e2d5244d162fcu64, 0x0889d0d759deb611u64, 0x04096794b6d81c2fu64]),
-    ([0x7acc968a7ee39687u64, 0x2b501e5b050695d6u64, 0x8d1373f244cb0933u64, 0x38b7e9acd483910fu64], [0xcd13b38cbc10ee09u64, 0x2a1086024b415b36u64, 0x1cf8cfe54231e47eu64, 0x01a2471d298a2fcau64]),
-    ([0x5fade10d7514eed6u64, 0x44e34c60b7c083e5u64, 0x627540c1307c98c1u64, 0x1ac53333ea0f66fau64], [0x783b3e3e4d61a5deu64, 0x4892d6c2bc650824u64, 0xd4527294116ec559u64, 0x6247c613340ac73au64]),
-    ([0xbbf254b932e053a6u64, 0x72291d47986d31d3u64, 0xbde19c2cf633387eu64, 0x5950a37bb55ee341u64], [0xec1c4bbb2a680968u64, 0x85793f16eaf10207u64, 0xb70c5434c0366b62u64, 0x2a4653cda7a9e692u64]),
-    ([0x4aff92c0ab6779fdu64, 0x10b6ee7abb0b512cu64, 0xa8f9ddce3882b200u64, 0x22229e6ca7456d51u64], [0x37e551b019dbbf6eu64, 0x054e67a7fefb8f69u64, 0x6680763994597444u64, 0x4463e162f97c14fdu64]),
-    ([0xb6526124c54da696u64, 0xfcac3a69d3f05c06u64, 0x34771621130c70cbu64, 0x07baba3f3484126du64], [0x786122a838f75535u64, 0xd76ac722aa583e0eu64, 0xad47b0da16298ef3u64, 0x22e24d4d40e3a42au64]),
-    ([0xa566da4dc6969ff2u64, 0x1ed904c73a6df631u64, 0x2b3f342903244163u64, 0x113b233a4e20eddcu64], [0xa1b5c568e638fa2du64, 0x0b46d5b7fd2fc23cu64, 0xa1a0e431fd76e3acu64, 0x27a7797dac833b9eu64]),
-    ([0x2d787c980313ea47u64, 0x95f315a6b2d095ffu64, 0x758318df7c28ca7bu64, 0x16d4372554761399u64], [0x026904523247132bu64, 0x2e9d75452530939cu64, 0xde2ec354f89a54f8u64, 0x0db2d8effd6699b9u64]),
-    ([0x88549e5f6c934620u64, 0x25cf42b161d631e6u64, 0x1e9238d1a4e8a711u64, 0x458ab48f1d3b4fecu64], [0xf4b8a6bce03ed96fu64, 0x668cb98e0c590565u64, 0xbed3ca257476711du64, 0x0a4ebd6f3b86f584u64]),
-    ([0xc3adf3d3e5d685bfu64, 0x13fd86cce858f8c8u64, 0x8df483f139d30e3cu64, 0x1c1c8c1e703f7f80u64], [0xff7284e12a884452u64, 0x1e8e9d261f54a230u64, 0xb717c06128657757u64, 0x05db98b293f6fda3u64]),
-    ([0x452b2c882eaec899u64, 0xdf31e04ad7a1c291u64, 0x1bdad4b55e2f230cu64, 0x153034fc43e61095u64], [0x4b66f042372a70f3u64, 0x8f96faf5ff2781a8u64, 0xc0145f9ad767b67bu64, 0x58473df2879a7911u64]),
-    ([0x196986abd51edcb5u64, 0x708c291cbae2a361u64, 0xac3f6e2f24bd52feu64, 0x6f8c83d4d0a0ae73u64], [0xe41edd4559026acdu64, 0x0abf69c25709d7b3u64, 0x37af316f36d8a760u64, 0x382d096605b4cc6au64]),
-    ([0x73d509e47ebe32d7u64, 0x8c6428df20f92acfu64, 0xa45a613d58124dcdu64, 0x6af7977fc989d6e9u64], [0xe8051fa91824738du64, 0x8530098379e46232u64, 0x3b9da56253626731u64, 0x43d4336d80980555u64]),
-    ([0xa87aba4a19b4551bu64, 0x77ecd8830dcc0a87u64, 0x29114bed4238dad3u64, 0x3fa220593e7aa7d6u64], [0x5edc1280b772481du64, 0xdb9815fc731f7d91u64, 0x8f8015a45d6106f4u64, 0x08fa4a00527f428bu64]),
-    ([0xd04fe1856b5161fau64, 0xe69e4983675d87e1u64, 0x143ae61d73677668u64, 0x02a880f0b3ede8abu64], [0x1948726e65ace648u64, 0xb5c0baeef4416b6cu64, 0x9b16f7391a4da65bu64, 0x3c2673da76b74eceu64]),
-    ([0x045697cc52056d9bu64, 0xc866372d5f7944f1u64, 0xd986c0c20a39fc66u64, 0x25b9984a697ff768u64], [0x346180c2cd339fdeu64, 0x5ae826a53df30174u64, 0x90aa06aeef78b46cu64, 0x4502129aad0e1aedu64]),
-    ([0x767ce31ad39ec74eu64, 0x28e0af91d785eeddu64, 0xd25e7f23bfb2ad7cu64, 0x6ff75dc4713f0d24u64], [0xb929fef0f45f028au64, 0xa4dc2d975a5d7690u64, 0x1830409fc68cdfe1u64, 0x27225ba4bedc863au64]),
-    ([0x064a5280cb9b0a5cu64, 0x028a0bce9fdfac61u64, 0x2dca882e26bcc0c1u64, 0x22de564df61c66eau64], [0x185ead42a9df0e12u64, 0x9a57eb762308b261u64, 0xf5c262bc18b2432du64, 0x2ba9b99fb3030c56u64]),
-    ([0x625d7b4024d0bc31u64, 0x70e783d9c51d6ce1u64, 0xb9ad44bff146d482u64, 0x38848f48d8897bd8u64], [0x9ba101e35625f059u64, 0x87edbb80e775cb1cu64, 0x969cddd0c7c4f55cu64, 0x3da0378aac3a0a0du64]),
-    ([0xf6a1cd7025d071a5u64, 0xa9bdb5218d05af8au64, 0x2ed1234cde5d6927u64, 0x4ae921e17d3cd87au64], [0x4e6ac482d33090d1u64, 0xce81eccd97aad3e1u64, 0x7d08ffc14a61e852u64, 0x5b71e44c185c7f5eu64]),
-    ([0x47af2d5b15406a72u64, 0xb9da9d4dc7871d4au64, 0xe72e21fce524caa0u64, 0x401133fe081daf40u64], [0x0601b32104f03790u64, 0xbe0fd5a1f3a20ff7u64, 0xcc1a7ca9e692d836u64, 0x2411d501ad114c37u64]),
-    ([0xbe92da98becd1980u64, 0xaac7ab07e301263fu64, 0xd5f34d908dff438cu64, 0x104bfa81cc9e333fu64], [0xb646246a7f0fce8au64, 0xe84e61d068dd8fbeu64, 0x0058b5abc8a8725eu64, 0x5f9088d87680de26u64]),
-    ([0xad5367ab4e7b9e84u64, 0x230164a97d188ac0u64, 0x0b2170f8fa33b907u64, 0x1637d76d92812edcu64], [0x5191edb0aa34c009u64, 0x046a009d4a2f938du64, 0x5dc10d1692c56610u64, 0x56357d10f42afd92u64]),
-    ([0x4c191129505eef66u64, 0xf27edb7e69c459f4u64, 0xb3e1674c00ddcfeeu64, 0x1638d87eefb9b5beu64], [0x75556b339042b6fbu64, 0x76124e3149bb0c33u64, 0xed7d16f7902e1970u64, 0x1d7153e41669a6c1u64]),
-    ([0x5a8bd76b81c8f97au64, 0x5edb4e2aef22df83u64, 0xebf8005827d0646au64, 0x26f678d408ec44ceu64], [0x9e3dfd8a85cb79eau64, 0x0a760e1a66fe35a8u64, 0x2d13c9c51de2be50u64, 0x3761a723f013f9f5u64]),
-    ([0x8bc1932b0d274a1du64, 0x885b416bad06a752u64, 0x70f081507724a71au64, 0x5b78f02be95b1a9eu64], [0x2839184819419503u64, 0x827da9f50daac902u64, 0x0455b86c7dd768d2u64, 0x5c0ae8d8ef346ff4u64]),
-    ([0xd04682224497a483u64, 0x4879a8628ff9a0c9u64, 0x5e5a49672c61ad12u64, 0x5d42ac3d44a1626au64], [0x892ee3a19dd88f9du64, 0x0c6db65900ed0f48u64, 0x280c4deb47e1d2edu64, 0x54e7bb1d2e0f0798u64]),
-    ([0xd64b34dde86304f3u64, 0xe94910119f300f47u64, 0xb3c03b15fa3a7826u64, 0x70822dc00c9fa4b3u64], [0x7bb8d59c5ef034b7u64, 0x72a9751478e4166fu64, 0x8816b2e18e674f56u64, 0x2160692d56150408u64]),
-    ([0x06b3eb0cdec6a8c2u64, 0xd453e58810b1cee0u64, 0x543f42237adbe725u64, 0x25db381661c95526u64], [0x89fdacd134f952dfu64, 0x09ba8cd6ac80db9eu64, 0xb11edb43aa52a2bcu64, 0x363d16250e3eadf8u64]),
-    ([0xc6570d2ea95bfd84u64, 0xdd4c749d2221afabu64, 0x99c8a193d439a310u64, 0x1a41230b34c24e22u64], [0x5435333e356347c5u64, 0x502f168f80f19b02u64, 0x3b51a1f92ddf74c3u64, 0x05ee0c2f873a084au64]),
-    ([0xe2a72c91d778db77u64, 0x2309a3d15c678111u64, 0x37ee782500a1480au64, 0x4e16b77eaaecc8b7u64], [0x1dbdbe26a36849b9u64, 0x5433d5815ea418b2u64, 0xcf17b9225d8fc7f0u64, 0x3dbee3c2a636f1efu64]),
-    ([0x4b94920969459132u64, 0x3768befea0679e49u64, 0x1d51a2e03b9c9cf2u64, 0x1e03d44cddf6abc2u64], [0x33c1380445eb4ff0u64, 0x7f21a1d3f062f0a1u64, 0x358dd2b1125fe8c8u64, 0x48acce648431fad4u64]),
-    ([0x8e92f6b7a949929eu64, 0x6b147027983c0f8eu64, 0x478c702a489564dau64, 0x6f1b8cad13ea364fu64], [0xdfe4ce7d6abc32d5u64, 0x7b4de1146cc5cf4au64, 0xabe3632d01396294u64, 0x3ae834304f5519ddu64]),
-    ([0x71962ed94d9ec469u64, 0xc21d1aec1b762d2bu64, 0x6db2b2d9ca2e4902u64, 0x5ea08340e97d268eu64], [0xdcb98a4bc57db224u64, 0x0b5676133eba0879u64, 0x9598ec9d0d219d47u64, 0x2692cd850402b915u64]),
-    ([0xe0833b7c617bdc5du64, 0x1911fd20282d1b25u64, 0x837045222b2c3844u64, 0x52dcf09ed8b68a8du64], [0x55cfa20e333ab198u64, 0xcd29424af723f92eu64, 0x40718cfa12d54014u64, 0x3988e758419b1282u64]),
-    ([0x2a0a83854a64dccfu64, 0x0dc290a30beab8a8u64, 0x96c0943166661472u64, 0x11fb33e0bb0973b0u64], [0xae8e64987fdc4212u64, 0x7bc2c153e3e9fbd2u64, 0xdbbb7d22c5f6b8a5u64, 0x1dbd484a0fe0a9d5u64]),
-    ([0x9cb7d0a095380a13u64, 0x38b307c4acc098afu64, 0xce4610ed89ddafa9u64, 0x54f53f93d39b0e35u64], [0x160366b45ddc069eu64, 0x5a39bbcca74ede4fu64, 0xf2977b1100a13aa4u64, 0x2e12136af6f884a6u64]),
-    ([0xac9567be9a36d522u64, 0x59d7a64cccac9b12u64, 0xefacaa48c2c48274u64, 0x6b54c447ce21b78au64], [0x4b826bee020ff940u64, 0xbf0985f53b04d8acu64, 0x8e09d2838eeda7b0u64, 0x3653a23b3847b6afu64]),
-    ([0xf91fc60871a54c24u64, 0x52a2948b0f28fd5du64, 0x833e844a9af6b85du64, 0x3d892150f3f639fcu64], [0x0295a1e49fc1dacau64, 0x7ce1d8872aab473au64, 0x3cff4ac4176da4edu64, 0x40bf831f3e7b9eabu64]),
-    ([0x194e60f3a1520636u64, 0x7be32750901a3f7fu64, 0xa495eee2bbe6b01au64, 0x57eb8aeb7895fc66u64], [0xbafa9dd327d57528u64, 0x9fac85d79e81bc31u64, 0x2e8d9a6691ed1091u64, 0x1f1abca92db641d7u64]),
-    ([0x4c5d77199341945fu64, 0x51622ff754d375d8u64, 0xf3ddf8f1f8e8f7d9u64, 0x122f78893b8d266eu64], [0xd4b0f2aaadcba71eu64, 0x09d673860bb378d8u64, 0xdc8595bc666a4eb3u64, 0x0dd6dcc4a160faa5u64]),
-    ([0x8902bffd4b07db39u64, 0x9fc5e9ec9c4b3efbu64, 0xeeb4135df31350fau64, 0x042f60cdeaa382f0u64], [0x3b8866fcd93ab39eu64, 0xc33abde31bdb70c3u64, 0x1b045ee1fa120a7du64, 0x36ce17345b6a9b17u64]),
-    ([0x92863b2deccd28d4u64, 0x3de32d00989071c5u64, 0xa035b7beb6578970u64, 0x251378def8f9daa7u64], [0x8a43ee000b46b87fu64, 0x9bc39743ba660cdbu64, 0xce762ae49bfbbf87u64, 0x25c8ef3ff66aa017u64]),
-    ([0x8dd702cb688bc087u64, 0xa032824078eaa4feu64, 0xa733b23a98ca5b22u64, 0x3f96405d25a31660u64], [0x71a21ff8bbbe9d2du64, 0x219b36727c8def64u64, 0x1b30d3550b0b1945u64, 0x12cd79cd40884091u64]),
-    ([0xed7a973f12c92d2bu64, 0x79e9771aed2a45e4u64, 0x931fcee5dda872f9u64, 0x0ec9457c8751bd35u64], [0x841f3c2a9cb6fc89u64, 0x91e710bc48623092u64, 0x0dceb8d4611a6753u64, 0x6156acdf8a2f0829u64]),
-    ([0x64d11e5e40eed6d0u64, 0x7d51124d9f38a3d2u64, 0x248cee69c0ae043au64, 0x4fb2cafd455e05aau64], [0x3d6984829836c7aau64, 0xfb8ca5ab4df46c30u64, 0xd2b630589ed0a453u64, 0x3b3ebd03536de894u64]),
-    ([0xdba78a63795564bbu64, 0x64ab6f0ea079a6e4u64, 0xd892949d7b608079u64, 0x590eea6ea42ae469u64], [0xcbd708739085cf27u64, 0x61083418c0d39a86u64, 0xf4015f43f35d6b18u64, 0x5829494d08898b81u64]),
-    ([0x523db144c948a873u64, 0xdf83c14c0425182eu64, 0x2c14671f92089bc7u64, 0x19e77e100025cedeu64], [0xcef66f2a903740dbu64, 0xfa8dd403132ee022u64, 0xc039c4259f8d0c31u64, 0x2ad7dda1ebdb4209u64]),
-    ([0x611aff08b8082bc6u64, 0x6a73ff57fd162c88u64, 0x2c4bf77e923ee502u64, 0x372bf00eba8d17bcu64], [0xca4aa0f2b6766d94u64, 0x1986247384620bbeu64, 0x5113d6136d2574ffu64, 0x32e2168c3eafd23cu64]),
-    ([0x5f2fb9c8ee9f3784u64, 0x5d5e4ac091a8413bu64, 0xf1b534e07ce71098u64, 0x55c4b0d68f671db5u64], [0x63d1f9a83f81bb33u64, 0x017fb8d251c4e679u64, 0x98c64f3f09a7f137u64, 0x5878760f07fed610u64]),
-    ([0xe7449852b6744cfbu64, 0x2d35851865696a5fu64, 0xc2301c80b5355c20u64, 0x6397ca1910b4c0d6u64], [0x153a2503158ee6c3u64, 0x77be3bd5c066a571u64, 0xba95343c8f861423u64, 0x114c2c8f65bbe798u64]),
-    ([0xd10899b24dbbf12du64, 0xf69f9c8b080ee81bu64, 0x313b8642525d5075u64, 0x22dfb19b5e5f8ee9u64], [0xd49517850ba89b7du64, 0xf19ed7658e81b85au64, 0xc6e970d501b61a8au64, 0x151393e6973a503du64]),
-    ([0x5570c083ce5628eeu64, 0xc2767fed4f084e0fu64, 0x5840ede1d53ed438u64, 0x337f4ba0c5dc95e9u64], [0x1d41bbd90102ada8u64, 0xddc4eacca6dfdeccu64, 0xc60a0fb99b0686a4u64, 0x0ee26831031967c7u64]),
-    ([0x623d46a32577733eu64, 0x4c3bea52fcee5c63u64, 0xd3aadb6799033829u64, 0x24e340908d912fb4u64], [0x81b326e80d0ac8acu64, 0xe8bb9afba4bba485u64, 0x423cc8e3ff58c5bcu64, 0x1b72e200f172b631u64]),
-    ([0xb1c6930c377f7040u64, 0xfd6c3c4c1e3774f5u64, 0xf67cf84ec44d0f01u64, 0x33b17c135543154eu64], [0xcd2940476173969bu64, 0x7d1d7aa8ce56be8du64, 0x30121dcb5685d9f5u64, 0x14acefe8570f9ebau64]),
-    ([0x2874e1098b7e9b1au64, 0xc684d7b3f8b7cf13u64, 0x7a9970f370ae50cbu64, 0x15855092e98ac1b4u64], [0x0bf2bc52ba5d70c4u64, 0x29803ae33d5586acu64, 0xab7809f405ef164eu64, 0x428015b250cf70f7u64]),
-    ([0xf72dcbd4ee24d39au64, 0xb9eaa2e037baf3bau64, 0xe2e10ddec548608fu64, 0x47d8c546aa30e6bbu64], [0xa3e650ee287c99d4u64, 0x57085c2f07b155beu64, 0x8ddd55cbee6a72d4u64, 0x32c94063a9622594u64]),
-    ([0xd5191d3dde403c3eu64, 0x71f811d4c6505005u64, 0xe84835306f7d058au64, 0x03ed091c4b9d1142u64], [0xe9fa27e2be88fc75u64, 0x63057a1104b9a09fu64, 0x8e9ef8177658aafeu64, 0x5f9b2c3acaaae133u64]),
-    ([0xe4ada96b3b020d8bu64, 0x580d4cb24f88de63u64, 0xe260e20d201f1dc5u64, 0x11c70732880a87c2u64], [0x41ac41cde2db7534u64, 0x84ad6ca0cfef420cu64, 0x666946a34f3cac01u64, 0x5492d33f98615fbau64]),
-    ([0x7b9610b1db94977eu64, 0x6ee1991fee0e510au64, 0xc1649310899759bau64, 0x205bf9e8afe7612du64], [0x4999170e3b3487afu64, 0x6e13950b17d6bd9du64, 0xaabada295e96511du64, 0x11b7f44af2b9b188u64]),
-    ([0xa8462d95102f2604u64, 0x6648da51a3ceef7du64, 0x3393332f846410f3u64, 0x3f536380b57ff9a3u64], [0x19eeebdf48344a5bu64, 0x5e87cd3082740286u64, 0x6a57405b7a642899u64, 0x55a915c8465ac036u64]),
-    ([0x4219652d417991d9u64, 0x40e18e474ae24746u64, 0x43e305da5e1bbe7bu64, 0x1cac59e2fa4a0e2du64], [0x266e8bdd9334f498u64, 0xd11177bb473cac12u64, 0x4045b416d121ffb6u64, 0x39e34fa5239028efu64]),
-    ([0x9eecc8e7ede857abu64, 0x9a404608c5b6bf70u64, 0x6979d9369a57471bu64, 0x4ff71ce95d845fd7u64], [0xcea2194167b35a6eu64, 0xa8afdcaed9243176u64, 0x1ded85620d20653eu64, 0x051141d9b8ba5578u64]),
-    ([0xf6f43227eaade450u64, 0x614a95d2c3e71de2u64, 0x388a3903558023a2u64, 0x2d432e127d4b36ffu64], [0x96542a63f5810b5cu64, 0xffb7819e365be32cu64, 0x978aaba8dffc406cu64, 0x2e5575ab297df3fcu64]),
-    ([0x66467f37ad6acacbu64, 0x6c4bd3293e184941u64, 0x3f67a56addd0a74cu64, 0x22c7808eb6c3dadfu64], [0xc30001c7fe8387c6u64, 0xbdd04d43dc1359cdu64, 0x34b0f2f66c7da021u64, 0x2485cbdac74f09aau64]),
-    ([0x5d8e6990bfa23004u64, 0xcb1bb332b4e8ce84u64, 0xd4fbf85ba3c7b295u64, 0x3c5dbc8a3a58c72au64], [0x19655c250e664a23u64, 0x62c187807b37f066u64, 0x5515d1cde8c65996u64, 0x18f36b82cd06fd1au64]),
-    ([0x39962c609da710e3u64, 0xb83127d801dca32au64, 0x738156030e0c00b4u64, 0x5d57a4b1ab2b9df1u64], [0x89ba3133b2eaf1cdu64, 0x47bcc44d318b8976u64, 0x861bf7e540da1ae1u64, 0x37d74412518b12c7u64]),
-    ([0xe2c1b6af78029270u64, 0x28db89e0a4a5765fu64, 0x7541df17373a0657u64, 0x4f531b5dc39ecc50u64], [0xc9a2d207d330b665u64, 0x9dae6eb1e97ec93du64, 0xf3dae73a6c656416u64, 0x647b682a01e48a5eu64]),
-    ([0x96b282ba55bd550fu64, 0xc66ed594862ae707u64, 0x3738c02808219767u64, 0x175581d01c4a001du64], [0x986b0cd2a361c677u64, 0x191e225934276e11u64, 0xfa946b3947999228u64, 0x2bb8701671f1ce29u64]),
-    ([0xfa6ceef222eb879au64, 0x2ef304939c366da7u64, 0x99f61f4617deac54u64, 0x06d49af90ed12f44u64], [0x3b06f8b4b5ee017cu64, 0x67f32d515931e77fu64, 0xefa1cdbbb06beaa1u64, 0x43ca61be63dcb435u64]),
-    ([0xf2d9f84be1cb409fu64, 0xc837975f1f4d9331u64, 0x788364ba04c31cffu64, 0x2d3a6d9d6d6d6548u64], [0xba80560e2c7eaf7du64, 0x812c479b787be0cbu64, 0x710a601d0e2ad0bfu64, 0x06026b8a3421be5du64]),
-    ([0x8117a83fe0dacaefu64, 0x5d1a65f594b09926u64, 0x12712448c25af059u64, 0x2b761bfda43188b5u64], [0x1754776a4bfae5b4u64, 0x00204e426e0152b3u64, 0xebbc5bb817852776u64, 0x227fea56d72c94c2u64]),
-    ([0x1bc024c840b8433eu64, 0x378086ae070bc3d1u64, 0xc40b39fcc48e6ab6u64, 0x04f1ecef36ebf26bu64], [0x5b371671663b1bbbu64, 0x73f3b7dfeda6d57cu64, 0x1d7241daecfe2763u64, 0x2c55a423828cc2afu64]),
-    ([0xf882c6f1282fa4bfu64, 0xf50c3ae2947f2aaau64, 0x4e318d58820a2e95u64, 0x32569ceaf4033df6u64], [0x691fa82d09ee6b50u64, 0xe9b89d46239cd0a3u64, 0xe0611efe1f7ab295u64, 0x6a386c59a16ba6c6u64]),
-    ([0x37d62c4d138d2f72u64, 0xa85f4e9992ab4edbu64, 0x688b7dc622905567u64, 0x37cee6bd27170f4au64], [0xb4031c70f110060eu64, 0x9c2e0c4d69e7f662u64, 0x2225d31e62174720u64, 0x4d3edaa8c87b42d5u64]),
-    ([0x9733f3a6ba60eaa6u64, 0xbd7fdf9c77487ae7u64, 0xd84f8612c8b6cc00u64, 0x476fa2fb6162ffabu64], [0xd40ddc9d9772f34eu64, 0x2498bd3898a452aau64, 0x77ac3654e28aebf2u64, 0x49c614ec68b689fdu64]),
-    ([0xc9ac443b6b1fe6b2u64, 0xc65b371e85e4ebbbu64, 0x270cb9aa18e75c64u64, 0x58e6987f518a9bb8u64], [0x70eb690c6e60df9fu64, 0xa68809d867721e52u64, 0xd9d87bafcdbd8676u64, 0x0a8e8e3dc73a09f5u64]),
-    ([0xe302dccf4853d300u64, 0xb07c5b52db2a5c58u64, 0x1875aba524351eafu64, 0x1b4018783b6e925bu64], [0x09f710daf844431du64, 0x03eb5dc3c319de01u64, 0x6640bd957a46347du64, 0x30207a226d6b6c19u64]),
-    ([0x076491b23c075728u64, 0x185bed60b7df5038u64, 0x6e35580692da3e80u64, 0x23a4db8369f69b7du64], [0xd50f3a8c51a7778fu64, 0xb09c9e9227ed3431u64, 0x5f8d8bac5e5d1deau64, 0x35e2ce0525a7f1ccu64]),
-    ([0x1340a28ffe15d019u64, 0x2c4adbcf1b2f2765u64, 0xc98da723e86bdc41u64, 0x2b92cbbd0503595eu64], [0x2b38d158178f69d4u64, 0xabb89fc6b7d269fdu64, 0x8b7bbd4622509800u64, 0x289fccbb7b2bd180u64]),
-    ([0x8a01bada5c2066edu64, 0x6e3b1f635d5fc7ccu64, 0xf4725150e337e2a8u64, 0x6fae5fcb606dc747u64], [0xa97459975fd6f01fu64, 0x8984953a526a1ad4u64, 0xca2e2c941a7e3fa0u64, 0x53f0acfb3ec2dd58u64]),
-    ([0x9062ff9a0538ec84u64, 0xa05b275c4ae15be4u64, 0x435ea8ffe19c8c85u64, 0x0496ecbcd74b6124u64], [0x1088cfa6fdd473beu64, 0x1af7dfea5c552e61u64, 0x328b9c06f02047bcu64, 0x444516e21ae80804u64]),
-    ([0xebb076877911c04bu64, 0xfe797372b5768dd9u64, 0x4f5b7a8a85b70bc7u64, 0x44789e39ebc0279cu64], [0x08d27f60a3e790c2u64, 0x24794100ef67708fu64, 0x14b55f7e65179188u64, 0x166b734a10c22416u64]),
-    ([0xb750da4cab28e208u64, 0x501dff643be95635u64, 0x8cbe2437f0b4b276u64, 0x07d0c802a94a946eu64], [0x09569efd77f6f383u64, 0x280f2d4e825eaa39u64, 0xbeff5259c71f3e83u64, 0x21a1a287abdb1b14u64]),
-    ([0xa7aaf7b62fda6cbau64, 0xb0d863493a775f14u64, 0x539c3d85087d66ffu64, 0x6f80ece0e9a4b842u64], [0x4a12af8981437111u64, 0xc72769a5370221deu64, 0xe67e9c0d5afe284bu64, 0x5f471ad5fadd191fu64]),
-    ([0x33811ea1fe0c65f4u64, 0x15c1ad4c687f28a2u64, 0xecfbede342dee7f4u64, 0x1bb466679a5d88b1u64], [0x8f25c059a33d4b7du64, 0xcb6df2cb87e0cc61u64, 0xe1495033006f129cu64, 0x433747b3a1f05ff8u64]),
-    ([0xb0ad80034eb71aa6u64, 0x50a32c41aa67e0beu64, 0x105f067219141f44u64, 0x2bcd9508a3dad316u64], [0x8462f33907a630ddu64, 0xcbae15961134fe2du64, 0x4d186c6d5a0911ffu64, 0x3a573c6a408c127bu64]),
-    ([0xe9561c176a2f777au64, 0xc03cae032393991bu64, 0x91b000235a5bfd4fu64, 0x6d64ed25272e58eeu64], [0xde2fbe1ed6ec5ff6u64, 0x6589993504559254u64, 0x58ee7671cec067a9u64, 0x529bc89f3f1a9cdcu64]),
-    ([0x6e1fa05bb77f119au64, 0x0b8d4a502532c28eu64, 0xc98b53c795188a5au64, 0x5078efb5e006cff6u64], [0xd226f8d8c0cdb8b3u64, 0x7b38fa11b2bf2b7bu64, 0xe57f976050d58184u64, 0x32b74644731bae12u64]),
-    ([0xc20b830cc925e818u64, 0x6a9026546d768efau64, 0x6dc01e733db2cbd8u64, 0x60e6b21e5543ecceu64], [0x8df275aefcf84ef0u64, 0xb418f678d3cc78c0u64, 0xf119ac5a9f86e60au64, 0x732973b0a6dcdf21u64]),
-    ([0x9b478876f4842e9fu64, 0xe4fee2872cd13305u64, 0x5dd7ecde8c306bc5u64, 0x2cbb8ac51357d535u64], [0x91407cd720bc9b9eu64, 0x56e9419a3566f839u64, 0x9d8b28cfed3c2877u64, 0x39020fb3bdd66b13u64]),
-    ([0xb891fa3f982b68c5u64, 0xa41e85011d426b52u64, 0x566009b5882952d6u64, 0x199cdaee7b3c79d6u64], [0x62b155cb6ec7c253u64, 0x51c9b9ff33ed4644u64, 0x348105d543384dd4u64, 0x6c8d8da0bcb39e97u64]),
-    ([0xef038837f0987616u64, 0x2487bbd026fa86d5u64, 0x01f7d5c2d383ee54u64, 0x1c8ea4f668519c9bu64], [0xd9c3dfb9fcc067e2u64, 0xefd43c18f16c8204u64, 0xe61fd7682f108351u64, 0x60938e2f88aa4785u64]),
-    ([0xd11b3fcd84beba66u64, 0x9903e8294f5b02f3u64, 0x7d71ada7cd3a3461u64, 0x5cf01b38f45499d5u64], [0x0c6e38e48c8c185au64, 0xe8c0fa2b27097008u64, 0x82d30a9e4b9f0a46u64, 0x3360951a24fe8ffeu64]),
-    ([0xa524c859157d01beu64, 0x46a2d66a05b0421du64, 0x7e16d937f8e8873cu64, 0x3a3643031dbf3261u64], [0xc1b1b960d8125cadu64, 0x38ca10f4e43716a0u64, 0x1ffd5db0503f1e14u64, 0x4bd4d2730d8ed942u64]),
-    ([0xc1e9f9b456588891u64, 0x6fbbd67710e3399eu64, 0xb383cca062603316u64, 0x0db886052e81456eu64], [0x3aa445f00055c964u64, 0xe927e83d3d1beba0u64, 0x92af54ba2fe50f06u64, 0x42df5c3a81f9d4a4u64]),
-    ([0x3587decdf7bdaa27u64, 0x383dd810d20c496du64, 0xdf8f48abf33cd405u64, 0x5288f2bbd8bfc515u64], [0x01962aa235209402u64, 0x66c27642023e5c5au64, 0x5c3dce9312df9870u64, 0x43181eb58972c791u64]),
-    ([0xe785f7e33fdf3618u64, 0xcb0bd5440656804fu64, 0x0487aa8f6987c373u64, 0x2b275d764886ee71u64], [0x2bea770a0ff83e9fu64, 0x121afeae5db5606eu64, 0xc72fc3ee25c60d1fu64, 0x15e05aba381eebadu64]),
-    ([0x2f69ba00cf49e3deu64, 0x8c09811b9ecc3cddu64, 0x80fb5cd21c7d361fu64, 0x3a910de23f4bcc61u64], [0x4b034fec43af89a4u64, 0x4c85feaf07fc92a8u64, 0x60d02fe65964c134u64, 0x458515b0d48d63f9u64]),
-    ([0x16236e58af56f1b8u64, 0xf70f59e12c643840u64, 0x4045ee80a2a90eecu64, 0x5d2a4d7bbcfd5250u64], [0xc37799b5ae131b82u64, 0xab792825ffca0964u64, 0x81965ece6ab6afeeu64, 0x486f01ec9c48237au64]),
-    ([0x0b288ed46081a8e5u64, 0xcd8648e1bd9e0eceu64, 0xa8674746f1368083u64, 0x18b0dc9e2560b853u64], [0xfff952ed8d4f0088u64, 0xdc7d192321227e2cu64, 0xe5d031d1cd60a94eu64, 0x62cb97793fb64f7bu64]),
-    ([0xd4bc9284b961cfbcu64, 0xd950400a4d7958bau64, 0x27307b162670856cu64, 0x2777d3b1f84b876eu64], [0x6e651532456eca69u64, 0xe66fd7190a0c8271u64, 0x165b9b4868028a52u64, 0x2cba7e2349b34e22u64]),
-    ([0xeb6f05198583cc5au64, 0x347e74e6253d9e28u64, 0x9e7820f403bec3fau64, 0x6263c3fb75a40edau64], [0x2d78cfdc568da438u64, 0xd981e6073c6154ffu64, 0xa11ed2d3e720e9feu64, 0x119ca1a518dcfd0eu64]),
-    ([0x7a355caf264d4933u64, 0x6e7cbc17fe538ac3u64, 0xffbe4b7354be35fcu64, 0x0177f579f5325dd3u64], [0x783d7760fdab7757u64, 0xb18a093c4f57b147u64, 0x178c439a3df96836u64, 0x1a9b3ba7b0ced94bu64]),
-    ([0x14d4dbb8e4c988deu64, 0x03af66b07fd2b89bu64, 0x869b997c65b4575eu64, 0x05859998cb776df0u64], [0xa37507ae7f8f0d50u64, 0x7b3b32a2f0690dbdu64, 0x6156774e6f8e59c8u64, 0x2c147ca8bfa84284u64]),
-    ([0xf3f78dff3e64eff2u64, 0x29144a26488e76c5u64, 0xa9f4d11fa88cb307u64, 0x4479d8c786d8f2a3u64], [0xfe74bb0243d03bd2u64, 0xd3d89f8fc4b4231cu64, 0x7956f70986944bedu64, 0x1afbb632975784a7u64]),
-    ([0x4ad1ec37bc4668a5u64, 0x50d79d9b5cf0b02fu64, 0x18da6541112be98fu64, 0x5f8d0b9445efe180u64], [0xba13a0973ac7d1bcu64, 0x91bf49c1969b0470u64, 0x32d8950faa714aeeu64, 0x452afc1eca0e6039u64]),
-    ([0x7f847ee47dc08d74u64, 0xbd43389604eeaf5au64, 0xd66c91d61832fc60u64, 0x551115b4607e449bu64], [0xecd4b593a753dc6du64, 0x356cfff30ae5e8eau64, 0xed9996b9173c1132u64, 0x1f9adf599d4fe439u64]),
-    ([0x587523b2c6c0bf06u64, 0x7a063a5dd416fc7eu64, 0x4791dc7d89fc483fu64, 0x6bfa1a5ea6afd85du64], [0x72c3497632476088u64, 0xc4b25920ae4f899eu64, 0x16a52fe3ced909b2u64, 0x07f9ff42d7d1ba77u64]),
-    ([0x4e936d6ec3cf97e5u64, 0x0ede1cdf30962755u64, 0xe8c982570f56a556u64, 0x49ba2c5d26f96239u64], [0x8bb479e8098c8b5eu64, 0x8900d0a981014de1u64, 0x1b2c49f654e1654cu64, 0x6a93bc72b5bb8ab3u64]),
-    ([0xa742ad16020f0c07u64, 0x98ab7346f1c5dd69u64, 0x73f1b64511c0021fu64, 0x3c9898cd60ef84bfu64], [0x3b4c3e104c791413u64, 0x6a929ed26745b9e6u64, 0x0a313ba89fbeed0fu64, 0x5cbdffd59f40308bu64]),
-    ([0xf766c4aadd94806eu64, 0x22e0cfc513638e8du64, 0x38f1bab4965c08a0u64, 0x31e567fad38d59a0u64], [0x28e594ff69476340u64, 0x0d7afa57b50d51e9u64, 0x66630ab97deb72a0u64, 0x3fa81df8c6bffb69u64]),
-    ([0xadec57b39d5976fdu64, 0x7ad5832d79ecd0b4u64, 0xe85cbd472ff7cb3bu64, 0x2d0a54978826cc78u64], [0x4cf011cdbcad54c9u64, 0x70f73b19cfb23934u64, 0x517e20188dbb612cu64, 0x0f219d111f07db4cu64]),
-    ([0xb9522b20ae99e8ebu64, 0x21af1958d3f38148u64, 0x60070f1a3e701e40u64, 0x04618fab0ad3ea6cu64], [0xb4edd45d4209db10u64, 0x0d1f7eeb903c0a06u64, 0x7d438e1b072e8451u64, 0x54c4b7e122c94c89u64]),
-    ([0xee6bc914fa35124fu64, 0x9d5b5d18505549feu64, 0x990ff506a2cd6b3du64, 0x288ea94623e44bc1u64], [0x217baee0f67c39e9u64, 0xd25252f7ad15a6b0u64, 0xfde81617696fb321u64, 0x69af0618918d8a3du64]),
-    ([0xe72cfc5c9966d09eu64, 0x5f286adfae56d25bu64, 0x0e09b1a387952009u64, 0x6e5f3ba0fa4b6f56u64], [0x8f83d17c76cb0e69u64, 0xc478978fb9b9817bu64, 0x57be9571f612846bu64, 0x55fb0dec974cc32fu64]),
-    ([0xa93031353fe3c003u64, 0x5bc9c7c25300308cu64, 0x9d683b0430a4a5c8u64, 0x0afccca26b4c5470u64], [0x3e3e9a37d5d39c68u64, 0xfe0433b9a705ab64u64, 0x120749eabb848c42u64, 0x646a2843a06ee26du64]),
-    ([0xfa1d1933f24d7bfdu64, 0xfe3e55838f11d552u64, 0x52c9a55bbbc7e37eu64, 0x51c035a06ce09197u64], [0xfb12559c822c4769u64, 0x76532a49fa3ee02bu64, 0x5b8ae2f968d7baa3u64, 0x1338900412912125u64]),
-    ([0xb01729273b501f58u64, 0x602c20261d6f63fdu64, 0x31abec1184bb529du64, 0x487cd773fa1a1bffu64], [0x0cf8830dfc0ba130u64, 0x03058d21f62e354au64, 0xbbaa7f7efc25e696u64, 0x4757185f9f7adee3u64]),
-    ([0xf0ea8a73552c030du64, 0xa350e43a80a9bd4cu64, 0xbb09fed64b951d7au64, 0x5b0c2863a6f63f83u64], [0x6b176715f29ee3c2u64, 0x07992a7350b47f94u64, 0x0804f16513f420c4u64, 0x2ced2e3eb34d91c8u64]),
-    ([0x6974f21222a37402u64, 0x250c741345a901b2u64, 0x7f386fec48ffba57u64, 0x579ce98ca62b5322u64], [0x7055961e5a798f18u64, 0x435fa650847558d6u64, 0xc8e25d11f1adb98cu64, 0x172bc6d315d4a166u64]),
-    ([0xba5726e06aa67c30u64, 0x7eca09572342def7u64, 0x52e1463e0a059e7du64, 0x6c8fb9bfed750844u64], [0xe866687f75a492f4u64, 0x8ac53f3e51e231adu64, 0x8db9dbbc40b85e1au64, 0x22bbb4381748181fu64]),
-    ([0x8288c66f4fdacbf8u64, 0x49414e3836e3157au64, 0x1e7d85b985abf826u64, 0x5b4352a8c981ab3au64], [0xf2b48a0f30b22dadu64, 0x4eda560daac04a1eu64, 0xafe20be984162f2du64, 0x67bfc4371b09f0d0u64]),
-    ([0x3f0eca84af71a186u64, 0xc29e4af408c2b941u64, 0x83e0070b973ffd92u64, 0x1b29a6a78265e488u64], [0x51b80a74d406d57bu64, 0x7f138c2098ad5fcdu64, 0xd798a07cb0df1bbeu64, 0x27d6d3b25660567au64]),
-    ([0x3c52f846b770de97u64, 0x6cab0cb85cf1ab23u64, 0x2bd9b05bc47234f0u64, 0x4bf54db8b5ea5527u64], [0x19f9fca583276b0fu64, 0xeb0fda6b8bf2e153u64, 0x24a1f3c1ff6aababu64, 0x57c9b332a79b5e35u64]),
-    ([0x84ae05866017ee40u64, 0x93903c61723623d7u64, 0x53fe5729a1399f10u64, 0x31e339f9472de76fu64], [0xcc88afc2b75ad40du64, 0x093d84584b7d149bu64, 0x97ba6691502d98b8u64, 0x3c3fc04ca74b3414u64]),
-    ([0xdbd79f51edccc9e4u64, 0x71bec3196e627413u64, 0xc273e1ba09ad8bf5u64, 0x219c55dcd186a03fu64], [0xb3008906c84b7328u64, 0x864866a3f5e079bau64, 0x6d32b7f776ac6148u64, 0x04be484c7dcc93aeu64]),
-    ([0xadf373b18b704af8u64, 0xc8483352f033ff64u64, 0xe80451eb57c7c2cau64, 0x36e751ecd7358cb0u64], [0x02e6aee9e2c0bf0cu64, 0xf99eaccb1ea3097au64, 0x2183a750651f35ceu64, 0x2b003531aa079c4cu64]),
-    ([0xd244d30cd8ea7bcdu64, 0xec2630a24cfce639u64, 0xe7c90da100aec7bcu64, 0x50d577725f8abc93u64], [0xfcb1b2673b2743a1u64, 0x744ed4ae2044516du64, 0xd56407003b281bd1u64, 0x447341defae9bc0fu64]),
-    ([0xa1afe59459ec7a6cu64, 0x69aca11799075f19u64, 0xfd33cb428c4702adu64, 0x06b01932f58837e2u64], [0xae5a5496b6d940fcu64, 0x7f6d2ce003544773u64, 0x41f44734636158ccu64, 0x290339fefef0b9d3u64]),
-    ([0x088ea3d3a61591a0u64, 0xa4526fc51e926eedu64, 0xdde09fa1fc42c5abu64, 0x3f9c5b7ee5750ad9u64], [0xcf76da7b3faa2868u64, 0x63a1fe30bdfb35e9u64, 0x7b77f61552b2f9a9u64, 0x215bb3ae9acc6f47u64]),
-    ([0x3227ea10b26bd413u64, 0x72333e46f0700ee0u64, 0x2628df85d7c3f50eu64, 0x40b706f483d66e53u64], [0x37226ab8a5663ea1u64, 0xd0a1630351cb2b14u64, 0x153c70114820dbecu64, 0x695ce61c9d9200d3u64]),
-    ([0xa414b4187bf0cefdu64, 0xbe8d0b8f5ef4f45au64, 0xc2b3fd7da0d1731au64, 0x1859c86e1684b663u64], [0x14fcd693524cb5dbu64, 0x2d445baab8cfebadu64, 0x19551d342fcf386fu64, 0x23ec40a3b70fc0c6u64]),
-    ([0x7c5dfd459fa42382u64, 0x499258044aa06058u64, 0xf6151fb652c0f571u64, 0x18b5d9cfdbfc9d66u64], [0xc1540fb76dcaf459u64, 0x4cdf2c1926e8345fu64, 0xc65735fb58facc02u64, 0x34e4a2fddc0ee9a5u64]),
-    ([0x39a9ab3291ebcb6du64, 0xa33815ad2b4e0bedu64, 0x6ce9304f5ee5ab74u64, 0x1073481a65dceabcu64], [0x0c71202559cf1742u64, 0xdec02957bed24ed5u64, 0x09bb241918eb9e67u64, 0x62b1845f75c7a235u64]),
-    ([0x109873789332cd2au64, 0xca408d95d9268abfu64, 0x1b2911f5ddb3864au64, 0x01909110624b3201u64], [0x635d617bc7186437u64, 0xbb5e530d63732958u64, 0x875a47836854952cu64, 0x48dd3f125bed480du64]),
-    ([0x0d7dee1433f8ef02u64, 0x20d37c4a9d7a6562u64, 0x9dc1d778f39327e5u64, 0x5d459cf12e56f631u64], [0xa2b4dd4e490f06c4u64, 0x2bf5af4a282c0cd8u64, 0x2719d828f0fb9010u64, 0x57e293d63e50b64fu64]),
-    ([0x3232c7280ee80ad2u64, 0x1c768cbb400f3ad3u64, 0x7ce549bde2dd6b0fu64, 0x4ddf20190a086268u64], [0xeb7837ff56f3e192u64, 0x523a31a1da6a47d8u64, 0x5c736c3e2ed107d7u64, 0x02d17ae8e90ae9fau64]),
-    ([0xd44daf086afcf9b8u64, 0x9297c25d899137c6u64, 0xa420f0afb1451cc1u64, 0x1a75d1a4bc0456ecu64], [0xac0196aea7caab09u64, 0xea988e4404661b14u64, 0x9fdd7fc6c2b24c23u64, 0x643e74d94ad658e4u64]),
-    ([0x7d19743e43af5772u64, 0xf116d26dfeb2f7eeu64, 0xecaed406f9fc1065u64, 0x1cfa4b9a3c48cf47u64], [0x60977ced26be24b3u64, 0xe08f6d0525b292ffu64, 0x3b1e064044a06222u64, 0x074ae1604f8e4343u64]),
-    ([0x59f37ab9f484d0bau64, 0x0403d461c35feb89u64, 0x6c844b24b5fb7d23u64, 0x7268402f68d64204u64], [0x64c9a31d32de51cau64, 0x47bb23b4617ddf05u64, 0x1e21d8a1fa4eb179u64, 0x67da7d23a51ef54eu64]),
-    ([0xd3873ed3a6ec005fu64, 0xa4206fbc4adcbd25u64, 0xf4382c695d1c52f9u64, 0x2a07a54afcaeb187u64], [0x66db3e3311482855u64, 0x458f3968dbc75f95u64, 0xbb0efb51a9cff7a0u64, 0x095b7e2bdf940eafu64]),
-    ([0x62e572f220fa4c0cu64, 0x44126166bddccd14u64, 0x0eb4cf7a3c92ea08u64, 0x2e7bed111ae29c7eu64], [0xaa96fb684b43c280u64, 0xe483fc7a8b89c4deu64, 0x82c5afd7f9dade9au64, 0x4fd2d54a6d8634e9u64]),
-    ([0x11c4c75b7ec9cc10u64, 0xbcef7d53496e4207u64, 0xf317e2f8e7ee39e7u64, 0x0ca9fa7c095d48bfu64], [0x24dc67ccad7dfbe5u64, 0x3195b10ef03b1aa5u64, 0xb4cb9d21af3d10c8u64, 0x146f46654eaf7c86u64]),
-    ([0x9d52919d7329c038u64, 0xa26141d745857290u64, 0x7e3d7cd77d927340u64, 0x04d1c14e478b55b0u64], [0xfe0ca24acf714d75u64, 0x84011e5547cfe3e7u64, 0x218ef60fd8e17d0eu64, 0x675ac17108000247u64]),
-    ([0xb138b29831a31ae4u64, 0xfe1803cb0ce55a68u64, 0x3bf47374fb414144u64, 0x0b9ad5b1c6859a59u64], [0x4be1bc1b04917fd4u64, 0xd52f9094424c0f31u64, 0xf544c402dafcc3d5u64, 0x38fe6bfccc570d0eu64]),
-    ([0x2e4846815bb5cb67u64, 0x70f11b943e0a5c1eu64, 0x31b10c4d0834e515u64, 0x0ab991e813585d0au64], [0xe9d1893387b4c439u64, 0x2608a922f188f606u64, 0xf6f7efcab738a26cu64, 0x4ee28b94c62e6c3fu64]),
-    ([0xc0f0045c15eb6ccau64, 0x3dc2217b84b789f1u64, 0xf9b36026bf15201eu64, 0x1bd823f0bdd367bbu64], [0x095b2d79d9861191u64, 0x75643d57e093f212u64, 0x29463ded4aaaf6f3u64, 0x6d3fcaf781c17e78u64]),
-    ([0x13b5cd83a2997e0eu64, 0xade8c6934d213fe2u64, 0x8aa91c8fa2a68184u64, 0x31d30fc72fbf9855u64], [0xd2a1243f5e2ec321u64, 0x41149297e3dcb05eu64, 0x751ab875704acf90u64, 0x2f40bde8bac17231u64]),
-    ([0x9d93099bed238a2du64, 0x938b6515f4891a07u64, 0x063a79a35d016c07u64, 0x6ecbb683645e7a9bu64], [0x3961313f7dd4bceau64, 0xf428a0bfce194f3du64, 0x2354499039d90192u64, 0x6bc4beab9a228defu64]),
-    ([0x31762634135d8773u64, 0x77a0fc06f956f6d1u64, 0x1256ead3bd82caddu64, 0x043b660adcd8596du64], [0x0dbd45c808d033c4u64, 0x25eefbf3d52fb464u64, 0x4dd376c53e3a15dbu64, 0x34455ef88f220d5cu64]),
-    ([0x32b49690815bdc10u64, 0x074b7d976c7acfe8u64, 0xfe74944fa524ee27u64, 0x677b941c73d35adbu64], [0x701f6c00332457f3u64, 0x72b3f287b0ec4b11u64, 0x07970272340d1548u64, 0x2e60e6925d4cd176u64]),
-    ([0x41240552aa7372e9u64, 0x23347d893a3ed84bu64, 0x69a05c17cf5cbd9fu64, 0x3c98bda6e04b700au64], [0xea44b43fb647fd21u64, 0xa4d3271e310c87cfu64, 0x00293b8464b2c9a7u64, 0x6f465864c7099d91u64]),
-    ([0x3bf2a9b9d4a639c1u64, 0x706d86f1951712e8u64, 0xd86c4cbda4eb5e62u64, 0x4105251c92d55cc1u64], [0x6d0daeecbaeb3212u64, 0xd535aa5ed885fcf4u64, 0xcfea2069dabb55eeu64, 0x61c041773a18e441u64]),
-    ([0x4b52bf5ff565037fu64, 0x118bc41357f8ecf3u64, 0xcb73d20cbefccdbfu64, 0x456c1602f720f2c6u64], [0xa45e74912fe2d583u64, 0x16d6a6a9e806600cu64, 0x57b4377c0d1e7039u64, 0x1bf801d5efac24aau64]),
-    ([0xf8d84c914e68b10du64, 0x04db96c6037b2ae2u64, 0x4b6f2c61beab61e4u64, 0x4b26d155db6e7882u64], [0x9fd63dc277c43a98u64, 0x49d6c86a5f56c094u64, 0x31b5b71f1d9613f5u64, 0x1648ac424d28d2b6u64]),
-    ([0x5d38cedd8d0fdb2cu64, 0xb9917e3da2b9bb31u64, 0x367c1710447002b2u64, 0x26d721e71d042942u64], [0xef8be53fcff25730u64, 0xd5e4cb43634f8410u64, 0x7205988ff2a300c1u64, 0x125e05e18ff4e35eu64]),
-    ([0xe67a1afdf2e1d708u64, 0xfa3db510e55d16a3u64, 0xe91596d67292f596u64, 0x16f9af7b83040141u64], [0x53b4bc74bc1cb566u64, 0x29ea6cf118c0aecdu64, 0x1d1c8d7cf073ab64u64, 0x06671cb265939732u64]),
-    ([0x6c35d2e338026d9eu64, 0xa1812a31c427febdu64, 0xcb0880ad6c1e36a6u64, 0x3aba40a4e5d8b982u64], [0x7b3af70654dd69a5u64, 0x6af18f847a993527u64, 0x7a18d7cf235aad20u64, 0x09288eda71ac880au64]),
-    ([0xe675fcb3e18fa3f3u64, 0x1746869d4d10052bu64, 0x52f66ec2c60b147cu64, 0x4e9195e215d7a7a4u64], [0x2df77300cd989085u64, 0xb28dcf18485dc88cu64, 0xe159dde211dcd7adu64, 0x66525090c19ae185u64]),
-    ([0xfd5caa9f3a11da0au64, 0x430ddd0120cf62cfu64, 0xa90e241ecc89caa2u64, 0x2c9c603682fe9fd4u64], [0xec89dafb9d2e0d03u64, 0x2990ee3a0a5a8176u64, 0xcb4cac84053500abu64, 0x6d6a127ba6be10a1u64]),
-    ([0xe7b70dbad015d339u64, 0x0a7fe3b9ce8d4477u64, 0x5135022b211d95bdu64, 0x473d391ed371c0f5u64], [0xe900a6a91aa19fbbu64, 0xf0163a6ef849c81du64, 0x69be293f5e879453u64, 0x0b5040211148637bu64]),
-    ([0x814ece7435bcf923u64, 0xe4f67c97f1fdd432u64, 0xdc3e971128e27174u64, 0x2f9c637daaeb4a5bu64], [0xe5e8acd6f46b452eu64, 0xf771541cddcbbb9du64, 0x7262d49e6162d8f7u64, 0x57739bd94166ae69u64]),
-    ([0x2433b50af8bb7619u64, 0x6d7d0aaebb6e8b5cu64, 0x2c164da00c6f5f07u64, 0x581ec2eca698be86u64], [0x4b29f76c7dd9e744u64, 0x06743cbad44d08d9u64, 0x0555ac0b39531fcbu64, 0x572450b578624ae4u64]),
-    ([0xdcba1da9c0ea4d84u64, 0x586a261391ef2b05u64, 0xab4614b528358304u64, 0x19076db34aa2ef6bu64], [0xfcb67e62ba9cf618u64, 0x75ba16a7d0ce64c7u64, 0x60d550365049fd8fu64, 0x0019ebc75feb3abfu64]),
-    ([0x1dad095b97e1f99cu64, 0x58d479a309a7768du64, 0xb1261c30d1e2c9a2u64, 0x0c365f2b8f504ab5u64], [0xeacb4f3e5f267d9du64, 0xedf0c082184c3985u64, 0xd27c1cb0b1b39a41u64, 0x6d35c7ca9007d5fcu64]),
-    ([0x7afa35ae1fc29be8u64, 0x0955e876c3e0a514u64, 0xfda9e3cc5bacdeabu64, 0x30ad0a9434ce3e69u64], [0x3647c1d597f3b463u64, 0x45cb34c6b6292929u64, 0xe1d66ac004a4aa1bu64, 0x6ff6b18c67117d85u64]),
-    ([0x93050b36e83ec01bu64, 0x22df56fce8bd22dau64, 0xdb43e6645492ac86u64, 0x7328993461faf18fu64], [0xf08e8e12483c6eadu64, 0xde89b372f8bc06fau64, 0x145240ec4edcef38u64, 0x54b0fe7b7fdc24d5u64]),
-    ([0x72890840b00420f5u64, 0xae63d364c9ba00d2u64, 0xa3fb843e6f883875u64, 0x160229c2ab9eba06u64], [0x48f51add4e595258u64, 0x6d9fec47df14a0d5u64, 0x92ec080af24ea417u64, 0x5c903588e8cefbadu64]),
-    ([0x5796130c33f27af7u64, 0xe9a8119ed34f6819u64, 0xc4a4a9bfd42acfd1u64, 0x3288cfc06aac46bau64], [0x2c832a1d5e78a736u64, 0x90872039c74af858u64, 0x5aa5d9dc4e41abe5u64, 0x28866c7c1ecfdd9cu64]),
-    ([0x0af32e561b3d24e8u64, 0xc1e78a85938af37eu64, 0xabacc0ad5a0f8108u64, 0x380e8ff9c4252ab7u64], [0xeb3c0243414fa84bu64, 0xeb8611ff2424f320u64, 0x5a36e1865656f1aau64, 0x02b70ac40fdbcccfu64]),
-    ([0xfffeffff00000001u64, 0x67baa40089fb5bfeu64, 0xa5e80b39939ed334u64, 0x73eda753299d7d47u64], [0xcb31279ae291794bu64, 0x3f5c7b0fc815de40u64, 0x2106db0f4dd51be6u64, 0x4b286e24446f1bd9u64]),
-    ([0xe006a537b0b22aa3u64, 0x9db978246a8a9751u64, 0x06393249e58f2e6du64, 0x2324b23b00803f23u64], [0xed7e59fbc52570b8u64, 0x5ae4ccf63d4cbbf3u64, 0xc97a9650dd9c7666u64, 0x5700a4fae4e5fd48u64]),
-    ([0xd5a18486a7be5e27u64, 0x691d256641a44b4fu64, 0x9db00efaaefd61d0u64, 0x0d5ff0d85715c238u64], [0xa7f51c6e2e150bf9u64, 0x975d437b1422070bu64, 0x0b9276f984015803u64, 0x58bd7aae60919c69u64]),
-    ([0xbfefc24704198a6bu64, 0x7aff24fa0bcfa8e4u64, 0x937ae0819ff6472du64, 0x173a313aaab6b721u64], [0x474c593750ab9209u64, 0x571a5429f5d7321du64, 0xfcb1dac33311af51u64, 0x68a68b9b0bb43837u64]),
-    ([0x9d47ae1b2d5cb20cu64, 0xef6d471fd7d619f9u64, 0xd2ed0c39d81ba4edu64, 0x0db09cb1d4e5cd90u64], [0xf3510008e133cec2u64, 0x66fc78f051e84430u64, 0xff23c322a1b56e6fu64, 0x516661628a4969afu64]),
-    ([0xa22f45c8ff3de158u64, 0x1764ec99029da116u64, 0x8cacb9af89cb58ecu64, 0x2fc2dbac28c819bau64], [0xa065c5b9886180f6u64, 0xa8c57a27a9269a87u64, 0x18377f23daca162du64, 0x59f62e682355e760u64]),
-    ([0x7ce50ec0e597b1beu64, 0xb9305485b09dd0d7u64, 0x188f4c46937150a9u64, 0x4d83bc95e93142abu64], [0x36a0c14e83356057u64, 0x32ee23fbc4f34490u64, 0x1968bf2914a6b3c6u64, 0x63a38387fb7b8bd0u64]),
-    ([0x3496d2f9d70bbbedu64, 0x57ce77b6b91a5bffu64, 0xf0b6fbbe96fe0b5fu64, 0x6e22fa867056ef0bu64], [0xa3df09a110fb1b3du64, 0x236b1e748f731679u64, 0xe9bf95b84f1666ffu64, 0x64f2c75d1c8defdcu64]),
-    ([0x5f6d85cc838aefbeu64, 0x9b8d00b4a1b1e1cdu64, 0xf1f9df7fc191d3acu64, 0x431b0f15498c6f24u64], [0x00fe28df620076e0u64, 0xa8ca9183f7abd226u64, 0x57ec3e50f15188cfu64, 0x2e7411d820d933f4u64]),
-    ([0x6c4d75133cfc0f8du64, 0x6926cc6375bfcdf3u64, 0xc332b08fb7b9293bu64, 0x4f11de42ff094ff6u64], [0xf41a499ee04b3a08u64, 0x10ad98bd66d2e69bu64, 0xc69c4a529ccaa160u64, 0x4353b726a72160d6u64]),
-    ([0x6838fa701f0cdbfeu64, 0xf3961fc06f917f45u64, 0xa6b777d6a373de1du64, 0x077391826d1ea37cu64], [0x0fad05edc9b6072cu64, 0xff0a56606f93da75u64, 0x6e40f50a595a131au64, 0x372254d0871084f5u64]),
-    ([0x3cf4c3c8b82268deu64, 0x3fc0e4c27b09624cu64, 0xbb0dbb32d6f021f2u64, 0x487a24ae8c3220d3u64], [0x4dfd6d3e14816be5u64, 0xc39919d82f9979a7u64, 0x9a007388d4fe7ba9u64, 0x586d32485236cbfeu64]),
-    ([0x38159f7147329ad5u64, 0x136eaf3c40be7b38u64, 0x3e013c718435ef54u64, 0x55f82aac2d0686c8u64], [0x49b9df6d0c64d8dau64, 0x418b757c1d506947u64, 0x723abfdaf67f391cu64, 0x54f291e4689f773eu64]),
-    ([0xa22443b06499ea49u64, 0xbc214e0c0015ea14u64, 0x9f6e1923a2a4b4dcu64, 0x7391a33d50975d0au64], [0x2ec0eb15c9d82ff2u64, 0x209324260e80a624u64, 0xc4ae20dde0f1cbc8u64, 0x320ba2236b32379fu64]),
-    ([0xa268f1f3f9991f29u64, 0x48eb78f9f7d520bbu64, 0x7eec9c9f7a1d45bfu64, 0x18eaa0cff984144au64], [0x2408407e52ef976fu64, 0x47e3396e7f19efe7u64, 0xef5c2a8f0c97968eu64, 0x136ad1067758d17bu64]),
-    ([0xb02181d185aa602eu64, 0x6ebc49eed496ecddu64, 0x32fa560b3c056fedu64, 0x6bb0379c4b7bd1beu64], [0x2090de69050eef99u64, 0x4bbfe7e1637c4805u64, 0xeb071b6088557187u64, 0x261cdbe08c0d733bu64]),
-    ([0x6220b9443bf5371du64, 0x47cb9993a1e75275u64, 0xa1cac574d6e7aa09u64, 0x4b0277449923ce3cu64], [0x927e7c370f7a39a0u64, 0x79d500394bd4c559u64, 0x1850408bf3c87771u64, 0x27d4c1f6aa5e55afu64]),
-    ([0xf8c8655cbd410ec7u64, 0xad62920b1726c6fbu64, 0x8eac7b3e1e56a597u64, 0x37142205bb523376u64], [0x06b182d94be40c24u64, 0xb4a14137a5ee8f2bu64, 0x1c8c99cfede475a2u64, 0x1f4f3c797a3a48c5u64]),
-    ([0xdce70e087bbfc955u64, 0x556fbe9550966ceeu64, 0xd2f740262a46581bu64, 0x0356a59c190e10e0u64], [0x9d909b2679473345u64, 0x96727ca5a4d6cb54u64, 0x53ec0fb455cfcf34u64, 0x0e0f30ab7fe41057u64]),
-    ([0x2cbed7f0bf090098u64, 0x7c4c7bf3f8b147beu64, 0x7f64e8055e8cdb1du64, 0x3d965e1556dbfbb2u64], [0x5cde195a0683100au64, 0xab693285b07a1923u64, 0x4c0a560d7e81c2ceu64, 0x43cd6c91c738bffdu64]),
-    ([0xd103024fb1d9a54au64, 0x890dd8d1bcb91626u64, 0x98e526154ae4ed79u64, 0x443af2c9a0e3a199u64], [0x966522ea7e2ea8feu64, 0xefe001432337dd53u64, 0x0f10a31f54a2a992u64, 0x54eba8d302a93ea7u64]),
-    ([0x73025a78d6a157b6u64, 0xcff4ae259cce48fcu64, 0x41ee514c8d8fd2a6u64, 0x364d2a8493910d20u64], [0x8e2ee3535bd5603eu64, 0x2f77443ac5b10fb4u64, 0x1203c81053b65dceu64, 0x260ee58b1297d765u64]),
-    ([0x69c563a89174294cu64, 0xf47715244985e129u64, 0x0cc5580a897bad41u64, 0x35f0146a268b13c2u64], [0x93a605a738f4ec3bu64, 0x8ee8456f449ad3b6u64, 0x437d5e714bef36d9u64, 0x18bb7b33b2150442u64]),
-    ([0xeed0691c4f064c24u64, 0x8ae294866778dba7u64, 0x5d0980355d73eb43u64, 0x59854e726428a854u64], [0x22c55a2c38be4680u64, 0x0c59c8a42ba99e2bu64, 0xbd65c596e8fe5793u64, 0x45417668a1dcafb8u64]),
-    ([0xbba97efd27ba08e2u64, 0xb67294c2bb71bbb2u64, 0xc4ae3115d25772a7u64, 0x20d5925014876109u64], [0x52f5c05836e7e800u64, 0x32366a3505339548u64, 0xdec24f2a9e91971eu64, 0x2ba41f85bbcf3ee4u64]),
-    ([0xcaa9050b145fc8ffu64, 0x4319167dd03b0082u64, 0x7de6598d54383a77u64, 0x69c529b3b56e181bu64], [0xab14baf758e5c4f2u64, 0x2b1c09863162a38eu64, 0x1433b36520bfc035u64, 0x548b3880deb770a5u64]),
-    ([0xc32c2d1d3fc57bb5u64, 0x6215b63f114c71efu64, 0x039beacf8acebb10u64, 0x6195e009cb95e995u64], [0xecfccc985452a24du64, 0x75530ac3664a580cu64, 0x7492980c2403a79eu64, 0x55b43ee3bb2a465fu64]),
-    ([0x470f4ebc84a1bd09u64, 0xe518d4d95a863f36u64, 0x5dcf1a4388a868b3u64, 0x591e925d1a9cc578u64], [0x9969c0ab15c0a75du64, 0x5eb8ddb627368b20u64, 0x2e132f25d535ef0du64, 0x311efa83866b8e45u64]),
-    ([0xdbbc76a74c1da9e8u64, 0xa1af7f13d0a74f74u64, 0x3b174240d6d0c662u64, 0x598798fa4cbff060u64], [0x35550a3eabc75871u64, 0x109d6ed940a9a443u64, 0x76970b3fc88385d5u64, 0x20c85a4e662b121du64]),
-    ([0xde112abd7a8e53ceu64, 0xb38d3d79f6e39d2bu64, 0xb65c067e38b58ae4u64, 0x65b01479cf4e8c2au64], [0x0201b980034284e2u64, 0x82b993c62e568218u64, 0xf61bf7e4dbf733a4u64, 0x68ca51cfdee6e4ecu64]),
-    ([0x1836ad1a4285082du64, 0x3e05c9aa3ad94142u64, 0x0baf4bd9cc81ff24u64, 0x156864bedefa515au64], [0xcbe9b2c30f303a37u64, 0xec86fffe1eaf4e7cu64, 0xe5b97109c3b59415u64, 0x204d92e1564c8345u64]),
-    ([0x412c65f5e605243eu64, 0x8754551ac51be1b7u64, 0x075f004f9b4bf643u64, 0x43075d17e1be209eu64], [0x9d3e2918c0d0b413u64, 0xaba58ded7d52f1d9u64, 0x7dab749bce10aa3bu64, 0x41a93d0afb558799u64]),
-    ([0xc13af36ece5c935eu64, 0x2d0124a02ec3ede2u64, 0x2d34ccafd6d0ce6eu64, 0x0df6e1cface780a6u64], [0x105f5c8ec4465623u64, 0x8c283d1da67eddc5u64, 0x14d31cb3f12cf2eau64, 0x37e620582969045cu64]),
-    ([0xc76b6f5d6b8f1730u64, 0xd79211824fd34cc6u64, 0xe8ce8c8ee3d05f4bu64, 0x73814123de61856au64], [0x0828864f541f927bu64, 0x0c550f082ac545c0u64, 0x8d1ac6ded32031f3u64, 0x03d29f914a9b455fu64]),
-    ([0x86642e87c3c5520fu64, 0xdbfe867dc61896f3u64, 0x072d1252b0ee8768u64, 0x2e8ed064608e5f2fu64], [0x50f7b1f552c98d5cu64, 0xbefe5e9eb084f779u64, 0xc9949b0988d702aau64, 0x62499c690fb2d594u64]),
-    ([0xea0b1c34224fbd61u64, 0x55167cabcf48aae9u64, 0x1ae9d1320a2c5bebu64, 0x1d24b6b181ffb7e1u64], [0x36e57675616fc053u64, 0x993dc808d3545834u64, 0x7c74eea1fa553a8du64, 0x46135424e23de877u64]),
-    ([0x8acad28c5f36ac67u64, 0xb33dd930616645bbu64, 0x3645eba92f93fcb1u64, 0x314ac138ef72e20eu64], [0x3ed7bdac29fb2803u64, 0xd683992478c6a364u64, 0x52e8c8645b34b66du64, 0x696f616a4851b4a6u64]),
-    ([0x7aa035dd3fba17b8u64, 0xdc0250f48d469c3bu64, 0x66d4ff4fe4de8341u64, 0x501a82b51da12ff6u64], [0x1facfe7a8c4727bau64, 0xed00376e2b1e73cau64, 0x7a45fbb291ce9caeu64, 0x36517788386c0c97u64]),
-    ([0x4f5bf1a980b4b953u64, 0x6f2d7ed96060d206u64, 0x2b58548adad88628u64, 0x67deef839c67e73bu64], [0x25cc758a0f306d39u64, 0xf9f0c12e58c2fa43u64, 0xbd5db25d448f1844u64, 0x50fcf4104e12f488u64]),
-    ([0x2fa970fa47b4d601u64, 0x89987808ecddda05u64, 0xddf69a51b1918693u64, 0x017f73f9895e0b1du64], [0xcd675c7ee6f75a29u64, 0x439c832263efec1cu64, 0xfba34eff2511c0f5u64, 0x43520c57232d2381u64]),
-    ([0x5b0e07816f77b3a7u64, 0xd482d714b1cb5d44u64, 0x8aeed579d92544a1u64, 0x519947e7840143b3u64], [0x642ef478f23a7597u64, 0x1de858d44759172fu64, 0xa439210765e0d68eu64, 0x5c7cc0efdb91f0bau64]),
-    ([0x69843829431e57fdu64, 0xbceb1d39384b6801u64, 0x64a8313119df103au64, 0x0379c9fcdddf2032u64], [0x1c4f6bbc05dd745du64, 0x9427a59eabd7c37bu64, 0xea4ec16e7dfcbb55u64, 0x1756e0cc5ea68e27u64]),
-    ([0x6810b3b69d253b76u64, 0x285afb20b758d4c5u64, 0xbdcc28003263f82du64, 0x736a48761d47bb2du64], [0xd3a66a28eb1fabbdu64, 0x285071c9d1a4989du64, 0x3e5ba5495be160e6u64, 0x57932f2db6b07761u64]),
-    ([0x96dac2ba21e1b301u64, 0x42b637bb3a3b9a85u64, 0xdeff558cd1ceec3fu64, 0x6a7d8e70d46598edu64], [0x1ae8c2e3cb7e71c4u64, 0xf23d46a4ad67bf5eu64, 0x0f60be1618868937u64, 0x12d66dd71ad401bau64]),
-    ([0x898a4a40149eac37u64, 0x1cf812a0fada0ad9u64, 0x49212bd8afe3031au64, 0x2fe82f025b748d09u64], [0x216cb2805b2be42fu64, 0x7c545f6b623d1e0fu64, 0x41c8eb5a365be2e9u64, 0x22577bea2d3959beu64]),
-    ([0x68acb5abcae514e0u64, 0xd22d459a176b8499u64, 0xde731b11f75f47a2u64, 0x57d1e49b48fef478u64], [0x34b23af2b63e84adu64, 0x45b1d67f16d286e0u64, 0x444a7b0a20bb960du64, 0x17dbeb126f810bb3u64]),
-    ([0x07da2d48f5cd0787u64, 0x9a201a5821e639dau64, 0x53122fd2b0ffde04u64, 0x55f95c66a3e483f1u64], [0x2e833b61ea76d461u64, 0x0bec4b19777a5b00u64, 0xbf8ba6a44e023943u64, 0x36dad7ba0d0ea830u64]),
-    ([0xd1c9abe0a3bb223du64, 0x080630f155a7283eu64, 0x7d3d3093d21097e6u64, 0x0f50b0488d87edd8u64], [0x18dfbad269f611ffu64, 0xf07fab8427cb9e36u64, 0xba7552652766743au64, 0x51b2f368549870edu64]),
-    ([0x0bb1eb8b66cd55ceu64, 0xf9d6586048a1b787u64, 0xd9620388e8f73902u64, 0x3c1a56c914ef83eeu64], [0x9313892161d6aed1u64, 0x4006a1f13f193815u64, 0xd9584eae842b7ffeu64, 0x6d4d3354af463cb1u64]),
-    ([0xea36d1e453fdd087u64, 0xf6fa6503c4d05ba1u64, 0x2cf83a952b74d32au64, 0x3b10a362bd6b6a04u64], [0x19dd863142710a51u64, 0x651ffc6e6186ad4bu64, 0x11650dbe678d30b9u64, 0x6b33c38223c46b97u64]),
-    ([0x01583cca695cba0fu64, 0xc1b07fa0fdd65a6bu64, 0x31e2fa98dc4b1e43u64, 0x1a4aec810deb108au64], [0xcebdd1706c691af2u64, 0xd6d0ba2f04e27a97u64, 0x35ff88ba74a4ee67u64, 0x0691b4524ec9dbd2u64]),
-    ([0x3215dfcaf179a125u64, 0xc976b4372a3289a3u64, 0x1783ec1b207387edu64, 0x38324a5b3f291c61u64], [0xff4123a3b29236c7u64, 0x73454631db0f3434u64, 0x7bef2130d2dd00b7u64, 0x00ae41ae9ad549feu64]),
-    ([0xc4d432320e1384ebu64, 0xe45e4d6e9cbd5e02u64, 0x78550062e390c312u64, 0x1e0b08d50d96eba4u64], [0xae7b44e7354a3e0du64, 0xe10a12f35f6140acu64, 0x0cbff357d7cfa897u64, 0x2d0daa0ed61b9edau64]),
-    ([0xb52b9cfaf405946bu64, 0xdd4565eab0ef4b24u64, 0x3c595e90e2afa653u64, 0x28db1d79893ac2b8u64], [0x07fdc6b72739d1c7u64, 0xd632e8e2e3fed32bu64, 0x6377c1acdf90697du64, 0x3014d516676c9ad3u64]),
-    ([0x13c76b01443a1a6au64, 0x494a14f4b69dcc7fu64, 0x4e16fbfa13619ed8u64, 0x455c3f2176d7176bu64], [0x1ef4dce370b79b27u64, 0x7385a4888086df34u64, 0x5cacab6ebc93ca0eu64, 0x6111ea5cd7bd7d7fu64]),
-    ([0x691832e8a41ee752u64, 0xce8a993fc6880486u64, 0xeb3b5bfee47aaa41u64, 0x07ed5621a1853029u64], [0x79d9a35410c7f61bu64, 0x1ae9eaa214dae48cu64, 0x1b12a007a2745df6u64, 0x53d63bc7a4493d2au64]),
-    ([0x4f76a9059bae8f9fu64, 0x29f5448404284373u64, 0x29c34d9781b10bdfu64, 0x60977bd601e8bb0au64], [0x36a42d74200745fdu64, 0xa0a7bfd496eaa244u64, 0xdd98b1229775aa4au64, 0x6a7b65563c0d1b08u64]),
-    ([0x4264d6404a2733acu64, 0x3eaf9e2530eab9cfu64, 0xe77632a0defe39c3u64, 0x679527f12d3a9a9eu64], [0xac5e802a75d568aeu64, 0xa4c545b6380fa21au64, 0xf8f1acfa8e71d950u64, 0x0e57ce7ecb344e55u64]),
-    ([0x4c72f9b057bbbedau64, 0x02ba514cd0bf3bc0u64, 0x3102bf19312d4f8au64, 0x18fead4f7c60faf3u64], [0x8fde44f3bbacd62bu64, 0x8b620cb21d984ac6u64, 0xbe986f12f213cd0au64, 0x380c3d3305cd8e22u64]),
-    ([0x998944eb24e3022au64, 0x9476d9747869e6fbu64, 0x7aeba5e346b9d70eu64, 0x2f0df60ba4afea24u64], [0x245c545f848dd7aeu64, 0x77c5c068ca32d178u64, 0x1dae21c9a8b3f358u64, 0x3fa43ca2a0232c53u64]),
-    ([0x0ffc32908c1049f0u64, 0xa8fdab76f48019b8u64, 0x2674c1244e803b82u64, 0x5f809775428ee41bu64], [0xf24dc8f6a6afefb2u64, 0x561bc4d30cb4e6c2u64, 0xa7b44e6521f31ee3u64, 0x439b34cae34401b3u64]),
-    ([0x0ab1196d1f9bc88au64, 0x7cb2dd5fcf5a47ddu64, 0x0f70fe1ee409d50du64, 0x525957bc56f0e20fu64], [0x4c4186d4dfdf675fu64, 0x89496786cfa7dd26u64, 0x5ff02f584f024130u64, 0x37cbdcfed18ee213u64]),
-    ([0x30b5dbc87cc84197u64, 0x97e01bf0fda622efu64, 0xe66fd8bc18071b1fu64, 0x430beafa5e3ec783u64], [0xc3fddb4af5dcd65fu64, 0x2d0df4fd86809772u64, 0xd92eb067ad93c040u64, 0x0536f19d443bb081u64]),
-    ([0xb0dc17816cb0e06bu64, 0x8d0c0f30d36b082eu64, 0xcbefd73174b145abu64, 0x0997d4b929a9031fu64], [0x83071ad3932cf7fau64, 0x28f427fa9907182fu64, 0xa1399a4ae7c8674du64, 0x4cb8f33c0b6d101eu64]),
-    ([0x6a20b4c8fbee018eu64, 0x34c8bd90143c7a43u64, 0xc4a72e43a8f20dbbu64, 0x24c14de4b45f2d7bu64], [0x1b04b2e601a4a627u64, 0x10410f9248d32767u64, 0xc09c7c2c57152e06u64, 0x02897feb03bd8d31u64]),
-    ([0xa2195045a974862au64, 0x56acb2cf03455d20u64, 0x303d37414fe2284fu64, 0x5f4e51f5fedf54eau64], [0x9eb02ee7558d9b34u64, 0xda8c1b59a96bbf99u64, 0xdcd78071a6fc8c84u64, 0x1a90b509dce1a4cbu64]),
-    ([0x8c8d1d91a8df6b62u64, 0xa922d0dee6c4fa9cu64, 0xd4ff231a7b85a381u64, 0x6f497aacf872a63au64], [0x37211f860fde838fu64, 0x3320a0a25d836b3au64, 0x7ab8fcd2dea1cb88u64, 0x727126493154ac41u64]),
-    ([0xeb8cf558ae23eb3bu64, 0xbff4f2863d6f00a5u64, 0xb539528902a8e6d4u64, 0x56239d2ac6d6cc9du64], [0x1a296f06984676bdu64, 0xdd93a7b341e9f9c3u64, 0x8c603681ef4e8c5cu64, 0x6354d1be4fbf56b0u64]),
-    ([0x6678ce10f387bc87u64, 0x1b964479e66a29b2u64, 0xe14f851900aff799u64, 0x284a504e08859b66u64], [0xca275762c4f49553u64, 0x04beeccf8c327be3u64, 0x5af33219dae97b1bu64, 0x6c46175eda14a57du64]),
-    ([0xa7624e7e7d9cdb91u64, 0xa33113167ed5e21du64, 0x4230f56b2d54e5aau64, 0x681699ee54290f33u64], [0x128431b20f8b5a86u64, 0xebb033f6aecfb0c3u64, 0x92f780c127abf263u64, 0x4a3ff7ce5d118b76u64]),
-    ([0x0bdbcbe1522354a4u64, 0xab515de81a8f54d1u64, 0xe134e3c781697fafu64, 0x294c99bc6abc5e8cu64], [0x436da35414e30b92u64, 0x6fb591a12c3528f6u64, 0x0334b4f34fa7e399u64, 0x626e5ffa693e146cu64]),
-    ([0x4eca385586eb2c8au64, 0xa299e27a2081799bu64, 0xd3709d3de66e349du64, 0x42176e1e917d3c28u64], [0xaf6faabe12d36362u64, 0x06762c2b78022acfu64, 0x42dc246ce7baeed0u64, 0x29cbc1abe57e92ecu64]),
-    ([0xbfff4f4b898366d9u64, 0x3a0cc5569e6281d1u64, 0xa7a111083c39ad63u64, 0x3e279c52d10eaa78u64], [0x45bc6aaf9cf75eefu64, 0x83340b355788576au64, 0x7bdb6e4a3631d736u64, 0x296988ff4f34822cu64]),
-    ([0x3ed6b1470d0d0994u64, 0x8958269e818e3fc3u64, 0xd853c0570cdbfc0bu64, 0x4b669febd6e43d38u64], [0x9986d22aaa83a594u64, 0xecfb32799bd8cf86u64, 0x9b79d403cbfc1f40u64, 0x5901b80f89792924u64]),
-    ([0x2f4286ba7a4e32a8u64, 0x75a058e2c2a8dc2bu64, 0xc82a05812d8bb32cu64, 0x595fa84ec3c5e8eau64], [0x509aedbcd6194140u64, 0x461d6811b7d33adfu64, 0x386b5ba8deb501afu64, 0x0a5b0ddaa5639358u64]),
-    ([0xae9be2c9a128b7a8u64, 0x52e92a68efce58edu64, 0x1aeba02b847008d2u64, 0x17b9ff24306585a0u64], [0x5e4ad0ccf036d321u64, 0x9990f193beb61225u64, 0xbc3ce3f7de373e00u64, 0x08d6e6c2bf942d16u64]),
-    ([0x72032b6dd9980aa2u64, 0x6bbba87982f8a3abu64, 0x2297d49fc28271cfu64, 0x0f0a7eba81db23a0u64], [0xfa881a8f82bbc32eu64, 0xa9ae3af7c713172au64, 0xd35c85614b43122du64, 0x224978e7b3a0ba86u64]),
-    ([0x8766a6b4eea4bd15u64, 0xaafaa20e0cddd4bau64, 0xa106358b0ea4c998u64, 0x45a1021925886da6u64], [0x97a4aeed20e17506u64, 0x16d342d16b21ea85u64, 0x7d591f16bbf65e51u64, 0x4917e7206eab378du64]),
-    ([0x9b6c9285b13538adu64, 0x086f2857c36a7e1du64, 0x9b5745a08aa3699du64, 0x5ac018566cfd81a4u64], [0x54149ebf9cfaead1u64, 0xece219aea961376eu64, 0x3624398ef5e30584u64, 0x1c1a3a3c2b07ec64u64]),
-    ([0x967d351412b79c04u64, 0x4e134e66da5b38f8u64, 0x941d33b1f8e336b5u64, 0x6fe85553ee0a4e4au64], [0xf29cfc177cfacc37u64, 0x7be7673ee1ee75b2u64, 0xb624d229966e87ccu64, 0x5594ccd4f80ee131u64]),
-    ([0x2d03a19553a24b82u64, 0xb293335cea2da31au64, 0x09febaa04d13f22bu64, 0x65a566a6d1a50deau64], [0x1cfea438043c5d39u64, 0x31abdb0989ce2ebbu64, 0xb88a2e092e781cacu64, 0x2440ab0b7e09f9a5u64]),
-    ([0x0558a1daf69bb76bu64, 0x30ace76ff7973928u64, 0x498040eea16a5976u64, 0x2e219a1d28915435u64], [0xf5b4a387dc0dd589u64, 0x88b4e9bd7672d7c6u64, 0x796e9491255ba855u64, 0x5e3bbdf342c81010u64]),
-    ([0x716d5f7d8adc97eau64, 0x2593bae27d7c8966u64, 0x7bc9bc70a777b79au64, 0x64741b0361161a43u64], [0xb9e757423b3cdaf0u64, 0xd474dfed732d1719u64, 0xf95561ec5d793904u64, 0x0f964dcf42d5883fu64]),
-    ([0x19bf210063c2e679u64, 0xe1be2f06e8c58c2au64, 0xce000121051220f6u64, 0x480847b8fcec34f0u64], [0x1395aebd615ab595u64, 0x64b4eea09f46d3ceu64, 0x10225e02780b2c5eu64, 0x2b413a0129373d72u64]),
-    ([0xd3545212d01cdd49u64, 0xde911f0feabd369eu64, 0x0092a559ef66cd15u64, 0x4557ccf9865fb156u64], [0x49cef423275debf0u64, 0x234876b06d92e29eu64, 0xbf0442963f492e92u64, 0x062e8dcacf210025u64]),
-    ([0x2a75a50ad2fef008u64, 0xd9cdcd52a8f26af5u64, 0xf46302ada65165e7u64, 0x44c5f6bad0a94058u64], [0x239724fee9a12ff6u64, 0x3b62f523b56d65cfu64, 0x011cfea52a2db1a8u64, 0x1088c552ecec6760u64]),
-    ([0x04caf2400fd5ee93u64, 0x376c95434baa21c0u64, 0x3cc485d9d1f4893bu64, 0x5ae601e4a8a5521au64], [0xfa3367d9e45a7419u64, 0x9a2f5e488be76401u64, 0xc582c3a0d383261au64, 0x4bd95b3652b358a3u64]),
-    ([0x914a23cd6857106fu64, 0x1e500508fe3d0e6au64, 0x346770ba87cbc4adu64, 0x5fb4136a2fdf95efu64], [0x5ec18fb114704699u64, 0x8a742be7db7af7f4u64, 0x2904912f05610ce3u64, 0x460c87ee6ebd35b6u64]),
-    ([0xa8e45d0230d79fe6u64, 0x3d4dee80538980ecu64, 0xe1f7cc23d4a0a0fau64, 0x5a56acc5d6a40c8du64], [0xa7e7f648b42b059fu64, 0xf72c506359697c67u64, 0xcbaf4fa39242973eu64, 0x6e27a4c2f404a17bu64]),
-    ([0xa6958a4de8220461u64, 0x47b5bb56ff3185f6u64, 0x45dcaef866cec414u64, 0x524d8adb129134d5u64], [0xef92d7d0ad9ebbc4u64, 0x56b1be470570e243u64, 0xa643be6f11cbea4bu64, 0x1f26aa5a31f0c45fu64]),
-    ([0xf65070e6d99ba93cu64, 0x73680114a074f1bau64, 0xdc895e94a770ff33u64, 0x2096fb30ee127ca0u64], [0xd4e5751e5918aa35u64, 0x1ee3ffbd5aa6231fu64, 0x326c167ec32ccf77u64, 0x15119392875f78bcu64]),
-    ([0xb2be9919fe7beb09u64, 0x32b4ad674a5b0e84u64, 0xebbd8e4a6a5f39d3u64, 0x08189b7a1f04d514u64], [0xe2c78af0d13fac21u64, 0x435bedd61393f4d3u64, 0xe1501debaa33f5f6u64, 0x047b5d237b32f51au64]),
-    ([0x85fcf33c21a24ad6u64, 0x9d80256afc11e71du64, 0x4dc142b14aa24926u64, 0x56dacb61c12c11e7u64], [0xc80e1a3f617452c2u64, 0xddb87dfce3e86633u64, 0x78d26f2619214f1au64, 0x20b68c38d3964234u64]),
-    ([0x1b074f4e6b57c34eu64, 0x06f8c738132c6009u64, 0xd475976325a4a964u64, 0x6eb3fe66eaad172eu64], [0xacb9c6884466a403u64, 0xa4403e8ee4c211feu64, 0x83b3228f86b12b88u64, 0x3b1665d795174314u64]),
-    ([0xbd7360167f49690fu64, 0x55ed617941b103b9u64, 0x1486518d8c5f9689u64, 0x40b5b7898c657ed6u64], [0x2a6c3bda575105a3u64, 0x103b53f06d362bcbu64, 0x32359b9e30b7edb9u64, 0x2952e6bed46bd3d4u64]),
-    ([0xa50981741a41e44fu64, 0x193459c934ecefacu64, 0x5318e11dc9b1ca91u64, 0x11901ae656312fefu64], [0xd856d949ae768e4bu64, 0x8559a241bba345d1u64, 0x132e3cd23b09fc36u64, 0x0ccb0e60aabdc89eu64]),
-    ([0x11a43132b1888c36u64, 0x0e6466930923ab78u64, 0x8f907adac98af548u64, 0x6f70f5e67a06fbd8u64], [0x1a57c01c64bc324bu64, 0x04a31bb61f93847fu64, 0x76ce5070b6e634e2u64, 0x19bd847d48e6fccfu64]),
-    ([0x4a181389f87bece1u64, 0x15a4cbd9833c3a41u64, 0xf1caddd8e5b9862cu64, 0x428e7743368184afu64], [0x77e7d47e0b9239e4u64, 0xb13d2010684af028u64, 0xd304ee121f11de3fu64, 0x0b52ff33ebc7e59cu64]),
-    ([0xdf259f8f0b5c45d6u64, 0x29749ce3d6806cc2u64, 0x67409b01de51fdb5u64, 0x012e9de85005026eu64], [0x722795799168646bu64, 0x0a62ffb56087ec3bu64, 0xf301be98f7016022u64, 0x09ecb5edcc5702d9u64]),
-    ([0xf40997d4e0cdb369u64, 0xe1302bd0f30daa22u64, 0x6690843e61cac4adu64, 0x25e8cdf6ec9a06b0u64], [0x38f50edb2d1e082au64, 0x9bcf01fa91f03591u64, 0x148c41a9e3f95c4fu64, 0x0409223df842a442u64]),
-    ([0xcca54ea0331495d4u64, 0x05b1ae62415a9910u64, 0xb40ec2b38999673eu64, 0x163daecf5af2f4abu64], [0x9cbdf8d7fcb5875eu64, 0x96c103540deccc78u64, 0x39824a346076d035u64, 0x72bda899342be170u64]),
-    ([0xfa4a62b198187d9au64, 0x5bc509359dec7597u64, 0xe2710626e8e387eau64, 0x360f55adc7a91433u64], [0x73fb1eab41752102u64, 0xfb43bedae30b7cb2u64, 0xe05cda6c59235752u64, 0x206cf48f0f800b6bu64]),
-    ([0x12e39b8b4c1ce30au64, 0xacd388dec8bdb5c9u64, 0x2f946b3f886734d3u64, 0x512a38f4465cd083u64], [0xb573a376b6484b93u64, 0xac13a01c4628b8a2u64, 0x48de738432174fb8u64, 0x3de71edc2cd6119eu64]),
-    ([0x8e651766a21aabf7u64, 0x074f5998407582cbu64, 0x37822f5731ee9d12u64, 0x453f222338f5c908u64], [0x50494bf1654fee81u64, 0xebe2b3d5716a5acfu64, 0x237d66539431ce47u64, 0x70a3fbb8b782e4f6u64]),
-    ([0x241de8ab6c75208du64, 0x87c0551ac0e33765u64, 0x5eec2340271598efu64, 0x59449d9a30240186u64], [0xa953f9db341213adu64, 0xf6dad349a0db9a87u64, 0xe51120764981f0f1u64, 0x5f573a433e43cb3du64]),
-    ([0xb654f9c26799dcc7u64, 0xcbe89be72af25d72u64, 0x31c4dadec9aeb860u64, 0x1f7e1a1266ade519u64], [0xdb11acc800d0c5e2u64, 0x00afc1d90cf3a819u64, 0xae7af843efe78325u64, 0x57458df86e187f7au64]),
-    ([0xf7a3f674f9b19bf4u64, 0x631c5b62960ddd7bu64, 0x400753b97514ed76u64, 0x1a308a0fee2cab3au64], [0xb7cba005cd348f03u64, 0x3874b7daae49b9e0u64, 0xd39328d668308d4eu64, 0x10c17960cac2ae65u64]),
-    ([0x40696f931cef27c7u64, 0xabd38af418dcea49u64, 0x4c64da2d1c87fee4u64, 0x1510f2b39ee7c1f1u64], [0x631dc4ba8d9982b5u64, 0x3345ba2e2586c0acu64, 0xe747e9198cbc40b5u64, 0x623380a0576fc104u64]),
-    ([0x8bd63e0485773dbau64, 0x83be0261fa1ef074u64, 0xed257db1ed5ff5dau64, 0x6cebc5875ac931fbu64], [0x409d9fae43131925u64, 0x8f88f7333c351042u64, 0x8ce2a74e9f2783dcu64, 0x5688915983fa87ecu64]),
-    ([0xafb86759927b47e6u64, 0xd9936d72b19928b1u64, 0xed202ef373dc7dccu64, 0x5a8a58eed6c70605u64], [0xf24b9745d35d2d4bu64, 0xf62502d1b7912ed1u64, 0x583bd64c086ff558u64, 0x20678333a0fb3bdbu64]),
-    ([0xe304a47cfe2c39f0u64, 0xe2b0a7d87187c5c0u64, 0x293082360528eebeu64, 0x3b4acba79e856c22u64], [0x7f3e74d7caee64dbu64, 0x8366f4d49892306au64, 0x23beca2818b7a9efu64, 0x22e956effbc6cbc4u64]),
-    ([0xc52cd3cb674b34f7u64, 0x8a66c147052435b8u64, 0xb08cd87970cb89e2u64, 0x4357b6bdb257b845u64], [0x3c8c5af264ef4c0au64, 0xa925040bed4f98d6u64, 0xd11e82ca70b98156u64, 0x5911fee2a3117439u64]),
-    ([0x73ea3fa3e6fe310fu64, 0x4eb73f5d99585805u64, 0x412d801131c542d2u64, 0x68f0ba2461933c32u64], [0x80aaad47604192cau64, 0x095e67b0595ef04eu64, 0xfda681380554bebbu64, 0x44473a6cc410232eu64]),
-    ([0x3ad9908c78bbc1bau64, 0x1d49692c1d29ea09u64, 0x87191b04b833d660u64, 0x491147e252806a53u64], [0x4550540ca3e5a668u64, 0x0a149c8e44ed9b02u64, 0x3a4e76e3f7b51d4eu64, 0x21f940bd2806bdedu64]),
-    ([0x8aab34fd0a699f92u64, 0x0b5b986955de3d63u64, 0xbb3dd6f817e65025u64, 0x730f5244f60d187bu64], [0x53ea8536490e59e2u64, 0x184350367add9b7au64, 0xc6c930796f09086fu64, 0x08d515844d1b60e9u64]),
-    ([0xb0b1b60a24681e28u64, 0xe7050de9d5c78215u64, 0xcf955a039d047aeeu64, 0x341d7754d7ae1281u64], [0xb39784dfe75a9644u64, 0xad260b165500636du64, 0x77573dd444feddd7u64, 0x00c38fe57935c02au64]),
-    ([0xbf7579b9d2eebd4du64, 0x4d693fdc8713f747u64, 0x963edac49c2696dau64, 0x178b92aeafc59bc1u64], [0xc2aedc73f3215cf0u64, 0xa37eee97d5df4bbfu64, 0x41bef1ed977d51e1u64, 0x3fd11b998e4c492fu64]),
-    ([0x99f34ca4605bd68fu64, 0x64c610f12b9e1d40u64, 0x3f186ccae13678a4u64, 0x6871cbed867978e4u64], [0xfaa01b523a9c66f0u64, 0xe5cdc97d18b67b05u64, 0xd7a99a2846203e15u64, 0x18e5b6c6b3ee0f13u64]),
-    ([0x7d8a7a6c8da4a98au64, 0x121959d9d4d000d5u64, 0x43c94f59a6eb37f3u64, 0x278e695f56d8f6c5u64], [0xb37b97930bc994f0u64, 0x32494373358435d9u64, 0x9fcfbb44b776c2e4u64, 0x343dbfd1f0003fcfu64]),
-    ([0xaf722cf7829fab72u64, 0x1d1d92ba5065f372u64, 0xabc3f1fbd860bb88u64, 0x600413f833701b1eu64], [0xa2a188f81ad5c38au64, 0xf609e1633e29d4c9u64, 0xa2365057b049f4ffu64, 0x6e5dae7f8ee8e2ebu64]),
-    ([0x9cc2c16c38357332u64, 0xd74c9bd5d6faba52u64, 0xf21979ec507b8b9au64, 0x374e4d8c8ac99daeu64], [0x4e19979cacda6b97u64, 0x3dd5afb802766186u64, 0x115984d740df2825u64, 0x0130f4a233416ffbu64]),
-    ([0xc6265320eb04df1fu64, 0x7cc96dc7bd14fd4cu64, 0x04b715cf2986e1bdu64, 0x3eef03162d547d0bu64], [0x2c75eab68865223bu64, 0x0e86769cbfa18426u64, 0xd19d6d84ee89958bu64, 0x64800d03c8403ae5u64]),
-    ([0x34d926b66d9a40d9u64, 0xd88feed2f822e3f6u64, 0x0b4704bcfac11d52u64, 0x12ea7051d2cfcef6u64], [0xa4e1d5c0cf962c27u64, 0x0db0f016a85cbd1fu64, 0x1796591070203927u64, 0x620169c51de5de8cu64]),
-    ([0xa88e850ca72894b1u64, 0x05d623dc41d0fd8au64, 0x4ed37d208f6077d2u64, 0x6501cc83e375c0ecu64], [0x2ec3f3c00fd4e448u64, 0x5da473148e6a89ccu64, 0xaf8baa34eb7c57f0u64, 0x0b41b20327365097u64]),
-    ([0x101710f74afb7107u64, 0x650dca122075b980u64, 0xf1d522fdb2753b9fu64, 0x6916332fb92a0335u64], [0xc528ced56bb43ddbu64, 0x7538af0a3c613910u64, 0xc73d54515a8d1b87u64, 0x42193212d3551e13u64]),
-    ([0x687d2670f622df6cu64, 0xb70aefb294a5d9e3u64, 0x293ef92ea67a692au64, 0x5cd695d2457d5d6bu64], [0xdf77f722fa98900du64, 0xa8794de7e35efbbeu64, 0x6a9f34535d26250du64, 0x36ace1128fdddd2cu64]),
-    ([0xe0fda947724a81e9u64, 0xebbc8a5b856414d3u64, 0x0b9943d2d6d61529u64, 0x2ca0b0c5760e32cdu64], [0x757aaeccb0639acfu64, 0x1a475dcb29eea8b0u64, 0x857a5ba465ec926au64, 0x2fdadca4dbecc826u64]),
-    ([0x3fb0d31aeeacbb65u64, 0xfb7db9aa83b35c11u64, 0xcd33828d5f406885u64, 0x354384ab3bcdeda0u64], [0x32855f7ae17d5133u64, 0xf46f01c9a26720e5u64, 0x74b50c64646a9c98u64, 0x058919ad9cca05aeu64]),
-    ([0x9fc8017d5166afd3u64, 0xc54913cc6b4e0c26u64, 0x787d7d083f1b189fu64, 0x60b9f524ccbc6d03u64], [0xae8d016fe1eb4753u64, 0x710bd8855ecbca3bu64, 0xee1b84c28138418eu64, 0x096f9b37c91d918fu64]),
-    ([0xcad4adcb40845700u64, 0xb46eb9c39f25a1b6u64, 0x112e10699e587abfu64, 0x40e90cf7a259f141u64], [0x6d75a628b4b8aa0eu64, 0xb702793774a03517u64, 0xc457d5920cdda8eeu64, 0x51ae87db16738fefu64]),
-    ([0x5f15555ba7c59481u64, 0x427b55024e5ba8cfu64, 0x2edb5dd3926d465fu64, 0x408b15b1a1011296u64], [0xf89aff124f17a345u64, 0x8b9e92006c0b1ce0u64, 0x8281b3fa55878c5bu64, 0x3ae60fe88d334081u64]),
-    ([0x5a21f57246d64bccu64, 0x83d4d8d238af2768u64, 0x00e379bdca7a6a27u64, 0x4db03610625447c7u64], [0xa9c10d57af0501dbu64, 0xc290a8842c694bbfu64, 0x44afea980114f5f5u64, 0x1854b81a33491d43u64]),
-    ([0x7d6dcabcb43adfebu64, 0x98f14a39fc751f56u64, 0x98af3a8f44a30552u64, 0x26b8a40efa6dcac0u64], [0x56acd6784bb60f3bu64, 0x4c796163a6ef2d1cu64, 0x29ee671e23f7a723u64, 0x6bf34c049bca0187u64]),
-    ([0x675a23bf595affedu64, 0xb26c8c0251e16956u64, 0x814b2a4f9ae135c4u64, 0x64503edbaffb7067u64], [0xcb7e98e95c8e6280u64, 0x95847196755fa100u64, 0x003bf518657c4d70u64, 0x6793cb93c516efa9u64]),
-    ([0x32d4d0b2be200c36u64, 0x4b089f3a743c5027u64, 0xbc12df9658c7b4a7u64, 0x0a5e89a7a2513fddu64], [0x8a8f916b8171bf68u64, 0x784da097c0c4ceacu64, 0xb6149d6042f3fd5eu64, 0x047eeefe4379a626u64]),
-    ([0x08f85d2cfd89da4eu64, 0xa415b13cd94b7720u64, 0x32862846c576ee59u64, 0x044c0bba06096d7fu64], [0x200df55ec0fa8220u64, 0x8c59d6446ade529du64, 0x291a0dcc5dd42015u64, 0x33cd885e6136be2eu64]),
-    ([0xc15168ac46d068dbu64, 0xa45d206f9aa2eeccu64, 0x6f0af7c7244a3da1u64, 0x5e3f8d3bec0cc346u64], [0x4d04a2f46b291ebbu64, 0x365d94dee09939f1u64, 0x896d8144a86f8592u64, 0x61487be947159179u64]),
-    ([0x5f2f249eac565c21u64, 0x789ae2975cf67ec4u64, 0x5b7d1adc6c9e1f52u64, 0x73aac69b6a0ef138u64], [0xf2c37a7370892c6du64, 0x2f2344ed810b10a5u64, 0x9df6324bb5dca81fu64, 0x4de390027e04bbd1u64]),
-    ([0x25093e32d48647fcu64, 0x0b0c65a059be118au64, 0x24e66bbc118025acu64, 0x456089a064a13d05u64], [0xe359d8927cb38dfbu64, 0xeeb911497de3c54cu64, 0x11d50e9126360a51u64, 0x39a996f797013615u64]),
-    ([0x398fb5e547b671b5u64, 0x3812ca311f7fd12du64, 0x1ccbbaa4383233ecu64, 0x46f10cc4ba4853e1u64], [0xef11b66d4e118a69u64, 0x4a029eb2b7211af0u64, 0x6cbbdd2250aa177du64, 0x03b45a75c2cee419u64]),
-    ([0x7a79183fcde843c9u64, 0x7d5d62341f399e52u64, 0x84061f9f18bf5ab6u64, 0x697431feceffb218u64], [0xe466c2cfc1a1902bu64, 0x74e325f27970dac7u64, 0xef73f3f00f4894c1u64, 0x07b2e93d7d0d0f59u64]),
-    ([0x241d4950959a1c81u64, 0x11ee0baf3ab52c8eu64, 0x433a25b11b796301u64, 0x67595c07c691a601u64], [0x27e131d929100df5u64, 0x9310aa5aaa730b3cu64, 0x6b6c71b4181b0b01u64, 0x0dcaf29c614eff7fu64]),
-    ([0xd70edde47d675fa1u64, 0x9ed7699069b48f58u64, 0xaddfcf3650346bbeu64, 0x617ac4567150a5beu64], [0x2db8542c7b829a89u64, 0x9084906591073658u64, 0xf576c76f98bc65d8u64, 0x05496d1c43b3c778u64]),
-    ([0xdb35b650d9ab3014u64, 0x3f281a3c2fd0196du64, 0xdcd23a6d13a1561fu64, 0x739813a14cf58033u64], [0x8eabc4cf84c6acc3u64, 0x6c0f0725f2c3a156u64, 0xf76fa690b2c8f834u64, 0x6457c64f46188d5eu64]),
-    ([0x26be16cb704b990au64, 0x308f60f019fd4009u64, 0xc6e50c813baa9974u64, 0x2f00a2325ba21faau64], [0xf9786fd9f7f62afdu64, 0xb67d7fb5c628824fu64, 0x21c58efc84e10098u64, 0x0c0de3353c217643u64]),
-    ([0xdee3b94096c967beu64, 0x972b763fc1c4da11u64, 0xe00372501f9a6206u64, 0x29b92e2ea7ca3dc3u64], [0x6f53ee9eec441440u64, 0xbe2458501c3537f7u64, 0xbad51682edf92309u64, 0x2d98baed63162154u64]),
-    ([0x466b081672596f68u64, 0x46a918bc0373c6c8u64, 0x3247210a1e7da36cu64, 0x1f43e2053bec9fecu64], [0xf31ce87142f01357u64, 0x4cfbdf340ec5b0dbu64, 0xbe3374b0a05b7d90u64, 0x38b6146785631a85u64]),
-    ([0x3f774225fa5aa0e0u64, 0x859b0c2b81d68141u64, 0xb9f6c4aa4c9d0973u64, 0x6526c198b4f1edccu64], [0xbc66622cf4b688f3u64, 0x5fc7b74e71f87667u64, 0x3e1ac3ac55fd9b3fu64, 0x01c02a5fec4ef11fu64]),
-    ([0xd922fa1a3b513f68u64, 0xa4170673bd1b99a3u64, 0x054ba2513a8f6466u64, 0x36294ce5aa3941eeu64], [0x7eebb0a7044dc724u64, 0x0ee55ffa857bf4acu64, 0x9d704eba67dad04fu64, 0x27aea3f36ed9943fu64]),
-    ([0xbb8ef7568e987dfbu64, 0x914a46043dc9fa03u64, 0xc3b5a6dbbcd0298fu64, 0x666b4e1376cb98ceu64], [0x92461580ca802d15u64, 0x145fe1a74d3212f4u64, 0x7d58f3b780dee320u64, 0x4f198a341edaa03cu64]),
-    ([0xba7f245503b95a8du64, 0xd34c06153eb88810u64, 0xbf948a2120473a20u64, 0x63c21b18f176a5e0u64], [0x755bcf3403862d64u64, 0x0288e2b5f673fb4bu64, 0x428c0789e0757502u64, 0x5b0ad55265cd2b16u64]),
-    ([0x0abf67a5fa6f0687u64, 0x8f7235137c96ca15u64, 0xb63f8399bdf5936du64, 0x49788eaab1e5d22au64], [0x9de54b68fc0a0ae1u64, 0x4e793f8557c0a44cu64, 0x78901c34381384ddu64, 0x63d218ba8d6dd853u64]),
-    ([0x7ef262ffa8f8c918u64, 0x8d28fb6cae51baffu64, 0x720543223d96f0acu64, 0x07aa611dcedcb15bu64], [0xb6dbf669eb85c687u64, 0xb45b83cbe29b3d57u64, 0xd312baa2e1aeee92u64, 0x732c69b591a31cd2u64]),
-    ([0x9170990306486340u64, 0x3f0d80f013eb6d12u64, 0xd03f8875b901a1eau64, 0x4758f2286dd35bc1u64], [0x18f20ca550fdf7a1u64, 0xf0e2ea9a2f440eaeu64, 0x47ab64ae5d5b6ae8u64, 0x6f9da5465f4f09f8u64]),
-    ([0xb9f177b76f473cbcu64, 0xc81a907743c3d4b3u64, 0xedbfb8fc9a7be5d5u64, 0x2a441ce244d73adbu64], [0x1ba6ac85dd3f9afcu64, 0xdb7cd4aa5e32c36bu64, 0xa821bc7e1ae99c9eu64, 0x210973256a0035b0u64]),
-    ([0x108045b70a904c29u64, 0x1113995efabb2e08u64, 0x18c2811a831726b4u64, 0x162668e227088a0cu64], [0x07fb43854efacf15u64, 0x7e8a3e86ff6577f6u64, 0x2590794eb70a094au64, 0x057fcd3a294b4581u64]),
-    ([0xda37c449f8b60f52u64, 0x3ba63f546586f6d2u64, 0x2f49c52c6190369du64, 0x15e22b8b7d02f5cfu64], [0xe271195f37d9fc41u64, 0x418267f670eb0a66u64, 0xebd0126d383e9330u64, 0x6ac83408c5613532u64]),
-    ([0xf578c292627d3424u64, 0x042d74caec959b4du64, 0x00c8b1843ee3d0d1u64, 0x22ff2c5313faac4au64], [0x03a318422af44be7u64, 0x2907a81174accaddu64, 0x54ec4f85f920b921u64, 0x05388de06c79394du64]),
-    ([0x0d41b8bf60aef96bu64, 0xd7819e5ef22725f4u64, 0xb6a386acee69958au64, 0x24eb217edb6cee5eu64], [0x8e469e89a3ad06f4u64, 0x45895b549df39e7au64, 0x51e683238041903du64, 0x4af328e9898981efu64]),
-    ([0x569350c43c0745dfu64, 0xc695171015da9c0fu64, 0x6ef5bca284efc7b5u64, 0x024df42bd04cd34eu64], [0x089acc2e0483213bu64, 0xf04ee36e2919c669u64, 0x9a12a1b90ea62cf4u64, 0x3da20b74749cb545u64]),
-    ([0xdf2b12e5b0330148u64, 0x51d460a1dbfcd267u64, 0x8a490835c612d679u64, 0x20e9cd3a7fca77e3u64], [0x36638836b0b3e17du64, 0x13c0b254214a158du64, 0x6b74d33f23bd50dcu64, 0x4ccca63924242880u64]),
-    ([0xbc553c7e42f7b311u64, 0xa3fe4a2d19b29c5bu64, 0x912d2496f5d3df5bu64, 0x209ec047cb8e976fu64], [0x27fa4f93c409c2acu64, 0x05bc21c7592a4120u64, 0x8b88ac1d5a2a780bu64, 0x2234682ebd29ffe2u64]),
-    ([0x49182c727dbb9b2eu64, 0x3031b0356f6a9b5cu64, 0xb0c1aa8a59aec67cu64, 0x51b3482e7e3453a9u64], [0xe1e25a3bd5789eb5u64, 0x2c4b224808d7362eu64, 0x938f00370bc0781du64, 0x1d57baad384ad06cu64]),
-    ([0x2de50d5f536e30dbu64, 0x9157c68d7415396cu64, 0xb1eec4853a3a35d8u64, 0x23adb1543cca2a1fu64], [0xcf3f0db2a8e53125u64, 0xdfff6d8e8c9382ddu64, 0x16cf2f1350878d3fu64, 0x35f3113291fd77f8u64]),
-    ([0xd010a9edb4b638a4u64, 0x9cc72871c1151445u64, 0xc4f0a81758d181acu64, 0x713a9164dbe6739du64], [0x9e937e2bd8786662u64, 0x237ac7a1935b8a23u64, 0x712f5f299d064d7cu64, 0x188e160c4fb0597eu64]),
-    ([0x3dcc7526c32059aau64, 0x6252347c40e9ad02u64, 0x28b4392029b4096cu64, 0x174ff213d0cefbb4u64], [0x50c2194763691027u64, 0x90334f6736b4b6a6u64, 0x4d7c93e373a24010u64, 0x1ccce5a5a7414a67u64]),
-    ([0xa099ecb7d80ab778u64, 0xe565da083310e06du64, 0xf6430aea6aa55bf0u64, 0x07dee6259f2dfb34u64], [0x84f21346cf458cacu64, 0x17e18389b9d1977cu64, 0x957c7eaeba42edefu64, 0x02744af4a9fced8bu64]),
-    ([0xb5614227d84785d3u64, 0x42d8a8d534b0bce4u64, 0x4d379f0994080019u64, 0x11427c149fa74f61u64], [0xea280ed9309ce3f9u64, 0x68985376030611d5u64, 0xbb096f124bd4fe7fu64, 0x4cdd344408d1aa76u64]),
-    ([0x4222925245f4e2fbu64, 0x20b2b183f7ec0b19u64, 0x09b97ddd04dcc909u64, 0x18780c47d9f8e633u64], [0xb3b3b5fa57daf67du64, 0x7ac865d1140f29aeu64, 0x2afa98703cb69764u64, 0x566fd2ea1cd73e6au64]),
-    ([0xc3c1676c47894ba0u64, 0xbc25f767bd489fccu64, 0x26b78eaf3df8bc95u64, 0x62c4d13e09d7af1au64], [0x22e868eb3aa22e21u64, 0xbdf1df212dbdd769u64, 0x7220de0a715dbc55u64, 0x36d989c1bc209d68u64]),
-    ([0xe0723c72eb58f584u64, 0xf9140b7174540d1eu64, 0xf01e6f45ceee8419u64, 0x21530d469c569786u64], [0xb6bc3198320cb632u64, 0xf0ff61aa95e78968u64, 0x423c2b9b90bacc41u64, 0x4692f6f4f3e69d6au64]),
-    ([0x58fd9a1ddf213be7u64, 0xce6b3940c718bb39u64, 0xd22a979f6b15fc86u64, 0x1f4aee356d71662cu64], [0x8fa83cdc456a0cc2u64, 0xcb42a04865911569u64, 0x74eb6bfb57268b2cu64, 0x6e783a03bed5a8d6u64]),
-    ([0x0e5070b9bada029bu64, 0xf244f49758f2e4f9u64, 0xb5c8af93f751a2bcu64, 0x27b9f6421e7859bbu64], [0x983a0cdb4ba8fbf1u64, 0x4f8b611fe15c8893u64, 0xd9472ce62a6d0278u64, 0x374682f58ce6ae39u64]),
-    ([0x86535fda205c415bu64, 0xd53bfddab2017f23u64, 0x7eee7fbc801335bcu64, 0x413e5a5aef0bbd1fu64], [0x3bfa85a6d5a81052u64, 0xfb22dd499524993eu64, 0x1e44399254266607u64, 0x1283431d71047d0bu64]),
-    ([0x30d3acddb0356cebu64, 0x78f1f245ca8edb31u64, 0xc3916432097245e5u64, 0x3f57c3bb68888454u64], [0xb890dfb8ec60d989u64, 0x7d1a232cac0da379u64, 0x1a9210e2cbf2e066u64, 0x48168a328f4ea527u64]),
-    ([0xdc6de7f9dc3cf057u64, 0xad7209469bf06555u64, 0xdeeaf901bbb17f4cu64, 0x6861da9a17c0cb99u64], [0x75da2b297483c552u64, 0x346c37c958ad8873u64, 0x3122f17b017c6cacu64, 0x04fe95f72d413660u64]),
-    ([0x870b11e11ba54d0fu64, 0x81a2bee506858fc6u64, 0xbcf95c6ee2bfcfceu64, 0x0596a3d0ddae097eu64], [0x015fdb045ad94420u64, 0x3613c781f31e826cu64, 0xfd7b297234b47d4bu64, 0x4838885b403bb881u64]),
-    ([0xdd41e574458fd37cu64, 0xbd05f87df80ac9fdu64, 0x8cc7fecf9873ce9cu64, 0x1b37816f5ff8d241u64], [0xedcd695ecb3b7c45u64, 0x5ea482ee4e5d645au64, 0x0cb3c6af3386991du64, 0x5487a47f83fd75acu64]),
-    ([0xca2e518d542f5ab4u64, 0x37f819f7fbd18722u64, 0x5c7bd78eafd2363fu64, 0x5c5a35118c1d063bu64], [0x98ef26d0c8c6b88eu64, 0x0cb826ac4ff381dfu64, 0xba4060b00689ef93u64, 0x3fe8fa8e4bc3f3eeu64]),
-    ([0x13760ef8046fdba5u64, 0x98c4281101923502u64, 0x7ce98b35604515d3u64, 0x0370dad815b39056u64], [0x1c005e81635fb991u64, 0x583bbfd8891b5f13u64, 0x031e89f2f5c473afu64, 0x1d675b41beb18c91u64]),
-    ([0x8ab3113c021da780u64, 0x6212655fec382b48u64, 0x180ea84a5115cfd2u64, 0x53aa7d875f42d925u64], [0x6489a530f54d4837u64, 0x296ed9d53654071au64, 0x6e0f04f22945045fu64, 0x11550b4e84b6cd38u64]),
-    ([0xfd46d94c88ac4edau64, 0x99cefb8080afbd38u64, 0x59d2edb08e5b49e4u64, 0x24deab95aa7cb979u64], [0x8d16ec5cd6001a2bu64, 0xae54a3d0cf6d6a54u64, 0x00e79bf42436984au64, 0x29846beeaa73d4fdu64]),
-    ([0xf8d0009cc2a6c535u64, 0xd54537fd0d912eeau64, 0x5d8cb9d35a162fdbu64, 0x28de61eb978ffd8au64], [0xd2e9fd1a9e72f989u64, 0x16fff6f8c0e039eau64, 0x6b0794a22c558910u64, 0x21cb261ca1581c52u64]),
-    ([0x6f0bea9e4dba4f9au64, 0x12b28df60d27e28au64, 0x46326e6d7100f474u64, 0x2992a7903b327a34u64], [0x68a02a6ff1a685cau64, 0x07db5bdd28805bfcu64, 0x1b49db82f1fa9daau64, 0x17db05acb740719eu64]),
-    ([0x5a9a50847f96ec43u64, 0x11f69b4a47af7565u64, 0xa6231d154bcbeebfu64, 0x463690b59abcf615u64], [0xc8c46d71a36525c0u64, 0xda1c3f1875a12a64u64, 0x0f30862a0d52f4adu64, 0x6bb467aba4198255u64]),
-    ([0xb23d90e7a150a81fu64, 0xffb072e3c322e74eu64, 0x1f96bcabcb3f5b3eu64, 0x73e00e2241ddb7f3u64], [0xe81436dd95885105u64, 0x41417b5bc1df31e4u64, 0x64ef31bb44ad828cu64, 0x664bf7156b4109a8u64]),
-    ([0x87b9261d38ab4a25u64, 0xc85aad4b9c1dd14bu64, 0x2b3248a8f8582464u64, 0x41715c5bb54b12a8u64], [0x43d7443bc61312edu64, 0x339ad34b57ff8af6u64, 0x829cb1a09b61915cu64, 0x324458340090ef57u64]),
-    ([0x6311b7eb3aeec353u64, 0x9f67b6ccb7cb52aau64, 0x725895bec22d77a1u64, 0x082e45181092c55bu64], [0x9f1c8917b5777621u64, 0xd1e5d51951969e84u64, 0xfedd7fbf55f238deu64, 0x491b10da62de190du64]),
-    ([0x3e0664b6a44982e0u64, 0x64560ac1281b27b4u64, 0xd2d28fd9dc0d920bu64, 0x18fdcd6eb725f684u64], [0x2cc3bc08651c41ecu64, 0x25382240c578878cu64, 0x02f074c2b71ec56eu64, 0x5a95b1c4cd465e0eu64]),
-    ([0x97f8f008cf768a76u64, 0xb8f61d3f6170e53du64, 0x8ef028376b9685c9u64, 0x2c205b602c58269cu64], [0x9eac43f5aa4420deu64, 0xc0fb7625d9912ffeu64, 0x0733675efa2307b6u64, 0x6dbd915a61bcf2dcu64]),
-    ([0x1ea4d56a5d6a3654u64, 0x824a1bd4592fe155u64, 0xc62477cbd8d1dc72u64, 0x3679279f667a6ff2u64], [0xd7625b6272de7774u64, 0xa9fd9eda4fb88d8cu64, 0x67c5f27da7c70ae9u64, 0x2e49b240091359c9u64]),
-    ([0x0f34141c7d3da09au64, 0xbc121d282465bdadu64, 0x1a776f67d16dfe80u64, 0x6c4d0ef76b4c8069u64], [0x3e2d61f125eb95f3u64, 0xb5f46f34aa6206dfu64, 0xff53c1c0aaa00eaeu64, 0x18a3ec6e0355fa23u64]),
-    ([0x5e544cdf4c887948u64, 0xfc010d53ccb22542u64, 0x687a73c92f188612u64, 0x3b25b475ab91194bu64], [0x72b76d30d7cfe804u64, 0xc9fe10711a9997fdu64, 0x177709708e21e00fu64, 0x2d0696019c03e7adu64]),
-    ([0x04740fcd7b80fc99u64, 0xaca066fe71d2adadu64, 0xd2a707cdd6d20c23u64, 0x289e2a441d7ffda7u64], [0x32e6f87b7da66cd2u64, 0xe1fecdb74d4ed01cu64, 0x0200f1d28b05f455u64, 0x6912a951107741d0u64]),
-    ([0x93277736a35fa518u64, 0xa61d9f02709eb900u64, 0xa14d12d4e142af50u64, 0x588bb8a187109e2du64], [0x58682b1268260c36u64, 0x33a4eb694c7e1394u64, 0x6cad4e6628732ae9u64, 0x04b6a6ef02d3ed87u64]),
-    ([0x52eef10592d37478u64, 0x19db5ac19e954717u64, 0x7f500934d6353811u64, 0x583178280afce1c1u64], [0xbf5603705b9e0664u64, 0xcd8cac8cb202d19eu64, 0xb1c301517fd046d0u64, 0x70a7f0bd5b7c1181u64]),
-    ([0x3c1d97dd9423b5d1u64, 0x490a9a547af7f720u64, 0x6dfda5ce9bf2fc0cu64, 0x67dc39c63dcea51cu64], [0xd13b55a656d1b899u64, 0x7f05bdf8be0b15f2u64, 0xcff2db4038123718u64, 0x47058dc37fa157e7u64]),
-    ([0x2768549ef50d9562u64, 0x01954a71cbe1cb70u64, 0x708b2ba556f0c0a8u64, 0x0caa6029a0323eb4u64], [0x0212c5816b26c924u64, 0x36f068eadd7fd3b7u64, 0x3d34a64d5d061e4eu64, 0x688efe5bd638b6deu64]),
-    ([0xaadbf867e295fe4eu64, 0xd72736107d34be5du64, 0x13c6ed1276f03221u64, 0x6388e2b1413ae56bu64], [0x48ee66ef55ad75bbu64, 0x1a761b9f5dbcdb0cu64, 0xa33d75131232560fu64, 0x265242e374e92195u64]),
-    ([0xd70b82be17d2ea44u64, 0x5f200aebafc5ea98u64, 0xc8c9b38256147ffcu64, 0x02b09e1350d0f12eu64], [0x0005e42cb2a72972u64, 0x446b48b7df1f3cc5u64, 0xc56d79210c159019u64, 0x67f915b93903f553u64]),
-    ([0xb14b44a23cfe6f92u64, 0x4f75c4fd31a9bdfbu64, 0xd9bbb5adebfdc299u64, 0x02a8fa6a84eec93cu64], [0xa54d9a7a6459bd84u64, 0xe5fdc6ef32954135u64, 0x41bbb19be21928c3u64, 0x208c48835fb7d081u64]),
-    ([0x21f698c3549e1769u64, 0x6583dcbb8737c64fu64, 0xfd78657de7686e33u64, 0x6024c7db395ba0b0u64], [0xfed5e0583072b6d7u64, 0x8935242b79b4fcc2u64, 0xa33a0a268fe6918du64, 0x69ad88ec360fff82u64]),
-    ([0x2d0f0a19166b00c7u64, 0xbd8bcd5f497503b1u64, 0xc54b5ed9c1a33946u64, 0x41850e645548e4bcu64], [0x9996410969307747u64, 0xd5c4559c0f7f9fe9u64, 0x3fdc1ee0493ebf9fu64, 0x2dc7888f497a460fu64]),
-    ([0xc38e58bed13d112cu64, 0x454511e37ec0a0e6u64, 0xa4f3a7a686812ee3u64, 0x3eee5c7d802ce23eu64], [0xaea76fbe81c336e2u64, 0x3ed3138a0bbbebedu64, 0x4b56bb438ab55fa7u64, 0x20818101f19c6963u64]),
-    ([0xe9dd22a497765719u64, 0xc2b901d9b44bf6ceu64, 0x8e1dd927f6f6bf4au64, 0x3dbe994d9fc0040du64], [0x6b53fdb8a0ae92e3u64, 0x3a6a5fbc12ed68aau64, 0x906ed2721e4b933au64, 0x11a4f71249a0fd67u64]),
-    ([0x6d342b664751b8b9u64, 0xa7cdf2a4df205c56u64, 0xf628a70262abb5b8u64, 0x3c457aac979f99d2u64], [0xa32ca3ef071a2d0eu64, 0x33cfa8a49c9d6c99u64, 0xf80a101cdb48e002u64, 0x70e029f986759993u64]),
-    ([0x1e268819ba508ef6u64, 0xd6b8cb20a77d7230u64, 0x69c5df0b617bd142u64, 0x2170479ce4654983u64], [0xb313699c7fd543d2u64, 0xc66f6244f123d5beu64, 0x3c8dd6e9a4a3800cu64, 0x1bc70d3759460d82u64]),
-    ([0x104662889534a02cu64, 0x924ab805ac95bedau64, 0x36fa5c7dcdca12fdu64, 0x5b3e539e4674f2d1u64], [0x68344e8ffd5e7326u64, 0x03e16b48553b93bcu64, 0xe232138dbe0ccf11u64, 0x359ab3edf2c66b1du64]),
-    ([0x223c4fc056e43d9au64, 0x9bbabf1e9e172de6u64, 0xabac70f83e4acd51u64, 0x10952f0122e8072du64], [0xb2b172b9d31d32a6u64, 0x4e65ac17e6502a23u64, 0x4e080855b5f8e735u64, 0x1bb99013364d97cbu64]),
-    ([0x601dede2df479679u64, 0x9cb5e1c37fc947d5u64, 0xf698a3d11158073fu64, 0x2149f7525102cbcbu64], [0x194395665b5f3c59u64, 0x18590893329e49d7u64, 0xe52f120f2621b320u64, 0x37eec46c78f25dacu64]),
-    ([0x0ade8d476f637dd2u64, 0xf024d57952c2179cu64, 0xf78e806eb0f4022fu64, 0x71b597e408eec004u64], [0x74732cf04db2bb9au64, 0x659ef89d30eb134bu64, 0x443804487da749ceu64, 0x01d7e5402a24a6a9u64]),
-    ([0x7e214c505cc37bb8u64, 0x81a062883d272c78u64, 0x739bee8ef3518d59u64, 0x4bebb19461ae3758u64], [0x2d19751a70321d79u64, 0xd11606fe72832d42u64, 0x88179a0eb0a4e3c0u64, 0x3b2fd2cf5453bb2fu64]),
-    ([0x6358cf18763f4063u64, 0xd98a9a8b48559c15u64, 0x3e293867324c9a71u64, 0x73432a235b185c76u64], [0x35ac0b5e2742adfeu64, 0x44e1ede73de33ac6u64, 0xbb33cd4b3d0f19e2u64, 0x2d9ca4d0a1d85112u64]),
-    ([0x84ccab00b208b2e0u64, 0xf63a476657e66ec9u64, 0x3790707ffd6c682au64, 0x069b021de21f69c3u64], [0x1f07c4c200b795afu64, 0xd8ef8558db47030bu64, 0x224c5ecc498c3a7du64, 0x47998e71bd237a4eu64]),
-    ([0x625384be62a7a2e8u64, 0xf63fb9a2d6fcdc22u64, 0x2c5ded5ceeccb2adu64, 0x109de66c7ea5ba6fu64], [0xcd62d11dacebabd9u64, 0x236c936b422b7e0bu64, 0x1cd3b06779f679bfu64, 0x4ee34b54f950c308u64]),
-    ([0x1e38ce660312f615u64, 0xe7e5f6189a91d313u64, 0xeb5509c25b6653d9u64, 0x6f3321c3f659c625u64], [0xefb4627b98b844fbu64, 0xc3342ce0a664a8e6u64, 0x43f5ecf589ac319bu64, 0x4684530ce0e4fc70u64]),
-    ([0x91e8a1ba3b6f3441u64, 0xbe66f8a0dc0e6bbeu64, 0xe1b2c3aa0eb455ffu64, 0x1a112d20409a835fu64], [0x72f6dbe4a41fd158u64, 0xc639fc616bafa312u64, 0x78803674f27c9b0du64, 0x2247d3e1115ad019u64]),
-    ([0x453da88542fd72f9u64, 0x3f51d705b1446605u64, 0xede5eb002c2b60b9u64, 0x539f5b3a9e5d2c3au64], [0x33ea7fbf3e2cbba6u64, 0xa9e2314a2627dfefu64, 0xbb5d0374c19b7ba9u64, 0x22e29ee9ab893e84u64]),
-    ([0x140700f6f5ad1992u64, 0x9d27a423c7cc92ccu64, 0x84be2f7125588a79u64, 0x3da1160d7fc7387bu64], [0x834c09cd507f5b19u64, 0x77200383101fa3c5u64, 0xad4ef2dbf36c1e64u64, 0x010177e0d76e5eceu64]),
-    ([0xe1c53bdc5c414033u64, 0x77675ee8f0cfc4fbu64, 0x51715c36a0edc53bu64, 0x6c1f6ff3dc88c3c9u64], [0x82e8eafd8b80eba4u64, 0x1c5b4ec80b753b5du64, 0xa30a09e71a535c22u64, 0x358e02787ef87b53u64]),
-    ([0x8d66b6b5017225bbu64, 0xcbba2b752da27a4eu64, 0xd247f1687a9eb754u64, 0x11c17601029a4196u64], [0x52b1a683e5bf04a4u64, 0x5d140a1f721bb710u64, 0x69820b2aaf709a5du64, 0x4912455e20aceafau64]),
-    ([0xcf7e7c09c345bf6au64, 0x3469b768b5d39b86u64, 0x6321de3462e322a6u64, 0x2434130e6f604f4eu64], [0x4c0b1a848accb12fu64, 0xa492ea6b0da9b302u64, 0xe60e3eee434b44d8u64, 0x19ba42a71755ebf7u64]),
-    ([0xba7d4348b3e9e6a5u64, 0x4fa1ce0189080895u64, 0xafb713389188cd09u64, 0x2e26afb08780ceadu64], [0x64b193a956f28f1du64, 0xf3a26ac5084187dbu64, 0xd99e1c6be6c6cc03u64, 0x0d3b77c91dbdfe3cu64]),
-    ([0x2d93bf8912cc93b0u64, 0x29ad19ee48375194u64, 0x2fea09cc230ac203u64, 0x32109c4f8b2315b5u64], [0x83b62957c10da0aeu64, 0x65aa57cd26844369u64, 0x7fa1aa657cda5fa5u64, 0x12c2bf22c3b4fdf1u64]),
-    ([0xae34a81e1cfca2ecu64, 0x6c7876bf0928e68fu64, 0xae25b21df6c52b2au64, 0x5e73ed8c432405d0u64], [0x8beebfbb470065d3u64, 0xf6de9c18dad22e81u64, 0x1dd1336ac0c56805u64, 0x3ab5df2cdb923a56u64]),
-    ([0xa9c8a7ac0afc154bu64, 0x1f72fb7ca4c58800u64, 0x921e2c6b2e2b0e5bu64, 0x45d09cf9569612deu64], [0xe2876af6d63e57dbu64, 0x39611b15bfde523au64, 0x5f7a6c057044587fu64, 0x0b7f22b4e639f994u64]),
-    ([0xbd7831edbb030fe8u64, 0x3305eef4a96aad48u64, 0x6d7b9d657ca55704u64, 0x3ec15814750a6cbeu64], [0xfa106694fc4dddd8u64, 0x9c3fc88200cf48b9u64, 0xe0cbeb611405638fu64, 0x4458decf49bb7836u64]),
-    ([0x568f4b0b097df9fbu64, 0x18339c0906b87b56u64, 0x70a13c9e329eb8ddu64, 0x3e79dfc74d695298u64], [0xfa7558a83f016ce4u64, 0x5f48b88e4c8456a4u64, 0x39d8b734f84f8cd0u64, 0x042894355ea1c25eu64]),
-    ([0x2e9a4db5f138dab3u64, 0x7a1cb8c070f0ce32u64, 0xbcca660ee327eb37u64, 0x6a945980dade66efu64], [0x49ac81708900a143u64, 0x1d8b21bcf99fd3c2u64, 0xb11694358c015e38u64, 0x563aa693e1ca5531u64]),
-    ([0x8d749c6f55b6f3bau64, 0x143f0ba16d9f1f79u64, 0xca3cc0bb4809745du64, 0x5e5d17cd54e1e0b2u64], [0xde82b0b15a4a1ce3u64, 0xcbcea11c0668b8cau64, 0x975fc7f5fa4deb2bu64, 0x51da65992d3bc58bu64]),
-    ([0x1be69f3270830aadu64, 0x6862075a6764c01eu64, 0x447bddbac2fd51edu64, 0x5807969225ba3389u64], [0x7d5dfe60a76cbca4u64, 0x95a63aadc274b253u64, 0xc9e2641ca8bc4024u64, 0x1d47d06f57499996u64]),
-    ([0x36cd4db1bbe5ca69u64, 0x131a72e496b8439bu64, 0xd43cc0d2474edd1au64, 0x12435ceb01a76fceu64], [0xc7bbfba75c1c45b4u64, 0x6aca937c50edfd1fu64, 0xd4eb3cd1121f39fau64, 0x308c218ea3929da7u64]),
-    ([0x3ff630d3f89c4c2cu64, 0x08ccffd43f1931b6u64, 0xfaa1f3804fdb73d0u64, 0x1f3834a0f387add7u64], [0x1e58ab695b04156bu64, 0x160e5914ee3eaa26u64, 0x8395e848b86fdb33u64, 0x3fc5833f56e08b9fu64]),
-    ([0x9af9ec6b82136fc7u64, 0x4076bd1d8b43599au64, 0x9fdd3bcb27daee15u64, 0x6f03e2555448411cu64], [0x3d533b553a6cd201u64, 0x6c2eda845068b48cu64, 0x84733ce80cf730bdu64, 0x483ac9380b747007u64]),
-    ([0x122fe2e0669a2cc8u64, 0x3050700bdc4b9b68u64, 0x7a36b26c3985d063u64, 0x035bc18d05559682u64], [0xce4bedf28b77b80eu64, 0x395b72d37ff02271u64, 0x5863768d0191d901u64, 0x229d062cf02863c6u64]),
-    ([0xba4f3fedebd06bc5u64, 0x0298b5990f7dd55du64, 0x532e7960de94f439u64, 0x4481eac74899e255u64], [0x2b6e87bf9638a72fu64, 0x71241fdfd79e86ccu64, 0xc492451fac5a136cu64, 0x317e44d108113faau64]),
-    ([0xf007bdc150d024b1u64, 0xbf5354ab6e8c2ab4u64, 0xe0eb9d91cdecccfbu64, 0x1f2caa7aeef7d8deu64], [0xc1649f7339f7bd68u64, 0x5211bcb97a89e9ecu64, 0xf0447069459a616fu64, 0x5961796bb457473du64]),
-    ([0xe3ebd6d30fdaa02cu64, 0x79cb4f0c95186dabu64, 0xad14b5a4357eafabu64, 0x217f885359d63febu64], [0xcd64cac4b07343a0u64, 0x74bc9398c1ab6765u64, 0x871a027beabad1b7u64, 0x7060594b8bc47df7u64]),
-    ([0x1602cb7786c1f5e3u64, 0x682032b420d1fe8bu64, 0x3bc2e754e78bbdb0u64, 0x67eb2fd57acdc0bcu64], [0x3c2e41d6acb5be76u64, 0x538c215e24139680u64, 0xdb0858670fa023bau64, 0x222f7b6ddccb211fu64]),
-    ([0x3d36e6c8d5c85d0cu64, 0x33d49563f5999b73u64, 0x48ad3268533efd67u64, 0x3b7b169535954357u64], [0xdc1d10e052057f90u64, 0x14c5601d817c4264u64, 0xfe5233716a0b81e3u64, 0x42a955b83c9f93a4u64]),
-    ([0x8d28c143168bba2cu64, 0xdb872ef3dce28e78u64, 0x4e84b3cd7dc75b61u64, 0x640d097461a2eeafu64], [0x12d73409838f6a7du64, 0x5af81e1a3672c85bu64, 0x09ead19fa32bdd2bu64, 0x630de82470ba8072u64]),
-    ([0x7fbbf17bc15c64acu64, 0x479b656972e6cc48u64, 0xd5912df70ec1b979u64, 0x5bd1649c9174faecu64], [0x7ea2e737276828f3u64, 0x393d51d6ad5f67eau64, 0xabb2e55f30e35395u64, 0x07ca83d8275063e6u64]),
-    ([0x40e4c962f57bba4bu64, 0xa3ce9513fd14fa73u64, 0x5bef62d338fc0848u64, 0x08097452c5c97866u64], [0x715b34b1965c2cabu64, 0x80a5bea394581790u64, 0xc742f31eff846ac2u64, 0x203c40f0a53df007u64]),
-    ([0x63f68d7c07ae7401u64, 0x8b386f8d921bce1fu64, 0x2b1f82c24c49a6a3u64, 0x142978cd5c4a2cc2u64], [0xfc4998372172f916u64, 0x4318aca8f84a9207u64, 0x6711c49f9973d3d3u64, 0x480f95d7e143ef66u64]),
-    ([0xf3f90bd2b05e602eu64, 0x42f464cb4256a3b3u64, 0xcfdc1da7bf59004bu64, 0x36e1ebe124834a8fu64], [0xd7a0dd53b79d03b2u64, 0x09fa30e1edcf21bbu64, 0x2cc1c8b25d243157u64, 0x0160f88ef53ee6bdu64]),
-    ([0x1b2f74c46426a23fu64, 0x4939f2f88e75b4a3u64, 0xd362456d75681d05u64, 0x612be66af1d7932au64], [0xbdc970b6b640ea6du64, 0x0a4618672eed1600u64, 0xbe498e8487846b3bu64, 0x484f0d1937bbbdd4u64]),
-    ([0xf9ed4f03933eef85u64, 0x30eb83e42ef3f6bfu64, 0xa9ca21650de7491cu64, 0x07d1dbabda2a09d6u64], [0x2b609a0854bbed43u64, 0xc0eb15725f2878b3u64, 0x66bb7ebba1f759acu64, 0x56a6af500263aad4u64]),
-    ([0x4fc7d67bfcec6520u64, 0x91d1fc0d8c4aab11u64, 0x83cfc755da62fd7cu64, 0x5dc3c284a67515dcu64], [0xfb5e16d325ffb4ebu64, 0xd6f67a5edc45670cu64, 0x92dfc2330885a527u64, 0x09bd9916efe4ca0du64]),
-    ([0x6207cd2f20df9d12u64, 0x0081066e578d78bfu64, 0x725ce48f79bf9cd6u64, 0x60c7efec34093e9au64], [0xc221850ad50dfdb4u64, 0x819db473bf754b9eu64, 0xf0f73a03e196e42eu64, 0x461515890809e843u64]),
-    ([0x7de0dbdf025ab85cu64, 0x4c8e53b94e2d7a77u64, 0xe186be8536666f27u64, 0x679f181891141667u64], [0xfe72ee5939497d52u64, 0xe8292bf164f53339u64, 0xcbfe61fed2e9530eu64, 0x68bfab0ddbb63602u64]),
-    ([0xaddcfc96a506dae2u64, 0x87736de903ce2b7au64, 0xaf1b0f713a146c93u64, 0x346cbb19b5815e67u64], [0x635c105135af37f9u64, 0xf9a032fbf2cc238du64, 0x4373b53bf45a43d8u64, 0x3eb80eb6a9a06599u64]),
-    ([0x5bd310c3a66c9537u64, 0x145e38e331c8b497u64, 0x2cd060f51bbb1a5cu64, 0x07e07452f50c70e7u64], [0xe21ddb32c642cf8eu64, 0xbd5d06785da0ac12u64, 0xb01840ec7d764dd7u64, 0x32b82609664d3451u64]),
-    ([0x7d01866add958780u64, 0xdf7b4ee66e4caccbu64, 0x67e701a945e9b713u64, 0x18ba7658084c71a4u64], [0xd7c60f00752384e5u64, 0xf0765c264aa8d652u64, 0xaf073354a8ace072u64, 0x3be51ffdf4b5b8f9u64]),
-    ([0x01dd7dd94f4c83f0u64, 0x5262e5ef54d446d0u64, 0x79e285d21c3be96au64, 0x1d6c2e6610db2be5u64], [0xf7f2005cedbf92dau64, 0xfb056181cab93e10u64, 0xca21b6def3f02356u64, 0x31b3705717faaadcu64]),
-    ([0x16654b4e3193f385u64, 0x7dd34dc48b542a79u64, 0x881dab49e15d3727u64, 0x6a61dbd781fc6d1cu64], [0xb13da56de279d75cu64, 0x91f3d68090df9805u64, 0xbd61d2b8fdacfbdfu64, 0x69829e21f73bf300u64]),
-    ([0x1b561347c6a47727u64, 0x9ce862487904d0abu64, 0x942c20e45c3ad14du64, 0x5164ca4cf7456c9bu64], [0xbf96a52c9ded2fb3u64, 0x82af7cf9f62d45efu64, 0x1ab2fda5adbff235u64, 0x17f15674106c97f3u64]),
-];
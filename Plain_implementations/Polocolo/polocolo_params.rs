@@ -0,0 +1,261 @@
+use super::polocolo::PolocoloParams;
+use crate::fields::{biguint_to_limbs_le_4, PrimeFieldWords};
+use crate::utils::{field_element_from_shake, modinv};
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+
+pub const INIT_SHAKE: &str = "Polocolo";
+
+/// Upper bound on the LUT size `m`, the size used by the BN254 and BLS12-381 instances.
+pub const MAX_M: usize = 1024;
+
+/// Instantiation over any prime field with a complete LUT. The S-box is
+/// `S(x) = x^-1 * LUT(x^ann)` with `ann = (p - 1) / m`; the LUT maps the `r`-th power of
+/// `g^ann` to `g^((m + 1) r + sigma(r))` for a permutation `sigma` of `[0, m)`, so `S` permutes
+/// the `m`-th power residue classes and is a bijection. Round constants and `sigma` come from
+/// SHAKE128, since the reference implementation samples them without a published seed.
+impl<F: PrimeFieldWords> PolocoloParams<F> {
+    pub fn generate(t: usize, security_level: usize) -> Self {
+        let p = F::modulus();
+        let m = get_m(&p);
+        let ann = (&p - 1u32) / m;
+        let rounds = get_number_of_rounds(t, m, security_level);
+
+        let mut shake = init_shake(&p);
+        let round_constants = get_round_constants::<F>(&p, t, rounds, &mut shake);
+        let sigma = get_sigma(m, &mut shake);
+        let lut = get_lut::<F>(&ann, &sigma);
+
+        PolocoloParams::new(
+            t,
+            m,
+            biguint_to_limbs_le_4(&ann),
+            rounds,
+            &get_mds_matrix::<F>(t),
+            &round_constants,
+            &lut,
+        )
+    }
+}
+
+/// The largest divisor of `p - 1` that is at most [`MAX_M`].
+pub fn get_m(p: &BigUint) -> usize {
+    let p_minus_one = p - 1u32;
+    (2..=MAX_M)
+        .rev()
+        .find(|&m| (&p_minus_one % m).is_zero())
+        .expect("p - 1 is even")
+}
+
+/// Every S-box costs an attacker `log2(m)` bits: its LUT output is only known once the power
+/// residue class of its input is guessed, or the solutions are filtered by it. The `t - 1` free
+/// input elements fix all first-layer classes but one. Smallest `R` with
+/// `(t * R - t + 1) * log2(m) >= security_level`, plus one round of margin; this gives the
+/// reference 6 rounds for `t = 3` and `m = 1024`.
+pub fn get_number_of_rounds(t: usize, m: usize, security_level: usize) -> usize {
+    let bits = m.ilog2() as usize;
+    let rounds = (1..)
+        .find(|&r| (t * r - t + 1) * bits >= security_level)
+        .expect("security level is finite");
+    rounds + 1
+}
+
+/// SHAKE128 seeded with `"Polocolo"` followed by the little-endian 64-bit limbs of `p`.
+pub fn init_shake(p: &BigUint) -> impl XofReader {
+    let mut shake = Shake128::default();
+    shake.update(INIT_SHAKE.as_bytes());
+    for limb in p.to_u64_digits() {
+        shake.update(&u64::to_le_bytes(limb));
+    }
+    shake.finalize_xof()
+}
+
+pub fn get_round_constants<F: PrimeFieldWords>(
+    p: &BigUint,
+    t: usize,
+    rounds: usize,
+    shake: &mut dyn XofReader,
+) -> Vec<Vec<F>> {
+    (0..rounds)
+        .map(|_| {
+            (0..t)
+                .map(|_| F::from_biguint(&field_element_from_shake(p, shake)))
+                .collect()
+        })
+        .collect()
+}
+
+/// A Fisher-Yates shuffle of `[0, m)`, reading 32-bit words and rejecting out-of-range ones.
+pub fn get_sigma(m: usize, shake: &mut dyn XofReader) -> Vec<usize> {
+    let mut sigma: Vec<usize> = (0..m).collect();
+    for i in (1..m).rev() {
+        let mask = u32::MAX >> (i as u32).leading_zeros();
+        let j = loop {
+            let mut buf = [0u8; 4];
+            shake.read(&mut buf);
+            let j = (u32::from_le_bytes(buf) & mask) as usize;
+            if j <= i {
+                break j;
+            }
+        };
+        sigma.swap(i, j);
+    }
+    sigma
+}
+
+/// The LUT entries `(g^ann)^r -> g^((m + 1) r + sigma(r))` for `r < m`.
+pub fn get_lut<F: PrimeFieldWords>(ann: &BigUint, sigma: &[usize]) -> Vec<([u64; 4], F)> {
+    let m = sigma.len();
+    let g = F::from_biguint(&F::generator());
+    let gk = g.pow_words_le(&biguint_to_limbs_le_4(ann));
+    let g_m1 = g.pow_u64(m as u64 + 1);
+
+    let mut g_pows = Vec::with_capacity(m);
+    let mut acc = F::one();
+    for _ in 0..m {
+        g_pows.push(acc.clone());
+        acc.mul_assign(&g);
+    }
+
+    let mut lut = Vec::with_capacity(m);
+
+    let mut key = F::one();
+    let mut base = F::one();
+    for &s in sigma {
+        let mut out = base.clone();
+        out.mul_assign(&g_pows[s]);
+        lut.push((key.to_words_le(), out));
+        key.mul_assign(&gk);
+        base.mul_assign(&g_m1);
+    }
+    assert_eq!(key, F::one(), "g^ann must have order m");
+    lut
+}
+
+/// The reference matrix for `t = 3`, otherwise the Cauchy matrix `1 / (i + j + 1)`, which is MDS
+/// as long as `p > 2t`.
+pub fn get_mds_matrix<F: PrimeFieldWords>(t: usize) -> Vec<Vec<F>> {
+    if t == 3 {
+        return [[2, 1, 1], [1, 2, 2], [1, 1, 2]]
+            .iter()
+            .map(|row| row.iter().map(|&x| F::from_u64(x)).collect())
+            .collect();
+    }
+
+    let p = F::modulus();
+    (0..t)
+        .map(|i| {
+            (0..t)
+                .map(|j| F::from_biguint(&modinv(&BigUint::from(i + j + 1), &p)))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::instances::{
+        POLOCOLO_BLS12_381_2_PARAMS, POLOCOLO_BLS12_381_3_PARAMS, POLOCOLO_BLS12_381_4_PARAMS,
+        POLOCOLO_BLS12_381_8_PARAMS, POLOCOLO_BN254_2_PARAMS, POLOCOLO_BN254_3_PARAMS,
+        POLOCOLO_BN254_4_PARAMS, POLOCOLO_BN254_8_PARAMS, SECURITY_LEVEL,
+    };
+    use super::super::polocolo::{Polocolo, PolocoloParams};
+    use super::{get_m, get_number_of_rounds};
+    use crate::fields::bls12_381::Bls12_381;
+    use crate::fields::bn254::Bn254;
+    use crate::fields::{biguint_to_limbs_le_4, FieldElement, PrimeFieldWords};
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    // The S-box maps the m classes of m-th power residues onto each other bijectively, so the
+    // images of g^0, ..., g^(m-1) raised to `ann` must be pairwise distinct.
+    fn assert_complete_lut<F: PrimeFieldWords>(params: &Arc<PolocoloParams<F>>) {
+        let m = params.lut.len();
+        assert_eq!(m, get_m(&F::modulus()));
+        let ann = (F::modulus() - 1u32) / m;
+        assert_eq!(params.ann_minus_one, biguint_to_limbs_le_4(&(&ann - 1u32)));
+
+        let perm = Polocolo::new(params);
+        let g = F::from_biguint(&F::generator());
+        let mut x = F::one();
+        let mut classes = HashSet::with_capacity(m);
+        for _ in 0..m {
+            let out = perm.sbox_elem(&x);
+            classes.insert(out.pow_words_le(&biguint_to_limbs_le_4(&ann)).to_words_le());
+            x.mul_assign(&g);
+        }
+        assert_eq!(classes.len(), m);
+
+        let input: Vec<F> = (0..params.t as u64).map(F::from_u64).collect();
+        assert_ne!(perm.permutation(&input), input);
+    }
+
+    #[test]
+    fn luts_are_complete() {
+        assert_complete_lut(&POLOCOLO_BN254_2_PARAMS);
+        assert_complete_lut(&POLOCOLO_BN254_3_PARAMS);
+        assert_complete_lut(&POLOCOLO_BN254_4_PARAMS);
+        assert_complete_lut(&POLOCOLO_BN254_8_PARAMS);
+        assert_complete_lut(&POLOCOLO_BLS12_381_2_PARAMS);
+        assert_complete_lut(&POLOCOLO_BLS12_381_3_PARAMS);
+        assert_complete_lut(&POLOCOLO_BLS12_381_4_PARAMS);
+        assert_complete_lut(&POLOCOLO_BLS12_381_8_PARAMS);
+    }
+
+    #[test]
+    fn round_numbers() {
+        for (t, rounds) in [(2, 8), (3, 6), (4, 5), (8, 4)] {
+            assert_eq!(get_number_of_rounds(t, 1024, 128), rounds, "t = {t}");
+        }
+        assert_eq!(POLOCOLO_BN254_8_PARAMS.rounds, 4);
+        assert_eq!(POLOCOLO_BLS12_381_2_PARAMS.rounds, 8);
+    }
+
+    fn assert_generated<F: PrimeFieldWords>(params: &Arc<PolocoloParams<F>>) {
+        let generated = PolocoloParams::<F>::generate(3, SECURITY_LEVEL);
+        assert_eq!(generated.rounds, params.rounds);
+        assert_eq!(generated.mds, params.mds);
+        assert_eq!(generated.round_constants, params.round_constants);
+        assert_eq!(generated.ann_minus_one, params.ann_minus_one);
+        assert_eq!(generated.lut, params.lut);
+        assert_eq!(generated.subgroup, params.subgroup);
+    }
+
+    #[test]
+    fn t3_instances_are_generated() {
+        assert_generated(&POLOCOLO_BN254_3_PARAMS);
+        assert_generated(&POLOCOLO_BLS12_381_3_PARAMS);
+        assert_eq!(POLOCOLO_BN254_3_PARAMS.rounds, 6);
+    }
+
+    #[test]
+    fn bn254_3_known_answer() {
+        let perm = Polocolo::new(&POLOCOLO_BN254_3_PARAMS);
+        let input: Vec<Bn254> = (0..3u64).map(Bn254::from_u64).collect();
+        let expected = [
+            "0x0b625971a08208268d2330703ab258df475db50f9853b57a2bf384a42fac96a1",
+            "0x211aea623d7631f0ea6251bf273a8800189771ba5d1c634c189c9da0c7e6998b",
+            "0x07738329f78c4cbc2f687ad866a92820b73b2873711dcaeb807aea7ade9107f2",
+        ]
+        .map(|h| Bn254::from_hex(h).unwrap());
+        assert_eq!(perm.permutation(&input), expected);
+    }
+
+    #[test]
+    fn bls12_381_3_known_answer() {
+        let perm = Polocolo::new(&POLOCOLO_BLS12_381_3_PARAMS);
+        let input: Vec<Bls12_381> = (0..3u64).map(Bls12_381::from_u64).collect();
+        let expected = [
+            "0x73b29c15fd6805fb9254ce13698caa04f6901a0de2bf72aa474100a37a0d73d2",
+            "0x1b68be0932920a9e28c87e18a60eb5701032257f208e16d148d6edbf0bdda78c",
+            "0x0aabacf584452977c6098c49197d202efc72b79add79adbf85717c41b7121fb3",
+        ]
+        .map(|h| Bls12_381::from_hex(h).unwrap());
+        assert_eq!(perm.permutation(&input), expected);
+    }
+}
@@ -0,0 +1,92 @@
+use super::instances::POSEIDON_CIRCOM_BN254_PARAMS;
+use super::poseidon::Poseidon;
+use crate::fields::bn254::Bn254;
+use crate::fields::FieldElement;
+use alloc::vec::Vec;
+
+/// circomlib's `Poseidon(nInputs)` for 1 to 16 inputs: the state is `[0, inputs...]` with width
+/// `inputs.len() + 1` and the hash is the first element of the permuted state.
+pub fn poseidon_hash(inputs: &[Bn254]) -> Bn254 {
+    assert!(
+        !inputs.is_empty() && inputs.len() <= POSEIDON_CIRCOM_BN254_PARAMS.len(),
+        "circomlib Poseidon takes 1 to 16 inputs"
+    );
+    let poseidon = Poseidon::new(&POSEIDON_CIRCOM_BN254_PARAMS[inputs.len() - 1]);
+
+    let mut state = Vec::with_capacity(inputs.len() + 1);
+    state.push(Bn254::zero());
+    state.extend_from_slice(inputs);
+    poseidon.permutation(&state).swap_remove(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::poseidon_hash;
+    use crate::fields::bn254::Bn254;
+    use crate::fields::{FieldElement, PrimeField};
+    use num_bigint::BigUint;
+
+    fn hash(inputs: &[u64]) -> Bn254 {
+        let inputs: Vec<Bn254> = inputs.iter().map(|&x| Bn254::from_u64(x)).collect();
+        poseidon_hash(&inputs)
+    }
+
+    fn from_dec(dec: &str) -> Bn254 {
+        Bn254::from_biguint(&BigUint::parse_bytes(dec.as_bytes(), 10).unwrap())
+    }
+
+    // Vectors from the circomlibjs and go-iden3-crypto test suites.
+    #[test]
+    fn circomlib_vectors() {
+        let vectors: [(&[u64], &str); 11] = [
+            (
+                &[1],
+                "18586133768512220936620570745912940619677854269274689475585506675881198879027",
+            ),
+            (
+                &[1, 2],
+                "7853200120776062878684798364095072458815029376092732009249414926327459813530",
+            ),
+            (
+                &[1, 2, 0, 0, 0],
+                "1018317224307729531995786483840663576608797660851238720571059489595066344487",
+            ),
+            (
+                &[3, 4, 0, 0, 0],
+                "5811595552068139067952687508729883632420015185677766880877743348592482390548",
+            ),
+            (
+                &[1, 2, 0, 0, 0, 0],
+                "15336558801450556532856248569924170992202208561737609669134139141992924267169",
+            ),
+            (
+                &[3, 4, 0, 0, 0, 0],
+                "12263118664590987767234828103155242843640892839966517009184493198782366909018",
+            ),
+            (
+                &[1, 2, 3, 4],
+                "18821383157269793795438455681495246036402687001665670618754263018637548127333",
+            ),
+            (
+                &[1, 2, 3, 4, 5, 6],
+                "20400040500897583745843009878988256314335038853985262692600694741116813247201",
+            ),
+            (
+                &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14],
+                "8354478399926161176778659061636406690034081872658507739535256090879947077494",
+            ),
+            (
+                &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+                "4203130618016961831408770638653325366880478848856764494148034853759773445968",
+            ),
+            (
+                &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+                "9989051620750914585850546081941653841776809718687451684622678807385399211877",
+            ),
+        ];
+
+        for (inputs, expected) in vectors {
+            assert_eq!(hash(inputs), from_dec(expected));
+        }
+    }
+}
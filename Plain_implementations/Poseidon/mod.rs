@@ -1,2 +1,5 @@
+pub mod circomlib;
 pub mod instances;
+pub mod plonky2;
 pub mod poseidon;
+pub mod poseidon_params;
@@ -0,0 +1,218 @@
+use super::poseidon::PoseidonParams;
+use crate::fields::goldilocks::Goldilocks;
+use crate::fields::FieldElement;
+use alloc::{sync::Arc, vec, vec::Vec};
+
+pub const WIDTH: usize = 12;
+pub const RATE: usize = 8;
+pub const NUM_HASH_OUT_ELTS: usize = 4;
+pub const HALF_N_FULL_ROUNDS: usize = 4;
+pub const N_PARTIAL_ROUNDS: usize = 22;
+pub const NUM_ROUNDS: usize = 2 * HALF_N_FULL_ROUNDS + N_PARTIAL_ROUNDS;
+
+/// First row of Plonky2's circulant MDS matrix; the full matrix adds `diag(MDS_MATRIX_DIAG)`.
+pub const MDS_MATRIX_CIRC: [u64; WIDTH] = [17, 15, 41, 16, 2, 28, 13, 13, 39, 18, 34, 20];
+pub const MDS_MATRIX_DIAG: [u64; WIDTH] = [8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Plonky2's `PoseidonHash` over Goldilocks, using the small-entry MDS with delayed reduction.
+#[derive(Clone, Debug)]
+pub struct Plonky2Poseidon {
+    pub(crate) params: Arc<PoseidonParams<Goldilocks>>,
+}
+
+impl Plonky2Poseidon {
+    pub fn new(params: &Arc<PoseidonParams<Goldilocks>>) -> Self {
+        assert_eq!(params.t, WIDTH);
+        assert_eq!(params.rounds_f_beginning, HALF_N_FULL_ROUNDS);
+        assert_eq!(params.rounds_p, N_PARTIAL_ROUNDS);
+        Plonky2Poseidon {
+            params: Arc::clone(params),
+        }
+    }
+
+    pub fn get_t(&self) -> usize {
+        WIDTH
+    }
+
+    pub fn permutation(&self, input: &[Goldilocks]) -> Vec<Goldilocks> {
+        assert_eq!(input.len(), WIDTH);
+        let mut state = input.to_vec();
+        self.permute(&mut state);
+        state
+    }
+
+    /// Plonky2 `hash_no_pad`: each chunk of `RATE` inputs overwrites the start of the state,
+    /// without padding or length encoding, and the first `NUM_HASH_OUT_ELTS` elements are output.
+    pub fn hash_no_pad(&self, inputs: &[Goldilocks]) -> [Goldilocks; NUM_HASH_OUT_ELTS] {
+        let mut state = vec![Goldilocks::zero(); WIDTH];
+        for chunk in inputs.chunks(RATE) {
+            state[..chunk.len()].copy_from_slice(chunk);
+            self.permute(&mut state);
+        }
+        core::array::from_fn(|i| state[i])
+    }
+
+    /// Plonky2 `hash_or_noop`: inputs that fit into a digest are zero-padded instead of hashed.
+    pub fn hash_or_noop(&self, inputs: &[Goldilocks]) -> [Goldilocks; NUM_HASH_OUT_ELTS] {
+        if inputs.len() <= NUM_HASH_OUT_ELTS {
+            core::array::from_fn(|i| inputs.get(i).copied().unwrap_or_else(Goldilocks::zero))
+        } else {
+            self.hash_no_pad(inputs)
+        }
+    }
+
+    /// Plonky2 `two_to_one`: both digests fill the rate, the capacity stays zero.
+    pub fn two_to_one(
+        &self,
+        left: &[Goldilocks; NUM_HASH_OUT_ELTS],
+        right: &[Goldilocks; NUM_HASH_OUT_ELTS],
+    ) -> [Goldilocks; NUM_HASH_OUT_ELTS] {
+        let mut state = vec![Goldilocks::zero(); WIDTH];
+        state[..NUM_HASH_OUT_ELTS].copy_from_slice(left);
+        state[NUM_HASH_OUT_ELTS..2 * NUM_HASH_OUT_ELTS].copy_from_slice(right);
+        self.permute(&mut state);
+        core::array::from_fn(|i| state[i])
+    }
+
+    fn permute(&self, state: &mut [Goldilocks]) {
+        let mut round = 0;
+        for _ in 0..HALF_N_FULL_ROUNDS {
+            self.add_rc(state, round);
+            for x in state.iter_mut() {
+                *x = sbox(x);
+            }
+            mds_layer(state);
+            round += 1;
+        }
+
+        for _ in 0..N_PARTIAL_ROUNDS {
+            self.add_rc(state, round);
+            state[0] = sbox(&state[0]);
+            mds_layer(state);
+            round += 1;
+        }
+
+        for _ in 0..HALF_N_FULL_ROUNDS {
+            self.add_rc(state, round);
+            for x in state.iter_mut() {
+                *x = sbox(x);
+            }
+            mds_layer(state);
+            round += 1;
+        }
+    }
+
+    fn add_rc(&self, state: &mut [Goldilocks], round: usize) {
+        let rc = &self.params.round_constants[round];
+        for (x, c) in state.iter_mut().zip(rc.iter()) {
+            x.add_assign(c);
+        }
+    }
+}
+
+/// Plonky2's `mds_layer`: the entries sum to less than 2^9, so each output is accumulated over
+/// the 32-bit halves of the inputs and reduced only once.
+pub(crate) fn mds_layer(state: &mut [Goldilocks]) {
+    let limbs: Vec<u64> = state.iter().map(Goldilocks::to_u64).collect();
+    let shift = Goldilocks::from_u64(1u64 << 32);
+
+    for (row, out) in state.iter_mut().enumerate() {
+        let mut acc_lo = MDS_MATRIX_DIAG[row] * (limbs[row] & 0xFFFF_FFFF);
+        let mut acc_hi = MDS_MATRIX_DIAG[row] * (limbs[row] >> 32);
+        for (i, coeff) in MDS_MATRIX_CIRC.iter().enumerate() {
+            let limb = limbs[(i + row) % WIDTH];
+            acc_lo += coeff * (limb & 0xFFFF_FFFF);
+            acc_hi += coeff * (limb >> 32);
+        }
+        let mut res = Goldilocks::from_u64(acc_hi);
+        res.mul_assign(&shift);
+        res.add_assign(&Goldilocks::from_u64(acc_lo));
+        *out = res;
+    }
+}
+
+fn sbox(input: &Goldilocks) -> Goldilocks {
+    let mut input2 = *input;
+    input2.square();
+    let mut out = input2;
+    out.square();
+    out.mul_assign(&input2);
+    out.mul_assign(input);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::instances::POSEIDON_PLONKY2_GOLDILOCKS_12_PARAMS;
+    use super::super::poseidon::Poseidon;
+    use super::{Plonky2Poseidon, WIDTH};
+    use crate::fields::goldilocks::Goldilocks;
+    use crate::fields::FieldElement;
+
+    // Test vectors from Plonky2's `poseidon_goldilocks.rs`.
+    #[test]
+    fn permutation_matches_plonky2_reference_vectors() {
+        #[rustfmt::skip]
+        let vectors: [([u64; WIDTH], [u64; WIDTH]); 3] = [
+            (
+                [0; WIDTH],
+                [
+                    0x3c18a9786cb0b359, 0xc4055e3364a246c3, 0x7953db0ab48808f4, 0xc71603f33a1144ca,
+                    0xd7709673896996dc, 0x46a84e87642f44ed, 0xd032648251ee0b3c, 0x1c687363b207df62,
+                    0xdf8565563e8045fe, 0x40f5b37ff4254dae, 0xd070f637b431067c, 0x1792b1c4342109d7,
+                ],
+            ),
+            (
+                core::array::from_fn(|i| i as u64),
+                [
+                    0xd64e1e3efc5b8e9e, 0x53666633020aaa47, 0xd40285597c6a8825, 0x613a4f81e81231d2,
+                    0x414754bfebd051f0, 0xcb1f8980294a023f, 0x6eb2a9e4d54a9d0f, 0x1902bc3af467e056,
+                    0xf045d5eafdc6021f, 0xe4150f77caaa3be5, 0xc9bfd01d39b50cce, 0x5c0a27fcb0e1459b,
+                ],
+            ),
+            (
+                [Goldilocks::MODULUS - 1; WIDTH],
+                [
+                    0xbe0085cfc57a8357, 0xd95af71847d05c09, 0xcf55a13d33c1c953, 0x95803a74f4530e82,
+                    0xfcd99eb30a135df1, 0xe095905e913a3029, 0xde0392461b42919b, 0x7d3260e24e81d031,
+                    0x10d3d0465d9deaa0, 0xa87571083dfc2a47, 0xe18263681e9958f8, 0xe28e96f1ae5e60d3,
+                ],
+            ),
+        ];
+
+        let fast = Plonky2Poseidon::new(&POSEIDON_PLONKY2_GOLDILOCKS_12_PARAMS);
+        let generic = Poseidon::new(&POSEIDON_PLONKY2_GOLDILOCKS_12_PARAMS);
+        for (input, expected) in vectors {
+            let input = input.map(Goldilocks::from_u64);
+            let expected = expected.map(Goldilocks::from_u64);
+            assert_eq!(fast.permutation(&input), expected);
+            assert_eq!(generic.permutation(&input), expected);
+        }
+    }
+
+    #[test]
+    fn hashers_follow_plonky2_conventions() {
+        let poseidon = Plonky2Poseidon::new(&POSEIDON_PLONKY2_GOLDILOCKS_12_PARAMS);
+        let a = [1u64, 2, 3, 4].map(Goldilocks::from_u64);
+        let b = [5u64, 6, 7, 8].map(Goldilocks::from_u64);
+
+        assert_eq!(poseidon.hash_or_noop(&a[..3])[..3], a[..3]);
+        assert_eq!(poseidon.hash_or_noop(&a[..3])[3], Goldilocks::zero());
+
+        // A single full chunk leaves the capacity zero, as in `two_to_one`.
+        let elements: Vec<Goldilocks> = a.iter().chain(b.iter()).copied().collect();
+        assert_eq!(poseidon.hash_no_pad(&elements), poseidon.two_to_one(&a, &b));
+        assert_eq!(
+            poseidon.hash_or_noop(&elements),
+            poseidon.two_to_one(&a, &b)
+        );
+
+        // A partial second chunk only overwrites its own lanes.
+        let mut longer = elements.clone();
+        longer.push(Goldilocks::from_u64(9));
+        let mut state = poseidon.permutation(&[elements, vec![Goldilocks::zero(); 4]].concat());
+        state[0] = Goldilocks::from_u64(9);
+        let state = poseidon.permutation(&state);
+        assert_eq!(poseidon.hash_no_pad(&longer)[..], state[..4]);
+    }
+}
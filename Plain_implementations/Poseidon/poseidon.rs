@@ -1,5 +1,20 @@
 use crate::fields::FieldElement;
-use std::sync::Arc;
+use alloc::{borrow::ToOwned, sync::Arc, vec, vec::Vec};
+
+/// Round structure of the permutation. Both variants share the round constants and the dense MDS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PoseidonVariant {
+    /// The SoK variant: an initial linear layer and a diag-plus-ones matrix in partial rounds.
+    Minit,
+    /// The original HADES construction as in the reference implementation: no initial linear
+    /// layer and the dense MDS in every round.
+    Original,
+}
 
 #[derive(Clone, Debug)]
 pub struct PoseidonParams<F: FieldElement> {
@@ -10,6 +25,7 @@ pub struct PoseidonParams<F: FieldElement> {
     pub(crate) round_constants: Vec<Vec<F>>, // [round_idx][state_idx]
     pub(crate) mds_full: Vec<Vec<F>>,
     pub(crate) mds_partial: Vec<Vec<F>>,
+    pub(crate) variant: PoseidonVariant,
 }
 
 impl<F: FieldElement> PoseidonParams<F> {
@@ -30,6 +46,21 @@ impl<F: FieldElement> PoseidonParams<F> {
             round_constants: round_constants.to_owned(), // [round_idx][state_idx]
             mds_full: mds_full.to_owned(),
             mds_partial: mds_partial.to_owned(),
+            variant: PoseidonVariant::Minit,
+        }
+    }
+
+    pub fn new_original(
+        t: usize,
+        d: u64,
+        rounds_f: usize,
+        rounds_p: usize,
+        mds: &[Vec<F>],
+        round_constants: &[Vec<F>],
+    ) -> Self {
+        PoseidonParams {
+            variant: PoseidonVariant::Original,
+            ..Self::new(t, d, rounds_f, rounds_p, mds, mds, round_constants)
         }
     }
 }
@@ -59,7 +90,9 @@ impl<F: FieldElement> Poseidon<F> {
         let mut round = 0usize;
 
         // Match Poseidon2b-style flow: Minit + full/partial/full.
-        self.mul_mds_full(&mut state);
+        if self.params.variant == PoseidonVariant::Minit {
+            self.mul_mds_full(&mut state);
+        }
 
         for _ in 0..half_f {
             self.round_full(&mut state, round);
@@ -92,7 +125,10 @@ impl<F: FieldElement> Poseidon<F> {
     fn round_partial(&self, state: &mut [F], round: usize) {
         self.add_rc_in_place(state, round);
         state[0] = self.sbox_p(&state[0]);
-        self.mul_mds_partial(state);
+        match self.params.variant {
+            PoseidonVariant::Minit => self.mul_mds_partial(state),
+            PoseidonVariant::Original => self.mul_mds_full(state),
+        }
     }
 
     fn sbox_p(&self, input: &F) -> F {
@@ -178,3 +214,28 @@ impl<F: FieldElement> Poseidon<F> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::instances::POSEIDON_ORIGINAL_BN254_3_PARAMS;
+    use super::Poseidon;
+    use crate::fields::bn254::Bn254;
+    use crate::fields::{FieldElement, PrimeField};
+    use num_bigint::BigUint;
+
+    #[test]
+    fn original_matches_reference_vector() {
+        let poseidon = Poseidon::new(&POSEIDON_ORIGINAL_BN254_3_PARAMS);
+        let input: Vec<Bn254> = (0..3u64).map(Bn254::from_u64).collect();
+        let expected: Vec<Bn254> = [
+            "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a",
+            "0fca49b798923ab0239de1c9e7a4a9a2210312b6a2f616d18b5a87f9b628ae29",
+            "0e7ae82e40091e63cbd4f16a6d16310b3729d4b6e138fcf54110e2867045a30c",
+        ]
+        .iter()
+        .map(|hex| Bn254::from_biguint(&BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()))
+        .collect();
+
+        assert_eq!(poseidon.permutation(&input), expected);
+    }
+}
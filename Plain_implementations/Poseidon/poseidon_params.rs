@@ -0,0 +1,177 @@
+use super::poseidon::PoseidonParams;
+use crate::fields::PrimeField;
+use crate::utils::modinv;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// Instantiation with the reference constants of the original Poseidon (Grassi et al., "Poseidon:
+/// A New Hash Function for Zero-Knowledge Proof Systems"), as produced by the reference script
+/// `generate_parameters_grain.sage`.
+impl<F: PrimeField> PoseidonParams<F> {
+    pub fn generate_original(t: usize, d: u64, rounds_f: usize, rounds_p: usize) -> Self {
+        let p = F::modulus();
+        let mut grain = GrainLfsr::new(p.bits() as usize, t, rounds_f, rounds_p);
+        let round_constants = get_round_constants::<F>(&p, t, rounds_f + rounds_p, &mut grain);
+        let mds = get_mds_matrix::<F>(&p, t, &mut grain);
+
+        PoseidonParams::new_original(t, d, rounds_f, rounds_p, &mds, &round_constants)
+    }
+}
+
+/// The 80-bit Grain LFSR of the reference script, initialised with the field type (prime), the
+/// s-box type (x^alpha), the field size in bits, the width and the round numbers.
+pub struct GrainLfsr {
+    state: [bool; 80],
+    head: usize,
+    field_bits: usize,
+}
+
+impl GrainLfsr {
+    pub fn new(field_bits: usize, t: usize, rounds_f: usize, rounds_p: usize) -> Self {
+        let mut state = [true; 80];
+        let fields = [
+            (1, 2),
+            (0, 4),
+            (field_bits, 12),
+            (t, 12),
+            (rounds_f, 10),
+            (rounds_p, 10),
+        ];
+        let mut pos = 0;
+        for (value, width) in fields {
+            for i in (0..width).rev() {
+                state[pos] = (value >> i) & 1 == 1;
+                pos += 1;
+            }
+        }
+
+        let mut grain = GrainLfsr {
+            state,
+            head: 0,
+            field_bits,
+        };
+        for _ in 0..160 {
+            grain.next_bit();
+        }
+        grain
+    }
+
+    fn next_bit(&mut self) -> bool {
+        let bit = |i: usize| self.state[(self.head + i) % 80];
+        let new_bit = bit(62) ^ bit(51) ^ bit(38) ^ bit(23) ^ bit(13) ^ bit(0);
+        self.state[self.head] = new_bit;
+        self.head = (self.head + 1) % 80;
+        new_bit
+    }
+
+    // Bits are emitted in pairs, keeping the second bit only when the first one is set.
+    fn filtered_bit(&mut self) -> bool {
+        loop {
+            let keep = self.next_bit();
+            let bit = self.next_bit();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// `field_bits` filtered bits, most significant first.
+    pub fn random_bits(&mut self) -> BigUint {
+        let mut out = BigUint::zero();
+        for _ in 0..self.field_bits {
+            out <<= 1;
+            if self.filtered_bit() {
+                out += 1u32;
+            }
+        }
+        out
+    }
+}
+
+/// `rounds` rows of `t` constants, rejecting samples `>= p`.
+pub fn get_round_constants<F: PrimeField>(
+    p: &BigUint,
+    t: usize,
+    rounds: usize,
+    grain: &mut GrainLfsr,
+) -> Vec<Vec<F>> {
+    (0..rounds)
+        .map(|_| {
+            (0..t)
+                .map(|_| loop {
+                    let candidate = grain.random_bits();
+                    if &candidate < p {
+                        break F::from_biguint(&candidate);
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The Cauchy matrix `1 / (x_i + y_j)` from `2t` distinct samples reduced mod p. The reference
+/// script also re-samples matrices that fail its subspace-trail checks; the first candidate
+/// is used here, which is the accepted one for all shipped instances.
+pub fn get_mds_matrix<F: PrimeField>(p: &BigUint, t: usize, grain: &mut GrainLfsr) -> Vec<Vec<F>> {
+    loop {
+        let samples: Vec<BigUint> = (0..2 * t).map(|_| grain.random_bits() % p).collect();
+        let distinct = samples
+            .iter()
+            .enumerate()
+            .all(|(i, a)| samples[..i].iter().all(|b| a != b));
+        if !distinct {
+            continue;
+        }
+
+        let (xs, ys) = samples.split_at(t);
+        if xs
+            .iter()
+            .any(|x| ys.iter().any(|y| ((x + y) % p).is_zero()))
+        {
+            continue;
+        }
+
+        return xs
+            .iter()
+            .map(|x| {
+                ys.iter()
+                    .map(|y| F::from_biguint(&modinv(&((x + y) % p), p)))
+                    .collect()
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::instances::{
+        POSEIDON_BABYBEAR_16_PARAMS, POSEIDON_BLS12_381_3_PARAMS, POSEIDON_BN254_2_PARAMS,
+        POSEIDON_BN254_3_PARAMS, POSEIDON_GOLDILOCKS_12_PARAMS, POSEIDON_KOALABEAR_24_PARAMS,
+        POSEIDON_MERSENNE31_16_PARAMS,
+    };
+    use super::super::poseidon::PoseidonParams;
+    use crate::fields::PrimeField;
+
+    fn assert_generates<F: PrimeField>(want: &PoseidonParams<F>) {
+        let got = PoseidonParams::<F>::generate_original(
+            want.t,
+            want.d,
+            2 * want.rounds_f_beginning,
+            want.rounds_p,
+        );
+        assert_eq!(got.round_constants, want.round_constants);
+        assert_eq!(got.mds_full, want.mds_full);
+    }
+
+    #[test]
+    fn generated_params_match_shipped_instances() {
+        assert_generates(&POSEIDON_BN254_2_PARAMS);
+        assert_generates(&POSEIDON_BN254_3_PARAMS);
+        assert_generates(&POSEIDON_BLS12_381_3_PARAMS);
+        assert_generates(&POSEIDON_GOLDILOCKS_12_PARAMS);
+        assert_generates(&POSEIDON_BABYBEAR_16_PARAMS);
+        assert_generates(&POSEIDON_KOALABEAR_24_PARAMS);
+        assert_generates(&POSEIDON_MERSENNE31_16_PARAMS);
+    }
+}
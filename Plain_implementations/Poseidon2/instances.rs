@@ -1,4 +1,6 @@
-use super::poseidon2::Poseidon2Params;
+use super::poseidon2::{matmul_external, Poseidon2Params};
+use super::poseidon2_params::get_mat_internal_diag_m_1;
+use crate::poseidon::poseidon_params::{get_round_constants, GrainLfsr};
 use crate::fields::babybear::BabyBear;
 use crate::fields::bls12_381::Bls12_381;
 use crate::fields::bn254::Bn254;
@@ -6,8 +8,8 @@ use crate::fields::goldilocks::Goldilocks;
 use crate::fields::koalabear::KoalaBear;
 use crate::fields::mersenne31::Mersenne31;
 use crate::fields::{biguint_from_limbs_le, FieldElement, PrimeField};
+use alloc::{sync::Arc, vec, vec::Vec};
 use lazy_static::lazy_static;
-use std::sync::Arc;
 
 fn f_from_words<F: PrimeField>(words: [u64; 4]) -> F {
     F::from_biguint(&biguint_from_limbs_le(&words))
@@ -30,7 +32,7 @@ fn poseidon2_external_matrix<F: FieldElement>(t: usize) -> Vec<Vec<F>> {
     for col in 0..t {
         let mut basis = vec![F::zero(); t];
         basis[col] = F::one();
-        poseidon2_external_apply(&mut basis);
+        matmul_external(&mut basis);
         for row in 0..t {
             matrix[row][col] = basis[row].clone();
         }
@@ -38,70 +40,6 @@ fn poseidon2_external_matrix<F: FieldElement>(t: usize) -> Vec<Vec<F>> {
     matrix
 }
 
-fn poseidon2_external_apply<F: FieldElement>(input: &mut [F]) {
-    let t = input.len();
-    match t {
-        2 => {
-            let mut sum = input[0].clone();
-            sum.add_assign(&input[1]);
-            input[0].add_assign(&sum);
-            input[1].add_assign(&sum);
-        }
-        3 => {
-            let mut sum = input[0].clone();
-            sum.add_assign(&input[1]);
-            sum.add_assign(&input[2]);
-            input[0].add_assign(&sum);
-            input[1].add_assign(&sum);
-            input[2].add_assign(&sum);
-        }
-        8 | 12 | 16 | 24 => {
-            let t4 = t / 4;
-            for i in 0..t4 {
-                let start = i * 4;
-                let mut t_0 = input[start].clone();
-                t_0.add_assign(&input[start + 1]);
-                let mut t_1 = input[start + 2].clone();
-                t_1.add_assign(&input[start + 3]);
-                let mut t_2 = input[start + 1].clone();
-                t_2.double();
-                t_2.add_assign(&t_1);
-                let mut t_3 = input[start + 3].clone();
-                t_3.double();
-                t_3.add_assign(&t_0);
-                let mut t_4 = t_1.clone();
-                t_4.double();
-                t_4.double();
-                t_4.add_assign(&t_3);
-                let mut t_5 = t_0.clone();
-                t_5.double();
-                t_5.double();
-                t_5.add_assign(&t_2);
-                let mut t_6 = t_3.clone();
-                t_6.add_assign(&t_5);
-                let mut t_7 = t_2.clone();
-                t_7.add_assign(&t_4);
-                input[start] = t_6;
-                input[start + 1] = t_5;
-                input[start + 2] = t_7;
-                input[start + 3] = t_4;
-            }
-
-            let mut stored = vec![F::zero(); 4];
-            for lane in 0..4 {
-                stored[lane] = input[lane].clone();
-                for blk in 1..t4 {
-                    stored[lane].add_assign(&input[4 * blk + lane]);
-                }
-            }
-            for i in 0..input.len() {
-                input[i].add_assign(&stored[i % 4]);
-            }
-        }
-        _ => panic!("unsupported width"),
-    }
-}
-
 lazy_static! {
     pub static ref POSEIDON2_BN254_2_PARAMS: Arc<Poseidon2Params<Bn254>> = {
     let mat_internal_diag_m_1: Vec<Bn254> = vec![
@@ -250,6 +188,23 @@ lazy_static! {
     ];
     Arc::new(Poseidon2Params::new(3, 5, 8, 56, &poseidon2_external_matrix::<Bn254>(3), &diag_plus_one_matrix(&mat_internal_diag_m_1), &round_constants))
     };
+    // Round constants from the Grain LFSR of the reference script, the internal diagonal from
+    // `get_mat_internal_diag_m_1`.
+    pub static ref POSEIDON2_BN254_4_PARAMS: Arc<Poseidon2Params<Bn254>> = {
+        let p = Bn254::modulus();
+        let mut grain = GrainLfsr::new(p.bits() as usize, 4, 8, 56);
+        let round_constants = get_round_constants::<Bn254>(&p, 4, 8 + 56, &mut grain);
+        let mat_internal_diag_m_1 = get_mat_internal_diag_m_1::<Bn254>(4);
+        Arc::new(Poseidon2Params::new(
+            4,
+            5,
+            8,
+            56,
+            &poseidon2_external_matrix::<Bn254>(4),
+            &diag_plus_one_matrix(&mat_internal_diag_m_1),
+            &round_constants,
+        ))
+    };
     pub static ref POSEIDON2_BLS12_381_2_PARAMS: Arc<Poseidon2Params<Bls12_381>> = {
     let mat_internal_diag_m_1: Vec<Bls12_381> = vec![
         f_from_words::<Bls12_381>([0x0000000000000001u64, 0x0000000000000000u64, 0x0000000000000000u64, 0x0000000000000000u64]),
@@ -1,2 +1,3 @@
 pub mod poseidon2;
+pub mod poseidon2_params;
 pub mod instances;
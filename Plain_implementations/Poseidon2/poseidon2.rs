@@ -1,5 +1,5 @@
 use crate::fields::FieldElement;
-use std::sync::Arc;
+use alloc::{borrow::ToOwned, sync::Arc, vec, vec::Vec};
 
 #[derive(Clone, Debug)]
 pub struct Poseidon2Params<F: FieldElement> {
@@ -24,6 +24,7 @@ impl<F: FieldElement> Poseidon2Params<F> {
         mat_internal: &[Vec<F>],
         round_constants: &[Vec<F>],
     ) -> Self {
+        assert!(t == 2 || t == 3 || (t >= 4 && t.is_multiple_of(4)));
         let mut mat_internal_diag_m_1 = Vec::with_capacity(t);
         let one = F::one();
         for (i, row) in mat_internal.iter().enumerate() {
@@ -126,67 +127,7 @@ impl<F: FieldElement> Poseidon2<F> {
     }
 
     fn matmul_external(&self, input: &mut [F]) {
-        let t = self.params.t;
-        match t {
-            2 => {
-                let mut sum = input[0].clone();
-                sum.add_assign(&input[1]);
-                input[0].add_assign(&sum);
-                input[1].add_assign(&sum);
-            }
-            3 => {
-                let mut sum = input[0].clone();
-                sum.add_assign(&input[1]);
-                sum.add_assign(&input[2]);
-                input[0].add_assign(&sum);
-                input[1].add_assign(&sum);
-                input[2].add_assign(&sum);
-            }
-            8 | 12 | 16 | 24 => {
-                let t4 = t / 4;
-                for i in 0..t4 {
-                    let start_index = i * 4;
-                    let mut t_0 = input[start_index].clone();
-                    t_0.add_assign(&input[start_index + 1]);
-                    let mut t_1 = input[start_index + 2].clone();
-                    t_1.add_assign(&input[start_index + 3]);
-                    let mut t_2 = input[start_index + 1].clone();
-                    t_2.double();
-                    t_2.add_assign(&t_1);
-                    let mut t_3 = input[start_index + 3].clone();
-                    t_3.double();
-                    t_3.add_assign(&t_0);
-                    let mut t_4 = t_1.clone();
-                    t_4.double();
-                    t_4.double();
-                    t_4.add_assign(&t_3);
-                    let mut t_5 = t_0.clone();
-                    t_5.double();
-                    t_5.double();
-                    t_5.add_assign(&t_2);
-                    let mut t_6 = t_3.clone();
-                    t_6.add_assign(&t_5);
-                    let mut t_7 = t_2.clone();
-                    t_7.add_assign(&t_4);
-                    input[start_index] = t_6;
-                    input[start_index + 1] = t_5;
-                    input[start_index + 2] = t_7;
-                    input[start_index + 3] = t_4;
-                }
-
-                let mut stored = vec![F::zero(); 4];
-                for l in 0..4 {
-                    stored[l] = input[l].clone();
-                    for j in 1..t4 {
-                        stored[l].add_assign(&input[4 * j + l]);
-                    }
-                }
-                for i in 0..input.len() {
-                    input[i].add_assign(&stored[i % 4]);
-                }
-            }
-            _ => panic!("unsupported width"),
-        }
+        matmul_external(input);
     }
 
     fn matmul_internal(&self, input: &mut [F]) {
@@ -209,7 +150,7 @@ impl<F: FieldElement> Poseidon2<F> {
                 input[2].double();
                 input[2].add_assign(&sum);
             }
-            8 | 12 | 16 | 24 => {
+            _ => {
                 let mut sum = input[0].clone();
                 for el in input.iter().skip(1) {
                     sum.add_assign(el);
@@ -219,7 +160,6 @@ impl<F: FieldElement> Poseidon2<F> {
                     input[i].add_assign(&sum);
                 }
             }
-            _ => panic!("unsupported width"),
         }
     }
 
@@ -230,3 +170,108 @@ impl<F: FieldElement> Poseidon2<F> {
         }
     }
 }
+
+/// Applies `circ(2, 1)` for t = 2, `circ(2, 1, 1)` for t = 3, `M4` for t = 4, and
+/// `circ(2 * M4, M4, ..., M4)` for every larger multiple of 4.
+pub(crate) fn matmul_external<F: FieldElement>(input: &mut [F]) {
+    let t = input.len();
+    match t {
+        2 => {
+            let mut sum = input[0].clone();
+            sum.add_assign(&input[1]);
+            input[0].add_assign(&sum);
+            input[1].add_assign(&sum);
+        }
+        3 => {
+            let mut sum = input[0].clone();
+            sum.add_assign(&input[1]);
+            sum.add_assign(&input[2]);
+            input[0].add_assign(&sum);
+            input[1].add_assign(&sum);
+            input[2].add_assign(&sum);
+        }
+        4 => matmul_m4(input),
+        t if t.is_multiple_of(4) => {
+            matmul_m4(input);
+
+            let t4 = t / 4;
+            let mut stored = vec![F::zero(); 4];
+            for l in 0..4 {
+                stored[l] = input[l].clone();
+                for j in 1..t4 {
+                    stored[l].add_assign(&input[4 * j + l]);
+                }
+            }
+            for i in 0..input.len() {
+                input[i].add_assign(&stored[i % 4]);
+            }
+        }
+        _ => panic!("unsupported width"),
+    }
+}
+
+// Applies the 4x4 MDS matrix [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]] to each
+// 4-element chunk.
+fn matmul_m4<F: FieldElement>(input: &mut [F]) {
+    for chunk in input.chunks_exact_mut(4) {
+        let mut t_0 = chunk[0].clone();
+        t_0.add_assign(&chunk[1]);
+        let mut t_1 = chunk[2].clone();
+        t_1.add_assign(&chunk[3]);
+        let mut t_2 = chunk[1].clone();
+        t_2.double();
+        t_2.add_assign(&t_1);
+        let mut t_3 = chunk[3].clone();
+        t_3.double();
+        t_3.add_assign(&t_0);
+        let mut t_4 = t_1.clone();
+        t_4.double();
+        t_4.double();
+        t_4.add_assign(&t_3);
+        let mut t_5 = t_0.clone();
+        t_5.double();
+        t_5.double();
+        t_5.add_assign(&t_2);
+        let mut t_6 = t_3.clone();
+        t_6.add_assign(&t_5);
+        let mut t_7 = t_2.clone();
+        t_7.add_assign(&t_4);
+        chunk[0] = t_6;
+        chunk[1] = t_5;
+        chunk[2] = t_7;
+        chunk[3] = t_4;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matmul_external;
+    use crate::fields::goldilocks::Goldilocks;
+    use crate::fields::FieldElement;
+
+    const M4: [[u64; 4]; 4] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+
+    // For t = 4 the external matrix is M4 itself, otherwise the block matrix with 2 * M4 on the
+    // diagonal and M4 everywhere else.
+    #[test]
+    fn external_layer_matches_dense_matmul() {
+        for t in [4, 8, 12, 16, 24] {
+            let mut state: Vec<Goldilocks> = (0..t as u64)
+                .map(|i| Goldilocks::from_u64(u64::MAX - 977 * i))
+                .collect();
+
+            let mut expected = vec![Goldilocks::zero(); t];
+            for (row, out) in expected.iter_mut().enumerate() {
+                for (col, x) in state.iter().enumerate() {
+                    let block = if t > 4 && row / 4 == col / 4 { 2 } else { 1 };
+                    let mut tmp = Goldilocks::from_u64(block * M4[row % 4][col % 4]);
+                    tmp.mul_assign(x);
+                    out.add_assign(&tmp);
+                }
+            }
+
+            matmul_external(&mut state);
+            assert_eq!(state, expected, "t = {t}");
+        }
+    }
+}
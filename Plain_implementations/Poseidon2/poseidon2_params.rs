@@ -0,0 +1,329 @@
+use crate::fields::{biguint_to_limbs_le_4, PrimeField};
+use crate::utils::field_element_from_shake;
+use alloc::{vec, vec::Vec};
+use num_bigint::BigUint;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+
+pub const INIT_SHAKE: &str = "Poseidon2";
+
+/// Samples `mat_internal_diag_m_1` for `M_I = J + diag(mat_internal_diag_m_1)` until the matrix
+/// passes [`has_irreducible_min_polys`] (Grassi, Khovratovich, Schofnegger, "Poseidon2: A
+/// Faster Version of the Poseidon Hash Function", Section 5.3).
+pub fn get_mat_internal_diag_m_1<F: PrimeField>(t: usize) -> Vec<F> {
+    assert!(t >= 4);
+    let p = F::modulus();
+    let mut shake = init_shake(&p, t);
+    loop {
+        let diag_m_1: Vec<F> = (0..t)
+            .map(|_| F::from_biguint(&field_element_from_shake(&p, &mut shake)))
+            .collect();
+        if has_irreducible_min_polys(&diag_m_1) {
+            return diag_m_1;
+        }
+    }
+}
+
+/// SHAKE128 seeded with `"Poseidon2"`, the width as a little-endian u64 and the little-endian
+/// 64-bit limbs of `p`.
+pub fn init_shake(p: &BigUint, t: usize) -> impl XofReader {
+    let mut shake = Shake128::default();
+    shake.update(INIT_SHAKE.as_bytes());
+    shake.update(&u64::to_le_bytes(t as u64));
+    for limb in p.to_u64_digits() {
+        shake.update(&u64::to_le_bytes(limb));
+    }
+    shake.finalize_xof()
+}
+
+/// `M_I = J + diag(mat_internal_diag_m_1)` is invertible, and the minimal polynomials of
+/// `M_I^k` for `k = 1..=2t` are irreducible of degree `t`. This is only a sufficient condition
+/// (Section 5.3 of the Poseidon2 paper): it rules out invariant subspaces and hence arbitrarily
+/// long subspace trails over the partial rounds, but a matrix that fails it is not necessarily
+/// insecure.
+pub fn has_irreducible_min_polys<F: PrimeField>(mat_internal_diag_m_1: &[F]) -> bool {
+    let t = mat_internal_diag_m_1.len();
+    let inv_exp = biguint_to_limbs_le_4(&(F::modulus() - 2u32));
+
+    let mut m = vec![vec![F::one(); t]; t];
+    for (i, d) in mat_internal_diag_m_1.iter().enumerate() {
+        m[i][i].add_assign(d);
+    }
+    if solve(&m, &vec![F::zero(); t], &inv_exp).is_none() {
+        return false;
+    }
+
+    let mut m_k = m.clone();
+    for _ in 0..2 * t {
+        match minimal_polynomial(&m_k, &inv_exp) {
+            Some(f) if is_irreducible(&f, &inv_exp) => {}
+            _ => return false,
+        }
+        m_k = mat_mul(&m, &m_k);
+    }
+    true
+}
+
+fn mat_mul<F: PrimeField>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    a.iter()
+        .map(|row| {
+            (0..b[0].len())
+                .map(|j| {
+                    let mut acc = F::zero();
+                    for (x, b_row) in row.iter().zip(b.iter()) {
+                        let mut tmp = x.clone();
+                        tmp.mul_assign(&b_row[j]);
+                        acc.add_assign(&tmp);
+                    }
+                    acc
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn mat_vec<F: PrimeField>(m: &[Vec<F>], v: &[F]) -> Vec<F> {
+    m.iter()
+        .map(|row| {
+            let mut acc = F::zero();
+            for (x, y) in row.iter().zip(v.iter()) {
+                let mut tmp = x.clone();
+                tmp.mul_assign(y);
+                acc.add_assign(&tmp);
+            }
+            acc
+        })
+        .collect()
+}
+
+// Solves `a * x = b` by Gaussian elimination, `None` if `a` is singular.
+fn solve<F: PrimeField>(a: &[Vec<F>], b: &[F], inv_exp: &[u64; 4]) -> Option<Vec<F>> {
+    let n = a.len();
+    let mut rows: Vec<Vec<F>> = a
+        .iter()
+        .zip(b.iter())
+        .map(|(row, b)| {
+            let mut row = row.clone();
+            row.push(b.clone());
+            row
+        })
+        .collect();
+
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| rows[r][col] != F::zero())?;
+        rows.swap(col, pivot);
+
+        let inv = rows[col][col].pow_words_le(inv_exp);
+        for x in rows[col].iter_mut() {
+            x.mul_assign(&inv);
+        }
+
+        let pivot_row = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r == col || row[col] == F::zero() {
+                continue;
+            }
+            let factor = row[col].clone();
+            for (x, y) in row.iter_mut().zip(pivot_row.iter()) {
+                let mut tmp = factor.clone();
+                tmp.mul_assign(y);
+                x.sub_assign(&tmp);
+            }
+        }
+    }
+
+    Some(rows.into_iter().map(|row| row[n].clone()).collect())
+}
+
+// The monic annihilator of e_0 from the Krylov sequence e_0, M e_0, ..., M^t e_0. It equals the
+// minimal polynomial whenever that one is irreducible of degree t, and `None` means the Krylov
+// vectors are dependent, so the minimal polynomial cannot be irreducible of degree t.
+fn minimal_polynomial<F: PrimeField>(m: &[Vec<F>], inv_exp: &[u64; 4]) -> Option<Vec<F>> {
+    let t = m.len();
+    let mut krylov = Vec::with_capacity(t + 1);
+    let mut v = vec![F::zero(); t];
+    v[0] = F::one();
+    for _ in 0..=t {
+        let next = mat_vec(m, &v);
+        krylov.push(core::mem::replace(&mut v, next));
+    }
+
+    // Columns are the first t Krylov vectors, the right-hand side is -M^t e_0.
+    let a: Vec<Vec<F>> = (0..t)
+        .map(|i| (0..t).map(|j| krylov[j][i].clone()).collect())
+        .collect();
+    let b: Vec<F> = krylov[t].iter().map(|x| x.negate()).collect();
+    let mut f = solve(&a, &b, inv_exp)?;
+    f.push(F::one());
+    Some(f)
+}
+
+// Rabin's test for a monic f of degree n: x^(p^n) = x mod f, and gcd(x^(p^(n/q)) - x, f) = 1
+// for every prime q dividing n.
+fn is_irreducible<F: PrimeField>(f: &[F], inv_exp: &[u64; 4]) -> bool {
+    let n = f.len() - 1;
+    let mut x = vec![F::zero(); n];
+    x[1] = F::one();
+
+    // x^p mod f by square-and-multiply, then the Frobenius map g -> g^p = sum g_j (x^p)^j.
+    let p = F::modulus();
+    let mut x_p = vec![F::zero(); n];
+    x_p[0] = F::one();
+    for i in (0..p.bits()).rev() {
+        x_p = poly_mul_mod(&x_p, &x_p, f);
+        if p.bit(i) {
+            x_p = poly_mul_mod(&x_p, &x, f);
+        }
+    }
+    let mut frobenius = Vec::with_capacity(n);
+    let mut power = vec![F::zero(); n];
+    power[0] = F::one();
+    for _ in 0..n {
+        let next = poly_mul_mod(&power, &x_p, f);
+        frobenius.push(core::mem::replace(&mut power, next));
+    }
+
+    let mut x_p_i = vec![x.clone()];
+    for _ in 0..n {
+        let prev = x_p_i.last().expect("non-empty");
+        let mut next = vec![F::zero(); n];
+        for (g, row) in prev.iter().zip(frobenius.iter()) {
+            for (out, r) in next.iter_mut().zip(row.iter()) {
+                let mut tmp = g.clone();
+                tmp.mul_assign(r);
+                out.add_assign(&tmp);
+            }
+        }
+        x_p_i.push(next);
+    }
+    if x_p_i[n] != x {
+        return false;
+    }
+
+    prime_factors(n).into_iter().all(|q| {
+        let mut g = x_p_i[n / q].clone();
+        g[1].sub_assign(&F::one());
+        poly_gcd_is_one(g, f.to_vec(), inv_exp)
+    })
+}
+
+// a * b mod f for a monic f of degree n, with a and b of length n.
+fn poly_mul_mod<F: PrimeField>(a: &[F], b: &[F], f: &[F]) -> Vec<F> {
+    let n = f.len() - 1;
+    let mut prod = vec![F::zero(); 2 * n - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            let mut tmp = x.clone();
+            tmp.mul_assign(y);
+            prod[i + j].add_assign(&tmp);
+        }
+    }
+    for i in (n..prod.len()).rev() {
+        let coeff = prod[i].clone();
+        for (j, c) in f.iter().take(n).enumerate() {
+            let mut tmp = coeff.clone();
+            tmp.mul_assign(c);
+            prod[i - n + j].sub_assign(&tmp);
+        }
+    }
+    prod.truncate(n);
+    prod
+}
+
+fn poly_gcd_is_one<F: PrimeField>(mut a: Vec<F>, mut b: Vec<F>, inv_exp: &[u64; 4]) -> bool {
+    trim(&mut a);
+    trim(&mut b);
+    while !b.is_empty() {
+        // a mod b
+        let inv = b.last().expect("non-empty").pow_words_le(inv_exp);
+        while a.len() >= b.len() {
+            let mut coeff = a.last().expect("non-empty").clone();
+            coeff.mul_assign(&inv);
+            let shift = a.len() - b.len();
+            for (j, c) in b.iter().enumerate() {
+                let mut tmp = coeff.clone();
+                tmp.mul_assign(c);
+                a[shift + j].sub_assign(&tmp);
+            }
+            a.pop();
+            trim(&mut a);
+        }
+        core::mem::swap(&mut a, &mut b);
+    }
+    a.len() == 1
+}
+
+fn trim<F: PrimeField>(a: &mut Vec<F>) {
+    while a.last().is_some_and(|c| *c == F::zero()) {
+        a.pop();
+    }
+}
+
+fn prime_factors(mut n: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    let mut q = 2;
+    while n > 1 {
+        if n.is_multiple_of(q) {
+            factors.push(q);
+            while n.is_multiple_of(q) {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::instances::{
+        POSEIDON2_BABYBEAR_16_PARAMS, POSEIDON2_GOLDILOCKS_12_PARAMS,
+        POSEIDON2_GOLDILOCKS_8_PARAMS, POSEIDON2_KOALABEAR_16_PARAMS,
+        POSEIDON2_KOALABEAR_24_PARAMS, POSEIDON2_MERSENNE31_16_PARAMS,
+        POSEIDON2_MERSENNE31_24_PARAMS,
+    };
+    use super::{get_mat_internal_diag_m_1, has_irreducible_min_polys};
+    use crate::fields::bn254::Bn254;
+    use crate::fields::goldilocks::Goldilocks;
+    use crate::fields::FieldElement;
+
+    #[test]
+    fn shipped_internal_diagonals_have_irreducible_min_polys() {
+        // BabyBear t = 24 is left out: its M_I does not meet this sufficient condition.
+        assert!(has_irreducible_min_polys(
+            &POSEIDON2_GOLDILOCKS_8_PARAMS.mat_internal_diag_m_1
+        ));
+        assert!(has_irreducible_min_polys(
+            &POSEIDON2_GOLDILOCKS_12_PARAMS.mat_internal_diag_m_1
+        ));
+        assert!(has_irreducible_min_polys(
+            &POSEIDON2_BABYBEAR_16_PARAMS.mat_internal_diag_m_1
+        ));
+        assert!(has_irreducible_min_polys(
+            &POSEIDON2_KOALABEAR_16_PARAMS.mat_internal_diag_m_1
+        ));
+        assert!(has_irreducible_min_polys(
+            &POSEIDON2_KOALABEAR_24_PARAMS.mat_internal_diag_m_1
+        ));
+        assert!(has_irreducible_min_polys(
+            &POSEIDON2_MERSENNE31_16_PARAMS.mat_internal_diag_m_1
+        ));
+        assert!(has_irreducible_min_polys(
+            &POSEIDON2_MERSENNE31_24_PARAMS.mat_internal_diag_m_1
+        ));
+    }
+
+    #[test]
+    fn generated_internal_diagonals_have_irreducible_min_polys() {
+        let diag = get_mat_internal_diag_m_1::<Bn254>(4);
+        assert!(has_irreducible_min_polys(&diag));
+        let diag = get_mat_internal_diag_m_1::<Goldilocks>(20);
+        assert!(has_irreducible_min_polys(&diag));
+
+        // Equal diagonal entries leave e_0 - e_1 as an eigenvector.
+        let diag = [1u64, 1, 2, 3].map(Goldilocks::from_u64);
+        assert!(!has_irreducible_min_polys(&diag));
+    }
+}
@@ -1,8 +1,8 @@
 use super::reinforced_concrete::ReinforcedConcreteParams;
 use crate::fields::bls12_381::Bls12_381;
 use crate::fields::bn254::Bn254;
+use alloc::{sync::Arc, vec, vec::Vec};
 use lazy_static::lazy_static;
-use std::sync::Arc;
 
 lazy_static! {
     // BLS12
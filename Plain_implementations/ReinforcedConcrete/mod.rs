@@ -1,2 +1,3 @@
 pub mod instances;
 pub mod reinforced_concrete;
+pub mod reinforced_concrete_params;
@@ -1,11 +1,15 @@
+use super::reinforced_concrete_params::get_mds_matrix;
+use crate::constant_time::{scan_u16, LookupMode};
 use crate::fields::{biguint_to_limbs_le_4, PrimeFieldWords};
+use alloc::{borrow::ToOwned, sync::Arc, vec, vec::Vec};
+use core::cmp::Ordering;
 use sha3::digest::{ExtendableOutput, Update, XofReader};
 use sha3::Shake128;
-use std::cmp::Ordering;
-use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 pub struct ReinforcedConcreteParams<F: PrimeFieldWords> {
+    pub(crate) t: usize,
+    pub(crate) mds: Vec<Vec<F>>,
     pub(crate) round_constants: Vec<Vec<F>>, // [round_idx][state_idx]
     pub(crate) alphas: Vec<u16>,
     pub(crate) betas: Vec<F>,
@@ -14,22 +18,25 @@ pub struct ReinforcedConcreteParams<F: PrimeFieldWords> {
 }
 
 impl<F: PrimeFieldWords> ReinforcedConcreteParams<F> {
-    pub const T: usize = 3;
     pub const PRE_ROUNDS: usize = 3;
     pub const POST_ROUNDS: usize = 3;
     pub const TOTAL_ROUNDS: usize = Self::PRE_ROUNDS + Self::POST_ROUNDS + 1;
     pub const INIT_SHAKE: &'static str = "ReinforcedConcrete";
 
+    // The width is one more than the number of (alpha, beta) pairs used by Bricks.
     pub fn new(si: &[u16], sbox: &[u16], alphas: &[u16], betas: &[u16]) -> Self {
-        assert_eq!(alphas.len(), Self::T - 1);
-        assert_eq!(betas.len(), Self::T - 1);
+        assert!(!alphas.is_empty(), "state width must be at least 2");
+        assert_eq!(alphas.len(), betas.len());
+        let t = alphas.len() + 1;
 
         let mut shake = Self::init_shake();
-        let round_constants = Self::instantiate_rc(&mut shake);
+        let round_constants = Self::instantiate_rc(&mut shake, t);
 
         let betas_field = betas.iter().map(|b| F::from_u64(*b as u64)).collect();
 
         ReinforcedConcreteParams {
+            t,
+            mds: get_mds_matrix(t),
             round_constants,
             alphas: alphas.to_owned(),
             betas: betas_field,
@@ -51,11 +58,11 @@ impl<F: PrimeFieldWords> ReinforcedConcreteParams<F> {
         shake.finalize_xof()
     }
 
-    // Build the full round-constant matrix with shape [TOTAL_ROUNDS + 1][T].
-    fn instantiate_rc(shake: &mut dyn XofReader) -> Vec<Vec<F>> {
+    // Build the full round-constant matrix with shape [TOTAL_ROUNDS + 1][t].
+    fn instantiate_rc(shake: &mut dyn XofReader, t: usize) -> Vec<Vec<F>> {
         (0..=Self::TOTAL_ROUNDS)
             .map(|_| {
-                (0..Self::T)
+                (0..t)
                     .map(|_| Self::field_element_from_shake(shake))
                     .collect()
             })
@@ -105,21 +112,28 @@ impl<F: PrimeFieldWords> ReinforcedConcreteParams<F> {
 #[derive(Clone, Debug)]
 pub struct ReinforcedConcrete<F: PrimeFieldWords> {
     pub(crate) params: Arc<ReinforcedConcreteParams<F>>,
+    pub(crate) lookup: LookupMode,
 }
 
 impl<F: PrimeFieldWords> ReinforcedConcrete<F> {
     pub fn new(params: &Arc<ReinforcedConcreteParams<F>>) -> Self {
+        Self::new_with_lookup(params, LookupMode::Table)
+    }
+
+    /// With `LookupMode::ConstantTime`, every digit lookup in Bars reads the whole S-box.
+    pub fn new_with_lookup(params: &Arc<ReinforcedConcreteParams<F>>, lookup: LookupMode) -> Self {
         ReinforcedConcrete {
             params: Arc::clone(params),
+            lookup,
         }
     }
 
     pub fn get_t(&self) -> usize {
-        ReinforcedConcreteParams::<F>::T
+        self.params.t
     }
 
     pub fn permutation(&self, input: &[F]) -> Vec<F> {
-        assert_eq!(input.len(), ReinforcedConcreteParams::<F>::T);
+        assert_eq!(input.len(), self.params.t);
 
         let mut state = input.to_vec();
         self.concrete_in_place(&mut state, 0);
@@ -142,18 +156,32 @@ impl<F: PrimeFieldWords> ReinforcedConcrete<F> {
         state
     }
 
-    fn concrete_in_place(&self, state: &mut [F], round: usize) {
-        // Multiplication by circulant(2,1,1,...) is state + sum(state).
-        let mut sum = F::zero();
-        for x in state.iter() {
-            sum.add_assign(x);
+    pub(crate) fn concrete_in_place(&self, state: &mut [F], round: usize) {
+        if self.params.t <= 3 {
+            // Multiplication by circulant(2,1,1) is state + sum(state).
+            let mut sum = F::zero();
+            for x in state.iter() {
+                sum.add_assign(x);
+            }
+            for x in state.iter_mut() {
+                x.add_assign(&sum);
+            }
+        } else {
+            let input = state.to_vec();
+            for (x, row) in state.iter_mut().zip(self.params.mds.iter()) {
+                *x = F::zero();
+                for (m, y) in row.iter().zip(input.iter()) {
+                    let mut tmp = m.clone();
+                    tmp.mul_assign(y);
+                    x.add_assign(&tmp);
+                }
+            }
         }
 
         for (x, rc) in state
             .iter_mut()
             .zip(self.params.round_constants[round].iter())
         {
-            x.add_assign(&sum);
             x.add_assign(rc);
         }
     }
@@ -189,7 +217,10 @@ impl<F: PrimeFieldWords> ReinforcedConcrete<F> {
         for x in out.iter_mut() {
             let mut digits = self.decompose(x);
             for d in digits.iter_mut() {
-                *d = self.params.sbox[*d as usize];
+                *d = match self.lookup {
+                    LookupMode::Table => self.params.sbox[*d as usize],
+                    LookupMode::ConstantTime => scan_u16(&self.params.sbox, *d),
+                };
             }
             *x = self.compose(&digits);
         }
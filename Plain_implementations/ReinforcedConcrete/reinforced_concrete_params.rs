@@ -0,0 +1,344 @@
+use super::reinforced_concrete::ReinforcedConcreteParams;
+use crate::fields::PrimeFieldWords;
+use crate::utils::modinv;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive, Zero};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+
+pub const INIT_SHAKE: &str = "ReinforcedConcreteBars";
+
+/// Number of Bars digits, as in the BN254 and BLS12-381 instances.
+pub const NUM_DIGITS: usize = 27;
+
+/// Instantiation over a ~256-bit prime field that has no reference tables. The decomposition
+/// follows the Reinforced Concrete paper (a prime `v` below all digits of `p - 1`); the s-box on
+/// `[0, v)` is a SHAKE-derived permutation instead of the paper's hand-picked table. The round
+/// count is not derived here: every width keeps the reference `t = 3` rounds, so no generated
+/// instance is shipped.
+impl<F: PrimeFieldWords> ReinforcedConcreteParams<F> {
+    pub fn generate(t: usize) -> Self {
+        let p = F::modulus();
+        let (v, si) = get_decomposition(&p, NUM_DIGITS)
+            .expect("no valid Bars decomposition for this modulus");
+        let sbox = get_sbox(&p, v);
+        Self::generate_with_bars(t, &si, &sbox)
+    }
+
+    /// Width `t` with the given Bars tables and the Bricks coefficients of [`get_alphas_betas`].
+    pub fn generate_with_bars(t: usize, si: &[u16], sbox: &[u16]) -> Self {
+        let (alphas, betas) = get_alphas_betas(&F::modulus(), t);
+        Self::new(si, sbox, &alphas, &betas)
+    }
+}
+
+/// Searches, for decreasing primes `v` below `p^(1/n)`, for `n` digit sizes `s_i` such that every
+/// digit of `p - 1` is at least `v`. Digits are picked from the least significant one, each close
+/// to the `k`-th root of what remains, and the most significant size is the remaining quotient
+/// plus one. Returns `v` and the sizes, most significant first.
+pub fn get_decomposition(p: &BigUint, n: usize) -> Option<(u16, Vec<u16>)> {
+    let root = p.nth_root(n as u32).to_u16()?;
+
+    'v: for v in (3..root).rev().filter(|&v| is_prime(v)) {
+        let mut rem = p - 1u32;
+        let mut si = Vec::with_capacity(n);
+
+        for k in (2..=n).rev() {
+            let target = rem.nth_root(k as u32).to_u32()?;
+            let mut found = None;
+            for delta in 0..target {
+                for c in [target - delta, target + delta + 1] {
+                    if c > v as u32 && c <= u16::MAX as u32 && &rem % c >= BigUint::from(v) {
+                        found = Some(c as u16);
+                        break;
+                    }
+                }
+                if found.is_some() || target - delta <= v as u32 {
+                    break;
+                }
+            }
+            let Some(s) = found else { continue 'v };
+            si.push(s);
+            rem /= s;
+        }
+
+        match rem.to_u16() {
+            Some(top) if top >= v && top < u16::MAX => si.push(top + 1),
+            _ => continue,
+        }
+        si.reverse();
+        return Some((v, si));
+    }
+    None
+}
+
+/// A permutation of `[0, v)` from a Fisher-Yates shuffle driven by SHAKE128, seeded with
+/// `"ReinforcedConcreteBars"` followed by the little-endian 64-bit limbs of `p`.
+pub fn get_sbox(p: &BigUint, v: u16) -> Vec<u16> {
+    let mut shake = Shake128::default();
+    shake.update(INIT_SHAKE.as_bytes());
+    for limb in p.to_u64_digits() {
+        shake.update(&u64::to_le_bytes(limb));
+    }
+    let mut shake = shake.finalize_xof();
+
+    let mut sbox: Vec<u16> = (0..v).collect();
+    for i in (1..v as usize).rev() {
+        let mask = u16::MAX >> (i as u16).leading_zeros();
+        let j = loop {
+            let mut buf = [0u8; 2];
+            shake.read(&mut buf);
+            let j = u16::from_le_bytes(buf) & mask;
+            if j as usize <= i {
+                break j as usize;
+            }
+        };
+        sbox.swap(i, j);
+    }
+    sbox
+}
+
+/// Bricks coefficients for width `t`: pair `i` starts at `(2i + 1, 2i + 2)` and increases `beta`
+/// until `alpha^2 - 4 beta` is a non-residue, so that `x^2 + alpha x + beta` has no root.
+pub fn get_alphas_betas(p: &BigUint, t: usize) -> (Vec<u16>, Vec<u16>) {
+    assert!(t >= 2, "state width must be at least 2");
+    let exp = (p - 1u32) >> 1;
+
+    (0..t as u16 - 1)
+        .map(|i| {
+            let alpha = 2 * i + 1;
+            let mut beta = 2 * i + 2;
+            loop {
+                let disc = (BigUint::from(alpha as u32 * alpha as u32) + p * 4u32
+                    - BigUint::from(4 * beta as u32))
+                    % p;
+                if !disc.is_zero() && !disc.modpow(&exp, p).is_one() {
+                    break (alpha, beta);
+                }
+                beta += 1;
+            }
+        })
+        .unzip()
+}
+
+/// The Concrete matrix for width `t`. Up to `t = 3` this is the paper's `circulant(2, 1, 1)`;
+/// from `t = 4` on that circulant has singular `2 x 2` minors, so wider states use the Cauchy
+/// matrix `1 / (i + j + t)`, which is MDS for `p > 2t`.
+pub fn get_mds_matrix<F: PrimeFieldWords>(t: usize) -> Vec<Vec<F>> {
+    if t <= 3 {
+        return (0..t)
+            .map(|i| {
+                (0..t)
+                    .map(|j| F::from_u64(if i == j { 2 } else { 1 }))
+                    .collect()
+            })
+            .collect();
+    }
+
+    let p = F::modulus();
+    (0..t)
+        .map(|i| {
+            (0..t)
+                .map(|j| F::from_biguint(&modinv(&BigUint::from(i + j + t), &p)))
+                .collect()
+        })
+        .collect()
+}
+
+fn is_prime(n: u16) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::instances::{BLS12_SI, BN256_SBOX, BN256_SI};
+    use super::super::reinforced_concrete::{ReinforcedConcrete, ReinforcedConcreteParams};
+    use super::{
+        get_alphas_betas, get_decomposition, get_mds_matrix, get_sbox, is_prime, NUM_DIGITS,
+    };
+    use crate::fields::bls12_381::Bls12_381;
+    use crate::fields::bn254::Bn254;
+    use crate::fields::felt252::Felt252;
+    use crate::fields::{FieldElement, PrimeField};
+    use num_bigint::BigUint;
+    use std::sync::Arc;
+
+    // Digits of `p - 1`, most significant first.
+    fn digits(p: &BigUint, si: &[u16]) -> Vec<u16> {
+        let mut rem = p - 1u32;
+        let mut out = vec![0u16; si.len()];
+        for i in (1..si.len()).rev() {
+            out[i] = (&rem % si[i]).try_into().unwrap();
+            rem /= si[i];
+        }
+        out[0] = rem.try_into().unwrap();
+        out
+    }
+
+    fn assert_valid(p: &BigUint, v: u16, si: &[u16]) {
+        assert!(is_prime(v));
+        assert_eq!(si.len(), NUM_DIGITS);
+        assert!(si.iter().map(|&s| BigUint::from(s)).product::<BigUint>() >= *p);
+        assert_eq!(digits(p, si)[0] + 1, si[0]);
+        assert!(digits(p, si).iter().all(|&d| d >= v));
+    }
+
+    #[test]
+    fn decompositions_are_valid() {
+        // The shipped tables satisfy the same condition with their s-box sizes.
+        assert_valid(&Bn254::modulus(), 641, &BN256_SI);
+        assert_valid(&Bls12_381::modulus(), 659, &BLS12_SI);
+
+        let moduli = [
+            Bn254::modulus(),
+            Bls12_381::modulus(),
+            Felt252::modulus(),
+            // Pallas, Vesta and BLS12-377.
+            BigUint::parse_bytes(
+                b"40000000000000000000000000000000224698fc094cf91b992d30ed00000001",
+                16,
+            )
+            .unwrap(),
+            BigUint::parse_bytes(
+                b"40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001",
+                16,
+            )
+            .unwrap(),
+            BigUint::parse_bytes(
+                b"12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001",
+                16,
+            )
+            .unwrap(),
+        ];
+        for p in moduli {
+            let (v, si) = get_decomposition(&p, NUM_DIGITS).unwrap();
+            assert_valid(&p, v, &si);
+
+            let mut sbox = get_sbox(&p, v);
+            sbox.sort_unstable();
+            assert!(sbox.iter().copied().eq(0..v));
+        }
+    }
+
+    #[test]
+    fn alphas_betas_extend_shipped_ones() {
+        let shipped = (vec![1, 3], vec![2, 4]);
+        assert_eq!(get_alphas_betas(&Bn254::modulus(), 3), shipped);
+        assert_eq!(get_alphas_betas(&Bls12_381::modulus(), 3), shipped);
+
+        let (alphas, betas) = get_alphas_betas(&Bn254::modulus(), 5);
+        assert_eq!(alphas[..2], shipped.0[..]);
+        assert_eq!(betas[..2], shipped.1[..]);
+    }
+
+    #[test]
+    fn generated_instances_permute() {
+        for t in [2, 4, 5] {
+            let params = ReinforcedConcreteParams::<Bn254>::generate_with_bars(
+                t,
+                &BN256_SI,
+                &BN256_SBOX,
+            );
+            let perm = ReinforcedConcrete::new(&Arc::new(params));
+            assert_eq!(perm.get_t(), t);
+            let input: Vec<Bn254> = (0..t as u64).map(Bn254::from_u64).collect();
+            assert_ne!(perm.permutation(&input), input);
+        }
+
+        let params = ReinforcedConcreteParams::<Felt252>::generate(3);
+        let perm = ReinforcedConcrete::new(&Arc::new(params));
+        let input: Vec<Felt252> = (0..3).map(Felt252::from_u64).collect();
+        assert_ne!(perm.permutation(&input), input);
+    }
+
+    fn is_invertible(mat: &[Vec<Bn254>]) -> bool {
+        let mut m = mat.to_vec();
+        let n = m.len();
+        for col in 0..n {
+            let Some(pivot) = (col..n).find(|&row| m[row][col] != Bn254::zero()) else {
+                return false;
+            };
+            m.swap(col, pivot);
+            let inv = m[col][col].inverse().unwrap();
+            let (top, rest) = m.split_at_mut(col + 1);
+            let pivot_row = &top[col];
+            for row in rest.iter_mut() {
+                let mut factor = row[col];
+                factor.mul_assign(&inv);
+                for (x, y) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                    let mut tmp = *y;
+                    tmp.mul_assign(&factor);
+                    x.sub_assign(&tmp);
+                }
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn mds_matrices_are_mds() {
+        for t in 2..=5 {
+            let mds = get_mds_matrix::<Bn254>(t);
+            // Every square submatrix, indexed by equally sized row and column masks.
+            for rows in 1u32..(1 << t) {
+                for cols in (1u32..(1 << t)).filter(|c| c.count_ones() == rows.count_ones()) {
+                    let sub: Vec<Vec<Bn254>> = (0..t)
+                        .filter(|i| rows & (1 << i) != 0)
+                        .map(|i| {
+                            (0..t)
+                                .filter(|j| cols & (1 << j) != 0)
+                                .map(|j| mds[i][j])
+                                .collect()
+                        })
+                        .collect();
+                    assert!(is_invertible(&sub), "t = {t}, rows {rows:b}, cols {cols:b}");
+                }
+            }
+        }
+
+        // circulant(2, 1, 1, 1) is not MDS: rows 0, 1 and columns 2, 3 give [[1, 1], [1, 1]].
+        let circulant: Vec<Vec<Bn254>> = (0..4)
+            .map(|i| (0..4).map(|j| Bn254::from_u64(1 + (i == j) as u64)).collect())
+            .collect();
+        assert!(!is_invertible(&[
+            circulant[0][2..].to_vec(),
+            circulant[1][2..].to_vec(),
+        ]));
+    }
+
+    #[test]
+    fn concrete_layer_multiplies_by_mds() {
+        for t in 2..=5 {
+            let params = Arc::new(ReinforcedConcreteParams::<Bn254>::generate_with_bars(
+                t,
+                &BN256_SI,
+                &BN256_SBOX,
+            ));
+            let perm = ReinforcedConcrete::new(&params);
+            let input: Vec<Bn254> = (0..params.t as u64)
+                .map(|i| Bn254::from_u64(3 * i + 7))
+                .collect();
+            let expected: Vec<Bn254> = params
+                .mds
+                .iter()
+                .zip(params.round_constants[1].iter())
+                .map(|(row, rc)| {
+                    let mut acc = *rc;
+                    for (m, x) in row.iter().zip(input.iter()) {
+                        let mut tmp = *m;
+                        tmp.mul_assign(x);
+                        acc.add_assign(&tmp);
+                    }
+                    acc
+                })
+                .collect();
+
+            let mut state = input.clone();
+            perm.concrete_in_place(&mut state, 1);
+            assert_eq!(state, expected);
+        }
+    }
+}
@@ -3,9 +3,9 @@ use crate::fields::bls12_381::Bls12_381;
 use crate::fields::bn254::Bn254;
 use crate::fields::goldilocks::Goldilocks;
 use crate::fields::PrimeField;
+use alloc::{sync::Arc, vec, vec::Vec};
 use lazy_static::lazy_static;
 use num_bigint::BigUint;
-use std::sync::Arc;
 
 fn f_from_hex<F: PrimeField>(hex: &str) -> Option<F> {
     let s = hex.strip_prefix("0x").unwrap_or(hex);
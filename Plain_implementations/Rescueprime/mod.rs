@@ -1,2 +1,3 @@
 pub mod instances;
 pub mod rescue_prime;
+pub mod rescue_prime_params;
@@ -1,5 +1,5 @@
 use crate::fields::FieldElement;
-use std::sync::Arc;
+use alloc::{borrow::ToOwned, sync::Arc, vec, vec::Vec};
 
 #[derive(Clone, Debug)]
 pub struct RescuePrimeParams<F: FieldElement> {
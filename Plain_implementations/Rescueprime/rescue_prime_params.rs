@@ -0,0 +1,182 @@
+use super::rescue_prime::RescuePrimeParams;
+use crate::fields::{biguint_to_limbs_le_4, PrimeField};
+use crate::utils::{binomial, modinv, smallest_invertible_power};
+use alloc::{format, vec, vec::Vec};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+/// Deterministic instantiation following the Rescue-Prime specification
+/// (Szepieniec, Ashur, Dhooghe, "Rescue-Prime: a Standard Specification (SoK)").
+impl<F: PrimeField> RescuePrimeParams<F> {
+    pub fn generate(t: usize, capacity: usize, security_level: usize) -> Self {
+        assert!(capacity > 0 && capacity < t);
+        let p = F::modulus();
+        let (d, d_inv) = get_alphas(&p);
+        let rounds = get_number_of_rounds(t, capacity, security_level, d);
+        let mds = get_mds_matrix::<F>(&p, t);
+        let round_constants =
+            get_round_constants::<F>(&p, t, capacity, security_level, rounds);
+
+        RescuePrimeParams::new(
+            t,
+            d,
+            biguint_to_limbs_le_4(&d_inv),
+            rounds,
+            &mds,
+            &round_constants,
+        )
+    }
+}
+
+/// Smallest `d >= 3` with `gcd(d, p - 1) = 1`, together with `d^-1 mod (p - 1)`.
+pub fn get_alphas(p: &BigUint) -> (u64, BigUint) {
+    smallest_invertible_power(p)
+}
+
+/// Rounds from the Groebner-basis bound, at least 5, plus a 50% security margin.
+pub fn get_number_of_rounds(t: usize, capacity: usize, security_level: usize, d: u64) -> usize {
+    let rate = t - capacity;
+    let target = BigUint::one() << security_level;
+    let dcon = |n: usize| ((d as usize - 1) * t * (n - 1)) / 2 + 2;
+    let v = |n: usize| t * (n - 1) + rate;
+
+    let mut l1 = 1;
+    while l1 < 25 {
+        let bound = binomial(v(l1) + dcon(l1), v(l1));
+        if &bound * &bound > target {
+            break;
+        }
+        l1 += 1;
+    }
+
+    (3 * l1.max(5)).div_ceil(2)
+}
+
+/// MDS matrix from the systematic generator matrix of a Reed-Solomon code: the transposed right
+/// half of the echelon form of the `t x 2t` Vandermonde matrix in the primitive element.
+pub fn get_mds_matrix<F: PrimeField>(p: &BigUint, t: usize) -> Vec<Vec<F>> {
+    let g = F::generator();
+    let mut v: Vec<Vec<BigUint>> = (0..t)
+        .map(|i| {
+            (0..2 * t)
+                .map(|j| g.modpow(&BigUint::from(i * j), p))
+                .collect()
+        })
+        .collect();
+
+    // Vandermonde rows are linearly independent, so the pivots are the first t columns.
+    for col in 0..t {
+        let pivot = (col..t)
+            .find(|&r| !v[r][col].is_zero())
+            .expect("Vandermonde matrix has full rank");
+        v.swap(col, pivot);
+
+        let inv = modinv(&v[col][col], p);
+        for x in v[col].iter_mut() {
+            *x = (&*x * &inv) % p;
+        }
+
+        let pivot_row = v[col].clone();
+        for (r, row) in v.iter_mut().enumerate() {
+            if r == col || row[col].is_zero() {
+                continue;
+            }
+            let factor = row[col].clone();
+            for (x, y) in row.iter_mut().zip(pivot_row.iter()) {
+                let sub = (&factor * y) % p;
+                *x = (&*x + p - sub) % p;
+            }
+        }
+    }
+
+    (0..t)
+        .map(|i| (0..t).map(|j| F::from_biguint(&v[j][t + i])).collect())
+        .collect()
+}
+
+/// `2 * rounds` rows of `t` constants, read from SHAKE256 seeded with
+/// `"Rescue-XLIX(p,t,capacity,security_level)"`.
+pub fn get_round_constants<F: PrimeField>(
+    p: &BigUint,
+    t: usize,
+    capacity: usize,
+    security_level: usize,
+    rounds: usize,
+) -> Vec<Vec<F>> {
+    let bytes_per_int = (p.bits() as usize).div_ceil(8) + 1;
+    let seed = format!("Rescue-XLIX({p},{t},{capacity},{security_level})");
+
+    let mut shake = Shake256::default();
+    shake.update(seed.as_bytes());
+    let mut reader = shake.finalize_xof();
+
+    (0..2 * rounds)
+        .map(|_| {
+            (0..t)
+                .map(|_| {
+                    let mut chunk = vec![0u8; bytes_per_int];
+                    reader.read(&mut chunk);
+                    F::from_biguint(&(BigUint::from_bytes_le(&chunk) % p))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::instances::{
+        RESCUE_PRIME_BLS12_381_2_PARAMS, RESCUE_PRIME_BLS12_381_3_PARAMS,
+        RESCUE_PRIME_BN254_3_PARAMS, RESCUE_PRIME_GOLDILOCKS_12_PARAMS,
+        RESCUE_PRIME_GOLDILOCKS_8_PARAMS,
+    };
+    use super::super::rescue_prime::RescuePrimeParams;
+    use crate::fields::bls12_381::Bls12_381;
+    use crate::fields::bn254::Bn254;
+    use crate::fields::goldilocks::Goldilocks;
+    use crate::fields::FieldElement;
+
+    fn assert_same<F: FieldElement>(got: &RescuePrimeParams<F>, want: &RescuePrimeParams<F>) {
+        assert_eq!(got.t, want.t);
+        assert_eq!(got.d, want.d);
+        assert_eq!(got.d_inv, want.d_inv);
+        assert_eq!(got.rounds, want.rounds);
+        assert_eq!(got.mds, want.mds);
+        assert_eq!(got.round_constants, want.round_constants);
+    }
+
+    #[test]
+    fn generated_params_match_shipped_instances() {
+        assert_same(
+            &RescuePrimeParams::<Bn254>::generate(3, 1, 128),
+            &RESCUE_PRIME_BN254_3_PARAMS,
+        );
+        assert_same(
+            &RescuePrimeParams::<Bls12_381>::generate(3, 1, 128),
+            &RESCUE_PRIME_BLS12_381_3_PARAMS,
+        );
+        assert_same(
+            &RescuePrimeParams::<Goldilocks>::generate(8, 4, 128),
+            &RESCUE_PRIME_GOLDILOCKS_8_PARAMS,
+        );
+        assert_same(
+            &RescuePrimeParams::<Goldilocks>::generate(12, 4, 128),
+            &RESCUE_PRIME_GOLDILOCKS_12_PARAMS,
+        );
+    }
+
+    #[test]
+    fn generated_bls12_381_t2_matches_shipped_round_count() {
+        // The shipped t = 2 tables are not spec-derived (the constants are the t = 3 stream
+        // truncated to two lanes), so only the derived exponents and round count are compared.
+        let got = RescuePrimeParams::<Bls12_381>::generate(2, 1, 128);
+        let want = &*RESCUE_PRIME_BLS12_381_2_PARAMS;
+        assert_eq!(got.d, want.d);
+        assert_eq!(got.d_inv, want.d_inv);
+        assert_eq!(got.rounds, want.rounds);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod instances;
+pub mod rpo;
+pub mod rpx;
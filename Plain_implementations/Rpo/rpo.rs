@@ -0,0 +1,273 @@
+use crate::fields::goldilocks::Goldilocks;
+use crate::fields::FieldElement;
+use alloc::{borrow::ToOwned, sync::Arc, vec, vec::Vec};
+
+pub const DIGEST_SIZE: usize = 4;
+
+pub trait RpoField: FieldElement {
+    fn to_u64(&self) -> u64;
+}
+
+impl RpoField for Goldilocks {
+    fn to_u64(&self) -> u64 {
+        Goldilocks::to_u64(self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RpoParams<F: RpoField> {
+    pub(crate) t: usize,
+    pub(crate) capacity: usize,
+    pub(crate) d_inv: [u64; 4],
+    pub(crate) rounds: usize,
+    pub(crate) mds_row: Vec<u64>, // first row of the circulant MDS matrix
+    pub(crate) ark1: Vec<Vec<F>>, // [round_idx][state_idx], added before the x^7 layer
+    pub(crate) ark2: Vec<Vec<F>>, // [round_idx][state_idx], added before the x^(1/7) layer
+}
+
+impl<F: RpoField> RpoParams<F> {
+    pub fn new(
+        t: usize,
+        capacity: usize,
+        d_inv_words: [u64; 4],
+        rounds: usize,
+        mds_row: &[u64],
+        ark1: &[Vec<F>],
+        ark2: &[Vec<F>],
+    ) -> Self {
+        assert!(capacity + DIGEST_SIZE <= t);
+        assert_eq!(mds_row.len(), t);
+        // The fast MDS multiplication accumulates 32-bit limbs in a u64.
+        assert!(mds_row.iter().sum::<u64>() < (1u64 << 32));
+        assert_eq!(ark1.len(), rounds);
+        assert_eq!(ark2.len(), rounds);
+        for rc in ark1.iter().chain(ark2.iter()) {
+            assert_eq!(rc.len(), t);
+        }
+
+        RpoParams {
+            t,
+            capacity,
+            d_inv: d_inv_words,
+            rounds,
+            mds_row: mds_row.to_owned(),
+            ark1: ark1.to_owned(),
+            ark2: ark2.to_owned(),
+        }
+    }
+
+    pub fn get_rate(&self) -> usize {
+        self.t - self.capacity
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Rpo<F: RpoField> {
+    pub(crate) params: Arc<RpoParams<F>>,
+}
+
+impl<F: RpoField> Rpo<F> {
+    pub fn new(params: &Arc<RpoParams<F>>) -> Self {
+        Rpo {
+            params: Arc::clone(params),
+        }
+    }
+
+    pub fn get_t(&self) -> usize {
+        self.params.t
+    }
+
+    pub fn permutation(&self, input: &[F]) -> Vec<F> {
+        let t = self.params.t;
+        assert_eq!(input.len(), t);
+
+        let mut state = input.to_vec();
+        for r in 0..self.params.rounds {
+            Self::round(&self.params, &mut state, r);
+        }
+        state
+    }
+
+    /// Miden `Rpo256::hash_elements`: the capacity is seeded with `len % rate` and a partial
+    /// last block is zero-padded.
+    pub fn hash_elements(&self, elements: &[F]) -> [F; DIGEST_SIZE] {
+        sponge_hash_elements(&self.params, elements, |state| {
+            for r in 0..self.params.rounds {
+                Self::round(&self.params, state, r);
+            }
+        })
+    }
+
+    /// Miden `Rpo256::merge`: both digests fill the rate, the capacity stays zero.
+    pub fn merge(&self, values: &[[F; DIGEST_SIZE]; 2]) -> [F; DIGEST_SIZE] {
+        let mut state = merge_state(&self.params, values);
+        for r in 0..self.params.rounds {
+            Self::round(&self.params, &mut state, r);
+        }
+        digest(&self.params, &state)
+    }
+
+    /// One RPO round, also used for the FB rounds of RPX.
+    pub(crate) fn round(params: &RpoParams<F>, state: &mut [F], round: usize) {
+        apply_mds(params, state);
+        add_rc(state, &params.ark1[round]);
+        for x in state.iter_mut() {
+            *x = sbox(x);
+        }
+
+        apply_mds(params, state);
+        add_rc(state, &params.ark2[round]);
+        for x in state.iter_mut() {
+            *x = x.pow_words_le(&params.d_inv);
+        }
+    }
+}
+
+pub(crate) fn sponge_hash_elements<F: RpoField, P: Fn(&mut [F])>(
+    params: &RpoParams<F>,
+    elements: &[F],
+    permute: P,
+) -> [F; DIGEST_SIZE] {
+    let rate = params.get_rate();
+    let mut state = vec![F::zero(); params.t];
+    state[0] = F::from_u64((elements.len() % rate) as u64);
+
+    let mut i = 0;
+    for el in elements {
+        state[params.capacity + i] = el.clone();
+        i += 1;
+        if i == rate {
+            permute(&mut state);
+            i = 0;
+        }
+    }
+
+    if i > 0 {
+        for x in state.iter_mut().skip(params.capacity + i) {
+            *x = F::zero();
+        }
+        permute(&mut state);
+    }
+
+    digest(params, &state)
+}
+
+pub(crate) fn merge_state<F: RpoField>(
+    params: &RpoParams<F>,
+    values: &[[F; DIGEST_SIZE]; 2],
+) -> Vec<F> {
+    assert_eq!(params.get_rate(), 2 * DIGEST_SIZE);
+    let mut state = vec![F::zero(); params.t];
+    for (x, v) in state[params.capacity..]
+        .iter_mut()
+        .zip(values.iter().flatten())
+    {
+        *x = v.clone();
+    }
+    state
+}
+
+pub(crate) fn digest<F: RpoField>(params: &RpoParams<F>, state: &[F]) -> [F; DIGEST_SIZE] {
+    core::array::from_fn(|i| state[params.capacity + i].clone())
+}
+
+/// Circulant MDS multiplication with delayed reduction: the matrix entries are small, so each
+/// output is accumulated over the 32-bit halves of the inputs and reduced only once.
+pub(crate) fn apply_mds<F: RpoField>(params: &RpoParams<F>, state: &mut [F]) {
+    let t = params.t;
+    let limbs: Vec<u64> = state.iter().map(|x| x.to_u64()).collect();
+    let shift = F::from_u64(1u64 << 32);
+
+    for (row, out) in state.iter_mut().enumerate() {
+        let mut acc_lo = 0u64;
+        let mut acc_hi = 0u64;
+        for (col, limb) in limbs.iter().enumerate() {
+            let coeff = params.mds_row[(col + t - row) % t];
+            acc_lo += coeff * (limb & 0xFFFF_FFFF);
+            acc_hi += coeff * (limb >> 32);
+        }
+        let mut res = F::from_u64(acc_hi);
+        res.mul_assign(&shift);
+        res.add_assign(&F::from_u64(acc_lo));
+        *out = res;
+    }
+}
+
+pub(crate) fn add_rc<F: FieldElement>(state: &mut [F], rc: &[F]) {
+    for (x, c) in state.iter_mut().zip(rc.iter()) {
+        x.add_assign(c);
+    }
+}
+
+pub(crate) fn sbox<F: FieldElement>(input: &F) -> F {
+    let mut input2 = input.clone();
+    input2.square();
+    let mut out = input2.clone();
+    out.square();
+    out.mul_assign(&input2);
+    out.mul_assign(input);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::instances::RPO_GOLDILOCKS_12_PARAMS;
+    use super::{apply_mds, Rpo};
+    use crate::fields::goldilocks::Goldilocks;
+    use crate::fields::FieldElement;
+
+    #[test]
+    fn fast_mds_matches_dense_matmul() {
+        let params = &*RPO_GOLDILOCKS_12_PARAMS;
+        let mut state: Vec<Goldilocks> = (0..12u64)
+            .map(|i| Goldilocks::from_u64(u64::MAX - 977 * i))
+            .collect();
+
+        let mut expected = vec![Goldilocks::zero(); 12];
+        for (row, out) in expected.iter_mut().enumerate() {
+            for (col, x) in state.iter().enumerate() {
+                let mut tmp = Goldilocks::from_u64(params.mds_row[(col + 12 - row) % 12]);
+                tmp.mul_assign(x);
+                out.add_assign(&tmp);
+            }
+        }
+
+        apply_mds(params, &mut state);
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn hash_elements_matches_miden_reference_vectors() {
+        let rpo = Rpo::new(&RPO_GOLDILOCKS_12_PARAMS);
+        let elements: Vec<Goldilocks> = (0..2u64).map(Goldilocks::from_u64).collect();
+        let expected = [
+            [
+                18126731724905382595u64,
+                7388557040857728717u64,
+                14290750514634285295u64,
+                7852282086160480146u64,
+            ],
+            [
+                10139303045932500183u64,
+                2293916558361785533u64,
+                15496361415980502047u64,
+                17904948502382283940u64,
+            ],
+        ];
+
+        for (i, want) in expected.iter().enumerate() {
+            let got = rpo.hash_elements(&elements[..i + 1]);
+            assert_eq!(got.map(|x| x.to_u64()), *want);
+        }
+    }
+
+    #[test]
+    fn hash_elements_of_two_digests_matches_merge() {
+        let rpo = Rpo::new(&RPO_GOLDILOCKS_12_PARAMS);
+        let a = [1u64, 2, 3, 4].map(Goldilocks::from_u64);
+        let b = [5u64, 6, 7, 8].map(Goldilocks::from_u64);
+        let elements: Vec<Goldilocks> = a.iter().chain(b.iter()).copied().collect();
+
+        assert_eq!(rpo.hash_elements(&elements), rpo.merge(&[a, b]));
+    }
+}
@@ -0,0 +1,229 @@
+use super::rpo::{
+    add_rc, apply_mds, digest, merge_state, sponge_hash_elements, Rpo, RpoField, RpoParams,
+    DIGEST_SIZE,
+};
+use crate::fields::FieldElement;
+use alloc::{sync::Arc, vec::Vec};
+
+/// RPX (Rescue-Prime eXtension) reuses the RPO constants and MDS, but alternates full RPO rounds
+/// with rounds that apply x^7 in the cubic extension F[x]/(x^3 - x - 1), and ends with a
+/// linear round.
+#[derive(Clone, Debug)]
+pub struct Rpx<F: RpoField> {
+    pub(crate) params: Arc<RpoParams<F>>,
+}
+
+impl<F: RpoField> Rpx<F> {
+    pub fn new(params: &Arc<RpoParams<F>>) -> Self {
+        assert_eq!(params.t % 3, 0);
+        assert_eq!(params.rounds % 2, 1);
+        Rpx {
+            params: Arc::clone(params),
+        }
+    }
+
+    pub fn get_t(&self) -> usize {
+        self.params.t
+    }
+
+    pub fn permutation(&self, input: &[F]) -> Vec<F> {
+        let t = self.params.t;
+        assert_eq!(input.len(), t);
+
+        let mut state = input.to_vec();
+        self.permute_in_place(&mut state);
+        state
+    }
+
+    /// Miden `Rpx256::hash_elements`, same sponge conventions as RPO.
+    pub fn hash_elements(&self, elements: &[F]) -> [F; DIGEST_SIZE] {
+        sponge_hash_elements(&self.params, elements, |state| self.permute_in_place(state))
+    }
+
+    /// Miden `Rpx256::merge`, same sponge conventions as RPO.
+    pub fn merge(&self, values: &[[F; DIGEST_SIZE]; 2]) -> [F; DIGEST_SIZE] {
+        let mut state = merge_state(&self.params, values);
+        self.permute_in_place(&mut state);
+        digest(&self.params, &state)
+    }
+
+    fn permute_in_place(&self, state: &mut [F]) {
+        let last = self.params.rounds - 1;
+        for r in 0..last {
+            if r % 2 == 0 {
+                self.fb_round(state, r);
+            } else {
+                self.ext_round(state, r);
+            }
+        }
+        self.final_round(state, last);
+    }
+
+    fn fb_round(&self, state: &mut [F], round: usize) {
+        Rpo::round(&self.params, state, round);
+    }
+
+    fn ext_round(&self, state: &mut [F], round: usize) {
+        add_rc(state, &self.params.ark1[round]);
+        for chunk in state.chunks_exact_mut(3) {
+            let out = ext_exp7([chunk[0].clone(), chunk[1].clone(), chunk[2].clone()]);
+            chunk.clone_from_slice(&out);
+        }
+    }
+
+    fn final_round(&self, state: &mut [F], round: usize) {
+        apply_mds(&self.params, state);
+        add_rc(state, &self.params.ark1[round]);
+    }
+}
+
+fn ext_exp7<F: FieldElement>(x: [F; 3]) -> [F; 3] {
+    let x2 = ext_mul(&x, &x);
+    let x4 = ext_mul(&x2, &x2);
+    let x3 = ext_mul(&x2, &x);
+    ext_mul(&x3, &x4)
+}
+
+// Multiplication in F[x]/(x^3 - x - 1) with x^3 = x + 1 and x^4 = x^2 + x.
+fn ext_mul<F: FieldElement>(a: &[F; 3], b: &[F; 3]) -> [F; 3] {
+    let prod = |x: &F, y: &F| {
+        let mut tmp = x.clone();
+        tmp.mul_assign(y);
+        tmp
+    };
+    let a0b0 = prod(&a[0], &b[0]);
+    let a1b1 = prod(&a[1], &b[1]);
+    let a2b2 = prod(&a[2], &b[2]);
+
+    let mut a1b2_a2b1 = prod(&a[1], &b[2]);
+    a1b2_a2b1.add_assign(&prod(&a[2], &b[1]));
+
+    let mut c0 = a0b0.clone();
+    c0.add_assign(&a1b2_a2b1);
+
+    let mut c1 = prod(&a[0], &b[1]);
+    c1.add_assign(&prod(&a[1], &b[0]));
+    c1.add_assign(&a1b2_a2b1);
+    c1.add_assign(&a2b2);
+
+    let mut c2 = prod(&a[0], &b[2]);
+    c2.add_assign(&a1b1);
+    c2.add_assign(&prod(&a[2], &b[0]));
+    c2.add_assign(&a2b2);
+
+    [c0, c1, c2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::instances::RPX_GOLDILOCKS_12_PARAMS;
+    use super::{ext_exp7, ext_mul, Rpx};
+    use crate::fields::goldilocks::Goldilocks;
+    use crate::fields::FieldElement;
+
+    #[test]
+    fn ext_mul_reduces_by_x3_minus_x_minus_1() {
+        let x = [0u64, 1, 0].map(Goldilocks::from_u64);
+        let x2 = ext_mul(&x, &x);
+        assert_eq!(ext_mul(&x2, &x), [1u64, 1, 0].map(Goldilocks::from_u64));
+
+        let a = [3u64, 5, 7].map(Goldilocks::from_u64);
+        let mut a7 = a;
+        for _ in 1..7 {
+            a7 = ext_mul(&a7, &a);
+        }
+        assert_eq!(ext_exp7(a), a7);
+    }
+
+    // Vectors produced by miden-crypto 0.13 `Rpx256`.
+    #[test]
+    fn permutation_matches_miden_reference_vector() {
+        let rpx = Rpx::new(&RPX_GOLDILOCKS_12_PARAMS);
+        let input: Vec<Goldilocks> = (0..12u64).map(Goldilocks::from_u64).collect();
+        let expected = [
+            3614697924784493998u64,
+            4917065433670799835,
+            12893407190838344317,
+            16769932886818781879,
+            17010299523770013195,
+            9826755761378503206,
+            1872785960340665977,
+            7783788981462778586,
+            45778307605882514,
+            7437259891664617628,
+            17010253034795346176,
+            6863075881906649113,
+        ];
+
+        let output: Vec<u64> = rpx.permutation(&input).iter().map(|x| x.to_u64()).collect();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn hash_elements_matches_miden_reference_vectors() {
+        let rpx = Rpx::new(&RPX_GOLDILOCKS_12_PARAMS);
+        let elements: Vec<Goldilocks> = (0..19u64).map(Goldilocks::from_u64).collect();
+        let expected: [(usize, [u64; 4]); 5] = [
+            (
+                1,
+                [
+                    15293807115397414812,
+                    15290017247514670316,
+                    10548590320248089637,
+                    9459855167724924903,
+                ],
+            ),
+            (
+                2,
+                [
+                    12186327779210739392,
+                    12437198001472812457,
+                    17431583359007807548,
+                    5889070798901825636,
+                ],
+            ),
+            (
+                8,
+                [
+                    12373829276206882697,
+                    10138650388065685463,
+                    15520480835694974951,
+                    2510219987660336228,
+                ],
+            ),
+            (
+                9,
+                [
+                    14898769958092295192,
+                    14076282783168040015,
+                    8476014900264177995,
+                    17336863755113979084,
+                ],
+            ),
+            (
+                19,
+                [
+                    17905682982576162590,
+                    5720278714894771907,
+                    9596600499219832172,
+                    5974292660959196,
+                ],
+            ),
+        ];
+
+        for (len, want) in expected {
+            let got = rpx.hash_elements(&elements[..len]);
+            assert_eq!(got.map(|x| x.to_u64()), want);
+        }
+    }
+
+    #[test]
+    fn hash_elements_of_two_digests_matches_merge() {
+        let rpx = Rpx::new(&RPX_GOLDILOCKS_12_PARAMS);
+        let a = [1u64, 2, 3, 4].map(Goldilocks::from_u64);
+        let b = [5u64, 6, 7, 8].map(Goldilocks::from_u64);
+        let elements: Vec<Goldilocks> = a.iter().chain(b.iter()).copied().collect();
+
+        assert_eq!(rpx.hash_elements(&elements), rpx.merge(&[a, b]));
+    }
+}
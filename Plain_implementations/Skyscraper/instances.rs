@@ -2,8 +2,8 @@ use super::skyscraper::SkyscraperParams;
 use crate::fields::bls12_381::Bls12_381;
 use crate::fields::bn254::Bn254;
 use crate::fields::{biguint_from_limbs_le, PrimeField};
+use alloc::{sync::Arc, vec, vec::Vec};
 use lazy_static::lazy_static;
-use std::sync::Arc;
 
 fn f_from_words<F: PrimeField>(words: [u64; 4]) -> F {
     F::from_biguint(&biguint_from_limbs_le(&words))
@@ -1,16 +1,16 @@
 use crate::fields::{PrimeField, PrimeFieldWords};
-use std::sync::Arc;
+use alloc::{sync::Arc, vec, vec::Vec};
 
-const TOTAL_ROUNDS: usize = 18;
+pub(crate) const TOTAL_ROUNDS: usize = 18;
 const BAR_ROUNDS: [usize; 4] = [6, 7, 10, 11];
 
 #[derive(Clone, Debug)]
 pub struct ExtElem<F: PrimeField> {
-    coeffs: Vec<F>,
+    pub(crate) coeffs: Vec<F>,
 }
 
 impl<F: PrimeField> ExtElem<F> {
-    fn from_coeffs(coeffs: Vec<F>) -> Self {
+    pub(crate) fn from_coeffs(coeffs: Vec<F>) -> Self {
         ExtElem { coeffs }
     }
 
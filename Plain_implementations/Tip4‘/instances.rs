@@ -1,14 +1,21 @@
 use super::tip4::Tip4Params;
 use crate::fields::goldilocks::Goldilocks;
-use crate::fields::FieldElement;
-use crate::utils::modinv;
+use crate::fields::{FieldElement, PrimeField};
+use crate::utils::{field_element_from_shake, modinv};
+use alloc::{sync::Arc, vec::Vec};
 use lazy_static::lazy_static;
 use num_bigint::BigUint;
 use num_traits::ToPrimitive;
-use std::sync::Arc;
+use sha3::{
+    digest::{ExtendableOutput, Update},
+    Shake128,
+};
 
 pub(crate) const NUM_ROUNDS: usize = 5;
 pub(crate) const NUM_SPLIT_AND_LOOKUP: usize = 4;
+pub(crate) const TIP4_STATE_WIDTH: usize = 16;
+
+pub const INIT_SHAKE: &str = "Tip4";
 
 pub(crate) const LOOKUP_TABLE: [u8; 256] = [
     0, 7, 26, 63, 124, 215, 85, 254, 214, 228, 45, 185, 140, 173, 33, 240, 29, 177, 176, 32,
@@ -118,6 +125,19 @@ lazy_static! {
             r_inv,
         })
     };
+    /// The original Tip4: Tip5's width-16 state with the Tip4 round function. It is a
+    /// permutation-only instance, the sponge methods of [`super::tip4::Tip4`] are Tip4'-only.
+    pub static ref TIP4_GOLDILOCKS_PARAMS: Arc<Tip4Params<Goldilocks>> = {
+        let (r, r_inv) = monty_constants();
+        Arc::new(Tip4Params {
+            t: TIP4_STATE_WIDTH,
+            rounds: NUM_ROUNDS,
+            round_constants: get_round_constants(TIP4_STATE_WIDTH, NUM_ROUNDS),
+            mds: get_mds_matrix(TIP4_STATE_WIDTH),
+            r,
+            r_inv,
+        })
+    };
 }
 
 fn monty_constants() -> (Goldilocks, Goldilocks) {
@@ -128,6 +148,36 @@ fn monty_constants() -> (Goldilocks, Goldilocks) {
     (r, r_inv)
 }
 
+/// SHAKE128 seeded with `"Tip4"` followed by the little-endian 64-bit limbs of `p`.
+fn get_round_constants<F: PrimeField>(t: usize, rounds: usize) -> Vec<Vec<F>> {
+    let p = F::modulus();
+    let mut shake = Shake128::default();
+    shake.update(INIT_SHAKE.as_bytes());
+    for limb in p.to_u64_digits() {
+        shake.update(&u64::to_le_bytes(limb));
+    }
+    let mut shake = shake.finalize_xof();
+    (0..rounds)
+        .map(|_| {
+            (0..t)
+                .map(|_| F::from_biguint(&field_element_from_shake(&p, &mut shake)))
+                .collect()
+        })
+        .collect()
+}
+
+/// The Cauchy matrix `1 / (i + j + t)`, which is MDS for `p > 2t`.
+fn get_mds_matrix<F: PrimeField>(t: usize) -> Vec<Vec<F>> {
+    let p = F::modulus();
+    (0..t)
+        .map(|i| {
+            (0..t)
+                .map(|j| F::from_biguint(&modinv(&BigUint::from(i + j + t), &p)))
+                .collect()
+        })
+        .collect()
+}
+
 fn circulant_from_row<F: FieldElement, const N: usize>(row: &[u64; N]) -> Vec<Vec<F>> {
     let t = row.len();
     let mut mat = Vec::with_capacity(t);
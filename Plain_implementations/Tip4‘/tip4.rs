@@ -1,7 +1,17 @@
 use super::instances::{LOOKUP_TABLE, NUM_SPLIT_AND_LOOKUP};
+use crate::constant_time::{scan_u8, LookupMode};
 use crate::fields::goldilocks::Goldilocks;
 use crate::fields::FieldElement;
-use std::sync::Arc;
+use alloc::{sync::Arc, vec, vec::Vec};
+use core::ops::Range;
+
+// Winterfell `Rp64_256` sponge layout, shared by its Tip4' hasher.
+pub const STATE_WIDTH: usize = 12;
+pub const RATE_WIDTH: usize = 8;
+pub const DIGEST_SIZE: usize = 4;
+const CAPACITY_RANGE: Range<usize> = 0..4;
+const RATE_RANGE: Range<usize> = 4..12;
+const DIGEST_RANGE: Range<usize> = 4..8;
 
 pub trait Tip4Field: FieldElement {
     fn to_u64(&self) -> u64;
@@ -26,12 +36,19 @@ pub struct Tip4Params<F: Tip4Field> {
 #[derive(Clone, Debug)]
 pub struct Tip4<F: Tip4Field> {
     pub(crate) params: Arc<Tip4Params<F>>,
+    pub(crate) lookup: LookupMode,
 }
 
 impl<F: Tip4Field> Tip4<F> {
     pub fn new(params: &Arc<Tip4Params<F>>) -> Self {
+        Self::new_with_lookup(params, LookupMode::Table)
+    }
+
+    /// With `LookupMode::ConstantTime`, every byte lookup reads the whole table.
+    pub fn new_with_lookup(params: &Arc<Tip4Params<F>>, lookup: LookupMode) -> Self {
         Tip4 {
             params: Arc::clone(params),
+            lookup,
         }
     }
 
@@ -52,6 +69,96 @@ impl<F: Tip4Field> Tip4<F> {
         state
     }
 
+    /// Winterfell `Hasher::hash`: the bytes are absorbed additively in 7-byte little-endian
+    /// chunks, the last chunk is padded with a single `1` byte and the capacity holds the number
+    /// of chunks.
+    pub fn hash(&self, bytes: &[u8]) -> ElementDigest<F> {
+        let num_elements = bytes.len().div_ceil(7);
+        let mut state = self.sponge_state(num_elements);
+
+        let mut i = 0;
+        for (n, chunk) in bytes.chunks(7).enumerate() {
+            let mut buf = [0u8; 8];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            if n == num_elements - 1 {
+                buf[chunk.len()] = 1;
+            }
+
+            state[RATE_RANGE.start + i].add_assign(&F::from_u64(u64::from_le_bytes(buf)));
+            i += 1;
+            if i == RATE_WIDTH {
+                state = self.permutation(&state);
+                i = 0;
+            }
+        }
+
+        if i > 0 {
+            state = self.permutation(&state);
+        }
+        ElementDigest::from_state(&state)
+    }
+
+    /// Winterfell `ElementHasher::hash_elements`: elements are added into the rate, the capacity
+    /// holds the number of elements and a partial last block is absorbed as is.
+    pub fn hash_elements(&self, elements: &[F]) -> ElementDigest<F> {
+        let mut state = self.sponge_state(elements.len());
+
+        let mut i = 0;
+        for element in elements {
+            state[RATE_RANGE.start + i].add_assign(element);
+            i += 1;
+            if i == RATE_WIDTH {
+                state = self.permutation(&state);
+                i = 0;
+            }
+        }
+
+        if i > 0 {
+            state = self.permutation(&state);
+        }
+        ElementDigest::from_state(&state)
+    }
+
+    /// Winterfell `Hasher::merge`: both digests fill the rate and the capacity holds the rate
+    /// width, which makes it agree with `hash_elements` on the eight digest elements.
+    pub fn merge(&self, values: &[ElementDigest<F>; 2]) -> ElementDigest<F> {
+        let mut state = self.sponge_state(RATE_WIDTH);
+        for (x, v) in state[RATE_RANGE]
+            .iter_mut()
+            .zip(values.iter().flat_map(|d| d.as_elements()))
+        {
+            *x = v.clone();
+        }
+        ElementDigest::from_state(&self.permutation(&state))
+    }
+
+    /// Winterfell `Hasher::merge_with_int`: the seed is followed by `value` split into
+    /// `value mod p` and `value / p`; the capacity holds the number of occupied rate elements.
+    pub fn merge_with_int(&self, seed: &ElementDigest<F>, value: u64) -> ElementDigest<F> {
+        let value_element = F::from_u64(value);
+        let high = value_element.to_u64() != value;
+        let len = DIGEST_SIZE + 1 + high as usize;
+
+        let mut state = self.sponge_state(len);
+        state[DIGEST_RANGE].clone_from_slice(seed.as_elements());
+        state[DIGEST_RANGE.end] = value_element;
+        if high {
+            // A u64 is below 2p, so value / p is 1.
+            state[DIGEST_RANGE.end + 1] = F::one();
+        }
+        ElementDigest::from_state(&self.permutation(&state))
+    }
+
+    fn sponge_state(&self, len: usize) -> Vec<F> {
+        assert_eq!(
+            self.params.t, STATE_WIDTH,
+            "the Winterfell sponge is only defined for Tip4'"
+        );
+        let mut state = vec![F::zero(); STATE_WIDTH];
+        state[CAPACITY_RANGE.start] = F::from_u64(len as u64);
+        state
+    }
+
     fn add_round_constants(&self, state: &mut [F], round: usize) {
         for (el, rc) in state
             .iter_mut()
@@ -75,7 +182,10 @@ impl<F: Tip4Field> Tip4<F> {
         monty.mul_assign(&self.params.r);
         let mut bytes = monty.to_u64().to_le_bytes();
         for b in bytes.iter_mut() {
-            *b = LOOKUP_TABLE[*b as usize];
+            *b = match self.lookup {
+                LookupMode::Table => LOOKUP_TABLE[*b as usize],
+                LookupMode::ConstantTime => scan_u8(&LOOKUP_TABLE, *b),
+            };
         }
         let mut out = F::from_u64(u64::from_le_bytes(bytes));
         out.mul_assign(&self.params.r_inv);
@@ -97,12 +207,56 @@ impl<F: Tip4Field> Tip4<F> {
     }
 }
 
+/// Winterfell `ElementDigest`: four field elements, serialized as their canonical little-endian
+/// `u64` encodings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ElementDigest<F: Tip4Field>([F; DIGEST_SIZE]);
+
+impl<F: Tip4Field> ElementDigest<F> {
+    pub fn new(elements: [F; DIGEST_SIZE]) -> Self {
+        ElementDigest(elements)
+    }
+
+    pub fn as_elements(&self) -> &[F; DIGEST_SIZE] {
+        &self.0
+    }
+
+    pub fn as_bytes(&self) -> [u8; 8 * DIGEST_SIZE] {
+        let mut bytes = [0u8; 8 * DIGEST_SIZE];
+        for (chunk, x) in bytes.chunks_exact_mut(8).zip(self.0.iter()) {
+            chunk.copy_from_slice(&x.to_u64().to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns `None` if an encoded element is not canonical.
+    pub fn from_bytes(bytes: &[u8; 8 * DIGEST_SIZE]) -> Option<Self> {
+        let mut elements = Vec::with_capacity(DIGEST_SIZE);
+        for chunk in bytes.chunks_exact(8) {
+            let value = u64::from_le_bytes(chunk.try_into().expect("chunk has 8 bytes"));
+            let element = F::from_u64(value);
+            if element.to_u64() != value {
+                return None;
+            }
+            elements.push(element);
+        }
+        Some(ElementDigest(elements.try_into().ok()?))
+    }
+
+    fn from_state(state: &[F]) -> Self {
+        ElementDigest(core::array::from_fn(|i| {
+            state[DIGEST_RANGE.start + i].clone()
+        }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::super::instances::TIP4P_GOLDILOCKS_PARAMS;
-    use super::Tip4;
+    use super::super::instances::{TIP4P_GOLDILOCKS_PARAMS, TIP4_GOLDILOCKS_PARAMS};
+    use super::{ElementDigest, Tip4};
     use crate::fields::goldilocks::Goldilocks;
     use crate::fields::FieldElement;
+    use crate::tip5::instances::TIP5_GOLDILOCKS_PARAMS;
 
     #[test]
     fn permutation_matches_tip4p_winterfell_reference_vector() {
@@ -132,4 +286,81 @@ mod tests {
             assert_eq!(got.to_u64(), *want);
         }
     }
+
+    #[test]
+    fn hasher_follows_winterfell_conventions() {
+        let tip4 = Tip4::new(&TIP4P_GOLDILOCKS_PARAMS);
+        let a = ElementDigest::new([1u64, 2, 3, 4].map(Goldilocks::from_u64));
+        let b = ElementDigest::new([5u64, 6, 7, 8].map(Goldilocks::from_u64));
+
+        let elements: Vec<Goldilocks> = a
+            .as_elements()
+            .iter()
+            .chain(b.as_elements())
+            .copied()
+            .collect();
+        assert_eq!(tip4.hash_elements(&elements), tip4.merge(&[a.clone(), b]));
+
+        for value in [
+            0,
+            42,
+            Goldilocks::MODULUS - 1,
+            Goldilocks::MODULUS,
+            u64::MAX,
+        ] {
+            let mut elements = a.as_elements().to_vec();
+            elements.push(Goldilocks::from_u64(value));
+            if value >= Goldilocks::MODULUS {
+                elements.push(Goldilocks::one());
+            }
+            assert_eq!(
+                tip4.hash_elements(&elements),
+                tip4.merge_with_int(&a, value)
+            );
+        }
+
+        // Bytes map to elements in 7-byte chunks, with a 1 byte appended to the last chunk.
+        let bytes: Vec<u8> = (1..=20).collect();
+        let chunks: Vec<Goldilocks> = bytes
+            .chunks(7)
+            .map(|c| {
+                let mut buf = [0u8; 8];
+                buf[..c.len()].copy_from_slice(c);
+                if c.len() < 7 {
+                    buf[c.len()] = 1;
+                }
+                Goldilocks::from_u64(u64::from_le_bytes(buf))
+            })
+            .collect();
+        assert_eq!(tip4.hash(&bytes), tip4.hash_elements(&chunks));
+        assert_ne!(tip4.hash(&[1, 2, 3]), tip4.hash(&[1, 2, 3, 0]));
+
+        let digest = tip4.hash(&bytes);
+        assert_eq!(ElementDigest::from_bytes(&digest.as_bytes()), Some(digest));
+        assert_eq!(ElementDigest::<Goldilocks>::from_bytes(&[0xFF; 32]), None);
+    }
+
+    #[test]
+    fn original_tip4_has_its_own_parameters() {
+        let params = &*TIP4_GOLDILOCKS_PARAMS;
+        assert_eq!(params.t, TIP5_GOLDILOCKS_PARAMS.t);
+        assert_ne!(params.mds, TIP5_GOLDILOCKS_PARAMS.mds);
+        assert_ne!(
+            params.round_constants[..],
+            TIP5_GOLDILOCKS_PARAMS.round_constants[..params.rounds]
+        );
+
+        let perm = Tip4::new(params);
+        let zero = vec![Goldilocks::zero(); params.t];
+        let mut one = zero.clone();
+        one[params.t - 1] = Goldilocks::one();
+        assert_eq!(perm.permutation(&zero).len(), params.t);
+        assert_ne!(perm.permutation(&zero), perm.permutation(&one));
+    }
+
+    #[test]
+    #[should_panic(expected = "only defined for Tip4'")]
+    fn original_tip4_has_no_sponge() {
+        Tip4::new(&TIP4_GOLDILOCKS_PARAMS).hash_elements(&[Goldilocks::one()]);
+    }
 }
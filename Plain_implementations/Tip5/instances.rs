@@ -1,11 +1,14 @@
 use super::tip5::Tip5Params;
+use crate::fields::babybear::BabyBear;
 use crate::fields::goldilocks::Goldilocks;
+use crate::fields::koalabear::KoalaBear;
+use crate::fields::mersenne31::Mersenne31;
 use crate::fields::FieldElement;
 use crate::utils::modinv;
+use alloc::{borrow::ToOwned, sync::Arc, vec, vec::Vec};
 use lazy_static::lazy_static;
 use num_bigint::BigUint;
 use num_traits::ToPrimitive;
-use std::sync::Arc;
 
 pub(crate) const STATE_SIZE: usize = 16;
 pub(crate) const NUM_ROUNDS: usize = 5;
@@ -68,13 +71,22 @@ lazy_static! {
         let (r, r_inv) = monty_constants();
         Arc::new(Tip5Params {
             t: STATE_SIZE,
+            d: 7,
             rounds: NUM_ROUNDS,
             round_constants,
             mds,
+            lookup_tables: vec![LOOKUP_TABLE; 8],
             r,
             r_inv,
         })
     };
+
+    pub static ref TIP5_BABYBEAR_16_PARAMS: Arc<Tip5Params<BabyBear>> =
+        Arc::new(Tip5Params::generate(STATE_SIZE));
+    pub static ref TIP5_KOALABEAR_16_PARAMS: Arc<Tip5Params<KoalaBear>> =
+        Arc::new(Tip5Params::generate(STATE_SIZE));
+    pub static ref TIP5_MERSENNE31_16_PARAMS: Arc<Tip5Params<Mersenne31>> =
+        Arc::new(Tip5Params::generate(STATE_SIZE));
 }
 
 fn monty_constants() -> (Goldilocks, Goldilocks) {
@@ -1,2 +1,3 @@
 pub mod tip5;
 pub mod instances;
+pub mod tip5_params;
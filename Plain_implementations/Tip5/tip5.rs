@@ -1,7 +1,23 @@
-use super::instances::{LOOKUP_TABLE, NUM_SPLIT_AND_LOOKUP};
+use super::instances::NUM_SPLIT_AND_LOOKUP;
+use crate::constant_time::{scan_u8, LookupMode};
+use crate::fields::babybear::BabyBear;
 use crate::fields::goldilocks::Goldilocks;
+use crate::fields::koalabear::KoalaBear;
+use crate::fields::mersenne31::Mersenne31;
 use crate::fields::FieldElement;
-use std::sync::Arc;
+use alloc::{sync::Arc, vec, vec::Vec};
+
+pub const RATE: usize = 10;
+pub const DIGEST_LEN: usize = 5;
+pub const EXTENSION_DEGREE: usize = 3;
+
+/// Triton VM sponge domains: fixed-length hashing sets every capacity element to one, the
+/// variable-length sponge starts from the all-zero state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Domain {
+    VariableLength,
+    FixedLength,
+}
 
 pub trait Tip5Field: FieldElement {
     fn to_u64(&self) -> u64;
@@ -13,12 +29,32 @@ impl Tip5Field for Goldilocks {
     }
 }
 
+impl Tip5Field for BabyBear {
+    fn to_u64(&self) -> u64 {
+        BabyBear::to_u32(self) as u64
+    }
+}
+
+impl Tip5Field for KoalaBear {
+    fn to_u64(&self) -> u64 {
+        KoalaBear::to_u32(self) as u64
+    }
+}
+
+impl Tip5Field for Mersenne31 {
+    fn to_u64(&self) -> u64 {
+        Mersenne31::to_u32(self) as u64
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Tip5Params<F: Tip5Field> {
     pub(crate) t: usize,
+    pub(crate) d: u64,
     pub(crate) rounds: usize,
     pub(crate) round_constants: Vec<Vec<F>>,
     pub(crate) mds: Vec<Vec<F>>,
+    pub(crate) lookup_tables: Vec<[u8; 256]>, // [byte_idx], least significant byte first
     pub(crate) r: F,
     pub(crate) r_inv: F,
 }
@@ -26,12 +62,19 @@ pub struct Tip5Params<F: Tip5Field> {
 #[derive(Clone, Debug)]
 pub struct Tip5<F: Tip5Field> {
     pub(crate) params: Arc<Tip5Params<F>>,
+    pub(crate) lookup: LookupMode,
 }
 
 impl<F: Tip5Field> Tip5<F> {
     pub fn new(params: &Arc<Tip5Params<F>>) -> Self {
+        Self::new_with_lookup(params, LookupMode::Table)
+    }
+
+    /// With `LookupMode::ConstantTime`, every byte lookup reads the whole table.
+    pub fn new_with_lookup(params: &Arc<Tip5Params<F>>, lookup: LookupMode) -> Self {
         Tip5 {
             params: Arc::clone(params),
+            lookup,
         }
     }
 
@@ -52,6 +95,32 @@ impl<F: Tip5Field> Tip5<F> {
         state
     }
 
+    /// Triton VM `Tip5::hash_10`: a single fixed-length permutation.
+    pub fn hash_10(&self, input: &[F; RATE]) -> [F; DIGEST_LEN] {
+        let mut sponge = Tip5Sponge::new(&self.params, Domain::FixedLength);
+        sponge.state[..RATE].clone_from_slice(input);
+        sponge.permute();
+        sponge.digest()
+    }
+
+    /// Triton VM `Tip5::hash_pair`: `hash_10` of the concatenated digests.
+    pub fn hash_pair(&self, left: &[F; DIGEST_LEN], right: &[F; DIGEST_LEN]) -> [F; DIGEST_LEN] {
+        let mut sponge = Tip5Sponge::new(&self.params, Domain::FixedLength);
+        sponge.state[..DIGEST_LEN].clone_from_slice(left);
+        sponge.state[DIGEST_LEN..RATE].clone_from_slice(right);
+        sponge.permute();
+        sponge.digest()
+    }
+
+    /// Triton VM `Tip5::hash_varlen`: the padded input is absorbed in the variable-length domain
+    /// and the digest is the start of the first squeeze.
+    pub fn hash_varlen(&self, input: &[F]) -> [F; DIGEST_LEN] {
+        let mut sponge = Tip5Sponge::new(&self.params, Domain::VariableLength);
+        sponge.pad_and_absorb_all(input);
+        let produce = sponge.squeeze();
+        core::array::from_fn(|i| produce[i].clone())
+    }
+
     fn add_round_constants(&self, state: &mut [F], round: usize) {
         for (el, rc) in state
             .iter_mut()
@@ -66,16 +135,19 @@ impl<F: Tip5Field> Tip5<F> {
             state[i] = self.split_and_lookup(&state[i]);
         }
         for i in NUM_SPLIT_AND_LOOKUP..self.params.t {
-            state[i] = state[i].pow_u64(7);
+            state[i] = state[i].pow_u64(self.params.d);
         }
     }
 
-    fn split_and_lookup(&self, element: &F) -> F {
+    pub(crate) fn split_and_lookup(&self, element: &F) -> F {
         let mut monty = element.clone();
         monty.mul_assign(&self.params.r);
         let mut bytes = monty.to_u64().to_le_bytes();
-        for b in bytes.iter_mut() {
-            *b = LOOKUP_TABLE[*b as usize];
+        for (b, table) in bytes.iter_mut().zip(self.params.lookup_tables.iter()) {
+            *b = match self.lookup {
+                LookupMode::Table => table[*b as usize],
+                LookupMode::ConstantTime => scan_u8(table, *b),
+            };
         }
         let mut out = F::from_u64(u64::from_le_bytes(bytes));
         out.mul_assign(&self.params.r_inv);
@@ -97,10 +169,101 @@ impl<F: Tip5Field> Tip5<F> {
     }
 }
 
+/// Triton VM's Tip5 sponge in overwrite mode: absorbing replaces the rate part of the state.
+#[derive(Clone, Debug)]
+pub struct Tip5Sponge<F: Tip5Field> {
+    tip5: Tip5<F>,
+    pub(crate) state: Vec<F>,
+}
+
+impl<F: Tip5Field> Tip5Sponge<F> {
+    pub fn new(params: &Arc<Tip5Params<F>>, domain: Domain) -> Self {
+        let mut state = vec![F::zero(); params.t];
+        if domain == Domain::FixedLength {
+            for x in state.iter_mut().skip(RATE) {
+                *x = F::one();
+            }
+        }
+        Tip5Sponge {
+            tip5: Tip5::new(params),
+            state,
+        }
+    }
+
+    pub fn absorb(&mut self, input: &[F; RATE]) {
+        self.state[..RATE].clone_from_slice(input);
+        self.permute();
+    }
+
+    /// Absorbs `input` followed by the padding `[1, 0, ..., 0]`, which is always at least one
+    /// element long.
+    pub fn pad_and_absorb_all(&mut self, input: &[F]) {
+        let mut chunks = input.chunks_exact(RATE);
+        for chunk in chunks.by_ref() {
+            self.state[..RATE].clone_from_slice(chunk);
+            self.permute();
+        }
+
+        let remainder = chunks.remainder();
+        let mut last_chunk: [F; RATE] = core::array::from_fn(|_| F::zero());
+        last_chunk[..remainder.len()].clone_from_slice(remainder);
+        last_chunk[remainder.len()] = F::one();
+        self.absorb(&last_chunk);
+    }
+
+    /// Returns the rate part of the state, then permutes.
+    pub fn squeeze(&mut self) -> [F; RATE] {
+        let produce = core::array::from_fn(|i| self.state[i].clone());
+        self.permute();
+        produce
+    }
+
+    /// Triton VM `sample_indices`: squeezed elements are taken in order, `p - 1` is rejected
+    /// and the low 32 bits are reduced modulo the power-of-two `upper_bound`.
+    pub fn sample_indices(&mut self, upper_bound: u32, num_indices: usize) -> Vec<u32> {
+        assert!(upper_bound.is_power_of_two());
+        let mut max = F::zero();
+        max.sub_assign(&F::one());
+
+        let mut indices = Vec::with_capacity(num_indices);
+        let mut squeezed = Vec::new();
+        while indices.len() != num_indices {
+            if squeezed.is_empty() {
+                squeezed = self.squeeze().into_iter().rev().collect();
+            }
+            let element = squeezed.pop().expect("squeezed elements are non-empty");
+            if element != max {
+                indices.push(element.to_u64() as u32 % upper_bound);
+            }
+        }
+        indices
+    }
+
+    /// Triton VM `sample_scalars`: consecutive triples of squeezed elements, as the coefficients
+    /// of elements of the cubic extension field.
+    pub fn sample_scalars(&mut self, num_elements: usize) -> Vec<[F; EXTENSION_DEGREE]> {
+        let num_squeezes = (num_elements * EXTENSION_DEGREE).div_ceil(RATE);
+        let squeezed: Vec<F> = (0..num_squeezes).flat_map(|_| self.squeeze()).collect();
+        squeezed
+            .chunks(EXTENSION_DEGREE)
+            .take(num_elements)
+            .map(|c| [c[0].clone(), c[1].clone(), c[2].clone()])
+            .collect()
+    }
+
+    fn permute(&mut self) {
+        self.state = self.tip5.permutation(&self.state);
+    }
+
+    fn digest(&self) -> [F; DIGEST_LEN] {
+        core::array::from_fn(|i| self.state[i].clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::instances::TIP5_GOLDILOCKS_PARAMS;
-    use super::Tip5;
+    use super::{Domain, Tip5, Tip5Sponge, DIGEST_LEN, RATE};
     use crate::fields::goldilocks::Goldilocks;
     use crate::fields::FieldElement;
 
@@ -125,4 +288,74 @@ mod tests {
             assert_eq!(got.to_u64(), *want);
         }
     }
+
+    // The chained and summed vectors below are Triton VM's `twenty-first` Tip5 test vectors.
+    #[test]
+    fn hash_10_matches_triton_chained_vector() {
+        let tip5 = Tip5::new(&TIP5_GOLDILOCKS_PARAMS);
+        let mut preimage = [Goldilocks::zero(); RATE];
+        for i in 0..6 {
+            let digest = tip5.hash_10(&preimage);
+            preimage[i..DIGEST_LEN + i].copy_from_slice(&digest);
+        }
+        let digest = tip5.hash_10(&preimage);
+        let expected = [
+            10869784347448351760u64,
+            1853783032222938415u64,
+            6856460589287344822u64,
+            17178399545409290325u64,
+            7650660984651717733u64,
+        ];
+        assert_eq!(digest.map(|x| x.to_u64()), expected);
+    }
+
+    #[test]
+    fn hash_varlen_matches_triton_summed_vector() {
+        let tip5 = Tip5::new(&TIP5_GOLDILOCKS_PARAMS);
+        let mut digest_sum = [Goldilocks::zero(); DIGEST_LEN];
+        for i in 0..20u64 {
+            let preimage: Vec<Goldilocks> = (0..i).map(Goldilocks::from_u64).collect();
+            for (s, d) in digest_sum.iter_mut().zip(tip5.hash_varlen(&preimage)) {
+                s.add_assign(&d);
+            }
+        }
+        let expected = [
+            7610004073009036015u64,
+            5725198067541094245u64,
+            4721320565792709122u64,
+            1732504843634706218u64,
+            259800783350288362u64,
+        ];
+        assert_eq!(digest_sum.map(|x| x.to_u64()), expected);
+    }
+
+    #[test]
+    fn sponge_operations_follow_triton_conventions() {
+        let tip5 = Tip5::new(&TIP5_GOLDILOCKS_PARAMS);
+        let left = [1u64, 2, 3, 4, 5].map(Goldilocks::from_u64);
+        let right = [6u64, 7, 8, 9, 10].map(Goldilocks::from_u64);
+        let mut concat = [Goldilocks::zero(); RATE];
+        concat[..DIGEST_LEN].copy_from_slice(&left);
+        concat[DIGEST_LEN..].copy_from_slice(&right);
+        assert_eq!(tip5.hash_pair(&left, &right), tip5.hash_10(&concat));
+
+        // The padded block of a full-rate input is [1, 0, ..., 0].
+        let mut sponge = Tip5Sponge::new(&TIP5_GOLDILOCKS_PARAMS, Domain::VariableLength);
+        sponge.absorb(&concat);
+        let mut padding = [Goldilocks::zero(); RATE];
+        padding[0] = Goldilocks::one();
+        sponge.absorb(&padding);
+        assert_eq!(sponge.squeeze()[..DIGEST_LEN], tip5.hash_varlen(&concat));
+
+        let mut a = Tip5Sponge::new(&TIP5_GOLDILOCKS_PARAMS, Domain::VariableLength);
+        let mut b = a.clone();
+        let squeezed: Vec<Goldilocks> = (0..2).flat_map(|_| a.squeeze()).collect();
+        let scalars = b.sample_scalars(5);
+        assert_eq!(scalars.concat(), squeezed[..15]);
+
+        let mut sponge = Tip5Sponge::new(&TIP5_GOLDILOCKS_PARAMS, Domain::VariableLength);
+        let indices = sponge.sample_indices(1 << 10, 25);
+        assert_eq!(indices.len(), 25);
+        assert!(indices.iter().all(|&i| i < 1 << 10));
+    }
 }
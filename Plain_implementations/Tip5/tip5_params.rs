@@ -0,0 +1,187 @@
+use super::instances::{LOOKUP_TABLE, NUM_ROUNDS};
+use super::tip5::{Tip5Field, Tip5Params};
+use crate::fields::PrimeField;
+use crate::utils::{field_element_from_shake, modinv, smallest_invertible_power};
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+
+pub const INIT_SHAKE: &str = "Tip5";
+
+/// Tip-style instance over an arbitrary prime field, in particular the 31-bit fields. The
+/// split-and-lookup works on the canonical representation, with per-byte tables derived from
+/// Tip5's table so that the s-box stays a permutation of the field (see [`get_lookup_tables`]).
+/// The round count and the number of split-and-lookup lanes are Tip5's; these instances have not
+/// been analysed beyond that.
+impl<F: Tip5Field + PrimeField> Tip5Params<F> {
+    pub fn generate(t: usize) -> Self {
+        let p = F::modulus();
+        let (d, _) = smallest_invertible_power(&p);
+
+        Tip5Params {
+            t,
+            d,
+            rounds: NUM_ROUNDS,
+            round_constants: get_round_constants::<F>(&p, t, NUM_ROUNDS),
+            mds: get_mds_matrix::<F>(t),
+            lookup_tables: get_lookup_tables(&p),
+            r: F::one(),
+            r_inv: F::one(),
+        }
+    }
+}
+
+/// SHAKE128 seeded with `"Tip5"` followed by the little-endian 64-bit limbs of `p`.
+pub fn init_shake(p: &BigUint) -> impl XofReader {
+    let mut shake = Shake128::default();
+    shake.update(INIT_SHAKE.as_bytes());
+    for limb in p.to_u64_digits() {
+        shake.update(&u64::to_le_bytes(limb));
+    }
+    shake.finalize_xof()
+}
+
+pub fn get_round_constants<F: PrimeField>(p: &BigUint, t: usize, rounds: usize) -> Vec<Vec<F>> {
+    let mut shake = init_shake(p);
+    (0..rounds)
+        .map(|_| {
+            (0..t)
+                .map(|_| F::from_biguint(&field_element_from_shake(p, &mut shake)))
+                .collect()
+        })
+        .collect()
+}
+
+/// The Cauchy matrix `1 / (i + j + t)`, which is MDS for `p > 2t`.
+pub fn get_mds_matrix<F: PrimeField>(t: usize) -> Vec<Vec<F>> {
+    let p = F::modulus();
+    (0..t)
+        .map(|i| {
+            (0..t)
+                .map(|j| F::from_biguint(&modinv(&BigUint::from(i + j + t), &p)))
+                .collect()
+        })
+        .collect()
+}
+
+/// One table per byte of the canonical representation. If every byte table maps the values
+/// below, equal to and above the matching byte of `p - 1` to themselves, comparing a
+/// representation with `p - 1` is unaffected by the lookup, so the s-box maps `[0, p)` onto
+/// itself. Each table is Tip5's table cycle-walked within those three classes.
+pub fn get_lookup_tables(p: &BigUint) -> Vec<[u8; 256]> {
+    let num_bytes = (p.bits() as usize).div_ceil(8);
+    let mut digits = (p - 1u32).to_bytes_le();
+    digits.resize(num_bytes, 0);
+
+    digits
+        .iter()
+        .map(|&digit| {
+            core::array::from_fn(|x| {
+                let class = (x as u8).cmp(&digit);
+                let mut y = LOOKUP_TABLE[x];
+                while y.cmp(&digit) != class {
+                    y = LOOKUP_TABLE[y as usize];
+                }
+                y
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::instances::{
+        LOOKUP_TABLE, TIP5_BABYBEAR_16_PARAMS, TIP5_KOALABEAR_16_PARAMS, TIP5_MERSENNE31_16_PARAMS,
+    };
+    use super::super::tip5::{Tip5, Tip5Field, Tip5Params};
+    use crate::fields::PrimeField;
+    use std::sync::Arc;
+
+    fn assert_sbox_and_mds_invertible<F: Tip5Field + PrimeField>(params: &Arc<Tip5Params<F>>) {
+        let p = F::modulus();
+        let digits = (&p - 1u32).to_bytes_le();
+        assert_eq!(params.lookup_tables.len(), 4);
+
+        for (table, &digit) in params.lookup_tables.iter().zip(digits.iter()) {
+            let mut seen = [false; 256];
+            for (x, &y) in table.iter().enumerate() {
+                assert_eq!((x as u8).cmp(&digit), y.cmp(&digit));
+                seen[y as usize] = true;
+            }
+            assert!(seen.iter().all(|&s| s));
+        }
+
+        // Undo the lookup byte by byte: this only recovers the input if the composed bytes
+        // stayed below p, i.e. the s-box is a bijection of the field.
+        let inverse_tables: Vec<[u8; 256]> = params
+            .lookup_tables
+            .iter()
+            .map(|table| {
+                let mut inv = [0u8; 256];
+                for (x, &y) in table.iter().enumerate() {
+                    inv[y as usize] = x as u8;
+                }
+                inv
+            })
+            .collect();
+        let perm = Tip5::new(params);
+        let p_minus_1 = digits
+            .iter()
+            .rev()
+            .fold(0u64, |acc, &b| (acc << 8) | b as u64);
+        let samples = (0..4096u64)
+            .map(|i| i.wrapping_mul(0x9e37_79b1) % (p_minus_1 + 1))
+            .chain(p_minus_1 - 255..=p_minus_1);
+        for x in samples {
+            let mut monty = F::from_u64(x);
+            monty.mul_assign(&params.r);
+            let mut out = perm.split_and_lookup(&F::from_u64(x));
+            out.mul_assign(&params.r);
+            let mut bytes = out.to_u64().to_le_bytes();
+            for (b, inv) in bytes.iter_mut().zip(inverse_tables.iter()) {
+                *b = inv[*b as usize];
+            }
+            assert_eq!(u64::from_le_bytes(bytes), monty.to_u64());
+        }
+
+        assert!(is_invertible(&params.mds));
+    }
+
+    fn is_invertible<F: PrimeField>(mat: &[Vec<F>]) -> bool {
+        let mut m = mat.to_vec();
+        let n = m.len();
+        for col in 0..n {
+            let Some(pivot) = (col..n).find(|&row| m[row][col] != F::zero()) else {
+                return false;
+            };
+            m.swap(col, pivot);
+            let inv = m[col][col].inverse().unwrap();
+            let (top, rest) = m.split_at_mut(col + 1);
+            let pivot_row = &top[col];
+            for row in rest.iter_mut() {
+                let mut factor = row[col].clone();
+                factor.mul_assign(&inv);
+                for (x, y) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                    let mut tmp = y.clone();
+                    tmp.mul_assign(&factor);
+                    x.sub_assign(&tmp);
+                }
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn sbox_and_mds_are_invertible() {
+        assert_sbox_and_mds_invertible(&TIP5_BABYBEAR_16_PARAMS);
+        assert_sbox_and_mds_invertible(&TIP5_KOALABEAR_16_PARAMS);
+        assert_sbox_and_mds_invertible(&TIP5_MERSENNE31_16_PARAMS);
+
+        // Zero bytes of p - 1 only separate 0, which Tip5's table fixes.
+        assert_eq!(LOOKUP_TABLE[0], 0);
+        assert_eq!(TIP5_BABYBEAR_16_PARAMS.lookup_tables[0], LOOKUP_TABLE);
+    }
+}
@@ -0,0 +1,136 @@
+//! Table lookups without secret-dependent memory accesses, for the lookup-based S-boxes.
+//!
+//! The default [`LookupMode::Table`] indexes tables directly, which leaks the index through the
+//! cache. [`LookupMode::ConstantTime`] instead computes Monolith's S-boxes bitsliced and scans
+//! every entry of the Tip5, Tip4', Reinforced Concrete and Polocolo tables, selecting with masks.
+
+use core::hint::black_box;
+
+/// How a permutation evaluates its lookup tables.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LookupMode {
+    #[default]
+    Table,
+    ConstantTime,
+}
+
+/// All ones if `a == b`, zero otherwise.
+#[inline]
+pub(crate) fn eq_mask(a: u64, b: u64) -> u64 {
+    // `black_box` keeps the compiler from turning the mask back into a branch.
+    let diff = black_box(a ^ b);
+    ((diff | diff.wrapping_neg()) >> 63).wrapping_sub(1)
+}
+
+/// [`eq_mask`] on all four limbs.
+#[inline]
+pub(crate) fn eq_mask_words(a: &[u64; 4], b: &[u64; 4]) -> u64 {
+    let diff = (a[0] ^ b[0]) | (a[1] ^ b[1]) | (a[2] ^ b[2]) | (a[3] ^ b[3]);
+    eq_mask(diff, 0)
+}
+
+/// `table[index]`, reading every entry.
+#[inline]
+pub(crate) fn scan_u8(table: &[u8; 256], index: u8) -> u8 {
+    let mut out = 0u64;
+    for (i, &entry) in table.iter().enumerate() {
+        out |= eq_mask(i as u64, index as u64) & entry as u64;
+    }
+    out as u8
+}
+
+/// `table[index]`, reading every entry. Out-of-range indices give zero.
+#[inline]
+pub(crate) fn scan_u16(table: &[u16], index: u16) -> u16 {
+    let mut out = 0u64;
+    for (i, &entry) in table.iter().enumerate() {
+        out |= eq_mask(i as u64, index as u64) & entry as u64;
+    }
+    out as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LookupMode;
+    use crate::fields::bls12_381::Bls12_381;
+    use crate::fields::bn254::Bn254;
+    use crate::fields::FieldElement;
+    use crate::monolith::instances::{MONOLITH_BABYBEAR_16_PARAMS, MONOLITH_GOLDILOCKS_12_PARAMS};
+    use crate::monolith::monolith::{Monolith31, Monolith64};
+    use crate::polocolo::instances::{POLOCOLO_BLS12_381_3_PARAMS, POLOCOLO_BN254_3_PARAMS};
+    use crate::polocolo::polocolo::Polocolo;
+    use crate::reinforced_concrete::instances::{
+        REINFORCED_CONCRETE_BLS12_381_3_PARAMS, REINFORCED_CONCRETE_BN254_3_PARAMS,
+    };
+    use crate::reinforced_concrete::reinforced_concrete::ReinforcedConcrete;
+    use crate::tip4::instances::{TIP4P_GOLDILOCKS_PARAMS, TIP4_GOLDILOCKS_PARAMS};
+    use crate::tip4::tip4::Tip4;
+    use crate::tip5::instances::{TIP5_GOLDILOCKS_PARAMS, TIP5_MERSENNE31_16_PARAMS};
+    use crate::tip5::tip5::Tip5;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    // Zero, the largest element and a few random states.
+    fn inputs<F: FieldElement>(t: usize) -> Vec<Vec<F>> {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let mut inputs = vec![vec![F::zero(); t], vec![F::one().negate(); t]];
+        for _ in 0..3 {
+            inputs.push((0..t).map(|_| F::random(&mut rng)).collect());
+        }
+        inputs
+    }
+
+    fn assert_modes_agree<F: FieldElement>(t: usize, perm: impl Fn(LookupMode, &[F]) -> Vec<F>) {
+        for input in inputs::<F>(t) {
+            assert_eq!(
+                perm(LookupMode::Table, &input),
+                perm(LookupMode::ConstantTime, &input)
+            );
+        }
+    }
+
+    #[test]
+    fn lookup_modes_agree() {
+        let params = &*MONOLITH_GOLDILOCKS_12_PARAMS;
+        assert_modes_agree(params.t, |mode, x| {
+            Monolith64::new_with_lookup(params, mode).permutation(x)
+        });
+        let params = &*MONOLITH_BABYBEAR_16_PARAMS;
+        assert_modes_agree(params.t, |mode, x| {
+            Monolith31::new_with_lookup(params, mode).permutation(x)
+        });
+
+        let params = &*TIP5_GOLDILOCKS_PARAMS;
+        assert_modes_agree(params.t, |mode, x| {
+            Tip5::new_with_lookup(params, mode).permutation(x)
+        });
+        let params = &*TIP5_MERSENNE31_16_PARAMS;
+        assert_modes_agree(params.t, |mode, x| {
+            Tip5::new_with_lookup(params, mode).permutation(x)
+        });
+        let params = &*TIP4P_GOLDILOCKS_PARAMS;
+        assert_modes_agree(params.t, |mode, x| {
+            Tip4::new_with_lookup(params, mode).permutation(x)
+        });
+        let params = &*TIP4_GOLDILOCKS_PARAMS;
+        assert_modes_agree(params.t, |mode, x| {
+            Tip4::new_with_lookup(params, mode).permutation(x)
+        });
+
+        assert_modes_agree::<Bn254>(3, |mode, x| {
+            ReinforcedConcrete::new_with_lookup(&REINFORCED_CONCRETE_BN254_3_PARAMS, mode)
+                .permutation(x)
+        });
+        assert_modes_agree::<Bls12_381>(3, |mode, x| {
+            ReinforcedConcrete::new_with_lookup(&REINFORCED_CONCRETE_BLS12_381_3_PARAMS, mode)
+                .permutation(x)
+        });
+
+        assert_modes_agree::<Bn254>(3, |mode, x| {
+            Polocolo::new_with_lookup(&POLOCOLO_BN254_3_PARAMS, mode).permutation(x)
+        });
+        assert_modes_agree::<Bls12_381>(3, |mode, x| {
+            Polocolo::new_with_lookup(&POLOCOLO_BLS12_381_3_PARAMS, mode).permutation(x)
+        });
+    }
+}
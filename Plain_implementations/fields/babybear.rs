@@ -1,8 +1,10 @@
-use super::{FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
+use super::sqrt::{legendre_euler, tonelli_shanks};
+use super::{random_limbs_below, FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
 use num_bigint::BigUint;
 use num_traits::ToPrimitive;
 use p3_baby_bear::BabyBear as P3BabyBear;
 use p3_field::{Field as P3Field, PrimeCharacteristicRing, PrimeField32};
+use rand::Rng;
 
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
 pub struct BabyBear(pub(crate) P3BabyBear);
@@ -32,6 +34,10 @@ impl FieldElement for BabyBear {
         Self(P3BabyBear::from_u64(val))
     }
 
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_u32(random_limbs_below(rng, &[Self::MODULUS as u64])[0] as u32)
+    }
+
     fn add_assign(&mut self, other: &Self) {
         self.0 += other.0;
     }
@@ -43,6 +49,20 @@ impl FieldElement for BabyBear {
     fn mul_assign(&mut self, other: &Self) {
         self.0 *= other.0;
     }
+
+    fn inverse(&self) -> Option<Self> {
+        P3Field::try_inverse(&self.0).map(Self)
+    }
+
+    fn legendre(&self) -> i8 {
+        legendre_euler(self, &[(Self::MODULUS as u64 - 1) / 2])
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // p - 1 = 2^27 * 15
+        let root_of_unity = Self::from_u64(31).pow_u64(15);
+        tonelli_shanks(self, 27, &[7], &root_of_unity)
+    }
 }
 
 impl PrimeField for BabyBear {
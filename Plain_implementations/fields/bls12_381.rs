@@ -1,7 +1,8 @@
-use super::{FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
+use super::{random_limbs_below, FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
 use ark_bls12_381::Fr as ArkBls12_381;
-use ark_ff::PrimeField as ArkPrimeField;
+use ark_ff::{BigInt, Field as ArkField, LegendreSymbol, PrimeField as ArkPrimeField};
 use num_bigint::BigUint;
+use rand::Rng;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub struct Bls12_381(pub(crate) ArkBls12_381);
@@ -27,6 +28,11 @@ impl FieldElement for Bls12_381 {
         Self(ArkBls12_381::from(val))
     }
 
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let limbs = random_limbs_below(rng, &<ArkBls12_381 as ArkPrimeField>::MODULUS.0);
+        Self(ArkBls12_381::from_bigint(BigInt(limbs)).expect("limbs are below the modulus"))
+    }
+
     fn add_assign(&mut self, other: &Self) {
         self.0 += other.0;
     }
@@ -38,6 +44,22 @@ impl FieldElement for Bls12_381 {
     fn mul_assign(&mut self, other: &Self) {
         self.0 *= other.0;
     }
+
+    fn inverse(&self) -> Option<Self> {
+        ArkField::inverse(&self.0).map(Self)
+    }
+
+    fn legendre(&self) -> i8 {
+        match ArkField::legendre(&self.0) {
+            LegendreSymbol::Zero => 0,
+            LegendreSymbol::QuadraticResidue => 1,
+            LegendreSymbol::QuadraticNonResidue => -1,
+        }
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        ArkField::sqrt(&self.0).map(Self)
+    }
 }
 
 impl PrimeField for Bls12_381 {
@@ -1,7 +1,8 @@
-use super::{FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
+use super::{random_limbs_below, FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
 use ark_bn254::Fr as ArkBn254;
-use ark_ff::PrimeField as ArkPrimeField;
+use ark_ff::{BigInt, Field as ArkField, LegendreSymbol, PrimeField as ArkPrimeField};
 use num_bigint::BigUint;
+use rand::Rng;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub struct Bn254(pub(crate) ArkBn254);
@@ -27,6 +28,11 @@ impl FieldElement for Bn254 {
         Self(ArkBn254::from(val))
     }
 
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let limbs = random_limbs_below(rng, &<ArkBn254 as ArkPrimeField>::MODULUS.0);
+        Self(ArkBn254::from_bigint(BigInt(limbs)).expect("limbs are below the modulus"))
+    }
+
     fn add_assign(&mut self, other: &Self) {
         self.0 += other.0;
     }
@@ -38,6 +44,22 @@ impl FieldElement for Bn254 {
     fn mul_assign(&mut self, other: &Self) {
         self.0 *= other.0;
     }
+
+    fn inverse(&self) -> Option<Self> {
+        ArkField::inverse(&self.0).map(Self)
+    }
+
+    fn legendre(&self) -> i8 {
+        match ArkField::legendre(&self.0) {
+            LegendreSymbol::Zero => 0,
+            LegendreSymbol::QuadraticResidue => 1,
+            LegendreSymbol::QuadraticNonResidue => -1,
+        }
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        ArkField::sqrt(&self.0).map(Self)
+    }
 }
 
 impl PrimeField for Bn254 {
@@ -0,0 +1,181 @@
+use super::babybear::BabyBear;
+use super::bls12_381::Bls12_381;
+use super::bn254::Bn254;
+use super::goldilocks::Goldilocks;
+use super::koalabear::KoalaBear;
+use super::mersenne31::Mersenne31;
+use super::{Monty31Params, MontyField, MontyField31, MontyParams, PrimeFieldExt};
+use core::fmt;
+use core::str::FromStr;
+use num_bigint::BigUint;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldParseError {
+    /// A byte encoding does not have exactly `num_bytes()` bytes.
+    InvalidLength,
+    /// A string is empty or contains a character that is not a digit of its radix.
+    InvalidDigit,
+    /// The value is not smaller than the modulus.
+    NonCanonical,
+}
+
+impl fmt::Display for FieldParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldParseError::InvalidLength => write!(f, "invalid encoding length"),
+            FieldParseError::InvalidDigit => write!(f, "invalid digit"),
+            FieldParseError::NonCanonical => write!(f, "value is not smaller than the modulus"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldParseError {}
+
+// `BigUint::parse_bytes` also accepts `_` separators, which are not part of the format.
+pub(crate) fn parse_radix(digits: &str, radix: u32) -> Result<BigUint, FieldParseError> {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(FieldParseError::InvalidDigit);
+    }
+    BigUint::parse_bytes(digits.as_bytes(), radix).ok_or(FieldParseError::InvalidDigit)
+}
+
+// Decimal `Display` and `FromStr` accepting both hex (`0x` prefix) and decimal.
+macro_rules! impl_display_from_str {
+    (@impl [$($generics:tt)*] $field:ty) => {
+        impl<$($generics)*> fmt::Display for $field {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.to_decimal())
+            }
+        }
+
+        impl<$($generics)*> FromStr for $field {
+            type Err = FieldParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <$field as PrimeFieldExt>::parse(s)
+            }
+        }
+    };
+    ($($field:ty),*) => {$(
+        impl_display_from_str!(@impl [] $field);
+    )*};
+}
+
+impl_display_from_str!(BabyBear, Bls12_381, Bn254, Goldilocks, KoalaBear, Mersenne31);
+impl_display_from_str!(@impl [P: MontyParams] MontyField<P>);
+impl_display_from_str!(@impl [P: Monty31Params] MontyField31<P>);
+
+// Serialized as the canonical `to_hex` string; deserialization accepts anything `parse` does.
+#[cfg(feature = "serde")]
+macro_rules! impl_serde {
+    (@impl [$($generics:tt)*] $field:ty) => {
+        impl<$($generics)*> serde::Serialize for $field {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.to_hex())
+            }
+        }
+
+        impl<'de, $($generics)*> serde::Deserialize<'de> for $field {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                <$field as PrimeFieldExt>::parse(&s).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+    ($($field:ty),*) => {$(
+        impl_serde!(@impl [] $field);
+    )*};
+}
+
+#[cfg(feature = "serde")]
+impl_serde!(BabyBear, Bls12_381, Bn254, Goldilocks, KoalaBear, Mersenne31);
+#[cfg(feature = "serde")]
+impl_serde!(@impl [P: MontyParams] MontyField<P>);
+#[cfg(feature = "serde")]
+impl_serde!(@impl [P: Monty31Params] MontyField31<P>);
+
+#[cfg(test)]
+mod tests {
+    use super::super::babybear::BabyBear;
+    use super::super::bls12_381::Bls12_381;
+    use super::super::bn254::Bn254;
+    use super::super::felt252::Felt252;
+    use super::super::goldilocks::Goldilocks;
+    use super::super::koalabear::KoalaBear;
+    use super::super::mersenne31::Mersenne31;
+    use super::super::{FieldElement, PrimeField, PrimeFieldExt};
+    use super::FieldParseError;
+    use num_bigint::BigUint;
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    fn assert_encodings<F>(num_bytes: usize)
+    where
+        F: PrimeFieldExt + Display + FromStr<Err = FieldParseError>,
+    {
+        assert_eq!(F::num_bytes(), num_bytes);
+        let p = F::modulus();
+        let max = F::from_biguint(&(&p - 1u32));
+
+        for x in [F::zero(), F::one(), F::from_u64(0x1234_5678), max.clone()] {
+            let le = x.to_bytes_le();
+            let be = x.to_bytes_be();
+            assert_eq!(le.len(), num_bytes);
+            assert_eq!(
+                le.iter().rev().collect::<Vec<_>>(),
+                be.iter().collect::<Vec<_>>()
+            );
+            assert_eq!(F::from_bytes_le(&le), Ok(x.clone()));
+            assert_eq!(F::from_bytes_be(&be), Ok(x.clone()));
+
+            assert_eq!(x.to_hex().len(), 2 + 2 * num_bytes);
+            assert_eq!(F::parse_hex(&x.to_hex()), Ok(x.clone()));
+            assert_eq!(x.to_string().parse::<F>(), Ok(x.clone()));
+            assert_eq!(x.to_hex().parse::<F>(), Ok(x.clone()));
+        }
+        assert_eq!(F::one().to_bytes_le()[0], 1);
+        assert_eq!(max.to_string(), (&p - 1u32).to_string());
+
+        // p itself and wrong widths are rejected.
+        let mut p_le = p.to_bytes_le();
+        p_le.resize(num_bytes, 0);
+        assert_eq!(F::from_bytes_le(&p_le), Err(FieldParseError::NonCanonical));
+        assert_eq!(
+            F::from_bytes_le(&p_le[1..]),
+            Err(FieldParseError::InvalidLength)
+        );
+        assert_eq!(
+            p.to_string().parse::<F>(),
+            Err(FieldParseError::NonCanonical)
+        );
+        assert_eq!(
+            F::parse_hex(&p.to_str_radix(16)),
+            Err(FieldParseError::NonCanonical)
+        );
+        for bad in ["", "0x", "12a", "0xg", "-1", "1_0"] {
+            assert_eq!(bad.parse::<F>(), Err(FieldParseError::InvalidDigit));
+        }
+    }
+
+    #[test]
+    fn canonical_encodings() {
+        assert_encodings::<Bn254>(32);
+        assert_encodings::<Bls12_381>(32);
+        assert_encodings::<Felt252>(32);
+        assert_encodings::<Goldilocks>(8);
+        assert_encodings::<BabyBear>(4);
+        assert_encodings::<KoalaBear>(4);
+        assert_encodings::<Mersenne31>(4);
+
+        assert_eq!(
+            Bn254::from_u64(255).to_hex(),
+            format!("0x{}ff", "0".repeat(62))
+        );
+        assert_eq!(
+            BabyBear::parse_decimal("2013265920"),
+            Ok(BabyBear::from_biguint(&BigUint::from(2013265920u32)))
+        );
+        assert_eq!("0X0a".parse::<Goldilocks>(), Ok(Goldilocks::from_u64(10)));
+    }
+}
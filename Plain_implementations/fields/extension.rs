@@ -0,0 +1,341 @@
+use super::babybear::BabyBear;
+use super::goldilocks::Goldilocks;
+use super::koalabear::KoalaBear;
+use super::mersenne31::Mersenne31;
+use super::sqrt::{legendre_euler, tonelli_shanks};
+use super::{FieldElement, PrimeField};
+use num_bigint::BigUint;
+use rand::Rng;
+
+/// A finite field of known order.
+pub trait FiniteField: FieldElement {
+    fn order() -> BigUint;
+}
+
+impl<F: PrimeField> FiniteField for F {
+    fn order() -> BigUint {
+        F::modulus()
+    }
+}
+
+/// A field with an irreducible binomial `X^D - W`. `D` must divide `order() - 1`, so that the
+/// Frobenius map is `X -> W^((order() - 1) / D) * X`.
+pub trait BinomiallyExtendable<const D: usize>: FiniteField {
+    fn w() -> Self;
+}
+
+/// `F[X] / (X^D - W)`, with coefficients of increasing degree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinomialExtension<F: BinomiallyExtendable<D>, const D: usize> {
+    pub(crate) coeffs: [F; D],
+}
+
+pub type BabyBear4 = BinomialExtension<BabyBear, 4>;
+pub type BabyBear5 = BinomialExtension<BabyBear, 5>;
+pub type KoalaBear4 = BinomialExtension<KoalaBear, 4>;
+pub type KoalaBear8 = BinomialExtension<KoalaBear, 8>;
+pub type Goldilocks2 = BinomialExtension<Goldilocks, 2>;
+pub type Goldilocks3 = BinomialExtension<Goldilocks, 3>;
+/// `F_p[i] / (i^2 + 1)`.
+pub type Mersenne31Complex = BinomialExtension<Mersenne31, 2>;
+/// A quadratic extension of [`Mersenne31Complex`].
+pub type Mersenne31Quartic = BinomialExtension<Mersenne31Complex, 2>;
+
+// Non-residues as used by Plonky3 where it has the same extension.
+macro_rules! impl_binomially_extendable {
+    ($($field:ty, $d:literal => $w:expr;)*) => {$(
+        impl BinomiallyExtendable<$d> for $field {
+            fn w() -> Self {
+                $w
+            }
+        }
+    )*};
+}
+
+impl_binomially_extendable!(
+    BabyBear, 4 => BabyBear::from_u64(11);
+    BabyBear, 5 => BabyBear::from_u64(2);
+    KoalaBear, 4 => KoalaBear::from_u64(3);
+    KoalaBear, 8 => KoalaBear::from_u64(3);
+    Goldilocks, 2 => Goldilocks::from_u64(7);
+    Goldilocks, 3 => Goldilocks::from_u64(7);
+    Mersenne31, 2 => Mersenne31::one().negate();
+    Mersenne31Complex, 2 => Mersenne31Complex::from_base_slice(&[
+        Mersenne31::from_u64(2),
+        Mersenne31::one(),
+    ]);
+);
+
+impl<F: BinomiallyExtendable<D>, const D: usize> BinomialExtension<F, D> {
+    pub fn from_base(value: F) -> Self {
+        let mut out = Self::zero();
+        out.coeffs[0] = value;
+        out
+    }
+
+    /// Packs `D` base field elements, e.g. squeezed from a sponge, into one element.
+    pub fn from_base_slice(coeffs: &[F]) -> Self {
+        assert_eq!(coeffs.len(), D);
+        Self {
+            coeffs: core::array::from_fn(|i| coeffs[i].clone()),
+        }
+    }
+
+    pub fn as_base_slice(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    /// The element `X`, for `D >= 2`.
+    pub fn x() -> Self {
+        let mut out = Self::zero();
+        out.coeffs[1] = F::one();
+        out
+    }
+
+    /// `self^|F|`, i.e. the Frobenius map over the base field.
+    pub fn frobenius(&self) -> Self {
+        let z = F::w().pow_words_le(&((F::order() - 1u32) / D).to_u64_digits());
+        let mut z_i = F::one();
+        let mut out = self.clone();
+        for coeff in out.coeffs.iter_mut() {
+            coeff.mul_assign(&z_i);
+            z_i.mul_assign(&z);
+        }
+        out
+    }
+
+    /// `self^(|F|^k)`.
+    pub fn repeated_frobenius(&self, k: usize) -> Self {
+        let mut out = self.clone();
+        for _ in 0..k % D {
+            out = out.frobenius();
+        }
+        out
+    }
+
+    /// The norm `self^(1 + |F| + ... + |F|^(D - 1))`, which lies in the base field.
+    pub fn norm(&self) -> F {
+        let mut prod = self.clone();
+        prod.mul_assign(&self.frobenius_product());
+        prod.coeffs[0].clone()
+    }
+
+    // `self^(|F| + ... + |F|^(D - 1))`.
+    fn frobenius_product(&self) -> Self {
+        let mut prod = Self::one();
+        let mut conj = self.clone();
+        for _ in 1..D {
+            conj = conj.frobenius();
+            prod.mul_assign(&conj);
+        }
+        prod
+    }
+
+    fn pow_biguint(&self, exp: &BigUint) -> Self {
+        self.pow_words_le(&exp.to_u64_digits())
+    }
+}
+
+impl<F: BinomiallyExtendable<D>, const D: usize> Default for BinomialExtension<F, D> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: BinomiallyExtendable<D>, const D: usize> FieldElement for BinomialExtension<F, D> {
+    fn zero() -> Self {
+        Self {
+            coeffs: core::array::from_fn(|_| F::zero()),
+        }
+    }
+
+    fn one() -> Self {
+        Self::from_base(F::one())
+    }
+
+    fn from_u64(val: u64) -> Self {
+        Self::from_base(F::from_u64(val))
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            coeffs: core::array::from_fn(|_| F::random(rng)),
+        }
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        for (a, b) in self.coeffs.iter_mut().zip(other.coeffs.iter()) {
+            a.add_assign(b);
+        }
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        for (a, b) in self.coeffs.iter_mut().zip(other.coeffs.iter()) {
+            a.sub_assign(b);
+        }
+    }
+
+    fn mul_assign(&mut self, other: &Self) {
+        // Schoolbook product, with X^(D + k) = W * X^k for the upper half.
+        let mut low: [F; D] = core::array::from_fn(|_| F::zero());
+        let mut high: [F; D] = core::array::from_fn(|_| F::zero());
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                let mut ab = a.clone();
+                ab.mul_assign(b);
+                if i + j < D {
+                    low[i + j].add_assign(&ab);
+                } else {
+                    high[i + j - D].add_assign(&ab);
+                }
+            }
+        }
+
+        let w = F::w();
+        for (l, mut h) in low.iter_mut().zip(high) {
+            h.mul_assign(&w);
+            l.add_assign(&h);
+        }
+        self.coeffs = low;
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        // self^-1 = self^(|F| + ... + |F|^(D - 1)) / norm(self).
+        let conj = self.frobenius_product();
+        let mut norm = conj.clone();
+        norm.mul_assign(self);
+        let norm_inv = norm.coeffs[0].inverse()?;
+        let mut out = conj;
+        for coeff in out.coeffs.iter_mut() {
+            coeff.mul_assign(&norm_inv);
+        }
+        Some(out)
+    }
+
+    fn legendre(&self) -> i8 {
+        let order_minus_one = Self::order() - 1u32;
+        legendre_euler(self, &(order_minus_one >> 1u32).to_u64_digits())
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // order - 1 = 2^two_adicity * q, with the root of unity taken from a small non-residue.
+        let order_minus_one = Self::order() - 1u32;
+        let two_adicity = order_minus_one
+            .trailing_zeros()
+            .expect("order is greater than one") as u32;
+        let q: BigUint = &order_minus_one >> two_adicity;
+        let non_residue = (0..)
+            .map(|k| {
+                let mut candidate = Self::x();
+                candidate.add_assign(&Self::from_u64(k));
+                candidate
+            })
+            .find(|candidate| candidate.legendre() == -1)
+            .expect("half of the elements are non-residues");
+        let root_of_unity = non_residue.pow_biguint(&q);
+        tonelli_shanks(
+            self,
+            two_adicity,
+            &(q >> 1u32).to_u64_digits(),
+            &root_of_unity,
+        )
+    }
+}
+
+impl<F: BinomiallyExtendable<D>, const D: usize> FiniteField for BinomialExtension<F, D> {
+    fn order() -> BigUint {
+        F::order().pow(D as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigUint;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn assert_extension<F: BinomiallyExtendable<D>, const D: usize>() {
+        // X^D - W is irreducible: W is no r-th power for the primes r | D, and 4 | D needs
+        // |F| = 1 mod 4.
+        let q = F::order();
+        for r in [2u32, 3, 5, 7].into_iter().filter(|r| D as u32 % r == 0) {
+            let exp = (&q - 1u32) / r;
+            assert_eq!((&q - 1u32) % r, BigUint::ZERO);
+            assert_ne!(F::w().pow_words_le(&exp.to_u64_digits()), F::one());
+        }
+        if D % 4 == 0 {
+            assert_eq!(&q % 4u32, BigUint::from(1u32));
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let fixed = (1..6u64).map(|i| {
+            let coeffs: Vec<F> = (0..D as u64)
+                .map(|j| F::from_u64(i * 1000 + 7 * j * j + 3))
+                .collect();
+            BinomialExtension::<F, D>::from_base_slice(&coeffs)
+        });
+        let random: Vec<BinomialExtension<F, D>> =
+            (0..5).map(|_| FieldElement::random(&mut rng)).collect();
+        for x in fixed.chain(random) {
+            let mut q_k = BigUint::from(1u32);
+            let mut conj = x.clone();
+            for k in 1..D {
+                q_k *= &q;
+                conj = conj.frobenius();
+                assert_eq!(conj, x.pow_biguint(&q_k));
+                assert_eq!(x.repeated_frobenius(k), conj);
+            }
+            assert_eq!(conj.frobenius(), x);
+
+            let mut one = x.inverse().unwrap();
+            one.mul_assign(&x);
+            assert_eq!(one, FieldElement::one());
+            let mut norm = x.norm();
+            norm.mul_assign(&x.inverse().unwrap().norm());
+            assert_eq!(norm, F::one());
+
+            let mut square = x.clone();
+            square.square();
+            assert_eq!(square.legendre(), 1);
+            let mut root = square.sqrt().unwrap();
+            root.square();
+            assert_eq!(root, square);
+        }
+        assert_eq!(BinomialExtension::<F, D>::zero().inverse(), None);
+    }
+
+    #[test]
+    fn binomial_extensions() {
+        assert_extension::<BabyBear, 4>();
+        assert_extension::<BabyBear, 5>();
+        assert_extension::<KoalaBear, 4>();
+        assert_extension::<KoalaBear, 8>();
+        assert_extension::<Goldilocks, 2>();
+        assert_extension::<Goldilocks, 3>();
+        assert_extension::<Mersenne31, 2>();
+        assert_extension::<Mersenne31Complex, 2>();
+
+        // Mersenne31Quartic over the prime field: the p-th power map has order 4, and its square
+        // is the Frobenius over the complex extension.
+        let p = Mersenne31::order();
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        for _ in 0..5 {
+            let x: Mersenne31Quartic = FieldElement::random(&mut rng);
+            let x_p = x.pow_biguint(&p);
+            assert_ne!(x_p, x);
+            assert_eq!(x_p.pow_biguint(&p), x.frobenius());
+            assert_eq!(x.frobenius().frobenius(), x);
+            assert_eq!(x.pow_biguint(&Mersenne31Quartic::order()), x);
+
+            let mut one = x.inverse().unwrap();
+            one.mul_assign(&x);
+            assert_eq!(one, Mersenne31Quartic::one());
+        }
+
+        // i^2 = -1 in the complex extension.
+        let mut i_squared = Mersenne31Complex::x();
+        i_squared.square();
+        assert_eq!(i_squared, Mersenne31Complex::one().negate());
+    }
+}
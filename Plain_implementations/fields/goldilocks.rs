@@ -1,8 +1,10 @@
-use super::{FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
+use super::sqrt::{legendre_euler, tonelli_shanks};
+use super::{random_limbs_below, FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
 use num_bigint::BigUint;
 use num_traits::ToPrimitive;
 use p3_field::{Field as P3Field, PrimeCharacteristicRing, PrimeField64};
 use p3_goldilocks::Goldilocks as P3Goldilocks;
+use rand::Rng;
 
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
 pub struct Goldilocks(pub(crate) P3Goldilocks);
@@ -33,6 +35,10 @@ impl FieldElement for Goldilocks {
         Self(P3Goldilocks::from_u64(val))
     }
 
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_u64(random_limbs_below(rng, &[Self::MODULUS])[0])
+    }
+
     fn add_assign(&mut self, other: &Self) {
         self.0 += other.0;
     }
@@ -44,6 +50,20 @@ impl FieldElement for Goldilocks {
     fn mul_assign(&mut self, other: &Self) {
         self.0 *= other.0;
     }
+
+    fn inverse(&self) -> Option<Self> {
+        P3Field::try_inverse(&self.0).map(Self)
+    }
+
+    fn legendre(&self) -> i8 {
+        legendre_euler(self, &[(Self::MODULUS - 1) / 2])
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // p - 1 = 2^32 * (2^32 - 1)
+        let root_of_unity = Self::from_u64(7).pow_u64(0xFFFF_FFFF);
+        tonelli_shanks(self, 32, &[0x7FFF_FFFF], &root_of_unity)
+    }
 }
 
 impl PrimeField for Goldilocks {
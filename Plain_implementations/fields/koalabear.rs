@@ -1,8 +1,10 @@
-use super::{FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
+use super::sqrt::{legendre_euler, tonelli_shanks};
+use super::{random_limbs_below, FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
 use num_bigint::BigUint;
 use num_traits::ToPrimitive;
 use p3_field::{Field as P3Field, PrimeCharacteristicRing, PrimeField32};
 use p3_koala_bear::KoalaBear as P3KoalaBear;
+use rand::Rng;
 
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
 pub struct KoalaBear(pub(crate) P3KoalaBear);
@@ -32,6 +34,10 @@ impl FieldElement for KoalaBear {
         Self(P3KoalaBear::from_u64(val))
     }
 
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_u32(random_limbs_below(rng, &[Self::MODULUS as u64])[0] as u32)
+    }
+
     fn add_assign(&mut self, other: &Self) {
         self.0 += other.0;
     }
@@ -43,6 +49,20 @@ impl FieldElement for KoalaBear {
     fn mul_assign(&mut self, other: &Self) {
         self.0 *= other.0;
     }
+
+    fn inverse(&self) -> Option<Self> {
+        P3Field::try_inverse(&self.0).map(Self)
+    }
+
+    fn legendre(&self) -> i8 {
+        legendre_euler(self, &[(Self::MODULUS as u64 - 1) / 2])
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // p - 1 = 2^24 * 127
+        let root_of_unity = Self::from_u64(3).pow_u64(127);
+        tonelli_shanks(self, 24, &[63], &root_of_unity)
+    }
 }
 
 impl PrimeField for KoalaBear {
@@ -1,8 +1,10 @@
-use super::{FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
+use super::sqrt::{legendre_euler, sqrt_3_mod_4};
+use super::{random_limbs_below, FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
 use num_bigint::BigUint;
 use num_traits::ToPrimitive;
 use p3_field::{Field as P3Field, PrimeCharacteristicRing, PrimeField32};
 use p3_mersenne_31::Mersenne31 as P3Mersenne31;
+use rand::Rng;
 
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
 pub struct Mersenne31(pub(crate) P3Mersenne31);
@@ -32,6 +34,10 @@ impl FieldElement for Mersenne31 {
         Self(P3Mersenne31::from_u64(val))
     }
 
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_u32(random_limbs_below(rng, &[Self::MODULUS as u64])[0] as u32)
+    }
+
     fn add_assign(&mut self, other: &Self) {
         self.0 += other.0;
     }
@@ -43,6 +49,18 @@ impl FieldElement for Mersenne31 {
     fn mul_assign(&mut self, other: &Self) {
         self.0 *= other.0;
     }
+
+    fn inverse(&self) -> Option<Self> {
+        P3Field::try_inverse(&self.0).map(Self)
+    }
+
+    fn legendre(&self) -> i8 {
+        legendre_euler(self, &[(Self::MODULUS as u64 - 1) / 2])
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        sqrt_3_mod_4(self, &[(Self::MODULUS as u64 + 1) / 4])
+    }
 }
 
 impl PrimeField for Mersenne31 {
@@ -1,21 +1,32 @@
 pub mod babybear;
 pub mod bls12_381;
 pub mod bn254;
+mod encoding;
+pub mod extension;
 pub mod felt252;
 pub mod goldilocks;
 pub mod koalabear;
 pub mod mersenne31;
-mod montgomery_4;
-mod montgomery_31;
+pub mod montgomery_31;
+pub mod montgomery_4;
+mod sqrt;
 
+use alloc::{format, string::String, vec::Vec};
+pub use encoding::FieldParseError;
+pub use montgomery_31::{Monty31Params, MontyField31};
+pub use montgomery_4::{MontyField, MontyParams};
 use num_bigint::BigUint;
 use num_traits::Zero;
+use rand::Rng;
 
-pub trait FieldElement: Clone + Default + PartialEq + Eq + std::fmt::Debug {
+pub trait FieldElement: Clone + Default + PartialEq + Eq + core::fmt::Debug {
     fn zero() -> Self;
     fn one() -> Self;
     fn from_u64(val: u64) -> Self;
 
+    /// A uniformly random element.
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self;
+
     fn add_assign(&mut self, other: &Self);
     fn sub_assign(&mut self, other: &Self);
     fn mul_assign(&mut self, other: &Self);
@@ -78,6 +89,42 @@ pub trait FieldElement: Clone + Default + PartialEq + Eq + std::fmt::Debug {
 
         result
     }
+
+    /// Multiplicative inverse, `None` for zero.
+    fn inverse(&self) -> Option<Self>;
+
+    /// Inverts all elements in place with a single inversion (Montgomery's trick). Zeros are
+    /// left unchanged.
+    fn batch_inverse(elems: &mut [Self]) {
+        let zero = Self::zero();
+        let mut prefixes = Vec::with_capacity(elems.len());
+        let mut acc = Self::one();
+        for x in elems.iter() {
+            prefixes.push(acc.clone());
+            if *x != zero {
+                acc.mul_assign(x);
+            }
+        }
+
+        let mut inv = acc
+            .inverse()
+            .expect("product of non-zero elements is invertible");
+        for (x, prefix) in elems.iter_mut().zip(prefixes).rev() {
+            if *x == zero {
+                continue;
+            }
+            let mut x_inv = inv.clone();
+            x_inv.mul_assign(&prefix);
+            inv.mul_assign(x);
+            *x = x_inv;
+        }
+    }
+
+    /// Legendre symbol: `1` for non-zero squares, `-1` for non-squares and `0` for zero.
+    fn legendre(&self) -> i8;
+
+    /// A square root, `None` if `self` is not a square.
+    fn sqrt(&self) -> Option<Self>;
 }
 
 pub trait PrimeField: FieldElement {
@@ -88,6 +135,86 @@ pub trait PrimeField: FieldElement {
 
 pub trait PrimeFieldExt: PrimeField {
     fn to_biguint(&self) -> BigUint;
+
+    /// Width of the byte encodings, `ceil(log2(p) / 8)`.
+    fn num_bytes() -> usize {
+        (Self::modulus().bits() as usize).div_ceil(8)
+    }
+
+    /// `value` as a field element, rejecting values `>= p`.
+    fn from_canonical_biguint(value: &BigUint) -> Result<Self, FieldParseError> {
+        if *value < Self::modulus() {
+            Ok(Self::from_biguint(value))
+        } else {
+            Err(FieldParseError::NonCanonical)
+        }
+    }
+
+    /// Fixed-width little-endian encoding of the canonical representative.
+    fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = self.to_biguint().to_bytes_le();
+        bytes.resize(Self::num_bytes(), 0);
+        bytes
+    }
+
+    /// Fixed-width big-endian encoding of the canonical representative.
+    fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Inverse of [`PrimeFieldExt::to_bytes_le`]; the length must be exactly `num_bytes()`.
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, FieldParseError> {
+        if bytes.len() != Self::num_bytes() {
+            return Err(FieldParseError::InvalidLength);
+        }
+        Self::from_canonical_biguint(&BigUint::from_bytes_le(bytes))
+    }
+
+    /// Inverse of [`PrimeFieldExt::to_bytes_be`]; the length must be exactly `num_bytes()`.
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, FieldParseError> {
+        if bytes.len() != Self::num_bytes() {
+            return Err(FieldParseError::InvalidLength);
+        }
+        Self::from_canonical_biguint(&BigUint::from_bytes_be(bytes))
+    }
+
+    /// `0x` followed by the big-endian encoding in lowercase hex, `2 * num_bytes()` digits.
+    fn to_hex(&self) -> String {
+        let digits: String = self
+            .to_bytes_be()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        format!("0x{digits}")
+    }
+
+    fn to_decimal(&self) -> String {
+        self.to_biguint().to_str_radix(10)
+    }
+
+    /// Hex digits with an optional `0x` prefix, of any length as long as the value is `< p`.
+    fn parse_hex(s: &str) -> Result<Self, FieldParseError> {
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        Self::from_canonical_biguint(&encoding::parse_radix(digits, 16)?)
+    }
+
+    fn parse_decimal(s: &str) -> Result<Self, FieldParseError> {
+        Self::from_canonical_biguint(&encoding::parse_radix(s, 10)?)
+    }
+
+    /// Hex if prefixed with `0x`, decimal otherwise.
+    fn parse(s: &str) -> Result<Self, FieldParseError> {
+        if s.starts_with("0x") || s.starts_with("0X") {
+            Self::parse_hex(s)
+        } else {
+            Self::parse_decimal(s)
+        }
+    }
 }
 
 pub trait PrimeFieldWords: PrimeFieldExt {
@@ -107,6 +234,29 @@ pub(crate) fn biguint_from_limbs_le(limbs: &[u64]) -> BigUint {
     value
 }
 
+/// Uniformly random little-endian limbs below `modulus`. Draws as many bits as the modulus has
+/// and rejects values `>= modulus`, so fewer than two draws are needed on average.
+pub(crate) fn random_limbs_below<R: Rng + ?Sized, const N: usize>(
+    rng: &mut R,
+    modulus: &[u64; N],
+) -> [u64; N] {
+    let top = modulus
+        .iter()
+        .rposition(|&limb| limb != 0)
+        .expect("modulus is non-zero");
+    let top_mask = u64::MAX >> modulus[top].leading_zeros();
+    loop {
+        let mut limbs = [0u64; N];
+        for limb in &mut limbs[..=top] {
+            *limb = rng.next_u64();
+        }
+        limbs[top] &= top_mask;
+        if limbs.iter().rev().lt(modulus.iter().rev()) {
+            return limbs;
+        }
+    }
+}
+
 pub(crate) fn biguint_to_limbs_le_4(value: &BigUint) -> [u64; 4] {
     let mut out = [0u64; 4];
     let limbs = value.to_u64_digits();
@@ -115,3 +265,183 @@ pub(crate) fn biguint_to_limbs_le_4(value: &BigUint) -> [u64; 4] {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::babybear::BabyBear;
+    use super::bls12_381::Bls12_381;
+    use super::bn254::Bn254;
+    use super::felt252::Felt252;
+    use super::goldilocks::Goldilocks;
+    use super::koalabear::KoalaBear;
+    use super::mersenne31::Mersenne31;
+    use super::{biguint_from_limbs_le, random_limbs_below};
+    use super::{Monty31Params, MontyField, MontyField31, MontyParams, PrimeField, PrimeFieldExt};
+    use num_bigint::BigUint;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    struct Bn254Params;
+
+    impl MontyParams for Bn254Params {
+        const MODULUS: [u64; 4] = [
+            0x43e1f593f0000001,
+            0x2833e84879b97091,
+            0xb85045b68181585d,
+            0x30644e72e131a029,
+        ];
+        const GENERATOR: u64 = 5;
+    }
+
+    struct BabyBearParams;
+
+    impl Monty31Params for BabyBearParams {
+        const PRIME: u32 = 0x78000001;
+        const GENERATOR: u32 = 31;
+    }
+
+    fn assert_inverse_and_sqrt<F: PrimeField>() {
+        // The multiplicative generator is a non-residue.
+        let g = F::from_biguint(&F::generator());
+        assert_eq!(g.legendre(), -1);
+        assert_eq!(g.sqrt(), None);
+        assert_eq!(F::zero().legendre(), 0);
+        assert_eq!(F::zero().sqrt(), Some(F::zero()));
+        assert_eq!(F::zero().inverse(), None);
+
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let elems: Vec<F> = (1..32u64)
+            .map(|i| F::from_u64(i * i * 0x9e37_79b9 + i).negate())
+            .chain((0..32).map(|_| F::random(&mut rng)))
+            .collect();
+        for x in elems.iter() {
+            let mut one = x.inverse().unwrap();
+            one.mul_assign(x);
+            assert_eq!(one, F::one());
+
+            let mut square = x.clone();
+            square.square();
+            assert_eq!(square.legendre(), 1);
+            let mut root = square.sqrt().unwrap();
+            root.square();
+            assert_eq!(root, square);
+
+            let mut non_square = square;
+            non_square.mul_assign(&g);
+            assert_eq!(non_square.legendre(), -1);
+            assert_eq!(non_square.sqrt(), None);
+        }
+
+        let mut batch = elems.clone();
+        batch.insert(5, F::zero());
+        F::batch_inverse(&mut batch);
+        assert_eq!(batch.remove(5), F::zero());
+        let single: Vec<F> = elems.iter().map(|x| x.inverse().unwrap()).collect();
+        assert_eq!(batch, single);
+    }
+
+    #[test]
+    fn inverse_sqrt_and_legendre() {
+        assert_inverse_and_sqrt::<Bn254>();
+        assert_inverse_and_sqrt::<Bls12_381>();
+        assert_inverse_and_sqrt::<Felt252>();
+        assert_inverse_and_sqrt::<Goldilocks>();
+        assert_inverse_and_sqrt::<BabyBear>();
+        assert_inverse_and_sqrt::<KoalaBear>();
+        assert_inverse_and_sqrt::<Mersenne31>();
+        assert_inverse_and_sqrt::<MontyField<Bn254Params>>();
+        assert_inverse_and_sqrt::<MontyField31<BabyBearParams>>();
+    }
+
+    #[test]
+    fn random_limbs_below_modulus() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let mut counts = [0usize; 5];
+        for _ in 0..5000 {
+            counts[random_limbs_below(&mut rng, &[5])[0] as usize] += 1;
+        }
+        assert!(counts.iter().all(|c| (900..1100).contains(c)), "{counts:?}");
+
+        // Zero top limbs are skipped, and every value of the top non-zero limb occurs.
+        let modulus = [u64::MAX, 2, 0];
+        let mut top = [false; 3];
+        for _ in 0..1000 {
+            let limbs = random_limbs_below(&mut rng, &modulus);
+            assert!(biguint_from_limbs_le(&limbs) < biguint_from_limbs_le(&modulus));
+            top[limbs[1] as usize] = true;
+        }
+        assert_eq!(top, [true; 3]);
+    }
+
+    fn assert_random_uniform<F: PrimeFieldExt>() {
+        // Canonical, and about half of the samples are in the upper half of the field.
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let half = F::modulus() >> 1u32;
+        let mut upper = 0;
+        for _ in 0..1000 {
+            let x = F::random(&mut rng);
+            assert_eq!(F::from_bytes_le(&x.to_bytes_le()), Ok(x.clone()));
+            if x.to_biguint() > half {
+                upper += 1;
+            }
+        }
+        assert!((400..600).contains(&upper), "{upper}");
+    }
+
+    #[test]
+    fn random_elements() {
+        assert_random_uniform::<Bn254>();
+        assert_random_uniform::<Bls12_381>();
+        assert_random_uniform::<Felt252>();
+        assert_random_uniform::<Goldilocks>();
+        assert_random_uniform::<BabyBear>();
+        assert_random_uniform::<KoalaBear>();
+        assert_random_uniform::<Mersenne31>();
+        assert_random_uniform::<MontyField<Bn254Params>>();
+        assert_random_uniform::<MontyField31<BabyBearParams>>();
+    }
+
+    fn assert_same_arithmetic<F: PrimeFieldExt, G: PrimeFieldExt>() {
+        assert_eq!(F::modulus(), G::modulus());
+        let a = BigUint::from(0x1234_5678_9abc_def0u64).pow(7) % F::modulus();
+        let b = BigUint::from(0x0fed_cba9_8765_4321u64).pow(5) % F::modulus();
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        let random = (0..16).map(|_| {
+            let a = F::random(&mut rng).to_biguint();
+            (a, F::random(&mut rng).to_biguint())
+        });
+        for (a, b) in core::iter::once((a, b)).chain(random) {
+            let (fa, fb) = (F::from_biguint(&a), F::from_biguint(&b));
+            let (ga, gb) = (G::from_biguint(&a), G::from_biguint(&b));
+
+            let mut f = fa.clone();
+            f.mul_assign(&fb);
+            f.sub_assign(&fb.inverse().unwrap());
+            f.add_assign(&fa);
+            let mut g = ga.clone();
+            g.mul_assign(&gb);
+            g.sub_assign(&gb.inverse().unwrap());
+            g.add_assign(&ga);
+            assert_eq!(f.to_biguint(), g.to_biguint());
+        }
+    }
+
+    #[test]
+    fn generic_montgomery_fields() {
+        // The derived constants match the ones previously hard-coded for Felt252 and BabyBear.
+        assert_eq!(super::felt252::Felt252Params::MU, 1);
+        assert_eq!(
+            super::felt252::Felt252Params::R2,
+            [
+                0xfffffd737e000401,
+                0x00000001330fffff,
+                0xffffffffff6f8000,
+                0x07ffd4ab5e008810,
+            ]
+        );
+        assert_eq!(BabyBearParams::MONTY_MU, 0x88000001);
+
+        assert_same_arithmetic::<MontyField<Bn254Params>, Bn254>();
+        assert_same_arithmetic::<MontyField31<BabyBearParams>, BabyBear>();
+    }
+}
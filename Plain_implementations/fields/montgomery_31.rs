@@ -1,10 +1,30 @@
+use super::sqrt::{legendre_euler, tonelli_shanks};
+use super::{random_limbs_below, FieldElement, PrimeField, PrimeFieldExt, PrimeFieldWords};
+use core::fmt;
 use core::marker::PhantomData;
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use rand::Rng;
 
+/// An odd prime below `2^31`. `MONTY_MU` is derived from the prime unless given explicitly.
 pub trait Monty31Params {
     const PRIME: u32;
+    /// A multiplicative generator of the field.
+    const GENERATOR: u32;
     const MONTY_BITS: u32 = 32;
     const MONTY_MASK: u32 = 0xFFFF_FFFF;
-    const MONTY_MU: u32;
+    const MONTY_MU: u32 = monty31_mu(Self::PRIME);
+}
+
+/// `p^-1 mod 2^32`, by Newton iteration (each step doubles the number of correct bits).
+pub const fn monty31_mu(prime: u32) -> u32 {
+    let mut inv = 1u32;
+    let mut i = 0;
+    while i < 5 {
+        inv = inv.wrapping_mul(2u32.wrapping_sub(prime.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv
 }
 
 #[inline]
@@ -51,7 +71,7 @@ pub const fn monty_reduce<P: Monty31Params>(x: u64) -> u32 {
     x_sub_u_hi.wrapping_add(corr)
 }
 
-#[derive(Clone, Copy, Default, Eq, PartialEq)]
+/// A 31-bit prime field in Montgomery form for the prime given by `P`.
 #[repr(transparent)]
 pub struct MontyField31<P: Monty31Params> {
     pub(crate) value: u32,
@@ -59,6 +79,10 @@ pub struct MontyField31<P: Monty31Params> {
 }
 
 impl<P: Monty31Params> MontyField31<P> {
+    // p - 1 = 2^TWO_ADICITY * q with q odd.
+    const TWO_ADICITY: u32 = (P::PRIME - 1).trailing_zeros();
+    const Q: u64 = (P::PRIME >> Self::TWO_ADICITY) as u64;
+
     #[inline(always)]
     pub const fn new(value: u32) -> Self {
         Self {
@@ -103,3 +127,107 @@ impl<P: Monty31Params> MontyField31<P> {
         self.value = monty_reduce::<P>((self.value as u64) * (rhs.value as u64));
     }
 }
+
+// Implemented by hand so that `P` itself needs no derives.
+impl<P: Monty31Params> Clone for MontyField31<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: Monty31Params> Copy for MontyField31<P> {}
+
+impl<P: Monty31Params> Default for MontyField31<P> {
+    fn default() -> Self {
+        Self::new_monty(0)
+    }
+}
+
+impl<P: Monty31Params> PartialEq for MontyField31<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<P: Monty31Params> Eq for MontyField31<P> {}
+
+impl<P: Monty31Params> fmt::Debug for MontyField31<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MontyField31").field(&self.to_u32()).finish()
+    }
+}
+
+impl<P: Monty31Params> FieldElement for MontyField31<P> {
+    fn zero() -> Self {
+        Self::new_monty(0)
+    }
+
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn from_u64(val: u64) -> Self {
+        Self::from_u64(val)
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(random_limbs_below(rng, &[P::PRIME as u64])[0] as u32)
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        Self::add_assign(self, other)
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        Self::sub_assign(self, other)
+    }
+
+    fn mul_assign(&mut self, other: &Self) {
+        Self::mul_assign(self, other)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        (*self != Self::zero()).then(|| self.pow_u64(P::PRIME as u64 - 2))
+    }
+
+    fn legendre(&self) -> i8 {
+        legendre_euler(self, &[(P::PRIME as u64 - 1) / 2])
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        let root_of_unity = Self::new(P::GENERATOR).pow_u64(Self::Q);
+        tonelli_shanks(
+            self,
+            Self::TWO_ADICITY,
+            &[(Self::Q - 1) / 2],
+            &root_of_unity,
+        )
+    }
+}
+
+impl<P: Monty31Params> PrimeField for MontyField31<P> {
+    fn modulus() -> BigUint {
+        BigUint::from(P::PRIME)
+    }
+
+    fn from_biguint(value: &BigUint) -> Self {
+        let reduced = value % P::PRIME;
+        Self::new(reduced.to_u32().expect("reduced value fits into u32"))
+    }
+
+    fn generator() -> BigUint {
+        BigUint::from(P::GENERATOR)
+    }
+}
+
+impl<P: Monty31Params> PrimeFieldExt for MontyField31<P> {
+    fn to_biguint(&self) -> BigUint {
+        BigUint::from(self.to_u32())
+    }
+}
+
+impl<P: Monty31Params> PrimeFieldWords for MontyField31<P> {
+    fn to_words_le(&self) -> [u64; 4] {
+        [self.to_u32() as u64, 0, 0, 0]
+    }
+}
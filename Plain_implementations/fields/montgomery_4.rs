@@ -1,9 +1,50 @@
+use super::sqrt::{legendre_euler, tonelli_shanks};
+use super::{
+    biguint_from_limbs_le, biguint_to_limbs_le_4, random_limbs_below, FieldElement, PrimeField,
+    PrimeFieldExt, PrimeFieldWords,
+};
+use alloc::string::String;
 use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use num_bigint::BigUint;
+use rand::Rng;
 
+/// An odd prime of at most 256 bits as little-endian limbs. `MU` and `R2` are derived from the
+/// modulus unless given explicitly.
 pub trait MontyParams {
     const MODULUS: [u64; 4];
-    const MU: u64;
-    const R2: [u64; 4];
+    /// A multiplicative generator of the field.
+    const GENERATOR: u64;
+    const MU: u64 = monty_mu(Self::MODULUS);
+    const R2: [u64; 4] = monty_r2(Self::MODULUS);
+}
+
+/// `p^-1 mod 2^64`, by Newton iteration (each step doubles the number of correct bits).
+pub const fn monty_mu(modulus: [u64; 4]) -> u64 {
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(modulus[0].wrapping_mul(inv)));
+        i += 1;
+    }
+    inv
+}
+
+/// `2^512 mod p`, by doubling `1` 512 times.
+pub const fn monty_r2(modulus: [u64; 4]) -> [u64; 4] {
+    let mut r = [1, 0, 0, 0];
+    let mut i = 0;
+    while i < 512 {
+        let (sum, carry) = wrapping_add::<4>(r, r);
+        r = if carry || !matches!(cmp_limbs(sum, modulus), Ordering::Less) {
+            wrapping_sub::<4>(sum, modulus).0
+        } else {
+            sum
+        };
+        i += 1;
+    }
+    r
 }
 
 #[inline]
@@ -21,28 +62,34 @@ const fn borrowing_sub(lhs: u64, rhs: u64, borrow: bool) -> (u64, bool) {
 }
 
 #[inline]
-fn wrapping_add<const N: usize>(lhs: [u64; N], rhs: [u64; N]) -> ([u64; N], bool) {
+const fn wrapping_add<const N: usize>(lhs: [u64; N], rhs: [u64; N]) -> ([u64; N], bool) {
     let mut carry = false;
     let mut output = [0u64; N];
-    for i in 0..N {
+    let mut i = 0;
+    while i < N {
         (output[i], carry) = carrying_add(lhs[i], rhs[i], carry);
+        i += 1;
     }
     (output, carry)
 }
 
 #[inline]
-fn wrapping_sub<const N: usize>(lhs: [u64; N], rhs: [u64; N]) -> ([u64; N], bool) {
+const fn wrapping_sub<const N: usize>(lhs: [u64; N], rhs: [u64; N]) -> ([u64; N], bool) {
     let mut borrow = false;
     let mut output = [0u64; N];
-    for i in 0..N {
+    let mut i = 0;
+    while i < N {
         (output[i], borrow) = borrowing_sub(lhs[i], rhs[i], borrow);
+        i += 1;
     }
     (output, borrow)
 }
 
 #[inline]
-fn cmp_limbs(lhs: [u64; 4], rhs: [u64; 4]) -> Ordering {
-    for i in (0..4).rev() {
+const fn cmp_limbs(lhs: [u64; 4], rhs: [u64; 4]) -> Ordering {
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
         if lhs[i] < rhs[i] {
             return Ordering::Less;
         }
@@ -53,6 +100,47 @@ fn cmp_limbs(lhs: [u64; 4], rhs: [u64; 4]) -> Ordering {
     Ordering::Equal
 }
 
+// `value >> shift` for `shift < 64`.
+const fn shr_limbs(value: [u64; 4], shift: u32) -> [u64; 4] {
+    if shift == 0 {
+        return value;
+    }
+    let mut out = [0u64; 4];
+    let mut i = 0;
+    while i < 4 {
+        out[i] = value[i] >> shift;
+        if i < 3 {
+            out[i] |= value[i + 1] << (64 - shift);
+        }
+        i += 1;
+    }
+    out
+}
+
+// `value >> shift` for any `shift < 256`.
+const fn shr_limbs_wide(value: [u64; 4], shift: u32) -> [u64; 4] {
+    let words = (shift / 64) as usize;
+    let mut out = [0u64; 4];
+    let mut i = 0;
+    while i + words < 4 {
+        out[i] = value[i + words];
+        i += 1;
+    }
+    shr_limbs(out, shift % 64)
+}
+
+const fn trailing_zeros_limbs(value: [u64; 4]) -> u32 {
+    let mut i = 0;
+    while i < 4 && value[i] == 0 {
+        i += 1;
+    }
+    if i == 4 {
+        256
+    } else {
+        64 * i as u32 + value[i].trailing_zeros()
+    }
+}
+
 #[inline]
 fn mul_small(lhs: [u64; 4], rhs: u64) -> (u64, [u64; 4]) {
     let mut output = [0u64; 4];
@@ -143,6 +231,7 @@ pub fn to_monty<P: MontyParams>(value: [u64; 4]) -> [u64; 4] {
     monty_mul::<P>(value, P::R2)
 }
 
+/// Reduces a value that is at most a few multiples of the modulus.
 pub fn reduce_raw<P: MontyParams>(mut value: [u64; 4]) -> [u64; 4] {
     while cmp_limbs(value, P::MODULUS) != Ordering::Less {
         value = sub_mod::<P>(value, P::MODULUS);
@@ -180,3 +269,147 @@ pub fn from_hex_to_limbs(s: &str) -> Option<[u64; 4]> {
 
     Some(limbs)
 }
+
+/// A prime field in Montgomery form for the modulus given by `P`.
+pub struct MontyField<P: MontyParams> {
+    pub(crate) value: [u64; 4],
+    _phantom: PhantomData<P>,
+}
+
+impl<P: MontyParams> MontyField<P> {
+    const P_MINUS_TWO: [u64; 4] = wrapping_sub::<4>(P::MODULUS, [2, 0, 0, 0]).0;
+    const P_MINUS_ONE_HALF: [u64; 4] = shr_limbs(P::MODULUS, 1);
+    // p - 1 = 2^TWO_ADICITY * q with q odd.
+    const TWO_ADICITY: u32 = trailing_zeros_limbs(wrapping_sub::<4>(P::MODULUS, [1, 0, 0, 0]).0);
+    const Q: [u64; 4] = shr_limbs_wide(P::MODULUS, Self::TWO_ADICITY);
+    const Q_MINUS_ONE_HALF: [u64; 4] = shr_limbs(Self::Q, 1);
+
+    pub fn from_hex(s: &str) -> Option<Self> {
+        let raw = from_hex_to_limbs(s)?;
+        Some(Self::from_raw(raw))
+    }
+
+    #[inline]
+    fn from_raw(raw: [u64; 4]) -> Self {
+        let reduced = if matches!(cmp_limbs(raw, P::MODULUS), Ordering::Less) {
+            raw
+        } else {
+            biguint_to_limbs_le_4(&(biguint_from_limbs_le(&raw) % Self::modulus()))
+        };
+        Self::new_monty(to_monty::<P>(reduced))
+    }
+
+    #[inline]
+    const fn new_monty(value: [u64; 4]) -> Self {
+        Self {
+            value,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+// Implemented by hand so that `P` itself needs no derives.
+impl<P: MontyParams> Clone for MontyField<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: MontyParams> Copy for MontyField<P> {}
+
+impl<P: MontyParams> Default for MontyField<P> {
+    fn default() -> Self {
+        Self::new_monty([0; 4])
+    }
+}
+
+impl<P: MontyParams> PartialEq for MontyField<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<P: MontyParams> Eq for MontyField<P> {}
+
+impl<P: MontyParams> fmt::Debug for MontyField<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MontyField")
+            .field(&self.to_biguint())
+            .finish()
+    }
+}
+
+impl<P: MontyParams> FieldElement for MontyField<P> {
+    fn zero() -> Self {
+        Self::new_monty([0; 4])
+    }
+
+    fn one() -> Self {
+        Self::from_u64(1)
+    }
+
+    fn from_u64(val: u64) -> Self {
+        Self::from_raw([val, 0, 0, 0])
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new_monty(to_monty::<P>(random_limbs_below(rng, &P::MODULUS)))
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        self.value = add_mod::<P>(self.value, other.value);
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        self.value = sub_mod::<P>(self.value, other.value);
+    }
+
+    fn mul_assign(&mut self, other: &Self) {
+        self.value = monty_mul::<P>(self.value, other.value);
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        (*self != Self::zero()).then(|| self.pow_words_le(&Self::P_MINUS_TWO))
+    }
+
+    fn legendre(&self) -> i8 {
+        legendre_euler(self, &Self::P_MINUS_ONE_HALF)
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        let root_of_unity = Self::from_u64(P::GENERATOR).pow_words_le(&Self::Q);
+        tonelli_shanks(
+            self,
+            Self::TWO_ADICITY,
+            &Self::Q_MINUS_ONE_HALF,
+            &root_of_unity,
+        )
+    }
+}
+
+impl<P: MontyParams> PrimeField for MontyField<P> {
+    fn modulus() -> BigUint {
+        biguint_from_limbs_le(&P::MODULUS)
+    }
+
+    fn from_biguint(value: &BigUint) -> Self {
+        let reduced = value % Self::modulus();
+        Self::from_raw(biguint_to_limbs_le_4(&reduced))
+    }
+
+    fn generator() -> BigUint {
+        BigUint::from(P::GENERATOR)
+    }
+}
+
+impl<P: MontyParams> PrimeFieldExt for MontyField<P> {
+    fn to_biguint(&self) -> BigUint {
+        biguint_from_limbs_le(&self.to_words_le())
+    }
+}
+
+impl<P: MontyParams> PrimeFieldWords for MontyField<P> {
+    fn to_words_le(&self) -> [u64; 4] {
+        monty_mul::<P>(self.value, [1, 0, 0, 0])
+    }
+}
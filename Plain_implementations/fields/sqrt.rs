@@ -0,0 +1,67 @@
+use super::FieldElement;
+
+/// Euler's criterion, with `p_minus_one_half` as little-endian 64-bit limbs.
+pub(crate) fn legendre_euler<F: FieldElement>(x: &F, p_minus_one_half: &[u64]) -> i8 {
+    let symbol = x.pow_words_le(p_minus_one_half);
+    if symbol == F::zero() {
+        0
+    } else if symbol == F::one() {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Square root for `p = 3 mod 4` as `x^((p + 1) / 4)`, checked by squaring.
+pub(crate) fn sqrt_3_mod_4<F: FieldElement>(x: &F, p_plus_one_quarter: &[u64]) -> Option<F> {
+    let root = x.pow_words_le(p_plus_one_quarter);
+    let mut check = root.clone();
+    check.square();
+    (check == *x).then_some(root)
+}
+
+/// Tonelli-Shanks for `p - 1 = 2^two_adicity * q` with `q` odd. `q_minus_one_half` is
+/// `(q - 1) / 2` as little-endian 64-bit limbs and `root_of_unity` has order `2^two_adicity`.
+pub(crate) fn tonelli_shanks<F: FieldElement>(
+    x: &F,
+    two_adicity: u32,
+    q_minus_one_half: &[u64],
+    root_of_unity: &F,
+) -> Option<F> {
+    if *x == F::zero() {
+        return Some(F::zero());
+    }
+
+    // r = x^((q + 1) / 2) and b = x^q, so that r^2 = x * b.
+    let w = x.pow_words_le(q_minus_one_half);
+    let mut r = w.clone();
+    r.mul_assign(x);
+    let mut b = r.clone();
+    b.mul_assign(&w);
+
+    let mut v = two_adicity;
+    let mut z = root_of_unity.clone();
+    while b != F::one() {
+        // Order of b is 2^k with k < v for squares.
+        let mut k = 0;
+        let mut b_pow = b.clone();
+        while b_pow != F::one() {
+            b_pow.square();
+            k += 1;
+            if k == v {
+                return None;
+            }
+        }
+
+        let mut w = z;
+        for _ in 0..v - k - 1 {
+            w.square();
+        }
+        z = w.clone();
+        z.square();
+        b.mul_assign(&z);
+        r.mul_assign(&w);
+        v = k;
+    }
+    Some(r)
+}
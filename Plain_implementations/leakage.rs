@@ -0,0 +1,256 @@
+//! Statistical timing-leakage test in the style of dudect (Reparaz, Balasch and Verbauwhede,
+//! "Dude, is my code constant time?").
+//!
+//! The function under test is timed on two input classes, one fixed input and fresh random
+//! inputs, in random order. Welch's t-test then compares the two timing distributions, both on
+//! all measurements and with the slowest ones cropped at several percentiles to remove noise
+//! from interrupts. A `|t|` above [`T_THRESHOLD`] means the classes are distinguishable, i.e.
+//! the running time depends on the input.
+
+use crate::fields::FieldElement;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::fmt;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// dudect's threshold: larger `|t|` values are very unlikely for identical distributions.
+pub const T_THRESHOLD: f64 = 4.5;
+
+// Measurements above these percentiles are dropped for the cropped tests.
+const CROP_PERCENTILES: [f64; 5] = [0.5, 0.75, 0.9, 0.95, 0.99];
+
+#[derive(Clone, Debug)]
+pub struct LeakageConfig {
+    /// Number of timed calls, split randomly between the two classes.
+    pub samples: usize,
+    /// Calls per measurement, so that short functions are above the timer resolution.
+    pub calls_per_sample: usize,
+    pub seed: u64,
+}
+
+impl Default for LeakageConfig {
+    fn default() -> Self {
+        LeakageConfig {
+            samples: 100_000,
+            calls_per_sample: 1,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeakageReport {
+    pub samples: usize,
+    /// Welch's t-statistic on all measurements.
+    pub t: f64,
+    /// The t-statistic of largest magnitude among all tests, including the cropped ones.
+    pub max_t: f64,
+}
+
+impl LeakageReport {
+    pub fn is_distinguishable(&self) -> bool {
+        self.max_t.abs() > T_THRESHOLD
+    }
+}
+
+impl fmt::Display for LeakageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} samples, t = {:.2}, max |t| = {:.2}: {}",
+            self.samples,
+            self.t,
+            self.max_t.abs(),
+            if self.is_distinguishable() {
+                "distinguishable"
+            } else {
+                "not distinguishable"
+            }
+        )
+    }
+}
+
+/// Online mean and variance (Welford).
+#[derive(Clone, Copy, Debug, Default)]
+struct Moments {
+    n: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    fn push(&mut self, x: f64) {
+        self.n += 1.0;
+        let delta = x - self.mean;
+        self.mean += delta / self.n;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        if self.n < 2.0 {
+            0.0
+        } else {
+            self.m2 / (self.n - 1.0)
+        }
+    }
+}
+
+/// Welch's t-statistic of two samples, zero if either is too small or both are constant.
+fn welch_t(a: &Moments, b: &Moments) -> f64 {
+    if a.n < 2.0 || b.n < 2.0 {
+        return 0.0;
+    }
+    let se = (a.variance() / a.n + b.variance() / b.n).sqrt();
+    if se == 0.0 {
+        0.0
+    } else {
+        (a.mean - b.mean) / se
+    }
+}
+
+/// Runs the test on `f`, with `fixed` as the fixed class and `random` producing the inputs of
+/// the random class. All inputs are prepared before timing starts. With fewer than two samples
+/// nothing can be compared, and the report is empty with `t = 0`.
+pub fn leakage_test<I, R>(
+    config: &LeakageConfig,
+    fixed: &I,
+    mut random: impl FnMut(&mut ChaCha8Rng) -> I,
+    mut f: impl FnMut(&I) -> R,
+) -> LeakageReport
+where
+    I: Clone,
+{
+    if config.samples < 2 {
+        return LeakageReport {
+            samples: config.samples,
+            t: 0.0,
+            max_t: 0.0,
+        };
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+    let inputs: Vec<(bool, I)> = (0..config.samples)
+        .map(|_| {
+            if !rng.gen::<bool>() {
+                (false, fixed.clone())
+            } else {
+                (true, random(&mut rng))
+            }
+        })
+        .collect();
+
+    let times: Vec<(bool, f64)> = inputs
+        .iter()
+        .map(|(class, input)| {
+            let start = Instant::now();
+            for _ in 0..config.calls_per_sample {
+                black_box(f(black_box(input)));
+            }
+            (*class, start.elapsed().as_nanos() as f64)
+        })
+        .collect();
+
+    let mut sorted: Vec<f64> = times.iter().map(|(_, t)| *t).collect();
+    sorted.sort_by(f64::total_cmp);
+    let thresholds = std::iter::once(f64::INFINITY).chain(
+        CROP_PERCENTILES
+            .iter()
+            .map(|p| sorted[((sorted.len() as f64 * p) as usize).min(sorted.len() - 1)]),
+    );
+
+    let mut t = 0.0;
+    let mut max_t = 0.0f64;
+    for (i, threshold) in thresholds.enumerate() {
+        let mut classes = [Moments::default(); 2];
+        for (class, time) in times.iter().filter(|(_, time)| *time <= threshold) {
+            classes[*class as usize].push(*time);
+        }
+        let t_i = welch_t(&classes[0], &classes[1]);
+        if i == 0 {
+            t = t_i;
+        }
+        if t_i.abs() > max_t.abs() {
+            max_t = t_i;
+        }
+    }
+
+    LeakageReport {
+        samples: config.samples,
+        t,
+        max_t,
+    }
+}
+
+/// [`leakage_test`] on a permutation of `width` elements, with the all-zero state as the fixed
+/// class.
+pub fn permutation_leakage_test<F: FieldElement>(
+    config: &LeakageConfig,
+    width: usize,
+    perm: impl Fn(&[F]) -> Vec<F>,
+) -> LeakageReport {
+    leakage_test(
+        config,
+        &vec![F::zero(); width],
+        |rng| (0..width).map(|_| F::random(rng)).collect(),
+        |input: &Vec<F>| perm(input),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{leakage_test, welch_t, LeakageConfig, Moments};
+    use rand::Rng;
+    use std::hint::black_box;
+
+    #[test]
+    fn welch_t_statistic() {
+        let mut a = Moments::default();
+        let mut b = Moments::default();
+        for x in [1.0, 2.0, 3.0, 4.0] {
+            a.push(x);
+            b.push(x + 2.0);
+        }
+        assert_eq!(a.mean, 2.5);
+        assert!((a.variance() - 5.0 / 3.0).abs() < 1e-12);
+        // Difference of means over sqrt(2 * (5/3) / 4).
+        assert!((welch_t(&a, &b) + 2.0 / (5.0f64 / 6.0).sqrt()).abs() < 1e-12);
+        assert_eq!(welch_t(&a, &a), 0.0);
+    }
+
+    #[test]
+    fn detects_data_dependent_work() {
+        let config = LeakageConfig {
+            samples: 4000,
+            calls_per_sample: 1,
+            seed: 1,
+        };
+        // The fixed input takes an early exit, the random ones do not.
+        let report = leakage_test(
+            &config,
+            &0u64,
+            |rng| rng.gen::<u64>() | 1,
+            |x| {
+                if *x == 0 {
+                    return 0;
+                }
+                (0..2000u64).fold(*x, |acc, i| black_box(acc.wrapping_mul(i | 1)))
+            },
+        );
+        assert!(report.is_distinguishable(), "{report}");
+    }
+
+    #[test]
+    fn too_few_samples_give_an_empty_report() {
+        for samples in [0, 1] {
+            let config = LeakageConfig {
+                samples,
+                calls_per_sample: 1,
+                seed: 0,
+            };
+            let report = leakage_test(&config, &0u64, |rng| rng.gen::<u64>(), |x| *x);
+            assert_eq!(report.t, 0.0);
+            assert!(!report.is_distinguishable());
+        }
+    }
+}
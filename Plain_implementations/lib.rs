@@ -1,5 +1,14 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod fields;
+#[cfg(feature = "serde")]
+pub mod serialization;
 mod utils;
+pub mod constant_time;
+#[cfg(feature = "std")]
+pub mod leakage;
 #[path = "Poseidon2/mod.rs"]
 pub mod poseidon2;
 #[path = "Poseidon/mod.rs"]
@@ -10,6 +19,8 @@ pub mod anemoi;
 pub mod gmimc_erf;
 #[path = "Rescueprime/mod.rs"]
 pub mod rescueprime;
+#[path = "Rpo/mod.rs"]
+pub mod rpo;
 #[path = "Griffin/mod.rs"]
 pub mod griffin;
 #[path = "Neptune/mod.rs"]
@@ -26,3 +37,4 @@ pub mod monolith;
 pub mod skyscraper;
 #[path = "Polocolo/mod.rs"]
 pub mod polocolo;
+pub mod registry;
@@ -0,0 +1,703 @@
+//! Every shipped instance, addressable by a name of the form `"<primitive>/<field>/<width>"`
+//! such as `"poseidon2/babybear/24"`, where `width` is the state size in field elements.
+//!
+//! Permutations are type-erased and work on canonical encodings: each state element is either
+//! `limbs_per_element` little-endian `u64` limbs or `bytes_per_element` little-endian bytes, the
+//! same layout as `PrimeFieldExt::to_bytes_le`. All instances are built the first time the
+//! registry is used.
+
+use crate::anemoi::{anemoi::Anemoi, instances::*};
+use crate::constant_time::LookupMode;
+use crate::fields::babybear::BabyBear;
+use crate::fields::bls12_381::Bls12_381;
+use crate::fields::bn254::Bn254;
+use crate::fields::felt252::Felt252;
+use crate::fields::goldilocks::Goldilocks;
+use crate::fields::koalabear::KoalaBear;
+use crate::fields::mersenne31::Mersenne31;
+use crate::fields::{FieldParseError, PrimeFieldWords};
+use crate::gmimc_erf::{gmimc_erf::GmimcErf, instances::*};
+use crate::griffin::{griffin::Griffin, instances::*};
+#[cfg(feature = "std")]
+use crate::leakage::{permutation_leakage_test, LeakageConfig, LeakageReport};
+use crate::monolith::instances::*;
+use crate::monolith::monolith::{Monolith31, Monolith64};
+use crate::neptune::{instances::*, neptune::Neptune};
+use crate::polocolo::{instances::*, polocolo::Polocolo};
+use crate::poseidon::{instances::*, poseidon::Poseidon};
+use crate::poseidon2::{instances::*, poseidon2::Poseidon2};
+use crate::reinforced_concrete::instances::*;
+use crate::reinforced_concrete::reinforced_concrete::{
+    ReinforcedConcrete, ReinforcedConcreteParams,
+};
+use crate::rescueprime::{instances::*, rescue_prime::RescuePrime};
+use crate::rpo::{instances::*, rpo::Rpo, rpx::Rpx};
+use crate::skyscraper::{instances::*, skyscraper::Skyscraper};
+use crate::tip4::instances::*;
+use crate::tip4::tip4::{self, Tip4};
+use crate::tip5::instances::*;
+use crate::tip5::tip5::{self, Tip5};
+use alloc::{boxed::Box, format, string::String, sync::Arc, vec::Vec};
+use lazy_static::lazy_static;
+use rand::RngCore;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstanceInfo {
+    pub name: String,
+    pub primitive: &'static str,
+    pub field: &'static str,
+    /// State size in field elements.
+    pub width: usize,
+    /// Field elements absorbed per permutation call in the instance's sponge.
+    pub rate: usize,
+    pub rounds: usize,
+    /// Degree of the power map in the non-linear layer; `None` when there is none.
+    pub sbox_degree: Option<u64>,
+    /// Where the parameters come from: `"sok"` for the benchmark instances, the name of the
+    /// reference implementation they match, or `"generated"` for the local generators.
+    pub source: &'static str,
+    pub bytes_per_element: usize,
+    pub limbs_per_element: usize,
+}
+
+/// A permutation over canonical encodings of its state.
+pub trait ErasedPermutation: Send + Sync {
+    fn info(&self) -> &InstanceInfo;
+
+    /// `width * limbs_per_element` limbs in, the same number out.
+    fn permute_limbs(&self, input: &[u64]) -> Result<Vec<u64>, FieldParseError>;
+
+    /// `width * bytes_per_element` bytes in, the same number out.
+    fn permute_bytes(&self, input: &[u8]) -> Result<Vec<u8>, FieldParseError>;
+
+    /// A uniformly random state in the encoding of [`ErasedPermutation::permute_limbs`].
+    fn random_limbs(&self, rng: &mut dyn RngCore) -> Vec<u64>;
+
+    /// Timing-leakage test of the permutation, see [`crate::leakage`].
+    #[cfg(feature = "std")]
+    fn leakage_test(&self, config: &LeakageConfig) -> LeakageReport;
+}
+
+/// Field names used in instance names.
+trait FieldName {
+    const NAME: &'static str;
+}
+
+macro_rules! impl_field_name {
+    ($($field:ty => $name:literal),*) => {$(
+        impl FieldName for $field {
+            const NAME: &'static str = $name;
+        }
+    )*};
+}
+
+impl_field_name!(
+    BabyBear => "babybear",
+    Bls12_381 => "bls12-381",
+    Bn254 => "bn254",
+    Felt252 => "felt252",
+    Goldilocks => "goldilocks",
+    KoalaBear => "koalabear",
+    Mersenne31 => "mersenne31"
+);
+
+type PermFn<F> = Box<dyn Fn(&[F]) -> Vec<F> + Send + Sync>;
+
+struct Instance<F> {
+    info: InstanceInfo,
+    perm: PermFn<F>,
+}
+
+impl<F: PrimeFieldWords + Send + Sync> Instance<F> {
+    fn decode(&self, input: &[u8]) -> Result<Vec<F>, FieldParseError> {
+        let n = self.info.bytes_per_element;
+        if input.len() != self.info.width * n {
+            return Err(FieldParseError::InvalidLength);
+        }
+        input.chunks(n).map(F::from_bytes_le).collect()
+    }
+}
+
+impl<F: PrimeFieldWords + Send + Sync> ErasedPermutation for Instance<F> {
+    fn info(&self) -> &InstanceInfo {
+        &self.info
+    }
+
+    fn permute_limbs(&self, input: &[u64]) -> Result<Vec<u64>, FieldParseError> {
+        let limbs = self.info.limbs_per_element;
+        if input.len() != self.info.width * limbs {
+            return Err(FieldParseError::InvalidLength);
+        }
+
+        // Limbs beyond the element size must be zero, which `from_bytes_le` cannot see.
+        let n = self.info.bytes_per_element;
+        let mut bytes = Vec::with_capacity(input.len() * n);
+        for element in input.chunks(limbs) {
+            let le: Vec<u8> = element.iter().flat_map(|l| l.to_le_bytes()).collect();
+            if le[n..].iter().any(|&b| b != 0) {
+                return Err(FieldParseError::NonCanonical);
+            }
+            bytes.extend_from_slice(&le[..n]);
+        }
+
+        let output = (self.perm)(&self.decode(&bytes)?);
+        Ok(output
+            .iter()
+            .flat_map(|x| x.to_words_le()[..limbs].to_vec())
+            .collect())
+    }
+
+    fn permute_bytes(&self, input: &[u8]) -> Result<Vec<u8>, FieldParseError> {
+        let output = (self.perm)(&self.decode(input)?);
+        Ok(output.iter().flat_map(|x| x.to_bytes_le()).collect())
+    }
+
+    fn random_limbs(&self, rng: &mut dyn RngCore) -> Vec<u64> {
+        let limbs = self.info.limbs_per_element;
+        (0..self.info.width)
+            .flat_map(|_| F::random(rng).to_words_le()[..limbs].to_vec())
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn leakage_test(&self, config: &LeakageConfig) -> LeakageReport {
+        permutation_leakage_test(config, self.info.width, |x: &[F]| (self.perm)(x))
+    }
+}
+
+// Capacity of the benchmark sponges: one element for ~256-bit fields, 4 for Goldilocks and 8
+// for the 31-bit fields, at most all but one element of the state.
+fn default_rate(width: usize, bytes_per_element: usize) -> usize {
+    let capacity = match bytes_per_element {
+        32 => 1,
+        8 => 4,
+        _ => 8,
+    };
+    width.saturating_sub(capacity).max(1)
+}
+
+struct Meta {
+    primitive: &'static str,
+    width: usize,
+    rate: Option<usize>,
+    rounds: usize,
+    sbox_degree: Option<u64>,
+    source: &'static str,
+}
+
+fn entry<F>(
+    meta: Meta,
+    perm: impl Fn(&[F]) -> Vec<F> + Send + Sync + 'static,
+) -> Arc<dyn ErasedPermutation>
+where
+    F: PrimeFieldWords + FieldName + Send + Sync + 'static,
+{
+    let bytes_per_element = F::num_bytes();
+    let info = InstanceInfo {
+        name: format!("{}/{}/{}", meta.primitive, F::NAME, meta.width),
+        primitive: meta.primitive,
+        field: F::NAME,
+        width: meta.width,
+        rate: meta
+            .rate
+            .unwrap_or_else(|| default_rate(meta.width, bytes_per_element)),
+        rounds: meta.rounds,
+        sbox_degree: meta.sbox_degree,
+        source: meta.source,
+        bytes_per_element,
+        limbs_per_element: bytes_per_element.div_ceil(8),
+    };
+    Arc::new(Instance {
+        info,
+        perm: Box::new(perm),
+    })
+}
+
+// Registers a permutation per parameter set, with `$meta` computed from the parameters `$p`.
+// Permutations are built with `new` unless another constructor and its extra arguments are given.
+macro_rules! register {
+    ($list:ident, $perm:ident, |$p:ident| $meta:expr, [$($params:expr),* $(,)?]) => {$(
+        let perm = $perm::new(&$params);
+        let $p = &*$params;
+        $list.push(entry($meta, move |x| perm.permutation(x)));
+    )*};
+    (
+        $list:ident,
+        $perm:ident::$ctor:ident($arg:expr),
+        |$p:ident| $meta:expr,
+        [$($params:expr),* $(,)?]
+    ) => {$(
+        let perm = $perm::$ctor(&$params, $arg);
+        let $p = &*$params;
+        $list.push(entry($meta, move |x| perm.permutation(x)));
+    )*};
+}
+
+fn meta(
+    primitive: &'static str,
+    width: usize,
+    rounds: usize,
+    sbox_degree: Option<u64>,
+    source: &'static str,
+) -> Meta {
+    Meta {
+        primitive,
+        width,
+        rate: None,
+        rounds,
+        sbox_degree,
+        source,
+    }
+}
+
+// Lookup-based primitives are registered once per lookup mode, the constant-time variant under
+// the primitive name with a `-ct` suffix.
+fn lookup_modes(
+    table: &'static str,
+    constant_time: &'static str,
+) -> [(&'static str, LookupMode); 2] {
+    [
+        (table, LookupMode::Table),
+        (constant_time, LookupMode::ConstantTime),
+    ]
+}
+
+fn build() -> Vec<Arc<dyn ErasedPermutation>> {
+    let mut list: Vec<Arc<dyn ErasedPermutation>> = Vec::new();
+
+    macro_rules! poseidon {
+        ($primitive:literal, $source:literal, [$($params:expr),* $(,)?]) => {
+            register!(list, Poseidon, |p| meta(
+                $primitive,
+                p.t,
+                2 * p.rounds_f_beginning + p.rounds_p,
+                Some(p.d),
+                $source,
+            ), [$($params),*]);
+        };
+    }
+    poseidon!(
+        "poseidon",
+        "sok",
+        [
+            POSEIDON_BN254_2_PARAMS,
+            POSEIDON_BN254_3_PARAMS,
+            POSEIDON_BLS12_381_2_PARAMS,
+            POSEIDON_BLS12_381_3_PARAMS,
+            POSEIDON_GOLDILOCKS_8_PARAMS,
+            POSEIDON_GOLDILOCKS_12_PARAMS,
+            POSEIDON_BABYBEAR_16_PARAMS,
+            POSEIDON_BABYBEAR_24_PARAMS,
+            POSEIDON_KOALABEAR_16_PARAMS,
+            POSEIDON_KOALABEAR_24_PARAMS,
+            POSEIDON_MERSENNE31_16_PARAMS,
+            POSEIDON_MERSENNE31_24_PARAMS,
+        ]
+    );
+    poseidon!(
+        "poseidon-original",
+        "sok",
+        [
+            POSEIDON_ORIGINAL_BN254_2_PARAMS,
+            POSEIDON_ORIGINAL_BN254_3_PARAMS,
+            POSEIDON_ORIGINAL_BLS12_381_2_PARAMS,
+            POSEIDON_ORIGINAL_BLS12_381_3_PARAMS,
+            POSEIDON_ORIGINAL_GOLDILOCKS_8_PARAMS,
+            POSEIDON_ORIGINAL_GOLDILOCKS_12_PARAMS,
+            POSEIDON_ORIGINAL_BABYBEAR_16_PARAMS,
+            POSEIDON_ORIGINAL_BABYBEAR_24_PARAMS,
+            POSEIDON_ORIGINAL_KOALABEAR_16_PARAMS,
+            POSEIDON_ORIGINAL_KOALABEAR_24_PARAMS,
+            POSEIDON_ORIGINAL_MERSENNE31_16_PARAMS,
+            POSEIDON_ORIGINAL_MERSENNE31_24_PARAMS,
+        ]
+    );
+    poseidon!(
+        "poseidon-plonky2",
+        "plonky2",
+        [POSEIDON_PLONKY2_GOLDILOCKS_12_PARAMS]
+    );
+    for params in POSEIDON_CIRCOM_BN254_PARAMS.iter() {
+        poseidon!("poseidon-circom", "circomlib", [params]);
+    }
+
+    register!(
+        list,
+        Poseidon2,
+        |p| meta("poseidon2", p.t, p.rounds, Some(p.d), "sok",),
+        [
+            POSEIDON2_BN254_2_PARAMS,
+            POSEIDON2_BN254_3_PARAMS,
+            POSEIDON2_BN254_4_PARAMS,
+            POSEIDON2_BLS12_381_2_PARAMS,
+            POSEIDON2_BLS12_381_3_PARAMS,
+            POSEIDON2_GOLDILOCKS_8_PARAMS,
+            POSEIDON2_GOLDILOCKS_12_PARAMS,
+            POSEIDON2_BABYBEAR_16_PARAMS,
+            POSEIDON2_BABYBEAR_24_PARAMS,
+            POSEIDON2_KOALABEAR_16_PARAMS,
+            POSEIDON2_KOALABEAR_24_PARAMS,
+            POSEIDON2_MERSENNE31_16_PARAMS,
+            POSEIDON2_MERSENNE31_24_PARAMS,
+        ]
+    );
+
+    register!(
+        list,
+        RescuePrime,
+        |p| meta("rescue-prime", p.t, p.rounds, Some(p.d), "sok",),
+        [
+            RESCUE_PRIME_BN254_3_PARAMS,
+            RESCUE_PRIME_BLS12_381_2_PARAMS,
+            RESCUE_PRIME_BLS12_381_3_PARAMS,
+            RESCUE_PRIME_GOLDILOCKS_8_PARAMS,
+            RESCUE_PRIME_GOLDILOCKS_12_PARAMS,
+        ]
+    );
+
+    // Miden's sponges have capacity 4 and rate 8.
+    register!(
+        list,
+        Rpo,
+        |p| Meta {
+            rate: Some(p.get_rate()),
+            ..meta("rpo", p.t, p.rounds, Some(7), "miden")
+        },
+        [RPO_GOLDILOCKS_12_PARAMS]
+    );
+    register!(
+        list,
+        Rpx,
+        |p| Meta {
+            rate: Some(p.get_rate()),
+            ..meta("rpx", p.t, p.rounds, Some(7), "miden")
+        },
+        [RPX_GOLDILOCKS_12_PARAMS]
+    );
+
+    register!(
+        list,
+        Anemoi,
+        |p| meta("anemoi", p.width, p.rounds, Some(p.alpha), "sok",),
+        [
+            ANEMOI_BN254_2_PARAMS,
+            ANEMOI_BLS12_381_2_PARAMS,
+            ANEMOI_GOLDILOCKS_8_PARAMS,
+            ANEMOI_GOLDILOCKS_12_PARAMS,
+            ANEMOI_BABYBEAR_16_PARAMS,
+            ANEMOI_BABYBEAR_24_PARAMS,
+            ANEMOI_KOALABEAR_16_PARAMS,
+            ANEMOI_KOALABEAR_24_PARAMS,
+            ANEMOI_MERSENNE31_16_PARAMS,
+            ANEMOI_MERSENNE31_24_PARAMS,
+        ]
+    );
+
+    macro_rules! gmimc {
+        ($primitive:literal, [$($params:expr),* $(,)?]) => {
+            register!(list, GmimcErf, |p| meta(
+                $primitive,
+                p.t,
+                p.rounds,
+                Some(p.d),
+                "generated",
+            ), [$($params),*]);
+        };
+    }
+    gmimc!(
+        "gmimc-erf",
+        [
+            GMIMC_ERF_BN254_2_PARAMS,
+            GMIMC_ERF_BN254_3_PARAMS,
+            GMIMC_ERF_BLS12_381_2_PARAMS,
+            GMIMC_ERF_BLS12_381_3_PARAMS,
+            GMIMC_ERF_GOLDILOCKS_8_PARAMS,
+            GMIMC_ERF_GOLDILOCKS_12_PARAMS,
+            GMIMC_ERF_BABYBEAR_16_PARAMS,
+            GMIMC_ERF_BABYBEAR_24_PARAMS,
+            GMIMC_ERF_KOALABEAR_16_PARAMS,
+            GMIMC_ERF_KOALABEAR_24_PARAMS,
+            GMIMC_ERF_MERSENNE31_16_PARAMS,
+            GMIMC_ERF_MERSENNE31_24_PARAMS,
+        ]
+    );
+    gmimc!(
+        "gmimc-erf-alpha3",
+        [
+            GMIMC_ERF_ALPHA3_BN254_2_PARAMS,
+            GMIMC_ERF_ALPHA3_BN254_3_PARAMS,
+            GMIMC_ERF_ALPHA3_BLS12_381_2_PARAMS,
+            GMIMC_ERF_ALPHA3_BLS12_381_3_PARAMS,
+            GMIMC_ERF_ALPHA3_GOLDILOCKS_8_PARAMS,
+            GMIMC_ERF_ALPHA3_GOLDILOCKS_12_PARAMS,
+            GMIMC_ERF_ALPHA3_BABYBEAR_16_PARAMS,
+            GMIMC_ERF_ALPHA3_BABYBEAR_24_PARAMS,
+            GMIMC_ERF_ALPHA3_KOALABEAR_16_PARAMS,
+            GMIMC_ERF_ALPHA3_KOALABEAR_24_PARAMS,
+            GMIMC_ERF_ALPHA3_MERSENNE31_16_PARAMS,
+            GMIMC_ERF_ALPHA3_MERSENNE31_24_PARAMS,
+        ]
+    );
+
+    register!(
+        list,
+        Griffin,
+        |p| meta("griffin", p.t, p.rounds, Some(p.d), "sok",),
+        [
+            GRIFFIN_BN254_3_PARAMS,
+            GRIFFIN_BLS12_381_3_PARAMS,
+            GRIFFIN_GOLDILOCKS_8_PARAMS,
+            GRIFFIN_GOLDILOCKS_12_PARAMS,
+        ]
+    );
+
+    register!(
+        list,
+        Neptune,
+        |p| meta("neptune", p.t, p.rounds, Some(p.d), "sok",),
+        [
+            NEPTUNE_BN254_2_PARAMS,
+            NEPTUNE_BN254_4_PARAMS,
+            NEPTUNE_BLS12_381_2_PARAMS,
+            NEPTUNE_BLS12_381_4_PARAMS,
+            NEPTUNE_GOLDILOCKS_8_PARAMS,
+            NEPTUNE_GOLDILOCKS_12_PARAMS,
+            NEPTUNE_BABYBEAR_16_PARAMS,
+            NEPTUNE_BABYBEAR_24_PARAMS,
+            NEPTUNE_KOALABEAR_16_PARAMS,
+            NEPTUNE_KOALABEAR_24_PARAMS,
+            NEPTUNE_MERSENNE31_16_PARAMS,
+            NEPTUNE_MERSENNE31_24_PARAMS,
+        ]
+    );
+
+    // The Bricks layer of Reinforced Concrete is x^5 on the first element.
+    for (primitive, lookup) in lookup_modes("reinforced-concrete", "reinforced-concrete-ct") {
+        register!(
+            list,
+            ReinforcedConcrete::new_with_lookup(lookup),
+            |p| meta(
+                primitive,
+                p.t,
+                ReinforcedConcreteParams::<Bn254>::TOTAL_ROUNDS,
+                Some(5),
+                "sok",
+            ),
+            [
+                REINFORCED_CONCRETE_BN254_3_PARAMS,
+                REINFORCED_CONCRETE_BLS12_381_3_PARAMS
+            ]
+        );
+    }
+
+    for (primitive, lookup) in lookup_modes("polocolo", "polocolo-ct") {
+        register!(
+            list,
+            Polocolo::new_with_lookup(lookup),
+            |p| meta(primitive, p.t, p.rounds, None, "generated"),
+            [
+                POLOCOLO_BN254_2_PARAMS,
+                POLOCOLO_BN254_3_PARAMS,
+                POLOCOLO_BN254_4_PARAMS,
+                POLOCOLO_BN254_8_PARAMS,
+                POLOCOLO_BLS12_381_2_PARAMS,
+                POLOCOLO_BLS12_381_3_PARAMS,
+                POLOCOLO_BLS12_381_4_PARAMS,
+                POLOCOLO_BLS12_381_8_PARAMS,
+            ]
+        );
+    }
+
+    // Skyscraper squares in an extension of degree n, on a state of two extension elements.
+    register!(
+        list,
+        Skyscraper,
+        |p| meta("skyscraper", 2 * p.n, p.rounds, Some(2), "sok",),
+        [
+            SKYSCRAPER_BN254_2_PARAMS,
+            SKYSCRAPER_BN254_3_PARAMS,
+            SKYSCRAPER_BLS12_381_2_PARAMS,
+            SKYSCRAPER_BLS12_381_3_PARAMS,
+        ]
+    );
+
+    // Monolith's Bricks layer squares.
+    for (primitive, lookup) in lookup_modes("monolith", "monolith-ct") {
+        register!(
+            list,
+            Monolith64::new_with_lookup(lookup),
+            |p| meta(primitive, p.t, p.rounds, Some(2), "sok",),
+            [MONOLITH_GOLDILOCKS_8_PARAMS, MONOLITH_GOLDILOCKS_12_PARAMS]
+        );
+        register!(
+            list,
+            Monolith31::new_with_lookup(lookup),
+            |p| meta(primitive, p.t, p.rounds, Some(2), "sok",),
+            [
+                MONOLITH_BABYBEAR_16_PARAMS,
+                MONOLITH_BABYBEAR_24_PARAMS,
+                MONOLITH_KOALABEAR_16_PARAMS,
+                MONOLITH_KOALABEAR_24_PARAMS,
+                MONOLITH_MERSENNE31_16_PARAMS,
+                MONOLITH_MERSENNE31_24_PARAMS,
+            ]
+        );
+    }
+
+    for (primitive, lookup) in lookup_modes("tip5", "tip5-ct") {
+        register!(
+            list,
+            Tip5::new_with_lookup(lookup),
+            |p| Meta {
+                rate: Some(tip5::RATE),
+                ..meta(primitive, p.t, p.rounds, Some(p.d), "triton-vm")
+            },
+            [TIP5_GOLDILOCKS_PARAMS]
+        );
+        register!(
+            list,
+            Tip5::new_with_lookup(lookup),
+            |p| Meta {
+                rate: Some(tip5::RATE),
+                ..meta(primitive, p.t, p.rounds, Some(p.d), "generated")
+            },
+            [
+                TIP5_BABYBEAR_16_PARAMS,
+                TIP5_KOALABEAR_16_PARAMS,
+                TIP5_MERSENNE31_16_PARAMS
+            ]
+        );
+    }
+
+    // Tip4' is Winterfell's Rp64_256 layout.
+    for (primitive, lookup) in lookup_modes("tip4p", "tip4p-ct") {
+        register!(
+            list,
+            Tip4::new_with_lookup(lookup),
+            |p| Meta {
+                rate: Some(tip4::RATE_WIDTH),
+                ..meta(primitive, p.t, p.rounds, Some(7), "winterfell")
+            },
+            [TIP4P_GOLDILOCKS_PARAMS]
+        );
+    }
+
+    // The original Tip4 is permutation-only, so it has no rate.
+    for (primitive, lookup) in lookup_modes("tip4", "tip4-ct") {
+        register!(
+            list,
+            Tip4::new_with_lookup(lookup),
+            |p| meta(primitive, p.t, p.rounds, Some(7), "generated"),
+            [TIP4_GOLDILOCKS_PARAMS]
+        );
+    }
+
+    list
+}
+
+lazy_static! {
+    static ref REGISTRY: Vec<Arc<dyn ErasedPermutation>> = build();
+}
+
+/// Metadata of all instances, grouped by primitive.
+pub fn instances() -> impl Iterator<Item = &'static InstanceInfo> {
+    REGISTRY.iter().map(|perm| perm.info())
+}
+
+/// The instance called `name`, e.g. `"poseidon2/babybear/24"`.
+pub fn get(name: &str) -> Option<Arc<dyn ErasedPermutation>> {
+    REGISTRY
+        .iter()
+        .find(|perm| perm.info().name == name)
+        .map(Arc::clone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get, instances};
+    use crate::fields::babybear::BabyBear;
+    use crate::fields::bn254::Bn254;
+    use crate::fields::{FieldElement, FieldParseError, PrimeFieldExt, PrimeFieldWords};
+    use crate::poseidon::instances::POSEIDON_BN254_3_PARAMS;
+    use crate::poseidon::poseidon::Poseidon;
+    use crate::poseidon2::instances::POSEIDON2_BABYBEAR_24_PARAMS;
+    use crate::poseidon2::poseidon2::Poseidon2;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn names_are_unique_and_resolve() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let mut names = HashSet::new();
+        for info in instances() {
+            assert!(names.insert(info.name.clone()), "duplicate {}", info.name);
+            assert!(info.rate >= 1 && info.rate < info.width, "{}", info.name);
+
+            let perm = get(&info.name).unwrap();
+            let input = perm.random_limbs(&mut rng);
+            assert_eq!(input.len(), info.width * info.limbs_per_element);
+            assert_eq!(perm.permute_limbs(&input).unwrap().len(), input.len());
+        }
+        assert!(get("poseidon2/babybear/23").is_none());
+
+        // Constant-time lookup variants compute the same permutation.
+        for ct in names.iter().filter(|name| name.contains("-ct/")) {
+            let perm = get(ct).unwrap();
+            let input = perm.random_limbs(&mut rng);
+            assert_eq!(
+                perm.permute_limbs(&input),
+                get(&ct.replacen("-ct/", "/", 1))
+                    .unwrap()
+                    .permute_limbs(&input)
+            );
+        }
+
+        let info = get("poseidon-circom/bn254/17").unwrap().info().clone();
+        assert_eq!(
+            (info.width, info.rounds, info.source),
+            (17, 8 + 68, "circomlib")
+        );
+    }
+
+    #[test]
+    fn encodings_match_typed_permutations() {
+        let perm = get("poseidon2/babybear/24").unwrap();
+        let input: Vec<BabyBear> = (0..24).map(BabyBear::from_u64).collect();
+        let want = Poseidon2::new(&POSEIDON2_BABYBEAR_24_PARAMS).permutation(&input);
+        let limbs: Vec<u64> = input.iter().map(|x| x.to_words_le()[0]).collect();
+        let got = perm.permute_limbs(&limbs).unwrap();
+        assert_eq!(
+            got,
+            want.iter().map(|x| x.to_words_le()[0]).collect::<Vec<_>>()
+        );
+
+        let perm = get("poseidon/bn254/3").unwrap();
+        assert_eq!(perm.info().limbs_per_element, 4);
+        let input: Vec<Bn254> = (0..3).map(Bn254::from_u64).collect();
+        let want = Poseidon::new(&POSEIDON_BN254_3_PARAMS).permutation(&input);
+        let bytes: Vec<u8> = input.iter().flat_map(|x| x.to_bytes_le()).collect();
+        let got = perm.permute_bytes(&bytes).unwrap();
+        assert_eq!(
+            got,
+            want.iter()
+                .flat_map(|x| x.to_bytes_le())
+                .collect::<Vec<_>>()
+        );
+
+        // Wrong sizes and non-canonical elements are rejected.
+        assert_eq!(
+            perm.permute_bytes(&bytes[1..]),
+            Err(FieldParseError::InvalidLength)
+        );
+        assert_eq!(
+            perm.permute_limbs(&[u64::MAX; 12]),
+            Err(FieldParseError::NonCanonical)
+        );
+        let perm = get("poseidon2/babybear/24").unwrap();
+        assert_eq!(
+            perm.permute_limbs(&[1 << 32; 24]),
+            Err(FieldParseError::NonCanonical)
+        );
+    }
+}
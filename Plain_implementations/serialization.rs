@@ -0,0 +1,1304 @@
+//! JSON (de)serialization of parameter sets, behind the `serde` feature.
+//!
+//! Every `*Params` struct is serialized through a flat schema holding the arguments of its
+//! constructor rather than its internal, partly precomputed layout:
+//!
+//! - Field elements are strings. They are written as canonical big-endian hex (`"0x"` followed by
+//!   two digits per byte, see `PrimeFieldExt::to_hex`); hex with a `0x` prefix and decimal are
+//!   both accepted when reading, but values must be smaller than the modulus.
+//! - Exponents wider than 64 bits (`d_inv`, `alpha_inv`, `ann`) are hex strings as well.
+//! - Matrices and round constants are arrays of rows, `[round_idx][state_idx]`.
+//! - Unknown keys are rejected.
+//!
+//! For example, a Poseidon2 instance reads
+//!
+//! ```json
+//! {
+//!   "t": 3, "d": 5, "rounds_f": 8, "rounds_p": 56,
+//!   "mat_external": [["0x...", ...], ...],
+//!   "mat_internal": [["0x...", ...], ...],
+//!   "round_constants": [["0x...", ...], ...]
+//! }
+//! ```
+//!
+//! The per-primitive schemas are the `*Spec` structs below. [`from_json`] validates a spec with
+//! the same checks as the corresponding constructor and returns a [`ParamsError`] instead of
+//! panicking.
+
+use crate::anemoi::anemoi::AnemoiParams;
+use crate::fields::{
+    biguint_from_limbs_le, biguint_to_limbs_le_4, FieldElement, PrimeField, PrimeFieldExt,
+    PrimeFieldWords,
+};
+use crate::gmimc_erf::gmimc_erf::GmimcErfParams;
+use crate::griffin::griffin::GriffinParams;
+use crate::monolith::monolith_params::{
+    Monolith31Params, Monolith64Params, MonolithField32, MonolithField64,
+};
+use crate::neptune::neptune::NeptuneParams;
+use crate::polocolo::polocolo::PolocoloParams;
+use crate::poseidon::poseidon::{PoseidonParams, PoseidonVariant};
+use crate::poseidon2::poseidon2::{matmul_external, Poseidon2Params};
+use crate::reinforced_concrete::reinforced_concrete::ReinforcedConcreteParams;
+use crate::rescueprime::rescue_prime::RescuePrimeParams;
+use crate::rpo::rpo::{RpoField, RpoParams, DIGEST_SIZE};
+use crate::skyscraper::skyscraper::{SkyscraperParams, TOTAL_ROUNDS as SKYSCRAPER_ROUNDS};
+use crate::tip4::tip4::{Tip4Field, Tip4Params};
+use crate::tip5::tip5::{Tip5Field, Tip5Params};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub enum ParamsError {
+    /// The input is not valid JSON for the schema, or a field element is malformed.
+    Json(serde_json::Error),
+    /// A vector or matrix dimension does not match the other parameters.
+    Length {
+        field: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    /// A parameter is outside of the values the permutation supports.
+    Invalid {
+        field: &'static str,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for ParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamsError::Json(e) => write!(f, "{e}"),
+            ParamsError::Length {
+                field,
+                expected,
+                found,
+            } => write!(f, "`{field}` has length {found}, expected {expected}"),
+            ParamsError::Invalid { field, reason } => write!(f, "invalid `{field}`: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ParamsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParamsError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for ParamsError {
+    fn from(e: serde_json::Error) -> Self {
+        ParamsError::Json(e)
+    }
+}
+
+/// A parameter set with a serializable schema.
+pub trait ParamsSchema: Sized {
+    type Spec: Serialize + DeserializeOwned;
+
+    fn to_spec(&self) -> Self::Spec;
+
+    /// Builds the parameters, checking everything the constructor would assert.
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError>;
+}
+
+/// Pretty-printed JSON of `params`.
+pub fn to_json<P: ParamsSchema>(params: &P) -> String {
+    serde_json::to_string_pretty(&params.to_spec()).expect("specs serialize to JSON")
+}
+
+/// Parses and validates a parameter set, ready to be passed to the permutation's `new`.
+pub fn from_json<P: ParamsSchema>(json: &str) -> Result<Arc<P>, ParamsError> {
+    let spec = serde_json::from_str(json)?;
+    P::from_spec(spec).map(Arc::new)
+}
+
+fn check_len(field: &'static str, found: usize, expected: usize) -> Result<(), ParamsError> {
+    if found == expected {
+        Ok(())
+    } else {
+        Err(ParamsError::Length {
+            field,
+            expected,
+            found,
+        })
+    }
+}
+
+fn check_matrix<T>(
+    field: &'static str,
+    matrix: &[Vec<T>],
+    rows: usize,
+    cols: usize,
+) -> Result<(), ParamsError> {
+    check_len(field, matrix.len(), rows)?;
+    for row in matrix {
+        check_len(field, row.len(), cols)?;
+    }
+    Ok(())
+}
+
+fn check(field: &'static str, ok: bool, reason: &'static str) -> Result<(), ParamsError> {
+    if ok {
+        Ok(())
+    } else {
+        Err(ParamsError::Invalid { field, reason })
+    }
+}
+
+fn check_d(d: u64) -> Result<(), ParamsError> {
+    check("d", matches!(d, 3 | 5 | 7), "must be 3, 5 or 7")
+}
+
+fn check_montgomery_factor<F: FieldElement>(r: &F, r_inv: &F) -> Result<(), ParamsError> {
+    let mut product = r.clone();
+    product.mul_assign(r_inv);
+    check("r_inv", product == F::one(), "must be the inverse of `r`")
+}
+
+// Exponents of up to 256 bits as little-endian limbs, written as a hex string.
+mod exponent {
+    use super::{biguint_from_limbs_le, biguint_to_limbs_le_4};
+    use num_bigint::BigUint;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(words: &[u64; 4], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!(
+            "0x{}",
+            biguint_from_limbs_le(words).to_str_radix(16)
+        ))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u64; 4], D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let value = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+            None => BigUint::parse_bytes(s.as_bytes(), 10),
+        }
+        .ok_or_else(|| D::Error::custom("invalid exponent"))?;
+        if value.bits() > 256 {
+            return Err(D::Error::custom("exponent does not fit in 256 bits"));
+        }
+        Ok(biguint_to_limbs_le_4(&value))
+    }
+}
+
+// Serde impls on the parameter structs going through their spec, so that they can also be
+// embedded in other serializable types.
+macro_rules! impl_serde_via_spec {
+    ($($params:ident<F: $bound:path>),* $(,)?) => {$(
+        impl<F: $bound> Serialize for $params<F>
+        where
+            $params<F>: ParamsSchema,
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.to_spec().serialize(serializer)
+            }
+        }
+
+        impl<'de, F: $bound> Deserialize<'de> for $params<F>
+        where
+            $params<F>: ParamsSchema,
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let spec = <Self as ParamsSchema>::Spec::deserialize(deserializer)?;
+                Self::from_spec(spec).map_err(serde::de::Error::custom)
+            }
+        }
+    )*};
+}
+
+impl_serde_via_spec!(
+    AnemoiParams<F: FieldElement>,
+    GmimcErfParams<F: FieldElement>,
+    GriffinParams<F: FieldElement>,
+    Monolith31Params<F: MonolithField32>,
+    Monolith64Params<F: MonolithField64>,
+    NeptuneParams<F: FieldElement>,
+    PolocoloParams<F: FieldElement>,
+    PoseidonParams<F: FieldElement>,
+    Poseidon2Params<F: FieldElement>,
+    ReinforcedConcreteParams<F: PrimeFieldWords>,
+    RescuePrimeParams<F: FieldElement>,
+    RpoParams<F: RpoField>,
+    SkyscraperParams<F: PrimeField>,
+    Tip4Params<F: Tip4Field>,
+    Tip5Params<F: Tip5Field>,
+);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PoseidonSpec<F> {
+    pub t: usize,
+    pub d: u64,
+    pub rounds_f: usize,
+    pub rounds_p: usize,
+    pub variant: PoseidonVariant,
+    pub mds_full: Vec<Vec<F>>,
+    /// Only for the `"minit"` variant; `"original"` uses `mds_full` in every round.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mds_partial: Option<Vec<Vec<F>>>,
+    pub round_constants: Vec<Vec<F>>,
+}
+
+impl<F: FieldElement + Serialize + DeserializeOwned> ParamsSchema for PoseidonParams<F> {
+    type Spec = PoseidonSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        PoseidonSpec {
+            t: self.t,
+            d: self.d,
+            rounds_f: 2 * self.rounds_f_beginning,
+            rounds_p: self.rounds_p,
+            variant: self.variant,
+            mds_full: self.mds_full.clone(),
+            mds_partial: (self.variant == PoseidonVariant::Minit).then(|| self.mds_partial.clone()),
+            round_constants: self.round_constants.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        let t = spec.t;
+        check("d", matches!(spec.d, 3 | 5 | 7 | 11), "must be 3, 5, 7 or 11")?;
+        check("rounds_f", spec.rounds_f % 2 == 0, "must be even")?;
+        check_matrix("mds_full", &spec.mds_full, t, t)?;
+        check_matrix(
+            "round_constants",
+            &spec.round_constants,
+            spec.rounds_f + spec.rounds_p,
+            t,
+        )?;
+
+        match (spec.variant, spec.mds_partial) {
+            (PoseidonVariant::Minit, Some(mds_partial)) => {
+                check_matrix("mds_partial", &mds_partial, t, t)?;
+                Ok(PoseidonParams::new(
+                    t,
+                    spec.d,
+                    spec.rounds_f,
+                    spec.rounds_p,
+                    &spec.mds_full,
+                    &mds_partial,
+                    &spec.round_constants,
+                ))
+            }
+            (PoseidonVariant::Minit, None) => Err(ParamsError::Invalid {
+                field: "mds_partial",
+                reason: "required by the minit variant",
+            }),
+            (PoseidonVariant::Original, Some(_)) => Err(ParamsError::Invalid {
+                field: "mds_partial",
+                reason: "not used by the original variant",
+            }),
+            (PoseidonVariant::Original, None) => Ok(PoseidonParams::new_original(
+                t,
+                spec.d,
+                spec.rounds_f,
+                spec.rounds_p,
+                &spec.mds_full,
+                &spec.round_constants,
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Poseidon2Spec<F> {
+    pub t: usize,
+    pub d: u64,
+    pub rounds_f: usize,
+    pub rounds_p: usize,
+    pub mat_external: Vec<Vec<F>>,
+    pub mat_internal: Vec<Vec<F>>,
+    pub round_constants: Vec<Vec<F>>,
+}
+
+impl<F: FieldElement + Serialize + DeserializeOwned> ParamsSchema for Poseidon2Params<F> {
+    type Spec = Poseidon2Spec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        Poseidon2Spec {
+            t: self.t,
+            d: self.d,
+            rounds_f: 2 * self.rounds_f_beginning,
+            rounds_p: self.rounds_p,
+            mat_external: self.mat_external.clone(),
+            mat_internal: self.mat_internal.clone(),
+            round_constants: self.round_constants.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        let t = spec.t;
+        check(
+            "t",
+            t == 2 || t == 3 || (t >= 4 && t.is_multiple_of(4)),
+            "must be 2, 3 or a multiple of 4",
+        )?;
+        check_d(spec.d)?;
+        check("rounds_f", spec.rounds_f % 2 == 0, "must be even")?;
+        check_matrix("mat_external", &spec.mat_external, t, t)?;
+        check_matrix("mat_internal", &spec.mat_internal, t, t)?;
+        check_matrix(
+            "round_constants",
+            &spec.round_constants,
+            spec.rounds_f + spec.rounds_p,
+            t,
+        )?;
+        check_poseidon2_matrices(&spec.mat_external, &spec.mat_internal)?;
+
+        Ok(Poseidon2Params::new(
+            t,
+            spec.d,
+            spec.rounds_f,
+            spec.rounds_p,
+            &spec.mat_external,
+            &spec.mat_internal,
+            &spec.round_constants,
+        ))
+    }
+}
+
+// The linear layers are hard-wired: the external matrix only depends on `t`, and the internal
+// one is `J + diag(diag_m_1)`, where only the diagonal is read and only for `t >= 4`.
+fn check_poseidon2_matrices<F: FieldElement>(
+    mat_external: &[Vec<F>],
+    mat_internal: &[Vec<F>],
+) -> Result<(), ParamsError> {
+    let t = mat_external.len();
+    let external = (0..t).all(|j| {
+        let mut col = vec![F::zero(); t];
+        col[j] = F::one();
+        matmul_external(&mut col);
+        mat_external.iter().zip(col.iter()).all(|(row, x)| row[j] == *x)
+    });
+    check(
+        "mat_external",
+        external,
+        "must be the matrix of the external layer for this `t`",
+    )?;
+
+    let fixed_diag: &[u64] = match t {
+        2 => &[2, 3],
+        3 => &[2, 2, 3],
+        _ => &[],
+    };
+    let internal = mat_internal.iter().enumerate().all(|(i, row)| {
+        row.iter().enumerate().all(|(j, x)| match (i == j, fixed_diag.get(i)) {
+            (false, _) => *x == F::one(),
+            (true, Some(&d)) => *x == F::from_u64(d),
+            (true, None) => true,
+        })
+    });
+    check(
+        "mat_internal",
+        internal,
+        "must be one plus a diagonal, with the fixed diagonal for `t` below 4",
+    )
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnemoiSpec<F> {
+    pub n_cols: usize,
+    pub rounds: usize,
+    pub alpha: u64,
+    #[serde(with = "exponent")]
+    pub alpha_inv: [u64; 4],
+    pub beta: F,
+    pub delta: F,
+    pub mds: Vec<Vec<F>>,
+    pub round_constants_c: Vec<Vec<F>>,
+    pub round_constants_d: Vec<Vec<F>>,
+}
+
+impl<F: FieldElement + Serialize + DeserializeOwned> ParamsSchema for AnemoiParams<F> {
+    type Spec = AnemoiSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        AnemoiSpec {
+            n_cols: self.n_cols,
+            rounds: self.rounds,
+            alpha: self.alpha,
+            alpha_inv: self.alpha_inv,
+            beta: self.beta.clone(),
+            delta: self.delta.clone(),
+            mds: self.mds.clone(),
+            round_constants_c: self.round_constants_c.clone(),
+            round_constants_d: self.round_constants_d.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        let l = spec.n_cols;
+        check("n_cols", l >= 1, "must be positive")?;
+        check_matrix("mds", &spec.mds, l, l)?;
+        check_matrix("round_constants_c", &spec.round_constants_c, spec.rounds, l)?;
+        check_matrix("round_constants_d", &spec.round_constants_d, spec.rounds, l)?;
+
+        Ok(AnemoiParams {
+            n_cols: l,
+            width: 2 * l,
+            rounds: spec.rounds,
+            alpha: spec.alpha,
+            alpha_inv: spec.alpha_inv,
+            beta: spec.beta,
+            delta: spec.delta,
+            mds: spec.mds,
+            round_constants_c: spec.round_constants_c,
+            round_constants_d: spec.round_constants_d,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GmimcErfSpec<F> {
+    pub t: usize,
+    pub d: u64,
+    pub rounds: usize,
+    /// One constant per round.
+    pub round_constants: Vec<F>,
+}
+
+impl<F: FieldElement + Serialize + DeserializeOwned> ParamsSchema for GmimcErfParams<F> {
+    type Spec = GmimcErfSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        GmimcErfSpec {
+            t: self.t,
+            d: self.d,
+            rounds: self.rounds,
+            round_constants: self.round_constants.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        check("t", spec.t >= 2, "must be at least 2")?;
+        check_len("round_constants", spec.round_constants.len(), spec.rounds)?;
+        Ok(GmimcErfParams::new(
+            spec.t,
+            spec.d,
+            spec.rounds,
+            &spec.round_constants,
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RescuePrimeSpec<F> {
+    pub t: usize,
+    pub d: u64,
+    #[serde(with = "exponent")]
+    pub d_inv: [u64; 4],
+    pub rounds: usize,
+    pub mds: Vec<Vec<F>>,
+    /// Two rows per round.
+    pub round_constants: Vec<Vec<F>>,
+}
+
+impl<F: FieldElement + Serialize + DeserializeOwned> ParamsSchema for RescuePrimeParams<F> {
+    type Spec = RescuePrimeSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        RescuePrimeSpec {
+            t: self.t,
+            d: self.d,
+            d_inv: self.d_inv,
+            rounds: self.rounds,
+            mds: self.mds.clone(),
+            round_constants: self.round_constants.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        let t = spec.t;
+        check_d(spec.d)?;
+        check_matrix("mds", &spec.mds, t, t)?;
+        check_matrix("round_constants", &spec.round_constants, 2 * spec.rounds, t)?;
+        Ok(RescuePrimeParams::new(
+            t,
+            spec.d,
+            spec.d_inv,
+            spec.rounds,
+            &spec.mds,
+            &spec.round_constants,
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NeptuneSpec<F> {
+    pub t: usize,
+    pub d: u64,
+    pub rounds_f: usize,
+    pub rounds_p: usize,
+    pub m_e: Vec<Vec<F>>,
+    pub mu: Vec<F>,
+    pub round_constants: Vec<Vec<F>>,
+    pub gamma: F,
+}
+
+impl<F: FieldElement + Serialize + DeserializeOwned> ParamsSchema for NeptuneParams<F> {
+    type Spec = NeptuneSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        NeptuneSpec {
+            t: self.t,
+            d: self.d,
+            rounds_f: 2 * self.rounds_f_beginning,
+            rounds_p: self.rounds_p,
+            m_e: self.m_e.clone(),
+            mu: self.mu.clone(),
+            round_constants: self.round_constants.clone(),
+            gamma: self.gamma.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        let t = spec.t;
+        check_d(spec.d)?;
+        check("rounds_f", spec.rounds_f % 2 == 0, "must be even")?;
+        check("t", t % 2 == 0, "must be even")?;
+        check_matrix("m_e", &spec.m_e, t, t)?;
+        check_len("mu", spec.mu.len(), t)?;
+        check_matrix(
+            "round_constants",
+            &spec.round_constants,
+            spec.rounds_f + spec.rounds_p,
+            t,
+        )?;
+        Ok(NeptuneParams::new(
+            t,
+            spec.d,
+            spec.rounds_f,
+            spec.rounds_p,
+            &spec.m_e,
+            &spec.mu,
+            &spec.round_constants,
+            &spec.gamma,
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GriffinSpec<F> {
+    pub t: usize,
+    pub d: u64,
+    #[serde(with = "exponent")]
+    pub d_inv: [u64; 4],
+    pub rounds: usize,
+    pub alpha_beta: Vec<[F; 2]>,
+    /// Rows for all but the last round.
+    pub round_constants: Vec<Vec<F>>,
+}
+
+impl<F: FieldElement + Serialize + DeserializeOwned> ParamsSchema for GriffinParams<F> {
+    type Spec = GriffinSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        GriffinSpec {
+            t: self.t,
+            d: self.d,
+            d_inv: self.d_inv,
+            rounds: self.rounds,
+            alpha_beta: self.alpha_beta.clone(),
+            round_constants: self.round_constants.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        let t = spec.t;
+        check(
+            "t",
+            t == 3 || (t >= 8 && t % 4 == 0),
+            "must be 3 or a multiple of 4 from 8 on",
+        )?;
+        check("rounds", spec.rounds >= 1, "must be positive")?;
+        check_len("alpha_beta", spec.alpha_beta.len(), t - 2)?;
+        check_matrix("round_constants", &spec.round_constants, spec.rounds - 1, t)?;
+        Ok(GriffinParams::new(
+            t,
+            spec.d,
+            spec.d_inv,
+            spec.rounds,
+            &spec.alpha_beta,
+            &spec.round_constants,
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RpoSpec<F> {
+    pub t: usize,
+    pub capacity: usize,
+    #[serde(with = "exponent")]
+    pub d_inv: [u64; 4],
+    pub rounds: usize,
+    /// First row of the circulant MDS matrix.
+    pub mds_row: Vec<u64>,
+    pub ark1: Vec<Vec<F>>,
+    pub ark2: Vec<Vec<F>>,
+}
+
+impl<F: RpoField + Serialize + DeserializeOwned> ParamsSchema for RpoParams<F> {
+    type Spec = RpoSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        RpoSpec {
+            t: self.t,
+            capacity: self.capacity,
+            d_inv: self.d_inv,
+            rounds: self.rounds,
+            mds_row: self.mds_row.clone(),
+            ark1: self.ark1.clone(),
+            ark2: self.ark2.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        let t = spec.t;
+        check(
+            "capacity",
+            spec.capacity + DIGEST_SIZE <= t,
+            "must leave room for the digest",
+        )?;
+        check_len("mds_row", spec.mds_row.len(), t)?;
+        check(
+            "mds_row",
+            spec.mds_row
+                .iter()
+                .try_fold(0u64, |acc, &x| acc.checked_add(x))
+                .is_some_and(|sum| sum < (1u64 << 32)),
+            "entries must sum to less than 2^32",
+        )?;
+        check_matrix("ark1", &spec.ark1, spec.rounds, t)?;
+        check_matrix("ark2", &spec.ark2, spec.rounds, t)?;
+        Ok(RpoParams::new(
+            t,
+            spec.capacity,
+            spec.d_inv,
+            spec.rounds,
+            &spec.mds_row,
+            &spec.ark1,
+            &spec.ark2,
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReinforcedConcreteSpec<F> {
+    pub si: Vec<u16>,
+    /// The s-box on `[0, v)`; it is extended by the identity up to the largest `si`.
+    pub sbox: Vec<u16>,
+    pub alphas: Vec<u16>,
+    pub betas: Vec<u16>,
+    /// `TOTAL_ROUNDS + 1` rows of width `alphas.len() + 1`.
+    pub round_constants: Vec<Vec<F>>,
+}
+
+impl<F: PrimeFieldWords + Serialize + DeserializeOwned> ParamsSchema
+    for ReinforcedConcreteParams<F>
+{
+    type Spec = ReinforcedConcreteSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        ReinforcedConcreteSpec {
+            si: self.si.clone(),
+            sbox: self.sbox.clone(),
+            alphas: self.alphas.clone(),
+            betas: self
+                .betas
+                .iter()
+                .map(|b| b.to_words_le()[0] as u16)
+                .collect(),
+            round_constants: self.round_constants.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        check(
+            "alphas",
+            !spec.alphas.is_empty(),
+            "state width must be at least 2",
+        )?;
+        check_len("betas", spec.betas.len(), spec.alphas.len())?;
+        let max_si = spec.si.iter().max().copied();
+        check("si", max_si.is_some(), "must not be empty")?;
+        check("si", !spec.si.contains(&0), "must not contain 0")?;
+        let capacity: BigUint = spec.si.iter().map(|&s| BigUint::from(s)).product();
+        check(
+            "si",
+            capacity >= F::modulus(),
+            "the product must be at least the modulus",
+        )?;
+        check(
+            "si",
+            capacity.bits() <= 256,
+            "the product must be below 2^256",
+        )?;
+        check(
+            "sbox",
+            max_si.is_some_and(|max| spec.sbox.len() <= max as usize),
+            "must not be longer than the largest `si`",
+        )?;
+        // A digit below `si[i]` must stay below `si[i]` after the lookup.
+        check(
+            "sbox",
+            spec.sbox.iter().enumerate().all(|(x, &y)| {
+                spec.si.iter().all(|&s| x >= s as usize || y < s)
+            }),
+            "must map digits below each `si` below that `si`",
+        )?;
+        let t = spec.alphas.len() + 1;
+        check_matrix(
+            "round_constants",
+            &spec.round_constants,
+            Self::TOTAL_ROUNDS + 1,
+            t,
+        )?;
+
+        let mut params = Self::new(&spec.si, &spec.sbox, &spec.alphas, &spec.betas);
+        params.round_constants = spec.round_constants;
+        Ok(params)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolocoloSpec<F> {
+    pub t: usize,
+    pub m: usize,
+    /// `(p - 1) / m`.
+    #[serde(with = "exponent")]
+    pub ann: [u64; 4],
+    pub rounds: usize,
+    pub mds: Vec<Vec<F>>,
+    pub round_constants: Vec<Vec<F>>,
+    /// `[input, output]` pairs covering the subgroup of order `m` generated by `g^ann`.
+    pub lut: Vec<(F, F)>,
+}
+
+impl<F: PrimeFieldWords + Serialize + DeserializeOwned> ParamsSchema for PolocoloParams<F> {
+    type Spec = PolocoloSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        let ann = biguint_from_limbs_le(&self.ann_minus_one) + 1u32;
+        let lut = self
+            .subgroup
+            .iter()
+            .map(|&(words, r)| {
+                let input = F::from_words_le(words);
+                let mut output = self.lut[r].clone();
+                output.mul_assign(&input);
+                (input, output)
+            })
+            .collect();
+
+        PolocoloSpec {
+            t: self.t,
+            m: self.lut.len(),
+            ann: biguint_to_limbs_le_4(&ann),
+            rounds: self.rounds,
+            mds: self.mds.clone(),
+            round_constants: self.round_constants.clone(),
+            lut,
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        let t = spec.t;
+        check("rounds", spec.rounds >= 1, "must be positive")?;
+        check_matrix("mds", &spec.mds, t, t)?;
+        check_matrix("round_constants", &spec.round_constants, spec.rounds, t)?;
+        check("m", spec.m >= 1, "must be positive")?;
+        check(
+            "ann",
+            biguint_from_limbs_le(&spec.ann) * spec.m == F::modulus() - 1u32,
+            "must be (p - 1) / m",
+        )?;
+
+        // With ann = (p - 1) / m, g^ann has order exactly m.
+        let lut: Vec<_> = spec
+            .lut
+            .iter()
+            .map(|(k, v)| (k.to_words_le(), v.clone()))
+            .collect();
+        let keys: HashSet<[u64; 4]> = lut.iter().map(|(k, _)| *k).collect();
+        let h = F::from_biguint(&F::generator()).pow_words_le(&spec.ann);
+        let mut h_r = F::one();
+        for _ in 0..spec.m {
+            check(
+                "lut",
+                keys.contains(&h_r.to_words_le()),
+                "must cover the subgroup of order m",
+            )?;
+            h_r.mul_assign(&h);
+        }
+
+        Ok(PolocoloParams::new(
+            t,
+            spec.m,
+            spec.ann,
+            spec.rounds,
+            &spec.mds,
+            &spec.round_constants,
+            &lut,
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SkyscraperSpec<F> {
+    pub n: usize,
+    pub beta: u64,
+    /// `n` coefficients for each of the rounds that add constants.
+    pub round_constants: Vec<Vec<F>>,
+}
+
+impl<F: PrimeFieldExt + Serialize + DeserializeOwned> ParamsSchema for SkyscraperParams<F> {
+    type Spec = SkyscraperSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        SkyscraperSpec {
+            n: self.n,
+            beta: self
+                .beta_f
+                .to_biguint()
+                .to_u64()
+                .expect("beta is a small integer"),
+            round_constants: self
+                .round_constants
+                .iter()
+                .map(|rc| rc.coeffs.clone())
+                .collect(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        check("n", spec.n == 2 || spec.n == 3, "must be 2 or 3")?;
+        check(
+            "beta",
+            BigUint::from(spec.beta) < F::modulus(),
+            "must be smaller than the modulus",
+        )?;
+        check_matrix(
+            "round_constants",
+            &spec.round_constants,
+            SKYSCRAPER_ROUNDS - 2,
+            spec.n,
+        )?;
+        Ok(SkyscraperParams::new(
+            spec.n,
+            spec.beta,
+            &spec.round_constants,
+        ))
+    }
+}
+
+/// Monolith lookups are fixed by the field, so only the linear layer and constants are stored.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MonolithSpec<F> {
+    pub t: usize,
+    /// `R - 1` rows; the last round has no constants.
+    pub round_constants: Vec<Vec<F>>,
+    pub mds: Vec<Vec<F>>,
+}
+
+impl<F: MonolithField64 + Serialize + DeserializeOwned> ParamsSchema for Monolith64Params<F> {
+    type Spec = MonolithSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        MonolithSpec {
+            t: self.t,
+            round_constants: self.round_constants.clone(),
+            mds: self.mds.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        let t = spec.t;
+        check("t", t == 8 || t == 12, "must be 8 or 12")?;
+        check_matrix("round_constants", &spec.round_constants, Self::R - 1, t)?;
+        check_matrix("mds", &spec.mds, t, t)?;
+
+        let mut params = Self::new(t);
+        params.round_constants = spec.round_constants;
+        params.mds = spec.mds;
+        Ok(params)
+    }
+}
+
+impl<F: MonolithField32 + Serialize + DeserializeOwned> ParamsSchema for Monolith31Params<F> {
+    type Spec = MonolithSpec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        MonolithSpec {
+            t: self.t,
+            round_constants: self.round_constants.clone(),
+            mds: self.mds.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        let t = spec.t;
+        check("t", t == 16 || t == 24, "must be 16 or 24")?;
+        check_matrix("round_constants", &spec.round_constants, Self::R - 1, t)?;
+        check_matrix("mds", &spec.mds, t, t)?;
+
+        let mut params = Self::new(t);
+        params.round_constants = spec.round_constants;
+        params.mds = spec.mds;
+        Ok(params)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Tip5Spec<F> {
+    pub t: usize,
+    pub d: u64,
+    pub rounds: usize,
+    pub round_constants: Vec<Vec<F>>,
+    pub mds: Vec<Vec<F>>,
+    /// 256-entry byte tables, least significant byte first.
+    pub lookup_tables: Vec<Vec<u8>>,
+    /// Montgomery factor applied before splitting into bytes, and its inverse.
+    pub r: F,
+    pub r_inv: F,
+}
+
+impl<F: Tip5Field + Serialize + DeserializeOwned> ParamsSchema for Tip5Params<F> {
+    type Spec = Tip5Spec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        Tip5Spec {
+            t: self.t,
+            d: self.d,
+            rounds: self.rounds,
+            round_constants: self.round_constants.clone(),
+            mds: self.mds.clone(),
+            lookup_tables: self.lookup_tables.iter().map(|t| t.to_vec()).collect(),
+            r: self.r.clone(),
+            r_inv: self.r_inv.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        use crate::tip5::instances::NUM_SPLIT_AND_LOOKUP;
+
+        let t = spec.t;
+        check(
+            "t",
+            t >= NUM_SPLIT_AND_LOOKUP,
+            "must cover the split-and-lookup lanes",
+        )?;
+        check_matrix("round_constants", &spec.round_constants, spec.rounds, t)?;
+        check_matrix("mds", &spec.mds, t, t)?;
+        check(
+            "lookup_tables",
+            spec.lookup_tables.len() <= 8,
+            "at most one table per byte of a u64",
+        )?;
+        let lookup_tables = spec
+            .lookup_tables
+            .iter()
+            .map(|table| {
+                <[u8; 256]>::try_from(table.as_slice()).map_err(|_| ParamsError::Length {
+                    field: "lookup_tables",
+                    expected: 256,
+                    found: table.len(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        check_montgomery_factor(&spec.r, &spec.r_inv)?;
+
+        Ok(Tip5Params {
+            t,
+            d: spec.d,
+            rounds: spec.rounds,
+            round_constants: spec.round_constants,
+            mds: spec.mds,
+            lookup_tables,
+            r: spec.r,
+            r_inv: spec.r_inv,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Tip4Spec<F> {
+    pub t: usize,
+    pub rounds: usize,
+    pub round_constants: Vec<Vec<F>>,
+    pub mds: Vec<Vec<F>>,
+    pub r: F,
+    pub r_inv: F,
+}
+
+impl<F: Tip4Field + Serialize + DeserializeOwned> ParamsSchema for Tip4Params<F> {
+    type Spec = Tip4Spec<F>;
+
+    fn to_spec(&self) -> Self::Spec {
+        Tip4Spec {
+            t: self.t,
+            rounds: self.rounds,
+            round_constants: self.round_constants.clone(),
+            mds: self.mds.clone(),
+            r: self.r.clone(),
+            r_inv: self.r_inv.clone(),
+        }
+    }
+
+    fn from_spec(spec: Self::Spec) -> Result<Self, ParamsError> {
+        use crate::tip4::instances::NUM_SPLIT_AND_LOOKUP;
+
+        let t = spec.t;
+        check(
+            "t",
+            t >= NUM_SPLIT_AND_LOOKUP,
+            "must cover the split-and-lookup lanes",
+        )?;
+        check_matrix("round_constants", &spec.round_constants, spec.rounds, t)?;
+        check_matrix("mds", &spec.mds, t, t)?;
+        check_montgomery_factor(&spec.r, &spec.r_inv)?;
+
+        Ok(Tip4Params {
+            t,
+            rounds: spec.rounds,
+            round_constants: spec.round_constants,
+            mds: spec.mds,
+            r: spec.r,
+            r_inv: spec.r_inv,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_json, to_json, ParamsError, ParamsSchema};
+    use crate::anemoi::{anemoi::Anemoi, instances::ANEMOI_BN254_2_PARAMS};
+    use crate::fields::bn254::Bn254;
+    use crate::fields::goldilocks::Goldilocks;
+    use crate::fields::{FieldElement, PrimeField};
+    use crate::gmimc_erf::{gmimc_erf::GmimcErf, instances::GMIMC_ERF_BABYBEAR_16_PARAMS};
+    use crate::griffin::{griffin::Griffin, instances::GRIFFIN_GOLDILOCKS_8_PARAMS};
+    use crate::monolith::instances::{MONOLITH_GOLDILOCKS_8_PARAMS, MONOLITH_KOALABEAR_16_PARAMS};
+    use crate::monolith::monolith::{Monolith31, Monolith64};
+    use crate::neptune::{instances::NEPTUNE_BN254_4_PARAMS, neptune::Neptune};
+    use crate::polocolo::{instances::POLOCOLO_BN254_3_PARAMS, polocolo::Polocolo};
+    use crate::poseidon::instances::{
+        POSEIDON_BN254_3_PARAMS, POSEIDON_ORIGINAL_MERSENNE31_16_PARAMS,
+    };
+    use crate::poseidon::poseidon::{Poseidon, PoseidonParams};
+    use crate::poseidon2::instances::{POSEIDON2_BN254_3_PARAMS, POSEIDON2_GOLDILOCKS_8_PARAMS};
+    use crate::poseidon2::poseidon2::{Poseidon2, Poseidon2Params};
+    use crate::reinforced_concrete::instances::REINFORCED_CONCRETE_BN254_3_PARAMS;
+    use crate::reinforced_concrete::reinforced_concrete::{
+        ReinforcedConcrete, ReinforcedConcreteParams,
+    };
+    use crate::rescueprime::{instances::RESCUE_PRIME_BN254_3_PARAMS, rescue_prime::RescuePrime};
+    use crate::rpo::{instances::RPO_GOLDILOCKS_12_PARAMS, rpo::Rpo};
+    use crate::skyscraper::{instances::SKYSCRAPER_BLS12_381_3_PARAMS, skyscraper::Skyscraper};
+    use crate::tip4::{instances::TIP4P_GOLDILOCKS_PARAMS, tip4::Tip4};
+    use crate::tip5::{instances::TIP5_GOLDILOCKS_PARAMS, tip5::Tip5};
+    use std::sync::Arc;
+
+    // JSON round trips must reproduce the permutation, and re-serialize to the same document.
+    fn assert_roundtrip<P, F>(params: &Arc<P>, width: usize, perm: impl Fn(&Arc<P>, &[F]) -> Vec<F>)
+    where
+        P: ParamsSchema,
+        F: FieldElement,
+    {
+        let json = to_json(&**params);
+        let loaded: Arc<P> = from_json(&json).unwrap();
+        assert_eq!(to_json(&*loaded), json);
+
+        let input: Vec<F> = (0..width as u64).map(F::from_u64).collect();
+        assert_eq!(perm(&loaded, &input), perm(params, &input));
+    }
+
+    #[test]
+    fn params_roundtrip() {
+        let p = &POSEIDON_BN254_3_PARAMS;
+        assert_roundtrip(p, 3, |p, x| Poseidon::new(p).permutation(x));
+        let p = &POSEIDON_ORIGINAL_MERSENNE31_16_PARAMS;
+        assert_roundtrip(p, 16, |p, x| Poseidon::new(p).permutation(x));
+        let p = &POSEIDON2_BN254_3_PARAMS;
+        assert_roundtrip(p, 3, |p, x| Poseidon2::new(p).permutation(x));
+        let p = &ANEMOI_BN254_2_PARAMS;
+        assert_roundtrip(p, 2, |p, x| Anemoi::new(p).permutation(x));
+        let p = &GMIMC_ERF_BABYBEAR_16_PARAMS;
+        assert_roundtrip(p, 16, |p, x| GmimcErf::new(p).permutation(x));
+        let p = &GRIFFIN_GOLDILOCKS_8_PARAMS;
+        assert_roundtrip(p, 8, |p, x| Griffin::new(p).permutation(x));
+        let p = &MONOLITH_GOLDILOCKS_8_PARAMS;
+        assert_roundtrip(p, 8, |p, x| Monolith64::new(p).permutation(x));
+        let p = &MONOLITH_KOALABEAR_16_PARAMS;
+        assert_roundtrip(p, 16, |p, x| Monolith31::new(p).permutation(x));
+        let p = &NEPTUNE_BN254_4_PARAMS;
+        assert_roundtrip(p, 4, |p, x| Neptune::new(p).permutation(x));
+        let p = &POLOCOLO_BN254_3_PARAMS;
+        assert_roundtrip(p, 3, |p, x| Polocolo::new(p).permutation(x));
+        let p = &REINFORCED_CONCRETE_BN254_3_PARAMS;
+        assert_roundtrip(p, 3, |p, x| ReinforcedConcrete::new(p).permutation(x));
+        let p = &RESCUE_PRIME_BN254_3_PARAMS;
+        assert_roundtrip(p, 3, |p, x| RescuePrime::new(p).permutation(x));
+        let p = &RPO_GOLDILOCKS_12_PARAMS;
+        assert_roundtrip(p, 12, |p, x| Rpo::new(p).permutation(x));
+        let p = &SKYSCRAPER_BLS12_381_3_PARAMS;
+        assert_roundtrip(p, 6, |p, x| Skyscraper::new(p).permutation(x));
+        let p = &TIP4P_GOLDILOCKS_PARAMS;
+        assert_roundtrip(p, 12, |p, x| Tip4::new(p).permutation(x));
+        let p = &TIP5_GOLDILOCKS_PARAMS;
+        assert_roundtrip(p, 16, |p, x| Tip5::new(p).permutation(x));
+
+        // The serde impls on the structs go through the same schema.
+        let embedded = serde_json::to_string(&vec![&**POSEIDON2_BN254_3_PARAMS]).unwrap();
+        let loaded: Vec<Poseidon2Params<Bn254>> = serde_json::from_str(&embedded).unwrap();
+        assert_eq!(to_json(&loaded[0]), to_json(&**POSEIDON2_BN254_3_PARAMS));
+    }
+
+    #[test]
+    fn invalid_params_are_rejected() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(&to_json(&**POSEIDON2_BN254_3_PARAMS)).unwrap();
+        let load = |json: &serde_json::Value| {
+            from_json::<Poseidon2Params<Bn254>>(&json.to_string()).unwrap_err()
+        };
+
+        json["round_constants"][0].as_array_mut().unwrap().pop();
+        assert!(matches!(
+            load(&json),
+            ParamsError::Length {
+                field: "round_constants",
+                expected: 3,
+                found: 2
+            }
+        ));
+
+        json["t"] = 5.into();
+        assert!(matches!(
+            load(&json),
+            ParamsError::Invalid { field: "t", .. }
+        ));
+
+        // Non-canonical and malformed elements, and unknown keys, fail in the parser.
+        json["t"] = 3.into();
+        json["round_constants"][0] = serde_json::json!(["0x1", "2", Bn254::modulus().to_string()]);
+        assert!(matches!(load(&json), ParamsError::Json(_)));
+        json["round_constants"][0] = serde_json::json!(["0x1", "2", "x"]);
+        assert!(matches!(load(&json), ParamsError::Json(_)));
+        json["round_constants"][0] = serde_json::json!(["0x1", "2", "3"]);
+        json["extra"] = 0.into();
+        assert!(matches!(load(&json), ParamsError::Json(_)));
+
+        // The Poseidon variant decides whether a partial-round matrix is present.
+        let mut json: serde_json::Value =
+            serde_json::from_str(&to_json(&**POSEIDON_BN254_3_PARAMS)).unwrap();
+        assert_eq!(json["variant"], "minit");
+        json["variant"] = "original".into();
+        assert!(matches!(
+            from_json::<PoseidonParams<Bn254>>(&json.to_string()),
+            Err(ParamsError::Invalid {
+                field: "mds_partial",
+                ..
+            })
+        ));
+
+        // S-box exponents are limited to the ones the permutations implement.
+        json["variant"] = "minit".into();
+        json["d"] = 11.into();
+        assert!(from_json::<PoseidonParams<Bn254>>(&json.to_string()).is_ok());
+        json["d"] = 4.into();
+        assert!(matches!(
+            from_json::<PoseidonParams<Bn254>>(&json.to_string()),
+            Err(ParamsError::Invalid { field: "d", .. })
+        ));
+        let mut json: serde_json::Value =
+            serde_json::from_str(&to_json(&**POSEIDON2_BN254_3_PARAMS)).unwrap();
+        json["d"] = 11.into();
+        assert!(matches!(load(&json), ParamsError::Invalid { field: "d", .. }));
+
+        // Poseidon2 only accepts the matrices its linear layers implement.
+        json["d"] = 5.into();
+        json["mat_external"][0][0] = "0x3".into();
+        assert!(matches!(
+            load(&json),
+            ParamsError::Invalid {
+                field: "mat_external",
+                ..
+            }
+        ));
+        json["mat_external"][0][0] = "0x2".into();
+        json["mat_internal"][2][2] = "0x4".into();
+        assert!(matches!(
+            load(&json),
+            ParamsError::Invalid {
+                field: "mat_internal",
+                ..
+            }
+        ));
+        let mut json: serde_json::Value =
+            serde_json::from_str(&to_json(&**POSEIDON2_GOLDILOCKS_8_PARAMS)).unwrap();
+        json["mat_internal"][3][3] = "0x4".into();
+        assert!(from_json::<Poseidon2Params<Goldilocks>>(&json.to_string()).is_ok());
+        json["mat_internal"][3][2] = "0x2".into();
+        assert!(matches!(
+            from_json::<Poseidon2Params<Goldilocks>>(&json.to_string()),
+            Err(ParamsError::Invalid {
+                field: "mat_internal",
+                ..
+            })
+        ));
+
+        // Bars digit sizes and s-box values that would make the decomposition panic.
+        let rc = serde_json::from_str::<serde_json::Value>(&to_json(
+            &**REINFORCED_CONCRETE_BN254_3_PARAMS,
+        ))
+        .unwrap();
+        let load_rc = |json: &serde_json::Value| {
+            from_json::<ReinforcedConcreteParams<Bn254>>(&json.to_string()).unwrap_err()
+        };
+        let mut json = rc.clone();
+        json["si"][3] = 0.into();
+        assert!(matches!(load_rc(&json), ParamsError::Invalid { field: "si", .. }));
+        let mut json = rc.clone();
+        json["si"].as_array_mut().unwrap().pop();
+        assert!(matches!(load_rc(&json), ParamsError::Invalid { field: "si", .. }));
+        let mut json = rc.clone();
+        json["si"][0] = u16::MAX.into();
+        json["si"][1] = u16::MAX.into();
+        json["si"][2] = u16::MAX.into();
+        assert!(matches!(load_rc(&json), ParamsError::Invalid { field: "si", .. }));
+        let mut json = rc;
+        let min_si = json["si"].as_array().unwrap().iter().map(|s| s.as_u64().unwrap()).min();
+        json["sbox"][0] = min_si.unwrap().into();
+        assert!(matches!(load_rc(&json), ParamsError::Invalid { field: "sbox", .. }));
+
+        let mut json: serde_json::Value =
+            serde_json::from_str(&to_json(&**TIP5_GOLDILOCKS_PARAMS)).unwrap();
+        json["r_inv"] = serde_json::to_value(Goldilocks::from_u64(2)).unwrap();
+        assert!(matches!(
+            from_json::<crate::tip5::tip5::Tip5Params<Goldilocks>>(&json.to_string()),
+            Err(ParamsError::Invalid { field: "r_inv", .. })
+        ));
+    }
+}
@@ -1,6 +1,8 @@
 use crate::fields::FieldElement;
+use alloc::vec;
 use num_bigint::{BigInt, BigUint};
-use num_traits::{One, Signed, Zero};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use sha3::digest::XofReader;
 
 pub(crate) fn modinv(value: &BigUint, modulus: &BigUint) -> BigUint {
     let mut t = BigInt::zero();
@@ -46,3 +48,58 @@ pub(crate) fn pow_biguint<F: FieldElement>(base: &F, exp: &BigUint) -> F {
 
     result
 }
+
+/// Smallest `d >= 3` with `gcd(d, p - 1) = 1`, together with `d^-1 mod (p - 1)`.
+pub(crate) fn smallest_invertible_power(p: &BigUint) -> (u64, BigUint) {
+    let p_minus_one = p - BigUint::one();
+    let mut d = 3u64;
+    loop {
+        let rem = (&p_minus_one % d)
+            .to_u64()
+            .expect("remainder fits into u64");
+        if gcd_u64(d, rem) == 1 {
+            break;
+        }
+        d += 1;
+    }
+    let d_inv = modinv(&BigUint::from(d), &p_minus_one);
+    (d, d_inv)
+}
+
+pub(crate) fn binomial(n: usize, k: usize) -> BigUint {
+    let mut out = BigUint::one();
+    for i in 0..k {
+        out *= BigUint::from(n - i);
+        out /= BigUint::from(i + 1);
+    }
+    out
+}
+
+/// Reads `ceil(log2(p) / 8)` bytes, masks the unused top bits and rejects values `>= p`.
+pub(crate) fn field_element_from_shake(p: &BigUint, shake: &mut dyn XofReader) -> BigUint {
+    let bits = p.bits() as usize;
+    let mod_bits = bits % 8;
+    let mask = if mod_bits == 0 {
+        0xFFu8
+    } else {
+        (1u8 << mod_bits) - 1
+    };
+
+    let mut buf = vec![0u8; bits.div_ceil(8)];
+    let last = buf.len() - 1;
+    loop {
+        shake.read(&mut buf);
+        buf[last] &= mask;
+        let candidate = BigUint::from_bytes_le(&buf);
+        if &candidate < p {
+            return candidate;
+        }
+    }
+}
+
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
@@ -5,6 +5,7 @@ use sok_zk_friendly_hash_functions::anemoi::instances::{
     ANEMOI_KOALABEAR_16_PARAMS, ANEMOI_KOALABEAR_24_PARAMS, ANEMOI_MERSENNE31_16_PARAMS,
     ANEMOI_MERSENNE31_24_PARAMS,
 };
+use sok_zk_friendly_hash_functions::fields::goldilocks::Goldilocks;
 use sok_zk_friendly_hash_functions::fields::{FieldElement, PrimeFieldWords};
 use sok_zk_friendly_hash_functions::griffin::griffin::Griffin;
 use sok_zk_friendly_hash_functions::griffin::instances::{
@@ -42,7 +43,9 @@ use sok_zk_friendly_hash_functions::neptune::instances::{
     NEPTUNE_MERSENNE31_24_PARAMS,
 };
 use sok_zk_friendly_hash_functions::polocolo::instances::{
-    POLOCOLO_BLS12_381_3_PARAMS, POLOCOLO_BN254_3_PARAMS,
+    POLOCOLO_BLS12_381_2_PARAMS, POLOCOLO_BLS12_381_3_PARAMS, POLOCOLO_BLS12_381_4_PARAMS,
+    POLOCOLO_BLS12_381_8_PARAMS, POLOCOLO_BN254_2_PARAMS, POLOCOLO_BN254_3_PARAMS,
+    POLOCOLO_BN254_4_PARAMS, POLOCOLO_BN254_8_PARAMS,
 };
 use sok_zk_friendly_hash_functions::polocolo::polocolo::Polocolo;
 use sok_zk_friendly_hash_functions::poseidon::poseidon::Poseidon;
@@ -51,16 +54,19 @@ use sok_zk_friendly_hash_functions::poseidon::instances::{
     POSEIDON_BLS12_381_3_PARAMS, POSEIDON_BN254_2_PARAMS, POSEIDON_BN254_3_PARAMS,
     POSEIDON_GOLDILOCKS_8_PARAMS, POSEIDON_GOLDILOCKS_12_PARAMS, POSEIDON_KOALABEAR_16_PARAMS,
     POSEIDON_KOALABEAR_24_PARAMS, POSEIDON_MERSENNE31_16_PARAMS,
-    POSEIDON_MERSENNE31_24_PARAMS,
+    POSEIDON_MERSENNE31_24_PARAMS, POSEIDON_PLONKY2_GOLDILOCKS_12_PARAMS,
 };
+use sok_zk_friendly_hash_functions::poseidon::plonky2::Plonky2Poseidon;
 use sok_zk_friendly_hash_functions::poseidon2::poseidon2::Poseidon2;
 use sok_zk_friendly_hash_functions::poseidon2::instances::{
     POSEIDON2_BABYBEAR_16_PARAMS, POSEIDON2_BABYBEAR_24_PARAMS, POSEIDON2_BLS12_381_2_PARAMS,
     POSEIDON2_BLS12_381_3_PARAMS, POSEIDON2_BN254_2_PARAMS, POSEIDON2_BN254_3_PARAMS,
-    POSEIDON2_GOLDILOCKS_8_PARAMS, POSEIDON2_GOLDILOCKS_12_PARAMS,
+    POSEIDON2_BN254_4_PARAMS, POSEIDON2_GOLDILOCKS_8_PARAMS, POSEIDON2_GOLDILOCKS_12_PARAMS,
     POSEIDON2_KOALABEAR_16_PARAMS, POSEIDON2_KOALABEAR_24_PARAMS,
     POSEIDON2_MERSENNE31_16_PARAMS, POSEIDON2_MERSENNE31_24_PARAMS,
 };
+use sok_zk_friendly_hash_functions::leakage::LeakageConfig;
+use sok_zk_friendly_hash_functions::registry;
 use sok_zk_friendly_hash_functions::reinforced_concrete::reinforced_concrete::ReinforcedConcrete;
 use sok_zk_friendly_hash_functions::reinforced_concrete::instances::{
     REINFORCED_CONCRETE_BLS12_381_3_PARAMS, REINFORCED_CONCRETE_BN254_3_PARAMS,
@@ -71,25 +77,49 @@ use sok_zk_friendly_hash_functions::rescueprime::instances::{
     RESCUE_PRIME_GOLDILOCKS_12_PARAMS,
 };
 use sok_zk_friendly_hash_functions::rescueprime::rescue_prime::RescuePrime;
+use sok_zk_friendly_hash_functions::rpo::instances::{
+    RPO_GOLDILOCKS_12_PARAMS, RPX_GOLDILOCKS_12_PARAMS,
+};
+use sok_zk_friendly_hash_functions::rpo::rpo::{Rpo, RpoField};
+use sok_zk_friendly_hash_functions::rpo::rpx::Rpx;
 use sok_zk_friendly_hash_functions::skyscraper::instances::{
     SKYSCRAPER_BLS12_381_2_PARAMS, SKYSCRAPER_BLS12_381_3_PARAMS, SKYSCRAPER_BN254_2_PARAMS,
     SKYSCRAPER_BN254_3_PARAMS,
 };
 use sok_zk_friendly_hash_functions::skyscraper::skyscraper::Skyscraper;
 use sok_zk_friendly_hash_functions::tip4::tip4::Tip4;
-use sok_zk_friendly_hash_functions::tip4::instances::TIP4P_GOLDILOCKS_PARAMS;
+use sok_zk_friendly_hash_functions::tip4::instances::{
+    TIP4P_GOLDILOCKS_PARAMS, TIP4_GOLDILOCKS_PARAMS,
+};
 use sok_zk_friendly_hash_functions::tip4::tip4::Tip4Field;
 use sok_zk_friendly_hash_functions::tip5::tip5::Tip5;
-use sok_zk_friendly_hash_functions::tip5::instances::TIP5_GOLDILOCKS_PARAMS;
+use sok_zk_friendly_hash_functions::tip5::instances::{
+    TIP5_BABYBEAR_16_PARAMS, TIP5_GOLDILOCKS_PARAMS, TIP5_KOALABEAR_16_PARAMS,
+    TIP5_MERSENNE31_16_PARAMS,
+};
 use sok_zk_friendly_hash_functions::tip5::tip5::Tip5Field;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 use sha2::{Digest, Sha256};
 use sha3::Keccak256;
 use std::hint::black_box;
 use std::time::Instant;
 
 const ITERS: usize = 1 << 14;
+// Permutations run on `INPUTS` random states from a fixed seed, `ITERS / INPUTS` times each, since
+// the cost of lookup-based primitives depends on the input.
+const INPUTS: usize = 16;
+const SEED: u64 = 0;
 
 fn main() {
+    // `cargo run --release -- poseidon2/babybear/24 ...` only benchmarks the named instances,
+    // `-- list` prints the registry and `-- leakage ...` runs the timing leakage test on them.
+    let names: Vec<String> = std::env::args().skip(1).collect();
+    if !names.is_empty() {
+        bench_registry(&names, ITERS);
+        return;
+    }
+
     println!("iters = {ITERS}");
 
     println!("\n== Poseidon (~256-bit fields) ==");
@@ -117,6 +147,16 @@ fn main() {
         &Poseidon::new(&POSEIDON_GOLDILOCKS_12_PARAMS),
         ITERS,
     );
+    bench_poseidon(
+        "Poseidon Plonky2 Goldilocks t=12",
+        &Poseidon::new(&POSEIDON_PLONKY2_GOLDILOCKS_12_PARAMS),
+        ITERS,
+    );
+    bench_plonky2_poseidon(
+        "Poseidon Plonky2 Goldilocks t=12 (fast MDS)",
+        &Plonky2Poseidon::new(&POSEIDON_PLONKY2_GOLDILOCKS_12_PARAMS),
+        ITERS,
+    );
 
     println!("\n== Poseidon (~31-bit fields) ==");
     bench_poseidon(
@@ -161,6 +201,11 @@ fn main() {
         &Poseidon2::new(&POSEIDON2_BN254_3_PARAMS),
         ITERS,
     );
+    bench_poseidon2(
+        "Poseidon2 BN254 t=4",
+        &Poseidon2::new(&POSEIDON2_BN254_4_PARAMS),
+        ITERS,
+    );
     bench_poseidon2(
         "Poseidon2 BLS12-381 t=2",
         &Poseidon2::new(&POSEIDON2_BLS12_381_2_PARAMS),
@@ -245,6 +290,10 @@ fn main() {
         ITERS,
     );
 
+    println!("\n== RPO/RPX (Goldilocks) ==");
+    bench_rpo("RPO Goldilocks t=12", &Rpo::new(&RPO_GOLDILOCKS_12_PARAMS), ITERS);
+    bench_rpx("RPX Goldilocks t=12", &Rpx::new(&RPX_GOLDILOCKS_12_PARAMS), ITERS);
+
     println!("\n== Anemoi (~256-bit fields) ==");
     bench_anemoi("Anemoi BN254 t=2", &Anemoi::new(&ANEMOI_BN254_2_PARAMS), ITERS);
     bench_anemoi(
@@ -508,6 +557,36 @@ fn main() {
         &Polocolo::new(&POLOCOLO_BLS12_381_3_PARAMS),
         ITERS,
     );
+    bench_polocolo(
+        "Polocolo BN254 t=2",
+        &Polocolo::new(&POLOCOLO_BN254_2_PARAMS),
+        ITERS,
+    );
+    bench_polocolo(
+        "Polocolo BN254 t=4",
+        &Polocolo::new(&POLOCOLO_BN254_4_PARAMS),
+        ITERS,
+    );
+    bench_polocolo(
+        "Polocolo BN254 t=8",
+        &Polocolo::new(&POLOCOLO_BN254_8_PARAMS),
+        ITERS,
+    );
+    bench_polocolo(
+        "Polocolo BLS12-381 t=2",
+        &Polocolo::new(&POLOCOLO_BLS12_381_2_PARAMS),
+        ITERS,
+    );
+    bench_polocolo(
+        "Polocolo BLS12-381 t=4",
+        &Polocolo::new(&POLOCOLO_BLS12_381_4_PARAMS),
+        ITERS,
+    );
+    bench_polocolo(
+        "Polocolo BLS12-381 t=8",
+        &Polocolo::new(&POLOCOLO_BLS12_381_8_PARAMS),
+        ITERS,
+    );
 
     println!("\n== Skyscraper (~256-bit fields) ==");
     bench_skyscraper(
@@ -587,7 +666,12 @@ fn main() {
         ITERS,
     );
 
-    println!("\n== Tip4' (Goldilocks) ==");
+    println!("\n== Tip4 / Tip4' (Goldilocks) ==");
+    bench_tip4(
+        "Tip4 Goldilocks t=16",
+        &Tip4::new(&TIP4_GOLDILOCKS_PARAMS),
+        ITERS,
+    );
     bench_tip4(
         "Tip4' Goldilocks",
         &Tip4::new(&TIP4P_GOLDILOCKS_PARAMS),
@@ -601,6 +685,23 @@ fn main() {
         ITERS,
     );
 
+    println!("\n== Tip5-style (~31-bit fields) ==");
+    bench_tip5(
+        "Tip5 BabyBear t=16",
+        &Tip5::new(&TIP5_BABYBEAR_16_PARAMS),
+        ITERS,
+    );
+    bench_tip5(
+        "Tip5 KoalaBear t=16",
+        &Tip5::new(&TIP5_KOALABEAR_16_PARAMS),
+        ITERS,
+    );
+    bench_tip5(
+        "Tip5 Mersenne31 t=16",
+        &Tip5::new(&TIP5_MERSENNE31_16_PARAMS),
+        ITERS,
+    );
+
     println!("\n== SHA2/Keccak (bytes, baseline) ==");
     bench_sha256("SHA-256 input=64B", ITERS, 64);
     bench_keccak256("Keccak-256 input=64B", ITERS, 64);
@@ -609,43 +710,43 @@ fn main() {
 }
 
 fn bench_poseidon<F: FieldElement>(label: &str, perm: &Poseidon<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_poseidon2<F: FieldElement>(label: &str, perm: &Poseidon2<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_anemoi<F: FieldElement>(label: &str, perm: &Anemoi<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_gmimc<F: FieldElement>(label: &str, perm: &GmimcErf<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_griffin<F: FieldElement>(label: &str, perm: &Griffin<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_neptune<F: FieldElement>(label: &str, perm: &Neptune<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_polocolo<F: PrimeFieldWords>(label: &str, perm: &Polocolo<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_skyscraper<F: PrimeFieldWords>(label: &str, perm: &Skyscraper<F>, iters: usize) {
-    let input = make_input::<F>(2 * perm.get_n());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(2 * perm.get_n());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_reinforced_concrete<F: PrimeFieldWords>(
@@ -653,33 +754,48 @@ fn bench_reinforced_concrete<F: PrimeFieldWords>(
     perm: &ReinforcedConcrete<F>,
     iters: usize,
 ) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_monolith64<F: MonolithField64>(label: &str, perm: &Monolith64<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_monolith31<F: MonolithField32>(label: &str, perm: &Monolith31<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_tip4<F: Tip4Field>(label: &str, perm: &Tip4<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_tip5<F: Tip5Field>(label: &str, perm: &Tip5<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_rescue<F: FieldElement>(label: &str, perm: &RescuePrime<F>, iters: usize) {
-    let input = make_input::<F>(perm.get_t());
-    bench_with_input(label, iters, &input, |inp| perm.permutation(inp));
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
+}
+
+fn bench_plonky2_poseidon(label: &str, perm: &Plonky2Poseidon, iters: usize) {
+    let inputs = make_inputs::<Goldilocks>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
+}
+
+fn bench_rpo<F: RpoField>(label: &str, perm: &Rpo<F>, iters: usize) {
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
+}
+
+fn bench_rpx<F: RpoField>(label: &str, perm: &Rpx<F>, iters: usize) {
+    let inputs = make_inputs::<F>(perm.get_t());
+    bench_with_inputs(label, iters, &inputs, |inp| perm.permutation(inp));
 }
 
 fn bench_sha256(label: &str, iters: usize, input_len: usize) {
@@ -692,29 +808,85 @@ fn bench_keccak256(label: &str, iters: usize, input_len: usize) {
     bench_with_bytes(label, iters, &input, |inp| Keccak256::digest(inp));
 }
 
-fn make_input<F: FieldElement>(t: usize) -> Vec<F> {
-    (0..t).map(|i| F::from_u64((i + 1) as u64)).collect()
+fn bench_registry(names: &[String], iters: usize) {
+    if names == ["list"] {
+        for info in registry::instances() {
+            println!(
+                "{} (rate {}, {} rounds, s-box degree {:?}, {})",
+                info.name, info.rate, info.rounds, info.sbox_degree, info.source
+            );
+        }
+        return;
+    }
+    if names.first().map(String::as_str) == Some("leakage") {
+        leakage_registry(&names[1..]);
+        return;
+    }
+
+    println!("iters = {iters}");
+    for name in names {
+        let Some(perm) = registry::get(name) else {
+            eprintln!("unknown instance {name}, see `list`");
+            std::process::exit(1);
+        };
+        let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+        let inputs: Vec<Vec<u64>> = (0..INPUTS).map(|_| perm.random_limbs(&mut rng)).collect();
+        bench_with_inputs(name, iters, &inputs, |inp| perm.permute_limbs(inp));
+    }
+}
+
+fn leakage_registry(names: &[String]) {
+    let config = LeakageConfig::default();
+    for name in names {
+        let Some(perm) = registry::get(name) else {
+            eprintln!("unknown instance {name}, see `list`");
+            std::process::exit(1);
+        };
+        println!("{name}: {}", perm.leakage_test(&config));
+    }
+}
+
+fn make_inputs<F: FieldElement>(t: usize) -> Vec<Vec<F>> {
+    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+    (0..INPUTS)
+        .map(|_| (0..t).map(|_| F::random(&mut rng)).collect())
+        .collect()
 }
 
 fn make_bytes_input(len: usize) -> Vec<u8> {
     (0..len).map(|i| (i as u8).wrapping_add(1)).collect()
 }
 
-fn bench_with_input<F: FieldElement, R, FFn: FnMut(&[F]) -> R>(
+// Reports the mean time per call and its standard deviation across the inputs.
+fn bench_with_inputs<T, R, FFn: FnMut(&[T]) -> R>(
     label: &str,
     iters: usize,
-    input: &[F],
+    inputs: &[Vec<T>],
     mut f: FFn,
 ) {
-    let start = Instant::now();
+    let per_input = (iters / inputs.len()).max(1);
     let mut out = None;
-    for _ in 0..iters {
-        out = Some(f(input));
-        black_box(&out);
+    let mut per_ns = Vec::with_capacity(inputs.len());
+    let start = Instant::now();
+    for input in inputs {
+        let input_start = Instant::now();
+        for _ in 0..per_input {
+            out = Some(f(input));
+            black_box(&out);
+        }
+        per_ns.push(input_start.elapsed().as_nanos() as f64 / per_input as f64);
     }
     let elapsed = start.elapsed();
-    let per_ns = elapsed.as_nanos() / iters as u128;
-    println!("{label}: {iters} iters in {elapsed:?} ({per_ns} ns/iter)");
+    let iters = per_input * inputs.len();
+
+    let mean = per_ns.iter().sum::<f64>() / per_ns.len() as f64;
+    let variance =
+        per_ns.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / (per_ns.len().max(2) - 1) as f64;
+    println!(
+        "{label}: {iters} iters in {elapsed:?} ({mean:.0} ns/iter, sd {:.0} ns across {} inputs)",
+        variance.sqrt(),
+        inputs.len()
+    );
     black_box(out);
 }
 